@@ -41,7 +41,7 @@ impl RepositorySession {
             } else {
                 anyhow!(
                     "Unable to load CI for branch '{branch}': configure a git remote named \
-                         '{remote_name}' with a supported GitHub, GitLab, or Gitea URL"
+                         '{remote_name}' with a supported GitHub, GitLab, Gitea, Bitbucket, or Azure DevOps URL"
                 )
             }
         })?;
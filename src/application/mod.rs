@@ -29,7 +29,7 @@ pub use operation::{
     RestackScope, TransactionStatus, TransactionSummary,
 };
 pub use repository::{RepositorySession, execute_repository_operation};
-pub(crate) use restack::RestackExecutionOptions;
+pub(crate) use restack::{RestackExecutionOptions, RestackStrategy};
 #[allow(unused_imports)]
 pub(crate) use submit::{
     PreparedSubmit, SubmitConfigSources, SubmitOptions, SubmitPreferences, SubmitPromptAnswer,
@@ -409,6 +409,7 @@ mod tests {
             number,
             state: "OPEN".into(),
             is_draft: Some(false),
+            head_owner: None,
         });
         metadata.write(repo.inner(), branch).unwrap();
     }
@@ -481,6 +482,7 @@ mod tests {
             |_, _| {
                 Ok(FakeForge {
                     open_pr_by_head: Some(fake_pr(42)),
+                    ..Default::default()
                 })
             },
             &mut NoopOperationReporter,
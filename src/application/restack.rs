@@ -23,6 +23,17 @@ pub(crate) struct RestackExecutionOptions {
     pub auto_stash: bool,
     pub restore_branch: Option<String>,
     pub completed_from_receipt: HashSet<String>,
+    pub strategy: RestackStrategy,
+}
+
+/// How a branch is brought up to date with its parent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum RestackStrategy {
+    /// Replay the branch's commits onto the parent (rewrites history).
+    #[default]
+    Rebase,
+    /// Merge the parent into the branch, keeping existing commit IDs.
+    Merge,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,6 +61,7 @@ impl RepositorySession {
             auto_stash,
             restore_branch: None,
             completed_from_receipt: HashSet::new(),
+            strategy: RestackStrategy::Rebase,
         };
         self.restack_with_options(options, reporter)
     }
@@ -81,6 +93,7 @@ impl RepositorySession {
             auto_stash,
             restore_branch: None,
             completed_from_receipt: HashSet::new(),
+            strategy: RestackStrategy::Rebase,
         };
         self.restack_with_options_unframed(request, options, reporter)
     }
@@ -528,7 +541,9 @@ fn restack_inner(
             .and_then(|branch| branch.pr_state.as_deref())
             .unwrap_or("");
         let pr_is_open = matches!(pr_state.to_uppercase().as_str(), "OPEN" | "DRAFT");
-        let rebase_result = if pr_is_open {
+        let rebase_result = if options.strategy == RestackStrategy::Merge {
+            repo.merge_parent_into_branch(branch, &parent_branch_name, options.auto_stash)
+        } else if pr_is_open {
             repo.rebase_branch_onto_with_provenance_no_squash_check(
                 branch,
                 &parent_branch_name,
@@ -589,17 +604,41 @@ fn restack_inner(
                 let source_remote = existing_metadata
                     .as_ref()
                     .and_then(|metadata| metadata.source_remote.clone());
-                let frozen = existing_metadata.is_some_and(|metadata| metadata.frozen);
+                let remote = existing_metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.remote.clone());
+                let head_owner = existing_metadata
+                    .as_ref()
+                    .and_then(|metadata| metadata.pr_info.as_ref())
+                    .and_then(|pr_info| pr_info.head_owner.clone());
+                let frozen = existing_metadata
+                    .as_ref()
+                    .is_some_and(|metadata| metadata.frozen);
+                let (reviewers, team_reviewers, description) = existing_metadata
+                    .map(|metadata| {
+                        (
+                            metadata.reviewers,
+                            metadata.team_reviewers,
+                            metadata.description,
+                        )
+                    })
+                    .unwrap_or_default();
                 let updated_metadata = BranchMetadata {
                     parent_branch_name: parent_branch_name.clone(),
                     parent_branch_revision: new_parent_rev.clone(),
                     source_remote,
+                    remote,
                     frozen,
+                    reviewers,
+                    team_reviewers,
+                    base_tag: None,
+                    description,
                     pr_info: live_stack.branches.get(branch).and_then(|branch| {
                         branch.pr_number.map(|number| PrInfo {
                             number,
                             state: branch.pr_state.clone().unwrap_or_default(),
                             is_draft: branch.pr_is_draft,
+                            head_owner: head_owner.clone(),
                         })
                     }),
                 };
@@ -656,6 +695,30 @@ fn restack_inner(
                 transaction_mut(&mut tx).push_completed_branch(branch);
                 completed.push(branch.clone());
             }
+            RebaseResult::Conflict if options.strategy == RestackStrategy::Merge => {
+                return Err(finalize_restack_failure(
+                    request,
+                    RestackFailureState {
+                        transaction: tx.take(),
+                        completed_branches: completed,
+                        stashes,
+                        side_effects: OperationSideEffects::RepositoryChanged,
+                    },
+                    OperationErrorKind::LocalGit,
+                    OperationErrorDetails::Branch {
+                        branch: branch.clone(),
+                    },
+                    format!("Merging '{parent_branch_name}' into '{branch}' stopped on a conflict"),
+                    "Resolve the conflicts and `git commit`, then rerun the restack with \
+                     `--restack-strategy merge`; or run `git merge --abort`"
+                        .to_string(),
+                    anyhow::anyhow!(
+                        "merge conflict while merging '{parent_branch_name}' into '{branch}'"
+                    ),
+                    "merge",
+                    Some(branch),
+                ));
+            }
             RebaseResult::Conflict => {
                 if !repo.has_conflicts_in(&target_workdir).unwrap_or(true) {
                     return Err(finalize_restack_failure(
@@ -38,6 +38,16 @@ impl SubmitScope {
             Self::Stack => "stack",
         }
     }
+
+    pub(crate) fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "branch" => Some(Self::Branch),
+            "downstack" => Some(Self::Downstack),
+            "upstack" => Some(Self::Upstack),
+            "stack" => Some(Self::Stack),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -54,6 +64,10 @@ pub(crate) struct SubmitOptions {
     pub rerequest_review: bool,
     pub native_stack_override: Option<NativeStackMode>,
     pub update_title: bool,
+    /// `stax --timeout` for forge requests made by this submit.
+    pub request_timeout: Option<u64>,
+    /// Remote chosen by `--remote` or recorded on the stack.
+    pub remote: Option<String>,
 }
 
 impl SubmitOptions {
@@ -71,6 +85,8 @@ impl SubmitOptions {
             rerequest_review: false,
             native_stack_override: None,
             update_title: false,
+            request_timeout: None,
+            remote: None,
         }
     }
 }
@@ -205,8 +221,13 @@ impl RepositorySession {
             || Ok(()),
         )?;
 
-        let trusted_network =
-            Config::load_for_trusted_network(self.repository_root()).map_err(|error| {
+        let trusted_network = Config::load_for_trusted_network(self.repository_root())
+            .map(|config| {
+                config
+                    .with_request_timeout(options.request_timeout)
+                    .with_remote(options.remote.clone())
+            })
+            .map_err(|error| {
                 submit_source_error(
                     &request,
                     OperationErrorKind::Authentication,
@@ -760,8 +781,13 @@ fn execute_submit_stack(
             None,
         )
     })?;
-    let trusted_network =
-        Config::load_for_trusted_network(session.repository_root()).map_err(|error| {
+    let trusted_network = Config::load_for_trusted_network(session.repository_root())
+        .map(|config| {
+            config
+                .with_request_timeout(options.request_timeout)
+                .with_remote(options.remote.clone())
+        })
+        .map_err(|error| {
             submit_source_error(
                 request,
                 OperationErrorKind::Authentication,
@@ -10,6 +10,7 @@ use tempfile::NamedTempFile;
 
 const MAX_TUI_DIFF_CACHE_ENTRIES: usize = 128;
 const MAX_TUI_DIFF_CACHE_BYTES: u64 = 100 * 1024 * 1024;
+const MAX_API_CACHE_ENTRIES: usize = 512;
 
 enum LockMode {
     Shared,
@@ -137,6 +138,18 @@ pub struct BranchCacheEntry {
     pub ci_state: Option<String>,
     pub pr_state: Option<String>,
     pub updated_at: u64,
+    /// Individual checks for `ci_revision`, shown by `stax log --checks`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub checks: Vec<CachedCheck>,
+}
+
+/// One CI check as last seen by `stax ci`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CachedCheck {
+    pub name: String,
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -218,11 +231,17 @@ impl CiCache {
     pub(crate) fn refresh_branches(
         git_dir: &std::path::Path,
         updates: &[(String, String, Option<String>, Option<String>)],
+        checks: &HashMap<String, Vec<CachedCheck>>,
         valid_branches: &[String],
     ) -> Result<()> {
         Self::transaction(git_dir, |stored| {
             for (branch, revision, ci_state, pr_state) in updates {
                 stored.update(branch, revision, ci_state.clone(), pr_state.clone());
+                if let (Some(entry), Some(checks)) =
+                    (stored.branches.get_mut(branch), checks.get(branch))
+                {
+                    entry.checks = checks.clone();
+                }
             }
             stored.cleanup(valid_branches);
             stored.mark_refreshed();
@@ -238,6 +257,15 @@ impl CiCache {
         })
     }
 
+    /// Get cached checks only when they belong to the branch's current commit.
+    pub fn get_checks_for_revision(&self, branch: &str, revision: &str) -> &[CachedCheck] {
+        self.branches
+            .get(branch)
+            .filter(|entry| entry.ci_revision.as_deref() == Some(revision))
+            .map(|entry| entry.checks.as_slice())
+            .unwrap_or_default()
+    }
+
     /// Update both cached fields for one exact branch revision.
     pub fn update(
         &mut self,
@@ -250,6 +278,13 @@ impl CiCache {
             .duration_since(UNIX_EPOCH)
             .map(|d| d.as_secs())
             .unwrap_or(0);
+        // Checks describe one revision; keep them only while it is unchanged.
+        let checks = self
+            .branches
+            .remove(branch)
+            .filter(|entry| entry.ci_revision.as_deref() == Some(revision))
+            .map(|entry| entry.checks)
+            .unwrap_or_default();
 
         self.branches.insert(
             branch.to_string(),
@@ -258,6 +293,7 @@ impl CiCache {
                 ci_state,
                 pr_state,
                 updated_at: now,
+                checks,
             },
         );
     }
@@ -288,6 +324,7 @@ impl CiCache {
                     ci_state: None,
                     pr_state,
                     updated_at: now,
+                    checks: Vec::new(),
                 },
             );
         }
@@ -301,6 +338,65 @@ impl CiCache {
             .unwrap_or(0);
     }
 
+    /// When any cached PR/CI state was last written, for commands that render
+    /// it without reaching the forge. `None` if nothing was ever cached.
+    pub fn refreshed_at(&self) -> Option<chrono::DateTime<chrono::Local>> {
+        let newest = self
+            .branches
+            .values()
+            .map(|entry| entry.updated_at)
+            .fold(self.last_refresh, u64::max);
+        if newest == 0 {
+            return None;
+        }
+        chrono::DateTime::from_timestamp(i64::try_from(newest).ok()?, 0)
+            .map(|at| at.with_timezone(&chrono::Local))
+    }
+
+    /// One-line marker shown when cached PR/CI state stands in for live data.
+    pub fn stale_notice(&self) -> String {
+        match self.refreshed_at() {
+            Some(at) => format!(
+                "Offline: PR/CI state is stale as of {}",
+                at.format("%Y-%m-%d %H:%M")
+            ),
+            None => "Offline: no cached PR/CI state yet (run `stax ci` online)".to_string(),
+        }
+    }
+
+    /// Seconds since the last full refresh, or `None` if there never was one.
+    pub fn age_secs(&self) -> Option<u64> {
+        (self.last_refresh > 0).then(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0)
+                .saturating_sub(self.last_refresh)
+        })
+    }
+
+    /// Whether the last full refresh is within `ttl_secs` (`cache.ttl_secs`).
+    pub fn is_fresh(&self, ttl_secs: u64) -> bool {
+        self.age_secs().is_some_and(|age| age <= ttl_secs)
+    }
+
+    /// Drop every cached entry.
+    pub(crate) fn clear(git_dir: &std::path::Path) -> Result<()> {
+        Self::transaction(git_dir, |stored| *stored = Self::default())
+    }
+
+    /// Drop entries for branches not in `valid_branches`, returning how many
+    /// were removed.
+    pub(crate) fn prune(git_dir: &std::path::Path, valid_branches: &[String]) -> Result<usize> {
+        let mut removed = 0;
+        Self::transaction(git_dir, |stored| {
+            let before = stored.branches.len();
+            stored.cleanup(valid_branches);
+            removed = before - stored.branches.len();
+        })?;
+        Ok(removed)
+    }
+
     /// Remove branches that no longer exist
     pub fn cleanup(&mut self, valid_branches: &[String]) {
         let valid_set: std::collections::HashSet<_> = valid_branches.iter().collect();
@@ -797,6 +893,111 @@ impl AheadBehindCache {
     }
 }
 
+/// A forge `GET` response kept for conditional requests.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CachedApiResponse {
+    pub endpoint: String,
+    pub etag: String,
+    pub body: String,
+}
+
+/// Forge API responses keyed by endpoint, one file per entry.
+///
+/// Entries are never served without asking the forge first: the client sends
+/// the stored ETag as `If-None-Match`, and a `304 Not Modified` reply (which
+/// GitHub does not count against the rate limit) means the body is current.
+pub struct ApiResponseCache;
+
+impl ApiResponseCache {
+    fn entries_dir(git_dir: &Path) -> PathBuf {
+        git_dir.join("stax").join("api-cache").join("v1")
+    }
+
+    fn entry_path(git_dir: &Path, endpoint: &str) -> PathBuf {
+        Self::entries_dir(git_dir).join(format!("{:016x}.json", endpoint_hash(endpoint)))
+    }
+
+    pub fn get(git_dir: &Path, endpoint: &str) -> Option<CachedApiResponse> {
+        let path = Self::entry_path(git_dir, endpoint);
+        if !path.exists() {
+            return None;
+        }
+        let _lock = acquire_cache_lock(&path, LockMode::Shared).ok()?;
+        load_json_unlocked::<Option<CachedApiResponse>>(&path)
+            .ok()
+            .flatten()
+            .filter(|entry| entry.endpoint == endpoint)
+    }
+
+    pub fn store(git_dir: &Path, response: &CachedApiResponse) -> Result<()> {
+        let path = Self::entry_path(git_dir, &response.endpoint);
+        {
+            let _lock = acquire_cache_lock(&path, LockMode::Exclusive)?;
+            persist_json_atomic(&path, response)?;
+        }
+        Self::prune(&Self::entries_dir(git_dir), MAX_API_CACHE_ENTRIES)
+    }
+
+    /// Number of stored responses and their total size in bytes.
+    pub fn usage(git_dir: &Path) -> (usize, u64) {
+        let entries = Self::entries(&Self::entries_dir(git_dir)).unwrap_or_default();
+        let bytes = entries
+            .iter()
+            .filter_map(|(_, path)| fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+        (entries.len(), bytes)
+    }
+
+    /// Remove every stored response, returning how many were removed.
+    pub fn clear(git_dir: &Path) -> Result<usize> {
+        let entries_dir = Self::entries_dir(git_dir);
+        if !entries_dir.exists() {
+            return Ok(0);
+        }
+        let count = Self::entries(&entries_dir)?.len();
+        fs::remove_dir_all(&entries_dir)
+            .with_context(|| format!("Failed to remove {}", entries_dir.display()))?;
+        Ok(count)
+    }
+
+    fn entries(entries_dir: &Path) -> Result<Vec<(SystemTime, PathBuf)>> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(entries_dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().and_then(|extension| extension.to_str()) != Some("json") {
+                continue;
+            }
+            let modified = entry.metadata()?.modified().unwrap_or(UNIX_EPOCH);
+            entries.push((modified, path));
+        }
+        Ok(entries)
+    }
+
+    /// Drop the least recently written entries beyond `max_entries`.
+    fn prune(entries_dir: &Path, max_entries: usize) -> Result<()> {
+        let mut entries = Self::entries(entries_dir)?;
+        if entries.len() <= max_entries {
+            return Ok(());
+        }
+        entries.sort();
+        let excess = entries.len() - max_entries;
+        for (_, path) in entries.into_iter().take(excess) {
+            let _ = fs::remove_file(&path);
+            let _ = fs::remove_file(cache_lock_path(&path));
+        }
+        Ok(())
+    }
+}
+
+/// FNV-1a, which unlike `DefaultHasher` is stable across Rust releases.
+fn endpoint_hash(endpoint: &str) -> u64 {
+    endpoint.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
 #[cfg(test)]
 fn current_unix_time() -> u64 {
     SystemTime::now()
@@ -1131,6 +1332,85 @@ mod tests {
         assert_eq!(entry_lock_count, entry_count);
     }
 
+    fn api_response(endpoint: &str, etag: &str) -> CachedApiResponse {
+        CachedApiResponse {
+            endpoint: endpoint.to_string(),
+            etag: etag.to_string(),
+            body: format!("{{\"endpoint\":\"{}\"}}", endpoint),
+        }
+    }
+
+    #[test]
+    fn api_response_cache_round_trips_by_endpoint() {
+        let temp = TempDir::new().unwrap();
+        let endpoint = "/repos/o/r/pulls/1";
+
+        assert_eq!(ApiResponseCache::get(temp.path(), endpoint), None);
+        ApiResponseCache::store(temp.path(), &api_response(endpoint, "\"v1\"")).unwrap();
+        ApiResponseCache::store(temp.path(), &api_response(endpoint, "\"v2\"")).unwrap();
+
+        assert_eq!(
+            ApiResponseCache::get(temp.path(), endpoint),
+            Some(api_response(endpoint, "\"v2\""))
+        );
+        assert_eq!(
+            ApiResponseCache::get(temp.path(), "/repos/o/r/pulls/2"),
+            None
+        );
+    }
+
+    #[test]
+    fn api_response_cache_clear_reports_and_removes_entries() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(ApiResponseCache::clear(temp.path()).unwrap(), 0);
+        for number in 0..3 {
+            let endpoint = format!("/repos/o/r/pulls/{}", number);
+            ApiResponseCache::store(temp.path(), &api_response(&endpoint, "\"e\"")).unwrap();
+        }
+
+        let (entries, bytes) = ApiResponseCache::usage(temp.path());
+        assert_eq!(entries, 3);
+        assert!(bytes > 0);
+        assert_eq!(ApiResponseCache::clear(temp.path()).unwrap(), 3);
+        assert_eq!(ApiResponseCache::usage(temp.path()), (0, 0));
+    }
+
+    #[test]
+    fn api_response_cache_prunes_oldest_entries() {
+        let temp = TempDir::new().unwrap();
+        let entries_dir = ApiResponseCache::entries_dir(temp.path());
+        for number in 0..4 {
+            let endpoint = format!("/repos/o/r/pulls/{}", number);
+            ApiResponseCache::store(temp.path(), &api_response(&endpoint, "\"e\"")).unwrap();
+            let path = ApiResponseCache::entry_path(temp.path(), &endpoint);
+            let file = OpenOptions::new().write(true).open(&path).unwrap();
+            file.set_times(
+                fs::FileTimes::new()
+                    .set_modified(UNIX_EPOCH + std::time::Duration::from_secs(1_000 + number)),
+            )
+            .unwrap();
+        }
+
+        ApiResponseCache::prune(&entries_dir, 2).unwrap();
+
+        assert_eq!(
+            ApiResponseCache::get(temp.path(), "/repos/o/r/pulls/0"),
+            None
+        );
+        assert_eq!(
+            ApiResponseCache::get(temp.path(), "/repos/o/r/pulls/1"),
+            None
+        );
+        assert!(ApiResponseCache::get(temp.path(), "/repos/o/r/pulls/3").is_some());
+        assert!(
+            !cache_lock_path(&ApiResponseCache::entry_path(
+                temp.path(),
+                "/repos/o/r/pulls/0"
+            ))
+            .exists()
+        );
+    }
+
     #[test]
     fn test_cache_path() {
         let temp = TempDir::new().unwrap();
@@ -1279,6 +1559,58 @@ mod tests {
         assert_eq!(entry.pr_state.as_deref(), Some("DRAFT"));
     }
 
+    #[test]
+    fn test_prune_and_clear_keep_the_cache_file_consistent() {
+        let temp = TempDir::new().unwrap();
+        CiCache::update_branch_ci(temp.path(), "kept", "rev-a", Some("success".into())).unwrap();
+        CiCache::update_branch_ci(temp.path(), "gone", "rev-b", Some("failure".into())).unwrap();
+
+        let removed = CiCache::prune(temp.path(), &["kept".to_string()]).unwrap();
+        assert_eq!(removed, 1);
+        let cache = CiCache::load(temp.path());
+        assert!(cache.branches.contains_key("kept"));
+        assert!(!cache.branches.contains_key("gone"));
+
+        CiCache::clear(temp.path()).unwrap();
+        let cache = CiCache::load(temp.path());
+        assert!(cache.branches.is_empty());
+        assert_eq!(cache.last_refresh, 0);
+    }
+
+    #[test]
+    fn test_is_fresh_compares_last_refresh_with_ttl() {
+        let mut cache = CiCache::default();
+        assert!(!cache.is_fresh(90));
+        cache.last_refresh = current_unix_time() - 30;
+        assert!(cache.is_fresh(90));
+        assert!(!cache.is_fresh(10));
+    }
+
+    #[test]
+    fn test_refreshed_at_uses_newest_entry_or_refresh() {
+        let mut cache = CiCache::default();
+        assert!(cache.refreshed_at().is_none());
+        assert!(cache.stale_notice().contains("no cached PR/CI state"));
+
+        cache.last_refresh = 1_000;
+        cache.branches.insert(
+            "feature".to_string(),
+            BranchCacheEntry {
+                ci_revision: None,
+                ci_state: Some("success".to_string()),
+                pr_state: Some("OPEN".to_string()),
+                updated_at: 2_000,
+                checks: Vec::new(),
+            },
+        );
+        assert_eq!(cache.refreshed_at().map(|at| at.timestamp()), Some(2_000));
+        assert!(
+            cache
+                .stale_notice()
+                .starts_with("Offline: PR/CI state is stale as of ")
+        );
+    }
+
     #[test]
     fn test_cache_mark_refreshed() {
         let mut cache = CiCache::default();
@@ -1320,6 +1652,7 @@ mod tests {
             ci_state: Some("success".to_string()),
             pr_state: Some("OPEN".to_string()),
             updated_at: 1234567890,
+            checks: Vec::new(),
         };
         let json = serde_json::to_string(&entry).unwrap();
         assert!(json.contains("success"));
@@ -1333,6 +1666,35 @@ mod tests {
         assert_eq!(deserialized.updated_at, entry.updated_at);
     }
 
+    #[test]
+    fn cached_checks_are_dropped_when_the_revision_changes() {
+        let mut cache = CiCache::default();
+        cache.update("branch", "revision-1", Some("success".to_string()), None);
+        cache.branches.get_mut("branch").unwrap().checks = vec![CachedCheck {
+            name: "build".to_string(),
+            status: "completed".to_string(),
+            conclusion: Some("success".to_string()),
+        }];
+
+        cache.update_ci("branch", "revision-1", Some("success".to_string()));
+        assert_eq!(
+            cache.get_checks_for_revision("branch", "revision-1").len(),
+            1
+        );
+        assert!(
+            cache
+                .get_checks_for_revision("branch", "revision-2")
+                .is_empty()
+        );
+
+        cache.update_ci("branch", "revision-2", Some("pending".to_string()));
+        assert!(
+            cache
+                .get_checks_for_revision("branch", "revision-2")
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_cache_serialization() {
         let mut cache = CiCache::default();
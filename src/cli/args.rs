@@ -12,6 +12,37 @@ fn parse_positive_usize(value: &str) -> Result<usize, String> {
         .ok_or_else(|| "value must be greater than zero".to_string())
 }
 
+/// Parse a short duration such as `90s`, `15m`, `1h`, or `2d` (bare numbers are seconds).
+pub(crate) fn parse_duration(value: &str) -> Result<std::time::Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (digits, unit) = value.split_at(split);
+    let amount = digits.parse::<u64>().map_err(|_| {
+        format!(
+            "invalid duration '{}': expected e.g. 30s, 15m, 1h, 2d",
+            value
+        )
+    })?;
+    let multiplier = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        _ => {
+            return Err(format!(
+                "invalid duration unit '{}': use s, m, h, or d",
+                unit
+            ));
+        }
+    };
+    let secs = amount
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("invalid duration '{}': too large", value))?;
+    Ok(std::time::Duration::from_secs(secs))
+}
+
 #[derive(Parser)]
 #[command(name = "stax")]
 #[command(version)]
@@ -21,6 +52,10 @@ pub(crate) struct Cli {
     #[arg(long, global = true)]
     pub(crate) trace: bool,
 
+    /// Abandon forge API requests after SECS seconds (before the subcommand: `stax --timeout 10 submit`)
+    #[arg(long, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub(crate) timeout: Option<u64>,
+
     #[command(subcommand)]
     pub(crate) command: Option<Commands>,
 }
@@ -43,7 +78,7 @@ pub(crate) struct SubmitOptions {
     #[arg(short, long, conflicts_with = "publish")]
     pub(crate) draft: bool,
     /// Create new PRs as published; convert existing draft PRs to published
-    #[arg(long, conflicts_with = "draft")]
+    #[arg(long, visible_alias = "ready", conflicts_with = "draft")]
     pub(crate) publish: bool,
     /// Only push, don't create/update PRs
     #[arg(long)]
@@ -82,12 +117,12 @@ pub(crate) struct SubmitOptions {
     #[arg(short, long)]
     pub(crate) verbose: bool,
     /// Specify template by name (skip picker)
-    #[arg(long)]
+    #[arg(long, visible_alias = "pr-template")]
     pub(crate) template: Option<String>,
     /// Skip template selection (no template)
     #[arg(long)]
     pub(crate) no_template: bool,
-    /// Always open editor for PR body
+    /// Open $EDITOR on each PR's title and body before creating or updating it
     #[arg(long)]
     pub(crate) edit: bool,
     /// Generate PR title and body using AI
@@ -102,6 +137,20 @@ pub(crate) struct SubmitOptions {
     /// Re-request review from existing reviewers when updating PRs
     #[arg(long)]
     pub(crate) rerequest_review: bool,
+    /// Request review from the CODEOWNERS of each new PR's changed files
+    #[arg(long)]
+    pub(crate) codeowners: bool,
+    /// Enable GitHub auto-merge on each submitted PR (squash unless given)
+    #[arg(
+        long,
+        value_name = "METHOD",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "squash",
+        value_parser = ["squash", "merge", "rebase"],
+        conflicts_with = "no_pr"
+    )]
+    pub(crate) auto_merge: Option<String>,
     /// Force-attempt native GitHub Stack registration via `gh stack`
     #[arg(long, conflicts_with = "no_native_stack")]
     pub(crate) native_stack: bool,
@@ -114,6 +163,38 @@ pub(crate) struct SubmitOptions {
     /// Update existing PR titles when the tip commit subject has changed
     #[arg(long)]
     pub(crate) update_title: bool,
+    /// Push to and open PRs on this git remote; remembered for the stack
+    #[arg(long, value_name = "NAME", visible_alias = "git-remote")]
+    pub(crate) remote: Option<String>,
+    /// Submit only the current branch
+    #[arg(long, conflicts_with_all = ["downstack", "up_to"])]
+    pub(crate) only: bool,
+    /// Submit the current branch and its ancestors, not its descendants
+    #[arg(long, visible_alias = "downstack-to", conflicts_with = "up_to")]
+    pub(crate) downstack: bool,
+    /// Submit the stack from trunk up to and including this branch
+    #[arg(long, value_name = "BRANCH")]
+    pub(crate) up_to: Option<String>,
+    /// Resume an interrupted submit from the first branch it did not finish
+    #[arg(long, conflicts_with_all = ["dry_run", "only", "downstack", "up_to"])]
+    pub(crate) r#continue: bool,
+}
+
+impl SubmitOptions {
+    /// Narrow the command's default scope with `--only` / `--downstack` /
+    /// `--up-to`.
+    pub(crate) fn scope(
+        &self,
+        default: commands::submit::SubmitScope,
+    ) -> commands::submit::SubmitScope {
+        if self.only {
+            commands::submit::SubmitScope::Branch
+        } else if self.downstack || self.up_to.is_some() {
+            commands::submit::SubmitScope::Downstack
+        } else {
+            default
+        }
+    }
 }
 
 impl From<SubmitOptions> for commands::submit::SubmitOptions {
@@ -126,6 +207,8 @@ impl From<SubmitOptions> for commands::submit::SubmitOptions {
             no_pr: submit.no_pr,
             no_fetch: submit.no_fetch,
             prefetched: false,
+            request_timeout: None,
+            remote: None,
             no_verify: submit.no_verify,
             force: submit.force,
             yes: submit.yes,
@@ -143,6 +226,10 @@ impl From<SubmitOptions> for commands::submit::SubmitOptions {
             title: submit.title,
             body: submit.body,
             rerequest_review: submit.rerequest_review,
+            codeowners: submit.codeowners,
+            auto_merge: submit
+                .auto_merge
+                .map(|method| method.parse().unwrap_or_default()),
             native_stack_override: if submit.no_native_stack {
                 Some(crate::config::NativeStackMode::Off)
             } else if submit.native_stack {
@@ -152,6 +239,163 @@ impl From<SubmitOptions> for commands::submit::SubmitOptions {
             },
             squash: submit.squash,
             update_title: submit.update_title,
+            up_to: submit.up_to,
+            resume: submit.r#continue,
+        }
+    }
+}
+
+#[derive(Args, Clone)]
+pub(crate) struct CreateArgs {
+    /// Name for the new branch
+    pub(crate) name: Option<String>,
+    /// Stage all changes (like git commit --all)
+    #[arg(short, long)]
+    pub(crate) all: bool,
+    /// Commit message (also used as branch name if no name provided)
+    #[arg(short, long, group = "commit_message")]
+    pub(crate) message: Option<String>,
+    /// Read the commit message from a file (first line also names the branch)
+    #[arg(short = 'F', long, value_name = "PATH", group = "commit_message")]
+    pub(crate) message_file: Option<String>,
+    /// Generate missing branch name and/or first commit message with AI
+    #[arg(long)]
+    pub(crate) ai: bool,
+    /// Accept generated AI values without prompting
+    #[arg(short, long)]
+    pub(crate) yes: bool,
+    /// Base branch or revision (`HEAD~2`, a SHA, a tag) to create from (defaults to current)
+    #[arg(long)]
+    pub(crate) from: Option<String>,
+    /// Override branch prefix (e.g. "feature/")
+    #[arg(long)]
+    pub(crate) prefix: Option<String>,
+    /// Insert between current branch and its children (reparent children)
+    #[arg(long, conflicts_with = "below")]
+    pub(crate) insert: bool,
+    /// Insert below current branch (reparent current and descendants)
+    #[arg(long, conflicts_with_all = ["insert", "from"])]
+    pub(crate) below: bool,
+    /// Skip pre-commit and commit-msg hooks, and the configured `branch.post_create_hook`
+    #[arg(long = "no-verify", short = 'n')]
+    pub(crate) no_verify: bool,
+    /// Parent branch to record when creating from a detached HEAD, `--from-tag`, or a `--from` revision
+    #[arg(long, conflicts_with_all = ["insert", "below"])]
+    pub(crate) parent: Option<String>,
+    /// Apply a patch file (`-` for stdin) and commit it on the new branch
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["all", "ai", "below", "parent"])]
+    pub(crate) patch: Option<String>,
+    /// Configure the new branch's upstream (e.g. `origin/my-branch`) without pushing
+    #[arg(long, value_name = "REMOTE/BRANCH")]
+    pub(crate) set_upstream: Option<String>,
+    /// Reviewers to request when submit opens the PR (comma-separated; `@org/team` for teams)
+    #[arg(long, value_delimiter = ',')]
+    pub(crate) reviewers: Vec<String>,
+    /// Command to run after the branch is created (overrides `branch.post_create_hook`)
+    #[arg(long, value_name = "COMMAND")]
+    pub(crate) hook: Option<String>,
+    /// Run `branch.scaffold_command` on the new branch to generate boilerplate
+    #[arg(long, conflicts_with = "dry_commit")]
+    pub(crate) scaffold: bool,
+    /// With --scaffold, stage and commit whatever the generator wrote
+    #[arg(long, requires = "scaffold")]
+    pub(crate) scaffold_commit: bool,
+    /// Record no parent revision so the branch is flagged for restack right away
+    #[arg(long, visible_alias = "pending")]
+    pub(crate) no_parent_revision: bool,
+    /// Create the branch at a tag's commit (stacked on trunk unless `--parent` is given)
+    #[arg(long, value_name = "TAG", conflicts_with_all = ["from", "insert", "below", "patch"])]
+    pub(crate) from_tag: Option<String>,
+    /// Prompt for the name, parent, message and an optional empty commit
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["name", "commit_message", "ai", "from", "insert", "below", "parent", "patch", "from_tag"]
+    )]
+    pub(crate) interactive: bool,
+    /// Show the files, branch name and message before committing and ask to confirm
+    #[arg(
+        long,
+        requires = "commit_message",
+        conflicts_with_all = ["patch", "interactive", "from_tag"]
+    )]
+    pub(crate) dry_commit: bool,
+    /// Name the branch after a forge issue (`<number>-<title>`) unless a name is given
+    #[arg(long, value_name = "NUMBER", conflicts_with_all = ["interactive", "ai"])]
+    pub(crate) from_issue: Option<u64>,
+    /// With --from-issue, pick the prefix from the issue's labels via `branch.label_prefixes`
+    #[arg(long, requires = "from_issue", conflicts_with = "prefix")]
+    pub(crate) prefix_from_labels: bool,
+    /// Create on trunk even past `branch.max_empty_trunk_children`
+    #[arg(long)]
+    pub(crate) allow_trunk_child: bool,
+    /// Create even when the name matches a protected remote branch
+    #[arg(long)]
+    pub(crate) force: bool,
+    /// Open $EDITOR after creation to write a branch description
+    #[arg(long)]
+    pub(crate) open_editor: bool,
+    /// Stack on an open PR's head branch, fetching and tracking it first
+    #[arg(
+        long,
+        value_name = "NUMBER",
+        conflicts_with_all = ["from", "from_tag", "insert", "below", "parent", "interactive"]
+    )]
+    pub(crate) base_pr: Option<u64>,
+    /// Skip the post-creation summary line
+    #[arg(short, long)]
+    pub(crate) quiet: bool,
+}
+
+impl From<CreateArgs> for commands::branch::create::CreateOptions {
+    fn from(create: CreateArgs) -> Self {
+        Self {
+            name: create.name,
+            message: create.message,
+            message_file: create.message_file,
+            from: create.from,
+            prefix: create.prefix,
+            all: create.all,
+            insert: create.insert,
+            below: create.below,
+            no_verify: create.no_verify,
+            ai: create.ai,
+            yes: create.yes,
+            parent: create.parent,
+            patch: create.patch,
+            set_upstream: create.set_upstream,
+            reviewers: create.reviewers,
+            hook: create.hook,
+            scaffold: create.scaffold,
+            scaffold_commit: create.scaffold_commit,
+            no_parent_revision: create.no_parent_revision,
+            from_tag: create.from_tag,
+            interactive: create.interactive,
+            dry_commit: create.dry_commit,
+            from_issue: create.from_issue,
+            prefix_from_labels: create.prefix_from_labels,
+            allow_trunk_child: create.allow_trunk_child,
+            force: create.force,
+            open_editor: create.open_editor,
+            base_pr: create.base_pr,
+            quiet: create.quiet,
+            request_timeout: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub(crate) enum RestackStrategyArg {
+    Merge,
+    #[default]
+    Rebase,
+}
+
+impl From<RestackStrategyArg> for crate::application::RestackStrategy {
+    fn from(value: RestackStrategyArg) -> Self {
+        match value {
+            RestackStrategyArg::Merge => crate::application::RestackStrategy::Merge,
+            RestackStrategyArg::Rebase => crate::application::RestackStrategy::Rebase,
         }
     }
 }
@@ -173,6 +417,26 @@ impl From<RestackSubmitAfter> for commands::restack::SubmitAfterRestack {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum SyncReportFormat {
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub(crate) enum TreeStyleArg {
+    Unicode,
+    Ascii,
+}
+
+impl From<TreeStyleArg> for crate::config::TreeStyle {
+    fn from(value: TreeStyleArg) -> Self {
+        match value {
+            TreeStyleArg::Unicode => crate::config::TreeStyle::Unicode,
+            TreeStyleArg::Ascii => crate::config::TreeStyle::Ascii,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, ValueEnum)]
 pub(crate) enum StandupSummaryStyle {
     Spoken,
@@ -273,9 +537,24 @@ pub(crate) enum Commands {
         /// Compact output for scripts
         #[arg(long)]
         compact: bool,
+        /// Plain one-line-per-branch list, trunk first, indented by depth
+        #[arg(long, conflicts_with_all = ["json", "compact"])]
+        flat: bool,
+        /// Hide tracked branches that have no PR (trunk is always shown)
+        #[arg(long)]
+        only_prs: bool,
         /// Suppress extra output
         #[arg(long)]
         quiet: bool,
+        /// Tree connector style (defaults to [ui] tree_style)
+        #[arg(long, value_enum)]
+        tree_style: Option<TreeStyleArg>,
+        /// Fetch review decisions and show how many open PRs are approved
+        #[arg(long)]
+        approvals: bool,
+        /// Show the last cached PR/CI state without contacting the forge
+        #[arg(long)]
+        offline: bool,
     },
 
     /// Show all stacks with PR URLs and full details
@@ -296,6 +575,12 @@ pub(crate) enum Commands {
         /// Suppress extra output
         #[arg(long)]
         quiet: bool,
+        /// Tree connector style (defaults to [ui] tree_style)
+        #[arg(long, value_enum)]
+        tree_style: Option<TreeStyleArg>,
+        /// Show the last cached PR/CI state without contacting the forge
+        #[arg(long)]
+        offline: bool,
     },
 
     /// Show all stacks with commits and PR info
@@ -313,9 +598,24 @@ pub(crate) enum Commands {
         /// Compact output for scripts
         #[arg(long)]
         compact: bool,
+        /// Hide branches with no commits ahead of their parent
+        #[arg(long)]
+        only_changed: bool,
+        /// Show the N most recent trunk commits above the stack
+        #[arg(long, value_name = "N")]
+        include_trunk_commits: Option<usize>,
+        /// List cached CI checks beneath each branch with a PR (refresh with `stax ci`)
+        #[arg(long)]
+        checks: bool,
         /// Suppress extra output
         #[arg(long)]
         quiet: bool,
+        /// Tree connector style (defaults to [ui] tree_style)
+        #[arg(long, value_enum)]
+        tree_style: Option<TreeStyleArg>,
+        /// Show the last cached PR/CI state without contacting the forge
+        #[arg(long)]
+        offline: bool,
     },
 
     /// Submit stack - push branches and create/update PRs
@@ -336,10 +636,19 @@ pub(crate) enum Commands {
         /// Merge ancestors below current, then rebase current branch
         #[arg(long, visible_alias = "ds", conflicts_with_all = ["all", "full", "remote", "queue"])]
         downstack_only: bool,
+        /// Merge only BRANCH's PR (its ancestors must already be merged), then rebase its descendants onto trunk
+        #[arg(long, value_name = "BRANCH", conflicts_with_all = ["all", "full", "downstack_only", "when_ready", "remote", "stack", "queue"])]
+        only: Option<String>,
+        /// Merge the PRs from the bottom of BRANCH's stack up to BRANCH, without checking it out
+        #[arg(long, value_name = "BRANCH", conflicts_with_all = ["all", "full", "downstack_only", "only", "when_ready", "remote", "stack", "queue"])]
+        until: Option<String>,
         /// Show merge plan without merging
         #[arg(long)]
         dry_run: bool,
-        /// Merge method: squash, merge, rebase (default: squash; rebase with --stack)
+        /// With --dry-run, print the merge plan as JSON
+        #[arg(long, requires = "dry_run", conflicts_with = "stack")]
+        json: bool,
+        /// Merge method: squash, merge, rebase (default: squash, or rebase with --stack, when the repository allows it)
         #[arg(long)]
         method: Option<String>,
         /// Keep branches after merge (don't delete)
@@ -361,15 +670,36 @@ pub(crate) enum Commands {
         #[arg(long, conflicts_with_all = ["no_wait", "remote", "queue"])]
         stack: bool,
         /// Enqueue PRs into the forge's merge queue instead of merging one-by-one.
-        /// Supported on GitHub (merge queue) and GitLab (merge trains). Not available on Gitea.
+        /// Supported on GitHub (merge queue) and GitLab (merge trains). Not available on Gitea, Bitbucket, or Azure DevOps.
         #[arg(long, conflicts_with_all = ["dry_run", "no_wait", "when_ready", "remote", "stack"])]
         queue: bool,
+        /// With --queue, put a PR dropped from the queue (e.g. by flaky queue CI) back up to N times
+        #[arg(long, value_name = "N", default_value_t = 0, requires = "queue")]
+        requeue: u32,
         /// Polling interval in seconds for --when-ready, --remote, --queue, and --stack --when-ready
         #[arg(long, default_value_t = 15, value_parser = clap::value_parser!(u64).range(1..))]
         interval: u64,
         /// Skip post-merge sync (`stax rs`)
         #[arg(long)]
         no_sync: bool,
+        /// Fast-forward local trunk to the remote after merging (for use with --no-sync)
+        #[arg(long, requires = "no_sync", conflicts_with_all = ["dry_run", "when_ready", "remote", "stack", "queue"])]
+        update_trunk: bool,
+        /// Print a timeline of merged PRs with the resulting trunk SHAs
+        #[arg(long, conflicts_with_all = ["dry_run", "when_ready", "remote", "stack", "queue"])]
+        timeline: bool,
+        /// Merge even when a local branch has changes that are not on its PR
+        #[arg(long, conflicts_with_all = ["when_ready", "remote", "stack", "queue"])]
+        force: bool,
+        /// Merge with admin rights, overriding branch protection (GitHub; asks for extra confirmation)
+        #[arg(long, conflicts_with_all = ["dry_run", "no_wait", "when_ready", "remote", "stack", "queue"])]
+        admin: bool,
+        /// Desktop notification when a PR you wait on becomes ready or fails, and when the run finishes
+        #[arg(long)]
+        notify: bool,
+        /// Use this git remote instead of the stack's recorded one (`--remote` selects remote merging)
+        #[arg(long, value_name = "NAME")]
+        git_remote: Option<String>,
         /// Skip confirmation prompt
         #[arg(short, long)]
         yes: bool,
@@ -387,9 +717,9 @@ pub(crate) enum Commands {
         /// Merge ancestors below current, then rebase current branch
         #[arg(long, visible_alias = "ds", conflicts_with = "all")]
         downstack_only: bool,
-        /// Merge method: squash, merge, rebase
-        #[arg(long, default_value = "squash")]
-        method: String,
+        /// Merge method: squash, merge, rebase (default: squash, if the repository allows it)
+        #[arg(long)]
+        method: Option<String>,
         /// Max wait time per PR in minutes (default: 30)
         #[arg(long, default_value = "30")]
         timeout: u64,
@@ -446,6 +776,25 @@ pub(crate) enum Commands {
         /// Auto-stash and auto-pop dirty target worktrees during restack operations
         #[arg(long)]
         auto_stash_pop: bool,
+        /// With --restack, preview each conflicting hunk inline on conflict
+        #[arg(long, requires = "restack")]
+        show_conflicts: bool,
+        /// With --restack, `merge` merges each parent into its branch instead of rebasing
+        #[arg(long, value_enum, default_value_t, requires = "restack")]
+        restack_strategy: RestackStrategyArg,
+        /// With --restack, rebase only branches actually behind their parent; just refresh
+        /// stale parent revisions in metadata for the rest
+        #[arg(long, requires = "restack")]
+        restack_real_only: bool,
+        /// Skip the sync if one succeeded within this window (e.g. 30m, 1h); `--force` overrides
+        #[arg(long, value_parser = parse_duration)]
+        since: Option<std::time::Duration>,
+        /// Print a machine-readable report when the sync finishes (implies --quiet)
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        report: Option<SyncReportFormat>,
+        /// Sync against this git remote instead of the stack's recorded one
+        #[arg(long, value_name = "NAME", visible_alias = "git-remote")]
+        remote: Option<String>,
     },
 
     /// List and optionally clean up local branches (merged, upstream-gone, stale)
@@ -491,9 +840,15 @@ pub(crate) enum Commands {
         /// Auto-stash and auto-pop dirty target worktrees during restack operations
         #[arg(long)]
         auto_stash_pop: bool,
+        /// On conflict, preview each conflicting hunk inline
+        #[arg(long)]
+        show_conflicts: bool,
         /// After restack, submit stack updates (`ask`, `yes`, `no`)
         #[arg(long, value_enum, default_value_t = RestackSubmitAfter::No)]
         submit_after: RestackSubmitAfter,
+        /// `merge` merges each parent into its branch instead of rebasing (keeps commit IDs)
+        #[arg(long, value_enum, default_value_t)]
+        restack_strategy: RestackStrategyArg,
     },
 
     /// Restack from the bottom and submit updates
@@ -636,6 +991,9 @@ pub(crate) enum Commands {
         /// Import token from GitHub CLI (`gh auth token`)
         #[arg(long)]
         from_gh: bool,
+        /// Save the token only for repositories on this host (e.g. a GitHub Enterprise server)
+        #[arg(long)]
+        host: Option<String>,
         #[command(subcommand)]
         command: Option<AuthSubcommand>,
     },
@@ -660,6 +1018,8 @@ pub(crate) enum Commands {
         /// Interactively set AI agent/model for a specific feature (or global default)
         #[arg(long, conflicts_with = "reset_ai")]
         set_ai: bool,
+        #[command(subcommand)]
+        command: Option<ConfigCommands>,
     },
 
     /// Initialize stax or reconfigure the repo trunk branch
@@ -694,6 +1054,9 @@ pub(crate) enum Commands {
         /// Apply safe local repairs after showing a repair plan
         #[arg(long)]
         fix: bool,
+        /// Output the stack graph health check (metadata/ref drift) as JSON
+        #[arg(long, conflicts_with = "fix")]
+        json: bool,
     },
 
     /// Manage AI agent skill files (`stax skills update` to refresh)
@@ -756,35 +1119,8 @@ pub(crate) enum Commands {
     /// Create a new branch stacked on current
     #[command(visible_aliases = ["c", "add"])]
     Create {
-        /// Name for the new branch
-        name: Option<String>,
-        /// Stage all changes (like git commit --all)
-        #[arg(short, long)]
-        all: bool,
-        /// Commit message (also used as branch name if no name provided)
-        #[arg(short, long)]
-        message: Option<String>,
-        /// Generate missing branch name and/or first commit message with AI
-        #[arg(long)]
-        ai: bool,
-        /// Accept generated AI values without prompting
-        #[arg(short, long)]
-        yes: bool,
-        /// Base branch to create from (defaults to current)
-        #[arg(long)]
-        from: Option<String>,
-        /// Override branch prefix (e.g. "feature/")
-        #[arg(long)]
-        prefix: Option<String>,
-        /// Insert between current branch and its children (reparent children)
-        #[arg(long, conflicts_with = "below")]
-        insert: bool,
-        /// Insert below current branch (reparent current and descendants)
-        #[arg(long, conflicts_with_all = ["insert", "from"])]
-        below: bool,
-        /// Skip pre-commit and commit-msg hooks
-        #[arg(long = "no-verify", short = 'n')]
-        no_verify: bool,
+        #[command(flatten)]
+        create: CreateArgs,
     },
 
     /// Open the current branch PR or list repo pull requests
@@ -892,6 +1228,9 @@ pub(crate) enum Commands {
         /// Exit watch mode as soon as any check fails
         #[arg(long, requires = "watch")]
         strict: bool,
+        /// Desktop notification when CI you were watching finishes
+        #[arg(long, requires = "watch")]
+        notify: bool,
         /// Polling interval in seconds (default: 15)
         #[arg(long, default_value = "15")]
         interval: u64,
@@ -913,6 +1252,12 @@ pub(crate) enum Commands {
         interval: Option<u64>,
     },
 
+    /// Inspect or reset the cached PR/CI state under .git/stax
+    Cache {
+        #[command(subcommand)]
+        command: commands::cache_cmd::CacheCommand,
+    },
+
     /// tmux integration: status bar string and popup viewer
     Tmux {
         #[command(subcommand)]
@@ -1195,6 +1540,9 @@ pub(crate) enum Commands {
         /// Suppress extra output
         #[arg(long)]
         quiet: bool,
+        /// Stash uncommitted changes before moving refs and restore them afterwards
+        #[arg(long)]
+        autostash: bool,
     },
 
     /// Redo the last undone stax operation
@@ -1210,6 +1558,9 @@ pub(crate) enum Commands {
         /// Suppress extra output
         #[arg(long)]
         quiet: bool,
+        /// Stash uncommitted changes before moving refs and restore them afterwards
+        #[arg(long)]
+        autostash: bool,
     },
 
     /// Manage worktrees for parallel branch development (`st wt` opens the dashboard in a TTY)
@@ -1271,33 +1622,8 @@ pub(crate) enum Commands {
     // Hidden top-level shortcuts for convenience
     #[command(hide = true)]
     Bc {
-        name: Option<String>,
-        /// Stage all changes (like git commit --all)
-        #[arg(short, long)]
-        all: bool,
-        #[arg(short, long)]
-        message: Option<String>,
-        /// Generate missing branch name and/or first commit message with AI
-        #[arg(long)]
-        ai: bool,
-        /// Accept generated AI values without prompting
-        #[arg(short, long)]
-        yes: bool,
-        /// Base branch to create from (defaults to current)
-        #[arg(long)]
-        from: Option<String>,
-        /// Override branch prefix (e.g. "feature/")
-        #[arg(long)]
-        prefix: Option<String>,
-        /// Insert between current branch and its children (reparent children)
-        #[arg(long, conflicts_with = "below")]
-        insert: bool,
-        /// Insert below current branch (reparent current and descendants)
-        #[arg(long, conflicts_with_all = ["insert", "from"])]
-        below: bool,
-        /// Skip pre-commit and commit-msg hooks
-        #[arg(long = "no-verify", short = 'n')]
-        no_verify: bool,
+        #[command(flatten)]
+        create: CreateArgs,
     },
     #[command(hide = true)]
     Bu {
@@ -1387,8 +1713,12 @@ pub(crate) enum Commands {
         quiet: bool,
         #[arg(long)]
         auto_stash_pop: bool,
+        #[arg(long)]
+        show_conflicts: bool,
         #[arg(long, value_enum, default_value_t = RestackSubmitAfter::No)]
         submit_after: RestackSubmitAfter,
+        #[arg(long, value_enum, default_value_t)]
+        restack_strategy: RestackStrategyArg,
     },
 }
 
@@ -1396,6 +1726,12 @@ pub(crate) enum Commands {
 pub(crate) enum AuthSubcommand {
     /// Show which auth source is currently active
     Status,
+    /// Sign in through the browser with the GitHub OAuth device flow
+    Login {
+        /// GitHub host to sign in to (default: auth.gh_hostname or github.com)
+        #[arg(long)]
+        hostname: Option<String>,
+    },
 }
 
 #[derive(Subcommand, Clone)]
@@ -1437,9 +1773,15 @@ pub(crate) enum StackCommands {
         /// Auto-stash and auto-pop dirty target worktrees during restack operations
         #[arg(long)]
         auto_stash_pop: bool,
+        /// On conflict, preview each conflicting hunk inline
+        #[arg(long)]
+        show_conflicts: bool,
         /// After restack, submit stack updates (`ask`, `yes`, `no`)
         #[arg(long, value_enum, default_value_t = RestackSubmitAfter::No)]
         submit_after: RestackSubmitAfter,
+        /// `merge` merges each parent into its branch instead of rebasing (keeps commit IDs)
+        #[arg(long, value_enum, default_value_t)]
+        restack_strategy: RestackStrategyArg,
     },
 
     /// Register the current stack as a native GitHub Stack via `gh stack`
@@ -1458,35 +1800,8 @@ pub(crate) enum BranchCommands {
     /// Create a new branch stacked on current
     #[command(visible_alias = "c")]
     Create {
-        /// Name for the new branch
-        name: Option<String>,
-        /// Stage all changes (like git commit --all)
-        #[arg(short, long)]
-        all: bool,
-        /// Commit message (also used as branch name if no name provided)
-        #[arg(short, long)]
-        message: Option<String>,
-        /// Generate missing branch name and/or first commit message with AI
-        #[arg(long)]
-        ai: bool,
-        /// Accept generated AI values without prompting
-        #[arg(short, long)]
-        yes: bool,
-        /// Base branch to create from (defaults to current)
-        #[arg(long)]
-        from: Option<String>,
-        /// Override branch prefix (e.g. "feature/")
-        #[arg(long)]
-        prefix: Option<String>,
-        /// Insert between current branch and its children (reparent children)
-        #[arg(long, conflicts_with = "below")]
-        insert: bool,
-        /// Insert below current branch (reparent current and descendants)
-        #[arg(long, conflicts_with_all = ["insert", "from"])]
-        below: bool,
-        /// Skip pre-commit and commit-msg hooks
-        #[arg(long = "no-verify", short = 'n')]
-        no_verify: bool,
+        #[command(flatten)]
+        create: CreateArgs,
     },
 
     /// Checkout a branch in the stack
@@ -1528,6 +1843,27 @@ pub(crate) enum BranchCommands {
         branch: Option<String>,
     },
 
+    /// Show a tracked branch's parent, PR and description
+    Info {
+        /// Branch to show (defaults to current branch)
+        branch: Option<String>,
+        /// Output JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Write the description submit uses as the branch's PR body
+    Describe {
+        /// Branch to describe (defaults to current branch)
+        branch: Option<String>,
+        /// Description text; opens $EDITOR when omitted
+        #[arg(short, long, conflicts_with = "clear")]
+        message: Option<String>,
+        /// Remove the description
+        #[arg(long)]
+        clear: bool,
+    },
+
     /// Change the parent of a tracked branch
     Reparent {
         /// Branch to reparent (defaults to current)
@@ -1576,6 +1912,10 @@ pub(crate) enum BranchCommands {
         /// Skip confirmation prompt
         #[arg(long)]
         yes: bool,
+        /// Fold the squashed commit into the parent branch, delete this branch,
+        /// and restack its children onto the parent
+        #[arg(long)]
+        into_parent: bool,
     },
 
     /// Fold current branch into its parent
@@ -1656,6 +1996,26 @@ pub(crate) enum DownstackCommands {
     },
 }
 
+#[derive(Subcommand)]
+pub(crate) enum ConfigCommands {
+    /// Print resolved config values (all settings, or those under KEY)
+    Get {
+        /// Dotted setting name or section, e.g. `branch.prefix` or `submit`
+        key: Option<String>,
+        /// Show where each value comes from (default, global, or repo)
+        #[arg(long)]
+        effective: bool,
+        /// Output JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
+    /// Pin the trunk branch for this repo, overriding main/master detection
+    SetTrunk {
+        /// Local branch to use as trunk
+        branch: String,
+    },
+}
+
 #[derive(Subcommand)]
 pub(crate) enum SkillsCommands {
     /// List installed AI agent skill files and their version status
@@ -1770,6 +2130,63 @@ pub(crate) enum PrCommands {
     /// Open the current branch PR in the browser
     Open,
 
+    /// Fetch, track, and checkout a PR branch by number
+    Checkout {
+        /// PR number
+        number: u64,
+        /// Also fetch every PR below it (following each PR's base) and rebuild the stack locally
+        #[arg(long)]
+        stack: bool,
+        /// Reset an existing local branch to the PR head, dropping commits the PR lacks
+        #[arg(long)]
+        force: bool,
+        /// Refuse when an existing local branch differs from the PR head, even if it is only behind
+        #[arg(long, conflicts_with = "force")]
+        no_ff: bool,
+    },
+
+    /// Merge the current branch PR only (no stack orchestration)
+    Merge {
+        /// Merge method: squash, merge, rebase (default: squash, if the repository allows it)
+        #[arg(long)]
+        method: Option<String>,
+        /// Retarget child PRs to trunk without prompting
+        #[arg(short, long)]
+        yes: bool,
+        /// Suppress extra output
+        #[arg(long)]
+        quiet: bool,
+    },
+
+    /// Close the current branch PR without merging it
+    Close {
+        /// Post this comment on the PR before closing it
+        #[arg(long)]
+        comment: Option<String>,
+    },
+
+    /// Reopen the current branch PR after it was closed
+    Reopen,
+
+    /// Mark the current (or named) branch's draft PR as ready for review
+    Ready {
+        /// Branch to operate on (defaults to current)
+        #[arg(conflicts_with = "stack")]
+        branch: Option<String>,
+        /// Mark every draft PR in the current stack as ready
+        #[arg(long, conflicts_with = "branch")]
+        stack: bool,
+    },
+
+    /// Show a PR's title, state, branches, reviewers, CI and description
+    View {
+        /// PR number (defaults to the current branch's PR)
+        number: Option<u64>,
+        /// Output JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Print or edit the current branch PR description
     Body {
         /// Open the PR description in $EDITOR and update it on save
@@ -1847,9 +2264,10 @@ pub(crate) enum CommandPolicy {
 
 #[cfg(test)]
 mod tests {
-    use super::{Cli, Commands, GuiArgs};
+    use super::{Cli, Commands, GuiArgs, parse_duration};
     use clap::Parser;
     use std::path::Path;
+    use std::time::Duration;
 
     fn parse_cli(args: &[&str]) -> Cli {
         let args: Vec<String> = args.iter().map(|arg| (*arg).to_string()).collect();
@@ -1877,4 +2295,16 @@ mod tests {
             Some(Commands::Gui(GuiArgs { path: None }))
         ));
     }
+
+    #[test]
+    fn parse_duration_accepts_common_units() {
+        assert_eq!(parse_duration("45"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("15m"), Ok(Duration::from_secs(900)));
+        assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+        assert_eq!(parse_duration("2d"), Ok(Duration::from_secs(172_800)));
+        assert!(parse_duration("1w").is_err());
+        assert!(parse_duration("h").is_err());
+        assert!(parse_duration("999999999999999999d").is_err());
+    }
 }
@@ -11,8 +11,29 @@ mod tests;
 use args::*;
 use interactive::*;
 
-fn run_submit(submit: SubmitOptions, scope: commands::submit::SubmitScope) -> Result<()> {
-    commands::submit::run(scope, submit.into())
+fn run_submit(
+    submit: SubmitOptions,
+    scope: commands::submit::SubmitScope,
+    request_timeout: Option<u64>,
+) -> Result<()> {
+    let remote = submit.remote.clone();
+    let dry_run = submit.dry_run;
+    let scope = submit.scope(scope);
+    let selected_remote = commands::remote_select::resolve(remote.as_deref())?;
+    commands::submit::run(
+        scope,
+        commands::submit::SubmitOptions {
+            request_timeout,
+            remote: selected_remote,
+            ..submit.into()
+        },
+    )?;
+    if let Some(remote) = remote
+        && !dry_run
+    {
+        commands::remote_select::record(&remote)?;
+    }
+    Ok(())
 }
 
 fn print_subcommand_help(name: &str) -> Result<()> {
@@ -25,6 +46,11 @@ fn print_subcommand_help(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Whether `notify.desktop` turns on desktop notifications without `--notify`.
+fn desktop_notifications_configured() -> bool {
+    crate::config::Config::load().is_ok_and(|config| config.notify.desktop)
+}
+
 pub fn run() -> Result<()> {
     let _ = rustls::crypto::ring::default_provider().install_default();
 
@@ -81,6 +107,7 @@ pub fn run() -> Result<()> {
     let _ = commands::shell_setup::refresh_installed_snippets();
 
     let (stdin_is_terminal, stdout_is_terminal) = detect_interactive_stdio();
+    let request_timeout = cli.timeout;
 
     // Bare `st`/`stax` should only enter the TUI when both sides are interactive.
     // In shells or wrappers without a usable TTY, fall back to the regular status view.
@@ -108,7 +135,12 @@ pub fn run() -> Result<()> {
                 stack: None,
                 current: false,
                 compact: false,
+                flat: false,
+                only_prs: false,
                 quiet: false,
+                tree_style: None,
+                approvals: false,
+                offline: false,
             }
         }
     };
@@ -122,14 +154,18 @@ pub fn run() -> Result<()> {
         Commands::Auth {
             token,
             from_gh,
+            host,
             command,
         } => {
-            if command.is_some() && (token.is_some() || *from_gh) {
-                anyhow::bail!("`stax auth status` cannot be combined with --token or --from-gh.");
+            if command.is_some() && (token.is_some() || *from_gh || host.is_some()) {
+                anyhow::bail!(
+                    "`stax auth status` and `stax auth login` cannot be combined with --token, --from-gh, or --host."
+                );
             }
             let result = match command {
                 Some(AuthSubcommand::Status) => commands::auth::status(),
-                None => commands::auth::run(token.clone(), *from_gh),
+                Some(AuthSubcommand::Login { hostname }) => commands::auth::login(hostname.clone()),
+                None => commands::auth::run(token.clone(), *from_gh, host.clone()),
             };
             update::show_update_notification();
             return result;
@@ -145,8 +181,19 @@ pub fn run() -> Result<()> {
             no_prompt,
             yes,
             set_ai,
+            command,
         } => {
-            let result = commands::config::run(*reset_ai, *no_prompt, *yes, *set_ai);
+            let result = match command {
+                Some(ConfigCommands::Get {
+                    key,
+                    effective,
+                    json,
+                }) => commands::config::run_get(key.as_deref(), *effective, *json),
+                Some(ConfigCommands::SetTrunk { branch }) => {
+                    commands::config::run_set_trunk(branch)
+                }
+                None => commands::config::run(*reset_ai, *no_prompt, *yes, *set_ai),
+            };
             update::show_update_notification();
             return result;
         }
@@ -155,8 +202,12 @@ pub fn run() -> Result<()> {
             update::show_update_notification();
             return result;
         }
-        Commands::Doctor { fix } => {
-            let result = commands::doctor::run(*fix);
+        Commands::Doctor { fix, json } => {
+            let result = if *json {
+                commands::doctor::run_json()
+            } else {
+                commands::doctor::run(*fix)
+            };
             update::show_update_notification();
             return result;
         }
@@ -198,28 +249,85 @@ pub fn run() -> Result<()> {
             stack,
             current,
             compact,
+            flat,
+            only_prs,
+            quiet,
+            tree_style,
+            approvals,
+            offline,
+        } => commands::status::run(
+            json,
+            stack,
+            current,
+            compact,
+            flat,
+            only_prs,
             quiet,
-        } => commands::status::run(json, stack, current, compact, quiet, false),
+            false,
+            tree_style.map(Into::into),
+            approvals,
+            offline,
+            request_timeout,
+        ),
         Commands::Ll {
             json,
             stack,
             current,
             compact,
             quiet,
-        } => commands::status::run(json, stack, current, compact, quiet, true),
+            tree_style,
+            offline,
+        } => commands::status::run(
+            json,
+            stack,
+            current,
+            compact,
+            false,
+            false,
+            quiet,
+            true,
+            tree_style.map(Into::into),
+            false,
+            offline,
+            request_timeout,
+        ),
         Commands::Log {
             json,
             stack,
             current,
             compact,
+            only_changed,
+            include_trunk_commits,
+            checks,
+            quiet,
+            tree_style,
+            offline,
+        } => commands::log::run(
+            json,
+            stack,
+            current,
+            compact,
+            only_changed,
+            include_trunk_commits,
+            checks,
             quiet,
-        } => commands::log::run(json, stack, current, compact, quiet),
-        Commands::Submit { submit } => run_submit(submit, commands::submit::SubmitScope::Stack),
+            tree_style.map(Into::into),
+            offline,
+            request_timeout,
+        ),
+        Commands::Submit { submit } => run_submit(
+            submit,
+            commands::submit::SubmitScope::Stack,
+            request_timeout,
+        ),
         Commands::Merge {
             all,
             full,
             downstack_only,
+            only,
+            until,
             dry_run,
+            json,
             method,
             no_delete,
             no_wait,
@@ -228,15 +336,34 @@ pub fn run() -> Result<()> {
             remote,
             stack,
             queue,
+            requeue,
             interval,
             no_sync,
+            update_trunk,
+            timeline,
+            force,
+            admin,
+            notify,
+            git_remote,
             yes,
             quiet,
         } => {
-            let default_method = if stack { "rebase" } else { "squash" };
-            let merge_method = method.as_deref().unwrap_or(default_method).parse()?;
+            let selected_remote = commands::remote_select::resolve(git_remote.as_deref())?;
+            let notify = notify || desktop_notifications_configured();
+            let merge_method = method.as_deref().map(str::parse).transpose()?;
             if queue {
-                commands::merge_queue::run(all, timeout, interval, no_sync, yes, quiet)
+                commands::merge_queue::run(
+                    all,
+                    timeout,
+                    interval,
+                    requeue,
+                    no_sync,
+                    yes,
+                    quiet,
+                    request_timeout,
+                    selected_remote,
+                    notify,
+                )
             } else if remote {
                 commands::merge_remote::run(
                     all,
@@ -247,6 +374,9 @@ pub fn run() -> Result<()> {
                     no_sync,
                     yes,
                     quiet,
+                    request_timeout,
+                    selected_remote,
+                    notify,
                 )
             } else if stack {
                 commands::merge_stack::run(
@@ -261,6 +391,8 @@ pub fn run() -> Result<()> {
                     no_sync,
                     yes,
                     quiet,
+                    request_timeout,
+                    selected_remote,
                 )
             } else if when_ready {
                 commands::merge_when_ready::run(
@@ -273,19 +405,32 @@ pub fn run() -> Result<()> {
                     no_sync,
                     yes,
                     quiet,
+                    request_timeout,
+                    selected_remote,
+                    notify,
                 )
             } else {
                 commands::merge::run(
                     all,
                     downstack_only,
+                    only.as_deref(),
+                    until.as_deref(),
                     dry_run,
+                    json,
                     merge_method,
                     no_delete,
                     no_wait,
                     timeout,
                     no_sync,
+                    update_trunk,
+                    timeline,
+                    force,
+                    admin,
                     yes,
                     quiet,
+                    request_timeout,
+                    selected_remote,
+                    notify,
                 )
             }
         }
@@ -300,7 +445,7 @@ pub fn run() -> Result<()> {
             yes,
             quiet,
         } => {
-            let merge_method = method.parse().unwrap_or_default();
+            let merge_method = method.map(|method| method.parse().unwrap_or_default());
             commands::merge_when_ready::run(
                 all,
                 downstack_only,
@@ -311,6 +456,9 @@ pub fn run() -> Result<()> {
                 no_sync,
                 yes,
                 quiet,
+                request_timeout,
+                None,
+                desktop_notifications_configured(),
             )
         }
         Commands::Sync {
@@ -325,20 +473,42 @@ pub fn run() -> Result<()> {
             quiet,
             verbose,
             auto_stash_pop,
-        } => commands::sync::run(
-            restack,
-            prune,
-            full,
-            !no_delete,
-            delete_upstream_gone,
-            force,
-            safe,
-            r#continue,
-            quiet,
-            verbose,
-            auto_stash_pop,
-            &[],
-        ),
+            show_conflicts,
+            restack_strategy,
+            restack_real_only,
+            since,
+            report,
+            remote,
+        } => match since.map(|since| commands::sync::skip_recent_sync(since, force, quiet)) {
+            Some(Err(err)) => Err(err),
+            Some(Ok(true)) => Ok(()),
+            _ => {
+                let remote = commands::remote_select::resolve(remote.as_deref())?;
+                let json_report = report == Some(SyncReportFormat::Json);
+                commands::sync::run(
+                    restack,
+                    prune,
+                    full,
+                    !no_delete,
+                    delete_upstream_gone,
+                    force,
+                    safe,
+                    r#continue,
+                    quiet || json_report,
+                    verbose,
+                    auto_stash_pop,
+                    &[],
+                    commands::sync::SyncOptions {
+                        show_conflicts,
+                        json_report,
+                        restack_strategy: restack_strategy.into(),
+                        restack_real_only,
+                        request_timeout,
+                        remote,
+                    },
+                )
+            }
+        },
         Commands::Sweep {
             delete,
             include_stale,
@@ -354,7 +524,9 @@ pub fn run() -> Result<()> {
             yes,
             quiet,
             auto_stash_pop,
+            show_conflicts,
             submit_after,
+            restack_strategy,
         } => commands::restack::run(
             all,
             stop_here,
@@ -363,13 +535,16 @@ pub fn run() -> Result<()> {
             yes,
             quiet,
             auto_stash_pop,
+            show_conflicts,
+            restack_strategy.into(),
             submit_after.into(),
+            request_timeout,
         ),
         Commands::Cascade {
             no_pr,
             no_submit,
             auto_stash_pop,
-        } => commands::cascade::run(no_pr, no_submit, auto_stash_pop),
+        } => commands::cascade::run(no_pr, no_submit, auto_stash_pop, request_timeout),
         Commands::Update {
             no_pr,
             no_submit,
@@ -388,6 +563,7 @@ pub fn run() -> Result<()> {
             yes,
             no_prompt,
             auto_stash_pop,
+            request_timeout,
         ),
         Commands::Checkout {
             branch,
@@ -396,7 +572,15 @@ pub fn run() -> Result<()> {
             parent,
             child,
             shell_output,
-        } => commands::checkout::run(branch, pr, trunk, parent, child, shell_output),
+        } => commands::checkout::run(
+            branch,
+            pr,
+            trunk,
+            parent,
+            child,
+            shell_output,
+            request_timeout,
+        ),
         Commands::Get {
             branch,
             parent,
@@ -415,6 +599,9 @@ pub fn run() -> Result<()> {
             remote_upstack,
             no_restack,
             unfrozen,
+            pr_stack: false,
+            existing: commands::get::ExistingBranch::Update,
+            request_timeout,
         }),
         Commands::Continue => commands::continue_cmd::run_and_resume_restack(),
         Commands::Resolve {
@@ -442,7 +629,7 @@ pub fn run() -> Result<()> {
             if let Some(name) = branch {
                 commands::set_trunk::run(&name)
             } else {
-                commands::checkout::run(None, None, true, false, None, false)
+                commands::checkout::run(None, None, true, false, None, false, request_timeout)
             }
         }
         Commands::Up { count } => commands::navigate::up(count),
@@ -451,23 +638,37 @@ pub fn run() -> Result<()> {
         Commands::Bottom => commands::navigate::bottom(),
         Commands::Prev => commands::navigate::prev(),
         Commands::Next => commands::navigate::next(),
-        Commands::Create {
-            name,
-            all,
-            message,
-            ai,
-            yes,
-            from,
-            prefix,
-            insert,
-            below,
-            no_verify,
-        } => commands::branch::create::run(
-            name, message, from, prefix, all, insert, below, no_verify, ai, yes,
-        ),
+        Commands::Create { create } => {
+            commands::branch::create::run(commands::branch::create::CreateOptions {
+                request_timeout,
+                ..create.into()
+            })
+        }
         Commands::Pr { command } => match command.unwrap_or(PrCommands::Open) {
             PrCommands::Open => commands::pr::run_open(),
-            PrCommands::Body { edit } => commands::pr::run_body(edit),
+            PrCommands::View { number, json } => {
+                commands::pr::run_view(number, json, request_timeout)
+            }
+            PrCommands::Body { edit } => commands::pr::run_body(edit, request_timeout),
+            PrCommands::Close { comment } => {
+                commands::pr::run_close(comment.as_deref(), request_timeout)
+            }
+            PrCommands::Reopen => commands::pr::run_reopen(request_timeout),
+            PrCommands::Ready { branch, stack } => {
+                commands::draft::run(branch, stack, false, request_timeout)
+            }
+            PrCommands::Checkout {
+                number,
+                stack,
+                force,
+                no_ff,
+            } => commands::pr::run_checkout(number, stack, force, no_ff, request_timeout),
+            PrCommands::Merge { method, yes, quiet } => commands::pr::run_merge(
+                method.as_deref().map(str::parse).transpose()?,
+                yes,
+                quiet,
+                request_timeout,
+            ),
             PrCommands::List {
                 limit,
                 json,
@@ -476,7 +677,16 @@ pub fn run() -> Result<()> {
                 current,
                 stack,
                 plain,
-            } => commands::pr::run_list(limit, json, ready, all, current, stack, plain),
+            } => commands::pr::run_list(
+                limit,
+                json,
+                ready,
+                all,
+                current,
+                stack,
+                plain,
+                request_timeout,
+            ),
         },
         Commands::Ready {
             all,
@@ -488,20 +698,27 @@ pub fn run() -> Result<()> {
             commands::ready::ReadyScopeMode::from_flags(all, current, stack),
             json,
             plain,
+            request_timeout,
         ),
         Commands::Issue { command } => match command {
-            Some(IssueCommands::List { limit, json }) => commands::issue::run_list(limit, json),
+            Some(IssueCommands::List { limit, json }) => {
+                commands::issue::run_list(limit, json, request_timeout)
+            }
             None => print_subcommand_help("issue"),
         },
         Commands::Open => commands::open::run(),
-        Commands::Draft { branch, stack } => commands::draft::run(branch, stack, true),
-        Commands::Undraft { branch, stack } => commands::draft::run(branch, stack, false),
+        Commands::Draft { branch, stack } => {
+            commands::draft::run(branch, stack, true, request_timeout)
+        }
+        Commands::Undraft { branch, stack } => {
+            commands::draft::run(branch, stack, false, request_timeout)
+        }
         Commands::Comments {
             plain,
             stack,
             all,
             json,
-        } => commands::comments::run(plain, stack, all, json),
+        } => commands::comments::run(plain, stack, all, json, request_timeout),
         Commands::Ci {
             all,
             stack,
@@ -511,6 +728,7 @@ pub fn run() -> Result<()> {
             alert,
             no_alert,
             strict,
+            notify,
             interval,
             verbose,
             oneline,
@@ -526,11 +744,16 @@ pub fn run() -> Result<()> {
             }),
             no_alert,
             strict,
+            notify,
             interval,
             verbose,
             oneline,
+            request_timeout,
         ),
-        Commands::Watch { current, interval } => commands::watch::run(current, interval),
+        Commands::Watch { current, interval } => {
+            commands::watch::run(current, interval, request_timeout)
+        }
+        Commands::Cache { command } => commands::cache_cmd::run(command),
         Commands::Tmux { command } => commands::tmux::run(command),
         Commands::Split {
             hunk,
@@ -544,7 +767,7 @@ pub fn run() -> Result<()> {
             } else {
                 commands::copy::CopyTarget::Branch
             };
-            commands::copy::run(target)
+            commands::copy::run(target, request_timeout)
         }
         Commands::Detach { branch, yes } => commands::detach::run(branch, yes),
         Commands::Fold { keep, yes } => commands::branch::fold::run(keep, yes),
@@ -592,6 +815,7 @@ pub fn run() -> Result<()> {
             model,
             plain_text,
             style.map(Into::into).unwrap_or_default(),
+            request_timeout,
         ),
         Commands::Generate {
             pr_body,
@@ -613,6 +837,7 @@ pub fn run() -> Result<()> {
             model,
             template,
             no_template,
+            request_timeout,
         ),
         Commands::Changelog {
             from,
@@ -633,28 +858,22 @@ pub fn run() -> Result<()> {
             yes,
             no_push,
             quiet,
-        } => commands::undo::run(op_id, yes, no_push, quiet),
+            autostash,
+        } => commands::undo::run(op_id, yes, no_push, quiet, autostash),
         Commands::Redo {
             op_id,
             yes,
             no_push,
             quiet,
-        } => commands::redo::run(op_id, yes, no_push, quiet),
+            autostash,
+        } => commands::redo::run(op_id, yes, no_push, quiet, autostash),
         Commands::Branch(cmd) => match cmd {
-            BranchCommands::Create {
-                name,
-                all,
-                message,
-                ai,
-                yes,
-                from,
-                prefix,
-                insert,
-                below,
-                no_verify,
-            } => commands::branch::create::run(
-                name, message, from, prefix, all, insert, below, no_verify, ai, yes,
-            ),
+            BranchCommands::Create { create } => {
+                commands::branch::create::run(commands::branch::create::CreateOptions {
+                    request_timeout,
+                    ..create.into()
+                })
+            }
             BranchCommands::Checkout {
                 branch,
                 pr,
@@ -662,11 +881,25 @@ pub fn run() -> Result<()> {
                 parent,
                 child,
                 shell_output,
-            } => commands::checkout::run(branch, pr, trunk, parent, child, shell_output),
+            } => commands::checkout::run(
+                branch,
+                pr,
+                trunk,
+                parent,
+                child,
+                shell_output,
+                request_timeout,
+            ),
             BranchCommands::Track { parent, all_prs } => {
-                commands::branch::track::run(parent, all_prs)
+                commands::branch::track::run(parent, all_prs, request_timeout)
             }
             BranchCommands::Untrack { branch } => commands::branch::untrack::run(branch),
+            BranchCommands::Info { branch, json } => commands::branch::info::run(branch, json),
+            BranchCommands::Describe {
+                branch,
+                message,
+                clear,
+            } => commands::branch::describe::run(branch, message, clear),
             BranchCommands::Reparent {
                 branch,
                 parent,
@@ -681,15 +914,21 @@ pub fn run() -> Result<()> {
             BranchCommands::Delete { branch, force } => {
                 commands::branch::delete::run(branch, force)
             }
-            BranchCommands::Squash { message, yes } => commands::branch::squash::run(message, yes),
+            BranchCommands::Squash {
+                message,
+                yes,
+                into_parent,
+            } => commands::branch::squash::run(message, yes, into_parent),
             BranchCommands::Fold { keep, yes } => commands::branch::fold::run(keep, yes),
             BranchCommands::Up { count } => commands::navigate::up(count),
             BranchCommands::Down { count } => commands::navigate::down(count),
             BranchCommands::Top => commands::navigate::top(),
             BranchCommands::Bottom => commands::navigate::bottom(),
-            BranchCommands::Submit { submit } => {
-                run_submit(submit, commands::submit::SubmitScope::Branch)
-            }
+            BranchCommands::Submit { submit } => run_submit(
+                submit,
+                commands::submit::SubmitScope::Branch,
+                request_timeout,
+            ),
         },
         Commands::Upstack(cmd) => match cmd {
             UpstackCommands::Restack { auto_stash_pop } => {
@@ -700,9 +939,11 @@ pub fn run() -> Result<()> {
                 restack: _,
                 auto_stash_pop,
             } => commands::upstack::onto::run(target, auto_stash_pop),
-            UpstackCommands::Submit { submit } => {
-                run_submit(submit, commands::submit::SubmitScope::Upstack)
-            }
+            UpstackCommands::Submit { submit } => run_submit(
+                submit,
+                commands::submit::SubmitScope::Upstack,
+                request_timeout,
+            ),
         },
         Commands::Move {
             target,
@@ -710,17 +951,32 @@ pub fn run() -> Result<()> {
             auto_stash_pop,
         } => commands::upstack::onto::run(target, auto_stash_pop),
         Commands::Downstack(cmd) => match cmd {
-            DownstackCommands::Get => {
-                commands::status::run(false, None, false, false, false, false)
-            }
-            DownstackCommands::Submit { submit } => {
-                run_submit(submit, commands::submit::SubmitScope::Downstack)
-            }
+            DownstackCommands::Get => commands::status::run(
+                false,
+                None,
+                false,
+                false,
+                false,
+                false,
+                false,
+                false,
+                None,
+                false,
+                false,
+                request_timeout,
+            ),
+            DownstackCommands::Submit { submit } => run_submit(
+                submit,
+                commands::submit::SubmitScope::Downstack,
+                request_timeout,
+            ),
         },
         Commands::Stack(cmd) => match cmd {
-            StackCommands::Submit { submit } => {
-                run_submit(submit, commands::submit::SubmitScope::Stack)
-            }
+            StackCommands::Submit { submit } => run_submit(
+                submit,
+                commands::submit::SubmitScope::Stack,
+                request_timeout,
+            ),
             StackCommands::Restack {
                 all,
                 stop_here,
@@ -729,7 +985,9 @@ pub fn run() -> Result<()> {
                 yes,
                 quiet,
                 auto_stash_pop,
+                show_conflicts,
                 submit_after,
+                restack_strategy,
             } => commands::restack::run(
                 all,
                 stop_here,
@@ -738,29 +996,30 @@ pub fn run() -> Result<()> {
                 yes,
                 quiet,
                 auto_stash_pop,
+                show_conflicts,
+                restack_strategy.into(),
                 submit_after.into(),
+                request_timeout,
             ),
-            StackCommands::Link => commands::stack_cmd::run_link(),
-            StackCommands::Unlink { stack_number } => commands::stack_cmd::run_unlink(stack_number),
+            StackCommands::Link => commands::stack_cmd::run_link(request_timeout),
+            StackCommands::Unlink { stack_number } => {
+                commands::stack_cmd::run_unlink(stack_number, request_timeout)
+            }
         },
         // Hidden shortcuts
-        Commands::Bc {
-            name,
-            all,
-            message,
-            ai,
-            yes,
-            from,
-            prefix,
-            insert,
-            below,
-            no_verify,
-        } => commands::branch::create::run(
-            name, message, from, prefix, all, insert, below, no_verify, ai, yes,
-        ),
+        Commands::Bc { create } => {
+            commands::branch::create::run(commands::branch::create::CreateOptions {
+                request_timeout,
+                ..create.into()
+            })
+        }
         Commands::Bu { count } => commands::navigate::up(count),
         Commands::Bd { count } => commands::navigate::down(count),
-        Commands::Bs { submit } => run_submit(submit, commands::submit::SubmitScope::Branch),
+        Commands::Bs { submit } => run_submit(
+            submit,
+            commands::submit::SubmitScope::Branch,
+            request_timeout,
+        ),
         Commands::Sr {
             all,
             stop_here,
@@ -769,7 +1028,9 @@ pub fn run() -> Result<()> {
             yes,
             quiet,
             auto_stash_pop,
+            show_conflicts,
             submit_after,
+            restack_strategy,
         } => commands::restack::run(
             all,
             stop_here,
@@ -778,7 +1039,10 @@ pub fn run() -> Result<()> {
             yes,
             quiet,
             auto_stash_pop,
+            show_conflicts,
+            restack_strategy.into(),
             submit_after.into(),
+            request_timeout,
         ),
         Commands::Worktree { command } => match command {
             None => {
@@ -1,6 +1,6 @@
 use crate::cli::args::{
-    BranchCommands, Cli, CliSubcommand, CommandPolicy, Commands, RestackSubmitAfter, StackCommands,
-    WorktreeCommands,
+    AuthSubcommand, BranchCommands, Cli, CliSubcommand, CommandPolicy, Commands, PrCommands,
+    RestackSubmitAfter, StackCommands, WorktreeCommands,
 };
 use crate::cli::interactive::{
     InteractiveTerminalCheck, check_interactive_terminal_with_probe, detect_interactive_stdio,
@@ -210,6 +210,28 @@ fn restack_parses_stop_here_flag() {
     ));
 }
 
+#[test]
+fn restack_parses_show_conflicts_and_sync_requires_restack() {
+    let cli = parse_cli(&["stax", "restack", "--show-conflicts"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Restack {
+            show_conflicts: true,
+            ..
+        })
+    ));
+
+    let cli = parse_cli(&["stax", "sync", "--restack", "--show-conflicts"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Sync {
+            show_conflicts: true,
+            ..
+        })
+    ));
+    assert!(try_parse_cli(&["stax", "sync", "--show-conflicts"]).is_err());
+}
+
 #[test]
 fn stack_restack_via_two_tokens() {
     let cli = parse_cli(&["stax", "s", "r"]);
@@ -261,16 +283,51 @@ fn submit_ai_flags_parse_for_full_title_and_body_generation() {
     ));
 }
 
+#[test]
+fn submit_auto_merge_defaults_to_squash_and_accepts_a_method() {
+    let cli = parse_cli(&["stax", "submit", "--auto-merge"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Submit { submit }) if submit.auto_merge.as_deref() == Some("squash")
+    ));
+
+    let cli = parse_cli(&["stax", "submit", "--auto-merge=rebase"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Submit { submit }) if submit.auto_merge.as_deref() == Some("rebase")
+    ));
+
+    assert!(try_parse_cli(&["stax", "submit", "--auto-merge=fast"]).is_err());
+    assert!(try_parse_cli(&["stax", "submit", "--auto-merge", "--no-pr"]).is_err());
+}
+
+#[test]
+fn ready_flags_parse_for_submit_and_pr() {
+    let cli = parse_cli(&["stax", "submit", "--ready"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Submit { submit }) if submit.publish && !submit.draft
+    ));
+    assert!(try_parse_cli(&["stax", "submit", "--ready", "--draft"]).is_err());
+
+    let cli = parse_cli(&["stax", "pr", "ready", "--stack"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Pr {
+            command: Some(PrCommands::Ready {
+                branch: None,
+                stack: true
+            })
+        })
+    ));
+}
+
 #[test]
 fn create_ai_flags_parse_for_generated_branch_details() {
     let cli = parse_cli(&["stax", "create", "--ai", "--yes"]);
     assert!(matches!(
         cli.command,
-        Some(Commands::Create {
-            ai: true,
-            yes: true,
-            ..
-        })
+        Some(Commands::Create { ref create }) if create.ai && create.yes
     ));
 }
 
@@ -279,11 +336,8 @@ fn create_add_alias_parses_as_create_command() {
     let cli = parse_cli(&["stax", "add", "feature-alias", "--below"]);
     assert!(matches!(
         cli.command,
-        Some(Commands::Create {
-            name: Some(ref name),
-            below: true,
-            ..
-        }) if name == "feature-alias"
+        Some(Commands::Create { ref create })
+            if create.name.as_deref() == Some("feature-alias") && create.below
     ));
 }
 
@@ -292,11 +346,8 @@ fn branch_create_ai_flags_parse() {
     let cli = parse_cli(&["stax", "branch", "create", "--ai", "-a"]);
     assert!(matches!(
         cli.command,
-        Some(Commands::Branch(BranchCommands::Create {
-            ai: true,
-            all: true,
-            ..
-        }))
+        Some(Commands::Branch(BranchCommands::Create { ref create }))
+            if create.ai && create.all
     ));
 }
 
@@ -305,12 +356,24 @@ fn hidden_bc_ai_flags_parse() {
     let cli = parse_cli(&["stax", "bc", "--ai", "--yes"]);
     assert!(matches!(
         cli.command,
-        Some(Commands::Bc {
-            ai: true,
-            yes: true,
-            ..
-        })
+        Some(Commands::Bc { ref create }) if create.ai && create.yes
+    ));
+}
+
+#[test]
+fn bc_parent_flag_parses_alone_and_with_from_revision() {
+    let cli = parse_cli(&["stax", "bc", "detached-work", "--parent", "main"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Bc { ref create }) if create.parent.as_deref() == Some("main")
+    ));
+    let cli = parse_cli(&["stax", "bc", "x", "--parent", "main", "--from", "HEAD~1"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Bc { ref create })
+            if create.parent.as_deref() == Some("main") && create.from.as_deref() == Some("HEAD~1")
     ));
+    assert!(try_parse_cli(&["stax", "bc", "x", "--parent", "main", "--insert"]).is_err());
 }
 
 #[test]
@@ -440,6 +503,54 @@ fn merge_queue_rejects_zero_interval() {
     assert!(try_parse_cli(&["stax", "merge", "--queue", "--interval", "0"]).is_err());
 }
 
+#[test]
+fn merge_notify_flag_parses_with_when_ready() {
+    let cli = parse_cli(&["stax", "merge", "--when-ready", "--notify"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Merge {
+            when_ready: true,
+            notify: true,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn merge_requeue_requires_queue() {
+    let cli = parse_cli(&["stax", "merge", "--queue", "--requeue", "2"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Merge {
+            queue: true,
+            requeue: 2,
+            ..
+        })
+    ));
+    assert!(try_parse_cli(&["stax", "merge", "--requeue", "1"]).is_err());
+}
+
+#[test]
+fn merge_until_takes_a_branch_and_rejects_other_scopes() {
+    let cli = parse_cli(&["stax", "merge", "--until", "feature-b"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Merge { until: Some(ref branch), .. }) if branch == "feature-b"
+    ));
+    assert!(try_parse_cli(&["stax", "merge", "--until", "feature-b", "--all"]).is_err());
+    assert!(
+        try_parse_cli(&[
+            "stax",
+            "merge",
+            "--until",
+            "feature-b",
+            "--only",
+            "feature-a"
+        ])
+        .is_err()
+    );
+}
+
 #[test]
 fn merge_stack_when_ready_rejects_zero_interval() {
     assert!(
@@ -463,3 +574,36 @@ fn merge_accepts_interval_of_one() {
         Some(Commands::Merge { interval: 1, .. })
     ));
 }
+
+#[test]
+fn remote_selection_flags_parse() {
+    let cli = parse_cli(&["stax", "submit", "--remote", "work"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Submit { submit }) if submit.remote.as_deref() == Some("work")
+    ));
+
+    let cli = parse_cli(&["stax", "sync", "--git-remote", "work"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Sync { remote: Some(ref remote), .. }) if remote == "work"
+    ));
+
+    let cli = parse_cli(&["stax", "merge", "--remote", "--git-remote", "work"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Merge { remote: true, git_remote: Some(ref remote), .. }) if remote == "work"
+    ));
+}
+
+#[test]
+fn auth_login_parses_hostname() {
+    let cli = parse_cli(&["stax", "auth", "login", "--hostname", "github.company.com"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Auth {
+            command: Some(AuthSubcommand::Login { hostname: Some(ref host) }),
+            ..
+        }) if host == "github.company.com"
+    ));
+}
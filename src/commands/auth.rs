@@ -1,23 +1,31 @@
-use crate::config::Config;
-use anyhow::Result;
+use crate::config::{Config, CredentialStorage, GitHubAuthSource, StoredToken};
+use crate::git::GitRepo;
+use crate::github::app_auth::GitHubAppCredentials;
+use crate::github::device_flow::{DEFAULT_SCOPES, DeviceFlow, DeviceFlowEndpoints, PollOutcome};
+use crate::github::token_info::fetch_token_info;
+use crate::remote::{ForgeType, RemoteInfo};
+use anyhow::{Context, Result, bail};
 use colored::Colorize;
 use dialoguer::{Password, theme::ColorfulTheme};
+use std::time::{Duration, Instant};
 
-pub fn run(token: Option<String>, from_gh: bool) -> Result<()> {
+/// Save a token; with `host` it is only used for repositories on that host.
+pub fn run(token: Option<String>, from_gh: bool, host: Option<String>) -> Result<()> {
+    let host = host.as_deref();
     let token = if from_gh {
-        Config::gh_cli_token_for_import()?
+        Config::gh_cli_token_for_import(host)?
     } else {
         match token {
             Some(t) => t,
             None => {
                 println!(
-                    "Enter a personal access token for your forge (GitHub, GitLab, or Gitea)."
+                    "Enter a personal access token for your forge (GitHub, GitLab, Gitea, Bitbucket, or Azure DevOps)."
                 );
                 println!(
                     "It is stored once and reused for whichever host your `origin` remote uses."
                 );
                 println!(
-                    "Examples: {} (GitHub), or your GitLab/Gitea instance token settings.",
+                    "Examples: {} (GitHub), or your GitLab/Gitea/Bitbucket/Azure DevOps token settings.",
                     "https://github.com/settings/tokens".cyan()
                 );
                 println!(
@@ -32,20 +40,16 @@ pub fn run(token: Option<String>, from_gh: bool) -> Result<()> {
         }
     };
 
-    Config::set_github_token(&token)?;
+    let stored = Config::set_github_token(&token, host)?;
 
-    println!("{}", "✓ API token saved!".green());
+    match host {
+        Some(host) => println!("{} {}", "✓ API token saved for".green(), host.cyan()),
+        None => println!("{}", "✓ API token saved!".green()),
+    }
     if from_gh {
         println!("{}", "Imported from `gh auth token`.".dimmed());
     }
-    println!(
-        "Credentials stored at: {}",
-        Config::dir()?
-            .join(".credentials")
-            .display()
-            .to_string()
-            .dimmed()
-    );
+    print_stored_location(stored, host)?;
     println!();
     println!(
         "{}",
@@ -55,16 +59,106 @@ pub fn run(token: Option<String>, from_gh: bool) -> Result<()> {
     Ok(())
 }
 
+/// Sign in with the GitHub OAuth device flow and save the resulting token.
+pub fn login(hostname: Option<String>) -> Result<()> {
+    let config = Config::load()?;
+    let client_id = config.github_oauth_client_id().context(
+        "No OAuth client id configured for `stax auth login`.\n\
+         Set auth.oauth_client_id in ~/.config/stax/config.toml (or STAX_GITHUB_OAUTH_CLIENT_ID)\n\
+         to an OAuth app with device flow enabled, or use `stax auth --token`.",
+    )?;
+    // An explicit --hostname keys the saved token to that host.
+    let keyed_host = hostname.clone();
+    let host = hostname
+        .or_else(|| config.auth.gh_hostname.clone())
+        .unwrap_or_else(|| "github.com".to_string());
+
+    let flow = DeviceFlow::new(DeviceFlowEndpoints::for_host(&host), &client_id)?;
+    let runtime = tokio::runtime::Runtime::new()?;
+    let code = runtime.block_on(flow.request_code(DEFAULT_SCOPES))?;
+
+    println!(
+        "Open {} and enter the code {}",
+        code.verification_uri.cyan(),
+        code.user_code.bold()
+    );
+    println!("{}", "Waiting for authorization...".dimmed());
+
+    let deadline = Instant::now() + Duration::from_secs(code.expires_in);
+    let mut interval = code.interval.max(1);
+    let token = loop {
+        std::thread::sleep(Duration::from_secs(interval));
+        if Instant::now() >= deadline {
+            bail!("The device code expired. Run `stax auth login` again.");
+        }
+        match runtime.block_on(flow.poll_token(&code.device_code))? {
+            PollOutcome::Token(token) => break token,
+            PollOutcome::Pending => {}
+            PollOutcome::SlowDown(next) => interval = next.max(interval + 5),
+        }
+    };
+
+    let stored = Config::set_github_token(&token, keyed_host.as_deref())?;
+    let login = runtime.block_on(flow.authenticated_login(&token));
+
+    match login {
+        Ok(login) => println!(
+            "{} {}",
+            "✓ Logged in to GitHub as".green(),
+            login.cyan().bold()
+        ),
+        Err(_) => println!("{}", "✓ Logged in to GitHub".green()),
+    }
+    print_stored_location(stored, keyed_host.as_deref())
+}
+
+fn print_stored_location(stored: StoredToken, host: Option<&str>) -> Result<()> {
+    if stored.source == GitHubAuthSource::Keyring {
+        println!("Credentials stored in: {}", "OS keyring".dimmed());
+        return Ok(());
+    }
+    if let Some(error) = stored.keyring_error {
+        println!(
+            "{}",
+            format!(
+                "⚠ OS keyring unavailable ({:#}); fell back to the credentials file.",
+                error
+            )
+            .yellow()
+        );
+    }
+    println!(
+        "Credentials stored at: {}",
+        Config::credentials_path(host)?
+            .display()
+            .to_string()
+            .dimmed()
+    );
+    Ok(())
+}
+
 pub fn status() -> Result<()> {
-    let status = Config::github_auth_status();
+    let remote = current_repo_remote();
+    let host = remote.as_ref().map(|remote| remote.host.clone());
+    let status = Config::github_auth_status(host.as_deref());
 
     println!("{}", "Auth status".bold());
     println!(
         "{}",
-        "(The saved credentials-file token is reused for GitHub, GitLab, and Gitea API calls.)"
+        "(The saved credentials-file token is reused for every supported forge's API calls.)"
             .dimmed()
     );
-    if let Some(source) = status.active_source {
+    let app = GitHubAppCredentials::from_env();
+    if let Ok(Some(app)) = &app {
+        println!(
+            "{} {} {}",
+            "✓ Active source:".green(),
+            GitHubAuthSource::GitHubApp.display_name().cyan(),
+            format!("(app {}, installation {})", app.app_id, app.installation_id).dimmed()
+        );
+    } else if let Err(err) = &app {
+        println!("{} {:#}", "✗ GitHub App misconfigured:".red(), err);
+    } else if let Some(source) = status.active_source {
         println!(
             "{} {}",
             "✓ Active source:".green(),
@@ -73,11 +167,27 @@ pub fn status() -> Result<()> {
     } else {
         println!("{}", "⚠ No GitHub auth source resolved.".yellow());
     }
+    if let Some(host) = status.host.as_deref() {
+        println!("{} {}", "Repository host:".dimmed(), host);
+    }
+    if !status.saved_hosts.is_empty() {
+        println!(
+            "{} {}",
+            "Host-specific tokens:".dimmed(),
+            status.saved_hosts.join(", ")
+        );
+    }
     println!();
     println!("{}", "Resolution order:".bold());
     print_source_line("1. STAX_GITHUB_TOKEN", status.stax_env_available, true, "");
     print_source_line(
-        "2. credentials file (~/.config/stax/.credentials)",
+        "2. OS keyring",
+        status.keyring_available,
+        status.storage == CredentialStorage::Keyring,
+        " (enable with [auth].storage = \"keyring\")",
+    );
+    print_source_line(
+        "3. credentials file (~/.config/stax/.credentials)",
         status.credentials_file_available,
         true,
         "",
@@ -89,29 +199,108 @@ pub fn status() -> Result<()> {
         String::new()
     };
     print_source_line(
-        "3. gh auth token",
+        "4. gh auth token",
         status.gh_cli_available,
         status.use_gh_cli,
         gh_note.as_str(),
     );
     print_source_line(
-        "4. GITHUB_TOKEN",
+        "5. GITHUB_TOKEN",
         status.github_env_available,
         status.allow_github_token_env,
         " (disabled by default; enable with [auth].allow_github_token_env = true)",
     );
 
+    if !matches!(app, Ok(None)) {
+        return Ok(());
+    }
     if status.active_source.is_none() {
         println!();
         println!(
             "{}",
-            "Run `stax auth`, `stax auth --from-gh`, or `gh auth login`.".dimmed()
+            "Run `stax auth login`, `stax auth`, `stax auth --from-gh`, or `gh auth login`."
+                .dimmed()
         );
+        return Ok(());
+    }
+
+    match remote.as_ref() {
+        Some(remote) if remote.forge != ForgeType::GitHub => {}
+        _ => print_token_check(remote.as_ref(), host.as_deref())?,
     }
 
     Ok(())
 }
 
+/// Validate the resolved token against the GitHub API the repo would use.
+fn print_token_check(remote: Option<&RemoteInfo>, host: Option<&str>) -> Result<()> {
+    let Some((_, token)) = Config::github_token_with_source(host) else {
+        return Ok(());
+    };
+    let api_base_url = remote
+        .and_then(|remote| remote.api_base_url.clone())
+        .unwrap_or_else(|| {
+            let host = host
+                .map(str::to_string)
+                .or_else(|| Config::load().ok().and_then(|c| c.auth.gh_hostname))
+                .unwrap_or_else(|| "github.com".to_string());
+            DeviceFlowEndpoints::for_host(&host).api_base_url
+        });
+
+    println!();
+    println!("{} {}", "Token check:".bold(), api_base_url.dimmed());
+    let runtime = tokio::runtime::Runtime::new()?;
+    let info = match runtime.block_on(fetch_token_info(&api_base_url, &token)) {
+        Ok(info) => info,
+        Err(err) => {
+            println!("  {} {}", "✗".red(), err.to_string().red());
+            return Ok(());
+        }
+    };
+
+    println!(
+        "  {} {}",
+        "✓ Authenticated as".green(),
+        info.login.cyan().bold()
+    );
+    match &info.scopes {
+        Some(scopes) if scopes.is_empty() => println!("  scopes:     {}", "none".yellow()),
+        Some(scopes) => println!("  scopes:     {}", scopes.join(", ")),
+        None => println!(
+            "  scopes:     {}",
+            "not reported (fine-grained or app token)".dimmed()
+        ),
+    }
+    if let (Some(remaining), Some(limit)) = (info.rate_limit_remaining, info.rate_limit_limit) {
+        let resets = info
+            .rate_limit_reset
+            .and_then(|reset| chrono::DateTime::from_timestamp(reset, 0))
+            .map(|reset| {
+                format!(
+                    " (resets {})",
+                    reset.with_timezone(&chrono::Local).format("%H:%M")
+                )
+            })
+            .unwrap_or_default();
+        println!("  rate limit: {}/{} remaining{}", remaining, limit, resets);
+    }
+    println!(
+        "  expires:    {}",
+        info.expires_at
+            .as_deref()
+            .unwrap_or("never (or not reported)")
+    );
+    Ok(())
+}
+
+/// The current repository's remote, so status reflects the token and API
+/// that repository would use.
+fn current_repo_remote() -> Option<RemoteInfo> {
+    let repo = GitRepo::open().ok()?;
+    let config = Config::load().ok()?;
+    RemoteInfo::from_repo(&repo, &config).ok()
+}
+
 fn print_source_line(label: &str, available: bool, enabled: bool, note: &str) {
     let availability = if available {
         "available".green()
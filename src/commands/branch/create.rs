@@ -24,17 +24,22 @@ use crate::application::{
     BranchNameContext, BranchNameError, BranchNameResult, NoopOperationReporter, OperationOutcome,
     OperationWarning, RepositorySession, format_branch_name,
 };
+use crate::commands::get;
 use crate::commands::staging::{self, ContinueLabel, StagingAction};
+use crate::commands::worktree::shared::platform_shell;
 use crate::config::Config;
 use crate::engine::{BranchMetadata, Stack};
+use crate::forge::ForgeClient;
 use crate::git::GitRepo;
+use crate::github::pr::split_reviewers;
 use crate::progress::LiveTimer;
 use crate::remote;
 use anyhow::{Context, Result, anyhow, bail};
 use colored::Colorize;
 use console::Term;
-use dialoguer::{Input, Select, theme::ColorfulTheme};
+use dialoguer::{Confirm, Input, Select, theme::ColorfulTheme};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::io::IsTerminal;
 use std::io::Write;
 use std::path::Path;
@@ -441,21 +446,480 @@ fn print_branch_name_warnings(warnings: &[OperationWarning]) {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn run(
-    name: Option<String>,
-    message: Option<String>,
-    from: Option<String>,
-    prefix: Option<String>,
-    all: bool,
-    insert: bool,
-    below: bool,
-    no_verify: bool,
-    ai: bool,
-    yes: bool,
-) -> Result<()> {
+/// Options for `stax create` / `stax branch create` / `stax bc`.
+#[derive(Debug, Default)]
+pub struct CreateOptions {
+    pub name: Option<String>,
+    pub message: Option<String>,
+    /// File holding the commit message (`--message-file`); its first line names the branch.
+    pub message_file: Option<String>,
+    pub from: Option<String>,
+    pub prefix: Option<String>,
+    pub all: bool,
+    pub insert: bool,
+    pub below: bool,
+    pub no_verify: bool,
+    pub ai: bool,
+    pub yes: bool,
+    /// Explicit parent for a branch created from a detached HEAD.
+    pub parent: Option<String>,
+    /// Patch file (or `-` for stdin) to apply and commit on the new branch.
+    pub patch: Option<String>,
+    /// `<remote>/<branch>` to record as the new branch's upstream (no push).
+    pub set_upstream: Option<String>,
+    /// Reviewers saved in metadata for `stax submit` to request on the new PR.
+    pub reviewers: Vec<String>,
+    /// Command run after creation; falls back to `branch.post_create_hook`.
+    pub hook: Option<String>,
+    /// Run `branch.scaffold_command` on the new branch.
+    pub scaffold: bool,
+    /// Stage and commit the scaffold output.
+    pub scaffold_commit: bool,
+    /// Leave `parent_branch_revision` empty so the branch starts out needing a restack.
+    pub no_parent_revision: bool,
+    /// Tag to start the branch at (parent defaults to trunk, or `parent`).
+    pub from_tag: Option<String>,
+    /// Prompt for name, parent, message and empty commit (`--interactive`).
+    pub interactive: bool,
+    /// Preview the staged files, branch and message, and confirm before committing.
+    pub dry_commit: bool,
+    /// Forge issue whose number and title name the branch.
+    pub from_issue: Option<u64>,
+    /// Map the issue's labels to a prefix through `branch.label_prefixes`.
+    pub prefix_from_labels: bool,
+    /// Skip the `branch.max_empty_trunk_children` guard.
+    pub allow_trunk_child: bool,
+    /// Create even when the name matches a protected remote branch.
+    pub force: bool,
+    /// Open `$EDITOR` after creation to record a branch description.
+    pub open_editor: bool,
+    /// Open PR whose head branch is fetched, tracked, and used as `from`.
+    pub base_pr: Option<u64>,
+    /// Skip the closing "Created <name> on <parent>" summary.
+    pub quiet: bool,
+    /// `stax --timeout` for forge requests.
+    pub request_timeout: Option<u64>,
+}
+
+pub fn run(opts: CreateOptions) -> Result<()> {
+    let quiet = opts.quiet;
+    if let Some(branch) = create_and_configure(opts)?
+        && !quiet
+    {
+        print_created_summary(&GitRepo::open()?, &branch)?;
+    }
+    Ok(())
+}
+
+/// One line naming the parent and base that inference actually picked.
+fn print_created_summary(repo: &GitRepo, branch: &str) -> Result<()> {
+    let Some(meta) = BranchMetadata::read(repo.inner(), branch)? else {
+        println!("Created {} — {}", branch.cyan(), "untracked".yellow());
+        return Ok(());
+    };
+    let revision = meta.parent_branch_revision.trim();
+    let base = if revision.is_empty() {
+        "no base recorded".to_string()
+    } else {
+        format!("base {}", &revision[..revision.len().min(7)])
+    };
+    let from_tag = meta
+        .base_tag
+        .as_deref()
+        .map(|tag| format!(" from tag {}", tag.cyan()))
+        .unwrap_or_default();
+    println!(
+        "Created {} on {}{} ({}) — {}",
+        branch.cyan(),
+        meta.parent_branch_name.cyan(),
+        from_tag,
+        base,
+        "tracked".green()
+    );
+    Ok(())
+}
+
+/// Create the branch and apply the follow-up options. `None` when a prompt
+/// was declined and nothing was created.
+fn create_and_configure(mut opts: CreateOptions) -> Result<Option<String>> {
+    if let Some(path) = opts.message_file.take() {
+        opts.message = Some(read_message_file(&path)?);
+    }
+    if let Some(number) = opts.base_pr {
+        let head = get::fetch_pr_branch(number, opts.request_timeout)?;
+        println!(
+            "Stacking on PR #{} ({})",
+            number.to_string().cyan(),
+            head.cyan()
+        );
+        opts.from = Some(head);
+    }
+    if let Some(number) = opts.from_issue {
+        prepare_issue_create(&mut opts, number)?;
+    }
+    let wizard_commit = if opts.interactive {
+        prepare_interactive_create(&mut opts)?
+    } else {
+        None
+    };
+    // --no-verify skips the configured hook; an explicit --hook always runs.
+    let hook = match &opts.hook {
+        Some(hook) => Some(hook.clone()),
+        None if opts.no_verify => None,
+        None => Config::load()?.branch.post_create_hook,
+    }
+    .filter(|hook| !hook.trim().is_empty());
+    let scaffold = if opts.scaffold {
+        let command = Config::load()?
+            .branch
+            .scaffold_command
+            .filter(|command| !command.trim().is_empty());
+        if command.is_none() {
+            bail!("--scaffold needs a generator. Set branch.scaffold_command in the stax config.");
+        }
+        command
+    } else {
+        None
+    };
+    // The editor is skipped rather than failing when nobody is there to type.
+    let open_editor = opts.open_editor && !opts.quiet && editor_terminal_available();
+    // Validate before touching anything so a typo never leaves a half-configured branch.
     let repo = GitRepo::open()?;
-    let mut config = Config::load()?;
+    let upstream = opts
+        .set_upstream
+        .as_deref()
+        .map(|upstream| parse_upstream(&repo, upstream))
+        .transpose()?;
+    let (reviewers, team_reviewers) = split_reviewers(&opts.reviewers);
+    let no_parent_revision = opts.no_parent_revision;
+    let no_verify = opts.no_verify;
+    let scaffold_commit = opts.scaffold_commit;
+    let existing_branches = repo.list_branches()?;
+
+    create(opts)?;
+
+    // Declining a confirmation prompt returns Ok without creating a branch.
+    let branch = repo.current_branch()?;
+    if existing_branches.contains(&branch) {
+        return Ok(None);
+    }
+    if let Some(commit) = wizard_commit {
+        commit_wizard_message(repo.workdir()?, &commit, no_verify)?;
+    }
+    if let Some((remote, remote_branch)) = upstream {
+        set_branch_upstream(repo.workdir()?, &branch, &remote, &remote_branch)?;
+        println!(
+            "Upstream set to '{}' (not pushed)",
+            format!("{}/{}", remote, remote_branch).cyan()
+        );
+    }
+    if !reviewers.is_empty() || !team_reviewers.is_empty() {
+        let mut meta = BranchMetadata::read(repo.inner(), &branch)?
+            .with_context(|| format!("No metadata for branch {}", branch))?;
+        let saved = reviewers
+            .iter()
+            .cloned()
+            .chain(team_reviewers.iter().map(|team| format!("@{}", team)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        meta.reviewers = reviewers;
+        meta.team_reviewers = team_reviewers;
+        meta.write(repo.inner(), &branch)?;
+        println!("Reviewers for submit: {}", saved.cyan());
+    }
+    if open_editor {
+        edit_branch_description(&repo, &branch)?;
+    }
+    if no_parent_revision {
+        let mut meta = BranchMetadata::read(repo.inner(), &branch)?
+            .with_context(|| format!("No metadata for branch {}", branch))?;
+        meta.parent_branch_revision = String::new();
+        meta.write(repo.inner(), &branch)?;
+        println!(
+            "No parent revision recorded; '{}' is marked for restack",
+            branch.cyan()
+        );
+    }
+    if let Some(command) = scaffold {
+        let generated = run_branch_command(&repo, "scaffold command", &command, &branch)?;
+        if generated && scaffold_commit {
+            commit_scaffold(repo.workdir()?, &branch, no_verify)?;
+        }
+    }
+    if let Some(hook) = hook {
+        run_branch_command(&repo, "post-create hook", &hook, &branch)?;
+    }
+    Ok(Some(branch))
+}
+
+fn editor_terminal_available() -> bool {
+    #[cfg(debug_assertions)]
+    if std::env::var_os("STAX_TEST_FORCE_INTERACTIVE_TERMINAL").is_some() {
+        return true;
+    }
+    std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+}
+
+/// Open `$EDITOR` on a description template and save the result in the
+/// branch's metadata. Best-effort like the post-create hook: problems only
+/// warn, and the branch stays created.
+fn edit_branch_description(repo: &GitRepo, branch: &str) -> Result<()> {
+    let mut meta = BranchMetadata::read(repo.inner(), branch)?
+        .with_context(|| format!("No metadata for branch {}", branch))?;
+    let template = format!(
+        "\n# Describe what '{}' is for (stacked on {}).\n\
+         # Lines starting with '#' are ignored; an empty description is not saved.\n",
+        branch, meta.parent_branch_name
+    );
+    let edited = match run_description_editor(&template) {
+        Ok(edited) => edited,
+        Err(error) => {
+            eprintln!(
+                "{}",
+                format!("Warning: no branch description saved: {:#}", error).yellow()
+            );
+            return Ok(());
+        }
+    };
+    let Some(description) = parse_branch_description(&edited) else {
+        println!("{}", "Empty description; nothing saved".dimmed());
+        return Ok(());
+    };
+    meta.description = Some(description);
+    meta.write(repo.inner(), branch)?;
+    println!("Saved description for '{}'", branch.cyan());
+    Ok(())
+}
+
+pub(super) fn run_description_editor(template: &str) -> Result<String> {
+    let editor = std::env::var("EDITOR").context("$EDITOR is not set")?;
+    if editor.trim().is_empty() {
+        bail!("$EDITOR is empty");
+    }
+
+    let mut file = tempfile::Builder::new()
+        .prefix("stax-branch-description-")
+        .suffix(".md")
+        .tempfile()
+        .context("Failed to create temporary description file")?;
+    file.write_all(template.as_bytes())
+        .context("Failed to write description template")?;
+    file.flush()
+        .context("Failed to flush temporary description file")?;
+
+    let path = file.path().to_path_buf();
+    let status = if cfg!(windows) {
+        Command::new("cmd")
+            .args(["/C", &format!("{} \"{}\"", editor, path.display())])
+            .status()
+    } else {
+        Command::new("sh")
+            .arg("-c")
+            .arg(format!("{} \"$1\"", editor))
+            .arg("stax-editor")
+            .arg(&path)
+            .status()
+    }
+    .context("Failed to launch $EDITOR")?;
+
+    if !status.success() {
+        bail!("$EDITOR exited with status {}", status);
+    }
+
+    std::fs::read_to_string(&path).context("Failed to read edited description")
+}
+
+/// Drop template comment lines and surrounding blank lines.
+pub(super) fn parse_branch_description(edited: &str) -> Option<String> {
+    let description = edited
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let description = description.trim();
+    (!description.is_empty()).then(|| description.to_string())
+}
+
+/// Run a post-create hook or scaffold generator with `STAX_BRANCH`, `STAX_PARENT`
+/// and `STAX_BASE` (the parent commit the branch starts from) set. Best-effort: a
+/// failure only warns, and the branch stays created. Returns whether it succeeded.
+fn run_branch_command(repo: &GitRepo, label: &str, command: &str, branch: &str) -> Result<bool> {
+    let meta = BranchMetadata::read(repo.inner(), branch)?;
+    let parent = meta
+        .as_ref()
+        .map(|meta| meta.parent_branch_name.clone())
+        .unwrap_or_default();
+    let base = meta
+        .map(|meta| meta.parent_branch_revision)
+        .unwrap_or_default();
+
+    let status = platform_shell(command)
+        .current_dir(repo.workdir()?)
+        .env("STAX_BRANCH", branch)
+        .env("STAX_PARENT", &parent)
+        .env("STAX_BASE", &base)
+        .stdin(Stdio::null())
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status();
+
+    match status {
+        Ok(status) if status.success() => return Ok(true),
+        Ok(status) => eprintln!(
+            "{}",
+            format!("Warning: {} exited with status {}", label, status).yellow()
+        ),
+        Err(error) => eprintln!(
+            "{}",
+            format!("Warning: could not run {}: {}", label, error).yellow()
+        ),
+    }
+    Ok(false)
+}
+
+/// Stage everything in the worktree and commit it as the branch's scaffold.
+fn commit_scaffold(workdir: &Path, branch: &str, no_verify: bool) -> Result<()> {
+    let status = Command::new("git")
+        .args(["add", "-A"])
+        .current_dir(workdir)
+        .status()
+        .context("Failed to run git add")?;
+    if !status.success() {
+        bail!("git add failed; the scaffold output was left unstaged");
+    }
+    if staging::is_staging_area_empty(workdir)? {
+        println!(
+            "{}",
+            "Scaffold command produced no changes to commit".dimmed()
+        );
+        return Ok(());
+    }
+    let message = format!("Scaffold {}", branch);
+    let mut args = vec!["commit", "--quiet"];
+    if no_verify {
+        args.push("--no-verify");
+    }
+    args.extend(["-m", message.as_str()]);
+    let status = Command::new("git")
+        .args(&args)
+        .current_dir(workdir)
+        .status()
+        .context("Failed to run git commit")?;
+    if !status.success() {
+        bail!("git commit failed; the scaffold output is staged but not committed");
+    }
+    println!("Committed scaffold output as '{}'", message.cyan());
+    Ok(())
+}
+
+fn create(opts: CreateOptions) -> Result<()> {
+    let CreateOptions {
+        name,
+        message,
+        message_file: _,
+        from,
+        prefix,
+        all,
+        insert,
+        below,
+        no_verify,
+        ai,
+        yes,
+        mut parent,
+        patch,
+        set_upstream: _,
+        reviewers: _,
+        hook: _,
+        scaffold: _,
+        scaffold_commit: _,
+        no_parent_revision: _,
+        from_tag,
+        interactive: _,
+        dry_commit,
+        from_issue: _,
+        prefix_from_labels: _,
+        allow_trunk_child,
+        force,
+        open_editor: _,
+        base_pr: _,
+        quiet: _,
+        request_timeout,
+    } = opts;
+    let repo = GitRepo::open()?;
+    let mut config = Config::load()?.with_request_timeout(request_timeout);
+    // Tags, revisions and a detached HEAD are not branches, so there is no
+    // placement to resolve; everything else is checked as for a branch parent.
+    let detached = if let Some(tag) = from_tag {
+        let tag_commit = repo
+            .rev_parse(&format!("refs/tags/{}^{{commit}}", tag))
+            .map_err(|_| anyhow!("Tag '{}' does not exist", tag))?;
+        let parent = match parent.take() {
+            Some(parent) => parent,
+            None => repo.trunk_branch()?,
+        };
+        Some((parent, tag_commit, Some(tag)))
+    } else if let Some(revision) = from.as_deref()
+        && let Some(start) = resolve_from_revision(&repo, revision)
+    {
+        let parent = match parent.take() {
+            Some(parent) => parent,
+            None => nearest_tracked_ancestor(&repo, &start)?,
+        };
+        println!(
+            "{}",
+            format!(
+                "Branching from {} ({})",
+                revision,
+                &start[..start.len().min(7)]
+            )
+            .dimmed()
+        );
+        Some((parent, start, None))
+    } else if repo.inner().head_detached().unwrap_or(false) {
+        let Some(parent) = parent.take() else {
+            bail!(
+                "HEAD is detached (not on a branch), so there is no parent to stack on.\n\
+                 Checkout a branch first (`stax checkout <branch>`), or pass `--parent <branch>` \
+                 to create the branch at the detached commit."
+            );
+        };
+        Some((parent, repo.rev_parse("HEAD")?, None))
+    } else {
+        None
+    };
+    if let Some((parent, start, base_tag)) = detached {
+        if insert || patch.is_some() || dry_commit || ai {
+            bail!(
+                "`--insert`, `--patch`, `--dry-commit` and `--ai` need a branch to create from, \
+                 not a tag, a revision or a detached HEAD"
+            );
+        }
+        if !allow_trunk_child && let Some(limit) = config.branch.max_empty_trunk_children {
+            // Starting from a tag or revision on trunk counts as creating on trunk.
+            check_trunk_child_limit(&repo, &parent, &parent, limit)?;
+        }
+        let generated_branch_name = name.is_none();
+        return run_detached(
+            &repo,
+            &config,
+            name.or_else(|| message.as_deref().map(message_subject)),
+            generated_branch_name,
+            message,
+            &parent,
+            &start,
+            base_tag.as_deref(),
+            prefix.as_deref(),
+            all,
+            no_verify,
+            force,
+        );
+    }
+    if let Some(parent) = parent {
+        bail!(
+            "`--parent` only applies to a detached HEAD or a `--from` revision; use `--from {}` to stack on another branch",
+            parent
+        );
+    }
     let current = repo.current_branch()?;
     let placement = resolve_create_placement(&repo, &current, from, insert, below)?;
     let parent_branch = placement.parent_branch;
@@ -464,8 +928,30 @@ pub fn run(
     if repo.branch_commit(&parent_branch).is_err() {
         anyhow::bail!("Branch '{}' does not exist", parent_branch);
     }
+    if !allow_trunk_child && let Some(limit) = config.branch.max_empty_trunk_children {
+        check_trunk_child_limit(&repo, &current, &parent_branch, limit)?;
+    }
 
     let workdir = repo.workdir()?;
+    let patch = match patch.as_deref() {
+        Some(source) => {
+            if !staging::is_staging_area_empty(workdir)? {
+                bail!(
+                    "`--patch` needs an empty index. Commit or unstage your staged changes first."
+                );
+            }
+            let patch = read_patch_input(source)?;
+            check_patch_applies(&repo, &parent_branch, &patch)?;
+            Some(patch)
+        }
+        None => None,
+    };
+    let message = match &patch {
+        Some(patch) => Some(message.or_else(|| patch_subject(patch)).context(
+            "`--patch` needs a commit message: pass -m, or use a `git format-patch` file with a Subject line",
+        )?),
+        None => message,
+    };
     let has_staged_changes = !staging::is_staging_area_empty(workdir)?;
     let has_uncommitted_changes = staging::has_uncommitted_changes(workdir);
     let non_interactive =
@@ -535,7 +1021,7 @@ pub fn run(
         (generated_branch, commit_message, stage_mode, true)
     } else if let Some(m) = &message {
         (
-            m.clone(),
+            message_subject(m),
             Some(m.clone()),
             if all {
                 StageMode::All
@@ -557,6 +1043,7 @@ pub fn run(
     let branch_name_context = branch_name_context(&config, prefix.as_deref(), workdir);
     let branch_name_result =
         format_branch_name(&input, &branch_name_context).map_err(branch_name_error)?;
+    check_protected_branch_name(&repo, &config, &branch_name_result.name, force)?;
     let existing_branches = repo.list_branches()?;
     let branch_name = resolve_branch_name_conflicts(
         &branch_name_result.name,
@@ -568,6 +1055,32 @@ pub fn run(
         warnings: branch_name_result.warnings,
     };
 
+    if let Some(patch) = &patch {
+        let msg = message.as_deref().unwrap_or_default();
+        run_git_apply(workdir, &["apply", "--index"], patch).context("Failed to apply patch")?;
+        let result = run_commit_first(
+            &repo,
+            &config,
+            &current,
+            &parent_branch,
+            &branch_name,
+            msg,
+            StageMode::ExistingOnly,
+            false,
+            insert,
+            None,
+            no_verify,
+        );
+        match &result {
+            Ok(()) => print_branch_name_warnings(&branch_name_result.warnings),
+            // Leave the original branch exactly as it was before the patch.
+            Err(_) => {
+                let _ = run_git_apply(workdir, &["apply", "--index", "-R"], patch);
+            }
+        }
+        return result;
+    }
+
     // Before creating the branch, resolve the staging question. Doing this
     // early means declining ("Abort" / empty `--patch` exit) is a clean no-op
     // — no orphaned branch, no refs touched.
@@ -619,6 +1132,19 @@ pub fn run(
     // command. Only after a successful commit do we create metadata and move
     // into the requested placement.
     if let Some(msg) = commit_message.as_deref() {
+        if dry_commit
+            && !confirm_dry_commit(
+                workdir,
+                &branch_name,
+                &parent_branch,
+                msg,
+                stage_mode == StageMode::All || needs_stage_all,
+                yes,
+            )?
+        {
+            println!("{}", "Aborted. No branch was created.".dimmed());
+            return Ok(());
+        }
         let result = run_commit_first(
             &repo,
             &config,
@@ -654,13 +1180,8 @@ pub fn run(
             &mut NoopOperationReporter,
         )?;
         print_branch_name_warnings(&receipt.warnings);
-        if let OperationOutcome::BranchCreated { branch, parent } = receipt.outcome {
+        if let OperationOutcome::BranchCreated { parent, .. } = receipt.outcome {
             print_remote_parent_warning(&repo, &config, &parent);
-            println!(
-                "Created and switched to branch '{}' (stacked on {})",
-                branch.green(),
-                parent.blue()
-            );
         }
         print_tips(&config);
         return Ok(());
@@ -708,6 +1229,352 @@ pub fn run(
     Ok(())
 }
 
+/// Print what `--dry-commit` is about to commit and ask before going ahead.
+/// `--yes` skips the question; without a terminal the answer is no.
+fn confirm_dry_commit(
+    workdir: &Path,
+    branch_name: &str,
+    parent_branch: &str,
+    message: &str,
+    stage_all: bool,
+    yes: bool,
+) -> Result<bool> {
+    let files = staging::commit_preview_files(workdir, stage_all)?;
+    println!(
+        "Branch:  {} (stacked on {})",
+        branch_name.green(),
+        parent_branch.blue()
+    );
+    println!("Message: {}", message);
+    println!("Files to commit ({}):", files.len());
+    for file in &files {
+        println!("  {}", file);
+    }
+
+    if yes {
+        return Ok(true);
+    }
+    if !std::io::stdin().is_terminal() || !Term::stderr().is_term() {
+        println!(
+            "{}",
+            "Not a terminal — pass --yes to commit without confirming.".dimmed()
+        );
+        return Ok(false);
+    }
+    Ok(Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt("Create the branch with this commit?")
+        .default(false)
+        .interact()?)
+}
+
+/// Split `<remote>/<branch>` against the configured remotes (remote names may contain `/`).
+fn parse_upstream(repo: &GitRepo, upstream: &str) -> Result<(String, String)> {
+    let workdir = repo.workdir()?;
+    let remotes = git_stdout(workdir, &["remote"]);
+    let (remote, branch) = remotes
+        .lines()
+        .map(str::trim)
+        .filter(|remote| !remote.is_empty())
+        .filter_map(|remote| {
+            upstream
+                .strip_prefix(remote)
+                .and_then(|rest| rest.strip_prefix('/'))
+                .map(|branch| (remote, branch))
+        })
+        .max_by_key(|(remote, _)| remote.len())
+        .with_context(|| {
+            format!(
+                "Invalid --set-upstream '{}': expected <remote>/<branch> with a configured remote",
+                upstream
+            )
+        })?;
+
+    let valid_branch = !branch.is_empty()
+        && Command::new("git")
+            .args(["check-ref-format", "--branch", branch])
+            .current_dir(workdir)
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false);
+    if !valid_branch {
+        bail!(
+            "Invalid --set-upstream '{}': '{}' is not a valid branch name",
+            upstream,
+            branch
+        );
+    }
+    Ok((remote.to_string(), branch.to_string()))
+}
+
+/// Point `branch` at `remote/remote_branch`. The remote branch does not have to exist
+/// yet: `git branch --set-upstream-to` needs the tracking ref, so fall back to writing
+/// the same `branch.<name>.remote` / `.merge` config directly.
+fn set_branch_upstream(
+    workdir: &Path,
+    branch: &str,
+    remote: &str,
+    remote_branch: &str,
+) -> Result<()> {
+    let upstream = format!("{}/{}", remote, remote_branch);
+    let tracking_ref = format!("refs/remotes/{}", upstream);
+    let tracking_exists = Command::new("git")
+        .args(["show-ref", "--verify", "--quiet", &tracking_ref])
+        .current_dir(workdir)
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false);
+    if tracking_exists {
+        run_git_output(
+            workdir,
+            &["branch", &format!("--set-upstream-to={}", upstream), branch],
+            "git branch --set-upstream-to",
+        )?;
+        return Ok(());
+    }
+
+    run_git_output(
+        workdir,
+        &["config", &format!("branch.{}.remote", branch), remote],
+        "git config branch.<name>.remote",
+    )?;
+    run_git_output(
+        workdir,
+        &[
+            "config",
+            &format!("branch.{}.merge", branch),
+            &format!("refs/heads/{}", remote_branch),
+        ],
+        "git config branch.<name>.merge",
+    )?;
+    Ok(())
+}
+
+/// Commit message read from `--message-file`, without trailing blank lines.
+fn read_message_file(path: &str) -> Result<String> {
+    let message = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read message file '{}'", path))?;
+    let message = message.trim_end();
+    if message.trim().is_empty() {
+        bail!("Message file '{}' is empty", path);
+    }
+    Ok(message.to_string())
+}
+
+/// The subject line of a commit message, used to derive a branch name.
+fn message_subject(message: &str) -> String {
+    message
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Read a patch from a file path, or from stdin when `source` is `-`.
+fn read_patch_input(source: &str) -> Result<String> {
+    let patch = if source == "-" {
+        let mut buf = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut buf)
+            .context("Failed to read patch from stdin")?;
+        buf
+    } else {
+        std::fs::read_to_string(source)
+            .with_context(|| format!("Failed to read patch file '{}'", source))?
+    };
+    if patch.trim().is_empty() {
+        bail!("Patch is empty");
+    }
+    Ok(patch)
+}
+
+/// The commit subject of a `git format-patch` file, without the `[PATCH]` tag.
+fn patch_subject(patch: &str) -> Option<String> {
+    let subject = patch
+        .lines()
+        .take_while(|line| !line.starts_with("diff --git") && !line.starts_with("---"))
+        .find_map(|line| line.strip_prefix("Subject:"))?
+        .trim();
+    let subject = match subject.strip_prefix('[') {
+        Some(rest) if subject.starts_with("[PATCH") => rest.split_once(']')?.1.trim(),
+        _ => subject,
+    };
+    (!subject.is_empty()).then(|| subject.to_string())
+}
+
+/// Dry-run the patch against `parent_branch`'s tree in a throwaway index, so a
+/// patch that does not apply is rejected before any ref or file is touched.
+fn check_patch_applies(repo: &GitRepo, parent_branch: &str, patch: &str) -> Result<()> {
+    let workdir = repo.workdir()?;
+    let index = tempfile::NamedTempFile::new_in(repo.git_dir()?)
+        .context("Failed to create temporary index")?;
+    let index_path = index.path().to_string_lossy().to_string();
+
+    let read_tree = Command::new("git")
+        .args(["read-tree", parent_branch])
+        .env("GIT_INDEX_FILE", &index_path)
+        .current_dir(workdir)
+        .output()
+        .context("Failed to run git read-tree")?;
+    if !read_tree.status.success() {
+        bail!("git read-tree failed: {}", format_git_output(&read_tree));
+    }
+
+    let mut check = Command::new("git");
+    check
+        .args(["apply", "--cached", "--check", "-"])
+        .env("GIT_INDEX_FILE", &index_path);
+    let output = pipe_patch(check.current_dir(workdir), patch)?;
+    if !output.status.success() {
+        bail!(
+            "Patch does not apply cleanly on '{}':\n{}",
+            parent_branch,
+            format_git_output(&output)
+        );
+    }
+    Ok(())
+}
+
+fn run_git_apply(workdir: &Path, args: &[&str], patch: &str) -> Result<()> {
+    let mut command = Command::new("git");
+    command.args(args).arg("-").current_dir(workdir);
+    let output = pipe_patch(&mut command, patch)?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            format_git_output(&output)
+        );
+    }
+    Ok(())
+}
+
+fn pipe_patch(command: &mut Command, patch: &str) -> Result<Output> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run git apply")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(patch.as_bytes())
+            .context("Failed to write patch to git apply")?;
+    }
+    child.wait_with_output().context("Failed to run git apply")
+}
+
+/// Create a branch at `start` (the detached HEAD commit, or a tag's commit),
+/// recording `parent` as its stack parent. Without this, the new branch would
+/// be stacked on whatever `HEAD` shorthand resolves to, which is not a branch
+/// at all.
+#[allow(clippy::too_many_arguments)]
+fn run_detached(
+    repo: &GitRepo,
+    config: &Config,
+    input: Option<String>,
+    generated_branch_name: bool,
+    message: Option<String>,
+    parent: &str,
+    start: &str,
+    base_tag: Option<&str>,
+    prefix: Option<&str>,
+    all: bool,
+    no_verify: bool,
+    force: bool,
+) -> Result<()> {
+    let Some(input) = input else {
+        bail!(match base_tag {
+            Some(tag) => format!(
+                "Branch name required. Use: stax create <name> --from-tag {}",
+                tag
+            ),
+            None => "Branch name required. Use: stax create <name> --parent <branch>".to_string(),
+        });
+    };
+    let parent_rev = repo
+        .branch_commit(parent)
+        .map_err(|_| anyhow!("Parent branch '{}' does not exist", parent))?;
+
+    let workdir = repo.workdir()?;
+    let branch_name_context = branch_name_context(config, prefix, workdir);
+    let branch_name_result =
+        format_branch_name(&input, &branch_name_context).map_err(branch_name_error)?;
+    check_protected_branch_name(repo, config, &branch_name_result.name, force)?;
+    let branch_name = resolve_branch_name_conflicts(
+        &branch_name_result.name,
+        &repo.list_branches()?,
+        generated_branch_name,
+    )?;
+
+    repo.create_branch_at_commit(&branch_name, start)?;
+    let mut meta = BranchMetadata::new(parent, &parent_rev);
+    meta.base_tag = base_tag.map(str::to_string);
+    if let Err(e) = meta.write(repo.inner(), &branch_name) {
+        let _ = repo.delete_branch(&branch_name, true);
+        return Err(e);
+    }
+    if let Err(e) = repo.checkout(&branch_name) {
+        let _ = BranchMetadata::delete(repo.inner(), &branch_name);
+        let _ = repo.delete_branch(&branch_name, true);
+        return Err(e);
+    }
+    print_branch_name_warnings(&branch_name_result.warnings);
+    print_remote_parent_warning(repo, config, parent);
+
+    if let Some(msg) = message.as_deref() {
+        if all {
+            staging::stage_all(workdir)?;
+        }
+        if staging::is_staging_area_empty(workdir)? {
+            println!("{}", "No changes to commit".dimmed());
+        } else {
+            let result = run_git_commit(workdir, msg, false, no_verify)?;
+            if !result.status.success() {
+                bail!(
+                    "git commit failed; branch '{}' was created without a commit",
+                    branch_name
+                );
+            }
+        }
+    }
+
+    print_tips(config);
+    Ok(())
+}
+
+/// Resolve `--from` to a commit when it is a revision (`HEAD~2`, a SHA, a tag)
+/// rather than a local branch. `None` for branch names and for anything git
+/// cannot resolve, which the branch path then reports as missing.
+fn resolve_from_revision(repo: &GitRepo, from: &str) -> Option<String> {
+    if repo.branch_commit(from).is_ok() {
+        return None;
+    }
+    repo.rev_parse(&format!("{}^{{commit}}", from)).ok()
+}
+
+/// The tracked branch (or trunk) whose tip is the closest ancestor of `start`.
+/// Ties go to trunk, then by name; falls back to trunk when nothing matches.
+fn nearest_tracked_ancestor(repo: &GitRepo, start: &str) -> Result<String> {
+    let stack = Stack::load(repo)?;
+    let mut candidates: Vec<&String> = stack.branches.keys().collect();
+    if !stack.branches.contains_key(&stack.trunk) {
+        candidates.push(&stack.trunk);
+    }
+    let nearest = candidates
+        .into_iter()
+        .filter_map(|branch| {
+            let tip = repo.branch_commit(branch).ok()?;
+            if !repo.is_ancestor(&tip, start).ok()? {
+                return None;
+            }
+            let (distance, _) = repo.commits_ahead_behind(&tip, start).ok()?;
+            Some((distance, branch != &stack.trunk, branch.clone()))
+        })
+        .min()
+        .map(|(_, _, branch)| branch);
+    Ok(nearest.unwrap_or(stack.trunk))
+}
+
 /// Best-effort rollback: unstage changes, checkout the original branch,
 /// delete the new branch and its metadata.
 /// Errors during rollback are intentionally ignored (matching the pattern in split_hunk/app.rs).
@@ -1202,11 +2069,6 @@ fn run_commit_first(
     auto_stash.drop_stash(workdir)?;
 
     print_remote_parent_warning(repo, config, parent_branch);
-    println!(
-        "Created and switched to branch '{}' (stacked on {})",
-        branch_name.green(),
-        parent_branch.blue()
-    );
     println!("Committed: {}", message.cyan());
     print_tips(config);
 
@@ -1374,9 +2236,9 @@ fn rollback_after_commit(
 }
 
 /// Create `branch_name` stacked on `parent_branch`, write stax metadata,
-/// apply `--insert`/`--below` reparenting, check out the new branch, and print the
-/// "Created and switched…" banner. On any failure, undo all of it with
-/// `rollback_create` so the caller sees a clean failure.
+/// apply `--insert`/`--below` reparenting, and check out the new branch. On any
+/// failure, undo all of it with `rollback_create` so the caller sees a clean
+/// failure.
 ///
 /// `original` is the branch the user was on when `st create` was invoked; it's
 /// where `rollback_create` checks out on failure. For the commit-first
@@ -1454,11 +2316,6 @@ fn create_branch_with_banner(
     }
 
     print_remote_parent_warning(repo, config, parent_branch);
-    println!(
-        "Created and switched to branch '{}' (stacked on {})",
-        branch_name.green(),
-        parent_branch.blue()
-    );
 
     Ok(())
 }
@@ -1605,6 +2462,139 @@ fn apply_below_reparenting(
     Ok(())
 }
 
+/// Guard against piling up empty branches on trunk (e.g. a script re-running `bc`).
+fn check_trunk_child_limit(
+    repo: &GitRepo,
+    current: &str,
+    parent_branch: &str,
+    limit: usize,
+) -> Result<()> {
+    let stack = Stack::load(repo)?;
+    if current != stack.trunk || parent_branch != stack.trunk {
+        return Ok(());
+    }
+    let mut empty_children: Vec<&str> = stack
+        .branches
+        .get(&stack.trunk)
+        .map(|trunk| trunk.children.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter(|child| {
+            repo.commits_ahead_behind(&stack.trunk, child)
+                .is_ok_and(|(ahead, _)| ahead == 0)
+        })
+        .map(String::as_str)
+        .collect();
+    if empty_children.len() < limit {
+        return Ok(());
+    }
+    empty_children.sort_unstable();
+    bail!(
+        "{} already has {} branches with no commits ({}), the `branch.max_empty_trunk_children` limit.\n\
+         Delete unused ones with `stax branch delete`, or pass --allow-trunk-child to create another.",
+        stack.trunk,
+        empty_children.len(),
+        empty_children.join(", ")
+    )
+}
+
+/// Long-lived branch names that are almost never meant as stack work.
+const COMMON_PROTECTED_BRANCH_NAMES: &[&str] = &[
+    "main",
+    "master",
+    "develop",
+    "development",
+    "trunk",
+    "staging",
+    "production",
+];
+
+/// Refuse names covered by the remote's branch protection unless `--force`.
+/// Without readable protection rules (no token, non-GitHub forge), only warn
+/// on trunk and common long-lived names.
+fn check_protected_branch_name(
+    repo: &GitRepo,
+    config: &Config,
+    branch_name: &str,
+    force: bool,
+) -> Result<()> {
+    if let Some(pattern) = matching_protection_pattern(repo, config, branch_name) {
+        if !force {
+            bail!(
+                "Cannot create '{}': it matches the protected branch pattern '{}' on the remote.\n\
+                 That is almost certainly a mistake. Pass --force to create it anyway.",
+                branch_name,
+                pattern
+            );
+        }
+        eprintln!(
+            "{}",
+            format!(
+                "Warning: '{}' matches the protected branch pattern '{}' on the remote.",
+                branch_name, pattern
+            )
+            .yellow()
+        );
+        return Ok(());
+    }
+
+    let is_trunk = repo.trunk_branch().is_ok_and(|trunk| trunk == branch_name);
+    if !force && (is_trunk || COMMON_PROTECTED_BRANCH_NAMES.contains(&branch_name)) {
+        eprintln!(
+            "{}",
+            format!(
+                "Warning: '{}' looks like a long-lived branch, not stack work.",
+                branch_name
+            )
+            .yellow()
+        );
+    }
+    Ok(())
+}
+
+/// First GitHub branch protection pattern covering `branch_name`. Only asked
+/// when a token is available; any lookup failure counts as no match.
+fn matching_protection_pattern(
+    repo: &GitRepo,
+    config: &Config,
+    branch_name: &str,
+) -> Option<String> {
+    let remote_info = remote::RemoteInfo::from_repo(repo, config).ok()?;
+    if remote_info.forge != remote::ForgeType::GitHub {
+        return None;
+    }
+    let rt = tokio::runtime::Runtime::new().ok()?;
+    let patterns = rt
+        .block_on(async {
+            let client = ForgeClient::new(&remote_info)?;
+            client.protected_branch_patterns().await
+        })
+        .ok()?;
+    patterns
+        .into_iter()
+        .find(|pattern| protection_pattern_matches(pattern, branch_name))
+}
+
+/// fnmatch-style match as GitHub applies protection patterns: `*` and `?`
+/// stop at `/`, `**` spans path segments.
+fn protection_pattern_matches(pattern: &str, branch_name: &str) -> bool {
+    let mut regex = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                regex.push_str(".*");
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            _ => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex::Regex::new(&regex).is_ok_and(|regex| regex.is_match(branch_name))
+}
+
 fn print_remote_parent_warning(repo: &GitRepo, config: &Config, parent_branch: &str) {
     let Ok(workdir) = repo.workdir() else {
         return;
@@ -1784,10 +2774,325 @@ fn run_wizard(workdir: &Path, parent_branch: &str) -> Result<(String, Option<Str
     Ok((name, msg, stage_mode))
 }
 
+/// Prompts behind `stax create --interactive`. The terminal implementation
+/// uses dialoguer; tests script the answers.
+trait CreateWizardPrompter {
+    /// Ask for the branch name; `preview` formats it (or rejects it) with the
+    /// configured prefix/format.
+    fn branch_name(&mut self, preview: &dyn Fn(&str) -> Result<String>) -> Result<String>;
+    fn parent(&mut self, branches: &[String], default: usize) -> Result<usize>;
+    fn message(&mut self) -> Result<String>;
+    fn empty_commit(&mut self, default: bool) -> Result<bool>;
+}
+
+struct TerminalCreateWizardPrompter;
+
+impl CreateWizardPrompter for TerminalCreateWizardPrompter {
+    fn branch_name(&mut self, preview: &dyn Fn(&str) -> Result<String>) -> Result<String> {
+        Ok(Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Branch name")
+            .validate_with(|input: &String| preview(input).map(|_| ()).map_err(|e| e.to_string()))
+            .interact_text()?)
+    }
+
+    fn parent(&mut self, branches: &[String], default: usize) -> Result<usize> {
+        Ok(Select::with_theme(&ColorfulTheme::default())
+            .with_prompt("Stack on")
+            .items(branches)
+            .default(default)
+            .interact()?)
+    }
+
+    fn message(&mut self) -> Result<String> {
+        Ok(Input::with_theme(&ColorfulTheme::default())
+            .with_prompt("Commit message (Enter to skip)")
+            .allow_empty(true)
+            .interact_text()?)
+    }
+
+    fn empty_commit(&mut self, default: bool) -> Result<bool> {
+        Ok(Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt("Make an empty commit (ignore staged changes)?")
+            .default(default)
+            .interact()?)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct CreateWizardAnswers {
+    /// Name as typed; `create` applies the prefix/format itself.
+    input: String,
+    /// Formatted name shown to the user before creating.
+    branch_name: String,
+    parent: String,
+    message: Option<String>,
+    empty_commit: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct WizardCommit {
+    message: String,
+    allow_empty: bool,
+}
+
+fn run_create_wizard(
+    prompter: &mut dyn CreateWizardPrompter,
+    context: &BranchNameContext,
+    branches: &[String],
+    current: &str,
+    has_staged_changes: bool,
+) -> Result<CreateWizardAnswers> {
+    let preview = |input: &str| -> Result<String> {
+        Ok(format_branch_name(input, context)
+            .map_err(branch_name_error)?
+            .name)
+    };
+    let input = prompter.branch_name(&preview)?;
+    let branch_name = preview(&input)?;
+    println!("  {} {}", "→".dimmed(), branch_name.green());
+
+    let default = branches
+        .iter()
+        .position(|branch| branch == current)
+        .unwrap_or(0);
+    let parent = branches
+        .get(prompter.parent(branches, default)?)
+        .cloned()
+        .context("Parent selection out of range")?;
+
+    let message = Some(prompter.message()?.trim().to_string()).filter(|m| !m.is_empty());
+    let empty_commit = match message {
+        Some(_) => prompter.empty_commit(!has_staged_changes)?,
+        None => false,
+    };
+
+    Ok(CreateWizardAnswers {
+        input,
+        branch_name,
+        parent,
+        message,
+        empty_commit,
+    })
+}
+
+/// Run the `--interactive` wizard and fold its answers into `opts`. Returns the
+/// commit to make once the branch exists, if the user gave a message.
+fn prepare_interactive_create(opts: &mut CreateOptions) -> Result<Option<WizardCommit>> {
+    if !std::io::stdin().is_terminal() || !std::io::stderr().is_terminal() {
+        bail!(
+            "Interactive mode needs a terminal. Pass the branch name instead: stax create <name>"
+        );
+    }
+    let repo = GitRepo::open()?;
+    let config = Config::load()?;
+    let workdir = repo.workdir()?;
+    let current = repo.current_branch()?;
+    let stack = Stack::load(&repo)?;
+    let mut branches = stack
+        .branches
+        .keys()
+        .filter(|branch| **branch != stack.trunk)
+        .cloned()
+        .collect::<Vec<_>>();
+    branches.sort();
+    branches.insert(0, stack.trunk.clone());
+
+    let context = branch_name_context(&config, opts.prefix.as_deref(), workdir);
+    let answers = run_create_wizard(
+        &mut TerminalCreateWizardPrompter,
+        &context,
+        &branches,
+        &current,
+        !staging::is_staging_area_empty(workdir)?,
+    )?;
+    opts.name = Some(answers.input);
+    opts.from = Some(answers.parent);
+    Ok(answers.message.map(|message| WizardCommit {
+        message,
+        allow_empty: answers.empty_commit,
+    }))
+}
+
+/// Fill in the branch name (and, with `--prefix-from-labels`, the prefix) from a forge issue.
+fn prepare_issue_create(opts: &mut CreateOptions, number: u64) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let config = Config::load()?.with_request_timeout(opts.request_timeout);
+    let remote_info = remote::RemoteInfo::from_repo(&repo, &config)?;
+    let rt = tokio::runtime::Runtime::new()?;
+    let issue = rt.block_on(async {
+        let client = ForgeClient::new(&remote_info)?;
+        client.get_issue(number).await
+    })?;
+
+    if opts.name.is_none() {
+        opts.name = Some(format!("{}-{}", issue.number, issue.title));
+    }
+    if opts.prefix_from_labels {
+        opts.prefix = prefix_for_labels(&issue.labels, &config.branch.label_prefixes);
+    }
+    Ok(())
+}
+
+/// First issue label (case-insensitive) with an entry in `branch.label_prefixes`.
+fn prefix_for_labels(
+    labels: &[String],
+    label_prefixes: &BTreeMap<String, String>,
+) -> Option<String> {
+    labels.iter().find_map(|label| {
+        label_prefixes
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(label))
+            .map(|(_, prefix)| prefix.clone())
+    })
+}
+
+/// Commit the wizard's message on the new branch: an empty commit when asked
+/// for, otherwise whatever is staged.
+fn commit_wizard_message(workdir: &Path, commit: &WizardCommit, no_verify: bool) -> Result<()> {
+    if !commit.allow_empty && staging::is_staging_area_empty(workdir)? {
+        println!("{}", "No changes to commit".dimmed());
+        return Ok(());
+    }
+    let mut args = vec!["commit", "--quiet"];
+    if commit.allow_empty {
+        args.push("--allow-empty");
+    }
+    if no_verify {
+        args.push("--no-verify");
+    }
+    args.extend(["-m", commit.message.as_str()]);
+    let status = Command::new("git")
+        .args(&args)
+        .current_dir(workdir)
+        .status()
+        .context("Failed to run git commit")?;
+    if !status.success() {
+        bail!("git commit failed; the branch was created without a commit");
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    struct ScriptedWizardPrompter {
+        name: &'static str,
+        parent: &'static str,
+        message: &'static str,
+        empty_commit: bool,
+        offered_parents: Vec<String>,
+        default_parent: Option<usize>,
+    }
+
+    impl ScriptedWizardPrompter {
+        fn new(name: &'static str, parent: &'static str, message: &'static str) -> Self {
+            Self {
+                name,
+                parent,
+                message,
+                empty_commit: true,
+                offered_parents: Vec::new(),
+                default_parent: None,
+            }
+        }
+    }
+
+    impl CreateWizardPrompter for ScriptedWizardPrompter {
+        fn branch_name(&mut self, preview: &dyn Fn(&str) -> Result<String>) -> Result<String> {
+            preview(self.name)?;
+            Ok(self.name.to_string())
+        }
+
+        fn parent(&mut self, branches: &[String], default: usize) -> Result<usize> {
+            self.offered_parents = branches.to_vec();
+            self.default_parent = Some(default);
+            branches
+                .iter()
+                .position(|branch| branch == self.parent)
+                .context("scripted parent not offered")
+        }
+
+        fn message(&mut self) -> Result<String> {
+            Ok(self.message.to_string())
+        }
+
+        fn empty_commit(&mut self, _default: bool) -> Result<bool> {
+            Ok(self.empty_commit)
+        }
+    }
+
+    fn wizard_context(prefix: Option<&str>) -> BranchNameContext {
+        BranchNameContext {
+            prefix: prefix.map(str::to_string),
+            ..BranchNameContext::literal()
+        }
+    }
+
+    #[test]
+    fn create_wizard_applies_prefix_preview_and_selected_parent() {
+        let branches = vec!["main".to_string(), "api".to_string(), "ui".to_string()];
+        let mut prompter = ScriptedWizardPrompter::new("Add login", "api", "Start login");
+
+        let answers = run_create_wizard(
+            &mut prompter,
+            &wizard_context(Some("cesar/")),
+            &branches,
+            "ui",
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers,
+            CreateWizardAnswers {
+                input: "Add login".to_string(),
+                branch_name: "cesar/Add-login".to_string(),
+                parent: "api".to_string(),
+                message: Some("Start login".to_string()),
+                empty_commit: true,
+            }
+        );
+        assert_eq!(prompter.offered_parents, branches);
+        assert_eq!(prompter.default_parent, Some(2));
+    }
+
+    #[test]
+    fn create_wizard_skips_commit_question_without_message() {
+        let branches = vec!["main".to_string()];
+        let mut prompter = ScriptedWizardPrompter::new("docs", "main", "   ");
+
+        let answers = run_create_wizard(
+            &mut prompter,
+            &wizard_context(None),
+            &branches,
+            "main",
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(answers.branch_name, "docs");
+        assert_eq!(answers.parent, "main");
+        assert_eq!(answers.message, None);
+        assert!(!answers.empty_commit);
+    }
+
+    #[test]
+    fn create_wizard_rejects_empty_branch_name() {
+        let mut prompter = ScriptedWizardPrompter::new("   ", "main", "");
+
+        let err = run_create_wizard(
+            &mut prompter,
+            &wizard_context(None),
+            &["main".to_string()],
+            "main",
+            false,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("cannot be empty"), "{err}");
+    }
+
     fn targets(branch: bool, message: bool) -> AiCreateTargets {
         AiCreateTargets { branch, message }
     }
@@ -1887,4 +3192,61 @@ mod tests {
         assert!(prompt.contains("The command will stage all changes before committing."));
         assert!(prompt.contains("diff --git"));
     }
+
+    #[test]
+    fn patch_subject_reads_format_patch_subject() {
+        let patch = "From 1234 Mon Sep 17 00:00:00 2001\nFrom: A <a@example.com>\nSubject: [PATCH 1/2] fix: handle empty input\n\n---\n a.txt | 1 +\n";
+        assert_eq!(
+            patch_subject(patch).as_deref(),
+            Some("fix: handle empty input")
+        );
+        assert_eq!(
+            patch_subject("Subject: plain subject\n").as_deref(),
+            Some("plain subject")
+        );
+        assert_eq!(patch_subject("diff --git a/x b/x\n"), None);
+    }
+
+    #[test]
+    fn prefix_for_labels_uses_first_mapped_label() {
+        let table = BTreeMap::from([
+            ("bug".to_string(), "fix/".to_string()),
+            ("enhancement".to_string(), "feat/".to_string()),
+        ]);
+        let labels = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            prefix_for_labels(&labels(&["triage", "Bug", "enhancement"]), &table),
+            Some("fix/".to_string())
+        );
+        assert_eq!(prefix_for_labels(&labels(&["question"]), &table), None);
+        assert_eq!(prefix_for_labels(&[], &table), None);
+    }
+
+    #[test]
+    fn protection_pattern_matches_like_github_fnmatch() {
+        assert!(protection_pattern_matches("main", "main"));
+        assert!(!protection_pattern_matches("main", "main-fix"));
+        assert!(protection_pattern_matches("release/*", "release/1.2"));
+        assert!(!protection_pattern_matches(
+            "release/*",
+            "release/1.2/hotfix"
+        ));
+        assert!(protection_pattern_matches(
+            "release/**",
+            "release/1.2/hotfix"
+        ));
+        assert!(protection_pattern_matches("v?.x", "v2.x"));
+        assert!(!protection_pattern_matches("v?.x", "v2-x"));
+    }
+
+    #[test]
+    fn branch_description_drops_template_comments() {
+        let edited = "\n# Describe what 'a' is for.\n# Lines starting with '#' are ignored.\nFirst line\n\nSecond\n\n";
+        assert_eq!(
+            parse_branch_description(edited).as_deref(),
+            Some("First line\n\nSecond")
+        );
+        assert_eq!(parse_branch_description("\n# only comments\n\n"), None);
+    }
 }
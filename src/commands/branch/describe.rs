@@ -0,0 +1,55 @@
+use super::create::{parse_branch_description, run_description_editor};
+use crate::engine::BranchMetadata;
+use crate::git::GitRepo;
+use anyhow::{Result, bail};
+use colored::Colorize;
+
+/// Set, edit or clear the description submit uses as a branch's PR body.
+pub fn run(branch: Option<String>, message: Option<String>, clear: bool) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let target = branch.unwrap_or(repo.current_branch()?);
+
+    let Some(mut meta) = BranchMetadata::read(repo.inner(), &target)? else {
+        bail!(
+            "Branch '{}' is not tracked. Run `stax branch track` first.",
+            target
+        );
+    };
+
+    if clear {
+        if meta.description.take().is_none() {
+            println!("{}", format!("'{}' has no description", target).dimmed());
+            return Ok(());
+        }
+        meta.write(repo.inner(), &target)?;
+        println!("Cleared description for '{}'", target.cyan());
+        return Ok(());
+    }
+
+    let description = match message {
+        Some(message) => parse_branch_description(&message),
+        None => {
+            let template = format!(
+                "{}\n\n# Describe '{}' (stacked on {}). Submit uses this as the PR body.\n\
+                 # Lines starting with '#' are ignored; save an empty file to keep the current text.\n",
+                meta.description.as_deref().unwrap_or_default(),
+                target,
+                meta.parent_branch_name
+            );
+            let edited = run_description_editor(&template)?;
+            let Some(description) = parse_branch_description(&edited) else {
+                println!("{}", "Empty description; nothing saved".dimmed());
+                return Ok(());
+            };
+            Some(description)
+        }
+    };
+    let Some(description) = description else {
+        bail!("Description is empty; use --clear to remove it");
+    };
+
+    meta.description = Some(description);
+    meta.write(repo.inner(), &target)?;
+    println!("Saved description for '{}'", target.cyan());
+    Ok(())
+}
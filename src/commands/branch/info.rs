@@ -0,0 +1,69 @@
+use crate::engine::BranchMetadata;
+use crate::git::GitRepo;
+use anyhow::{Result, bail};
+use colored::Colorize;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+struct BranchInfo {
+    branch: String,
+    parent: String,
+    parent_revision: String,
+    pr_number: Option<u64>,
+    pr_state: Option<String>,
+    frozen: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remote: Option<String>,
+    description: Option<String>,
+}
+
+/// Show the stax metadata recorded for a branch.
+pub fn run(branch: Option<String>, json: bool) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let target = branch.unwrap_or(repo.current_branch()?);
+
+    let Some(meta) = BranchMetadata::read(repo.inner(), &target)? else {
+        bail!(
+            "Branch '{}' is not tracked. Run `stax branch track` first.",
+            target
+        );
+    };
+    let info = BranchInfo {
+        branch: target,
+        parent: meta.parent_branch_name,
+        parent_revision: meta.parent_branch_revision,
+        pr_number: meta.pr_info.as_ref().map(|pr| pr.number),
+        pr_state: meta.pr_info.map(|pr| pr.state),
+        frozen: meta.frozen,
+        remote: meta.remote,
+        description: meta.description,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+        return Ok(());
+    }
+
+    println!("{}", info.branch.green().bold());
+    println!("  parent: {}", info.parent.blue());
+    if let Some(number) = info.pr_number {
+        let state = info.pr_state.as_deref().unwrap_or_default();
+        println!("  PR:     #{} {}", number, state.dimmed());
+    }
+    if let Some(remote) = &info.remote {
+        println!("  remote: {}", remote.cyan());
+    }
+    if info.frozen {
+        println!("  frozen: {}", "yes".yellow());
+    }
+    match &info.description {
+        Some(description) => {
+            println!();
+            for line in description.lines() {
+                println!("  {}", line);
+            }
+        }
+        None => println!("  {}", "No description".dimmed()),
+    }
+    Ok(())
+}
@@ -1,6 +1,8 @@
 pub mod create;
 pub mod delete;
+pub mod describe;
 pub mod fold;
+pub mod info;
 pub mod rename;
 pub mod reparent;
 pub mod squash;
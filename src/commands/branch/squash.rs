@@ -1,12 +1,19 @@
-use crate::engine::BranchMetadata;
+use crate::commands::upstack;
+use crate::engine::{BranchMetadata, Stack};
 use crate::git::GitRepo;
-use anyhow::{Context, Result};
+use crate::ops::receipt::OpKind;
+use crate::ops::tx::Transaction;
+use anyhow::{Context, Result, bail};
 use colored::Colorize;
 use dialoguer::{Confirm, Input, theme::ColorfulTheme};
+use std::path::Path;
 use std::process::Command;
 
 /// Squash all commits on the current branch into a single commit
-pub fn run(message: Option<String>, skip_confirm: bool) -> Result<()> {
+pub fn run(message: Option<String>, skip_confirm: bool, into_parent: bool) -> Result<()> {
+    if into_parent {
+        return run_into_parent(message, skip_confirm);
+    }
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let workdir = repo.workdir()?;
@@ -63,37 +70,7 @@ pub fn run(message: Option<String>, skip_confirm: bool) -> Result<()> {
     }
     println!();
 
-    // Get commit message
-    let squash_message = if let Some(msg) = message {
-        msg
-    } else if skip_confirm {
-        // In non-interactive mode, use first commit's message as default
-        let first_msg_output = Command::new("git")
-            .args(["log", "-1", "--format=%s", &format!("{}..HEAD", parent)])
-            .current_dir(workdir)
-            .output()
-            .context("Failed to get commit message")?;
-
-        String::from_utf8_lossy(&first_msg_output.stdout)
-            .trim()
-            .to_string()
-    } else {
-        // Get the first commit's message as default
-        let first_msg_output = Command::new("git")
-            .args(["log", "-1", "--format=%s", &format!("{}..HEAD", parent)])
-            .current_dir(workdir)
-            .output()
-            .context("Failed to get commit message")?;
-
-        let default_msg = String::from_utf8_lossy(&first_msg_output.stdout)
-            .trim()
-            .to_string();
-
-        Input::with_theme(&ColorfulTheme::default())
-            .with_prompt("Squash commit message")
-            .default(default_msg)
-            .interact_text()?
-    };
+    let squash_message = resolve_message(workdir, parent, message, skip_confirm)?;
 
     // Confirm (unless --yes flag)
     if !skip_confirm {
@@ -155,3 +132,205 @@ pub fn run(message: Option<String>, skip_confirm: bool) -> Result<()> {
 
     Ok(())
 }
+
+/// Use `message`, or fall back to the branch's latest commit subject (prompting
+/// to edit it unless `skip_confirm`).
+fn resolve_message(
+    workdir: &Path,
+    parent: &str,
+    message: Option<String>,
+    skip_confirm: bool,
+) -> Result<String> {
+    if let Some(msg) = message {
+        return Ok(msg);
+    }
+    let first_msg_output = Command::new("git")
+        .args(["log", "-1", "--format=%s", &format!("{}..HEAD", parent)])
+        .current_dir(workdir)
+        .output()
+        .context("Failed to get commit message")?;
+    let default_msg = String::from_utf8_lossy(&first_msg_output.stdout)
+        .trim()
+        .to_string();
+
+    if skip_confirm {
+        return Ok(default_msg);
+    }
+    Ok(Input::with_theme(&ColorfulTheme::default())
+        .with_prompt("Squash commit message")
+        .default(default_msg)
+        .interact_text()?)
+}
+
+/// `stax branch squash --into-parent`: squash the current branch into one commit
+/// on top of its parent, drop the branch, and restack everything that was
+/// stacked on either of them onto the parent's new tip.
+///
+/// The squash and fold run as one transaction (`stax undo` restores both
+/// branches); the follow-up restack is a regular upstack restack, so a conflict
+/// there resumes with `stax continue`.
+fn run_into_parent(message: Option<String>, skip_confirm: bool) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let stack = Stack::load(&repo)?;
+    let current = repo.current_branch()?;
+    let workdir = repo.workdir()?;
+
+    if current == stack.trunk {
+        bail!("Cannot squash trunk. Checkout a stacked branch first.");
+    }
+    if repo.is_dirty()? {
+        bail!("Working tree has uncommitted changes. Commit or stash them before squashing.");
+    }
+    if repo.rebase_in_progress()? {
+        bail!("A rebase is in progress. Run `stax continue` or `stax abort` first.");
+    }
+
+    let meta = BranchMetadata::read(repo.inner(), &current)?
+        .context("Current branch is not tracked. Use 'stax branch track' first.")?;
+    let parent = meta.parent_branch_name.clone();
+    if parent == stack.trunk {
+        bail!("Cannot fold into trunk. Use `stax submit` to merge the branch into trunk via a PR.");
+    }
+    if BranchMetadata::read(repo.inner(), &parent)?.is_none() {
+        bail!(
+            "Parent branch '{}' is not tracked. Run `stax branch track` on it first.",
+            parent
+        );
+    }
+
+    let current_tip = repo.branch_commit(&current)?;
+    let parent_tip = repo.branch_commit(&parent)?;
+    if !repo.is_ancestor(&parent_tip, &current_tip)? {
+        bail!(
+            "'{}' is not on top of '{}'. Run `stax restack` first.",
+            current,
+            parent
+        );
+    }
+    let (commit_count, _) = repo.commits_ahead_behind(&parent, &current)?;
+    if commit_count == 0 {
+        println!("{}", "No commits to squash.".yellow());
+        return Ok(());
+    }
+
+    let kids = stack.children(&current);
+    println!(
+        "Squash {} commit(s) on '{}' into one commit on '{}'",
+        commit_count.to_string().cyan(),
+        current.cyan(),
+        parent.cyan()
+    );
+    println!("  {} '{}' will be deleted", "▸".dimmed(), current.red());
+    if !kids.is_empty() {
+        println!(
+            "  {} {} child branch(es) re-parented onto '{}': {}",
+            "▸".dimmed(),
+            kids.len().to_string().cyan(),
+            parent.green(),
+            kids.join(", ").dimmed()
+        );
+    }
+    println!();
+
+    let squash_message = resolve_message(workdir, &parent, message, skip_confirm)?;
+
+    if !skip_confirm {
+        let confirm = Confirm::with_theme(&ColorfulTheme::default())
+            .with_prompt(format!("Squash '{}' into '{}'?", current, parent))
+            .default(true)
+            .interact()?;
+        if !confirm {
+            println!("{}", "Aborted.".red());
+            return Ok(());
+        }
+    }
+
+    let mut tx = Transaction::begin(OpKind::SquashIntoParent, &repo, false)?;
+    tx.plan_branch(&repo, &parent)?;
+    tx.plan_branch(&repo, &current)?;
+    tx.plan_metadata_ref(&repo, &parent)?;
+    tx.plan_metadata_ref(&repo, &current)?;
+    for kid in &kids {
+        tx.plan_metadata_ref(&repo, kid)?;
+    }
+    tx.snapshot()?;
+
+    let output = Command::new("git")
+        .args([
+            "commit-tree",
+            &format!("{}^{{tree}}", current_tip),
+            "-p",
+            &parent_tip,
+            "-m",
+            &squash_message,
+        ])
+        .current_dir(workdir)
+        .output()
+        .context("Failed to run git commit-tree")?;
+    if !output.status.success() {
+        let msg = format!(
+            "Failed to create squashed commit: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        tx.finish_err(&msg, Some("squash"), Some(&current))?;
+        bail!("{}", msg);
+    }
+    let squashed = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    repo.checkout(&parent)
+        .with_context(|| format!("Failed to checkout '{}'", parent))?;
+    repo.update_ref(&format!("refs/heads/{}", parent), &squashed)
+        .with_context(|| format!("Failed to move '{}' to {}", parent, squashed))?;
+    repo.reset_hard(&squashed)
+        .with_context(|| format!("Failed to reset working tree to {}", squashed))?;
+    repo.delete_branch(&current, true)
+        .with_context(|| format!("Failed to delete '{}'", current))?;
+    tx.record_after(&repo, &parent)?;
+    // The deleted ref has no "after" oid, which is what undo needs to recreate it.
+    tx.record_after(&repo, &current).ok();
+
+    // Children keep their old base (the deleted branch's tip) as the recorded
+    // parent revision, so the restack below replays only their own commits.
+    for kid in &kids {
+        let kid_meta = BranchMetadata::read(repo.inner(), kid)?.with_context(|| {
+            format!(
+                "Child branch '{}' is missing metadata; cannot reparent.",
+                kid
+            )
+        })?;
+        BranchMetadata {
+            parent_branch_name: parent.clone(),
+            ..kid_meta
+        }
+        .write(repo.inner(), kid)?;
+        tx.record_metadata_ref_after(&repo, kid)?;
+    }
+    BranchMetadata::delete(repo.inner(), &current)
+        .with_context(|| format!("Failed to delete metadata for '{}'", current))?;
+    tx.record_metadata_ref_after(&repo, &current)?;
+    tx.finish_ok()?;
+
+    println!(
+        "{} Squashed {} commit(s) from '{}' into '{}'.",
+        "✓".green().bold(),
+        commit_count,
+        current.cyan(),
+        parent.cyan()
+    );
+    if let Some(pr) = meta.pr_info.as_ref().map(|pr| pr.number) {
+        println!(
+            "{} '{}' had PR #{}. Close it with: {}",
+            "ⓘ".blue(),
+            current.dimmed(),
+            pr,
+            "stax pr close".cyan()
+        );
+    }
+
+    // Children of the deleted branch and its former siblings all sit on stale bases now.
+    if stack.children(&parent).len() > 1 || !kids.is_empty() {
+        println!();
+        upstack::restack::run(false)?;
+    }
+    Ok(())
+}
@@ -8,9 +8,9 @@ use colored::Colorize;
 use dialoguer::{FuzzySelect, theme::ColorfulTheme};
 use std::process::Command;
 
-pub fn run(parent: Option<String>, all_prs: bool) -> Result<()> {
+pub fn run(parent: Option<String>, all_prs: bool, request_timeout: Option<u64>) -> Result<()> {
     if all_prs {
-        return run_track_all_prs();
+        return run_track_all_prs(request_timeout);
     }
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
@@ -121,9 +121,9 @@ pub fn run(parent: Option<String>, all_prs: bool) -> Result<()> {
 }
 
 /// Track all open PRs authored by the current user
-fn run_track_all_prs() -> Result<()> {
+fn run_track_all_prs(request_timeout: Option<u64>) -> Result<()> {
     let repo = GitRepo::open()?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let trunk = repo.trunk_branch()?;
     let workdir = repo.workdir()?;
     let remote_name = config.remote_name();
@@ -233,11 +233,17 @@ fn run_track_all_prs() -> Result<()> {
             parent_branch_name: parent_branch.clone(),
             parent_branch_revision: parent_rev,
             source_remote: None,
+            remote: None,
             frozen: false,
+            reviewers: Vec::new(),
+            team_reviewers: Vec::new(),
+            base_tag: None,
+            description: None,
             pr_info: Some(PrInfo {
                 number: pr.number,
                 state: pr.state.to_uppercase(),
                 is_draft: Some(pr.is_draft),
+                head_owner: None,
             }),
         };
 
@@ -0,0 +1,176 @@
+use crate::cache::{ApiResponseCache, CiCache};
+use crate::config::Config;
+use crate::engine::Stack;
+use crate::git::GitRepo;
+use crate::git::repo::format_duration;
+use anyhow::Result;
+use clap::Subcommand;
+use colored::Colorize;
+use serde::Serialize;
+
+#[derive(Debug, Subcommand)]
+pub enum CacheCommand {
+    /// Show cached PR/CI state and whether it is still fresh
+    Show {
+        /// Output JSON for scripting
+        #[arg(long)]
+        json: bool,
+    },
+    /// Delete all cached PR/CI state and API responses
+    Clear,
+    /// Drop cached state for branches stax no longer tracks
+    Prune,
+}
+
+#[derive(Serialize)]
+struct CacheShowJson {
+    ttl_secs: u64,
+    /// RFC 3339 time of the last full refresh (`stax ci`).
+    last_refresh: Option<String>,
+    age_secs: Option<u64>,
+    fresh: bool,
+    branches: Vec<CachedBranchJson>,
+    api_responses: usize,
+    api_response_bytes: u64,
+}
+
+#[derive(Serialize)]
+struct CachedBranchJson {
+    name: String,
+    pr_state: Option<String>,
+    ci_state: Option<String>,
+    ci_revision: Option<String>,
+    updated_at: u64,
+}
+
+pub fn run(cmd: CacheCommand) -> Result<()> {
+    match cmd {
+        CacheCommand::Show { json } => run_show(json),
+        CacheCommand::Clear => run_clear(),
+        CacheCommand::Prune => run_prune(),
+    }
+}
+
+fn run_show(json: bool) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let git_dir = repo.common_git_dir()?;
+    let config = Config::load()?;
+    let ttl_secs = config.cache.ttl_secs;
+    let cache = CiCache::load_strict(&git_dir)?;
+    let (api_responses, api_response_bytes) = ApiResponseCache::usage(&git_dir);
+
+    let mut branches: Vec<_> = cache.branches.iter().collect();
+    branches.sort_by(|a, b| a.0.cmp(b.0));
+
+    if json {
+        let output = CacheShowJson {
+            ttl_secs,
+            last_refresh: last_refresh_time(&cache).map(|at| at.to_rfc3339()),
+            age_secs: cache.age_secs(),
+            fresh: cache.is_fresh(ttl_secs),
+            branches: branches
+                .into_iter()
+                .map(|(name, entry)| CachedBranchJson {
+                    name: name.clone(),
+                    pr_state: entry.pr_state.clone(),
+                    ci_state: entry.ci_state.clone(),
+                    ci_revision: entry.ci_revision.clone(),
+                    updated_at: entry.updated_at,
+                })
+                .collect(),
+            api_responses,
+            api_response_bytes,
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    let refreshed = match (last_refresh_time(&cache), cache.age_secs()) {
+        (Some(at), Some(age)) => {
+            let freshness = if cache.is_fresh(ttl_secs) {
+                "fresh".green()
+            } else {
+                "stale".yellow()
+            };
+            format!(
+                "{} ({}, {}; ttl {}s)",
+                at.format("%Y-%m-%d %H:%M"),
+                format_duration(age as i64),
+                freshness,
+                ttl_secs
+            )
+        }
+        _ => "never (run `stax ci` to populate)".dimmed().to_string(),
+    };
+    println!("{} {}", "Last refresh:".bold(), refreshed);
+
+    if branches.is_empty() {
+        println!("{}", "No cached branches.".dimmed());
+    } else {
+        println!("{} {}", "Cached branches:".bold(), branches.len());
+        let width = branches
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+        for (name, entry) in branches {
+            println!(
+                "  {:<width$}  PR {:<7}  CI {}",
+                name,
+                entry.pr_state.as_deref().unwrap_or("-"),
+                entry.ci_state.as_deref().unwrap_or("-"),
+                width = width
+            );
+        }
+    }
+    println!(
+        "{} {} ({} bytes)",
+        "API responses:".bold(),
+        api_responses,
+        api_response_bytes
+    );
+    Ok(())
+}
+
+fn last_refresh_time(cache: &CiCache) -> Option<chrono::DateTime<chrono::Local>> {
+    let secs = i64::try_from(cache.last_refresh)
+        .ok()
+        .filter(|secs| *secs > 0)?;
+    chrono::DateTime::from_timestamp(secs, 0).map(|at| at.with_timezone(&chrono::Local))
+}
+
+fn run_clear() -> Result<()> {
+    let repo = GitRepo::open()?;
+    let git_dir = repo.common_git_dir()?;
+    let branches = CiCache::load(&git_dir).branches.len();
+    CiCache::clear(&git_dir)?;
+    let responses = ApiResponseCache::clear(&git_dir)?;
+    println!(
+        "{} Cleared cached state for {} {} and {} API {}",
+        "✓".green(),
+        branches,
+        if branches == 1 { "branch" } else { "branches" },
+        responses,
+        if responses == 1 {
+            "response"
+        } else {
+            "responses"
+        }
+    );
+    Ok(())
+}
+
+fn run_prune() -> Result<()> {
+    let repo = GitRepo::open()?;
+    let git_dir = repo.common_git_dir()?;
+    let stack = Stack::load(&repo)?;
+    let tracked: Vec<String> = stack.branches.keys().cloned().collect();
+    let removed = CiCache::prune(&git_dir, &tracked)?;
+    println!(
+        "{} Pruned cached state for {} untracked {}",
+        "✓".green(),
+        removed,
+        if removed == 1 { "branch" } else { "branches" }
+    );
+    Ok(())
+}
@@ -6,7 +6,12 @@ use anyhow::Result;
 use colored::Colorize;
 use std::process::Command;
 
-pub fn run(no_pr: bool, no_submit: bool, auto_stash_pop: bool) -> Result<()> {
+pub fn run(
+    no_pr: bool,
+    no_submit: bool,
+    auto_stash_pop: bool,
+    request_timeout: Option<u64>,
+) -> Result<()> {
     let repo = GitRepo::open()?;
     let original = repo.current_branch()?;
     let workdir = repo.workdir()?.to_path_buf();
@@ -30,7 +35,10 @@ pub fn run(no_pr: bool, no_submit: bool, auto_stash_pop: bool) -> Result<()> {
         true,
         true,
         auto_stash_pop,
+        false,
+        crate::application::RestackStrategy::Rebase,
         commands::restack::SubmitAfterRestack::No,
+        request_timeout,
     )?;
 
     if repo.rebase_in_progress()? {
@@ -46,6 +54,7 @@ pub fn run(no_pr: bool, no_submit: bool, auto_stash_pop: bool) -> Result<()> {
                 no_pr,
                 yes: true,
                 no_prompt: true,
+                request_timeout,
                 ..Default::default()
             },
         )?;
@@ -199,6 +199,7 @@ pub fn run(
     parent: bool,
     child: Option<usize>,
     shell_output: bool,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let workdir = repo.workdir()?.to_path_buf();
@@ -206,14 +207,14 @@ pub fn run(
 
     // Handle explicit --pr flag
     if let Some(pr_num) = pr {
-        return checkout_by_pr(&repo, pr_num, shell_output);
+        return checkout_by_pr(&repo, pr_num, shell_output, request_timeout);
     }
 
     // Parse "#123" from branch string
     if let Some(ref branch_str) = branch
         && let Some(pr_num) = parse_pr_number(branch_str)?
     {
-        return checkout_by_pr(&repo, pr_num, shell_output);
+        return checkout_by_pr(&repo, pr_num, shell_output, request_timeout);
     }
 
     if branch.is_some() && (trunk || parent || child.is_some()) {
@@ -726,8 +727,13 @@ fn parse_pr_number(input: &str) -> Result<Option<u64>> {
 }
 
 /// Checkout branch by PR number
-fn checkout_by_pr(repo: &GitRepo, pr_num: u64, shell_output: bool) -> Result<()> {
-    let config = Config::load()?;
+fn checkout_by_pr(
+    repo: &GitRepo,
+    pr_num: u64,
+    shell_output: bool,
+    request_timeout: Option<u64>,
+) -> Result<()> {
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let remote_info = crate::remote::RemoteInfo::from_repo(repo, &config)?;
 
     // Get PR info including head branch
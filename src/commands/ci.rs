@@ -1,4 +1,4 @@
-use crate::cache::CiCache;
+use crate::cache::{CachedCheck, CiCache};
 use crate::ci::{CheckRunInfo, history};
 use crate::config::Config;
 use crate::engine::Stack;
@@ -12,6 +12,7 @@ use chrono::{DateTime, Utc};
 use colored::Colorize;
 use futures_util::{StreamExt, stream};
 use serde::Serialize;
+use std::collections::HashMap;
 use std::io::Write;
 use std::path::PathBuf;
 use std::time::Duration;
@@ -232,14 +233,16 @@ pub fn run(
     alert_arg: Option<CiAlertSoundArg>,
     no_alert: bool,
     strict: bool,
+    notify: bool,
     interval: u64,
     verbose: bool,
     oneline: bool,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack_data = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
 
     let remote_info = RemoteInfo::from_repo(&repo, &config).ok();
 
@@ -273,13 +276,15 @@ pub fn run(
         anyhow::bail!("Could not determine remote info. Check that a git remote is configured.");
     };
 
-    if crate::forge::forge_token(remote.forge).is_none() {
+    if !crate::forge::forge_auth_configured(remote.forge, &remote.host) {
         anyhow::bail!(
             "{} auth not configured.\n\
              Set the appropriate token for your forge:\n  \
              - GitHub: `stax auth`, `stax auth --from-gh`, or set `STAX_GITHUB_TOKEN`\n  \
              - GitLab: `stax auth`, or set `STAX_GITLAB_TOKEN`, `GITLAB_TOKEN`, or `STAX_FORGE_TOKEN`\n  \
-             - Gitea:  `stax auth`, or set `STAX_GITEA_TOKEN`, `GITEA_TOKEN`, or `STAX_FORGE_TOKEN`",
+             - Gitea:  `stax auth`, or set `STAX_GITEA_TOKEN`, `GITEA_TOKEN`, or `STAX_FORGE_TOKEN`\n  \
+             - Bitbucket: `stax auth`, or set `STAX_BITBUCKET_TOKEN`, `BITBUCKET_TOKEN`, or `STAX_FORGE_TOKEN`\n  \
+             - Azure DevOps: `stax auth`, or set `STAX_AZURE_DEVOPS_TOKEN`, `AZURE_DEVOPS_EXT_PAT`, or `STAX_FORGE_TOKEN`",
             remote.forge
         );
     }
@@ -304,6 +309,7 @@ pub fn run(
             oneline,
             alert,
             strict,
+            notify || config.notify.desktop,
         );
     }
 
@@ -342,12 +348,27 @@ pub(crate) async fn fetch_ci_statuses_async(
         })
         .collect();
 
+    // One GraphQL round trip covers every PR in the stack on GitHub; branches
+    // missing from the batch fall back to the per-PR REST lookups below.
+    let batched_statuses = match client {
+        ForgeClient::GitHub(_) => {
+            let pr_numbers: Vec<u64> = prepared.iter().filter_map(|(_, _, n)| *n).collect();
+            if pr_numbers.is_empty() {
+                HashMap::new()
+            } else {
+                client
+                    .get_pr_merge_statuses(&pr_numbers)
+                    .await
+                    .unwrap_or_default()
+            }
+        }
+        _ => HashMap::new(),
+    };
+    let batched_statuses = &batched_statuses;
+
     let mut statuses = stream::iter(prepared.iter().map(
         |(branch, local_sha, pr_number)| async move {
-            let pr_merge_status = match (client, pr_number) {
-                (ForgeClient::GitHub(_), Some(n)) => client.get_pr_merge_status(*n).await.ok(),
-                _ => None,
-            };
+            let pr_merge_status = pr_number.and_then(|n| batched_statuses.get(&n).cloned());
             let (pr_live, fallback_head_sha) = match (pr_number, pr_merge_status.is_some()) {
                 (Some(n), false) => {
                     let (pr, head_sha) =
@@ -1232,6 +1253,7 @@ fn run_watch_mode(
     oneline: bool,
     alert: Option<CiAlertSounds>,
     strict: bool,
+    notify: bool,
 ) -> Result<()> {
     let poll_duration = Duration::from_secs(interval);
     let mut iteration = 0;
@@ -1316,6 +1338,13 @@ fn run_watch_mode(
                 CiAlertOutcome::Success
             };
             play_ci_alert(alert.as_ref(), alert_outcome);
+            if iteration > 1 {
+                notifications::send_wait_notification(
+                    "stax ci",
+                    &ci_finished_message(&statuses),
+                    notify,
+                );
+            }
             return Ok(());
         }
 
@@ -1330,6 +1359,17 @@ fn run_watch_mode(
     }
 }
 
+/// One-line summary of finished CI for a desktop notification.
+fn ci_finished_message(statuses: &[BranchCiStatus]) -> String {
+    match statuses
+        .iter()
+        .find(|s| s.overall_status.as_deref() == Some("failure"))
+    {
+        Some(failed) => format!("CI failed on {}", failed.branch),
+        None => "All CI checks passed".to_string(),
+    }
+}
+
 /// Format duration in seconds to human-readable string
 fn format_duration(secs: u64) -> String {
     match secs {
@@ -1379,8 +1419,23 @@ pub(crate) fn update_ci_cache(repo: &GitRepo, stack: &Stack, statuses: &[BranchC
             )
         })
         .collect::<Vec<_>>();
+    let checks = statuses
+        .iter()
+        .map(|status| {
+            let checks = status
+                .check_runs
+                .iter()
+                .map(|check| CachedCheck {
+                    name: check.name.clone(),
+                    status: check.status.clone(),
+                    conclusion: check.conclusion.clone(),
+                })
+                .collect();
+            (status.branch.clone(), checks)
+        })
+        .collect::<HashMap<_, _>>();
     let valid_branches: Vec<String> = stack.branches.keys().cloned().collect();
-    let _ = CiCache::refresh_branches(&cache_dir, &updates, &valid_branches);
+    let _ = CiCache::refresh_branches(&cache_dir, &updates, &checks, &valid_branches);
 }
 
 // --- Small helpers ---
@@ -1428,8 +1483,13 @@ fn check_sort_key(c: &CheckRunInfo) -> u8 {
 
 /// Icon and label string for a check in verbose mode
 fn check_icon_label(check: &CheckRunInfo) -> (String, String) {
-    match check.status.as_str() {
-        "completed" => match check.conclusion.as_deref() {
+    check_state_icon_label(&check.status, check.conclusion.as_deref())
+}
+
+/// Icon and label for a check's raw status/conclusion pair.
+pub(crate) fn check_state_icon_label(status: &str, conclusion: Option<&str>) -> (String, String) {
+    match status {
+        "completed" => match conclusion {
             Some("success") => ("✓".green().to_string(), "passed".green().to_string()),
             Some("failure") => (
                 "✗".red().bold().to_string(),
@@ -1449,7 +1509,7 @@ fn check_icon_label(check: &CheckRunInfo) -> (String, String) {
         "queued" | "waiting" | "requested" => ("◎".cyan().to_string(), "queued".cyan().to_string()),
         "in_progress" => ("●".yellow().to_string(), "running".yellow().to_string()),
         "pending" => ("●".yellow().to_string(), "pending".yellow().to_string()),
-        _ => ("?".dimmed().to_string(), check.status.dimmed().to_string()),
+        _ => ("?".dimmed().to_string(), status.dimmed().to_string()),
     }
 }
 
@@ -11,11 +11,17 @@ use serde::Serialize;
 use termimad::MadSkin;
 
 /// Show comments on the current branch's PR
-pub fn run(plain: bool, stack_scope: bool, all: bool, json: bool) -> Result<()> {
+pub fn run(
+    plain: bool,
+    stack_scope: bool,
+    all: bool,
+    json: bool,
+    request_timeout: Option<u64>,
+) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
 
     if stack_scope || all || json {
         return run_inbox(
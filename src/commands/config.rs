@@ -1,11 +1,96 @@
 use crate::commands::generate;
-use crate::config::Config;
-use anyhow::Result;
+use crate::config::{Config, ConfigSource};
+use crate::git::{GitRepo, refs};
+use anyhow::{Result, bail};
 use colored::Colorize;
 use dialoguer::{Confirm, Select, theme::ColorfulTheme};
 use std::fs;
 use std::io::IsTerminal;
 
+/// `stax config set-trunk <branch>`
+pub fn run_set_trunk(branch: &str) -> Result<()> {
+    let repo = GitRepo::open()?;
+    if repo
+        .inner()
+        .find_branch(branch, git2::BranchType::Local)
+        .is_err()
+    {
+        bail!(
+            "Branch '{}' does not exist locally. Create it first or check the name.",
+            branch
+        );
+    }
+
+    refs::write_explicit_trunk(repo.inner(), branch)?;
+    refs::write_trunk(repo.inner(), branch)?;
+    println!(
+        "Trunk pinned to '{}' {}",
+        branch.cyan(),
+        "(overrides main/master detection)".dimmed()
+    );
+    Ok(())
+}
+
+/// `stax config get [key] [--effective] [--json]`
+pub fn run_get(key: Option<&str>, effective: bool, json: bool) -> Result<()> {
+    let settings: Vec<_> = Config::effective_settings()?
+        .into_iter()
+        .filter(|setting| {
+            key.is_none_or(|key| {
+                setting.key == key
+                    || setting
+                        .key
+                        .strip_prefix(key)
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+        })
+        .collect();
+    if let Some(key) = key
+        && settings.is_empty()
+    {
+        bail!("Unknown config key '{}'", key);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&settings)?);
+        return Ok(());
+    }
+
+    if effective {
+        let layers = Config::layers()?;
+        let global_note = if layers.global_path.exists() {
+            String::new()
+        } else {
+            " (not found)".to_string()
+        };
+        println!(
+            "{} {}{}",
+            "Global config:".blue().bold(),
+            layers.global_path.display(),
+            global_note.dimmed()
+        );
+        match &layers.repo_path {
+            Some(path) => println!("{} {}", "Repo config:".blue().bold(), path.display()),
+            None => println!("{} {}", "Repo config:".blue().bold(), "none".dimmed()),
+        }
+        println!();
+    }
+
+    for setting in &settings {
+        if effective {
+            let source = match setting.source {
+                ConfigSource::Default => setting.source.to_string().dimmed(),
+                ConfigSource::Global => setting.source.to_string().cyan(),
+                ConfigSource::Repo => setting.source.to_string().green(),
+            };
+            println!("{} = {}  ({})", setting.key, setting.value, source);
+        } else {
+            println!("{} = {}", setting.key, setting.value);
+        }
+    }
+    Ok(())
+}
+
 pub fn run(reset_ai: bool, no_prompt: bool, yes: bool, set_ai: bool) -> Result<()> {
     if reset_ai {
         return reset_ai_defaults(no_prompt, yes);
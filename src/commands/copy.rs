@@ -29,7 +29,7 @@ impl CopyTarget {
 /// Resolve the value to copy (branch name or PR URL) without touching the
 /// clipboard. Kept separate from clipboard writing so that value resolution
 /// fails (or succeeds) independently of clipboard availability.
-fn resolve_value(target: CopyTarget) -> Result<String> {
+fn resolve_value(target: CopyTarget, request_timeout: Option<u64>) -> Result<String> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
 
@@ -37,7 +37,7 @@ fn resolve_value(target: CopyTarget) -> Result<String> {
         CopyTarget::Branch => current,
         CopyTarget::Pr => {
             let stack = Stack::load(&repo)?;
-            let config = Config::load()?;
+            let config = Config::load()?.with_request_timeout(request_timeout);
 
             // Check if branch is tracked
             let branch_info = stack.branches.get(&current);
@@ -91,10 +91,10 @@ fn write_to_clipboard(text: &str) -> Result<()> {
 /// resolved value is still printed (with a warning on stderr) and the command
 /// exits successfully, so callers always get the value instead of only a
 /// clipboard backend error.
-pub fn run(target: CopyTarget) -> Result<()> {
+pub fn run(target: CopyTarget, request_timeout: Option<u64>) -> Result<()> {
     // Resolve the value first; this is what the user actually wants and must
     // not be gated behind clipboard availability.
-    let text = resolve_value(target)?;
+    let text = resolve_value(target, request_timeout)?;
     let label = target.label();
 
     match write_to_clipboard(&text) {
@@ -10,6 +10,8 @@ use crate::remote::{self, RemoteInfo};
 use anyhow::{Result, bail};
 use colored::Colorize;
 use dialoguer::{Confirm, theme::ColorfulTheme};
+use serde::Serialize;
+use std::collections::HashSet;
 use std::io::IsTerminal;
 use std::process::Command;
 
@@ -86,7 +88,20 @@ pub fn run(fix: bool) -> Result<()> {
     }
 
     match repo.trunk_branch() {
-        Ok(trunk) => println!("{} {} {}", "✓".green(), "Trunk:".dimmed(), trunk.cyan()),
+        Ok(trunk) => {
+            let source = if repo.explicit_trunk()?.as_deref() == Some(trunk.as_str()) {
+                "(explicit)"
+            } else {
+                "(detected)"
+            };
+            println!(
+                "{} {} {} {}",
+                "✓".green(),
+                "Trunk:".dimmed(),
+                trunk.cyan(),
+                source.dimmed()
+            );
+        }
         Err(err) => {
             println!("{} {} {}", "✗".red(), "Trunk not set:".yellow(), err);
             issues += 1;
@@ -115,7 +130,7 @@ pub fn run(fix: bool) -> Result<()> {
 
     let has_token = remote_info
         .as_ref()
-        .map(|info| forge::forge_token(info.forge).is_some())
+        .map(|info| forge::forge_auth_configured(info.forge, &info.host))
         .unwrap_or_else(|| Config::github_token().is_some());
 
     if has_token {
@@ -125,12 +140,20 @@ pub fn run(fix: bool) -> Result<()> {
             format!("{} API token available", forge_label).dimmed()
         );
     } else {
+        let is_github = remote_info
+            .as_ref()
+            .is_none_or(|info| info.forge == crate::remote::ForgeType::GitHub);
+        let fix = if is_github && Config::gh_token_importable() {
+            "stax auth --from-gh"
+        } else {
+            "stax auth"
+        };
         println!(
             "{} {}",
             "⚠".yellow(),
             format!(
-                "{} API token missing (run `stax auth` — needed for PR/submit against this remote)",
-                forge_label
+                "{} API token missing (run `{}` — needed for PR/submit against this remote)",
+                forge_label, fix
             )
             .yellow()
         );
@@ -254,6 +277,22 @@ pub fn run(fix: bool) -> Result<()> {
             }
         }
 
+        let disconnected = stack.validate(&repo).unwrap_or_default();
+        if !disconnected.is_empty() {
+            issues += 1;
+            println!(
+                "{} {}",
+                "✗".red(),
+                "Branches not connected to trunk:".yellow()
+            );
+            for stray in &disconnected {
+                println!(
+                    "  {} (chain breaks at {} → {})",
+                    stray.branch, stray.broken_at, stray.missing_parent
+                );
+            }
+        }
+
         let needs_restack = stack.needs_restack();
         if !needs_restack.is_empty() {
             println!(
@@ -348,7 +387,7 @@ pub fn run(fix: bool) -> Result<()> {
 
     // Check: stale PR metadata (OPEN PR on a branch that no longer exists locally)
     {
-        let local_branches: std::collections::HashSet<String> = repo
+        let local_branches: HashSet<String> = repo
             .list_branches()
             .unwrap_or_default()
             .into_iter()
@@ -431,6 +470,81 @@ pub fn run(fix: bool) -> Result<()> {
     Ok(())
 }
 
+#[derive(Serialize)]
+struct GraphHealthJson {
+    trunk: String,
+    tracked_branches: usize,
+    /// Branches whose recorded parent revision differs from the parent's tip.
+    drifted_branches: usize,
+    /// Branches with stax metadata but no local ref.
+    missing_refs: usize,
+    /// Share of tracked branches with neither problem, 0–100.
+    health_score: u8,
+    branches: Vec<BranchHealthJson>,
+}
+
+#[derive(Serialize)]
+struct BranchHealthJson {
+    name: String,
+    parent: String,
+    recorded_parent_revision: String,
+    parent_revision: Option<String>,
+    drifted: bool,
+    missing_ref: bool,
+}
+
+/// `stax doctor --json`: compare every branch's metadata against the refs it points at.
+pub fn run_json() -> Result<()> {
+    let repo = GitRepo::open()?;
+    let trunk = repo.trunk_branch()?;
+    let local_branches: HashSet<String> = repo.list_branches()?.into_iter().collect();
+
+    let mut metadata_branches = refs::list_metadata_branches(repo.inner())?;
+    metadata_branches.sort();
+    let mut branches = Vec::new();
+    for name in metadata_branches {
+        let Some(meta) = BranchMetadata::read(repo.inner(), &name)? else {
+            continue;
+        };
+        let parent_revision = repo.branch_commit(&meta.parent_branch_name).ok();
+        let drifted = parent_revision
+            .as_ref()
+            .is_some_and(|tip| *tip != meta.parent_branch_revision);
+        branches.push(BranchHealthJson {
+            missing_ref: !local_branches.contains(&name),
+            name,
+            parent: meta.parent_branch_name,
+            recorded_parent_revision: meta.parent_branch_revision,
+            parent_revision,
+            drifted,
+        });
+    }
+
+    let drifted_branches = branches.iter().filter(|b| b.drifted).count();
+    let missing_refs = branches.iter().filter(|b| b.missing_ref).count();
+    let healthy = branches
+        .iter()
+        .filter(|b| !b.drifted && !b.missing_ref)
+        .count();
+    let report = GraphHealthJson {
+        trunk,
+        tracked_branches: branches.len(),
+        drifted_branches,
+        missing_refs,
+        health_score: health_score(healthy, branches.len()),
+        branches,
+    };
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(())
+}
+
+fn health_score(healthy: usize, total: usize) -> u8 {
+    if total == 0 {
+        return 100;
+    }
+    (healthy * 100 / total) as u8
+}
+
 fn apply_fix_flow(repair_plan: &RepairPlan) -> Result<()> {
     println!();
 
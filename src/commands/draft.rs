@@ -7,10 +7,15 @@ use crate::remote::RemoteInfo;
 use anyhow::Result;
 use colored::Colorize;
 
-pub fn run(branch: Option<String>, stack: bool, is_draft: bool) -> Result<()> {
+pub fn run(
+    branch: Option<String>,
+    stack: bool,
+    is_draft: bool,
+    request_timeout: Option<u64>,
+) -> Result<()> {
     let repo = GitRepo::open()?;
     let stack_data = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let current = repo.current_branch()?;
 
     let branches = if stack {
@@ -128,6 +133,7 @@ fn update_local_pr_metadata(repo: &GitRepo, branch: &str, pr_number: u64, is_dra
                 number: pr_number,
                 state: "open".to_string(),
                 is_draft: Some(is_draft),
+                head_owner: None,
             });
         }
         let _ = meta.write(repo.inner(), branch);
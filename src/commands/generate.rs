@@ -59,6 +59,7 @@ pub fn run(
     model_flag: Option<String>,
     template_flag: Option<String>,
     no_template: bool,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     let artifact_count = [pr_body, pr_title, commit_msg]
         .iter()
@@ -107,8 +108,11 @@ pub fn run(
             model_flag,
             template_flag,
             no_template,
+            request_timeout,
         ),
-        GenerateTarget::PrTitle => generate_pr_title(edit, no_prompt, agent_flag, model_flag),
+        GenerateTarget::PrTitle => {
+            generate_pr_title(edit, no_prompt, agent_flag, model_flag, request_timeout)
+        }
         GenerateTarget::CommitMsg => generate_commit_msg(edit, no_prompt, agent_flag, model_flag),
     }
 }
@@ -120,8 +124,9 @@ fn generate_pr_body(
     model_flag: Option<String>,
     template_flag: Option<String>,
     no_template: bool,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let repo = GitRepo::open()?;
     let workdir = repo.workdir()?.to_path_buf();
     let stack = Stack::load(&repo)?;
@@ -273,8 +278,9 @@ fn generate_pr_title(
     no_prompt: bool,
     agent_flag: Option<String>,
     model_flag: Option<String>,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let repo = GitRepo::open()?;
     let workdir = repo.workdir()?.to_path_buf();
     let stack = Stack::load(&repo)?;
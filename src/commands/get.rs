@@ -21,6 +21,27 @@ pub struct GetOptions {
     pub remote_upstack: bool,
     pub no_restack: bool,
     pub unfrozen: bool,
+    /// For a PR number, also fetch the PRs its base chain points at, down to trunk.
+    pub pr_stack: bool,
+    /// What to do when the requested branch already exists locally and differs
+    /// from its remote (`--force` always resets instead).
+    pub existing: ExistingBranch,
+    /// `stax --timeout` for forge requests.
+    pub request_timeout: Option<u64>,
+}
+
+/// Handling of a requested branch that already exists locally.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExistingBranch {
+    /// Fast-forward it, keep it when it is ahead, or rebase its own commits
+    /// onto the remote (`stax get`).
+    #[default]
+    Update,
+    /// Fast-forward it, but refuse when it has commits the remote lacks
+    /// (`stax pr checkout`).
+    FastForward,
+    /// Refuse unless it already matches the remote (`stax pr checkout --no-ff`).
+    Refuse,
 }
 
 #[derive(Debug, Clone)]
@@ -41,8 +62,7 @@ enum BranchSyncOutcome {
 pub fn run(options: GetOptions) -> Result<()> {
     let repo = GitRepo::open()?;
     let workdir = repo.workdir()?.to_path_buf();
-    let config = Config::load()?;
-    let remote = config.remote_name().to_string();
+    let config = Config::load()?.with_request_timeout(options.request_timeout);
     let trunk = repo.trunk_branch()?;
 
     let Some(requested) = options.branch.as_deref() else {
@@ -59,36 +79,18 @@ pub fn run(options: GetOptions) -> Result<()> {
             false,
             false,
             &[],
+            crate::commands::sync::SyncOptions {
+                request_timeout: options.request_timeout,
+                ..Default::default()
+            },
         );
     };
 
-    let target = resolve_requested_target(&repo, &config, requested, options.parent.as_deref())?;
-    if target.branch == trunk {
-        anyhow::bail!(
-            "'{}' is the trunk branch and cannot be tracked. Use {} to checkout trunk.",
-            target.branch,
-            "stax trunk".cyan()
-        );
-    }
-    let requested_branch = target.branch.clone();
-
-    let stack = Stack::load(&repo)?;
-    let targets = collect_targets(&repo, &workdir, &config, &stack, target, &options, &trunk)?;
-    let mut skipped = Vec::new();
-
-    for target in &targets {
-        let outcome = sync_branch(
-            &repo,
-            &workdir,
-            &remote,
-            target,
-            options.force,
-            options.unfrozen,
-        )?;
-        if outcome != BranchSyncOutcome::Synced {
-            skipped.push(target.branch.clone());
-        }
-    }
+    let FetchedTargets {
+        requested_branch,
+        targets,
+        skipped,
+    } = fetch_targets(&repo, &workdir, &config, &trunk, requested, &options)?;
 
     if !options.no_checkout && !skipped.iter().any(|branch| branch == &requested_branch) {
         checkout::run(
@@ -98,6 +100,7 @@ pub fn run(options: GetOptions) -> Result<()> {
             false,
             None,
             false,
+            options.request_timeout,
         )?;
     } else if options.no_checkout {
         println!("{}", "Skipped checkout (--no-checkout).".dimmed());
@@ -123,7 +126,10 @@ pub fn run(options: GetOptions) -> Result<()> {
                 true,
                 false,
                 false,
+                false,
+                crate::application::RestackStrategy::Rebase,
                 crate::commands::restack::SubmitAfterRestack::No,
+                options.request_timeout,
             )?;
         } else if !worktree_skipped.is_empty() {
             println!(
@@ -137,6 +143,162 @@ pub fn run(options: GetOptions) -> Result<()> {
     Ok(())
 }
 
+/// Refuse before touching any branch when the requested branch exists locally
+/// and `existing` does not allow moving it to the fetched remote head.
+fn check_existing_branch(
+    workdir: &Path,
+    remote: &str,
+    branch: &str,
+    requested: &str,
+    existing: ExistingBranch,
+) -> Result<()> {
+    if !local_branch_exists(workdir, branch)?
+        || !fetch_remote_branch(workdir, remote, branch, true)?
+    {
+        return Ok(());
+    }
+    let remote_ref = format!("{}/{}", remote, branch);
+    if rev_parse(workdir, branch)? == rev_parse(workdir, &remote_ref)? {
+        return Ok(());
+    }
+
+    if is_ancestor(workdir, branch, &remote_ref)? {
+        if existing == ExistingBranch::FastForward {
+            return Ok(());
+        }
+        anyhow::bail!(
+            "Local branch '{}' is behind {} and --no-ff refuses to fast-forward it.\n\
+             Run {} to fast-forward it, or {} to reset it to the PR head.",
+            branch,
+            remote_ref,
+            format!("stax pr checkout {}", requested).cyan(),
+            format!("stax pr checkout {} --force", requested).cyan()
+        );
+    }
+
+    let output = Command::new("git")
+        .args([
+            "rev-list",
+            "--count",
+            &format!("{}..{}", remote_ref, branch),
+        ])
+        .current_dir(workdir)
+        .output()
+        .context("Failed to count local commits")?;
+    let local_only = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    anyhow::bail!(
+        "Local branch '{}' already exists with {} commit(s) that are not on {}.\n\
+         Run {} to reset it to the PR head (dropping those commits), or {} to keep working on it.",
+        branch,
+        local_only,
+        remote_ref,
+        format!("stax pr checkout {} --force", requested).cyan(),
+        format!("stax checkout {}", branch).cyan()
+    );
+}
+
+/// Branches touched while fetching one `get` request.
+struct FetchedTargets {
+    requested_branch: String,
+    targets: Vec<GetTarget>,
+    /// Targets left alone (frozen, missing on the remote, or in another worktree).
+    skipped: Vec<String>,
+}
+
+fn fetch_targets(
+    repo: &GitRepo,
+    workdir: &Path,
+    config: &Config,
+    trunk: &str,
+    requested: &str,
+    options: &GetOptions,
+) -> Result<FetchedTargets> {
+    let remote = config.remote_name();
+    let target = resolve_requested_target(repo, config, requested, options.parent.as_deref())?;
+    if target.branch == trunk {
+        anyhow::bail!(
+            "'{}' is the trunk branch and cannot be tracked. Use {} to checkout trunk.",
+            target.branch,
+            "stax trunk".cyan()
+        );
+    }
+    let requested_branch = target.branch.clone();
+    if !options.force && options.existing != ExistingBranch::Update {
+        check_existing_branch(
+            workdir,
+            remote,
+            &requested_branch,
+            requested,
+            options.existing,
+        )?;
+    }
+
+    let stack = Stack::load(repo)?;
+    let mut targets = if options.pr_stack && target.pr_info.is_some() {
+        collect_pr_base_chain(repo, config, &target, trunk)?
+    } else {
+        Vec::new()
+    };
+    for target in collect_targets(repo, workdir, config, &stack, target, options, trunk)? {
+        if !targets.iter().any(|t| t.branch == target.branch) {
+            targets.push(target);
+        }
+    }
+    let mut skipped = Vec::new();
+
+    for target in &targets {
+        let outcome = sync_branch(
+            repo,
+            workdir,
+            remote,
+            target,
+            options.force,
+            options.unfrozen,
+        )?;
+        if outcome != BranchSyncOutcome::Synced {
+            skipped.push(target.branch.clone());
+        }
+    }
+
+    Ok(FetchedTargets {
+        requested_branch,
+        targets,
+        skipped,
+    })
+}
+
+/// Fetch and track PR `number`'s head branch (plus its tracked ancestors) without
+/// checking it out or restacking. A local copy is only fast-forwarded; local
+/// commits the PR lacks make this refuse. Returns the head branch name.
+pub(crate) fn fetch_pr_branch(number: u64, request_timeout: Option<u64>) -> Result<String> {
+    let repo = GitRepo::open()?;
+    let workdir = repo.workdir()?.to_path_buf();
+    let config = Config::load()?.with_request_timeout(request_timeout);
+    let trunk = repo.trunk_branch()?;
+    let options = GetOptions {
+        branch: Some(number.to_string()),
+        parent: None,
+        no_checkout: true,
+        force: false,
+        downstack: true,
+        remote_upstack: false,
+        no_restack: true,
+        unfrozen: false,
+        pr_stack: false,
+        existing: ExistingBranch::FastForward,
+        request_timeout,
+    };
+    let fetched = fetch_targets(
+        &repo,
+        &workdir,
+        &config,
+        &trunk,
+        &number.to_string(),
+        &options,
+    )?;
+    Ok(fetched.requested_branch)
+}
+
 fn sync_branch(
     repo: &GitRepo,
     workdir: &Path,
@@ -283,6 +445,7 @@ fn resolve_requested_target(
                 number: pr.info.number,
                 state: pr.info.state,
                 is_draft: Some(pr.info.is_draft),
+                head_owner: None,
             }),
         });
     }
@@ -346,6 +509,51 @@ fn collect_targets(
     Ok(targets)
 }
 
+/// Follow PR bases from `target` down to trunk, returning the PRs below it bottom-first.
+fn collect_pr_base_chain(
+    repo: &GitRepo,
+    config: &Config,
+    target: &GetTarget,
+    trunk: &str,
+) -> Result<Vec<GetTarget>> {
+    let remote_info = RemoteInfo::from_repo(repo, config)?;
+    let runtime = tokio::runtime::Runtime::new().context("Failed to create async runtime")?;
+    let _enter = runtime.enter();
+    let client = ForgeClient::new(&remote_info)?;
+
+    let mut chain = Vec::new();
+    let mut seen = HashSet::from([target.branch.clone()]);
+    let mut base = target.parent.clone();
+    while base != trunk {
+        if !seen.insert(base.clone()) {
+            anyhow::bail!("PR base chain loops back to '{}'", base);
+        }
+        let pr = runtime
+            .block_on(client.find_open_pr_by_head(&base))
+            .with_context(|| format!("Failed to look up the PR for '{}'", base))?
+            .with_context(|| {
+                format!(
+                    "PR base chain stops at '{}': no open PR uses it as head branch",
+                    base
+                )
+            })?;
+        chain.push(GetTarget {
+            branch: base.clone(),
+            parent: pr.info.base.clone(),
+            required_remote: true,
+            pr_info: Some(PrInfo {
+                number: pr.info.number,
+                state: pr.info.state,
+                is_draft: Some(pr.info.is_draft),
+                head_owner: None,
+            }),
+        });
+        base = pr.info.base;
+    }
+    chain.reverse();
+    Ok(chain)
+}
+
 fn add_existing_stack_target(
     repo: &GitRepo,
     targets: &mut Vec<GetTarget>,
@@ -409,6 +617,7 @@ fn add_remote_upstack_targets(
                         number: pr.info.number,
                         state: pr.info.state.clone(),
                         is_draft: Some(pr.info.is_draft),
+                        head_owner: None,
                     }),
                 },
             );
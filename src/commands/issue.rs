@@ -12,9 +12,9 @@ const TITLE_MIN_WIDTH: usize = 24;
 const LABELS_MIN_WIDTH: usize = 12;
 const LABELS_MAX_WIDTH: usize = 24;
 
-pub fn run_list(limit: u8, json: bool) -> Result<()> {
+pub fn run_list(limit: u8, json: bool, request_timeout: Option<u64>) -> Result<()> {
     let repo = GitRepo::open()?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let remote_info = RemoteInfo::from_repo(&repo, &config)?;
     let repo_label = format!("{}/{}", remote_info.namespace, remote_info.repo);
 
@@ -1,6 +1,8 @@
-use crate::cache::CiCache;
-use crate::config::Config;
-use crate::engine::Stack;
+use crate::cache::{CachedCheck, CiCache};
+use crate::commands::ci::check_state_icon_label;
+use crate::commands::stack_palette::{TreeGlyphs, tree_glyphs};
+use crate::config::{Config, TreeStyle};
+use crate::engine::{BranchMetadata, Stack};
 use crate::git::GitRepo;
 use crate::remote::{self, RemoteInfo};
 use anyhow::Result;
@@ -48,6 +50,9 @@ struct BranchLogJson {
     behind: usize,
     has_remote: bool,
     age: Option<String>,
+    /// Text saved with `stax branch describe`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
     commits: Vec<CommitJson>,
 }
 
@@ -56,20 +61,34 @@ struct LogJson {
     trunk: String,
     current: String,
     branches: Vec<BranchLogJson>,
+    /// Most recent trunk commits, newest first (`--include-trunk-commits`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trunk_commits: Option<Vec<CommitJson>>,
+    /// With `--offline`, the time the cached PR/CI state was last refreshed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stale_as_of: Option<String>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     json: bool,
     stack_filter: Option<String>,
     current_only: bool,
     compact: bool,
+    only_changed: bool,
+    include_trunk_commits: Option<usize>,
+    show_checks: bool,
     quiet: bool,
+    tree_style: Option<TreeStyle>,
+    offline: bool,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
     let workdir = repo.workdir()?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
+    let glyphs = tree_glyphs(tree_style.unwrap_or(config.ui.tree_style));
     let has_tracked = stack.branches.len() > 1;
     let cache_dir = repo.common_git_dir()?;
 
@@ -134,13 +153,32 @@ pub fn run(
         next_column = max_column + 1;
     }
 
+    // --only-changed: drop branches with nothing on top of their parent (the
+    // current branch always stays). Survivors keep their columns, so the tree
+    // stays connected around the gaps.
+    let mut hidden_empty = 0;
+    if only_changed {
+        let before = display_branches.len();
+        display_branches.retain(|db| {
+            db.name == current
+                || stack
+                    .branches
+                    .get(&db.name)
+                    .and_then(|b| b.parent.as_deref())
+                    .and_then(|parent| repo.commits_ahead_behind(parent, &db.name).ok())
+                    .is_none_or(|(ahead, _)| ahead > 0)
+        });
+        hidden_empty = before - display_branches.len();
+    }
+
     let tree_target_width = (max_column + 1) * 2;
 
     let mut ordered_branches: Vec<String> =
         display_branches.iter().map(|b| b.name.clone()).collect();
     ordered_branches.push(stack.trunk.clone());
 
-    // Load CI cache (refresh happens in `stax ci`)
+    // Load CI cache. No forge calls here: `stax ci` and `stax watch` refresh
+    // it with one batched lookup for the whole stack.
     let cache = CiCache::load(&cache_dir);
 
     // Build CI states from cache
@@ -154,6 +192,20 @@ pub fn run(
         })
         .collect();
 
+    // Individual checks, also from cache; `stax ci` is what refreshes them
+    let branch_checks: HashMap<String, Vec<CachedCheck>> = if show_checks {
+        ordered_branches
+            .iter()
+            .filter_map(|branch| {
+                let revision = repo.branch_commit(branch).ok()?;
+                let checks = cache.get_checks_for_revision(branch, &revision);
+                (!checks.is_empty()).then(|| (branch.clone(), checks.to_vec()))
+            })
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
     let mut branch_logs: Vec<BranchLogJson> = Vec::new();
     let mut branch_log_map: HashMap<String, BranchLogJson> = HashMap::new();
 
@@ -184,6 +236,10 @@ pub fn run(
             .collect::<Vec<_>>();
 
         let age = repo.branch_age(name).ok();
+        let description = BranchMetadata::read(repo.inner(), name)
+            .ok()
+            .flatten()
+            .and_then(|meta| meta.description);
 
         let entry = BranchLogJson {
             name: name.clone(),
@@ -200,6 +256,7 @@ pub fn run(
             behind,
             has_remote: remote_branches.contains(name),
             age,
+            description,
             commits,
         };
 
@@ -207,17 +264,35 @@ pub fn run(
         branch_logs.push(entry);
     }
 
+    let trunk_commits = include_trunk_commits.map(|limit| {
+        repo.recent_commits(&stack.trunk, limit)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| CommitJson {
+                short_hash: c.short_hash,
+                message: c.message,
+            })
+            .collect::<Vec<_>>()
+    });
+
     if json {
         let output = LogJson {
             trunk: stack.trunk.clone(),
             current: current.clone(),
             branches: branch_logs,
+            trunk_commits,
+            stale_as_of: offline
+                .then(|| cache.refreshed_at().map(|at| at.to_rfc3339()))
+                .flatten(),
         };
         println!("{}", serde_json::to_string_pretty(&output)?);
         return Ok(());
     }
 
     if compact {
+        if offline && !quiet {
+            eprintln!("{}", cache.stale_notice().dimmed());
+        }
         for entry in &branch_logs {
             let parent = entry.parent.clone().unwrap_or_default();
             let pr_state = entry.pr_state.clone().unwrap_or_default();
@@ -245,6 +320,32 @@ pub fn run(
         return Ok(());
     }
 
+    // Trunk context goes first, fenced off so it does not read as a branch
+    if let Some(commits) = &trunk_commits {
+        println!(
+            "{}",
+            format!(
+                "── {} · last {} {} ──",
+                stack.trunk,
+                commits.len(),
+                if commits.len() == 1 {
+                    "commit"
+                } else {
+                    "commits"
+                }
+            )
+            .dimmed()
+        );
+        for commit in commits {
+            println!(
+                "   {} {}",
+                commit.short_hash.bright_yellow(),
+                commit.message.white()
+            );
+        }
+        println!("{}", "──".dimmed());
+    }
+
     // Render each branch
     for (i, db) in display_branches.iter().enumerate() {
         let branch = &db.name;
@@ -266,17 +367,21 @@ pub fn run(
 
         for col in 0..=db.column {
             if col == db.column {
-                let circle = if is_current { "◉" } else { "○" };
+                let circle = if is_current {
+                    glyphs.current
+                } else {
+                    glyphs.node
+                };
                 tree.push_str(&format!("{}", circle.color(color)));
                 visual_width += 1;
 
                 if needs_corner {
-                    tree.push_str(&format!("{}", "─┘".color(color)));
+                    tree.push_str(&format!("{}", glyphs.corner.color(color)));
                     visual_width += 2;
                 }
             } else {
                 let line_color = DEPTH_COLORS[col % DEPTH_COLORS.len()];
-                tree.push_str(&format!("{} ", "│".color(line_color)));
+                tree.push_str(&format!("{} ", glyphs.vertical.color(line_color)));
                 visual_width += 2;
             }
         }
@@ -350,7 +455,15 @@ pub fn run(
         // Show commits for this branch
         if let Some(entry) = branch_log_map.get(branch) {
             let detail_prefix =
-                build_detail_prefix(&display_branches, i, tree_target_width, max_column);
+                build_detail_prefix(&display_branches, i, tree_target_width, max_column, glyphs);
+
+            if let Some(summary) = entry
+                .description
+                .as_deref()
+                .and_then(|description| description.lines().next())
+            {
+                println!("{}   {}", detail_prefix, summary.italic());
+            }
 
             if let Some(ref age) = entry.age {
                 println!("{}   {}", detail_prefix, age.dimmed());
@@ -364,6 +477,23 @@ pub fn run(
                     commit.message.white()
                 );
             }
+
+            if show_checks && entry.pr_number.is_some() {
+                match branch_checks.get(branch) {
+                    Some(checks) => {
+                        for check in checks {
+                            let (icon, label) =
+                                check_state_icon_label(&check.status, check.conclusion.as_deref());
+                            println!("{}   {} {} {}", detail_prefix, icon, check.name, label);
+                        }
+                    }
+                    None => println!(
+                        "{}   {}",
+                        detail_prefix,
+                        "no cached checks (run `stax ci`)".dimmed()
+                    ),
+                }
+            }
         }
     }
 
@@ -374,16 +504,20 @@ pub fn run(
     let mut trunk_tree = String::new();
     let mut trunk_visual_width = 0;
 
-    let trunk_circle = if is_trunk_current { "◉" } else { "○" };
+    let trunk_circle = if is_trunk_current {
+        glyphs.current
+    } else {
+        glyphs.node
+    };
     trunk_tree.push_str(&format!("{}", trunk_circle.color(trunk_color)));
     trunk_visual_width += 1;
 
     if max_column >= 1 {
         for col in 1..=max_column {
             if col < max_column {
-                trunk_tree.push_str(&format!("{}", "─┴".color(trunk_color)));
+                trunk_tree.push_str(&format!("{}", glyphs.tee.color(trunk_color)));
             } else {
-                trunk_tree.push_str(&format!("{}", "─┘".color(trunk_color)));
+                trunk_tree.push_str(&format!("{}", glyphs.corner.color(trunk_color)));
             }
             trunk_visual_width += 2;
         }
@@ -413,7 +547,9 @@ pub fn run(
         if let Some(ref age) = entry.age {
             println!("{}   {}", trunk_detail_prefix, age.dimmed());
         }
-        for commit in entry.commits.iter().take(3) {
+        // Already listed above the stack with --include-trunk-commits
+        let shown = if trunk_commits.is_some() { 0 } else { 3 };
+        for commit in entry.commits.iter().take(shown) {
             println!(
                 "{}   {} {}",
                 trunk_detail_prefix,
@@ -423,6 +559,26 @@ pub fn run(
         }
     }
 
+    if hidden_empty > 0 && !quiet {
+        println!(
+            "{}",
+            format!(
+                "({} empty {} hidden)",
+                hidden_empty,
+                if hidden_empty == 1 {
+                    "branch"
+                } else {
+                    "branches"
+                }
+            )
+            .dimmed()
+        );
+    }
+
+    if offline && !quiet {
+        println!("{}", cache.stale_notice().dimmed());
+    }
+
     if !has_tracked && !quiet {
         println!(
             "{}",
@@ -470,6 +626,7 @@ fn build_detail_prefix(
     current_idx: usize,
     tree_target_width: usize,
     _max_column: usize,
+    glyphs: TreeGlyphs,
 ) -> String {
     let current_col = display_branches[current_idx].column;
     let mut prefix = String::new();
@@ -477,7 +634,7 @@ fn build_detail_prefix(
 
     for col in 0..=current_col {
         let line_color = DEPTH_COLORS[col % DEPTH_COLORS.len()];
-        prefix.push_str(&format!("{} ", "│".color(line_color)));
+        prefix.push_str(&format!("{} ", glyphs.vertical.color(line_color)));
         visual_width += 2;
     }
 
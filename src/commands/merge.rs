@@ -3,22 +3,31 @@ use crate::commands::merge_rebase::{
 };
 use crate::commands::merge_shared::{
     BlockedReasonStyle, PrBaseUpdate, WaitResult, blocked_reason, print_native_stack_locked_note,
-    rebase_and_finalize_remaining_branch, record_ci_history_for_branch, sync_head_after_push,
-    update_pr_base_unless_current, wait_for_pr_ready,
+    push_with_lease, rebase_and_finalize_remaining_branch, record_ci_history_for_branch,
+    recorded_remote_oid, resolve_merge_method, sync_head_after_push, update_pr_base_unless_current,
+    wait_for_pr_ready,
 };
 use crate::config::Config;
 use crate::engine::Stack;
 use crate::forge::ForgeClient;
-use crate::git::{GitRepo, RebaseResult};
-use crate::github::pr::{MergeMethod, PrMergeStatus};
-use crate::progress::LiveTimer;
+use crate::git::{ConflictPrediction, GitRepo, RebaseResult};
+use crate::github::pr::{CiStatus, MergeMethod, PrMergeStatus};
+use crate::progress::{LiveTimer, StepProgress};
 use crate::remote::RemoteInfo;
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use colored::Colorize;
-use std::io::Write;
+use dialoguer::{Confirm, theme::ColorfulTheme};
+use futures_util::{StreamExt, stream};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{IsTerminal, Write};
 use std::path::Path;
 use std::process::Command;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// How many times a PR that conflicts with a trunk that moved mid-merge is
+/// rebased and re-checked before the merge gives up on it.
+const MAX_BASE_MOVE_RETRIES: usize = 3;
 
 /// Information about a branch in the merge scope
 #[derive(Debug, Clone)]
@@ -45,51 +54,194 @@ struct MergeScope {
     downstack_only: bool,
 }
 
+/// One merged PR in the `--timeline` summary
+#[derive(Debug, Clone)]
+struct TimelineEntry {
+    branch: String,
+    pr_number: u64,
+    /// Remote trunk tip right after the merge (`None` if the PR was already merged)
+    trunk_sha: Option<String>,
+    /// Time since the merge started
+    elapsed: Duration,
+}
+
+/// `merge --dry-run --json` output
+#[derive(Serialize)]
+struct MergePlanJson {
+    trunk: String,
+    current: String,
+    method: &'static str,
+    downstack_only: bool,
+    /// Branches that would be merged, bottom first
+    to_merge: Vec<MergePlanBranchJson>,
+    /// Tracked branches above the merge scope that stay open
+    remaining: Vec<MergePlanBranchJson>,
+}
+
+#[derive(Serialize)]
+struct MergePlanBranchJson {
+    position: usize,
+    branch: String,
+    is_current: bool,
+    pr_number: Option<u64>,
+    /// `None` when the PR status could not be fetched
+    readiness: Option<MergeReadinessJson>,
+}
+
+#[derive(Serialize)]
+struct MergeReadinessJson {
+    ready: bool,
+    status: &'static str,
+    state: String,
+    ci: &'static str,
+    review_decision: Option<String>,
+    approvals: usize,
+    changes_requested: bool,
+    mergeable: Option<bool>,
+    is_draft: bool,
+}
+
+impl MergePlanBranchJson {
+    fn from_info(info: &MergeBranchInfo) -> Self {
+        Self {
+            position: info.position,
+            branch: info.branch.clone(),
+            is_current: info.is_current,
+            pr_number: info.pr_number,
+            readiness: info.pr_status.as_ref().map(|status| MergeReadinessJson {
+                ready: status.is_ready(),
+                status: status.status_text(),
+                state: status.state.clone(),
+                ci: match status.ci_status {
+                    CiStatus::Success => "success",
+                    CiStatus::Pending => "pending",
+                    CiStatus::Failure => "failure",
+                    CiStatus::NoCi => "no_ci",
+                },
+                review_decision: status.review_decision.clone(),
+                approvals: status.approvals,
+                changes_requested: status.changes_requested,
+                mergeable: status.mergeable,
+                is_draft: status.is_draft,
+            }),
+        }
+    }
+}
+
+fn merge_plan_json(scope: &MergeScope, method: &MergeMethod) -> MergePlanJson {
+    MergePlanJson {
+        trunk: scope.trunk.clone(),
+        current: scope.current.clone(),
+        method: method.as_str(),
+        downstack_only: scope.downstack_only,
+        to_merge: scope
+            .to_merge
+            .iter()
+            .map(MergePlanBranchJson::from_info)
+            .collect(),
+        remaining: scope
+            .remaining
+            .iter()
+            .map(MergePlanBranchJson::from_info)
+            .collect(),
+    }
+}
+
+/// A branch whose local tip is not the commit its PR would merge
+#[derive(Debug, Clone)]
+struct HeadMismatch {
+    branch: String,
+    pr_number: u64,
+    local_sha: String,
+    pr_head_sha: String,
+    /// Whether the PR head commit exists locally. When it does, the local
+    /// branch was rewritten or extended without being pushed.
+    pr_head_known: bool,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn run(
     all: bool,
     downstack_only: bool,
+    only: Option<&str>,
+    until: Option<&str>,
     dry_run: bool,
-    method: MergeMethod,
+    json: bool,
+    method: Option<MergeMethod>,
     no_delete: bool,
     no_wait: bool,
     timeout_mins: u64,
     no_sync: bool,
+    update_trunk: bool,
+    timeline: bool,
+    force: bool,
+    admin: bool,
     yes: bool,
     quiet: bool,
+    request_timeout: Option<u64>,
+    remote: Option<String>,
+    notify: bool,
 ) -> Result<()> {
+    // Keep stdout to the JSON document
+    let quiet = quiet || json;
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(request_timeout)
+        .with_remote(remote.clone());
 
-    // Check if we're on a tracked branch
-    if current == stack.trunk {
-        if !quiet {
-            println!(
-                "{}",
-                "You are on trunk. Checkout a branch in a stack to merge.".yellow()
+    let mut scope = if let Some(only) = only {
+        if only == stack.trunk {
+            bail!("'{}' is trunk. Pass a branch in a stack to --only.", only);
+        }
+        if !stack.branches.contains_key(only) {
+            bail!(
+                "Branch '{}' is not tracked. Run 'stax branch track' first.",
+                only
             );
         }
-        return Ok(());
-    }
-
-    if !stack.branches.contains_key(&current) {
-        if !quiet {
-            println!(
-                "{}",
-                format!(
-                    "Branch '{}' is not tracked. Run 'stax branch track' first.",
-                    current
-                )
-                .yellow()
+        calculate_only_merge_scope(&stack, &current, only)
+    } else if let Some(until) = until {
+        if until == stack.trunk {
+            bail!("'{}' is trunk. Pass a branch in a stack to --until.", until);
+        }
+        if !stack.branches.contains_key(until) {
+            bail!(
+                "Branch '{}' is not tracked. Run 'stax branch track' first.",
+                until
             );
         }
-        return Ok(());
-    }
+        calculate_merge_scope(&stack, &current, Some(until), false, false)
+    } else {
+        // Check if we're on a tracked branch
+        if current == stack.trunk {
+            if !quiet {
+                println!(
+                    "{}",
+                    "You are on trunk. Checkout a branch in a stack to merge.".yellow()
+                );
+            }
+            return Ok(());
+        }
 
-    // Calculate merge scope based on current position
-    let mut scope = calculate_merge_scope(&stack, &current, all, downstack_only);
+        if !stack.branches.contains_key(&current) {
+            if !quiet {
+                println!(
+                    "{}",
+                    format!(
+                        "Branch '{}' is not tracked. Run 'stax branch track' first.",
+                        current
+                    )
+                    .yellow()
+                );
+            }
+            return Ok(());
+        }
+
+        // Calculate merge scope based on current position
+        calculate_merge_scope(&stack, &current, None, all, downstack_only)
+    };
 
     if scope.to_merge.is_empty() {
         if !quiet {
@@ -105,17 +257,30 @@ pub fn run(
     // Try to create forge client (may fail if no remote or no token)
     let client = remote_info.as_ref().ok().and_then(|info| {
         let _enter = rt.enter();
-        ForgeClient::new(info).ok()
+        ForgeClient::new(info)
+            .ok()
+            .map(|client| client.with_quiet(quiet))
     });
 
     // For branches missing PR metadata, check the forge for existing PRs
     if let Some(ref client) = client {
-        for branch_info in &mut scope.to_merge {
-            if branch_info.pr_number.is_none()
-                && let Ok(Some(pr_info)) =
-                    rt.block_on(async { client.find_pr(&branch_info.branch).await })
-            {
-                branch_info.pr_number = Some(pr_info.number);
+        let lookups = rt.block_on(
+            stream::iter(
+                scope
+                    .to_merge
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, branch_info)| branch_info.pr_number.is_none())
+                    .map(|(idx, branch_info)| async move {
+                        (idx, client.find_pr(&branch_info.branch).await)
+                    }),
+            )
+            .buffer_unordered(crate::parallel::IO_CONCURRENCY_LIMIT)
+            .collect::<Vec<_>>(),
+        );
+        for (idx, found) in lookups {
+            if let Ok(Some(pr_info)) = found {
+                scope.to_merge[idx].pr_number = Some(pr_info.number);
             }
         }
     }
@@ -142,23 +307,89 @@ pub fn run(
             "Failed to connect to the configured forge. Check your token and remote configuration."
         )
     })?;
+    let method = resolve_merge_method(&rt, &client, method, MergeMethod::Squash)?;
+
+    // Merging out of order is only safe once everything below the branch has landed.
+    if let Some(only) = only {
+        let client = &client;
+        let mut checked = rt.block_on(
+            stream::iter(
+                unmerged_ancestors(&stack, only)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, (ancestor, pr_number))| async move {
+                        let merged = match pr_number {
+                            Some(pr) => client.is_pr_merged(pr).await.unwrap_or(false),
+                            None => false,
+                        };
+                        (idx, ancestor, merged)
+                    }),
+            )
+            .buffer_unordered(crate::parallel::IO_CONCURRENCY_LIMIT)
+            .collect::<Vec<_>>(),
+        );
+        checked.sort_by_key(|(idx, _, _)| *idx);
+        let blocked: Vec<String> = checked
+            .into_iter()
+            .filter(|(_, _, merged)| !merged)
+            .map(|(_, ancestor, _)| ancestor)
+            .collect();
+        if !blocked.is_empty() {
+            bail!(
+                "Cannot merge '{}' on its own: these branches below it are not merged yet:\n  {}\n\nMerge them first, or run 'stax merge' from '{}' to merge the stack up to it.",
+                only,
+                blocked.join("\n  "),
+                only
+            );
+        }
+    }
 
     let fetch_status_timer = LiveTimer::maybe_new(!quiet, "Fetching PR status...");
 
-    // Fetch status for branches to merge
+    // Fetch status for every PR in scope in one batch (remaining branches are
+    // only displayed). Branches to merge that the batch missed are fetched one
+    // by one so a lookup failure still stops the merge.
+    let pr_numbers: Vec<u64> = scope
+        .to_merge
+        .iter()
+        .chain(scope.remaining.iter())
+        .filter_map(|branch_info| branch_info.pr_number)
+        .collect();
+    let mut statuses = rt
+        .block_on(async { client.get_pr_merge_statuses(&pr_numbers).await })
+        .unwrap_or_default();
+    let missed: Vec<u64> = scope
+        .to_merge
+        .iter()
+        .filter_map(|branch_info| branch_info.pr_number)
+        .filter(|pr_num| !statuses.contains_key(pr_num))
+        .collect();
+    let client_ref = &client;
+    let mut fallback =
+        rt.block_on(
+            stream::iter(missed.iter().map(|&pr_num| async move {
+                (pr_num, client_ref.get_pr_merge_status(pr_num).await)
+            }))
+            .buffer_unordered(crate::parallel::IO_CONCURRENCY_LIMIT)
+            .collect::<HashMap<_, _>>(),
+        );
     for branch_info in &mut scope.to_merge {
         if let Some(pr_num) = branch_info.pr_number {
-            let status = rt.block_on(async { client.get_pr_merge_status(pr_num).await })?;
+            let status = match statuses.remove(&pr_num) {
+                Some(status) => status,
+                None => fallback.remove(&pr_num).unwrap_or_else(|| {
+                    Err(anyhow::anyhow!(
+                        "No merge status fetched for PR #{}",
+                        pr_num
+                    ))
+                })?,
+            };
             branch_info.pr_status = Some(status);
         }
     }
-
-    // Fetch status for remaining branches too (for display)
     for branch_info in &mut scope.remaining {
-        if let Some(pr_num) = branch_info.pr_number
-            && let Ok(status) = rt.block_on(async { client.get_pr_merge_status(pr_num).await })
-        {
-            branch_info.pr_status = Some(status);
+        if let Some(pr_num) = branch_info.pr_number {
+            branch_info.pr_status = statuses.remove(&pr_num);
         }
     }
 
@@ -169,14 +400,50 @@ pub fn run(
         print_merge_plan(&scope, &method);
     }
 
+    // The forge merges the PR head, not the local branch: catch local work
+    // that never made it to the PR.
+    let head_mismatches = find_head_mismatches(&repo, &scope);
+    if !head_mismatches.is_empty() {
+        if !quiet {
+            for line in format_head_mismatches(&head_mismatches) {
+                println!("{}", line);
+            }
+        }
+        let unpushed: Vec<_> = head_mismatches
+            .iter()
+            .filter(|mismatch| mismatch.pr_head_known)
+            .map(|mismatch| mismatch.branch.as_str())
+            .collect();
+        if !dry_run && !force && !unpushed.is_empty() {
+            anyhow::bail!(
+                "Local changes are not on the PR for:\n  {}\n\nRun 'stax submit' first to push them, or pass --force to merge the PRs as they are.",
+                unpushed.join("\n  ")
+            );
+        }
+    }
+
     // Dry run - just show plan and exit
     if dry_run {
-        if !quiet {
+        if json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&merge_plan_json(&scope, &method))?
+            );
+        } else if !quiet {
+            let predictions = predict_merge_conflicts(&repo, &scope, &remote_info.name);
+            for line in format_conflict_predictions(&predictions) {
+                println!("{}", line);
+            }
             println!("{}", "  Dry run — no changes made.".dimmed());
         }
         return Ok(());
     }
 
+    if admin && !confirm_admin_override(&rt, &client, &remote_info, scope.to_merge.len(), yes)? {
+        println!("{}", "Aborted.".dimmed());
+        return Ok(());
+    }
+
     // Confirm with user
     if !yes && !quiet {
         print!("Proceed? [Y/n] ");
@@ -194,10 +461,34 @@ pub fn run(
         println!("Merging stack...");
     }
 
+    // With --only the PR may still target a parent that merged since; point it at trunk.
+    if let Some(only) = only
+        && stack.branches.get(only).and_then(|b| b.parent.as_deref()) != Some(&scope.trunk)
+        && let Some(pr_number) = scope.to_merge[0].pr_number
+    {
+        let retarget_timer = LiveTimer::maybe_new(
+            !quiet,
+            &format!("Retargeting #{} to {}...", pr_number, scope.trunk),
+        );
+        match update_pr_base_unless_current(&rt, &client, pr_number, &scope.trunk, only)? {
+            PrBaseUpdate::Updated => LiveTimer::maybe_finish_ok(retarget_timer, "done"),
+            PrBaseUpdate::AlreadyTargeted => {
+                LiveTimer::maybe_finish_ok(retarget_timer, "already on base")
+            }
+            PrBaseUpdate::NativeStackLocked => {
+                LiveTimer::maybe_finish_warn(retarget_timer, "skipped (native Stack)");
+                print_native_stack_locked_note(quiet, pr_number);
+            }
+        }
+    }
+
     let timeout = Duration::from_secs(timeout_mins * 60);
     let mut merged_prs: Vec<(String, u64)> = Vec::new();
     let mut failed_pr: Option<(String, u64, String)> = None;
+    let merge_started = Instant::now();
+    let mut timeline_entries: Vec<TimelineEntry> = Vec::new();
 
+    let mut merge_progress = StepProgress::new(!quiet, scope.to_merge.len());
     for (idx, branch_info) in scope.to_merge.iter().enumerate() {
         let pr_number = branch_info.pr_number.unwrap();
         let next_branch = scope.to_merge.get(idx + 1);
@@ -205,59 +496,122 @@ pub fn run(
         // Check if already merged
         let is_merged = rt.block_on(async { client.is_pr_merged(pr_number).await })?;
         if is_merged {
-            let timer =
-                LiveTimer::maybe_new(!quiet, &format!("#{} {}...", pr_number, branch_info.branch));
+            let timer = merge_progress.start(&format!("#{} {}...", pr_number, branch_info.branch));
             LiveTimer::maybe_finish_ok(timer, "already merged");
             merged_prs.push((branch_info.branch.clone(), pr_number));
+            if timeline {
+                timeline_entries.push(TimelineEntry {
+                    branch: branch_info.branch.clone(),
+                    pr_number,
+                    trunk_sha: None,
+                    elapsed: merge_started.elapsed(),
+                });
+            }
         } else {
             // Wait for CI and approval if needed (kept outside the per-PR spinner
-            // because it can span minutes).
-            if !no_wait {
-                match wait_for_pr_ready(
+            // because it can span minutes). A PR we rebased earlier in this run
+            // can conflict again when trunk moves on in the meantime; rebase it
+            // onto the new trunk and re-check a bounded number of times.
+            let mut base_move_retries = 0;
+            let not_ready = loop {
+                let reason = if admin {
+                    // `--admin` overrides branch protection, so skip the gates.
+                    None
+                } else if !no_wait {
+                    match wait_for_pr_ready(
+                        &rt,
+                        &client,
+                        pr_number,
+                        timeout,
+                        Duration::from_secs(10),
+                        BlockedReasonStyle::Detailed,
+                        quiet,
+                        notify,
+                    )? {
+                        WaitResult::Ready(_) => None,
+                        WaitResult::Failed(reason) => Some(reason),
+                        WaitResult::Timeout => Some("Timeout waiting for CI".to_string()),
+                    }
+                } else {
+                    // Check if ready without waiting
+                    let status =
+                        rt.block_on(async { client.get_pr_merge_status(pr_number).await })?;
+                    if status.is_ready() {
+                        None
+                    } else if status.is_blocked() {
+                        Some(blocked_reason(&status))
+                    } else {
+                        Some(format!("PR not ready: {}", status.status_text()))
+                    }
+                };
+
+                let Some(reason) = reason else {
+                    break None;
+                };
+                if idx == 0 || base_move_retries >= MAX_BASE_MOVE_RETRIES {
+                    break Some(reason);
+                }
+                let status = rt.block_on(async { client.get_pr_merge_status(pr_number).await })?;
+                if !blocked_only_by_conflicts(&status) {
+                    break Some(reason);
+                }
+
+                base_move_retries += 1;
+                if !quiet {
+                    println!(
+                        "  {} #{} conflicts with the updated {}, rebasing again ({}/{})",
+                        "↻".yellow(),
+                        pr_number,
+                        scope.trunk.cyan(),
+                        base_move_retries,
+                        MAX_BASE_MOVE_RETRIES
+                    );
+                }
+                if let Some(failure) = rebase_onto_remote_trunk_and_push(
+                    &repo,
                     &rt,
                     &client,
+                    &remote_info.name,
+                    &scope.trunk,
+                    &branch_info.branch,
                     pr_number,
-                    timeout,
-                    Duration::from_secs(10),
-                    BlockedReasonStyle::Detailed,
                     quiet,
                 )? {
-                    WaitResult::Ready(_) => {}
-                    WaitResult::Failed(reason) => {
-                        failed_pr = Some((branch_info.branch.clone(), pr_number, reason));
-                        break;
-                    }
-                    WaitResult::Timeout => {
-                        failed_pr = Some((
-                            branch_info.branch.clone(),
-                            pr_number,
-                            "Timeout waiting for CI".to_string(),
-                        ));
-                        break;
-                    }
-                }
-            } else {
-                // Check if ready without waiting
-                let status = rt.block_on(async { client.get_pr_merge_status(pr_number).await })?;
-                if !status.is_ready() {
-                    let reason = if status.is_blocked() {
-                        blocked_reason(&status)
-                    } else {
-                        format!("PR not ready: {}", status.status_text())
-                    };
-                    failed_pr = Some((branch_info.branch.clone(), pr_number, reason));
-                    break;
+                    break Some(failure);
                 }
+            };
+            if let Some(reason) = not_ready {
+                failed_pr = Some((branch_info.branch.clone(), pr_number, reason));
+                break;
             }
 
             // Merge the PR
             let merge_timer =
-                LiveTimer::maybe_new(!quiet, &format!("#{} {}...", pr_number, branch_info.branch));
+                merge_progress.start(&format!("#{} {}...", pr_number, branch_info.branch));
 
-            match rt.block_on(async { client.merge_pr(pr_number, method, None, None).await }) {
+            let merged = rt.block_on(async {
+                if admin {
+                    client.admin_merge_pr(pr_number, method).await
+                } else {
+                    client.merge_pr(pr_number, method, None, None).await
+                }
+            });
+            match merged {
                 Ok(()) => {
                     LiveTimer::maybe_finish_ok(merge_timer, "merged");
                     merged_prs.push((branch_info.branch.clone(), pr_number));
+                    if timeline {
+                        timeline_entries.push(TimelineEntry {
+                            branch: branch_info.branch.clone(),
+                            pr_number,
+                            trunk_sha: remote_trunk_sha(
+                                repo.workdir()?,
+                                &remote_info.name,
+                                &scope.trunk,
+                            ),
+                            elapsed: merge_started.elapsed(),
+                        });
+                    }
 
                     // Record CI history for the merged branch
                     record_ci_history_for_branch(&repo, &rt, &client, &stack, &branch_info.branch);
@@ -310,86 +664,19 @@ pub fn run(
         // If there are more PRs, rebase the next one onto trunk.
         if let Some(next_branch) = next_branch {
             let next_pr = next_branch.pr_number.unwrap();
-
-            // Fetch latest from remote
-            let fetch_timer = LiveTimer::maybe_new(!quiet, "Fetching latest...");
-            let fetch_ok = fetch_remote_for_descendant_rebase(&repo, &remote_info.name)?;
-            if !fetch_ok {
-                LiveTimer::maybe_finish_warn(fetch_timer, "warning");
-            } else {
-                LiveTimer::maybe_finish_ok(fetch_timer, "done");
-            }
-
-            // Rebase next branch onto trunk
-            let rebase_timer = LiveTimer::maybe_new(
-                !quiet,
-                &format!("Rebasing {} onto {}...", next_branch.branch, scope.trunk),
-            );
-
-            let rebase_result = rebase_descendant_onto_remote_trunk_with_provenance(
+            if let Some(failure) = rebase_onto_remote_trunk_and_push(
                 &repo,
-                &next_branch.branch,
-                &scope.trunk,
+                &rt,
+                &client,
                 &remote_info.name,
-            )?;
-            match rebase_result {
-                RebaseResult::Success => {
-                    LiveTimer::maybe_finish_ok(rebase_timer, "done");
-                }
-                RebaseResult::Conflict => {
-                    let abort_dir = repo
-                        .branch_worktree_path(&next_branch.branch)?
-                        .unwrap_or(repo.workdir()?.to_path_buf());
-                    let _ = Command::new("git")
-                        .args(["rebase", "--abort"])
-                        .current_dir(&abort_dir)
-                        .output();
-
-                    LiveTimer::maybe_finish_err(rebase_timer, "conflict");
-                    failed_pr = Some((
-                        next_branch.branch.clone(),
-                        next_pr,
-                        "Rebase conflict".to_string(),
-                    ));
-                    break;
-                }
-            }
-
-            // Force push the rebased branch
-            let push_timer =
-                LiveTimer::maybe_new(!quiet, &format!("Pushing {}...", next_branch.branch));
-
-            let push_status = Command::new("git")
-                .args([
-                    "push",
-                    "--force-with-lease",
-                    &remote_info.name,
-                    &next_branch.branch,
-                ])
-                .current_dir(repo.workdir()?)
-                .output()
-                .context("Failed to push")?;
-
-            if !push_status.status.success() {
-                // If the next PR is already merged the push isn't needed — skip the error.
-                let next_is_merged = rt
-                    .block_on(async { client.is_pr_merged(next_pr).await })
-                    .unwrap_or(false);
-                if next_is_merged {
-                    LiveTimer::maybe_finish_ok(push_timer, "skipped (already merged)");
-                } else {
-                    LiveTimer::maybe_finish_err(push_timer, "failed");
-                    failed_pr = Some((
-                        next_branch.branch.clone(),
-                        next_pr,
-                        "Failed to push rebased branch".to_string(),
-                    ));
-                    break;
-                }
-            } else {
-                LiveTimer::maybe_finish_ok(push_timer, "done");
+                &scope.trunk,
+                &next_branch.branch,
+                next_pr,
+                quiet,
+            )? {
+                failed_pr = Some((next_branch.branch.clone(), next_pr, failure));
+                break;
             }
-            sync_head_after_push(&rt, &client, next_pr, &repo, &next_branch.branch);
         }
     }
 
@@ -453,10 +740,11 @@ pub fn run(
             }
         }
 
-        let checkout_after_cleanup = if scope.downstack_only {
-            &scope.current
-        } else {
+        // Stay on the current branch unless it was part of the merge.
+        let checkout_after_cleanup = if scope.to_merge.iter().any(|b| b.is_current) {
             &scope.trunk
+        } else {
+            &scope.current
         };
         let _ = repo.checkout(checkout_after_cleanup);
     }
@@ -477,6 +765,53 @@ pub fn run(
             scope.trunk.cyan()
         );
 
+        if timeline && !timeline_entries.is_empty() {
+            println!();
+            for line in
+                format_merge_timeline(&scope.trunk, &timeline_entries, merge_started.elapsed())
+            {
+                println!("{}", line);
+            }
+        }
+
+        if no_sync && update_trunk {
+            let workdir = repo.workdir()?;
+            match fast_forward_local_trunk(&repo, workdir, config.remote_name(), &scope.trunk) {
+                Ok(TrunkUpdate::FastForwarded) => {
+                    if !quiet {
+                        println!(
+                            "{} Local {} fast-forwarded to {}/{}",
+                            "✓".green(),
+                            scope.trunk.cyan(),
+                            config.remote_name(),
+                            scope.trunk
+                        );
+                    }
+                }
+                Ok(TrunkUpdate::UpToDate) => {}
+                Ok(TrunkUpdate::Diverged) => {
+                    println!(
+                        "{} {}",
+                        "warning:".yellow().bold(),
+                        format!(
+                            "local {} has commits not on {}/{}; not fast-forwarded",
+                            scope.trunk,
+                            config.remote_name(),
+                            scope.trunk
+                        )
+                        .yellow()
+                    );
+                }
+                Err(err) => {
+                    println!(
+                        "{} {}",
+                        "warning:".yellow().bold(),
+                        format!("could not update local {}: {}", scope.trunk, err).yellow()
+                    );
+                }
+            }
+        }
+
         if !no_sync {
             if !quiet {
                 println!();
@@ -518,6 +853,11 @@ pub fn run(
                 false, // verbose
                 false, // auto_stash_pop
                 &[],
+                crate::commands::sync::SyncOptions {
+                    request_timeout,
+                    remote: remote.clone(),
+                    ..Default::default()
+                },
             ) && !quiet
             {
                 println!();
@@ -537,21 +877,147 @@ pub fn run(
     Ok(())
 }
 
+/// Fetch, rebase `branch` onto the remote trunk and push it with a lease.
+/// Returns why it failed, if it did.
+#[allow(clippy::too_many_arguments)]
+fn rebase_onto_remote_trunk_and_push(
+    repo: &GitRepo,
+    rt: &tokio::runtime::Runtime,
+    client: &ForgeClient,
+    remote_name: &str,
+    trunk: &str,
+    branch: &str,
+    pr_number: u64,
+    quiet: bool,
+) -> Result<Option<String>> {
+    // Fetch latest from remote
+    let fetch_timer = LiveTimer::maybe_new(!quiet, "Fetching latest...");
+    let fetch_ok = fetch_remote_for_descendant_rebase(repo, remote_name)?;
+    if !fetch_ok {
+        LiveTimer::maybe_finish_warn(fetch_timer, "warning");
+    } else {
+        LiveTimer::maybe_finish_ok(fetch_timer, "done");
+    }
+
+    // Rebase the branch onto trunk
+    let rebase_timer =
+        LiveTimer::maybe_new(!quiet, &format!("Rebasing {} onto {}...", branch, trunk));
+
+    let expected_remote_oid = recorded_remote_oid(repo, remote_name, branch);
+    let rebase_result =
+        rebase_descendant_onto_remote_trunk_with_provenance(repo, branch, trunk, remote_name)?;
+    match rebase_result {
+        RebaseResult::Success => {
+            LiveTimer::maybe_finish_ok(rebase_timer, "done");
+        }
+        RebaseResult::Conflict => {
+            let abort_dir = repo
+                .branch_worktree_path(branch)?
+                .unwrap_or(repo.workdir()?.to_path_buf());
+            let _ = Command::new("git")
+                .args(["rebase", "--abort"])
+                .current_dir(&abort_dir)
+                .output();
+
+            LiveTimer::maybe_finish_err(rebase_timer, "conflict");
+            return Ok(Some("Rebase conflict".to_string()));
+        }
+    }
+
+    // Force push the rebased branch
+    let push_timer = LiveTimer::maybe_new(!quiet, &format!("Pushing {}...", branch));
+
+    let push_result = push_with_lease(repo, remote_name, branch, expected_remote_oid.as_deref());
+
+    if let Err(push_error) = push_result {
+        // If the PR is already merged the push isn't needed — skip the error.
+        let is_merged = rt
+            .block_on(async { client.is_pr_merged(pr_number).await })
+            .unwrap_or(false);
+        if is_merged {
+            LiveTimer::maybe_finish_ok(push_timer, "skipped (already merged)");
+        } else {
+            LiveTimer::maybe_finish_err(push_timer, "failed");
+            return Ok(Some(format!(
+                "Failed to push rebased branch: {:#}",
+                push_error
+            )));
+        }
+    } else {
+        LiveTimer::maybe_finish_ok(push_timer, "done");
+    }
+    sync_head_after_push(rt, client, pr_number, repo, branch);
+    Ok(None)
+}
+
+/// Whether merge conflicts are the only thing keeping an open PR from merging,
+/// i.e. rebasing it onto the current trunk could unblock it.
+fn blocked_only_by_conflicts(status: &PrMergeStatus) -> bool {
+    status.mergeable == Some(false)
+        && status.state.eq_ignore_ascii_case("open")
+        && !status.is_draft
+        && !status.changes_requested
+        && !status.ci_status.is_failure()
+}
+
 /// Calculate which branches to merge based on current position
+/// `--admin` gate: only repository admins may override branch protection,
+/// and doing so needs its own confirmation on top of the merge prompt.
+fn confirm_admin_override(
+    rt: &tokio::runtime::Runtime,
+    client: &ForgeClient,
+    remote_info: &RemoteInfo,
+    pr_count: usize,
+    yes: bool,
+) -> Result<bool> {
+    let permission = rt.block_on(async { client.viewer_permission().await })?;
+    if permission.as_deref() != Some("ADMIN") {
+        bail!(
+            "`--admin` needs admin rights on {}, but your permission is {}.\n\
+             Merge without --admin once the PR passes branch protection, or ask a repository admin.",
+            remote_info.project_path(),
+            permission.as_deref().unwrap_or("unknown")
+        );
+    }
+    if yes {
+        return Ok(true);
+    }
+    if !std::io::stdin().is_terminal() {
+        bail!(
+            "`--admin` overrides branch protection and must be confirmed. Rerun with --yes to confirm."
+        );
+    }
+    Ok(Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!(
+            "Override branch protection and merge {} with admin rights?",
+            if pr_count == 1 {
+                "1 PR".to_string()
+            } else {
+                format!("{} PRs", pr_count)
+            }
+        ))
+        .default(false)
+        .interact()?)
+}
+
 fn calculate_merge_scope(
     stack: &Stack,
     current: &str,
+    until: Option<&str>,
     all: bool,
     downstack_only: bool,
 ) -> MergeScope {
-    // Get ancestors of current branch (from current up to trunk)
-    let mut ancestors = stack.ancestors(current);
+    // Merge up to `until` when given, otherwise up to the current branch
+    let top = until.unwrap_or(current);
+
+    // Get ancestors of the top branch (from top up to trunk)
+    let mut ancestors = stack.ancestors(top);
     ancestors.reverse(); // Now bottom-to-top (trunk-adjacent first)
 
     // Remove trunk from ancestors if present
     ancestors.retain(|b| b != &stack.trunk);
 
-    // Build list of branches from bottom to current
+    // Build list of branches from bottom to top
     let mut to_merge: Vec<MergeBranchInfo> = Vec::new();
 
     for (idx, branch) in ancestors.iter().enumerate() {
@@ -562,33 +1028,33 @@ fn calculate_merge_scope(
             branch: branch.clone(),
             pr_number,
             pr_status: None,
-            is_current: false,
+            is_current: branch == current,
             position: idx + 1,
         });
     }
 
-    let current_info = stack.branches.get(current);
-    let current_pr = current_info.and_then(|b| b.pr_number);
-    let current_position = to_merge.len() + 1;
+    let top_info = stack.branches.get(top);
+    let top_pr = top_info.and_then(|b| b.pr_number);
+    let top_position = to_merge.len() + 1;
 
-    let current_branch_info = MergeBranchInfo {
-        branch: current.to_string(),
-        pr_number: current_pr,
+    let top_branch_info = MergeBranchInfo {
+        branch: top.to_string(),
+        pr_number: top_pr,
         pr_status: None,
-        is_current: true,
-        position: current_position,
+        is_current: top == current,
+        position: top_position,
     };
 
     let mut remaining: Vec<MergeBranchInfo> = Vec::new();
 
     if downstack_only {
-        remaining.push(current_branch_info);
+        remaining.push(top_branch_info);
     } else {
-        to_merge.push(current_branch_info);
+        to_merge.push(top_branch_info);
     }
 
-    // Get descendants (branches above current)
-    let descendants = stack.descendants(current);
+    // Get descendants (branches above top)
+    let descendants = stack.descendants(top);
 
     for (idx, branch) in descendants.iter().enumerate() {
         let branch_info = stack.branches.get(branch);
@@ -598,8 +1064,8 @@ fn calculate_merge_scope(
             branch: branch.clone(),
             pr_number,
             pr_status: None,
-            is_current: false,
-            position: current_position + idx + 1,
+            is_current: branch == current,
+            position: top_position + idx + 1,
         });
     }
 
@@ -618,6 +1084,54 @@ fn calculate_merge_scope(
     }
 }
 
+/// `--only`: merge just `branch`, leaving its descendants to be rebased onto trunk
+fn calculate_only_merge_scope(stack: &Stack, current: &str, branch: &str) -> MergeScope {
+    let below = stack
+        .ancestors(branch)
+        .iter()
+        .filter(|ancestor| *ancestor != &stack.trunk)
+        .count();
+    let position = below + 1;
+    let info = |name: &str, position: usize| MergeBranchInfo {
+        branch: name.to_string(),
+        pr_number: stack.branches.get(name).and_then(|b| b.pr_number),
+        pr_status: None,
+        is_current: name == current,
+        position,
+    };
+
+    MergeScope {
+        to_merge: vec![info(branch, position)],
+        remaining: stack
+            .descendants(branch)
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| info(name, position + idx + 1))
+            .collect(),
+        trunk: stack.trunk.clone(),
+        current: current.to_string(),
+        downstack_only: false,
+    }
+}
+
+/// Tracked ancestors of `branch` whose PR is not recorded as merged, bottom first
+fn unmerged_ancestors(stack: &Stack, branch: &str) -> Vec<(String, Option<u64>)> {
+    let mut ancestors = stack.ancestors(branch);
+    ancestors.reverse();
+    ancestors
+        .into_iter()
+        .filter(|ancestor| ancestor != &stack.trunk)
+        .filter_map(|ancestor| {
+            let info = stack.branches.get(&ancestor)?;
+            let merged = info
+                .pr_state
+                .as_deref()
+                .is_some_and(|state| state.eq_ignore_ascii_case("merged"));
+            (!merged).then_some((ancestor, info.pr_number))
+        })
+        .collect()
+}
+
 /// Print the one-line merge plan summary
 fn print_merge_plan(scope: &MergeScope, method: &MergeMethod) {
     let n = scope.to_merge.len();
@@ -649,6 +1163,59 @@ fn print_merge_plan(scope: &MergeScope, method: &MergeMethod) {
     }
 }
 
+/// Compare each open PR's head commit with the local branch tip.
+fn find_head_mismatches(repo: &GitRepo, scope: &MergeScope) -> Vec<HeadMismatch> {
+    scope
+        .to_merge
+        .iter()
+        .filter_map(|branch_info| {
+            let status = branch_info.pr_status.as_ref()?;
+            if status.state.to_lowercase() != "open" || status.head_sha.is_empty() {
+                return None;
+            }
+            let local_sha = repo.branch_commit(&branch_info.branch).ok()?;
+            if local_sha == status.head_sha {
+                return None;
+            }
+            Some(HeadMismatch {
+                branch: branch_info.branch.clone(),
+                pr_number: branch_info.pr_number?,
+                local_sha,
+                pr_head_sha: status.head_sha.clone(),
+                pr_head_known: repo.resolve_ref(&status.head_sha).is_ok(),
+            })
+        })
+        .collect()
+}
+
+fn format_head_mismatches(mismatches: &[HeadMismatch]) -> Vec<String> {
+    let mut lines = vec![format!(
+        "  {}",
+        "Local branches differ from their PR heads (the PR head is what gets merged):".yellow()
+    )];
+    for mismatch in mismatches {
+        let hint = if mismatch.pr_head_known {
+            "local changes not pushed"
+        } else {
+            "PR has commits you have not fetched"
+        };
+        lines.push(format!(
+            "    {} {} (#{}): local {}, PR {} — {}",
+            "⚠".yellow(),
+            mismatch.branch.bold(),
+            mismatch.pr_number,
+            short_sha(&mismatch.local_sha),
+            short_sha(&mismatch.pr_head_sha),
+            hint
+        ));
+    }
+    lines
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
 #[cfg_attr(not(test), allow(dead_code))]
 fn merge_target_label(position: usize, trunk: &str) -> String {
     if position == 1 {
@@ -711,6 +1278,158 @@ fn local_trunk_reset_is_safe(workdir: &Path, trunk: &str) -> bool {
     }
 }
 
+/// Current tip of `trunk` on the remote, read without fetching objects.
+/// Simulate every branch's trip onto trunk with `git merge-tree` (no checkout,
+/// no ref updates) and return the steps that would likely conflict.
+///
+/// Each branch already contains the branches merged before it, so checking it
+/// against the current remote trunk approximates the rebase `merge` performs
+/// after the previous PR lands.
+fn predict_merge_conflicts(
+    repo: &GitRepo,
+    scope: &MergeScope,
+    remote: &str,
+) -> Vec<ConflictPrediction> {
+    let remote_trunk = format!("{}/{}", remote, scope.trunk);
+    let onto = if repo.resolve_ref(&remote_trunk).is_ok() {
+        remote_trunk
+    } else {
+        scope.trunk.clone()
+    };
+    let steps: Vec<(String, String)> = scope
+        .to_merge
+        .iter()
+        .chain(scope.remaining.iter())
+        .map(|branch_info| (branch_info.branch.clone(), onto.clone()))
+        .collect();
+    repo.predict_restack_conflicts(&steps)
+}
+
+fn format_conflict_predictions(predictions: &[ConflictPrediction]) -> Vec<String> {
+    if predictions.is_empty() {
+        return vec![format!("  {}", "No rebase conflicts predicted.".green())];
+    }
+    let mut lines = vec![format!(
+        "  {}",
+        "Likely conflicts (simulated with git merge-tree):".yellow()
+    )];
+    for prediction in predictions {
+        lines.push(format!(
+            "    {} {} onto {}: {}",
+            "✗".red(),
+            prediction.branch.bold(),
+            prediction.onto,
+            prediction.conflicting_files.join(", ")
+        ));
+    }
+    lines
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum TrunkUpdate {
+    UpToDate,
+    FastForwarded,
+    Diverged,
+}
+
+/// Fetch `<remote>/<trunk>` and fast-forward local trunk to it. A trunk that is
+/// checked out (here or in a linked worktree) is updated with `merge --ff-only`
+/// so its working tree follows; otherwise only the ref moves.
+fn fast_forward_local_trunk(
+    repo: &GitRepo,
+    workdir: &Path,
+    remote: &str,
+    trunk: &str,
+) -> Result<TrunkUpdate> {
+    let fetch = Command::new("git")
+        .args(["fetch", "--quiet", remote, trunk])
+        .current_dir(workdir)
+        .output()
+        .context("Failed to run git fetch")?;
+    if !fetch.status.success() {
+        anyhow::bail!(
+            "git fetch {} {} failed: {}",
+            remote,
+            trunk,
+            String::from_utf8_lossy(&fetch.stderr).trim()
+        );
+    }
+
+    let remote_ref = format!("refs/remotes/{}/{}", remote, trunk);
+    let remote_sha = repo.rev_parse(&remote_ref)?;
+    let local_sha = repo.rev_parse(&format!("refs/heads/{}", trunk))?;
+    if local_sha == remote_sha {
+        return Ok(TrunkUpdate::UpToDate);
+    }
+    if !repo.is_ancestor(&local_sha, &remote_sha)? {
+        return Ok(TrunkUpdate::Diverged);
+    }
+
+    let output = match repo.branch_worktree_path(trunk)? {
+        Some(path) => Command::new("git")
+            .args(["merge", "--ff-only", "--quiet", &remote_ref])
+            .current_dir(path)
+            .output(),
+        None => Command::new("git")
+            .args([
+                "update-ref",
+                &format!("refs/heads/{}", trunk),
+                &remote_sha,
+                &local_sha,
+            ])
+            .current_dir(workdir)
+            .output(),
+    }
+    .context("Failed to fast-forward local trunk")?;
+    if !output.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(TrunkUpdate::FastForwarded)
+}
+
+fn remote_trunk_sha(workdir: &Path, remote: &str, trunk: &str) -> Option<String> {
+    crate::remote::ls_remote_head_oids(workdir, remote)
+        .ok()?
+        .remove(trunk)
+}
+
+/// Plain-text merge timeline, one PR per line in merge order, suitable for a changelog.
+fn format_merge_timeline(trunk: &str, entries: &[TimelineEntry], total: Duration) -> Vec<String> {
+    let pr_word = if entries.len() == 1 { "PR" } else { "PRs" };
+    let mut lines = vec![format!(
+        "Merge timeline ({} {}, {}):",
+        entries.len(),
+        pr_word,
+        format_timeline_duration(total)
+    )];
+    for (idx, entry) in entries.iter().enumerate() {
+        let result = match &entry.trunk_sha {
+            Some(sha) => format!("{}@{}", trunk, &sha[..sha.len().min(7)]),
+            None => "already merged".to_string(),
+        };
+        lines.push(format!(
+            "  {}. #{} {} -> {} (+{})",
+            idx + 1,
+            entry.pr_number,
+            entry.branch,
+            result,
+            format_timeline_duration(entry.elapsed)
+        ));
+    }
+    lines
+}
+
+fn format_timeline_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -838,7 +1557,7 @@ mod tests {
     fn test_calculate_merge_scope_downstack_only_excludes_current() {
         let stack = create_test_stack();
 
-        let scope = calculate_merge_scope(&stack, "feature-b", false, true);
+        let scope = calculate_merge_scope(&stack, "feature-b", None, false, true);
 
         let to_merge: Vec<_> = scope.to_merge.iter().map(|b| b.branch.as_str()).collect();
         let remaining: Vec<_> = scope.remaining.iter().map(|b| b.branch.as_str()).collect();
@@ -855,7 +1574,7 @@ mod tests {
     fn test_calculate_merge_scope_downstack_only_direct_child_has_no_merge_targets() {
         let stack = create_test_stack();
 
-        let scope = calculate_merge_scope(&stack, "feature-a", false, true);
+        let scope = calculate_merge_scope(&stack, "feature-a", None, false, true);
 
         let remaining: Vec<_> = scope.remaining.iter().map(|b| b.branch.as_str()).collect();
 
@@ -864,6 +1583,66 @@ mod tests {
         assert!(scope.remaining[0].is_current);
     }
 
+    #[test]
+    fn test_calculate_merge_scope_until_stops_at_named_branch() {
+        let stack = create_test_stack();
+
+        let scope = calculate_merge_scope(&stack, "main", Some("feature-b"), false, false);
+
+        let to_merge: Vec<_> = scope.to_merge.iter().map(|b| b.branch.as_str()).collect();
+        let remaining: Vec<_> = scope.remaining.iter().map(|b| b.branch.as_str()).collect();
+
+        assert_eq!(to_merge, vec!["feature-a", "feature-b"]);
+        assert_eq!(remaining, vec!["feature-c"]);
+        assert_eq!(scope.remaining[0].position, 3);
+        assert!(scope.to_merge.iter().all(|b| !b.is_current));
+        assert_eq!(scope.current, "main");
+    }
+
+    #[test]
+    fn test_calculate_merge_scope_until_marks_current_inside_the_range() {
+        let stack = create_test_stack();
+
+        let scope = calculate_merge_scope(&stack, "feature-a", Some("feature-b"), false, false);
+
+        assert!(scope.to_merge[0].is_current);
+        assert!(!scope.to_merge[1].is_current);
+    }
+
+    #[test]
+    fn test_calculate_only_merge_scope_keeps_descendants_remaining() {
+        let stack = create_test_stack();
+
+        let scope = calculate_only_merge_scope(&stack, "feature-c", "feature-b");
+
+        let to_merge: Vec<_> = scope.to_merge.iter().map(|b| b.branch.as_str()).collect();
+        let remaining: Vec<_> = scope.remaining.iter().map(|b| b.branch.as_str()).collect();
+
+        assert_eq!(to_merge, vec!["feature-b"]);
+        assert_eq!(scope.to_merge[0].position, 2);
+        assert_eq!(remaining, vec!["feature-c"]);
+        assert!(scope.remaining[0].is_current);
+    }
+
+    #[test]
+    fn test_unmerged_ancestors_skips_merged_prs() {
+        let mut stack = create_test_stack();
+        assert_eq!(
+            unmerged_ancestors(&stack, "feature-c"),
+            vec![
+                ("feature-a".to_string(), Some(1)),
+                ("feature-b".to_string(), Some(2))
+            ]
+        );
+
+        stack.branches.get_mut("feature-a").unwrap().pr_state = Some("MERGED".to_string());
+        assert_eq!(
+            unmerged_ancestors(&stack, "feature-c"),
+            vec![("feature-b".to_string(), Some(2))]
+        );
+        assert!(unmerged_ancestors(&stack, "feature-a").is_empty());
+    }
+
     #[test]
     fn test_merge_target_label_uses_configured_trunk() {
         assert_eq!(merge_target_label(1, "master"), "master");
@@ -898,4 +1677,111 @@ mod tests {
             "feature-b"
         );
     }
+
+    #[test]
+    fn test_format_merge_timeline_lists_prs_in_merge_order() {
+        let entries = vec![
+            TimelineEntry {
+                branch: "feature-a".to_string(),
+                pr_number: 1,
+                trunk_sha: Some("1111111aaaaaaa".to_string()),
+                elapsed: Duration::from_secs(12),
+            },
+            TimelineEntry {
+                branch: "feature-b".to_string(),
+                pr_number: 2,
+                trunk_sha: Some("2222222bbbbbbb".to_string()),
+                elapsed: Duration::from_secs(95),
+            },
+            TimelineEntry {
+                branch: "feature-c".to_string(),
+                pr_number: 3,
+                trunk_sha: None,
+                elapsed: Duration::from_secs(96),
+            },
+        ];
+
+        let lines = format_merge_timeline("main", &entries, Duration::from_secs(100));
+
+        assert_eq!(
+            lines,
+            vec![
+                "Merge timeline (3 PRs, 1m 40s):",
+                "  1. #1 feature-a -> main@1111111 (+12s)",
+                "  2. #2 feature-b -> main@2222222 (+1m 35s)",
+                "  3. #3 feature-c -> already merged (+1m 36s)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_head_mismatches_separates_unpushed_from_unfetched() {
+        colored::control::set_override(false);
+        let mismatches = vec![
+            HeadMismatch {
+                branch: "feature-a".to_string(),
+                pr_number: 1,
+                local_sha: "1111111aaaaaaa".to_string(),
+                pr_head_sha: "2222222bbbbbbb".to_string(),
+                pr_head_known: true,
+            },
+            HeadMismatch {
+                branch: "feature-b".to_string(),
+                pr_number: 2,
+                local_sha: "3333333ccccccc".to_string(),
+                pr_head_sha: "4444444ddddddd".to_string(),
+                pr_head_known: false,
+            },
+        ];
+
+        let lines = format_head_mismatches(&mismatches);
+
+        assert_eq!(
+            lines,
+            vec![
+                "  Local branches differ from their PR heads (the PR head is what gets merged):",
+                "    ⚠ feature-a (#1): local 1111111, PR 2222222 — local changes not pushed",
+                "    ⚠ feature-b (#2): local 3333333, PR 4444444 — PR has commits you have not fetched",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blocked_only_by_conflicts_requires_a_clean_conflicting_pr() {
+        let conflicting = PrMergeStatus {
+            number: 2,
+            title: "feature-b".to_string(),
+            state: "OPEN".to_string(),
+            updated_at: None,
+            is_draft: false,
+            mergeable: Some(false),
+            mergeable_state: "dirty".to_string(),
+            ci_status: CiStatus::Success,
+            review_decision: Some("APPROVED".to_string()),
+            approvals: 1,
+            changes_requested: false,
+            head_sha: "abc".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
+        };
+        assert!(blocked_only_by_conflicts(&conflicting));
+
+        let still_computing = PrMergeStatus {
+            mergeable: None,
+            ..conflicting.clone()
+        };
+        assert!(!blocked_only_by_conflicts(&still_computing));
+
+        let ci_failed = PrMergeStatus {
+            ci_status: CiStatus::Failure,
+            ..conflicting.clone()
+        };
+        assert!(!blocked_only_by_conflicts(&ci_failed));
+
+        let draft = PrMergeStatus {
+            is_draft: true,
+            ..conflicting
+        };
+        assert!(!blocked_only_by_conflicts(&draft));
+    }
 }
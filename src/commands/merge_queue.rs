@@ -29,18 +29,25 @@ struct QueueBranchInfo {
     original_base: String,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     all: bool,
     timeout: u64,
     interval: u64,
+    requeue: u32,
     no_sync: bool,
     yes: bool,
     quiet: bool,
+    request_timeout: Option<u64>,
+    remote: Option<String>,
+    notify: bool,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(request_timeout)
+        .with_remote(remote.clone());
 
     if current == stack.trunk {
         if !quiet {
@@ -68,20 +75,31 @@ pub fn run(
 
     let remote_info =
         RemoteInfo::from_repo(&repo, &config).context("Failed to read git remote configuration")?;
-    if remote_info.forge == ForgeType::Gitea {
+    if matches!(
+        remote_info.forge,
+        ForgeType::Gitea | ForgeType::Bitbucket | ForgeType::AzureDevOps
+    ) {
+        let forge_name = match remote_info.forge {
+            ForgeType::Gitea => "Gitea/Forgejo",
+            ForgeType::Bitbucket => "Bitbucket",
+            _ => "Azure DevOps",
+        };
         anyhow::bail!(
-            "`stax merge --queue` is not supported for Gitea/Forgejo — \
-             Gitea does not have a merge queue feature.\n\
-             Tip: use `stax merge` or `stax merge --when-ready` instead."
+            "`stax merge --queue` is not supported for {} — \
+             it does not have a merge queue feature.\n\
+             Tip: use `stax merge` or `stax merge --when-ready` instead.",
+            forge_name
         );
     }
 
     let rt = tokio::runtime::Runtime::new()?;
     let _enter = rt.enter();
 
-    let client = ForgeClient::new(&remote_info).context(
-        "Failed to connect to the configured forge. Check your token and remote configuration.",
-    )?;
+    let client = ForgeClient::new(&remote_info)
+        .context(
+            "Failed to connect to the configured forge. Check your token and remote configuration.",
+        )?
+        .with_quiet(quiet);
     let forge_name = remote_info.forge.to_string();
     let queue_term = match remote_info.forge {
         ForgeType::GitLab => "merge train",
@@ -204,132 +222,13 @@ pub fn run(
             );
         }
 
-        match rt.block_on(async { client.is_pr_merged(branch.pr_number).await }) {
-            Ok(true) => {
-                if !quiet {
-                    println!("      {} Already merged", "✓".green());
-                }
-                continue;
-            }
-            Ok(false) => {}
-            Err(e) => {
-                failed = Some((
-                    branch.branch.clone(),
-                    branch.pr_number,
-                    format!("Failed to check merge status: {}", e),
-                ));
-                break;
-            }
-        }
-
-        let retarget_timer = LiveTimer::maybe_new(
-            !quiet,
-            &format!("Retargeting #{} to {}...", branch.pr_number, trunk),
-        );
-
-        let retarget_result =
-            update_pr_base_unless_current(&rt, &client, branch.pr_number, &trunk, &branch.branch);
-        match retarget_result {
-            Ok(PrBaseUpdate::Updated) => LiveTimer::maybe_finish_ok(retarget_timer, "done"),
-            Ok(PrBaseUpdate::AlreadyTargeted) => {
-                LiveTimer::maybe_finish_ok(retarget_timer, "already on base")
-            }
-            Ok(PrBaseUpdate::NativeStackLocked) => {
-                LiveTimer::maybe_finish_err(retarget_timer, "locked");
-                failed = Some((
-                    branch.branch.clone(),
-                    branch.pr_number,
-                    format!(
-                        "PR #{} is registered in a native GitHub Stack, which locks its base \
-                         branch and prevents retargeting it to {} for the queue. Run `st stack \
-                         unlink` first if you need to enqueue it out of stack order.",
-                        branch.pr_number, trunk
-                    ),
-                ));
-                break;
-            }
-            Err(e) => {
-                LiveTimer::maybe_finish_err(retarget_timer, "failed");
-                failed = Some((
-                    branch.branch.clone(),
-                    branch.pr_number,
-                    format!("Failed to retarget PR: {:#}", e),
-                ));
-                break;
-            }
-        }
-
-        let enqueue_timer =
-            LiveTimer::maybe_new(!quiet, &format!("Enqueuing #{}...", branch.pr_number));
-
-        match rt.block_on(async { client.enqueue_pr(branch.pr_number).await }) {
-            Ok(result) => {
-                let position = result.merge_queue_entry.and_then(|e| e.position);
-                let msg = match position {
-                    Some(pos) => format!("queued at position {}", pos),
-                    None => "queued".to_string(),
-                };
-                LiveTimer::maybe_finish_ok(enqueue_timer, &msg);
+        match enqueue_branch(&rt, &client, branch, &trunk, quiet) {
+            Ok(EnqueueOutcome::AlreadyMerged) => {}
+            Ok(EnqueueOutcome::Queued(position)) => {
                 enqueued.push((branch.branch.clone(), branch.pr_number, position));
             }
-            Err(e) => {
-                LiveTimer::maybe_finish_err(enqueue_timer, "failed");
-
-                // Rollback: restore the original PR base since the PR was
-                // retargeted to trunk but never actually enqueued.  Use
-                // best-effort — if the rollback itself fails we still report
-                // the original enqueue error.
-                if branch.original_base != trunk {
-                    let rollback_timer = LiveTimer::maybe_new(
-                        !quiet,
-                        &format!(
-                            "Rolling back #{} base to {}...",
-                            branch.pr_number, branch.original_base
-                        ),
-                    );
-                    match update_pr_base_unless_current(
-                        &rt,
-                        &client,
-                        branch.pr_number,
-                        &branch.original_base,
-                        &branch.branch,
-                    ) {
-                        Ok(PrBaseUpdate::Updated) => {
-                            LiveTimer::maybe_finish_ok(rollback_timer, "restored")
-                        }
-                        Ok(PrBaseUpdate::AlreadyTargeted) => {
-                            LiveTimer::maybe_finish_ok(rollback_timer, "already restored")
-                        }
-                        Ok(PrBaseUpdate::NativeStackLocked) => {
-                            LiveTimer::maybe_finish_warn(rollback_timer, "skipped (native Stack)");
-                            if !quiet {
-                                println!(
-                                    "      {} #{} manages its base via GitHub's native Stack; \
-                                     restore it with `st stack link` if it isn't updated \
-                                     automatically",
-                                    "note:".dimmed(),
-                                    branch.pr_number
-                                );
-                            }
-                        }
-                        Err(rb_err) => {
-                            LiveTimer::maybe_finish_err(rollback_timer, "rollback failed");
-                            if !quiet {
-                                println!(
-                                    "      {} Could not restore original base: {:#}",
-                                    "⚠".yellow(),
-                                    rb_err
-                                );
-                            }
-                        }
-                    }
-                }
-
-                failed = Some((
-                    branch.branch.clone(),
-                    branch.pr_number,
-                    format!("Failed to enqueue: {}", e),
-                ));
+            Err(reason) => {
+                failed = Some((branch.branch.clone(), branch.pr_number, reason));
                 break;
             }
         }
@@ -388,8 +287,11 @@ pub fn run(
     let timeout_duration = Duration::from_secs(timeout * 60);
     let poll_interval = Duration::from_secs(interval);
     let start = Instant::now();
-    let mut pending: Vec<(String, u64)> =
-        enqueued.iter().map(|(b, pr, _)| (b.clone(), *pr)).collect();
+    let mut pending: Vec<&QueueBranchInfo> = branches
+        .iter()
+        .filter(|branch| enqueued.iter().any(|(_, pr, _)| *pr == branch.pr_number))
+        .collect();
+    let mut requeues_left = requeue;
     let mut timed_out = false;
 
     while !pending.is_empty() {
@@ -402,27 +304,117 @@ pub fn run(
         }
 
         let mut still_pending = Vec::new();
-        for (branch, pr) in &pending {
-            match rt.block_on(async { client.is_pr_merged(*pr).await }) {
-                Ok(true) => {
+        let mut dropped = None;
+        for branch in &pending {
+            match rt.block_on(async { client.merge_queue_status(branch.pr_number).await }) {
+                Ok(status) if status.merged => {
                     if !quiet {
                         println!(
                             "  {} #{} {} merged  {}",
                             "✓".green(),
-                            pr,
-                            branch,
+                            branch.pr_number,
+                            branch.branch,
                             format!("({}s)", elapsed.as_secs()).dimmed()
                         );
                     }
                 }
-                Ok(false) => still_pending.push((branch.clone(), *pr)),
-                Err(_) => still_pending.push((branch.clone(), *pr)),
+                Ok(status) if status.in_queue == Some(false) && dropped.is_none() => {
+                    dropped = Some(still_pending.len());
+                    still_pending.push(*branch);
+                }
+                _ => still_pending.push(*branch),
             }
         }
         pending = still_pending;
 
+        if let Some(dropped_idx) = dropped {
+            if !quiet {
+                print!("\r{}\r", " ".repeat(72));
+            }
+            let dropped_branch = pending[dropped_idx];
+
+            // PRs above the dropped one still carry its commits; landing them
+            // now would merge its changes without it. Take them out first.
+            let dequeued = dequeue_branches(&rt, &client, &pending[dropped_idx + 1..], quiet);
+
+            if requeues_left > 0 {
+                requeues_left -= 1;
+                if !quiet {
+                    println!(
+                        "  {} #{} {} left the {} without merging; requeuing ({} of {})",
+                        "↻".yellow(),
+                        dropped_branch.pr_number,
+                        dropped_branch.branch,
+                        queue_term,
+                        requeue - requeues_left,
+                        requeue
+                    );
+                }
+                let mut requeued = pending[..dropped_idx].to_vec();
+                for branch in &pending[dropped_idx..] {
+                    match enqueue_branch(&rt, &client, branch, &trunk, quiet) {
+                        Ok(EnqueueOutcome::AlreadyMerged) => {}
+                        Ok(EnqueueOutcome::Queued(_)) => requeued.push(*branch),
+                        Err(reason) => {
+                            print_header_error(&format!("{} Failed", capitalize(queue_term)));
+                            println!();
+                            println!(
+                                "  {} #{} {} → {}",
+                                "✗".red(),
+                                branch.pr_number,
+                                branch.branch,
+                                reason
+                            );
+                            println!();
+                            println!(
+                                "{}",
+                                "Fix the issue and run 'stax merge --queue' to continue.".dimmed()
+                            );
+                            return Ok(());
+                        }
+                    }
+                }
+                pending = requeued;
+                continue;
+            }
+
+            print_header_error(&format!("{} Failed", capitalize(queue_term)));
+            println!();
+            println!(
+                "  {} #{} {} → left the {} without merging (check its queue CI run)",
+                "✗".red(),
+                dropped_branch.pr_number,
+                dropped_branch.branch,
+                queue_term
+            );
+            for branch in &dequeued {
+                println!(
+                    "  {} #{} {} → removed from the {} (stacked on #{})",
+                    "↷".yellow(),
+                    branch.pr_number,
+                    branch.branch,
+                    queue_term,
+                    dropped_branch.pr_number
+                );
+            }
+            println!();
+            println!(
+                "{}",
+                "Fix the issue and run 'stax merge --queue' to requeue.".dimmed()
+            );
+            crate::notifications::send_desktop_notification(
+                "stax merge --queue",
+                &format!("#{} left the {}", dropped_branch.pr_number, queue_term),
+                notify,
+            );
+            return Ok(());
+        }
+
         if !pending.is_empty() && !quiet {
-            let names: Vec<String> = pending.iter().map(|(_, pr)| format!("#{}", pr)).collect();
+            let names: Vec<String> = pending
+                .iter()
+                .map(|branch| format!("#{}", branch.pr_number))
+                .collect();
             print!(
                 "\r  ⏳ {}  {}",
                 names.join(", "),
@@ -479,6 +471,7 @@ pub fn run(
             if enqueued.len() == 1 { "PR" } else { "PRs" },
             trunk
         ),
+        notify,
     );
 
     if !no_sync {
@@ -505,6 +498,11 @@ pub fn run(
             false, // verbose
             false, // auto_stash_pop
             &[],
+            crate::commands::sync::SyncOptions {
+                request_timeout,
+                remote: remote.clone(),
+                ..Default::default()
+            },
         ) && !quiet
         {
             println!();
@@ -523,6 +521,173 @@ pub fn run(
     Ok(())
 }
 
+enum EnqueueOutcome {
+    AlreadyMerged,
+    /// Enqueued now or already waiting in the queue, with its position.
+    Queued(Option<u32>),
+}
+
+/// Retarget one PR to trunk and put it in the merge queue. On failure the
+/// PR's original base is restored and the reason is returned.
+fn enqueue_branch(
+    rt: &tokio::runtime::Runtime,
+    client: &ForgeClient,
+    branch: &QueueBranchInfo,
+    trunk: &str,
+    quiet: bool,
+) -> std::result::Result<EnqueueOutcome, String> {
+    match rt.block_on(async { client.merge_queue_status(branch.pr_number).await }) {
+        Ok(status) if status.merged => {
+            if !quiet {
+                println!("      {} Already merged", "✓".green());
+            }
+            return Ok(EnqueueOutcome::AlreadyMerged);
+        }
+        Ok(status) if status.in_queue == Some(true) => {
+            if !quiet {
+                let msg = match status.position {
+                    Some(pos) => format!("already queued at position {}", pos),
+                    None => "already queued".to_string(),
+                };
+                println!("      {} {}", "✓".green(), msg);
+            }
+            return Ok(EnqueueOutcome::Queued(status.position));
+        }
+        Ok(_) => {}
+        Err(e) => return Err(format!("Failed to check merge status: {}", e)),
+    }
+
+    let retarget_timer = LiveTimer::maybe_new(
+        !quiet,
+        &format!("Retargeting #{} to {}...", branch.pr_number, trunk),
+    );
+
+    let retarget_result =
+        update_pr_base_unless_current(rt, client, branch.pr_number, trunk, &branch.branch);
+    match retarget_result {
+        Ok(PrBaseUpdate::Updated) => LiveTimer::maybe_finish_ok(retarget_timer, "done"),
+        Ok(PrBaseUpdate::AlreadyTargeted) => {
+            LiveTimer::maybe_finish_ok(retarget_timer, "already on base")
+        }
+        Ok(PrBaseUpdate::NativeStackLocked) => {
+            LiveTimer::maybe_finish_err(retarget_timer, "locked");
+            return Err(format!(
+                "PR #{} is registered in a native GitHub Stack, which locks its base \
+                 branch and prevents retargeting it to {} for the queue. Run `st stack \
+                 unlink` first if you need to enqueue it out of stack order.",
+                branch.pr_number, trunk
+            ));
+        }
+        Err(e) => {
+            LiveTimer::maybe_finish_err(retarget_timer, "failed");
+            return Err(format!("Failed to retarget PR: {:#}", e));
+        }
+    }
+
+    let enqueue_timer =
+        LiveTimer::maybe_new(!quiet, &format!("Enqueuing #{}...", branch.pr_number));
+
+    match rt.block_on(async { client.enqueue_pr(branch.pr_number).await }) {
+        Ok(result) => {
+            let position = result.merge_queue_entry.and_then(|e| e.position);
+            let msg = match position {
+                Some(pos) => format!("queued at position {}", pos),
+                None => "queued".to_string(),
+            };
+            LiveTimer::maybe_finish_ok(enqueue_timer, &msg);
+            Ok(EnqueueOutcome::Queued(position))
+        }
+        Err(e) => {
+            LiveTimer::maybe_finish_err(enqueue_timer, "failed");
+
+            // Rollback: restore the original PR base since the PR was
+            // retargeted to trunk but never actually enqueued.  Use
+            // best-effort — if the rollback itself fails we still report
+            // the original enqueue error.
+            if branch.original_base != trunk {
+                let rollback_timer = LiveTimer::maybe_new(
+                    !quiet,
+                    &format!(
+                        "Rolling back #{} base to {}...",
+                        branch.pr_number, branch.original_base
+                    ),
+                );
+                match update_pr_base_unless_current(
+                    rt,
+                    client,
+                    branch.pr_number,
+                    &branch.original_base,
+                    &branch.branch,
+                ) {
+                    Ok(PrBaseUpdate::Updated) => {
+                        LiveTimer::maybe_finish_ok(rollback_timer, "restored")
+                    }
+                    Ok(PrBaseUpdate::AlreadyTargeted) => {
+                        LiveTimer::maybe_finish_ok(rollback_timer, "already restored")
+                    }
+                    Ok(PrBaseUpdate::NativeStackLocked) => {
+                        LiveTimer::maybe_finish_warn(rollback_timer, "skipped (native Stack)");
+                        if !quiet {
+                            println!(
+                                "      {} #{} manages its base via GitHub's native Stack; \
+                                 restore it with `st stack link` if it isn't updated \
+                                 automatically",
+                                "note:".dimmed(),
+                                branch.pr_number
+                            );
+                        }
+                    }
+                    Err(rb_err) => {
+                        LiveTimer::maybe_finish_err(rollback_timer, "rollback failed");
+                        if !quiet {
+                            println!(
+                                "      {} Could not restore original base: {:#}",
+                                "⚠".yellow(),
+                                rb_err
+                            );
+                        }
+                    }
+                }
+            }
+
+            Err(format!("Failed to enqueue: {}", e))
+        }
+    }
+}
+
+/// Take `branches` out of the merge queue, returning the ones that left it.
+/// Branches that were not queued (or already merged) are skipped.
+fn dequeue_branches<'a>(
+    rt: &tokio::runtime::Runtime,
+    client: &ForgeClient,
+    branches: &[&'a QueueBranchInfo],
+    quiet: bool,
+) -> Vec<&'a QueueBranchInfo> {
+    let mut dequeued = Vec::new();
+    for branch in branches {
+        let queued = rt
+            .block_on(async { client.merge_queue_status(branch.pr_number).await })
+            .is_ok_and(|status| !status.merged && status.in_queue == Some(true));
+        if !queued {
+            continue;
+        }
+        match rt.block_on(async { client.dequeue_pr(branch.pr_number).await }) {
+            Ok(()) => dequeued.push(*branch),
+            Err(e) => {
+                if !quiet {
+                    println!(
+                        "  {} Could not remove #{} from the queue: {:#}",
+                        "⚠".yellow(),
+                        branch.pr_number,
+                        e
+                    );
+                }
+            }
+        }
+    }
+    dequeued
+}
+
 fn calculate_queue_scope(stack: &Stack, current: &str, all: bool) -> (Vec<String>, String) {
     let scope = calculate_scope(stack, current, all, false);
     (scope.to_merge, scope.trunk)
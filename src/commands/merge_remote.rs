@@ -5,7 +5,8 @@
 use crate::commands::merge_shared::{
     BlockedReasonStyle, PrBaseUpdate, WaitResult, calculate_scope, print_header,
     print_header_error, print_header_success, print_native_stack_locked_note,
-    record_ci_history_for_branch, update_pr_base_unless_current, wait_for_pr_ready,
+    record_ci_history_for_branch, resolve_merge_method, update_pr_base_unless_current,
+    wait_for_pr_ready,
 };
 use crate::config::Config;
 use crate::engine::Stack;
@@ -40,18 +41,23 @@ struct MergeRemoteScope {
 #[allow(clippy::too_many_arguments)]
 pub fn run(
     all: bool,
-    method: MergeMethod,
+    method: Option<MergeMethod>,
     timeout_mins: u64,
     interval_secs: u64,
     no_delete: bool,
     no_sync: bool,
     yes: bool,
     quiet: bool,
+    request_timeout: Option<u64>,
+    remote: Option<String>,
+    notify: bool,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(request_timeout)
+        .with_remote(remote.clone());
 
     if current == stack.trunk {
         if !quiet {
@@ -87,7 +93,8 @@ pub fn run(
     let probe_client = remote_info
         .as_ref()
         .ok()
-        .and_then(|info| ForgeClient::new(info).ok());
+        .and_then(|info| ForgeClient::new(info).ok())
+        .map(|client| client.with_quiet(quiet));
 
     let fetch_timer = LiveTimer::maybe_new(!quiet, "Fetching PR info...");
 
@@ -152,9 +159,12 @@ pub fn run(
         );
     }
 
-    let client = ForgeClient::new(&remote_info).context(
-        "Failed to connect to the configured forge. Check your token and remote configuration.",
-    )?;
+    let client = ForgeClient::new(&remote_info)
+        .context(
+            "Failed to connect to the configured forge. Check your token and remote configuration.",
+        )?
+        .with_quiet(quiet);
+    let method = resolve_merge_method(&rt, &client, method, MergeMethod::Squash)?;
 
     if !quiet {
         println!();
@@ -256,6 +266,7 @@ pub fn run(
                 poll_interval,
                 BlockedReasonStyle::StatusText,
                 quiet,
+                notify,
             )? {
                 WaitResult::Ready(_) => {}
                 WaitResult::Failed(reason) => {
@@ -489,6 +500,7 @@ pub fn run(
                 if merged_prs.len() == 1 { "PR" } else { "PRs" },
                 scope.trunk
             ),
+            notify,
         );
     }
 
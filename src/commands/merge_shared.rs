@@ -10,11 +10,12 @@ use crate::commands::merge_rebase::{
     rebase_descendant_onto_remote_trunk_with_provenance,
 };
 use crate::engine::Stack;
-use crate::forge::ForgeClient;
+use crate::forge::{AllowedMergeMethods, Forge, ForgeClient, MergeMethod};
 use crate::git::{GitRepo, RebaseResult};
 use crate::github::pr::{PrMergeStatus, is_native_stack_base_locked_error};
+use crate::notifications;
 use crate::progress::LiveTimer;
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use colored::Colorize;
 use std::io::Write;
 use std::process::Command;
@@ -105,18 +106,64 @@ pub(crate) fn blocked_reason(status: &PrMergeStatus) -> String {
     status.status_text().to_string()
 }
 
+/// Pick the merge method for this repository. An explicit `requested` method
+/// must be one the repository allows; without one, `default` is used when
+/// allowed and the first allowed method otherwise. When the settings cannot
+/// be read the choice is left to the forge.
+pub(crate) fn resolve_merge_method(
+    rt: &tokio::runtime::Runtime,
+    client: &ForgeClient,
+    requested: Option<MergeMethod>,
+    default: MergeMethod,
+) -> Result<MergeMethod> {
+    let allowed = rt
+        .block_on(async { client.allowed_merge_methods().await })
+        .ok()
+        .flatten();
+    choose_merge_method(allowed, requested, default)
+}
+
+fn choose_merge_method(
+    allowed: Option<AllowedMergeMethods>,
+    requested: Option<MergeMethod>,
+    default: MergeMethod,
+) -> Result<MergeMethod> {
+    let wanted = requested.unwrap_or(default);
+    let Some(allowed) = allowed else {
+        return Ok(wanted);
+    };
+    let methods = allowed.methods();
+    if allowed.allows(wanted) || methods.is_empty() {
+        return Ok(wanted);
+    }
+    if requested.is_none() {
+        return Ok(methods[0]);
+    }
+    let names: Vec<&str> = methods.iter().map(MergeMethod::as_str).collect();
+    bail!(
+        "This repository does not allow {} merges (allowed: {}).\n\nPass --method {} instead.",
+        wanted.as_str(),
+        names.join(", "),
+        methods[0].as_str()
+    );
+}
+
 /// Wait for a PR to be ready to merge (CI passed, approved).
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn wait_for_pr_ready(
     rt: &tokio::runtime::Runtime,
-    client: &ForgeClient,
+    client: &impl Forge,
     pr_number: u64,
     timeout: Duration,
     poll_interval: Duration,
     blocked_style: BlockedReasonStyle,
     quiet: bool,
+    notify: bool,
 ) -> Result<WaitResult> {
     let start = Instant::now();
     let mut last_status: Option<String> = None;
+    // Only PRs that made us wait are worth a notification.
+    let mut waited = false;
 
     loop {
         let status = rt.block_on(async { client.get_pr_merge_status(pr_number).await })?;
@@ -126,6 +173,13 @@ pub(crate) fn wait_for_pr_ready(
             if !quiet && last_status.is_some() {
                 println!(); // End the waiting line
             }
+            if waited {
+                notifications::send_wait_notification(
+                    "stax merge",
+                    &format!("PR #{} is ready to merge", pr_number),
+                    notify,
+                );
+            }
             return Ok(WaitResult::Ready(status));
         }
 
@@ -138,6 +192,13 @@ pub(crate) fn wait_for_pr_ready(
                 BlockedReasonStyle::Detailed => blocked_reason(&status),
                 BlockedReasonStyle::StatusText => status.status_text().to_string(),
             };
+            if waited {
+                notifications::send_wait_notification(
+                    "stax merge",
+                    &format!("PR #{} can't be merged: {}", pr_number, reason),
+                    notify,
+                );
+            }
             return Ok(WaitResult::Failed(reason));
         }
 
@@ -146,6 +207,13 @@ pub(crate) fn wait_for_pr_ready(
             if !quiet && last_status.is_some() {
                 println!(); // End the waiting line
             }
+            if waited {
+                notifications::send_wait_notification(
+                    "stax merge",
+                    &format!("Timed out waiting for PR #{}", pr_number),
+                    notify,
+                );
+            }
             return Ok(WaitResult::Timeout);
         }
 
@@ -170,6 +238,7 @@ pub(crate) fn wait_for_pr_ready(
 
         // Wait before next poll
         std::thread::sleep(poll_interval);
+        waited = true;
     }
 }
 
@@ -177,7 +246,7 @@ pub(crate) fn wait_for_pr_ready(
 /// Silently times out so the next merge attempt surfaces the real error.
 fn wait_for_github_head_sync(
     rt: &tokio::runtime::Runtime,
-    client: &ForgeClient,
+    client: &impl Forge,
     pr_number: u64,
     expected_sha: &str,
     max_wait: Duration,
@@ -198,6 +267,66 @@ fn wait_for_github_head_sync(
     }
 }
 
+/// Remote head of `branch` as stax last saw it, recorded before a rebase so
+/// the push that follows leases against exactly that commit.
+pub(crate) fn recorded_remote_oid(
+    repo: &GitRepo,
+    remote_name: &str,
+    branch: &str,
+) -> Option<String> {
+    repo.rev_parse(&format!("refs/remotes/{}/{}", remote_name, branch))
+        .ok()
+}
+
+/// Force-push a rebased branch with `--force-with-lease` pinned to
+/// `expected_remote_oid` (`None`: the branch must not exist on the remote).
+/// When someone else pushed in the meantime the push is refused with recovery
+/// steps instead of overwriting their commits.
+pub(crate) fn push_with_lease(
+    repo: &GitRepo,
+    remote_name: &str,
+    branch: &str,
+    expected_remote_oid: Option<&str>,
+) -> Result<()> {
+    let lease = format!(
+        "--force-with-lease=refs/heads/{}:{}",
+        branch,
+        expected_remote_oid.unwrap_or("")
+    );
+    let refspec = format!("refs/heads/{0}:refs/heads/{0}", branch);
+    let output = Command::new("git")
+        .args(["push", &lease, remote_name, &refspec])
+        .current_dir(repo.workdir()?)
+        .output()
+        .context("Failed to push")?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+    if String::from_utf8_lossy(&output.stderr).contains("stale info") {
+        anyhow::bail!(remote_moved_message(
+            remote_name,
+            branch,
+            expected_remote_oid
+        ));
+    }
+    anyhow::bail!("push rejected: {}", summarize_git_stderr(&output.stderr))
+}
+
+fn remote_moved_message(remote_name: &str, branch: &str, expected: Option<&str>) -> String {
+    let seen = expected
+        .map(|oid| format!("at {}", &oid[..oid.len().min(7)]))
+        .unwrap_or_else(|| "as absent".to_string());
+    format!(
+        "remote moved, refusing to clobber {remote}/{branch} (stax recorded it {seen}).\n\
+         Someone else pushed to it. To recover:\n  \
+         git fetch {remote} {branch}\n  \
+         git log {branch}..{remote}/{branch}   # commits the push would overwrite\n\
+         Rebase {branch} onto {remote}/{branch} (or drop those commits deliberately), then re-run the merge.",
+        remote = remote_name,
+    )
+}
+
 /// Extract a concise, user-visible reason from `git push` stderr output.
 /// Returns the first non-empty line, or a generic fallback when stderr is empty.
 fn summarize_git_stderr(stderr: &[u8]) -> String {
@@ -218,7 +347,7 @@ fn summarize_git_stderr(stderr: &[u8]) -> String {
 /// confirms the intended base.
 pub(crate) fn update_pr_base_unless_current(
     rt: &tokio::runtime::Runtime,
-    client: &ForgeClient,
+    client: &impl Forge,
     pr_number: u64,
     new_base: &str,
     expected_head: &str,
@@ -299,7 +428,7 @@ fn ensure_pr_head_matches(pr_number: u64, actual_head: &str, expected_head: &str
 /// itself shortly after (e.g. once the merged branch is deleted).
 fn pr_base_matches_after_recheck(
     rt: &tokio::runtime::Runtime,
-    client: &ForgeClient,
+    client: &impl Forge,
     pr_number: u64,
     new_base: &str,
 ) -> bool {
@@ -339,26 +468,21 @@ fn finalize_remaining_branch_push(
     client: &ForgeClient,
     remote_name: &str,
     branch: &str,
+    expected_remote_oid: Option<&str>,
     pr_number: Option<u64>,
     new_base: &str,
     timer: Option<LiveTimer>,
 ) -> Result<()> {
-    let push_output = Command::new("git")
-        .args(["push", "--force-with-lease", remote_name, branch])
-        .current_dir(repo.workdir()?)
-        .output();
-
-    let push_err = match &push_output {
-        Ok(out) if out.status.success() => None,
-        Ok(out) => Some(summarize_git_stderr(&out.stderr)),
-        Err(e) => Some(e.to_string()),
-    };
-
-    if let Some(reason) = push_err {
+    if let Err(e) = push_with_lease(repo, remote_name, branch, expected_remote_oid) {
+        let message = format!("{:#}", e);
+        let (summary, details) = message.split_once('\n').unwrap_or((&message, ""));
         LiveTimer::maybe_finish_err(
             timer,
-            &format!("push failed; PR base unchanged ({})", reason),
+            &format!("push failed; PR base unchanged ({})", summary),
         );
+        if !details.is_empty() {
+            eprintln!("{}", details.dimmed());
+        }
         return Ok(());
     }
 
@@ -405,6 +529,7 @@ pub(crate) fn rebase_and_finalize_remaining_branch(
     } else {
         LiveTimer::maybe_finish_ok(fetch_timer, "done");
     }
+    let expected_remote_oid = recorded_remote_oid(repo, remote_name, branch);
 
     let remaining_timer = LiveTimer::maybe_new(
         !quiet,
@@ -431,6 +556,7 @@ pub(crate) fn rebase_and_finalize_remaining_branch(
                 client,
                 remote_name,
                 branch,
+                expected_remote_oid.as_deref(),
                 pr_number,
                 &parent_branch,
                 remaining_timer,
@@ -459,7 +585,7 @@ pub(crate) fn rebase_and_finalize_remaining_branch(
 /// return `405 Base branch was modified`.
 pub(crate) fn sync_head_after_push(
     rt: &tokio::runtime::Runtime,
-    client: &ForgeClient,
+    client: &impl Forge,
     pr_number: u64,
     repo: &GitRepo,
     branch: &str,
@@ -590,7 +716,10 @@ pub(crate) fn print_header_error(title: &str) {
 mod tests {
     use super::*;
     use crate::engine::stack::StackBranch;
+    use crate::forge::FakeForge;
+    use crate::forge::{PrInfo, PrInfoWithHead};
     use crate::github::pr::CiStatus;
+    use std::cell::RefCell;
     use std::collections::HashMap;
 
     fn create_test_stack() -> Stack {
@@ -672,6 +801,8 @@ mod tests {
             approvals: 0,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         }
     }
 
@@ -793,6 +924,50 @@ mod tests {
         assert_eq!(blocked_reason(&status), "CI failed");
     }
 
+    const ONLY_MERGE_AND_REBASE: AllowedMergeMethods = AllowedMergeMethods {
+        squash: false,
+        merge: true,
+        rebase: true,
+    };
+
+    #[test]
+    fn test_choose_merge_method_keeps_allowed_or_unknown_methods() {
+        let method = choose_merge_method(
+            Some(ONLY_MERGE_AND_REBASE),
+            Some(MergeMethod::Merge),
+            MergeMethod::Squash,
+        )
+        .unwrap();
+        assert_eq!(method, MergeMethod::Merge);
+
+        let method =
+            choose_merge_method(None, Some(MergeMethod::Squash), MergeMethod::Squash).unwrap();
+        assert_eq!(method, MergeMethod::Squash);
+    }
+
+    #[test]
+    fn test_choose_merge_method_default_falls_back_to_an_allowed_method() {
+        let method =
+            choose_merge_method(Some(ONLY_MERGE_AND_REBASE), None, MergeMethod::Squash).unwrap();
+        assert_eq!(method, MergeMethod::Rebase);
+    }
+
+    #[test]
+    fn test_choose_merge_method_rejects_disallowed_request() {
+        let err = choose_merge_method(
+            Some(ONLY_MERGE_AND_REBASE),
+            Some(MergeMethod::Squash),
+            MergeMethod::Squash,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            err.contains("does not allow squash merges (allowed: rebase, merge)"),
+            "{err}"
+        );
+        assert!(err.contains("--method rebase"), "{err}");
+    }
+
     #[test]
     fn test_calculate_scope_default_from_middle_keeps_descendants_remaining() {
         let stack = create_test_stack();
@@ -837,4 +1012,131 @@ mod tests {
         assert!(scope.to_merge.is_empty());
         assert_eq!(scope.remaining, vec!["feature-a", "feature-b", "feature-c"]);
     }
+
+    fn fake_pr_with_head(base: &str, head: &str) -> PrInfoWithHead {
+        PrInfoWithHead {
+            info: PrInfo {
+                number: 5,
+                state: "OPEN".to_string(),
+                is_draft: false,
+                base: base.to_string(),
+            },
+            head: head.to_string(),
+            head_label: None,
+            title: "test".to_string(),
+        }
+    }
+
+    fn fake_with_pr(base: &str, head: &str) -> FakeForge {
+        FakeForge {
+            pr_with_head: RefCell::new(Some(fake_pr_with_head(base, head))),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_update_pr_base_skips_patch_when_already_targeted() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let fake = fake_with_pr("main", "feature-b");
+
+        let result = update_pr_base_unless_current(&rt, &fake, 5, "main", "feature-b").unwrap();
+
+        assert_eq!(result, PrBaseUpdate::AlreadyTargeted);
+        assert!(fake.base_updates.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_update_pr_base_patches_when_base_differs() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let fake = fake_with_pr("feature-a", "feature-b");
+
+        let result = update_pr_base_unless_current(&rt, &fake, 5, "main", "feature-b").unwrap();
+
+        assert_eq!(result, PrBaseUpdate::Updated);
+        assert_eq!(*fake.base_updates.borrow(), vec![(5, "main".to_string())]);
+    }
+
+    #[test]
+    fn test_update_pr_base_rejects_unexpected_head() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let fake = fake_with_pr("feature-a", "someone-else");
+
+        let err = update_pr_base_unless_current(&rt, &fake, 5, "main", "feature-b").unwrap_err();
+
+        assert!(err.to_string().contains("expected 'feature-b'"));
+        assert!(fake.base_updates.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_update_pr_base_surfaces_forge_error() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let fake = FakeForge {
+            update_pr_base_error: Some("boom".to_string()),
+            ..fake_with_pr("feature-a", "feature-b")
+        };
+
+        let err = update_pr_base_unless_current(&rt, &fake, 5, "main", "feature-b").unwrap_err();
+
+        assert!(format!("{:#}", err).contains("failed to retarget PR #5 to main"));
+    }
+
+    #[test]
+    fn test_wait_for_pr_ready_returns_ready_status() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let fake = FakeForge {
+            merge_status: Some(merge_status("OPEN")),
+            ..Default::default()
+        };
+
+        let result = wait_for_pr_ready(
+            &rt,
+            &fake,
+            1,
+            Duration::from_secs(1),
+            Duration::from_millis(1),
+            BlockedReasonStyle::Detailed,
+            true,
+            false,
+        )
+        .unwrap();
+
+        assert!(matches!(result, WaitResult::Ready(status) if status.number == 1));
+    }
+
+    #[test]
+    fn test_wait_for_pr_ready_reports_draft_as_blocked() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let mut status = merge_status("OPEN");
+        status.is_draft = true;
+        let fake = FakeForge {
+            merge_status: Some(status),
+            ..Default::default()
+        };
+
+        let result = wait_for_pr_ready(
+            &rt,
+            &fake,
+            1,
+            Duration::from_secs(1),
+            Duration::from_millis(1),
+            BlockedReasonStyle::Detailed,
+            true,
+            false,
+        )
+        .unwrap();
+
+        assert!(matches!(result, WaitResult::Failed(reason) if reason.contains("Draft")));
+    }
+
+    #[test]
+    fn test_remote_moved_message_names_recorded_head_and_recovery() {
+        let message = remote_moved_message("origin", "feature-a", Some("0123456789abcdef"));
+
+        assert!(message.starts_with("remote moved, refusing to clobber origin/feature-a"));
+        assert!(message.contains("recorded it at 0123456"));
+        assert!(message.contains("git log feature-a..origin/feature-a"));
+
+        let absent = remote_moved_message("origin", "feature-a", None);
+        assert!(absent.contains("recorded it as absent"));
+    }
 }
@@ -6,7 +6,7 @@
 
 use crate::commands::merge_shared::{
     PrBaseUpdate, WaitResult, print_header, print_header_success,
-    rebase_and_finalize_remaining_branch, update_pr_base_unless_current,
+    rebase_and_finalize_remaining_branch, resolve_merge_method, update_pr_base_unless_current,
 };
 use crate::config::Config;
 use crate::engine::Stack;
@@ -80,18 +80,22 @@ pub fn run(
     downstack_only: bool,
     dry_run: bool,
     when_ready: bool,
-    method: MergeMethod,
+    method: Option<MergeMethod>,
     timeout_mins: u64,
     interval_secs: u64,
     no_delete: bool,
     no_sync: bool,
     yes: bool,
     quiet: bool,
+    request_timeout: Option<u64>,
+    remote: Option<String>,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(request_timeout)
+        .with_remote(remote.clone());
 
     if current == stack.trunk {
         if !quiet {
@@ -135,9 +139,12 @@ pub fn run(
 
     let rt = tokio::runtime::Runtime::new()?;
     let _enter = rt.enter();
-    let client = ForgeClient::new(&remote_info).context(
-        "Failed to connect to the configured forge. Check your token and remote configuration.",
-    )?;
+    let client = ForgeClient::new(&remote_info)
+        .context(
+            "Failed to connect to the configured forge. Check your token and remote configuration.",
+        )?
+        .with_quiet(quiet);
+    let method = resolve_merge_method(&rt, &client, method, MergeMethod::Rebase)?;
 
     let fetch_timer = LiveTimer::maybe_new(!quiet, "Fetching latest trunk...");
     let trunk_sha = fetch_and_verify_trunk_current(&repo, &remote_info, &scope.trunk)?;
@@ -322,7 +329,7 @@ pub fn run(
     }
 
     if !no_sync {
-        run_post_merge_sync(quiet);
+        run_post_merge_sync(quiet, request_timeout, remote);
     }
 
     if !quiet {
@@ -854,7 +861,7 @@ fn cleanup_local_stack(repo: &GitRepo, scope: &StackMergeScope, quiet: bool) ->
     Ok(())
 }
 
-fn run_post_merge_sync(quiet: bool) {
+fn run_post_merge_sync(quiet: bool, request_timeout: Option<u64>, remote: Option<String>) {
     if !quiet {
         println!();
         println!(
@@ -876,6 +883,11 @@ fn run_post_merge_sync(quiet: bool) {
         false, // verbose
         false, // auto_stash_pop
         &[],
+        crate::commands::sync::SyncOptions {
+            request_timeout,
+            remote,
+            ..Default::default()
+        },
     ) && !quiet
     {
         println!();
@@ -1051,6 +1063,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         }
     }
 
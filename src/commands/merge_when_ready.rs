@@ -3,9 +3,9 @@ use crate::commands::merge_rebase::{
 };
 use crate::commands::merge_shared::{
     BlockedReasonStyle, PrBaseUpdate, WaitResult, calculate_scope, print_header,
-    print_header_error, print_header_success, print_native_stack_locked_note,
-    rebase_and_finalize_remaining_branch, record_ci_history_for_branch, sync_head_after_push,
-    update_pr_base_unless_current, wait_for_pr_ready,
+    print_header_error, print_header_success, print_native_stack_locked_note, push_with_lease,
+    rebase_and_finalize_remaining_branch, record_ci_history_for_branch, recorded_remote_oid,
+    resolve_merge_method, sync_head_after_push, update_pr_base_unless_current, wait_for_pr_ready,
 };
 use crate::config::Config;
 use crate::engine::Stack;
@@ -87,18 +87,23 @@ impl LandStatus {
 pub fn run(
     all: bool,
     downstack_only: bool,
-    method: MergeMethod,
+    method: Option<MergeMethod>,
     timeout_mins: u64,
     interval_secs: u64,
     no_delete: bool,
     no_sync: bool,
     yes: bool,
     quiet: bool,
+    request_timeout: Option<u64>,
+    remote: Option<String>,
+    notify: bool,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(request_timeout)
+        .with_remote(remote.clone());
 
     // Validate: not on trunk
     if current == stack.trunk {
@@ -137,9 +142,12 @@ pub fn run(
     let remote_info = RemoteInfo::from_repo(&repo, &config)?;
     let rt = tokio::runtime::Runtime::new()?;
     let _enter = rt.enter();
-    let client = ForgeClient::new(&remote_info).context(
-        "Failed to connect to the configured forge. Check your token and remote configuration.",
-    )?;
+    let client = ForgeClient::new(&remote_info)
+        .context(
+            "Failed to connect to the configured forge. Check your token and remote configuration.",
+        )?
+        .with_quiet(quiet);
+    let method = resolve_merge_method(&rt, &client, method, MergeMethod::Squash)?;
 
     // Resolve PR numbers for merge scope and optional PR numbers for remaining scope.
     let fetch_timer = LiveTimer::maybe_new(!quiet, "Fetching PR info...");
@@ -285,6 +293,7 @@ pub fn run(
                 poll_interval,
                 BlockedReasonStyle::StatusText,
                 quiet,
+                notify,
             )? {
                 WaitResult::Ready(_) => {}
                 WaitResult::Failed(reason) => {
@@ -384,6 +393,8 @@ pub fn run(
                 &format!("Rebasing {} onto {}...", next_branch_name, scope.trunk),
             );
 
+            let expected_remote_oid =
+                recorded_remote_oid(&repo, &remote_info.name, &next_branch_name);
             let rebase_result = rebase_descendant_onto_remote_trunk_with_provenance(
                 &repo,
                 &next_branch_name,
@@ -414,20 +425,16 @@ pub fn run(
             let push_timer =
                 LiveTimer::maybe_new(!quiet, &format!("Pushing {}...", next_branch_name));
 
-            let push_status = Command::new("git")
-                .args([
-                    "push",
-                    "--force-with-lease",
-                    &remote_info.name,
-                    &next_branch_name,
-                ])
-                .current_dir(repo.workdir()?)
-                .output()
-                .context("Failed to push")?;
+            let push_result = push_with_lease(
+                &repo,
+                &remote_info.name,
+                &next_branch_name,
+                expected_remote_oid.as_deref(),
+            );
 
-            if !push_status.status.success() {
+            if let Err(push_error) = push_result {
                 LiveTimer::maybe_finish_err(push_timer, "failed");
-                let reason = "Failed to push rebased branch".to_string();
+                let reason = format!("Failed to push rebased branch: {:#}", push_error);
                 branches[idx + 1].status = LandStatus::Failed(reason.clone());
                 failed_pr = Some((next_branch_name, next_pr, reason));
                 break;
@@ -587,7 +594,6 @@ pub fn run(
             println!("  • Switched to: {}", checkout_after_cleanup.cyan());
         }
 
-        // Send macOS notification
         crate::notifications::send_desktop_notification(
             "stax merge --when-ready",
             &format!(
@@ -596,6 +602,7 @@ pub fn run(
                 if merged_prs.len() == 1 { "PR" } else { "PRs" },
                 scope.trunk
             ),
+            notify,
         );
 
         if !no_sync {
@@ -622,6 +629,11 @@ pub fn run(
                 false, // verbose
                 false, // auto_stash_pop
                 &[],
+                crate::commands::sync::SyncOptions {
+                    request_timeout,
+                    remote: remote.clone(),
+                    ..Default::default()
+                },
             ) && !quiet
             {
                 println!();
@@ -2,6 +2,7 @@ pub mod abort;
 pub mod absorb;
 pub mod auth;
 pub mod branch;
+pub mod cache_cmd;
 pub mod cascade;
 pub mod changelog;
 pub mod checkout;
@@ -40,6 +41,7 @@ pub mod range_diff;
 pub mod ready;
 pub mod redo;
 pub mod refresh;
+pub(crate) mod remote_select;
 pub mod reorder;
 pub mod resolve;
 pub(crate) mod resolve_pr;
@@ -56,6 +58,8 @@ pub mod standup;
 pub mod status;
 pub mod submit;
 pub(crate) mod submit_plan;
+pub(crate) mod submit_progress;
+pub(crate) mod submit_recovery;
 pub mod sweep;
 pub mod sync;
 pub mod tmux;
@@ -175,7 +175,10 @@ pub fn run(
             true,  // yes (skip confirmation)
             quiet,
             false, // auto_stash_pop
+            false, // show_conflicts
+            crate::application::RestackStrategy::Rebase,
             super::restack::SubmitAfterRestack::No,
+            None,
         )?;
     } else if !quiet && config.ui.tips {
         println!(
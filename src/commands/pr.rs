@@ -2,19 +2,30 @@ use crate::application::{
     NoopOperationReporter, OperationErrorDetails, OperationErrorKind, OperationOutcome,
     RepositorySession,
 };
+use crate::cache::CiCache;
+use crate::ci::CheckRunInfo;
+use crate::commands::get::{self, ExistingBranch, GetOptions};
 use crate::commands::github_list::{
     CellTone, TableCell, TableColumn, TruncationMode, format_relative_time, print_table,
     split_flexible_width, terminal_width,
 };
+use crate::commands::merge_shared::{
+    PrBaseUpdate, blocked_reason, print_native_stack_locked_note, resolve_merge_method,
+    update_pr_base_unless_current,
+};
 use crate::commands::open::open_url_in_browser;
 use crate::config::Config;
-use crate::engine::Stack;
-use crate::forge::{ForgeClient, RepoPrListItem};
+use crate::engine::metadata::PrInfo;
+use crate::engine::{BranchMetadata, Stack};
+use crate::forge::{ForgeClient, MergeMethod, PrDetails, RepoPrListItem};
 use crate::git::GitRepo;
+use crate::progress::LiveTimer;
 use crate::remote::RemoteInfo;
 use anyhow::{Context, Result, bail};
 use colored::Colorize;
-use std::io::Write;
+use dialoguer::{Confirm, theme::ColorfulTheme};
+use serde::Serialize;
+use std::io::{IsTerminal, Write};
 use std::process::Command;
 use termimad::MadSkin;
 
@@ -73,6 +84,7 @@ pub fn run_open() -> Result<()> {
 }
 
 /// List open pull requests for the current repository.
+#[allow(clippy::too_many_arguments)]
 pub fn run_list(
     limit: u8,
     json: bool,
@@ -81,17 +93,19 @@ pub fn run_list(
     current: bool,
     stack: bool,
     plain: bool,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     if ready {
         return crate::commands::ready::run(
             crate::commands::ready::ReadyScopeMode::from_flags(all, current, stack),
             json,
             plain,
+            request_timeout,
         );
     }
 
     let repo = GitRepo::open()?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let remote_info = RemoteInfo::from_repo(&repo, &config)?;
     let repo_label = format!("{}/{}", remote_info.namespace, remote_info.repo);
 
@@ -110,12 +124,43 @@ pub fn run_list(
     Ok(())
 }
 
+/// Fetch and checkout a PR by number; with `stack`, rebuild the PRs below it too.
+///
+/// Branches are left at their PR heads (no restack) so reviewers see exactly what
+/// was pushed. An existing local branch is only fast-forwarded; local commits
+/// the PR lacks make it refuse unless `force` resets the branch.
+pub fn run_checkout(
+    number: u64,
+    stack: bool,
+    force: bool,
+    no_ff: bool,
+    request_timeout: Option<u64>,
+) -> Result<()> {
+    get::run(GetOptions {
+        branch: Some(number.to_string()),
+        parent: None,
+        no_checkout: false,
+        force,
+        downstack: true,
+        remote_upstack: false,
+        no_restack: true,
+        unfrozen: false,
+        pr_stack: stack,
+        existing: if no_ff {
+            ExistingBranch::Refuse
+        } else {
+            ExistingBranch::FastForward
+        },
+        request_timeout,
+    })
+}
+
 /// Print or edit the PR body for the current branch.
-pub fn run_body(edit: bool) -> Result<()> {
+pub fn run_body(edit: bool, request_timeout: Option<u64>) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
 
     let branch_info = stack.branches.get(&current);
     if branch_info.is_none() {
@@ -158,6 +203,380 @@ pub fn run_body(edit: bool) -> Result<()> {
     Ok(())
 }
 
+#[derive(Serialize)]
+struct PrViewJson {
+    #[serde(flatten)]
+    pr: PrDetails,
+    ci: Option<CiSummary>,
+}
+
+/// Overall CI state plus per-check counts for the PR head commit.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+struct CiSummary {
+    state: Option<String>,
+    passed: usize,
+    failed: usize,
+    pending: usize,
+}
+
+impl CiSummary {
+    fn from_checks(state: Option<String>, checks: &[CheckRunInfo]) -> Self {
+        let mut summary = Self {
+            state,
+            passed: 0,
+            failed: 0,
+            pending: 0,
+        };
+        for check in checks {
+            match check.conclusion.as_deref() {
+                Some("success" | "neutral" | "skipped") if check.status == "completed" => {
+                    summary.passed += 1
+                }
+                Some(_) if check.status == "completed" => summary.failed += 1,
+                _ => summary.pending += 1,
+            }
+        }
+        summary
+    }
+}
+
+/// Show a PR (by number, or the current branch's) in the terminal.
+pub fn run_view(number: Option<u64>, json: bool, request_timeout: Option<u64>) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
+
+    let pr_number = match number {
+        Some(number) => number,
+        None => {
+            let current = repo.current_branch()?;
+            let stack = Stack::load(&repo)?;
+            match super::resolve_pr::resolve_pr_number(&repo, &stack, &current, &config)? {
+                Some(number) => number,
+                None => {
+                    println!(
+                        "No PR found for branch '{}'. Use {} to create one.",
+                        current,
+                        "stax submit".cyan()
+                    );
+                    return Ok(());
+                }
+            }
+        }
+    };
+
+    let remote_info = RemoteInfo::from_repo(&repo, &config)?;
+    let rt = tokio::runtime::Runtime::new()?;
+    let _enter = rt.enter();
+    let client = ForgeClient::new(&remote_info)?;
+    let (mut pr, ci) = rt.block_on(async {
+        let pr = client.get_pr_details(pr_number).await?;
+        // CI is a nice-to-have; a failed lookup just leaves it out.
+        let ci = if pr.head_sha.is_empty() {
+            None
+        } else {
+            client
+                .fetch_checks(&repo, &pr.head_sha)
+                .await
+                .ok()
+                .map(|(state, checks)| CiSummary::from_checks(state, &checks))
+        };
+        Ok::<_, anyhow::Error>((pr, ci))
+    })?;
+    if pr.url.is_empty() {
+        pr.url = remote_info.pr_url(pr_number);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&PrViewJson { pr, ci })?);
+        return Ok(());
+    }
+
+    print_pr_view(&pr, ci.as_ref());
+    Ok(())
+}
+
+fn print_pr_view(pr: &PrDetails, ci: Option<&CiSummary>) {
+    let state = if pr.is_draft && pr.state == "OPEN" {
+        "draft".to_string()
+    } else {
+        pr.state.to_lowercase()
+    };
+    let state = match state.as_str() {
+        "open" => state.green(),
+        "merged" => state.magenta(),
+        "closed" => state.red(),
+        _ => state.yellow(),
+    };
+    println!(
+        "{} {} ({})",
+        format!("#{}", pr.number).cyan(),
+        pr.title.bold(),
+        state
+    );
+    if !pr.author.is_empty() {
+        println!("{} {}", "Author:   ".dimmed(), pr.author);
+    }
+    println!(
+        "{} {} ← {}",
+        "Branches: ".dimmed(),
+        pr.base_branch.blue(),
+        pr.head_branch.green()
+    );
+    if !pr.labels.is_empty() {
+        println!("{} {}", "Labels:   ".dimmed(), pr.labels.join(", "));
+    }
+    if !pr.reviewers.is_empty() {
+        println!("{} {}", "Reviewers:".dimmed(), pr.reviewers.join(", "));
+    }
+    if let Some(ci) = ci {
+        let state = ci.state.as_deref().unwrap_or("none");
+        println!(
+            "{} {} ({} passed, {} failed, {} pending)",
+            "CI:       ".dimmed(),
+            state,
+            ci.passed,
+            ci.failed,
+            ci.pending
+        );
+    }
+    println!("{} {}", "URL:      ".dimmed(), pr.url.underline());
+
+    if pr.body.trim().is_empty() {
+        println!();
+        println!("{}", "No description provided.".dimmed());
+    } else {
+        println!();
+        print_rendered_body(&pr.body);
+    }
+}
+
+/// Close the current branch's PR without merging, optionally commenting first.
+pub fn run_close(comment: Option<&str>, request_timeout: Option<u64>) -> Result<()> {
+    set_current_pr_open(false, comment, request_timeout)
+}
+
+/// Reopen the current branch's closed PR.
+pub fn run_reopen(request_timeout: Option<u64>) -> Result<()> {
+    set_current_pr_open(true, None, request_timeout)
+}
+
+fn set_current_pr_open(
+    open: bool,
+    comment: Option<&str>,
+    request_timeout: Option<u64>,
+) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let current = repo.current_branch()?;
+    let stack = Stack::load(&repo)?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
+
+    if !stack.branches.contains_key(&current) {
+        bail!(
+            "Branch '{}' is not tracked. Use {} to track it first.",
+            current,
+            "stax branch track".cyan()
+        );
+    }
+
+    let Some(pr_number) = super::resolve_pr::resolve_pr_number(&repo, &stack, &current, &config)?
+    else {
+        bail!(
+            "No PR found for branch '{}'. Use {} to create one.",
+            current,
+            "stax submit".cyan()
+        );
+    };
+
+    let remote_info = RemoteInfo::from_repo(&repo, &config)?;
+    let rt = tokio::runtime::Runtime::new()?;
+    let _enter = rt.enter();
+    let client = ForgeClient::new(&remote_info)?;
+
+    if let Some(comment) = comment.map(str::trim).filter(|c| !c.is_empty()) {
+        rt.block_on(async { client.create_issue_comment(pr_number, comment).await })?;
+    }
+    if open {
+        rt.block_on(async { client.reopen_pr(pr_number).await })?;
+    } else {
+        rt.block_on(async { client.close_pr(pr_number).await })?;
+    }
+
+    let state = if open { "OPEN" } else { "CLOSED" };
+    if let Some(mut meta) = BranchMetadata::read(repo.inner(), &current)? {
+        match meta.pr_info {
+            Some(ref mut pr_info) => pr_info.state = state.to_string(),
+            None => {
+                meta.pr_info = Some(PrInfo {
+                    number: pr_number,
+                    state: state.to_string(),
+                    is_draft: None,
+                    head_owner: None,
+                })
+            }
+        }
+        meta.write(repo.inner(), &current)?;
+    }
+    let _ = CiCache::update_branch_pr(&repo.common_git_dir()?, &current, Some(state.to_string()));
+
+    let verb = if open {
+        "reopened".green()
+    } else {
+        "closed".yellow()
+    };
+    println!(
+        "PR #{} on {} {}.",
+        pr_number.to_string().cyan(),
+        current.cyan(),
+        verb
+    );
+    Ok(())
+}
+
+/// Merge only the current branch's PR.
+///
+/// Unlike `stax merge`, this never walks the stack: it checks readiness once,
+/// merges the PR at the head SHA that was checked, and then offers to retarget
+/// the PRs of the branch's immediate children to trunk.
+pub fn run_merge(
+    method: Option<MergeMethod>,
+    yes: bool,
+    quiet: bool,
+    request_timeout: Option<u64>,
+) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let current = repo.current_branch()?;
+    let stack = Stack::load(&repo)?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
+
+    if current == stack.trunk {
+        bail!("Cannot merge trunk. Checkout a stacked branch first.");
+    }
+    if !stack.branches.contains_key(&current) {
+        bail!(
+            "Branch '{}' is not tracked. Use {} to track it first.",
+            current,
+            "stax branch track".cyan()
+        );
+    }
+
+    let Some(pr_number) = super::resolve_pr::resolve_pr_number(&repo, &stack, &current, &config)?
+    else {
+        bail!(
+            "No PR found for branch '{}'. Use {} to create one.",
+            current,
+            "stax submit".cyan()
+        );
+    };
+
+    let remote_info = RemoteInfo::from_repo(&repo, &config)?;
+    let rt = tokio::runtime::Runtime::new()?;
+    let _enter = rt.enter();
+    let client = ForgeClient::new(&remote_info)?;
+    let method = resolve_merge_method(&rt, &client, method, MergeMethod::Squash)?;
+
+    // Fail closed: if readiness (CI, reviews, mergeability) cannot be read,
+    // do not merge.
+    let status = rt
+        .block_on(async { client.get_pr_merge_status(pr_number).await })
+        .with_context(|| {
+            format!(
+                "Could not check whether PR #{} is ready; refusing to merge",
+                pr_number
+            )
+        })?;
+    if status.review_decision.as_deref() == Some("REVIEW_REQUIRED") {
+        bail!("PR #{} is not ready to merge: review required", pr_number);
+    }
+    if !status.is_ready() {
+        bail!(
+            "PR #{} is not ready to merge: {}",
+            pr_number,
+            blocked_reason(&status)
+        );
+    }
+
+    let merge_timer = LiveTimer::maybe_new(
+        !quiet,
+        &format!(
+            "Merging #{} {} ({})...",
+            pr_number,
+            current,
+            method.as_str()
+        ),
+    );
+    if let Err(e) = rt.block_on(async {
+        client
+            .merge_pr(pr_number, method, None, Some(&status.head_sha))
+            .await
+    }) {
+        LiveTimer::maybe_finish_err(merge_timer, "failed");
+        return Err(e);
+    }
+    LiveTimer::maybe_finish_ok(merge_timer, "merged");
+
+    let children_with_prs: Vec<(String, u64)> = stack
+        .children(&current)
+        .into_iter()
+        .filter_map(|child| {
+            let pr = stack.branches.get(&child).and_then(|b| b.pr_number)?;
+            Some((child, pr))
+        })
+        .collect();
+
+    for (child, child_pr) in children_with_prs {
+        let retarget = yes
+            || (std::io::stdin().is_terminal()
+                && std::io::stderr().is_terminal()
+                && Confirm::with_theme(&ColorfulTheme::default())
+                    .with_prompt(format!(
+                        "Retarget #{} ({}) to {}?",
+                        child_pr, child, stack.trunk
+                    ))
+                    .default(true)
+                    .interact()?);
+        if !retarget {
+            if !quiet {
+                println!(
+                    "  {} #{} still targets {}; run {} to update it",
+                    "note:".dimmed(),
+                    child_pr,
+                    current,
+                    "stax sync --restack".cyan()
+                );
+            }
+            continue;
+        }
+
+        let timer = LiveTimer::maybe_new(
+            !quiet,
+            &format!("Retargeting #{} to {}...", child_pr, stack.trunk),
+        );
+        match update_pr_base_unless_current(&rt, &client, child_pr, &stack.trunk, &child) {
+            Ok(PrBaseUpdate::Updated) => LiveTimer::maybe_finish_ok(timer, "done"),
+            Ok(PrBaseUpdate::AlreadyTargeted) => {
+                LiveTimer::maybe_finish_ok(timer, "already on base")
+            }
+            Ok(PrBaseUpdate::NativeStackLocked) => {
+                LiveTimer::maybe_finish_warn(timer, "skipped (native Stack)");
+                print_native_stack_locked_note(quiet, child_pr);
+            }
+            Err(e) => {
+                LiveTimer::maybe_finish_err(timer, "failed");
+                return Err(e);
+            }
+        }
+    }
+
+    if !quiet {
+        println!(
+            "{} Run {} to clean up the merged branch locally.",
+            "✓".green(),
+            "stax sync".cyan()
+        );
+    }
+    Ok(())
+}
+
 fn print_rendered_body(body: &str) {
     let body = body.trim();
     if body.is_empty() {
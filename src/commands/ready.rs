@@ -2,7 +2,7 @@ use crate::cache::CiCache;
 use crate::ci::CheckRunInfo;
 use crate::config::Config;
 use crate::engine::{BranchMetadata, PrInfo, Stack};
-use crate::forge::{ForgeClient, forge_token};
+use crate::forge::{ForgeClient, forge_auth_configured};
 use crate::git::GitRepo;
 use crate::github::pr::{CiStatus, PrMergeStatus};
 use crate::remote::RemoteInfo;
@@ -269,26 +269,31 @@ impl ReadyScopeMode {
     }
 }
 
-pub fn run(scope_mode: ReadyScopeMode, json: bool, plain: bool) -> Result<()> {
+pub fn run(
+    scope_mode: ReadyScopeMode,
+    json: bool,
+    plain: bool,
+    request_timeout: Option<u64>,
+) -> Result<()> {
     if json {
-        return run_static(scope_mode, true);
+        return run_static(scope_mode, true, request_timeout);
     }
 
     if !plain && std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
         return crate::tui::ready::run(scope_mode);
     }
 
-    run_static(scope_mode, false)
+    run_static(scope_mode, false, request_timeout)
 }
 
-fn run_static(scope_mode: ReadyScopeMode, json: bool) -> Result<()> {
+fn run_static(scope_mode: ReadyScopeMode, json: bool, request_timeout: Option<u64>) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let remote = RemoteInfo::from_repo(&repo, &config)?;
 
-    if forge_token(remote.forge).is_none() {
+    if !forge_auth_configured(remote.forge, &remote.host) {
         anyhow::bail!(
             "{} auth not configured; live PR readiness cannot be fetched.",
             remote.forge
@@ -332,7 +337,7 @@ pub(crate) fn load_ready_scope(scope_mode: ReadyScopeMode) -> Result<ReadyScope>
     let config = Config::load()?;
     let remote = RemoteInfo::from_repo(&repo, &config)?;
 
-    if forge_token(remote.forge).is_none() {
+    if !forge_auth_configured(remote.forge, &remote.host) {
         anyhow::bail!(
             "{} auth not configured; live PR readiness cannot be fetched.",
             remote.forge
@@ -393,10 +398,12 @@ pub(crate) fn warm_caches_for_ready_row(
     }
 
     if let Some(mut meta) = BranchMetadata::read(repo.inner(), &row.branch)? {
+        let head_owner = meta.pr_info.take().and_then(|pr_info| pr_info.head_owner);
         meta.pr_info = Some(PrInfo {
             number: row.pr_number,
             state: row.pr_state.clone(),
             is_draft: Some(row.is_draft),
+            head_owner,
         });
         meta.write(repo.inner(), &row.branch)?;
     }
@@ -719,6 +726,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
         overrides(&mut status);
         status
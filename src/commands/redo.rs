@@ -8,7 +8,13 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use dialoguer::{Confirm, theme::ColorfulTheme};
 
-pub fn run(op_id: Option<String>, yes: bool, no_push: bool, quiet: bool) -> Result<()> {
+pub fn run(
+    op_id: Option<String>,
+    yes: bool,
+    no_push: bool,
+    quiet: bool,
+    autostash: bool,
+) -> Result<()> {
     let repo = GitRepo::open()?;
     let git_dir = repo.git_dir()?;
 
@@ -52,7 +58,13 @@ pub fn run(op_id: Option<String>, yes: bool, no_push: bool, quiet: bool) -> Resu
     }
 
     // Check for dirty working tree
-    if repo.is_dirty()? {
+    let mut autostashed = false;
+    if autostash && repo.is_dirty()? {
+        autostashed = repo.stash_push()?;
+        if autostashed && !quiet {
+            println!("  {} Stashed working tree changes.", "✓".green());
+        }
+    } else if repo.is_dirty()? {
         if quiet {
             anyhow::bail!("Working tree is dirty. Please stash or commit changes first.");
         }
@@ -85,7 +97,11 @@ pub fn run(op_id: Option<String>, yes: bool, no_push: bool, quiet: bool) -> Resu
         Some(&receipt.op_id),
         false,
         &mut NoopOperationReporter,
-    )?;
+    );
+    if autostashed {
+        super::undo::restore_autostash(&repo, quiet);
+    }
+    let operation = operation?;
     let restored_count = match operation.outcome {
         OperationOutcome::TransactionRedone { changed_refs, .. } => changed_refs.len(),
         _ => 0,
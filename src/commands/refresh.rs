@@ -14,6 +14,7 @@ pub fn run(
     yes: bool,
     no_prompt: bool,
     auto_stash_pop: bool,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let original = repo.current_branch()?;
@@ -53,6 +54,10 @@ pub fn run(
         verbose,
         auto_stash_pop,
         &submit_fetch_refs,
+        commands::sync::SyncOptions {
+            request_timeout,
+            ..Default::default()
+        },
     )?;
 
     if repo.rebase_in_progress()? {
@@ -71,6 +76,7 @@ pub fn run(
             yes,
             no_prompt,
             verbose,
+            request_timeout,
             ..Default::default()
         },
     )?;
@@ -0,0 +1,78 @@
+//! `--remote <name>` for submit, sync, and merge, plus the per-stack remote
+//! recorded in branch metadata so later runs reuse it without the flag.
+
+use crate::engine::{BranchMetadata, Stack};
+use crate::git::GitRepo;
+use crate::remote;
+use anyhow::{Result, bail};
+use std::collections::HashSet;
+
+/// Select the remote for this command: an explicit `--remote` wins, otherwise
+/// the remote recorded on the current stack. `None` (neither is set) keeps the
+/// configured remotes; callers pass the result to `Config::with_remote`.
+pub fn resolve(explicit: Option<&str>) -> Result<Option<String>> {
+    Ok(match explicit {
+        Some(name) => {
+            ensure_remote_exists(&GitRepo::open()?, name)?;
+            Some(name.to_string())
+        }
+        // Outside a repository the command itself reports the error.
+        None => match GitRepo::open() {
+            Ok(repo) => recorded_stack_remote(&repo)?,
+            Err(_) => None,
+        },
+    })
+}
+
+/// Remember `name` on every branch of the current stack.
+pub fn record(name: &str) -> Result<()> {
+    let repo = GitRepo::open()?;
+    let stack = Stack::load(&repo)?;
+    let current = repo.current_branch()?;
+    for branch in stack.current_stack(&current) {
+        if branch == stack.trunk {
+            continue;
+        }
+        let Some(meta) = BranchMetadata::read(repo.inner(), &branch)? else {
+            continue;
+        };
+        if meta.remote.as_deref() == Some(name) {
+            continue;
+        }
+        BranchMetadata {
+            remote: Some(name.to_string()),
+            ..meta
+        }
+        .write(repo.inner(), &branch)?;
+    }
+    Ok(())
+}
+
+fn ensure_remote_exists(repo: &GitRepo, name: &str) -> Result<()> {
+    if remote::get_remote_url(repo.workdir()?, name).is_err() {
+        bail!(
+            "Remote '{}' not found. Add it with `git remote add {} <url>`.",
+            name,
+            name
+        );
+    }
+    Ok(())
+}
+
+/// First remote recorded on the current branch or one of its ancestors.
+fn recorded_stack_remote(repo: &GitRepo) -> Result<Option<String>> {
+    let Ok(mut branch) = repo.current_branch() else {
+        return Ok(None);
+    };
+    let mut visited = HashSet::new();
+    while visited.insert(branch.clone()) {
+        let Some(meta) = BranchMetadata::read(repo.inner(), &branch)? else {
+            break;
+        };
+        if meta.remote.is_some() {
+            return Ok(meta.remote);
+        }
+        branch = meta.parent_branch_name;
+    }
+    Ok(None)
+}
@@ -1,13 +1,12 @@
 use crate::application::{
-    NoopOperationReporter, OperationError, OperationErrorDetails, OperationErrorKind,
-    OperationOutcome, OperationReceipt, OperationWarning, RepositorySession,
-    RestackExecutionOptions, RestackScope,
+    OperationError, OperationErrorDetails, OperationErrorKind, OperationOutcome, OperationReceipt,
+    OperationWarning, RepositorySession, RestackExecutionOptions, RestackScope, RestackStrategy,
 };
 use crate::commands::restack_conflict::{RestackConflictContext, print_restack_conflict};
 use crate::engine::{BranchMetadata, Stack};
 use crate::errors::ConflictStopped;
 use crate::git::GitRepo;
-use crate::progress::LiveTimer;
+use crate::progress::{LiveTimer, StepProgressReporter};
 use anyhow::{Result, anyhow};
 use colored::Colorize;
 use dialoguer::{Confirm, theme::ColorfulTheme};
@@ -30,7 +29,10 @@ pub fn run(
     yes: bool,
     quiet: bool,
     auto_stash_pop: bool,
+    show_conflicts: bool,
+    strategy: RestackStrategy,
     submit_after: SubmitAfterRestack,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
 
@@ -77,7 +79,10 @@ pub fn run(
         dry_run,
         quiet,
         auto_stash_pop,
+        show_conflicts,
+        strategy,
         submit_after,
+        request_timeout,
         None,
         completed_from_receipt,
     )
@@ -95,7 +100,10 @@ pub(crate) fn resume_after_rebase(
         false,
         false,
         auto_stash_pop,
+        false,
+        RestackStrategy::Rebase,
         SubmitAfterRestack::No,
+        None,
         restore_branch,
         HashSet::new(),
     )
@@ -109,7 +117,10 @@ fn run_adapter(
     dry_run: bool,
     quiet: bool,
     mut auto_stash_pop: bool,
+    show_conflicts: bool,
+    strategy: RestackStrategy,
     submit_after: SubmitAfterRestack,
+    request_timeout: Option<u64>,
     restore_branch: Option<String>,
     completed_from_receipt: HashSet<String>,
 ) -> Result<()> {
@@ -143,22 +154,24 @@ fn run_adapter(
         auto_stash: auto_stash_pop,
         restore_branch,
         completed_from_receipt,
+        strategy,
     };
-    let receipt = match session.restack_with_options(options, &mut NoopOperationReporter) {
-        Ok(receipt) => receipt,
-        Err(error) if error.kind == OperationErrorKind::RebaseConflict => {
-            render_restack_error(repo, &error, false);
-            return Err(ConflictStopped.into());
-        }
-        Err(error) => return Err(operation_error(error)),
-    };
+    let receipt =
+        match session.restack_with_options(options, &mut StepProgressReporter::new(!quiet)) {
+            Ok(receipt) => receipt,
+            Err(error) if error.kind == OperationErrorKind::RebaseConflict => {
+                render_restack_error(repo, &error, false, show_conflicts);
+                return Err(ConflictStopped.into());
+            }
+            Err(error) => return Err(operation_error(error)),
+        };
 
     render_restack_receipt(&receipt, quiet);
     let restacked = restacked_branches(&receipt);
     let should_submit = should_submit_after_restack(&restacked, quiet, submit_after)?;
 
     if should_submit {
-        submit_after_restack(quiet)?;
+        submit_after_restack(quiet, request_timeout)?;
     }
 
     Ok(())
@@ -365,7 +378,7 @@ fn restacked_branches(receipt: &OperationReceipt) -> Vec<String> {
     }
 }
 
-fn render_restack_error(repo: &GitRepo, error: &OperationError, quiet: bool) {
+fn render_restack_error(repo: &GitRepo, error: &OperationError, quiet: bool, show_conflicts: bool) {
     if quiet {
         return;
     }
@@ -398,6 +411,7 @@ fn render_restack_error(repo: &GitRepo, error: &OperationError, quiet: bool) {
             remaining_branches: remaining,
             continue_commands: &["stax restack --continue", "git rebase --abort"],
             stack_branches: &stack_branches,
+            show_hunks: show_conflicts,
         };
         print_restack_conflict(repo, &context);
         return;
@@ -415,7 +429,7 @@ fn operation_error(error: OperationError) -> anyhow::Error {
     )
 }
 
-fn submit_after_restack(quiet: bool) -> Result<()> {
+fn submit_after_restack(quiet: bool, request_timeout: Option<u64>) -> Result<()> {
     if !quiet {
         println!();
     }
@@ -426,6 +440,7 @@ fn submit_after_restack(quiet: bool) -> Result<()> {
             yes: true,
             no_prompt: true,
             quiet,
+            request_timeout,
             ..Default::default()
         },
     )?;
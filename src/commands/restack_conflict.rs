@@ -1,6 +1,9 @@
-use crate::git::GitRepo;
+use crate::git::{GitRepo, command};
 use colored::Colorize;
 
+/// Conflict preview lines shown per file before truncating.
+const PREVIEW_LINES_PER_FILE: usize = 12;
+
 pub(crate) struct RestackConflictContext<'a> {
     pub branch: &'a str,
     pub parent_branch: &'a str,
@@ -10,6 +13,8 @@ pub(crate) struct RestackConflictContext<'a> {
     /// Ordered list of branches in the current stack (from trunk to leaf) for
     /// the conflict position indicator.
     pub stack_branches: &'a [String],
+    /// Preview conflicting hunks inline (`--show-conflicts`).
+    pub show_hunks: bool,
 }
 
 pub(crate) fn print_restack_conflict(repo: &GitRepo, context: &RestackConflictContext<'_>) {
@@ -27,6 +32,21 @@ pub(crate) fn print_restack_conflict(repo: &GitRepo, context: &RestackConflictCo
     for line in render_restack_conflict_details(context, &conflicted_files) {
         println!("{}", line);
     }
+    if context.show_hunks && !conflicted_files.is_empty() {
+        println!();
+        println!("{}", "Conflict preview:".yellow());
+        for path in &conflicted_files {
+            let diff = repo
+                .workdir()
+                .ok()
+                .and_then(|workdir| command::output(workdir, &["diff", "--", path]).ok())
+                .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+                .unwrap_or_default();
+            for line in render_conflict_preview(path, &diff) {
+                println!("{}", line);
+            }
+        }
+    }
     println!();
     println!("{}", "Resolve conflicts and run:".yellow());
     for command in context.continue_commands {
@@ -93,6 +113,31 @@ fn render_restack_conflict_details(
     lines
 }
 
+/// Render the hunks of a conflicted file's `git diff`, capped at
+/// [`PREVIEW_LINES_PER_FILE`] lines.
+fn render_conflict_preview(path: &str, diff: &str) -> Vec<String> {
+    let hunk_lines: Vec<&str> = diff
+        .lines()
+        .skip_while(|line| !line.starts_with("@@"))
+        .collect();
+
+    let mut lines = vec![format!("  {}", path)];
+    if hunk_lines.is_empty() {
+        lines.push("    (no diff available; open the file to resolve)".to_string());
+        return lines;
+    }
+    for line in hunk_lines.iter().take(PREVIEW_LINES_PER_FILE) {
+        lines.push(format!("    {}", line));
+    }
+    if hunk_lines.len() > PREVIEW_LINES_PER_FILE {
+        lines.push(format!(
+            "    … ({} more lines)",
+            hunk_lines.len() - PREVIEW_LINES_PER_FILE
+        ));
+    }
+    lines
+}
+
 fn branch_count_label(count: usize) -> String {
     format!(
         "{} {}",
@@ -103,7 +148,10 @@ fn branch_count_label(count: usize) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::{RestackConflictContext, render_restack_conflict_details, render_stack_position};
+    use super::{
+        PREVIEW_LINES_PER_FILE, RestackConflictContext, render_conflict_preview,
+        render_restack_conflict_details, render_stack_position,
+    };
 
     #[test]
     fn renders_progress_completed_branches_and_conflicted_files() {
@@ -122,6 +170,7 @@ mod tests {
             remaining_branches: 1,
             continue_commands: &["stax continue"],
             stack_branches: &stack_branches,
+            show_hunks: false,
         };
 
         let lines = render_restack_conflict_details(&context, &conflicted_files);
@@ -149,6 +198,7 @@ mod tests {
             remaining_branches: 0,
             continue_commands: &["stax continue"],
             stack_branches: &stack_branches,
+            show_hunks: false,
         };
 
         let lines = render_restack_conflict_details(&context, &[]);
@@ -199,4 +249,36 @@ mod tests {
         assert!(lines[0].contains("conflict"));
         assert!(lines[1].contains("main"));
     }
+
+    #[test]
+    fn renders_conflict_preview_from_first_hunk_and_truncates() {
+        let mut diff = String::from(
+            "diff --cc shared.txt\nindex 1,1..0 100644\n--- a/shared.txt\n+++ b/shared.txt\n@@@ -1,1 -1,1 +1,5 @@@\n",
+        );
+        diff.push_str("++<<<<<<< HEAD\n +parent\n++=======\n+ child\n++>>>>>>> child\n");
+        for i in 0..PREVIEW_LINES_PER_FILE {
+            diff.push_str(&format!("  context {}\n", i));
+        }
+
+        let lines = render_conflict_preview("shared.txt", &diff);
+
+        assert_eq!(lines[0], "  shared.txt");
+        assert_eq!(lines[1], "    @@@ -1,1 -1,1 +1,5 @@@");
+        assert_eq!(lines[2], "    ++<<<<<<< HEAD");
+        assert_eq!(lines.len(), PREVIEW_LINES_PER_FILE + 2);
+        assert_eq!(lines.last().unwrap(), "    … (6 more lines)");
+    }
+
+    #[test]
+    fn renders_conflict_preview_placeholder_without_hunks() {
+        let lines = render_conflict_preview("binary.png", "");
+
+        assert_eq!(
+            lines,
+            vec![
+                "  binary.png".to_string(),
+                "    (no diff available; open the file to resolve)".to_string()
+            ]
+        );
+    }
 }
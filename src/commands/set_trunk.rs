@@ -14,6 +14,10 @@ pub fn run(branch: &str) -> Result<()> {
     }
 
     refs::write_trunk(repo.inner(), branch)?;
+    // Keep a pinned trunk in sync so it doesn't silently override this choice
+    if repo.explicit_trunk()?.is_some() {
+        refs::write_explicit_trunk(repo.inner(), branch)?;
+    }
     println!("Trunk branch set to '{}'", branch);
     Ok(())
 }
@@ -570,7 +570,7 @@ fn maybe_install_skills(options: SetupOptions) -> Result<()> {
 }
 
 fn maybe_setup_auth(options: SetupOptions) -> Result<()> {
-    let status = crate::config::Config::github_auth_status();
+    let status = crate::config::Config::github_auth_status(None);
     if has_non_gh_auth(&status) {
         return Ok(());
     }
@@ -620,7 +620,7 @@ fn handle_default_auth_setup(
 
 fn import_auth_from_gh() -> Result<()> {
     println!();
-    crate::commands::auth::run(None, true)
+    crate::commands::auth::run(None, true, None)
 }
 
 fn prompt_for_skill_install() -> Result<bool> {
@@ -20,9 +20,9 @@ use std::time::{SystemTime, UNIX_EPOCH};
 // validate
 // =========================================================================
 
-pub fn run_link() -> Result<()> {
+pub fn run_link(request_timeout: Option<u64>) -> Result<()> {
     let repo = GitRepo::open()?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let remote_info = RemoteInfo::from_repo(&repo, &config)?;
     if remote_info.forge != ForgeType::GitHub {
         anyhow::bail!(
@@ -109,9 +109,9 @@ pub fn run_link() -> Result<()> {
     }
 }
 
-pub fn run_unlink(stack_number: Option<u64>) -> Result<()> {
+pub fn run_unlink(stack_number: Option<u64>, request_timeout: Option<u64>) -> Result<()> {
     let repo = GitRepo::open()?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let remote_info = RemoteInfo::from_repo(&repo, &config)?;
     if remote_info.forge != ForgeType::GitHub {
         anyhow::bail!(
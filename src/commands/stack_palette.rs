@@ -1,3 +1,4 @@
+use crate::config::TreeStyle;
 use colored::Color as AnsiColor;
 use console::Color as ConsoleColor;
 
@@ -25,3 +26,55 @@ pub(crate) fn lane_console_color(column: usize) -> ConsoleColor {
     let (r, g, b) = lane_rgb(column);
     ConsoleColor::TrueColor(r, g, b)
 }
+
+/// Connector glyphs for stack trees. ASCII glyphs keep the Unicode display widths
+/// so branch names stay aligned in either style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TreeGlyphs {
+    pub(crate) current: &'static str,
+    pub(crate) node: &'static str,
+    pub(crate) vertical: &'static str,
+    pub(crate) corner: &'static str,
+    pub(crate) tee: &'static str,
+}
+
+pub(crate) fn tree_glyphs(style: TreeStyle) -> TreeGlyphs {
+    match style {
+        TreeStyle::Unicode => TreeGlyphs {
+            current: "◉",
+            node: "○",
+            vertical: "│",
+            corner: "─┘",
+            tee: "─┴",
+        },
+        TreeStyle::Ascii => TreeGlyphs {
+            current: "@",
+            node: "o",
+            vertical: "|",
+            corner: "-`",
+            tee: "-+",
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::measure_text_width;
+
+    #[test]
+    fn ascii_glyphs_match_unicode_widths() {
+        let unicode = tree_glyphs(TreeStyle::Unicode);
+        let ascii = tree_glyphs(TreeStyle::Ascii);
+        for (u, a) in [
+            (unicode.current, ascii.current),
+            (unicode.node, ascii.node),
+            (unicode.vertical, ascii.vertical),
+            (unicode.corner, ascii.corner),
+            (unicode.tee, ascii.tee),
+        ] {
+            assert_eq!(measure_text_width(u), measure_text_width(a));
+            assert!(a.is_ascii());
+        }
+    }
+}
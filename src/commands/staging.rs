@@ -152,6 +152,22 @@ pub fn count_uncommitted_changes(workdir: &Path) -> usize {
         .unwrap_or(0)
 }
 
+/// `git status --porcelain` entries a commit would include: everything when
+/// `include_unstaged` (the `-a` case), otherwise only entries with a staged side.
+pub fn commit_preview_files(workdir: &Path, include_unstaged: bool) -> Result<Vec<String>> {
+    let output = Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(workdir)
+        .output()
+        .context("Failed to list changed files")?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .filter(|line| include_unstaged || !matches!(line.chars().next(), Some(' ' | '?')))
+        .map(str::to_string)
+        .collect())
+}
+
 /// True when the working tree has any uncommitted changes at all.
 pub fn has_uncommitted_changes(workdir: &Path) -> bool {
     Command::new("git")
@@ -104,11 +104,15 @@ struct StandupData {
     needs_attention: NeedsAttention,
 }
 
-fn collect_standup_data(all: bool, hours: i64) -> Result<StandupData> {
+fn collect_standup_data(
+    all: bool,
+    hours: i64,
+    request_timeout: Option<u64>,
+) -> Result<StandupData> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let remote_info = RemoteInfo::from_repo(&repo, &config).ok();
 
     let branches_to_show: Vec<String> = if all {
@@ -157,6 +161,7 @@ pub fn run(
     model_flag: Option<String>,
     plain_text: bool,
     summary_style: SummaryStyle,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     if plain_text && !ai {
         bail!("--plain-text only applies when used with --ai");
@@ -168,7 +173,7 @@ pub fn run(
     let show_progress = standup_progress_enabled(json, plain_text);
 
     let collect_timer = LiveTimer::maybe_new_stderr(show_progress, "Collecting standup context...");
-    let data = match collect_standup_data(all, hours) {
+    let data = match collect_standup_data(all, hours, request_timeout) {
         Ok(data) => {
             LiveTimer::maybe_finish_ok(collect_timer, "done");
             data
@@ -1109,7 +1114,7 @@ fn fetch_forge_activity(
         return (vec![], vec![], vec![], vec![]);
     };
 
-    if crate::forge::forge_token(remote.forge).is_none() {
+    if !crate::forge::forge_auth_configured(remote.forge, &remote.host) {
         return (vec![], vec![], vec![], vec![]);
     }
 
@@ -1,11 +1,13 @@
 use crate::cache::CiCache;
 use crate::commands::stack_palette;
-use crate::config::Config;
+use crate::config::{Config, TreeStyle};
 use crate::engine::{BranchMetadata, Stack, StackSnapshot};
+use crate::forge::{self, ForgeClient};
 use crate::git::{GitRepo, command};
-use crate::remote::{self, RemoteInfo};
+use crate::remote::{self, ForgeType, RemoteInfo};
 use anyhow::Result;
 use colored::Colorize;
+use futures_util::future::join_all;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
@@ -45,6 +47,23 @@ fn divergence_labels(ahead: usize, behind: usize) -> String {
     labels
 }
 
+fn disconnected_label(missing_parent: &str) -> String {
+    format!(
+        "{}",
+        format!("(disconnected at: {})", missing_parent)
+            .yellow()
+            .bold()
+    )
+}
+
+fn parent_unpushed_label() -> String {
+    format!("{}", "(parent not submitted)".yellow())
+}
+
+fn merged_leftovers_label() -> String {
+    format!("{}", "(merged, local leftovers)".yellow())
+}
+
 fn missing_parent_label(parent: &str) -> String {
     format!(
         "{}",
@@ -61,38 +80,371 @@ struct BranchStatusJson {
     linked_worktree: Option<String>,
     needs_restack: bool,
     missing_parent: Option<String>,
+    /// The recorded parent chain does not reach trunk.
+    disconnected: bool,
+    /// Untracked or deleted parent where the chain breaks.
+    disconnected_at: Option<String>,
     pr_number: Option<u64>,
     pr_state: Option<String>,
     pr_is_draft: Option<bool>,
     pr_url: Option<String>,
+    /// Fork owner when the PR is cross-repo (its head lives in a fork).
+    pr_head_owner: Option<String>,
     ci_state: Option<String>,
     ahead: usize,
     behind: usize,
     lines_added: usize,
     lines_deleted: usize,
     has_remote: bool,
+    /// The parent has no remote branch or has commits its remote lacks,
+    /// so submitting this branch now would open its PR against a stale base.
+    parent_unpushed: bool,
+    /// Tag the branch was created from (`stax create --from-tag`).
+    base_tag: Option<String>,
+    /// The PR is merged but the branch still has commits ahead of its parent,
+    /// typically left behind by a squash merge.
+    merged_with_leftovers: bool,
 }
 
 #[derive(Serialize)]
 struct StatusJson {
+    schema_version: u8,
     trunk: String,
     current: String,
+    /// The forge remote stax resolved, so scripts need not re-parse `git remote`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remote: Option<StatusRemoteJson>,
     branches: Vec<BranchStatusJson>,
+    /// Open PRs whose review decision is approved (`--approvals`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approved_prs: Option<usize>,
+    /// Open PRs considered for the approval rollup (`--approvals`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_prs: Option<usize>,
+    health: StackHealth,
+    /// When offline, the time the cached PR/CI state was last refreshed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stale_as_of: Option<String>,
+}
+
+#[derive(Serialize)]
+struct StatusRemoteJson {
+    name: String,
+    provider: ForgeType,
+    owner: String,
+    repo: String,
+    base_url: String,
+}
+
+impl From<&RemoteInfo> for StatusRemoteJson {
+    fn from(info: &RemoteInfo) -> Self {
+        Self {
+            name: info.name.clone(),
+            provider: info.forge,
+            owner: info.namespace.clone(),
+            repo: info.repo.clone(),
+            base_url: info.base_url.clone(),
+        }
+    }
+}
+
+/// One-glance rollup of the problems `status` already flags per branch.
+#[derive(Serialize, Debug, PartialEq, Eq)]
+struct StackHealth {
+    /// `green`, `yellow` (needs attention) or `red` (broken stack or failing CI).
+    overall: &'static str,
+    needs_restack: usize,
+    failing_ci: usize,
+    /// Only known with `--approvals`, which looks up review decisions.
+    changes_requested: Option<usize>,
+    /// Branches with missing parent metadata or a parent chain that misses trunk.
+    disconnected: usize,
+}
+
+impl StackHealth {
+    fn from_statuses(entries: &[BranchStatusJson], changes_requested: Option<usize>) -> Self {
+        let branches = || entries.iter().filter(|entry| !entry.is_trunk);
+        let is_broken = |entry: &BranchStatusJson| {
+            entry.missing_parent.is_some() || entry.disconnected_at.is_some()
+        };
+        let needs_restack = branches()
+            .filter(|entry| entry.needs_restack && !is_broken(entry))
+            .count();
+        let failing_ci = branches()
+            .filter(|entry| {
+                matches!(
+                    entry.ci_state.as_deref(),
+                    Some("failure" | "timed_out" | "action_required")
+                )
+            })
+            .count();
+        let disconnected = branches().filter(|entry| is_broken(entry)).count();
+        let overall = if failing_ci > 0 || disconnected > 0 {
+            "red"
+        } else if needs_restack > 0 || changes_requested.unwrap_or(0) > 0 {
+            "yellow"
+        } else {
+            "green"
+        };
+        Self {
+            overall,
+            needs_restack,
+            failing_ci,
+            changes_requested,
+            disconnected,
+        }
+    }
+
+    fn render(&self) -> String {
+        let level = match self.overall {
+            "red" => self.overall.red().bold(),
+            "yellow" => self.overall.yellow().bold(),
+            _ => self.overall.green().bold(),
+        };
+        let mut issues = Vec::new();
+        if self.needs_restack > 0 {
+            issues.push(format!(
+                "{} {} restack",
+                self.needs_restack,
+                if self.needs_restack == 1 {
+                    "needs"
+                } else {
+                    "need"
+                }
+            ));
+        }
+        if self.failing_ci > 0 {
+            issues.push(format!("{} failing CI", self.failing_ci));
+        }
+        if let Some(count) = self.changes_requested.filter(|count| *count > 0) {
+            issues.push(format!("{} changes requested", count));
+        }
+        if self.disconnected > 0 {
+            issues.push(format!("{} disconnected", self.disconnected));
+        }
+        if issues.is_empty() {
+            issues.push("no issues".to_string());
+        }
+        format!("Health: {} · {}", level, issues.join(" · "))
+    }
+}
+
+/// Review decisions across the shown stack's open PRs.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ApprovalRollup {
+    approved: usize,
+    changes_requested: usize,
+    /// Lookups that failed; counted in `total`, never as approved.
+    unknown: usize,
+    total: usize,
+}
+
+impl ApprovalRollup {
+    fn from_decisions(decisions: &[Result<Option<String>>]) -> Self {
+        let mut rollup = Self {
+            total: decisions.len(),
+            ..Self::default()
+        };
+        for decision in decisions {
+            match decision {
+                Ok(Some(decision)) if decision.eq_ignore_ascii_case("APPROVED") => {
+                    rollup.approved += 1
+                }
+                Ok(Some(decision)) if decision.eq_ignore_ascii_case("CHANGES_REQUESTED") => {
+                    rollup.changes_requested += 1
+                }
+                Ok(_) => {}
+                Err(_) => rollup.unknown += 1,
+            }
+        }
+        rollup
+    }
+
+    fn render(&self) -> String {
+        let mut line = format!(
+            "Approvals: {}/{} {} approved",
+            self.approved,
+            self.total,
+            if self.total == 1 { "PR" } else { "PRs" }
+        );
+        if self.unknown > 0 {
+            line.push_str(&format!(" ({} unknown)", self.unknown));
+        }
+        line
+    }
+}
+
+/// Look up the review decision of each PR. Fails open: any lookup error
+/// (including no forge access) leaves that PR as unknown.
+/// State the `status` next-action hint is chosen from.
+struct NextActionContext<'a> {
+    health: &'a StackHealth,
+    merged_leftovers: usize,
+    on_trunk: bool,
+    /// Current branch has commits its remote branch lacks, or no remote branch yet.
+    current_unpushed: bool,
+}
+
+/// One suggestion: why, and the command to run.
+struct NextAction {
+    reason: String,
+    command: &'static str,
+}
+
+type NextActionRule = fn(&NextActionContext) -> Option<NextAction>;
+
+/// Checked in order; the first rule that matches is the hint shown.
+const NEXT_ACTION_RULES: &[NextActionRule] = &[
+    |ctx| {
+        (ctx.health.disconnected > 0).then(|| NextAction {
+            reason: format!(
+                "{} {} disconnected",
+                ctx.health.disconnected,
+                if ctx.health.disconnected == 1 {
+                    "branch is"
+                } else {
+                    "branches are"
+                }
+            ),
+            command: "stax fix --yes",
+        })
+    },
+    |ctx| {
+        (ctx.health.needs_restack > 0).then(|| NextAction {
+            reason: format!(
+                "{} {} restack",
+                ctx.health.needs_restack,
+                if ctx.health.needs_restack == 1 {
+                    "branch needs"
+                } else {
+                    "branches need"
+                }
+            ),
+            command: "stax restack --all",
+        })
+    },
+    |ctx| {
+        (ctx.merged_leftovers > 0).then(|| NextAction {
+            reason: "merged branches still local".to_string(),
+            command: "stax sync",
+        })
+    },
+    |ctx| {
+        (!ctx.on_trunk && ctx.current_unpushed).then(|| NextAction {
+            reason: "branch ahead of remote".to_string(),
+            command: "stax submit",
+        })
+    },
+    |ctx| {
+        ctx.on_trunk.then(|| NextAction {
+            reason: "on trunk".to_string(),
+            command: "stax bc",
+        })
+    },
+];
+
+impl NextAction {
+    fn pick(ctx: &NextActionContext) -> Option<Self> {
+        NEXT_ACTION_RULES.iter().find_map(|rule| rule(ctx))
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "{} {} → run {}",
+            "Next:".dimmed(),
+            self.reason,
+            self.command.bright_cyan()
+        )
+    }
+}
+
+/// Look up review decisions for `pr_numbers`. The flag is set when the forge
+/// could not be reached, so the caller can fall back to cached state.
+fn fetch_approval_rollup(
+    remote_info: Option<&RemoteInfo>,
+    pr_numbers: &[u64],
+    offline: bool,
+    quiet: bool,
+) -> (ApprovalRollup, bool) {
+    if pr_numbers.is_empty() {
+        return (ApprovalRollup::default(), false);
+    }
+    let lookup = remote_info
+        .filter(|_| !offline)
+        .ok_or_else(|| anyhow::anyhow!("no forge remote"))
+        .and_then(|remote_info| {
+            let rt = tokio::runtime::Runtime::new()?;
+            rt.block_on(async {
+                let client = ForgeClient::new(remote_info)?.with_quiet(quiet);
+                // GitHub answers for the whole stack in one GraphQL query; PRs
+                // the batch could not load are looked up individually.
+                let batched = match &client {
+                    ForgeClient::GitHub(_) => {
+                        match client.get_pr_merge_statuses(pr_numbers).await {
+                            Ok(statuses) => statuses,
+                            Err(err) if forge::is_unreachable(&err) => return Err(err),
+                            Err(_) => HashMap::new(),
+                        }
+                    }
+                    _ => HashMap::new(),
+                };
+                Ok(join_all(pr_numbers.iter().map(|number| {
+                    let batched = batched
+                        .get(number)
+                        .map(|status| status.review_decision.clone());
+                    let client = &client;
+                    async move {
+                        match batched {
+                            Some(decision) => Ok(decision),
+                            None => client.get_pr_review_decision(*number).await,
+                        }
+                    }
+                }))
+                .await)
+            })
+        });
+    let (decisions, forge_unreachable) = match lookup {
+        Ok(decisions) => {
+            let forge_unreachable = decisions
+                .iter()
+                .any(|decision| decision.as_ref().is_err_and(forge::is_unreachable));
+            (decisions, forge_unreachable)
+        }
+        Err(err) => (
+            pr_numbers
+                .iter()
+                .map(|_| Err(anyhow::anyhow!("forge unavailable")))
+                .collect(),
+            forge::is_unreachable(&err),
+        ),
+    };
+    (
+        ApprovalRollup::from_decisions(&decisions),
+        forge_unreachable,
+    )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     json: bool,
     stack_filter: Option<String>,
     current_only: bool,
     compact: bool,
+    flat: bool,
+    only_prs: bool,
     quiet: bool,
     verbose: bool,
+    tree_style: Option<TreeStyle>,
+    approvals: bool,
+    offline: bool,
+    request_timeout: Option<u64>,
 ) -> Result<()> {
     let repo = GitRepo::open()?;
     let snapshot = StackSnapshot::load(&repo)?;
     let current = snapshot.current_branch;
     let stack = snapshot.stack;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
+    let glyphs = stack_palette::tree_glyphs(tree_style.unwrap_or(config.ui.tree_style));
     let workdir = repo.workdir()?;
     let has_tracked = stack.branches.len() > 1;
     let cache_dir = repo.common_git_dir()?;
@@ -150,6 +502,17 @@ pub fn run(
         );
     }
 
+    // --only-prs: drop branches without a PR but keep each survivor's column,
+    // so the remaining branches still line up with their stacks.
+    if only_prs {
+        display_branches.retain(|db| {
+            stack
+                .branches
+                .get(&db.name)
+                .is_some_and(|b| b.pr_number.is_some())
+        });
+    }
+
     let tree_target_width = (max_column + 1) * 2;
     let mut ordered_branches: Vec<String> =
         display_branches.iter().map(|b| b.name.clone()).collect();
@@ -160,8 +523,18 @@ pub fn run(
         &ordered_branches,
     );
     let missing_parent_by_branch = collect_missing_parent_branches(&repo, &stack);
+    let base_tag_by_branch = collect_base_tags(&repo, &stack);
+    let pr_head_owner_by_branch = collect_pr_head_owners(&repo, &stack);
+    let unpushed_parents =
+        collect_unpushed_parents(&repo, &stack, config.remote_name(), &ordered_branches);
+    let disconnected_by_branch: HashMap<String, String> = stack
+        .validate(&repo)?
+        .into_iter()
+        .map(|d| (d.branch, d.missing_parent))
+        .collect();
 
-    // Load CI cache (refresh happens in `stax ci`)
+    // Load CI cache. No forge calls here: `stax ci` and `stax watch` refresh
+    // it with one batched lookup for the whole stack.
     let cache = CiCache::load(&cache_dir);
 
     // Build CI states from cache
@@ -224,10 +597,16 @@ pub fn run(
             .and_then(|b| b.pr_state.clone())
             .filter(|s| !s.trim().is_empty());
 
+        let merged_with_leftovers = ahead > 0
+            && pr_state
+                .as_deref()
+                .is_some_and(|state| state.eq_ignore_ascii_case("merged"));
+
         let pr_number = info.and_then(|b| b.pr_number);
         let pr_url = pr_number.and_then(|n| remote_info.as_ref().map(|r| r.pr_url(n)));
         let ci_state = ci_states.get(name).cloned();
         let missing_parent = missing_parent_by_branch.get(name).cloned();
+        let disconnected_at = disconnected_by_branch.get(name).cloned();
 
         let entry = BranchStatusJson {
             name: name.clone(),
@@ -237,32 +616,79 @@ pub fn run(
             linked_worktree: linked_worktrees_by_branch.get(name).cloned(),
             needs_restack: info.map(|b| b.needs_restack).unwrap_or(false),
             missing_parent,
+            disconnected: disconnected_at.is_some(),
+            disconnected_at,
             pr_number,
             pr_state,
             pr_is_draft: info.and_then(|b| b.pr_is_draft),
             pr_url,
+            pr_head_owner: pr_head_owner_by_branch.get(name).cloned(),
             ci_state,
             ahead,
             behind,
             lines_added,
             lines_deleted,
             has_remote: remote_branches.contains(name),
+            parent_unpushed: parent
+                .as_ref()
+                .is_some_and(|parent| unpushed_parents.contains(parent)),
+            base_tag: base_tag_by_branch.get(name).cloned(),
+            merged_with_leftovers,
         };
 
         branch_status_map.insert(name.clone(), entry.clone());
         branch_statuses.push(entry);
     }
 
+    let mut offline = offline;
+    let approval_rollup = approvals.then(|| {
+        let open_prs: Vec<u64> = display_branches
+            .iter()
+            .filter_map(|db| branch_status_map.get(&db.name))
+            .filter(|entry| {
+                entry
+                    .pr_state
+                    .as_deref()
+                    .is_some_and(|state| state.eq_ignore_ascii_case("open"))
+            })
+            .filter_map(|entry| entry.pr_number)
+            .collect();
+        let (rollup, forge_unreachable) =
+            fetch_approval_rollup(remote_info.as_ref(), &open_prs, offline, quiet);
+        offline |= forge_unreachable;
+        rollup
+    });
+
+    let health = StackHealth::from_statuses(
+        &branch_statuses,
+        approval_rollup
+            .as_ref()
+            .map(|rollup| rollup.changes_requested),
+    );
+
     if json {
         let output = StatusJson {
+            schema_version: 1,
             trunk: stack.trunk.clone(),
             current: current.clone(),
+            remote: remote_info.as_ref().map(StatusRemoteJson::from),
             branches: branch_statuses,
+            approved_prs: approval_rollup.as_ref().map(|rollup| rollup.approved),
+            total_prs: approval_rollup.as_ref().map(|rollup| rollup.total),
+            health,
+            stale_as_of: offline
+                .then(|| cache.refreshed_at().map(|at| at.to_rfc3339()))
+                .flatten(),
         };
         println!("{}", serde_json::to_string_pretty(&output)?);
         return Ok(());
     }
 
+    // Script-oriented formats keep stdout parseable.
+    if offline && !quiet && (compact || flat) {
+        eprintln!("{}", cache.stale_notice().dimmed());
+    }
+
     if compact {
         for entry in &branch_statuses {
             let parent = entry.parent.clone().unwrap_or_default();
@@ -271,8 +697,14 @@ pub fn run(
             let ci_state = entry.ci_state.clone().unwrap_or_default();
             let stack_status = if let Some(parent) = &entry.missing_parent {
                 format!("missing-parent:{}", parent)
+            } else if let Some(parent) = &entry.disconnected_at {
+                format!("disconnected:{}", parent)
             } else if entry.needs_restack {
                 "restack".to_string()
+            } else if entry.parent_unpushed {
+                "parent-unpushed".to_string()
+            } else if entry.merged_with_leftovers {
+                "merged-leftovers".to_string()
             } else {
                 String::new()
             };
@@ -291,6 +723,18 @@ pub fn run(
         return Ok(());
     }
 
+    if flat {
+        // Parents before children: reverse the tree's children-first order.
+        let flat_order =
+            std::iter::once(&stack.trunk).chain(display_branches.iter().rev().map(|db| &db.name));
+        for name in flat_order {
+            if let Some(entry) = branch_status_map.get(name) {
+                println!("{}", flat_line(branch_depth(&stack, name), entry));
+            }
+        }
+        return Ok(());
+    }
+
     // Render each branch
     for (i, db) in display_branches.iter().enumerate() {
         let branch = &db.name;
@@ -317,18 +761,22 @@ pub fn run(
             let col_color = column_color(col);
             if col == db.column {
                 // This is our column - draw circle
-                let circle = if is_current { "◉" } else { "○" };
+                let circle = if is_current {
+                    glyphs.current
+                } else {
+                    glyphs.node
+                };
                 tree.push_str(&format!("{}", circle.color(col_color)));
                 visual_width += 1;
 
                 // Check if we need corner connector (side branch ending)
                 if needs_corner {
-                    tree.push_str(&format!("{}", "─┘".color(col_color)));
+                    tree.push_str(&format!("{}", glyphs.corner.color(col_color)));
                     visual_width += 2;
                 }
             } else {
                 // Columns to our left - always draw vertical lines for active columns
-                tree.push_str(&format!("{} ", "│".color(col_color)));
+                tree.push_str(&format!("{} ", glyphs.vertical.color(col_color)));
                 visual_width += 2;
             }
         }
@@ -366,9 +814,26 @@ pub fn run(
             info_str.push_str(&divergence_labels(entry.ahead, entry.behind));
             if let Some(parent) = &entry.missing_parent {
                 info_str.push_str(&format!(" {}", missing_parent_label(parent)));
+            } else if let Some(parent) = &entry.disconnected_at {
+                info_str.push_str(&format!(" {}", disconnected_label(parent)));
             } else if entry.needs_restack {
                 info_str.push_str(&format!(" {}", restack_label()));
             }
+            if entry.parent_unpushed {
+                info_str.push_str(&format!(" {}", parent_unpushed_label()));
+            }
+            if entry.merged_with_leftovers {
+                info_str.push_str(&format!(" {}", merged_leftovers_label()));
+            }
+            if let Some(tag) = &entry.base_tag {
+                info_str.push_str(&format!(" {}", format!("(from tag {})", tag).dimmed()));
+            }
+            if let Some(owner) = &entry.pr_head_owner {
+                info_str.push_str(&format!(
+                    " {}",
+                    format!("(cross-repo PR from {})", owner).dimmed()
+                ));
+            }
 
             // Only show PR info in verbose mode (ll command)
             if verbose && let Some(pr_number) = entry.pr_number {
@@ -406,7 +871,11 @@ pub fn run(
     let mut trunk_tree = String::new();
     let mut trunk_visual_width = 0;
 
-    let trunk_circle = if is_trunk_current { "◉" } else { "○" };
+    let trunk_circle = if is_trunk_current {
+        glyphs.current
+    } else {
+        glyphs.node
+    };
     let trunk_color = column_color(0);
     trunk_tree.push_str(&format!("{}", trunk_circle.color(trunk_color)));
     trunk_visual_width += 1;
@@ -416,9 +885,9 @@ pub fn run(
         for col in 1..=trunk_child_max_col {
             let col_color = column_color(col);
             if col < trunk_child_max_col {
-                trunk_tree.push_str(&format!("{}", "─┴".color(col_color)));
+                trunk_tree.push_str(&format!("{}", glyphs.tee.color(col_color)));
             } else {
-                trunk_tree.push_str(&format!("{}", "─┘".color(col_color)));
+                trunk_tree.push_str(&format!("{}", glyphs.corner.color(col_color)));
             }
             trunk_visual_width += 2;
         }
@@ -475,7 +944,14 @@ pub fn run(
     let needs_restack = stack.needs_restack();
     let restack_only: Vec<String> = needs_restack
         .into_iter()
-        .filter(|branch| !missing_parent_by_branch.contains_key(branch))
+        .filter(|branch| {
+            !missing_parent_by_branch.contains_key(branch)
+                && !disconnected_by_branch.contains_key(branch)
+        })
+        .collect();
+    let disconnected_only: Vec<&String> = disconnected_by_branch
+        .keys()
+        .filter(|branch| !missing_parent_by_branch.contains_key(*branch))
         .collect();
     let config = Config::load().unwrap_or_default();
     let mut printed_stack_hint = false;
@@ -495,6 +971,28 @@ pub fn run(
         );
         printed_stack_hint = true;
     }
+    if !quiet && config.ui.tips && !disconnected_only.is_empty() {
+        if !printed_stack_hint {
+            println!();
+        }
+        let count = disconnected_only.len();
+        println!(
+            "{} Run {} to reconnect.",
+            format!(
+                "! {} {} not connected to {}.",
+                count,
+                if count == 1 {
+                    "branch is"
+                } else {
+                    "branches are"
+                },
+                stack.trunk
+            )
+            .bright_yellow(),
+            "stax branch reparent".bright_cyan()
+        );
+        printed_stack_hint = true;
+    }
     if !quiet && config.ui.tips && !restack_only.is_empty() {
         if !printed_stack_hint {
             println!();
@@ -516,6 +1014,32 @@ pub fn run(
         printed_stack_hint = true;
     }
 
+    let merged_leftovers = branch_statuses
+        .iter()
+        .filter(|entry| entry.merged_with_leftovers)
+        .count();
+    if !quiet && config.ui.tips && merged_leftovers > 0 {
+        if !printed_stack_hint {
+            println!();
+        }
+        println!(
+            "{} Run {} to clean up.",
+            format!(
+                "! {} {} a merged PR but still {} local commits.",
+                merged_leftovers,
+                if merged_leftovers == 1 {
+                    "branch has"
+                } else {
+                    "branches have"
+                },
+                if merged_leftovers == 1 { "has" } else { "have" }
+            )
+            .bright_yellow(),
+            "stax sync".bright_cyan()
+        );
+        printed_stack_hint = true;
+    }
+
     // Show additional stats only in verbose mode (ll command)
     if verbose && !quiet && config.ui.tips {
         let total_branches = stack.branches.len().saturating_sub(1); // Exclude trunk
@@ -559,6 +1083,45 @@ pub fn run(
                 ));
             }
             println!("{}", stats.join(" · ").dimmed());
+            printed_stack_hint = true;
+        }
+    }
+
+    if has_tracked && !quiet {
+        if !printed_stack_hint {
+            println!();
+        }
+        println!("{}", health.render());
+    }
+
+    if let Some(rollup) = &approval_rollup {
+        println!();
+        if rollup.total > 0 && rollup.approved == rollup.total {
+            println!("{}", rollup.render().green());
+        } else {
+            println!("{}", rollup.render());
+        }
+    }
+
+    if offline && !quiet {
+        println!("{}", cache.stale_notice().dimmed());
+    }
+
+    if !quiet && config.ui.tips {
+        let on_trunk = current == stack.trunk;
+        let current_unpushed = !on_trunk
+            && stack.branches.contains_key(&current)
+            && repo
+                .commits_vs_remote_named(config.remote_name(), &current)
+                .is_none_or(|(unpushed, _)| unpushed > 0);
+        let ctx = NextActionContext {
+            health: &health,
+            merged_leftovers,
+            on_trunk,
+            current_unpushed,
+        };
+        if let Some(action) = NextAction::pick(&ctx) {
+            println!("{}", action.render());
         }
     }
 
@@ -567,6 +1130,40 @@ pub fn run(
 
 /// Collect branches with proper nesting for branches that have multiple children
 /// fp-style: children sorted alphabetically, each child gets column + index
+/// Number of parent hops from `branch` down to trunk (trunk itself is 0).
+fn branch_depth(stack: &Stack, branch: &str) -> usize {
+    let mut depth = 0;
+    let mut current = branch;
+    while current != stack.trunk && depth <= stack.branches.len() {
+        match stack
+            .branches
+            .get(current)
+            .and_then(|info| info.parent.as_deref())
+        {
+            Some(parent) => current = parent,
+            None => break,
+        }
+        depth += 1;
+    }
+    depth
+}
+
+/// `<indent><branch> [ahead/behind] <#pr|-> <ci|->` with no colors or glyphs.
+fn flat_line(depth: usize, entry: &BranchStatusJson) -> String {
+    format!(
+        "{}{} [{}/{}] {} {}",
+        "  ".repeat(depth),
+        entry.name,
+        entry.ahead,
+        entry.behind,
+        entry
+            .pr_number
+            .map(|number| format!("#{}", number))
+            .unwrap_or_else(|| "-".to_string()),
+        entry.ci_state.as_deref().unwrap_or("-")
+    )
+}
+
 fn collect_display_branches_with_nesting(
     stack: &Stack,
     branch: &str,
@@ -660,6 +1257,67 @@ fn collect_missing_parent_branches(repo: &GitRepo, stack: &Stack) -> HashMap<Str
     missing
 }
 
+fn collect_base_tags(repo: &GitRepo, stack: &Stack) -> HashMap<String, String> {
+    stack
+        .branches
+        .keys()
+        .filter(|name| *name != &stack.trunk)
+        .filter_map(|name| {
+            let meta = BranchMetadata::read(repo.inner(), name).ok()??;
+            Some((name.clone(), meta.base_tag?))
+        })
+        .collect()
+}
+
+fn collect_pr_head_owners(repo: &GitRepo, stack: &Stack) -> HashMap<String, String> {
+    stack
+        .branches
+        .keys()
+        .filter(|name| *name != &stack.trunk)
+        .filter_map(|name| {
+            let meta = BranchMetadata::read(repo.inner(), name).ok()??;
+            Some((name.clone(), meta.pr_info?.head_owner?))
+        })
+        .collect()
+}
+
+/// Tracked non-trunk parents of `branches` that are missing from the remote
+/// or ahead of their remote branch.
+fn collect_unpushed_parents(
+    repo: &GitRepo,
+    stack: &Stack,
+    remote_name: &str,
+    branches: &[String],
+) -> HashSet<String> {
+    let mut parents: Vec<String> = branches
+        .iter()
+        .filter_map(|name| stack.branches.get(name)?.parent.clone())
+        .filter(|parent| parent != &stack.trunk && stack.branches.contains_key(parent))
+        .collect();
+    parents.sort();
+    parents.dedup();
+
+    let on_remote =
+        remote::get_existing_remote_branches_from_repo(repo.inner(), remote_name, &parents);
+    let pairs: Vec<(String, String)> = parents
+        .iter()
+        .filter(|parent| on_remote.contains(*parent))
+        .map(|parent| (format!("{}/{}", remote_name, parent), parent.clone()))
+        .collect();
+
+    let mut unpushed: HashSet<String> = parents
+        .iter()
+        .filter(|parent| !on_remote.contains(*parent))
+        .cloned()
+        .collect();
+    for ((_, parent), result) in pairs.iter().zip(repo.commits_ahead_behind_many(&pairs)) {
+        if matches!(result, Ok((ahead, _)) if ahead > 0) {
+            unpushed.insert(parent.clone());
+        }
+    }
+    unpushed
+}
+
 /// Get line additions and deletions between parent and branch
 fn get_line_diff_stats_many(
     workdir: &Path,
@@ -836,4 +1494,69 @@ mod tests {
         colored::control::unset_override();
         assert_eq!(label, "\u{1b}[1;37m(needs restack)\u{1b}[0m");
     }
+
+    #[test]
+    fn approval_rollup_counts_lookup_errors_as_unknown() {
+        let rollup = ApprovalRollup::from_decisions(&[
+            Ok(Some("APPROVED".to_string())),
+            Ok(Some("REVIEW_REQUIRED".to_string())),
+            Ok(None),
+            Err(anyhow::anyhow!("rate limited")),
+            Ok(Some("APPROVED".to_string())),
+        ]);
+
+        assert_eq!(
+            rollup,
+            ApprovalRollup {
+                approved: 2,
+                changes_requested: 0,
+                unknown: 1,
+                total: 5,
+            }
+        );
+        assert_eq!(rollup.render(), "Approvals: 2/5 PRs approved (1 unknown)");
+    }
+
+    #[test]
+    fn next_action_prefers_restack_over_submit_and_trunk_hints() {
+        let health = StackHealth {
+            overall: "yellow",
+            needs_restack: 3,
+            failing_ci: 0,
+            changes_requested: None,
+            disconnected: 0,
+        };
+        let ctx = NextActionContext {
+            health: &health,
+            merged_leftovers: 0,
+            on_trunk: false,
+            current_unpushed: true,
+        };
+        let action = NextAction::pick(&ctx).expect("restack hint");
+        assert_eq!(action.reason, "3 branches need restack");
+        assert_eq!(action.command, "stax restack --all");
+
+        let healthy = StackHealth {
+            overall: "green",
+            needs_restack: 0,
+            failing_ci: 0,
+            changes_requested: None,
+            disconnected: 0,
+        };
+        let ctx = NextActionContext {
+            health: &healthy,
+            merged_leftovers: 0,
+            on_trunk: false,
+            current_unpushed: true,
+        };
+        assert_eq!(NextAction::pick(&ctx).unwrap().command, "stax submit");
+
+        let ctx = NextActionContext {
+            health: &healthy,
+            merged_leftovers: 0,
+            on_trunk: true,
+            current_unpushed: false,
+        };
+        assert_eq!(NextAction::pick(&ctx).unwrap().command, "stax bc");
+    }
 }
@@ -3,25 +3,28 @@ use crate::application::submit::{
     PushSpec, TemporaryPublishRefs, TemporarySubmitWorktree, push_branches,
 };
 use crate::commands::open::open_url_in_browser;
+use crate::commands::{submit_progress, submit_recovery};
 use crate::config::{
     Config, NativeStackMode, SingleStackMode, StackLinksMode, StackLinksWhenNative,
 };
 use crate::engine::{BranchMetadata, Stack};
-use crate::forge::ForgeClient;
+use crate::forge::{ForgeClient, MergeMethod};
 use crate::git::GitRepo;
+use crate::github::codeowners::CodeOwners;
 use crate::github::gh_stack::{self, ExtensionStatus, FeatureState, LinkOutcome};
 use crate::github::pr::{
     PrInfoWithHead, StackPrInfo, generate_stack_links_markdown, is_native_stack_base_locked_error,
-    remove_stack_links_from_body, upsert_stack_links_in_body,
+    remove_stack_links_from_body, stack_links_placeholder, sync_body_with_manual_sections,
+    upsert_stack_links_in_body,
 };
 use crate::github::pr_template::{discover_pr_templates, select_template_interactive};
 use crate::ops::receipt::{OpKind, PlanSummary};
 use crate::ops::tx::{self, Transaction};
-use crate::progress::LiveTimer;
+use crate::progress::{LiveTimer, StepProgress};
 use crate::remote::{self, ForgeType, RemoteInfo};
 use anyhow::{Context, Result};
 use colored::Colorize;
-use dialoguer::{Editor, Input, Select, theme::ColorfulTheme};
+use dialoguer::{Editor, Input, MultiSelect, Select, theme::ColorfulTheme};
 use futures_util::future::join_all;
 use serde::Deserialize;
 use std::collections::{BTreeSet, HashMap, HashSet};
@@ -57,9 +60,21 @@ pub struct SubmitOptions {
     pub title: bool,
     pub body: bool,
     pub rerequest_review: bool,
+    /// Request review from CODEOWNERS without asking.
+    pub codeowners: bool,
+    /// Turn on GitHub auto-merge with this method for every submitted PR.
+    pub auto_merge: Option<MergeMethod>,
     pub native_stack_override: Option<NativeStackMode>,
     pub squash: bool,
     pub update_title: bool,
+    /// Submit the downstack of this branch instead of the current one.
+    pub up_to: Option<String>,
+    /// Resume the interrupted submit recorded by `submit_progress`.
+    pub resume: bool,
+    /// `stax --timeout` for forge requests.
+    pub request_timeout: Option<u64>,
+    /// Remote chosen by `--remote` or recorded on the stack.
+    pub remote: Option<String>,
 }
 
 struct PrPlan {
@@ -77,12 +92,19 @@ struct PrPlan {
     /// Whether the tip commit subject differs from the existing PR title.
     needs_title_update: bool,
     existing_pr_title: Option<String>,
+    /// Title and body rebuilt from the branch's commits when
+    /// `submit.sync_pr_text` is on.
+    synced_pr_text: Option<(String, String)>,
     // For new PRs, we'll collect these upfront
     title: Option<String>,
     body: Option<String>,
-    ai_title_update: Option<String>,
+    title_update: Option<String>,
     generated_body_update: Option<String>,
     is_draft: Option<bool>,
+    /// CODEOWNERS of the branch's changes chosen as reviewers for a new PR.
+    codeowner_reviewers: Vec<String>,
+    /// Text saved with `stax branch describe`, used as the PR body.
+    description: Option<String>,
     // Track if this is a no-op (already synced)
     needs_push: bool,
     needs_pr_update: bool,
@@ -97,6 +119,11 @@ struct PrPlan {
     is_empty: bool,
     // Branches imported with `stax get` are read-only support branches.
     is_imported: bool,
+    /// Fork owner when the existing PR's head lives outside the base repo.
+    head_owner: Option<String>,
+    /// Remote to push to: the fork's remote for cross-repo PRs, else the
+    /// configured remote.
+    push_remote: String,
 }
 
 struct ExistingPrLookup {
@@ -238,7 +265,7 @@ fn extract_ai_json(raw: &str) -> String {
     }
 }
 
-fn resolve_is_draft_without_prompt(
+pub(crate) fn resolve_is_draft_without_prompt(
     draft_flag_set: bool,
     publish_flag_set: bool,
     draft: bool,
@@ -403,6 +430,8 @@ where
         rerequest_review: options.rerequest_review,
         native_stack_override: options.native_stack_override,
         update_title: options.update_title,
+        request_timeout: options.request_timeout,
+        remote: options.remote.clone(),
     };
     let prepared = backend.prepare(application_options, reporter)?;
     let prompt_requests = prepared_prompt_requests(&prepared);
@@ -488,9 +517,15 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
         title: ai_title,
         body: body_scope,
         rerequest_review,
+        codeowners,
+        auto_merge,
         native_stack_override,
         squash,
         update_title,
+        up_to,
+        resume,
+        request_timeout,
+        remote,
     } = options;
 
     let ai_targets = resolve_ai_targets(ai, ai_title, body_scope, update_title)?;
@@ -505,16 +540,30 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(request_timeout)
+        .with_remote(remote);
     let stack_links_mode = config.submit.stack_links;
     let single_stack_mode = config.submit.single_stack;
     let stack_links_when_native = config.submit.stack_links_when_native;
     let native_stack_mode = native_stack_override.unwrap_or(config.submit.native_stack);
+    // --no-prompt runs must never block on an editor, even when configured.
+    let edit = (edit || config.submit.edit) && !no_prompt;
+    let sync_pr_text = config.submit.sync_pr_text;
+    let new_pr_labels = with_config_defaults(&labels, &config.submit.labels);
+    let new_pr_assignees = with_config_defaults(&assignees, &config.submit.assignees);
 
     // Track if --draft was explicitly passed (we'll ask interactively if not)
     let draft_flag_set = draft;
 
-    if matches!(scope, SubmitScope::Branch) && current == stack.trunk {
+    let resume_point = if resume {
+        Some(submit_progress::resume_point(&repo, &stack)?)
+    } else {
+        None
+    };
+    let scope = resume_point.as_ref().map_or(scope, |point| point.scope);
+
+    if resume_point.is_none() && matches!(scope, SubmitScope::Branch) && current == stack.trunk {
         anyhow::bail!(
             "Cannot submit trunk '{}' as a single branch.\n\
              Checkout a tracked branch and run `stax branch submit`, or run `stax submit` for the whole stack.",
@@ -522,7 +571,14 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
         );
     }
 
-    let branches_to_submit = resolve_branches_for_scope(&stack, &current, scope);
+    let (scope_anchor, branches_to_submit) = match &resume_point {
+        Some(point) => (point.anchor.clone(), point.remaining.clone()),
+        None => {
+            let anchor = resolve_scope_anchor(&stack, &current, up_to.as_deref())?;
+            let branches = resolve_branches_for_scope(&stack, &anchor, scope);
+            (anchor, branches)
+        }
+    };
     if branches_to_submit.is_empty() {
         if !quiet {
             println!("{}", "No tracked branches to submit.".yellow());
@@ -533,6 +589,18 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
     // Validation phase
     if !quiet {
         println!("{} {}...", "Submitting".bold(), scope.label().bold());
+        if let Some(point) = resume_point
+            .as_ref()
+            .filter(|point| !point.completed.is_empty())
+        {
+            println!(
+                "  {} {} of {} branches already submitted; resuming from {}",
+                "↻".dimmed(),
+                point.completed.len(),
+                point.branches.len(),
+                branches_to_submit[0].cyan()
+            );
+        }
     }
 
     // Check for needs restack - show warning but continue (like fp)
@@ -743,7 +811,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
             scope,
             &repo,
             &stack,
-            &current,
+            &scope_anchor,
             &remote_info.name,
             &branches_to_submit,
             no_fetch,
@@ -766,7 +834,9 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
     if no_pr {
         let runtime = tokio::runtime::Runtime::new().ok();
         let _enter = runtime.as_ref().map(|rt| rt.enter());
-        let forge_client = ForgeClient::new(&remote_info).ok();
+        let forge_client = ForgeClient::new(&remote_info)
+            .ok()
+            .map(|client| client.with_quiet(quiet));
         client = forge_client.clone();
         let mut open_prs_by_head: Option<HashMap<String, PrInfoWithHead>> = None;
 
@@ -840,12 +910,13 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
 
                 if let Some(pr) = found_pr {
                     existing_pr = Some(pr.info.number);
-                    let owner_matches = pr
-                        .head_label
-                        .as_ref()
-                        .and_then(|label| label.split_once(':').map(|(owner, _)| owner))
-                        .map(|owner| owner == remote_info.owner())
-                        .unwrap_or(false);
+                    let owner_matches = pr.head_owner()
+                        == Some(
+                            remote_info
+                                .head_owner
+                                .as_deref()
+                                .unwrap_or(remote_info.owner()),
+                        );
 
                     let needs_meta_update = meta
                         .pr_info
@@ -863,6 +934,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                                 number: pr.info.number,
                                 state: pr.info.state.clone(),
                                 is_draft: Some(pr.info.is_draft),
+                                head_owner: remote_info.head_owner.clone(),
                             }),
                             ..meta
                         };
@@ -887,22 +959,27 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                 tip_commit_subject: None,
                 needs_title_update: false,
                 existing_pr_title: None,
+                synced_pr_text: None,
                 title: None,
                 body: None,
-                ai_title_update: None,
+                title_update: None,
                 generated_body_update: None,
                 is_draft: None,
+                codeowner_reviewers: Vec::new(),
+                description: None,
                 needs_push,
                 needs_pr_update: false,
                 needs_base_update: false,
                 is_empty,
                 is_imported,
+                head_owner: None,
+                push_remote: remote_info.name.clone(),
             });
         }
     } else {
         let runtime = tokio::runtime::Runtime::new()?;
         let _enter = runtime.enter();
-        let forge_client = ForgeClient::new(&remote_info)?;
+        let forge_client = ForgeClient::new(&remote_info)?.with_quiet(quiet);
         let mut lookup_inputs = Vec::new();
         for branch in &branches_to_submit {
             if empty_set.contains(branch) {
@@ -1002,14 +1079,23 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                 println!("    Empty branch {}, skipping PR lookup", branch.cyan());
             }
             let pr_number = existing_pr.as_ref().map(|p| p.info.number);
+            let cross_repo = existing_pr
+                .as_ref()
+                .and_then(|pr| cross_repo_head(repo.workdir().ok(), &remote_info, branch, pr));
+            let push_remote = cross_repo
+                .as_ref()
+                .and_then(|head| head.remote.clone())
+                .unwrap_or_else(|| remote_info.name.clone());
 
             if let Some(pr) = &existing_pr {
-                let owner_matches = pr
-                    .head_label
-                    .as_ref()
-                    .and_then(|label| label.split_once(':').map(|(owner, _)| owner))
-                    .map(|owner| owner == remote_info.owner())
-                    .unwrap_or(false);
+                // A fork PR is only ours to cache when a local remote points
+                // at that fork; otherwise it may be someone else's branch
+                // that happens to share the name.
+                let owner_matches = match &cross_repo {
+                    Some(head) => head.remote.is_some(),
+                    None => pr.head_owner().is_some(),
+                };
+                let head_owner = cross_repo.as_ref().map(|head| head.owner.clone());
 
                 let needs_meta_update = meta
                     .pr_info
@@ -1018,6 +1104,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                         info.number != pr.info.number
                             || info.state != pr.info.state
                             || info.is_draft.unwrap_or(false) != pr.info.is_draft
+                            || info.head_owner != head_owner
                     })
                     .unwrap_or(true);
 
@@ -1027,6 +1114,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                             number: pr.info.number,
                             state: pr.info.state.clone(),
                             is_draft: Some(pr.info.is_draft),
+                            head_owner,
                         }),
                         ..meta.clone()
                     };
@@ -1046,7 +1134,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
             let needs_push = !is_imported
                 && ref_needs_push(
                     repo.workdir()?,
-                    &remote_info.name,
+                    &push_remote,
                     branch,
                     &publish_source.source_ref,
                 );
@@ -1085,6 +1173,23 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                     .map(|(pr, commit_subject)| pr.title != *commit_subject)
                     .unwrap_or(false);
 
+            let synced_pr_text = if sync_pr_text && pr_number.is_some() && !is_empty && !is_imported
+            {
+                let commit_messages = collect_commit_messages(
+                    repo.workdir()?,
+                    &publish_source.commit_range_base,
+                    &publish_source.source_ref,
+                );
+                (!commit_messages.is_empty()).then(|| {
+                    (
+                        default_pr_title(&commit_messages, branch),
+                        branch_pr_body(meta.description.as_deref(), None, branch, &commit_messages),
+                    )
+                })
+            } else {
+                None
+            };
+
             plans.push(PrPlan {
                 branch: branch.clone(),
                 parent: base,
@@ -1094,23 +1199,28 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                 uses_temporary_publish_ref: publish_source.is_temporary,
                 remote_oid_after_fetch: rev_parse_ref(
                     repo.workdir().ok(),
-                    &format!("{}/{}", remote_info.name, branch),
+                    &format!("{}/{}", push_remote, branch),
                 ),
                 existing_pr: pr_number,
                 existing_pr_is_draft: existing_pr.as_ref().map(|pr| pr.info.is_draft),
                 tip_commit_subject,
                 needs_title_update,
                 existing_pr_title: existing_pr.as_ref().map(|pr| pr.title.clone()),
+                synced_pr_text,
                 title: None,
                 body: None,
-                ai_title_update: None,
+                title_update: None,
                 generated_body_update: None,
                 is_draft: None,
+                codeowner_reviewers: Vec::new(),
+                description: meta.description.clone(),
                 needs_push,
                 needs_pr_update,
                 needs_base_update,
                 is_empty,
                 is_imported,
+                head_owner: cross_repo.map(|head| head.owner),
+                push_remote,
             });
         }
 
@@ -1165,6 +1275,28 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                 if noops.len() == 1 { "PR" } else { "PRs" }
             );
         }
+        for plan in &plans {
+            if let (Some(owner), Some(number)) = (&plan.head_owner, plan.existing_pr) {
+                if plan.push_remote == remote_info.name {
+                    println!(
+                        "  {} {} PR #{} is cross-repo (head in {}'s fork); add a remote for the fork so submit can push there",
+                        "⚠".yellow(),
+                        plan.branch.cyan(),
+                        number,
+                        owner
+                    );
+                } else {
+                    println!(
+                        "  {} {} PR #{} is cross-repo (head in {}'s fork, pushing to {})",
+                        "↗".dimmed(),
+                        plan.branch.cyan(),
+                        number,
+                        owner,
+                        plan.push_remote.cyan()
+                    );
+                }
+            }
+        }
     }
 
     // Collect PR details and AI update choices BEFORE pushing (skip empty branches).
@@ -1176,6 +1308,11 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
             discover_pr_templates(repo.workdir()?).unwrap_or_default()
         };
         let mut ai_agent_selection: Option<AiAgentSelection> = None;
+        let code_owners = if codeowners || !auto_accept_prompts {
+            CodeOwners::discover(repo.workdir()?).unwrap_or_default()
+        } else {
+            None
+        };
         let new_prs: Vec<_> = plans
             .iter()
             .filter(|p| p.existing_pr.is_none() && !p.is_empty && !p.is_imported)
@@ -1229,8 +1366,12 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
 
             // Use selected template content if available
             let template_content = selected_template.as_ref().map(|t| t.content.as_str());
-            let default_body =
-                build_default_pr_body(template_content, &plan.branch, &commit_messages);
+            let default_body = branch_pr_body(
+                plan.description.as_deref(),
+                template_content,
+                &plan.branch,
+                &commit_messages,
+            );
 
             if !quiet {
                 println!("  {}", plan.branch.cyan());
@@ -1287,23 +1428,17 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                 .and_then(|details| details.body.clone())
                 .unwrap_or(default_body);
 
-            let title = if auto_accept_prompts {
-                suggested_title
+            let (title, body) = if edit {
+                edit_pr_details(&suggested_title, &suggested_body)?
+                    .unwrap_or((suggested_title, suggested_body))
+            } else if auto_accept_prompts {
+                (suggested_title, suggested_body)
             } else {
-                Input::with_theme(&ColorfulTheme::default())
+                let title = Input::with_theme(&ColorfulTheme::default())
                     .with_prompt("  Title")
                     .default(suggested_title)
-                    .interact_text()?
-            };
+                    .interact_text()?;
 
-            let body = if auto_accept_prompts {
-                suggested_body
-            } else if edit {
-                // --edit flag: always open editor
-                Editor::new()
-                    .edit(&suggested_body)?
-                    .unwrap_or(suggested_body)
-            } else {
                 // Interactive prompt
                 let options = if suggested_body.trim().is_empty() {
                     vec!["Edit", "Skip (leave empty)"]
@@ -1317,13 +1452,14 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                     .default(0)
                     .interact()?;
 
-                match options[choice] {
+                let body = match options[choice] {
                     "Use default" => suggested_body,
                     "Edit" => Editor::new()
                         .edit(&suggested_body)?
                         .unwrap_or(suggested_body),
                     _ => String::new(),
-                }
+                };
+                (title, body)
             };
 
             // Ask about draft vs publish (only if --draft/--publish wasn't explicitly set)
@@ -1343,6 +1479,22 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
             plan.title = Some(title);
             plan.body = Some(body);
             plan.is_draft = Some(is_draft);
+
+            if let Some(code_owners) = &code_owners {
+                let changed_paths = repo
+                    .diff_paths(&plan.publish_ref, &plan.commit_range_base)
+                    .unwrap_or_default();
+                let suggested = code_owners.reviewers_for_paths(&changed_paths);
+                plan.codeowner_reviewers = if codeowners || suggested.is_empty() {
+                    suggested
+                } else {
+                    let picked = MultiSelect::with_theme(&ColorfulTheme::default())
+                        .with_prompt("  Request review from code owners (space to toggle)")
+                        .items(&suggested)
+                        .interact()?;
+                    picked.into_iter().map(|i| suggested[i].clone()).collect()
+                };
+            }
         }
 
         if let Some(targets) = ai_targets {
@@ -1432,7 +1584,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                                         );
                                     }
                                 } else {
-                                    plan.ai_title_update = Some(title);
+                                    plan.title_update = Some(title);
                                 }
                             }
                             if let Some(body) = details.body {
@@ -1453,8 +1605,56 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                 }
             }
         }
+
+        if edit && let (Some(runtime), Some(forge_client)) = (rt.as_ref(), client.as_ref()) {
+            for plan in &mut plans {
+                let Some(pr_number) = plan.existing_pr else {
+                    continue;
+                };
+                if plan.is_empty || plan.is_imported {
+                    continue;
+                }
+
+                let current_title = plan
+                    .title_update
+                    .clone()
+                    .or_else(|| plan.existing_pr_title.clone())
+                    .unwrap_or_default();
+                let current_body = match plan.generated_body_update.clone() {
+                    Some(body) => body,
+                    None => runtime.block_on(forge_client.get_pr_body(pr_number))?,
+                };
+                if !quiet {
+                    println!("  {} #{}", plan.branch.cyan(), pr_number);
+                }
+
+                let Some((title, body)) = edit_pr_details(&current_title, &current_body)? else {
+                    continue;
+                };
+                if plan.existing_pr_title.as_deref() != Some(title.as_str()) {
+                    plan.title_update = Some(title);
+                }
+                if body.trim_end() != current_body.trim_end() {
+                    plan.generated_body_update = Some(body);
+                }
+            }
+        }
     }
 
+    // From here on each finished branch is recorded so an interrupted submit
+    // can be resumed with `stax submit --continue`.
+    let mut progress = submit_progress::SubmitProgress::begin(
+        &repo,
+        scope,
+        &scope_anchor,
+        resume_point
+            .as_ref()
+            .map_or(&branches_to_submit, |point| &point.branches),
+        resume_point
+            .as_ref()
+            .map_or(&[][..], |point| point.completed.as_slice()),
+    )?;
+
     // Now push branches that need it
     let branches_needing_push: Vec<_> = plans.iter().filter(|p| p.needs_push).collect();
 
@@ -1471,7 +1671,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
 
         // Plan remote refs (record current remote state before pushing)
         for plan in &branches_needing_push {
-            tx.plan_remote_branch(&repo, &remote_info.name, &plan.branch)?;
+            tx.plan_remote_branch(&repo, &plan.push_remote, &plan.branch)?;
         }
 
         let summary = PlanSummary {
@@ -1503,6 +1703,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
         }
 
         let mut pushed_branches = Vec::new();
+        let mut fork_pushes: Vec<(String, PushSpec)> = Vec::new();
         for plan in &branches_needing_push {
             // Squash all commits on the branch down to one before pushing
             if squash {
@@ -1517,7 +1718,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                     println!("  {} squash {}: {}", "⚠".yellow(), plan.branch, e);
                 }
             }
-            pushed_branches.push(PushSpec {
+            let spec = PushSpec {
                 branch: plan.branch.clone(),
                 source_ref: plan.publish_ref.clone(),
                 oid: plan
@@ -1525,44 +1726,116 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                     .clone()
                     .or_else(|| rev_parse_ref(repo.workdir().ok(), &plan.publish_ref)),
                 expected_remote_oid: plan.remote_oid_after_fetch.clone(),
-            });
+            };
+            if plan.push_remote == remote_info.name {
+                pushed_branches.push(spec);
+            } else {
+                fork_pushes.push((plan.push_remote.clone(), spec));
+            }
         }
 
-        let push_timer = LiveTimer::maybe_new(
-            !quiet,
-            &format!(
-                "Pushing {} {}...",
-                pushed_branches.len(),
-                if pushed_branches.len() == 1 {
-                    "branch"
-                } else {
-                    "branches"
+        // Cross-repo PRs update their fork's branch, not the base repo's.
+        for (fork_remote, spec) in &fork_pushes {
+            if !quiet {
+                println!(
+                    "  Pushing {} to fork remote {}...",
+                    spec.branch.cyan(),
+                    fork_remote.cyan()
+                );
+            }
+            if let Err(e) = push_branches(
+                repo.workdir()?,
+                fork_remote,
+                std::slice::from_ref(spec),
+                no_verify,
+            ) {
+                if let Some(tx) = tx {
+                    tx.finish_err(&format!("Push failed: {}", e), Some("push"), None)?;
                 }
-            ),
-        );
-        match push_branches(
-            repo.workdir()?,
-            &remote_info.name,
-            &pushed_branches,
-            no_verify,
-        ) {
-            Ok(()) => {
-                for spec in &pushed_branches {
-                    if let Some(ref mut tx) = tx {
-                        let _ = tx.record_after(&repo, &spec.branch);
-                        if let Some(oid) = &spec.oid {
-                            tx.record_remote_after(&remote_info.name, &spec.branch, oid);
+                progress.fail(&e, quiet);
+                return Err(e);
+            }
+            if let Some(ref mut tx) = tx {
+                let _ = tx.record_after(&repo, &spec.branch);
+                if let Some(oid) = &spec.oid {
+                    tx.record_remote_after(fork_remote, &spec.branch, oid);
+                }
+            }
+        }
+
+        if !pushed_branches.is_empty() {
+            let push_timer = LiveTimer::maybe_new(
+                !quiet,
+                &format!(
+                    "Pushing {} {}...",
+                    pushed_branches.len(),
+                    if pushed_branches.len() == 1 {
+                        "branch"
+                    } else {
+                        "branches"
+                    }
+                ),
+            );
+            match push_branches(
+                repo.workdir()?,
+                &remote_info.name,
+                &pushed_branches,
+                no_verify,
+            ) {
+                Ok(()) => LiveTimer::maybe_finish_ok(push_timer, "done"),
+                Err(e) => {
+                    LiveTimer::maybe_finish_err(push_timer, "failed");
+                    // A rejected lease usually means someone else pushed to the
+                    // branch; diagnose that and offer to rebase before retrying.
+                    let leases: Vec<(String, String)> = pushed_branches
+                        .iter()
+                        .filter_map(|spec| {
+                            spec.expected_remote_oid
+                                .clone()
+                                .map(|oid| (spec.branch.clone(), oid))
+                        })
+                        .collect();
+                    let retried = submit_recovery::rebase_onto_moved_remotes(
+                        &repo,
+                        &remote_info.name,
+                        &leases,
+                        quiet,
+                        e,
+                    )
+                    .and_then(|moved| {
+                        for moved in moved {
+                            if let Some(spec) = pushed_branches
+                                .iter_mut()
+                                .find(|spec| spec.branch == moved.branch)
+                            {
+                                spec.source_ref = format!("refs/heads/{}", moved.branch);
+                                spec.oid = Some(repo.branch_commit(&moved.branch)?);
+                                spec.expected_remote_oid = Some(moved.actual);
+                            }
+                        }
+                        push_branches(
+                            repo.workdir()?,
+                            &remote_info.name,
+                            &pushed_branches,
+                            no_verify,
+                        )
+                    });
+                    if let Err(e) = retried {
+                        if let Some(tx) = tx {
+                            tx.finish_err(&format!("Push failed: {}", e), Some("push"), None)?;
                         }
+                        progress.fail(&e, quiet);
+                        return Err(e);
                     }
                 }
-                LiveTimer::maybe_finish_ok(push_timer, "done");
             }
-            Err(e) => {
-                LiveTimer::maybe_finish_err(push_timer, "failed");
-                if let Some(tx) = tx {
-                    tx.finish_err(&format!("Push failed: {}", e), Some("push"), None)?;
+        }
+        for spec in &pushed_branches {
+            if let Some(ref mut tx) = tx {
+                let _ = tx.record_after(&repo, &spec.branch);
+                if let Some(oid) = &spec.oid {
+                    tx.record_remote_after(&remote_info.name, &spec.branch, oid);
                 }
-                return Err(e);
             }
         }
     }
@@ -1572,6 +1845,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
         if let Some(tx) = tx {
             tx.finish_ok()?;
         }
+        progress.finish()?;
         if !quiet {
             println!();
             println!("{}", "✓ Branches pushed successfully!".green().bold());
@@ -1593,7 +1867,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
         !p.is_empty
             && (p.existing_pr.is_none()
                 || p.needs_pr_update
-                || p.ai_title_update.is_some()
+                || p.title_update.is_some()
                 || p.generated_body_update.is_some())
     });
 
@@ -1602,6 +1876,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
         .any(|p| !p.is_empty && !p.is_imported && p.existing_pr.is_some());
 
     if !any_pr_work && branches_needing_push.is_empty() && !any_existing_prs {
+        progress.finish()?;
         if !quiet {
             println!();
             println!("{}", "✓ Stack already up to date!".green().bold());
@@ -1629,16 +1904,25 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
 
     let imported_stack_branches = imported_branches_for_stack(&repo, &stack, &current)?;
 
-    let (open_pr_url, async_timings, async_full_scan_fallbacks) = rt.block_on(async {
+    let submitted = rt.block_on(async {
         let mut pr_infos: Vec<StackPrInfo> = Vec::new();
         let mut created_pr_numbers: HashSet<u64> = HashSet::new();
         let mut async_timings = SubmitPhaseTimings::default();
         let async_full_scan_fallbacks = 0usize;
 
         let create_update_started_at = Instant::now();
+        let mut pr_progress = StepProgress::new(
+            !quiet,
+            plans
+                .iter()
+                .filter(|plan| !plan.is_empty && !plan.is_imported)
+                .filter(|plan| plan.existing_pr.is_none() || plan.needs_pr_update)
+                .count(),
+        );
         for plan in &plans {
             // Skip empty branches for PR operations
             if plan.is_empty || plan.is_imported {
+                progress.complete_branch(&plan.branch)?;
                 continue;
             }
 
@@ -1655,10 +1939,10 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
             if let Some(existing_pr_number) = plan.existing_pr {
                 if plan.needs_pr_update {
                     // Update existing PR (only if needed)
-                    let update_timer = LiveTimer::maybe_new(
-                        !quiet,
-                        &format!("Updating {} #{}...", plan.branch, existing_pr_number),
-                    );
+                    let update_timer = pr_progress.start(&format!(
+                        "Updating {} #{}...",
+                        plan.branch, existing_pr_number
+                    ));
 
                     // Update base only when it actually differs — `needs_pr_update`
                     // is also true for a plain push with no base change, and GitHub's
@@ -1699,11 +1983,13 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                             .await?;
                     }
 
-                    apply_ai_pr_content_updates(
+                    sync_pr_text_from_commits(&client, existing_pr_number, plan, quiet).await?;
+
+                    apply_pr_content_updates(
                         &client,
                         existing_pr_number,
                         &plan.branch,
-                        plan.ai_title_update.as_deref(),
+                        plan.title_update.as_deref(),
                         plan.generated_body_update.as_deref(),
                         quiet,
                     )
@@ -1745,6 +2031,8 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                     }
 
                     LiveTimer::maybe_finish_ok(update_timer, "done");
+                    enable_pr_auto_merge(&client, existing_pr_number, plan, auto_merge, quiet)
+                        .await;
 
                     // Get current PR state
                     let pr = client.get_pr(existing_pr_number).await?;
@@ -1754,6 +2042,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                             number: pr.number,
                             state: pr.state.clone(),
                             is_draft: Some(pr.is_draft),
+                            head_owner: plan.head_owner.clone(),
                         }),
                         ..meta
                     };
@@ -1801,6 +2090,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                                     number: pr.number,
                                     state: pr.state.clone(),
                                     is_draft: Some(pr.is_draft),
+                                    head_owner: plan.head_owner.clone(),
                                 }),
                                 ..meta
                             };
@@ -1825,15 +2115,19 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                         LiveTimer::maybe_finish_ok(title_timer, "done");
                     }
 
-                    apply_ai_pr_content_updates(
+                    sync_pr_text_from_commits(&client, existing_pr_number, plan, quiet).await?;
+
+                    apply_pr_content_updates(
                         &client,
                         existing_pr_number,
                         &plan.branch,
-                        plan.ai_title_update.as_deref(),
+                        plan.title_update.as_deref(),
                         plan.generated_body_update.as_deref(),
                         quiet,
                     )
                     .await?;
+                    enable_pr_auto_merge(&client, existing_pr_number, plan, auto_merge, quiet)
+                        .await;
 
                     // No-op - just add to pr_infos for summary
                     pr_infos.push(StackPrInfo {
@@ -1849,8 +2143,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                 let body = plan.body.as_ref().unwrap();
                 let is_draft = plan.is_draft.unwrap_or(draft);
 
-                let create_timer =
-                    LiveTimer::maybe_new(!quiet, &format!("Creating {}...", plan.branch));
+                let create_timer = pr_progress.start(&format!("Creating {}...", plan.branch));
 
                 let pr = match client
                     .create_pr(&plan.branch, &plan.parent, title, body, is_draft)
@@ -1890,12 +2183,40 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                         number: pr.number,
                         state: pr.state.clone(),
                         is_draft: Some(pr.is_draft),
+                        head_owner: None,
                     }),
                     ..meta
                 };
                 updated_meta.write(repo.inner(), &plan.branch)?;
 
-                apply_pr_metadata(&client, pr.number, &reviewers, &labels, &assignees).await?;
+                let mut new_pr_reviewers = with_config_defaults(
+                    &reviewers_for_new_pr(&reviewers, &updated_meta),
+                    &config.submit.reviewers,
+                );
+                if !plan.codeowner_reviewers.is_empty() {
+                    // GitHub rejects review requests from the PR's own author.
+                    let author = client.get_current_user().await.ok();
+                    let owners: Vec<String> = plan
+                        .codeowner_reviewers
+                        .iter()
+                        .filter(|owner| {
+                            author
+                                .as_deref()
+                                .is_none_or(|author| !owner.eq_ignore_ascii_case(author))
+                        })
+                        .cloned()
+                        .collect();
+                    new_pr_reviewers = with_config_defaults(&new_pr_reviewers, &owners);
+                }
+                apply_pr_metadata(
+                    &client,
+                    pr.number,
+                    &new_pr_reviewers,
+                    &new_pr_labels,
+                    &new_pr_assignees,
+                )
+                .await?;
+                enable_pr_auto_merge(&client, pr.number, plan, auto_merge, quiet).await;
 
                 pr_infos.push(StackPrInfo {
                     branch: plan.branch.clone(),
@@ -1904,6 +2225,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
                     depth: stack.ancestors(&plan.branch).len(),
                 });
             }
+            progress.complete_branch(&plan.branch)?;
         }
         async_timings.pr_create_update = create_update_started_at.elapsed();
 
@@ -2023,7 +2345,14 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
             async_timings,
             async_full_scan_fallbacks,
         ))
-    })?;
+    });
+    let (open_pr_url, async_timings, async_full_scan_fallbacks) = match submitted {
+        Ok(submitted) => submitted,
+        Err(error) => {
+            progress.fail(&error, quiet);
+            return Err(error);
+        }
+    };
     timings.open_pr_discovery += async_timings.open_pr_discovery;
     timings.pr_create_update += async_timings.pr_create_update;
     timings.stack_links += async_timings.stack_links;
@@ -2046,6 +2375,7 @@ pub fn run(scope: SubmitScope, options: SubmitOptions) -> Result<()> {
     if let Some(tx) = tx {
         tx.finish_ok()?;
     }
+    progress.finish()?;
 
     if verbose && !quiet {
         print_verbose_network_summary(
@@ -2074,6 +2404,10 @@ fn uses_application_default_submit(scope: SubmitScope, options: &SubmitOptions)
         && !options.no_template
         && options.template.is_none()
         && !options.update_title
+        && !options.codeowners
+        && options.auto_merge.is_none()
+        && options.up_to.is_none()
+        && !options.resume
 }
 
 fn run_application_default_submit(scope: SubmitScope, options: &SubmitOptions) -> Result<()> {
@@ -2095,12 +2429,52 @@ fn run_application_default_submit(scope: SubmitScope, options: &SubmitOptions) -
         quiet: options.quiet,
     };
     let receipt =
-        run_default_with_prompter(scope, options, &mut backend, &mut prompter, &mut reporter)?;
+        match run_default_with_prompter(scope, options, &mut backend, &mut prompter, &mut reporter)
+        {
+            Ok(receipt) => receipt,
+            Err(error) => {
+                let Some(branches) = rejected_push_receipt_branches(&error) else {
+                    return Err(error);
+                };
+                let config = Config::load()?.with_remote(options.remote.clone());
+                let remote_name = RemoteInfo::from_repo(&repo, &config)?.name;
+                let leases =
+                    submit_recovery::tracked_leases(repo.workdir()?, &remote_name, &branches);
+                submit_recovery::rebase_onto_moved_remotes(
+                    &repo,
+                    &remote_name,
+                    &leases,
+                    options.quiet,
+                    error,
+                )?;
+                run_default_with_prompter(
+                    scope,
+                    options,
+                    &mut backend,
+                    &mut prompter,
+                    &mut reporter,
+                )?
+            }
+        };
+    submit_progress::clear(&repo)?;
     sync_application_submit_links(&repo, &current, &receipt, options)?;
     render_application_submit_receipt(&receipt, &current, options.open, options.quiet);
     Ok(())
 }
 
+/// Branches of a submit that failed mid-push, the only failure worth
+/// checking for a remote that moved underneath the lease.
+fn rejected_push_receipt_branches(error: &anyhow::Error) -> Option<Vec<String>> {
+    let error = error.downcast_ref::<crate::application::OperationError>()?;
+    if error.kind != crate::application::OperationErrorKind::PartialRemoteUpdate {
+        return None;
+    }
+    error
+        .receipt
+        .as_ref()
+        .map(|receipt| receipt.affected_branches.clone())
+}
+
 fn sync_application_submit_links(
     repo: &GitRepo,
     current: &str,
@@ -2108,7 +2482,7 @@ fn sync_application_submit_links(
     options: &SubmitOptions,
 ) -> Result<()> {
     if options.no_pr {
-        return refresh_application_no_pr_metadata(repo, receipt);
+        return refresh_application_no_pr_metadata(repo, receipt, options);
     }
     let pull_requests = match &receipt.outcome {
         crate::application::OperationOutcome::Submitted { pull_requests } => pull_requests,
@@ -2119,7 +2493,9 @@ fn sync_application_submit_links(
     }
 
     let stack = Stack::load(repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(options.request_timeout)
+        .with_remote(options.remote.clone());
     let remote_info = RemoteInfo::from_repo(repo, &config)?;
     let imported_branches = imported_branches_for_stack(repo, &stack, current)?;
     let mut pr_infos = pull_requests
@@ -2229,11 +2605,14 @@ fn sync_application_submit_links(
 fn refresh_application_no_pr_metadata(
     repo: &GitRepo,
     receipt: &crate::application::OperationReceipt,
+    options: &SubmitOptions,
 ) -> Result<()> {
     if receipt.affected_branches.is_empty() {
         return Ok(());
     }
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(options.request_timeout)
+        .with_remote(options.remote.clone());
     let remote_info = RemoteInfo::from_repo(repo, &config)?;
     let runtime = tokio::runtime::Runtime::new()?;
     let _enter = runtime.enter();
@@ -2253,12 +2632,7 @@ fn refresh_application_no_pr_metadata(
         let Some(pr) = found_pr else {
             continue;
         };
-        let owner_matches = pr
-            .head_label
-            .as_ref()
-            .and_then(|label| label.split_once(':').map(|(owner, _)| owner))
-            .map(|owner| owner == remote_info.owner())
-            .unwrap_or(false);
+        let owner_matches = pr.head_owner() == Some(remote_info.owner());
         if !owner_matches {
             continue;
         }
@@ -2277,6 +2651,7 @@ fn refresh_application_no_pr_metadata(
                     number: pr.info.number,
                     state: pr.info.state.clone(),
                     is_draft: Some(pr.info.is_draft),
+                    head_owner: None,
                 }),
                 ..meta
             };
@@ -2737,6 +3112,28 @@ pub(crate) fn resolve_branches_for_scope(
     crate::application::submit::branches_for_submit_scope(stack, current, scope)
 }
 
+/// Branch the submit scope is measured from: the current branch, or the
+/// `--up-to` target, which must be a tracked branch of the current stack.
+pub(crate) fn resolve_scope_anchor(
+    stack: &Stack,
+    current: &str,
+    up_to: Option<&str>,
+) -> Result<String> {
+    let Some(target) = up_to else {
+        return Ok(current.to_string());
+    };
+    if target == stack.trunk || !stack.branches.contains_key(target) {
+        anyhow::bail!("'{}' is not a tracked branch; --up-to needs one", target);
+    }
+    if !stack.current_stack(current).iter().any(|b| b == target) {
+        anyhow::bail!(
+            "'{}' is not in the current stack; check out a branch of its stack first",
+            target
+        );
+    }
+    Ok(target.to_string())
+}
+
 /// Ref names to pass to `git fetch --no-tags <remote> ...` before submit (trunk, submitted branches,
 /// and parents required for narrow-scope validation).
 fn branches_to_fetch_for_submit(
@@ -2832,6 +3229,30 @@ fn validate_narrow_scope_submit(
     Ok(())
 }
 
+/// Head of an existing PR that lives in a fork rather than the base repo.
+struct CrossRepoHead {
+    owner: String,
+    /// Local remote pointing at the fork, when one is configured.
+    remote: Option<String>,
+}
+
+/// Detects a cross-repo (fork) PR for `branch` and, when the fork has a local
+/// remote, fetches the branch from it so the push lease is current.
+fn cross_repo_head(
+    workdir: Option<&Path>,
+    remote_info: &RemoteInfo,
+    branch: &str,
+    pr: &PrInfoWithHead,
+) -> Option<CrossRepoHead> {
+    let owner = pr.cross_repo_owner(remote_info.owner())?.to_string();
+    let remote = workdir.and_then(|workdir| {
+        let remote = remote::find_remote_for_owner(workdir, &remote_info.host, &owner)?;
+        let _ = remote::fetch_remote_refs(workdir, &remote, &[branch.to_string()]);
+        Some(remote)
+    });
+    Some(CrossRepoHead { owner, remote })
+}
+
 async fn discover_existing_pr(
     forge_client: ForgeClient,
     branch: String,
@@ -3201,6 +3622,34 @@ fn default_pr_title(commit_messages: &[String], branch: &str) -> String {
         .replace(['-', '_'], " ")
 }
 
+/// Swap the first `{{STACK}}` for an empty managed block so stack links land
+/// there; any further occurrences are dropped.
+fn fill_stack_placeholder(body: &str) -> String {
+    match body.split_once("{{STACK}}") {
+        Some((before, after)) => format!(
+            "{}{}{}",
+            before,
+            stack_links_placeholder(),
+            after.replace("{{STACK}}", "")
+        ),
+        None => body.to_string(),
+    }
+}
+
+/// PR body for a branch: the text saved with `stax branch describe` when
+/// there is one, otherwise the template/commit-list default.
+fn branch_pr_body(
+    description: Option<&str>,
+    template: Option<&str>,
+    branch: &str,
+    commit_messages: &[String],
+) -> String {
+    match description {
+        Some(description) => fill_stack_placeholder(description),
+        None => build_default_pr_body(template, branch, commit_messages),
+    }
+}
+
 fn build_default_pr_body(
     template: Option<&str>,
     branch: &str,
@@ -3209,7 +3658,16 @@ fn build_default_pr_body(
     let commits_text = render_commit_list(commit_messages);
 
     let mut body = if let Some(template) = template {
-        template.to_string()
+        // Templates without a {{COMMITS}} slot get the commit list below them.
+        if template.contains("{{COMMITS}}") || commits_text.is_empty() {
+            template.to_string()
+        } else {
+            format!(
+                "{}\n\n## Commits\n\n{}\n",
+                template.trim_end(),
+                commits_text
+            )
+        }
     } else if commits_text.is_empty() {
         String::new()
     } else {
@@ -3219,11 +3677,34 @@ fn build_default_pr_body(
     if !body.is_empty() {
         body = body.replace("{{BRANCH}}", branch);
         body = body.replace("{{COMMITS}}", &commits_text);
+        body = fill_stack_placeholder(&body);
     }
 
     body
 }
 
+/// Open `$EDITOR` on a PR's title and body. The first line is the title and
+/// the rest is the body. Returns `None` when the editor exits without saving.
+fn edit_pr_details(title: &str, body: &str) -> Result<Option<(String, String)>> {
+    let Some(text) = Editor::new().edit(&format!("{}\n\n{}", title, body))? else {
+        return Ok(None);
+    };
+    parse_pr_details(&text).map(Some)
+}
+
+fn parse_pr_details(text: &str) -> Result<(String, String)> {
+    let text = text.trim_start_matches(['\r', '\n']);
+    let (title, body) = text.split_once('\n').unwrap_or((text, ""));
+    let title = title.trim();
+    if title.is_empty() {
+        anyhow::bail!("Aborting submit: the PR title is empty");
+    }
+    Ok((
+        title.to_string(),
+        body.trim_start_matches(['\r', '\n']).to_string(),
+    ))
+}
+
 fn render_commit_list(commit_messages: &[String]) -> String {
     if commit_messages.is_empty() {
         return String::new();
@@ -3533,6 +4014,49 @@ fn load_pr_template(workdir: &Path) -> Option<String> {
     None
 }
 
+/// `--reviewers` wins; otherwise use the reviewers saved by `stax create --reviewers`.
+fn reviewers_for_new_pr(cli_reviewers: &[String], meta: &BranchMetadata) -> Vec<String> {
+    if !cli_reviewers.is_empty() {
+        return cli_reviewers.to_vec();
+    }
+    meta.reviewers
+        .iter()
+        .chain(&meta.team_reviewers)
+        .cloned()
+        .collect()
+}
+
+/// `[submit]` defaults appended to the CLI values, without duplicates. They only
+/// apply to new PRs so re-submits never re-request review.
+pub(crate) fn with_config_defaults(cli: &[String], defaults: &[String]) -> Vec<String> {
+    let mut merged: Vec<String> = Vec::with_capacity(cli.len() + defaults.len());
+    for value in cli.iter().chain(defaults) {
+        if !merged.contains(value) {
+            merged.push(value.clone());
+        }
+    }
+    merged
+}
+
+/// Replace `@me` with the authenticated user's login.
+async fn resolve_self_assignee(client: &ForgeClient, assignees: &[String]) -> Result<Vec<String>> {
+    if !assignees.iter().any(|assignee| assignee == "@me") {
+        return Ok(assignees.to_vec());
+    }
+    let login = client.get_current_user().await?;
+    let resolved = assignees
+        .iter()
+        .map(|assignee| {
+            if assignee == "@me" {
+                login.clone()
+            } else {
+                assignee.clone()
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(with_config_defaults(&resolved, &[]))
+}
+
 async fn apply_pr_metadata(
     client: &ForgeClient,
     pr_number: u64,
@@ -3549,13 +4073,82 @@ async fn apply_pr_metadata(
     }
 
     if !assignees.is_empty() {
-        client.add_assignees(pr_number, assignees).await?;
+        let assignees = resolve_self_assignee(client, assignees).await?;
+        client.add_assignees(pr_number, &assignees).await?;
     }
 
     Ok(())
 }
 
-async fn apply_ai_pr_content_updates(
+/// Turn on auto-merge for a submitted PR. GitHub refusing it (auto-merge
+/// disabled for the repository, a draft PR, or one that can already merge)
+/// is reported without failing the submit.
+async fn enable_pr_auto_merge(
+    client: &ForgeClient,
+    pr_number: u64,
+    plan: &PrPlan,
+    method: Option<MergeMethod>,
+    quiet: bool,
+) {
+    let Some(method) = method else {
+        return;
+    };
+    let timer = LiveTimer::maybe_new(
+        !quiet,
+        &format!("Enabling auto-merge for {} #{}...", plan.branch, pr_number),
+    );
+    match client.enable_auto_merge(pr_number, method).await {
+        Ok(()) => LiveTimer::maybe_finish_ok(timer, method.as_str()),
+        Err(e) => {
+            LiveTimer::maybe_finish_err(timer, "skipped");
+            if !quiet {
+                eprintln!("    {} {:#}", "⚠".yellow(), e);
+            }
+        }
+    }
+}
+
+/// Bring an existing PR's title and body in line with its commits. Explicit
+/// updates from `--ai`, `--edit` or `--update-title` take precedence.
+async fn sync_pr_text_from_commits(
+    client: &ForgeClient,
+    pr_number: u64,
+    plan: &PrPlan,
+    quiet: bool,
+) -> Result<()> {
+    let Some((title, body)) = plan.synced_pr_text.as_ref() else {
+        return Ok(());
+    };
+
+    if plan.title_update.is_none()
+        && !plan.needs_title_update
+        && plan.existing_pr_title.as_deref() != Some(title.as_str())
+    {
+        let timer = LiveTimer::maybe_new(
+            !quiet,
+            &format!("Syncing title for {} #{}...", plan.branch, pr_number),
+        );
+        client.update_pr_title(pr_number, title).await?;
+        LiveTimer::maybe_finish_ok(timer, "done");
+    }
+
+    if plan.generated_body_update.is_none() {
+        let current_body = client.get_pr_body(pr_number).await?;
+        let desired_body = sync_body_with_manual_sections(&current_body, body);
+        if desired_body != current_body {
+            let timer = LiveTimer::maybe_new(
+                !quiet,
+                &format!("Syncing body for {} #{}...", plan.branch, pr_number),
+            );
+            client.update_pr_body(pr_number, &desired_body).await?;
+            LiveTimer::maybe_finish_ok(timer, "done");
+        }
+    }
+
+    Ok(())
+}
+
+async fn apply_pr_content_updates(
     client: &ForgeClient,
     pr_number: u64,
     branch: &str,
@@ -3566,7 +4159,7 @@ async fn apply_ai_pr_content_updates(
     if let Some(title) = title {
         let timer = LiveTimer::maybe_new(
             !quiet,
-            &format!("Updating AI title for {} #{}...", branch, pr_number),
+            &format!("Updating title for {} #{}...", branch, pr_number),
         );
         client.update_pr_title(pr_number, title).await?;
         LiveTimer::maybe_finish_ok(timer, "done");
@@ -3575,7 +4168,7 @@ async fn apply_ai_pr_content_updates(
     if let Some(body) = body {
         let timer = LiveTimer::maybe_new(
             !quiet,
-            &format!("Updating AI body for {} #{}...", branch, pr_number),
+            &format!("Updating body for {} #{}...", branch, pr_number),
         );
         client.update_pr_body(pr_number, body).await?;
         LiveTimer::maybe_finish_ok(timer, "done");
@@ -3655,8 +4248,9 @@ mod tests {
     use super::{
         AiPrTargets, DefaultSubmitBackend, MAX_AI_DIFF_BYTES, PR_TYPE_DEFAULT_INDEX,
         PR_TYPE_OPTIONS, PushSpec, StackPrInfo, SubmitOptions, SubmitPrompter, SubmitScope,
-        build_ai_pr_details_prompt, existing_ai_prompt_items, existing_ai_targets_for_auto_accept,
-        parse_ai_pr_details, push_failure_details, rejected_push_branches, resolve_ai_targets,
+        branch_pr_body, build_ai_pr_details_prompt, build_default_pr_body,
+        existing_ai_prompt_items, existing_ai_targets_for_auto_accept, parse_ai_pr_details,
+        parse_pr_details, push_failure_details, rejected_push_branches, resolve_ai_targets,
         resolve_is_draft_without_prompt, run_default_with_prompter, stack_has_fork,
         stack_link_contexts_for_sync, stack_pr_infos_for_links, truncate_ai_diff,
     };
@@ -3679,6 +4273,75 @@ mod tests {
         );
     }
 
+    #[test]
+    fn default_pr_body_reserves_stack_slot_from_template() {
+        let body = build_default_pr_body(
+            Some("## What\n\n{{COMMITS}}\n\n{{STACK}}\n\n## Checklist\n{{STACK}}"),
+            "feature",
+            &["Add login".to_string()],
+        );
+        let with_links =
+            crate::github::pr::upsert_stack_links_in_body(&body, "## Stack\n\n* feature");
+
+        assert!(!with_links.contains("{{STACK}}"));
+        assert!(
+            with_links.starts_with("## What\n\n- Add login\n\n<!-- stax-stack-links:start -->")
+        );
+        assert!(with_links.ends_with("<!-- stax-stack-links:end -->\n\n## Checklist\n"));
+    }
+
+    #[test]
+    fn default_pr_body_appends_commits_below_template_without_slot() {
+        let body = build_default_pr_body(
+            Some("## Description\n\n## Testing\n"),
+            "feature",
+            &["Add login".to_string(), "Fix logout".to_string()],
+        );
+
+        assert_eq!(
+            body,
+            "## Description\n\n## Testing\n\n## Commits\n\n- Add login\n- Fix logout\n"
+        );
+    }
+
+    #[test]
+    fn branch_description_replaces_template_and_commit_list() {
+        let commits = ["Add login".to_string()];
+
+        assert_eq!(
+            branch_pr_body(
+                Some("Why login moves to OAuth.\n\n{{STACK}}"),
+                Some("## What\n\n{{COMMITS}}"),
+                "feature",
+                &commits,
+            ),
+            format!(
+                "Why login moves to OAuth.\n\n{}",
+                crate::github::pr::stack_links_placeholder()
+            )
+        );
+        assert_eq!(
+            branch_pr_body(None, None, "feature", &commits),
+            "## Summary\n\n- Add login"
+        );
+    }
+
+    #[test]
+    fn parse_pr_details_splits_title_from_body() {
+        assert_eq!(
+            parse_pr_details("\nAdd login\n\n## Summary\n\n- Add login\n").unwrap(),
+            (
+                "Add login".to_string(),
+                "## Summary\n\n- Add login\n".to_string()
+            )
+        );
+        assert_eq!(
+            parse_pr_details("Title only").unwrap(),
+            ("Title only".to_string(), String::new())
+        );
+        assert!(parse_pr_details("\n\n").is_err());
+    }
+
     fn branch_scope_test_stack() -> Stack {
         // main (trunk)
         //  ├── a
@@ -3732,6 +4395,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn config_defaults_append_to_cli_values_without_duplicates() {
+        let cli = vec!["alice".to_string(), "bob".to_string()];
+        let defaults = vec!["bob".to_string(), "org/team".to_string()];
+        assert_eq!(
+            super::with_config_defaults(&cli, &defaults),
+            vec!["alice", "bob", "org/team"]
+        );
+        assert_eq!(super::with_config_defaults(&[], &defaults), defaults);
+    }
+
+    #[test]
+    fn up_to_anchors_downstack_at_target_branch() {
+        let stack = branch_scope_test_stack();
+        let anchor = super::resolve_scope_anchor(&stack, "a2", Some("a1")).unwrap();
+        assert_eq!(
+            super::resolve_branches_for_scope(&stack, &anchor, SubmitScope::Downstack),
+            vec!["a".to_string(), "a1".to_string()]
+        );
+        assert_eq!(
+            super::resolve_scope_anchor(&stack, "a", None).unwrap(),
+            "a".to_string()
+        );
+        assert!(super::resolve_scope_anchor(&stack, "a2", Some("b")).is_err());
+        assert!(super::resolve_scope_anchor(&stack, "a2", Some("main")).is_err());
+        assert!(super::resolve_scope_anchor(&stack, "a2", Some("missing")).is_err());
+    }
+
     #[derive(Default)]
     struct RecordingSubmitBackend {
         prepared_options: Vec<ApplicationSubmitOptions>,
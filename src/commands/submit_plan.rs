@@ -1,4 +1,4 @@
-use crate::commands::submit::{SubmitOptions, SubmitScope};
+use crate::commands::submit::{SubmitOptions, SubmitScope, with_config_defaults};
 use crate::config::{
     Config, NativeStackMode, SingleStackMode, StackLinksMode, StackLinksWhenNative,
 };
@@ -18,6 +18,11 @@ struct SubmitPlan {
     trunk: String,
     remote: String,
     fetch: PlannedOperation,
+    new_pull_requests: &'static str,
+    reviewers: Vec<String>,
+    labels: Vec<String>,
+    assignees: Vec<String>,
+    auto_merge: Option<&'static str>,
     branches: Vec<BranchPlan>,
     stack_links: PlannedOperation,
     native_stack: PlannedOperation,
@@ -36,6 +41,9 @@ struct BranchPlan {
     needs_restack: bool,
     publish_source: &'static str,
     push: &'static str,
+    /// Remote head the push's `--force-with-lease` expects; `None` when the
+    /// branch must not exist on the remote yet.
+    lease_expected_oid: Option<String>,
     pull_request: &'static str,
     pr_number: Option<u64>,
     desired_base: Option<String>,
@@ -46,7 +54,9 @@ pub(crate) fn run(scope: SubmitScope, options: &SubmitOptions) -> Result<()> {
     let repo = GitRepo::open()?;
     let current = repo.current_branch()?;
     let stack = Stack::load(&repo)?;
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(options.request_timeout)
+        .with_remote(options.remote.clone());
     let remote = RemoteInfo::from_repo(&repo, &config)?;
     let workdir = repo.workdir()?;
 
@@ -57,7 +67,9 @@ pub(crate) fn run(scope: SubmitScope, options: &SubmitOptions) -> Result<()> {
         );
     }
 
-    let branches = super::submit::resolve_branches_for_scope(&stack, &current, scope);
+    let scope_anchor =
+        super::submit::resolve_scope_anchor(&stack, &current, options.up_to.as_deref())?;
+    let branches = super::submit::resolve_branches_for_scope(&stack, &scope_anchor, scope);
     let live_remote_heads = if options.no_fetch {
         None
     } else {
@@ -69,6 +81,13 @@ pub(crate) fn run(scope: SubmitScope, options: &SubmitOptions) -> Result<()> {
             .into_iter()
             .collect(),
     };
+    let tracked_remote_oids: HashMap<String, String> = if live_remote_heads.is_some() {
+        HashMap::new()
+    } else {
+        super::submit_recovery::tracked_leases(workdir, &remote.name, &branches)
+            .into_iter()
+            .collect()
+    };
     let mut branch_plans = Vec::with_capacity(branches.len());
     let mut temporary_publish_branches = HashSet::new();
 
@@ -107,6 +126,15 @@ pub(crate) fn run(scope: SubmitScope, options: &SubmitOptions) -> Result<()> {
         } else {
             "create"
         };
+        let lease_expected_oid = if matches!(push, "none" | "skip_imported") {
+            None
+        } else {
+            live_remote_heads
+                .as_ref()
+                .unwrap_or(&tracked_remote_oids)
+                .get(&branch)
+                .cloned()
+        };
         let pr_number = meta
             .pr_info
             .as_ref()
@@ -135,6 +163,7 @@ pub(crate) fn run(scope: SubmitScope, options: &SubmitOptions) -> Result<()> {
                 "local_branch"
             },
             push,
+            lease_expected_oid,
             pull_request,
             pr_number,
             desired_base: (pull_request == "inspect_and_update").then_some(meta.parent_branch_name),
@@ -226,6 +255,20 @@ pub(crate) fn run(scope: SubmitScope, options: &SubmitOptions) -> Result<()> {
                 reason: "submit refreshes trunk and selected branch refs".into(),
             }
         },
+        new_pull_requests: match super::submit::resolve_is_draft_without_prompt(
+            options.draft,
+            options.publish,
+            options.draft,
+            options.yes || options.no_prompt,
+        ) {
+            Some(true) => "draft",
+            Some(false) => "ready",
+            None => "prompt",
+        },
+        reviewers: with_config_defaults(&options.reviewers, &config.submit.reviewers),
+        labels: with_config_defaults(&options.labels, &config.submit.labels),
+        assignees: with_config_defaults(&options.assignees, &config.submit.assignees),
+        auto_merge: options.auto_merge.map(|method| method.as_str()),
         branches: branch_plans,
         stack_links: PlannedOperation {
             action: stack_links_action.into(),
@@ -278,6 +321,11 @@ fn print_human_plan(plan: &SubmitPlan) {
             "  {} <- {}: push={}, pr={}, metadata={}",
             branch.branch, branch.parent, branch.push, branch.pull_request, branch.metadata
         );
+        if let Some(oid) = &branch.lease_expected_oid {
+            println!("    force-with-lease expects {}", &oid[..oid.len().min(7)]);
+        } else if branch.push == "create" {
+            println!("    force-with-lease expects no remote branch");
+        }
         if branch.publish_source == "temporary_restack" {
             println!("    prepare temporary restack before push");
         }
@@ -285,6 +333,19 @@ fn print_human_plan(plan: &SubmitPlan) {
             println!("    verify/retarget PR base to {base}");
         }
     }
+    println!("  new PRs: {}", plan.new_pull_requests);
+    for (label, values) in [
+        ("reviewers", &plan.reviewers),
+        ("labels", &plan.labels),
+        ("assignees", &plan.assignees),
+    ] {
+        if !values.is_empty() {
+            println!("  {}: {}", label, values.join(", "));
+        }
+    }
+    if let Some(method) = plan.auto_merge {
+        println!("  auto-merge: {}", method);
+    }
     println!("  stack links: {}", plan.stack_links.action);
     println!("  native stack: {}", plan.native_stack.action);
 }
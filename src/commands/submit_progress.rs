@@ -0,0 +1,243 @@
+//! Resumable `stax submit`.
+//!
+//! Submit records the branches it selected, and each branch whose push and
+//! PR create/update finished, in `.git/stax/ops/submit-progress`. When a
+//! network or API error stops it part-way, `stax submit --continue` runs the
+//! same scope again starting at the first unfinished branch.
+//!
+//! The file holds an [`OpReceipt`] but has no `.json` suffix, so it is never
+//! listed (or undone) as an operation.
+
+use crate::engine::Stack;
+use crate::git::GitRepo;
+use crate::ops::receipt::{OpKind, OpReceipt, OpStatus, SubmitResume};
+use crate::ops::{self, ops_dir};
+use anyhow::{Context, Result, bail};
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+
+use super::submit::SubmitScope;
+
+const PROGRESS_FILE: &str = "submit-progress";
+
+fn progress_path(git_dir: &Path) -> PathBuf {
+    ops_dir(git_dir).join(PROGRESS_FILE)
+}
+
+/// Progress of the submit that is currently running.
+pub(crate) struct SubmitProgress {
+    receipt: OpReceipt,
+    path: PathBuf,
+}
+
+impl SubmitProgress {
+    /// Start recording a submit of `branches`, replacing any earlier progress.
+    pub(crate) fn begin(
+        repo: &GitRepo,
+        scope: SubmitScope,
+        anchor: &str,
+        branches: &[String],
+        already_completed: &[String],
+    ) -> Result<Self> {
+        let git_dir = repo.git_dir()?;
+        let mut receipt = OpReceipt::new(
+            ops::generate_op_id(),
+            OpKind::Submit,
+            repo.workdir()?.to_string_lossy().to_string(),
+            repo.trunk_branch()?,
+            repo.current_branch()?,
+        );
+        receipt.submit_resume = Some(SubmitResume {
+            scope: scope.label().to_string(),
+            anchor: anchor.to_string(),
+            branches: branches.to_vec(),
+        });
+        receipt.completed_branches = already_completed.to_vec();
+
+        let progress = Self {
+            receipt,
+            path: progress_path(git_dir),
+        };
+        ops::ensure_ops_dir(git_dir)?;
+        progress.save()?;
+        Ok(progress)
+    }
+
+    /// Mark `branch` as pushed and its PR as created/updated.
+    pub(crate) fn complete_branch(&mut self, branch: &str) -> Result<()> {
+        if !self.receipt.completed_branches.iter().any(|b| b == branch) {
+            self.receipt.completed_branches.push(branch.to_string());
+        }
+        self.save()
+    }
+
+    /// Record why the submit stopped and leave the progress for `--continue`.
+    pub(crate) fn fail(mut self, error: &anyhow::Error, quiet: bool) {
+        let next = self.next_branch();
+        self.receipt
+            .mark_failed(&format!("{error:#}"), Some("submit"), next.as_deref());
+        let _ = self.save();
+
+        if !quiet && let Some(next) = next {
+            eprintln!();
+            eprintln!(
+                "{} {} of {} branches were submitted. Resume from {} with:",
+                "Submit interrupted:".yellow(),
+                self.receipt.completed_branches.len(),
+                self.total_branches(),
+                next.cyan()
+            );
+            eprintln!("  {}", "stax submit --continue".cyan());
+        }
+    }
+
+    /// The submit finished; nothing is left to resume.
+    pub(crate) fn finish(self) -> Result<()> {
+        remove_progress_file(&self.path)
+    }
+
+    fn next_branch(&self) -> Option<String> {
+        self.receipt
+            .submit_resume
+            .as_ref()?
+            .branches
+            .iter()
+            .find(|branch| !self.receipt.completed_branches.contains(branch))
+            .cloned()
+    }
+
+    fn total_branches(&self) -> usize {
+        self.receipt
+            .submit_resume
+            .as_ref()
+            .map_or(0, |resume| resume.branches.len())
+    }
+
+    fn save(&self) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.receipt)
+            .context("Failed to serialize submit progress")?;
+        std::fs::write(&self.path, json)
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+}
+
+/// What `stax submit --continue` picks up from an interrupted submit.
+pub(crate) struct ResumePoint {
+    pub(crate) scope: SubmitScope,
+    pub(crate) anchor: String,
+    /// Every still-tracked branch of the interrupted submit, in order.
+    pub(crate) branches: Vec<String>,
+    /// Branches to submit now: those that did not finish, or all of them when
+    /// only the final stack-link sync was left.
+    pub(crate) remaining: Vec<String>,
+    pub(crate) completed: Vec<String>,
+}
+
+/// Load the interrupted submit of this repository.
+pub(crate) fn resume_point(repo: &GitRepo, stack: &Stack) -> Result<ResumePoint> {
+    let path = progress_path(repo.git_dir()?);
+    let receipt = match std::fs::read_to_string(&path) {
+        Ok(json) => serde_json::from_str::<OpReceipt>(&json)
+            .with_context(|| format!("Failed to parse {}", path.display()))?,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            bail!("No interrupted submit to continue. Run `stax submit` to start one.")
+        }
+        Err(error) => {
+            return Err(error).with_context(|| format!("Failed to read {}", path.display()));
+        }
+    };
+    if receipt.status == OpStatus::Success {
+        bail!("No interrupted submit to continue. Run `stax submit` to start one.");
+    }
+    let resume = receipt
+        .submit_resume
+        .context("Submit progress is missing its scope; run `stax submit` again")?;
+    let scope = SubmitScope::from_label(&resume.scope)
+        .with_context(|| format!("Unknown submit scope '{}' in submit progress", resume.scope))?;
+
+    let branches: Vec<String> = resume
+        .branches
+        .into_iter()
+        .filter(|branch| stack.branches.contains_key(branch))
+        .collect();
+    if branches.is_empty() {
+        remove_progress_file(&path)?;
+        bail!(
+            "None of the branches of the interrupted submit are tracked anymore; nothing to continue."
+        );
+    }
+    let remaining = remaining_branches(&branches, &receipt.completed_branches);
+
+    Ok(ResumePoint {
+        scope,
+        anchor: resume.anchor,
+        completed: branches
+            .iter()
+            .filter(|branch| receipt.completed_branches.contains(branch))
+            .cloned()
+            .collect(),
+        branches,
+        remaining,
+    })
+}
+
+/// Drop the recorded progress, e.g. after a submit that did not track it.
+pub(crate) fn clear(repo: &GitRepo) -> Result<()> {
+    remove_progress_file(&progress_path(repo.git_dir()?))
+}
+
+fn remaining_branches(branches: &[String], completed: &[String]) -> Vec<String> {
+    let remaining: Vec<String> = branches
+        .iter()
+        .filter(|branch| !completed.contains(branch))
+        .cloned()
+        .collect();
+    if remaining.is_empty() {
+        branches.to_vec()
+    } else {
+        remaining
+    }
+}
+
+fn remove_progress_file(path: &Path) -> Result<()> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(error).with_context(|| format!("Failed to remove {}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn remaining_branches_skip_completed_ones_in_order() {
+        assert_eq!(
+            remaining_branches(&names(&["a", "b", "c", "d"]), &names(&["b", "a"])),
+            names(&["c", "d"])
+        );
+    }
+
+    #[test]
+    fn fully_completed_submit_resumes_every_branch_for_link_sync() {
+        assert_eq!(
+            remaining_branches(&names(&["a", "b"]), &names(&["a", "b"])),
+            names(&["a", "b"])
+        );
+    }
+
+    #[test]
+    fn progress_file_is_not_listed_as_an_operation() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let git_dir = temp.path();
+        std::fs::create_dir_all(ops_dir(git_dir)).unwrap();
+        std::fs::write(progress_path(git_dir), "{}").unwrap();
+
+        assert!(ops::list_op_ids(git_dir).unwrap().is_empty());
+    }
+}
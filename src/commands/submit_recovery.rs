@@ -0,0 +1,231 @@
+//! Guided recovery when `stax submit`'s `--force-with-lease` push is rejected
+//! because someone else pushed (or force-pushed) a branch since stax last
+//! fetched it.
+//!
+//! The recovery re-fetches the rejected branches, shows a range-diff between
+//! the local branch and the new remote head, and, interactively, offers to
+//! replay the local commits on top of the remote so the caller can retry.
+
+use crate::git::{GitRepo, RebaseResult};
+use crate::remote;
+use anyhow::{Result, bail};
+use colored::Colorize;
+use dialoguer::{Confirm, theme::ColorfulTheme};
+use std::io::IsTerminal;
+use std::path::Path;
+use std::process::Command;
+
+/// A branch whose remote head no longer matches the lease stax pushed with.
+pub(crate) struct MovedRemote {
+    pub(crate) branch: String,
+    /// Remote head stax last saw (the lease value).
+    expected: String,
+    /// Remote head after re-fetching.
+    pub(crate) actual: String,
+}
+
+/// Leases for `branches` taken from their remote-tracking refs, which a
+/// rejected push leaves untouched.
+pub(crate) fn tracked_leases(
+    workdir: &Path,
+    remote_name: &str,
+    branches: &[String],
+) -> Vec<(String, String)> {
+    branches
+        .iter()
+        .filter_map(|branch| {
+            rev_parse(workdir, &format!("{}/{}", remote_name, branch))
+                .map(|oid| (branch.clone(), oid))
+        })
+        .collect()
+}
+
+/// Called after a submit push failed. Returns the original error unless a
+/// branch's remote moved underneath its lease; then diagnoses it and, when
+/// interactive and confirmed, rebases each branch onto its new remote head.
+/// On `Ok` the caller should retry the push.
+pub(crate) fn rebase_onto_moved_remotes(
+    repo: &GitRepo,
+    remote_name: &str,
+    leases: &[(String, String)],
+    quiet: bool,
+    push_error: anyhow::Error,
+) -> Result<Vec<MovedRemote>> {
+    let workdir = repo.workdir()?;
+    let moved = find_moved_remotes(workdir, remote_name, leases);
+    if moved.is_empty() {
+        return Err(push_error);
+    }
+
+    let diagnosis = moved
+        .iter()
+        .map(|moved| {
+            format!(
+                "  {}: expected {} on {}, found {}",
+                moved.branch,
+                short_sha(&moved.expected),
+                remote_name,
+                short_sha(&moved.actual)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let summary = format!(
+        "The remote changed underneath this submit (someone else pushed or force-pushed):\n{}",
+        diagnosis
+    );
+    if quiet {
+        bail!(
+            "{}\n\nFetch and rebase your changes onto the remote branch, then run 'stax submit' again.",
+            summary
+        );
+    }
+
+    eprintln!();
+    eprintln!("{}", summary.yellow());
+    for moved in &moved {
+        print_range_diff(workdir, remote_name, moved);
+    }
+
+    if !std::io::stdin().is_terminal() {
+        print_manual_steps(remote_name, &moved);
+        bail!("Push rejected: remote branches moved since the last fetch");
+    }
+
+    let confirmed = Confirm::with_theme(&ColorfulTheme::default())
+        .with_prompt(format!(
+            "Rebase your commits on top of {} and retry the push?",
+            if moved.len() == 1 {
+                format!("{}/{}", remote_name, moved[0].branch)
+            } else {
+                format!("the {} remote branches", moved.len())
+            }
+        ))
+        .default(false)
+        .interact()?;
+    if !confirmed {
+        print_manual_steps(remote_name, &moved);
+        bail!("Push rejected: remote branches moved since the last fetch");
+    }
+
+    for moved in &moved {
+        let remote_ref = format!("{}/{}", remote_name, moved.branch);
+        match repo.rebase_branch_onto_with_provenance_no_squash_check(
+            &moved.branch,
+            &remote_ref,
+            &moved.expected,
+            false,
+        )? {
+            RebaseResult::Success => {
+                println!(
+                    "  {} Rebased {} onto {}",
+                    "✓".green(),
+                    moved.branch.cyan(),
+                    remote_ref.cyan()
+                );
+            }
+            RebaseResult::Conflict => {
+                let _ = repo.rebase_abort();
+                bail!(
+                    "Rebasing '{}' onto '{}' hit conflicts; the branch was left unchanged.\n\
+                     Rebase it by hand, then run 'stax submit' again.",
+                    moved.branch,
+                    remote_ref
+                );
+            }
+        }
+    }
+
+    println!("  Retrying push...");
+    Ok(moved)
+}
+
+fn find_moved_remotes(
+    workdir: &Path,
+    remote_name: &str,
+    leases: &[(String, String)],
+) -> Vec<MovedRemote> {
+    let leases: Vec<&(String, String)> = leases
+        .iter()
+        .filter(|(_, expected)| !expected.is_empty())
+        .collect();
+    if leases.is_empty() {
+        return Vec::new();
+    }
+    let branches: Vec<String> = leases.iter().map(|(branch, _)| branch.clone()).collect();
+    if remote::fetch_remote_refs(workdir, remote_name, &branches).is_err() {
+        return Vec::new();
+    }
+
+    leases
+        .into_iter()
+        .filter_map(|(branch, expected)| {
+            let actual = rev_parse(workdir, &format!("{}/{}", remote_name, branch))?;
+            (&actual != expected).then(|| MovedRemote {
+                branch: branch.clone(),
+                expected: expected.clone(),
+                actual,
+            })
+        })
+        .collect()
+}
+
+fn print_range_diff(workdir: &Path, remote_name: &str, moved: &MovedRemote) {
+    let remote_ref = format!("{}/{}", remote_name, moved.branch);
+    eprintln!();
+    eprintln!(
+        "{}",
+        format!(
+            "Range diff for {} (yours: {}, remote: {}):",
+            moved.branch, moved.branch, remote_ref
+        )
+        .bold()
+    );
+    let output = Command::new("git")
+        .args([
+            "range-diff",
+            &format!("{}..{}", moved.expected, moved.branch),
+            &format!("{}..{}", moved.expected, remote_ref),
+        ])
+        .current_dir(workdir)
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {
+            eprint!("{}", String::from_utf8_lossy(&output.stdout))
+        }
+        _ => eprintln!("  {}", "(range-diff unavailable)".dimmed()),
+    }
+}
+
+fn print_manual_steps(remote_name: &str, moved: &[MovedRemote]) {
+    eprintln!();
+    eprintln!("To keep both sides, replay your commits on the new remote head:");
+    for moved in moved {
+        eprintln!(
+            "  {}",
+            format!(
+                "git rebase --onto {}/{} {} {}",
+                remote_name,
+                moved.branch,
+                short_sha(&moved.expected),
+                moved.branch
+            )
+            .cyan()
+        );
+    }
+    eprintln!("then run {} again.", "stax submit".cyan());
+}
+
+fn rev_parse(workdir: &Path, reference: &str) -> Option<String> {
+    Command::new("git")
+        .args(["rev-parse", "--verify", reference])
+        .current_dir(workdir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
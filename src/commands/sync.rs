@@ -1,5 +1,9 @@
+use crate::application::RestackStrategy;
 use crate::cache::CiCache;
 use crate::commands::ci::{fetch_ci_statuses, record_ci_history};
+use crate::commands::merge_shared::{
+    PrBaseUpdate, print_native_stack_locked_note, update_pr_base_unless_current,
+};
 use crate::commands::restack_conflict::{RestackConflictContext, print_restack_conflict};
 use crate::commands::worktree::{
     remove::remove_worktree_with_hooks,
@@ -10,12 +14,13 @@ use crate::engine::branch_detect::has_unique_commits_since_any_base;
 use crate::engine::{BranchMetadata, PrInfo, Stack};
 use crate::errors::ConflictStopped;
 use crate::forge::ForgeClient;
+use crate::git::repo::RebaseOutcome;
 use crate::git::repo::{BranchDeleteResolution, BranchDeleteSwitchTarget};
 use crate::git::{GitRepo, RebaseResult, RebaseTimings};
 use crate::github::pr::PrInfo as ForgePrInfo;
 use crate::ops::receipt::{OpKind, PlanSummary};
 use crate::ops::tx::{self, Transaction};
-use crate::progress::LiveTimer;
+use crate::progress::{LiveTimer, StepProgress};
 use crate::remote::{self, RemoteInfo};
 use anyhow::{Context, Result};
 use colored::Colorize;
@@ -27,6 +32,29 @@ use std::process::Command;
 use std::time::{Duration, Instant};
 
 const PR_METADATA_REFRESH_CONCURRENCY: usize = 8;
+const LAST_SYNC_FILE: &str = "last-sync";
+/// Restacks faster than this are never flagged as slow, however uneven the stack.
+const SLOW_RESTACK_FLOOR: Duration = Duration::from_millis(500);
+
+/// Restack and reporting choices selected on the `sync` command line.
+#[derive(Debug, Clone, Default)]
+pub struct SyncOptions {
+    /// Preview conflicting hunks when a restack stops (`--show-conflicts`).
+    pub show_conflicts: bool,
+    /// Print a JSON report (restack outcomes with timings) when the sync finishes.
+    pub json_report: bool,
+    /// Merge parents into branches instead of rebasing (`--restack-strategy merge`).
+    pub restack_strategy: RestackStrategy,
+    /// With `--restack`, rebase only branches that are actually behind their
+    /// parent; branches that already contain the parent tip just get their
+    /// recorded parent revision refreshed.
+    pub restack_real_only: bool,
+    /// `stax --timeout` for forge requests made during the sync.
+    pub request_timeout: Option<u64>,
+    /// Remote chosen by `--remote` or recorded on the stack; `None` keeps the
+    /// configured remotes.
+    pub remote: Option<String>,
+}
 
 #[derive(Debug, Default)]
 struct SyncStats {
@@ -76,6 +104,33 @@ impl SyncStats {
     }
 }
 
+/// Flag `branch`'s children whose recorded parent revision no longer matches
+/// `parent_rev`.
+fn mark_children_for_restack(stack: &mut Stack, branch: &str, parent_rev: &str) {
+    let children = stack
+        .branches
+        .get(branch)
+        .map(|br| br.children.clone())
+        .unwrap_or_default();
+    for child in &children {
+        if let Some(child_br) = stack.branches.get_mut(child) {
+            child_br.needs_restack = child_br
+                .parent_revision
+                .as_deref()
+                .map(|rev| rev != parent_rev)
+                .unwrap_or(true);
+        }
+    }
+}
+
+/// How one branch fared in the `sync --restack` loop.
+#[derive(Debug, Clone)]
+struct RestackOutcome {
+    branch: String,
+    ok: bool,
+    duration: Duration,
+}
+
 #[derive(Debug, Clone)]
 struct RestackBranchTiming {
     branch: String,
@@ -164,6 +219,71 @@ enum SyncBranchDeleteAction {
     Skip,
 }
 
+/// Returns `true` (after printing a note) when a successful sync finished
+/// within `window`, so `stax sync --since <window>` can be a cheap no-op.
+/// `--force` always syncs.
+pub fn skip_recent_sync(window: Duration, force: bool, quiet: bool) -> Result<bool> {
+    if force {
+        return Ok(false);
+    }
+    let repo = GitRepo::open()?;
+    let Some(elapsed) = last_sync_elapsed(&repo.common_git_dir()?) else {
+        return Ok(false);
+    };
+    if elapsed > window {
+        return Ok(false);
+    }
+    if !quiet {
+        println!(
+            "{} last successful sync was {} ago (within --since {}). Use {} to sync anyway.",
+            "Sync skipped:".yellow(),
+            format_age(elapsed),
+            format_age(window),
+            "--force".cyan()
+        );
+    }
+    Ok(true)
+}
+
+fn last_sync_path(git_dir: &Path) -> std::path::PathBuf {
+    git_dir.join("stax").join(LAST_SYNC_FILE)
+}
+
+fn last_sync_elapsed(git_dir: &Path) -> Option<Duration> {
+    let raw = std::fs::read_to_string(last_sync_path(git_dir)).ok()?;
+    let synced_at = raw.trim().parse::<u64>().ok()?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .ok()?
+        .as_secs();
+    Some(Duration::from_secs(now.saturating_sub(synced_at)))
+}
+
+/// Best-effort: a failure to record the timestamp only disables `--since`.
+fn record_successful_sync(git_dir: &Path) {
+    let path = last_sync_path(git_dir);
+    let Ok(now) = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let _ = std::fs::write(path, format!("{}\n", now.as_secs()));
+}
+
+fn format_age(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs < 3600 {
+        format!("{}m", secs / 60)
+    } else if secs < 86_400 {
+        format!("{}h", secs / 3600)
+    } else {
+        format!("{}d", secs / 86_400)
+    }
+}
+
 /// Sync repo: pull trunk from remote, delete merged branches, optionally restack
 #[allow(clippy::too_many_arguments)]
 pub fn run(
@@ -179,10 +299,12 @@ pub fn run(
     verbose: bool,
     mut auto_stash_pop: bool,
     extra_fetch_refs: &[String],
+    options: SyncOptions,
 ) -> Result<()> {
     let sync_started_at = Instant::now();
     let mut step_timings: Vec<(String, Duration)> = Vec::new();
     let mut restack_branch_timings: Vec<RestackBranchTiming> = Vec::new();
+    let mut restack_outcomes: Vec<RestackOutcome> = Vec::new();
     let mut stats = SyncStats::default();
 
     let repo = GitRepo::open()?;
@@ -190,9 +312,13 @@ pub fn run(
     let current = repo.current_branch()?;
     let workdir = repo.workdir()?.to_path_buf();
     let reopen_repo_path = repo.git_dir()?.to_path_buf();
-    let config = Config::load()?;
+    let config = Config::load()?
+        .with_request_timeout(options.request_timeout)
+        .with_remote(options.remote.clone());
     let remote_name = config.remote_name().to_string();
-    let remote_trunk_ref = format!("{}/{}", remote_name, stack.trunk);
+    // In a fork workflow trunk follows the pull request remote, not the fork.
+    let trunk_remote = config.pr_remote_name().to_string();
+    let remote_trunk_ref = format!("{}/{}", trunk_remote, stack.trunk);
     let imported_branches = imported_branches_for_remote(&repo, &stack, &remote_name)?;
     let remote_delete_exempt_imported_branches = imported_branches_for_cleanup(&repo, &stack)?;
     let mut sync_extra_fetch_refs = extra_fetch_refs.to_vec();
@@ -339,9 +465,21 @@ pub fn run(
         remote_branches_for_merged = None;
     }
 
+    let trunk_fetch_succeeded = trunk_remote == remote_name
+        || Command::new("git")
+            .args([
+                "fetch",
+                "--no-tags",
+                trunk_remote.as_str(),
+                stack.trunk.as_str(),
+            ])
+            .current_dir(&workdir)
+            .output()
+            .is_ok_and(|output| output.status.success());
+
     step_timings.push((format!("fetch {}", remote_name), fetch_started_at.elapsed()));
 
-    let fetch_succeeded = output.status.success();
+    let fetch_succeeded = output.status.success() && trunk_fetch_succeeded;
     if fetch_succeeded {
         LiveTimer::maybe_finish_timed(fetch_timer);
         if !quiet && verbose {
@@ -552,7 +690,7 @@ pub fn run(
                             .args([
                                 "update-ref",
                                 &format!("refs/heads/{}", stack.trunk),
-                                &format!("refs/remotes/{}/{}", remote_name, stack.trunk),
+                                &format!("refs/remotes/{}/{}", trunk_remote, stack.trunk),
                             ])
                             .current_dir(&workdir)
                             .output()
@@ -637,7 +775,7 @@ pub fn run(
             &repo,
             &workdir,
             &stack,
-            &remote_name,
+            &trunk_remote,
             remote_branches_for_merged
                 .as_ref()
                 .expect("remote branch list when deleting merged branches"),
@@ -659,7 +797,9 @@ pub fn run(
             if let Some(info) = remote_info {
                 tokio::runtime::Runtime::new().ok().and_then(|rt| {
                     let _enter = rt.enter();
-                    ForgeClient::new(&info).ok().map(|client| (rt, client))
+                    ForgeClient::new(&info)
+                        .ok()
+                        .map(|client| (rt, client.with_quiet(quiet)))
                 })
             } else {
                 None
@@ -1220,7 +1360,9 @@ pub fn run(
                 if let Some(info) = remote_info {
                     tokio::runtime::Runtime::new().ok().and_then(|rt| {
                         let _enter = rt.enter();
-                        ForgeClient::new(&info).ok().map(|client| (rt, client))
+                        ForgeClient::new(&info)
+                            .ok()
+                            .map(|client| (rt, client.with_quiet(quiet)))
                     })
                 } else {
                     None
@@ -1529,6 +1671,10 @@ pub fn run(
         ));
     }
 
+    if let Some(retarget_elapsed) = retarget_prs_of_merged_parents(&repo, &config, quiet) {
+        step_timings.push(("retarget merged-parent PRs".to_string(), retarget_elapsed));
+    }
+
     // 4. Optionally restack
     if restack && !trunk_reached_remote(&workdir, &stack.trunk, remote_trunk_after_fetch.as_deref())
     {
@@ -1551,6 +1697,7 @@ pub fn run(
             println!("{}", "Restacking...".bold());
         }
 
+        let merge_restack = options.restack_strategy == RestackStrategy::Merge;
         // Scope restacking to the stack we started on, even if sync switched branches
         // (for example, if the current branch was deleted after merge).
         let scope_order: Vec<String> =
@@ -1651,8 +1798,17 @@ pub fn run(
             tx.set_auto_stash_pop(auto_stash_pop);
             tx.snapshot()?;
 
-            let mut summary: Vec<(String, String)> = Vec::new();
             let mut restacked_branches = 0usize;
+            // Numbered by scope position: restacking a parent can make later branches
+            // need a restack too, so the initial count is not the number of steps.
+            let scope_step = |index: usize| {
+                restack_scope_order[..=index]
+                    .iter()
+                    .filter(|branch| **branch != stack.trunk)
+                    .count()
+            };
+            let mut restack_progress =
+                StepProgress::new(!quiet, scope_step(restack_scope_order.len() - 1));
 
             for (index, branch) in restack_scope_order.iter().enumerate() {
                 let needs_restack = live_stack
@@ -1676,7 +1832,36 @@ pub fn run(
                         },
                     };
 
-                let restack_timer = LiveTimer::maybe_new(!quiet, &format!("Restack {}", branch));
+                // Stale metadata alone: the branch already sits on the parent tip.
+                if options.restack_real_only
+                    && repo
+                        .is_ancestor(&parent_branch_name, branch)
+                        .unwrap_or(false)
+                {
+                    let parent_tip = repo.branch_commit(&parent_branch_name)?;
+                    if let Some(mut meta) = BranchMetadata::read(repo.inner(), branch)? {
+                        meta.parent_branch_revision = parent_tip.clone();
+                        meta.write(repo.inner(), branch)?;
+                    }
+                    if let Some(br) = live_stack.branches.get_mut(branch.as_str()) {
+                        br.needs_restack = false;
+                        br.parent_revision = Some(parent_tip.clone());
+                    }
+                    mark_children_for_restack(&mut live_stack, branch, &parent_tip);
+                    if !quiet {
+                        println!(
+                            "  {} {} already contains {}; refreshed metadata without rebasing",
+                            "▸".dimmed(),
+                            branch.cyan(),
+                            parent_branch_name.blue()
+                        );
+                    }
+                    continue;
+                }
+
+                let branch_started_at = Instant::now();
+                let restack_timer =
+                    restack_progress.start_at(scope_step(index), &format!("Restack {}", branch));
 
                 let rebase_upstream = crate::engine::restack_preflight::choose_rebase_upstream(
                     &repo,
@@ -1687,13 +1872,26 @@ pub fn run(
                     quiet,
                 );
 
-                let rebase = repo.rebase_branch_onto_with_provenance_timing(
-                    branch,
-                    &parent_branch_name,
-                    &rebase_upstream,
-                    auto_stash_pop,
-                    true,
-                )?;
+                let rebase = if merge_restack {
+                    let started_at = Instant::now();
+                    let result =
+                        repo.merge_parent_into_branch(branch, &parent_branch_name, auto_stash_pop)?;
+                    RebaseOutcome {
+                        result,
+                        timings: RebaseTimings {
+                            git_rebase: started_at.elapsed(),
+                            ..RebaseTimings::default()
+                        },
+                    }
+                } else {
+                    repo.rebase_branch_onto_with_provenance_timing(
+                        branch,
+                        &parent_branch_name,
+                        &rebase_upstream,
+                        auto_stash_pop,
+                        true,
+                    )?
+                };
 
                 match rebase.result {
                     RebaseResult::Success => {
@@ -1703,17 +1901,33 @@ pub fn run(
                         let source_remote = existing_metadata
                             .as_ref()
                             .and_then(|meta| meta.source_remote.clone());
-                        let frozen = existing_metadata.is_some_and(|meta| meta.frozen);
+                        let remote = existing_metadata
+                            .as_ref()
+                            .and_then(|meta| meta.remote.clone());
+                        let head_owner = existing_metadata
+                            .as_ref()
+                            .and_then(|meta| meta.pr_info.as_ref())
+                            .and_then(|pr_info| pr_info.head_owner.clone());
+                        let frozen = existing_metadata.as_ref().is_some_and(|meta| meta.frozen);
+                        let (reviewers, team_reviewers, description) = existing_metadata
+                            .map(|meta| (meta.reviewers, meta.team_reviewers, meta.description))
+                            .unwrap_or_default();
                         let updated_meta = BranchMetadata {
                             parent_branch_name: parent_branch_name.clone(),
                             parent_branch_revision: new_parent_rev.clone(),
                             source_remote,
+                            remote,
                             frozen,
+                            reviewers,
+                            team_reviewers,
+                            base_tag: None,
+                            description,
                             pr_info: live_stack.branches.get(branch.as_str()).and_then(|br| {
                                 br.pr_number.map(|n| PrInfo {
                                     number: n,
                                     state: br.pr_state.clone().unwrap_or_default(),
                                     is_draft: br.pr_is_draft,
+                                    head_owner: head_owner.clone(),
                                 })
                             }),
                         };
@@ -1723,20 +1937,7 @@ pub fn run(
                             br.needs_restack = false;
                             br.parent_revision = Some(new_parent_rev.clone());
                         }
-                        let children: Vec<String> = live_stack
-                            .branches
-                            .get(branch.as_str())
-                            .map(|br| br.children.clone())
-                            .unwrap_or_default();
-                        for child in &children {
-                            if let Some(child_br) = live_stack.branches.get_mut(child) {
-                                child_br.needs_restack = child_br
-                                    .parent_revision
-                                    .as_deref()
-                                    .map(|rev| rev != new_parent_rev.as_str())
-                                    .unwrap_or(true);
-                            }
-                        }
+                        mark_children_for_restack(&mut live_stack, branch, &new_parent_rev);
 
                         let metadata_update = metadata_update_started_at.elapsed();
 
@@ -1754,7 +1955,11 @@ pub fn run(
 
                         LiveTimer::maybe_finish_timed(restack_timer);
                         restacked_branches += 1;
-                        summary.push((branch.clone(), "ok".to_string()));
+                        restack_outcomes.push(RestackOutcome {
+                            branch: branch.clone(),
+                            ok: true,
+                            duration: branch_started_at.elapsed(),
+                        });
                     }
                     RebaseResult::Conflict => {
                         if verbose {
@@ -1766,10 +1971,10 @@ pub fn run(
                         }
 
                         LiveTimer::maybe_finish_warn(restack_timer, "conflict");
-                        let completed_branches: Vec<String> = summary
+                        let completed_branches: Vec<String> = restack_outcomes
                             .iter()
-                            .filter(|(_, status)| status == "ok")
-                            .map(|(name, _)| name.clone())
+                            .filter(|outcome| outcome.ok)
+                            .map(|outcome| outcome.branch.clone())
                             .collect();
                         let conflict_stack = live_stack.current_stack(branch);
                         print_restack_conflict(
@@ -1779,21 +1984,34 @@ pub fn run(
                                 parent_branch: &parent_branch_name,
                                 completed_branches: &completed_branches,
                                 remaining_branches: scope_order.len().saturating_sub(index + 1),
-                                continue_commands: &[
-                                    "stax resolve",
-                                    "stax continue",
-                                    "stax sync --continue",
-                                ],
+                                continue_commands: if merge_restack {
+                                    &["git commit", "stax sync --restack --restack-strategy merge"]
+                                } else {
+                                    &["stax resolve", "stax continue", "stax sync --continue"]
+                                },
                                 stack_branches: &conflict_stack,
+                                show_hunks: options.show_conflicts,
                             },
                         );
                         if stashed {
                             println!("{}", "Stash kept to avoid conflicts.".yellow());
                         }
-                        summary.push((branch.clone(), "conflict".to_string()));
+                        restack_outcomes.push(RestackOutcome {
+                            branch: branch.clone(),
+                            ok: false,
+                            duration: branch_started_at.elapsed(),
+                        });
 
                         // Finish transaction with error
-                        tx.finish_err("Rebase conflict", Some("restack"), Some(branch))?;
+                        tx.finish_err(
+                            if merge_restack {
+                                "Merge conflict"
+                            } else {
+                                "Rebase conflict"
+                            },
+                            Some("restack"),
+                            Some(branch),
+                        )?;
 
                         return Err(ConflictStopped.into());
                     }
@@ -1806,12 +2024,28 @@ pub fn run(
             tx.finish_ok()?;
             stats.restacked_branches = restacked_branches;
 
-            if !quiet && !summary.is_empty() {
+            if !quiet && !restack_outcomes.is_empty() {
                 println!();
                 println!("{}", "Restack summary:".dimmed());
-                for (branch, status) in &summary {
-                    let symbol = if status == "ok" { "✓" } else { "✗" };
-                    println!("  {} {} {}", symbol, branch, status);
+                let slow = slow_restacks(&restack_outcomes);
+                for (outcome, slow) in restack_outcomes.iter().zip(slow) {
+                    let (symbol, status) = if outcome.ok {
+                        ("✓", "ok")
+                    } else {
+                        ("✗", "conflict")
+                    };
+                    println!(
+                        "  {} {} {} {}{}",
+                        symbol,
+                        outcome.branch,
+                        status,
+                        format_duration(outcome.duration).dimmed(),
+                        if slow {
+                            format!(" {}", "slow".yellow())
+                        } else {
+                            String::new()
+                        }
+                    );
                 }
             }
         }
@@ -1894,9 +2128,58 @@ pub fn run(
         }
     }
 
+    if let Ok(git_dir) = repo.common_git_dir() {
+        record_successful_sync(&git_dir);
+    }
+
+    if options.json_report {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&render_json_report(
+                &restack_outcomes,
+                sync_started_at.elapsed()
+            ))?
+        );
+    }
+
     Ok(())
 }
 
+/// Flag restacks that took at least twice the median (and over
+/// [`SLOW_RESTACK_FLOOR`]); needs three branches for the median to mean anything.
+fn slow_restacks(outcomes: &[RestackOutcome]) -> Vec<bool> {
+    if outcomes.len() < 3 {
+        return vec![false; outcomes.len()];
+    }
+    let mut durations: Vec<Duration> = outcomes.iter().map(|o| o.duration).collect();
+    durations.sort();
+    let median = durations[durations.len() / 2];
+    outcomes
+        .iter()
+        .map(|o| o.duration >= SLOW_RESTACK_FLOOR && o.duration >= median * 2)
+        .collect()
+}
+
+fn render_json_report(outcomes: &[RestackOutcome], total: Duration) -> serde_json::Value {
+    let slow = slow_restacks(outcomes);
+    let restacked: Vec<serde_json::Value> = outcomes
+        .iter()
+        .zip(slow)
+        .map(|(outcome, slow)| {
+            serde_json::json!({
+                "branch": outcome.branch,
+                "status": if outcome.ok { "ok" } else { "conflict" },
+                "duration_ms": outcome.duration.as_millis() as u64,
+                "slow": slow,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "restacked": restacked,
+        "duration_ms": total.as_millis() as u64,
+    })
+}
+
 /// Fetch live PR state from the forge for all tracked branches and update
 /// both branch metadata and CiCache. Called at end of sync so that operations
 /// like `gh pr ready`, `gh pr merge`, or `gh pr edit --base` are reflected.
@@ -1940,7 +2223,7 @@ fn refresh_pr_draft_states(repo: &GitRepo, config: &Config, quiet: bool) -> Opti
     };
     let _enter = rt.enter();
     let client = match ForgeClient::new(&remote_info) {
-        Ok(c) => c,
+        Ok(c) => c.with_quiet(quiet),
         Err(_) => {
             LiveTimer::maybe_finish_skipped(timer, "skipped");
             return Some(started_at.elapsed());
@@ -1980,6 +2263,179 @@ fn refresh_pr_draft_states(repo: &GitRepo, config: &Config, quiet: bool) -> Opti
     Some(started_at.elapsed())
 }
 
+/// Retarget open PRs whose parent PR has merged on the forge to the nearest
+/// unmerged ancestor, and reparent those branches locally to match.
+///
+/// Children of merged branches that sync deleted were already moved by
+/// [`reparent_children_for_deletion`]; this covers merged parents that are
+/// kept (declined, `--quiet`, `--no-delete`) or that git does not yet see as
+/// merged, e.g. squash-merged with the head branch left on the remote.
+fn retarget_prs_of_merged_parents(
+    repo: &GitRepo,
+    config: &Config,
+    quiet: bool,
+) -> Option<Duration> {
+    let started_at = Instant::now();
+    let stack = Stack::load(repo).ok()?;
+    let has_pr = |branch: &str| {
+        stack
+            .branches
+            .get(branch)
+            .is_some_and(|info| info.pr_number.is_some())
+    };
+    let mut stacked: Vec<String> = stack
+        .branches
+        .iter()
+        .filter(|(name, info)| {
+            *name != &stack.trunk
+                && info.pr_number.is_some()
+                && info
+                    .parent
+                    .as_deref()
+                    .is_some_and(|parent| parent != stack.trunk && has_pr(parent))
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+    if stacked.is_empty() {
+        return None;
+    }
+    stacked.sort();
+
+    // Every stacked PR plus its ancestors with PRs, so a chain of merged
+    // parents resolves to the first one still open.
+    let mut tracked_prs: HashMap<String, u64> = HashMap::new();
+    for branch in &stacked {
+        let mut current = branch.as_str();
+        while let Some(info) = stack.branches.get(current)
+            && let Some(pr_number) = info.pr_number
+            && current != stack.trunk
+        {
+            if tracked_prs.insert(current.to_string(), pr_number).is_some() {
+                break;
+            }
+            match info.parent.as_deref() {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+    }
+
+    let timer = LiveTimer::maybe_new(!quiet, "Check parent PRs");
+    let Some((rt, client)) = RemoteInfo::from_repo(repo, config).ok().and_then(|info| {
+        tokio::runtime::Runtime::new().ok().and_then(|rt| {
+            let _enter = rt.enter();
+            ForgeClient::new(&info)
+                .ok()
+                .map(|client| (rt, client.with_quiet(quiet)))
+        })
+    }) else {
+        LiveTimer::maybe_finish_skipped(timer, "skipped");
+        return Some(started_at.elapsed());
+    };
+
+    let live_prs: HashMap<String, ForgePrInfo> = rt.block_on(async {
+        stream::iter(tracked_prs.into_iter().map(|(branch_name, pr_number)| {
+            let client = client.clone();
+            async move { (branch_name, client.get_pr(pr_number).await.ok()) }
+        }))
+        .buffer_unordered(PR_METADATA_REFRESH_CONCURRENCY)
+        .filter_map(|(branch_name, live_pr)| async move { Some((branch_name, live_pr?)) })
+        .collect()
+        .await
+    });
+    let merged: HashSet<String> = live_prs
+        .iter()
+        .filter(|(_, pr)| pr.state.eq_ignore_ascii_case("merged"))
+        .map(|(branch, _)| branch.clone())
+        .collect();
+    LiveTimer::maybe_finish_timed(timer);
+
+    for branch in &stacked {
+        let Some(parent) = stack.branches.get(branch).and_then(|b| b.parent.clone()) else {
+            continue;
+        };
+        let open = live_prs
+            .get(branch)
+            .is_some_and(|pr| pr.state.eq_ignore_ascii_case("open"));
+        if !open || !merged.contains(&parent) {
+            continue;
+        }
+        let new_base = nearest_unmerged_base(&stack, &parent, &merged);
+        let pr_number = live_prs[branch].number;
+
+        match update_pr_base_unless_current(&rt, &client, pr_number, &new_base, branch) {
+            Ok(PrBaseUpdate::Updated) | Ok(PrBaseUpdate::AlreadyTargeted) => {
+                if !quiet {
+                    println!(
+                        "    {} retargeted PR #{} ({}) → {}; {} was merged",
+                        "↪".cyan(),
+                        pr_number,
+                        branch.cyan(),
+                        new_base.cyan(),
+                        parent
+                    );
+                }
+            }
+            Ok(PrBaseUpdate::NativeStackLocked) => {
+                print_native_stack_locked_note(quiet, pr_number);
+            }
+            Err(e) => {
+                if !quiet {
+                    println!(
+                        "    {} couldn't retarget PR #{}: {:#}",
+                        "⚠".yellow(),
+                        pr_number,
+                        e
+                    );
+                }
+                continue;
+            }
+        }
+
+        // Keep the merged parent's tip as the restack boundary, as the
+        // deletion path does (see #120).
+        if let Ok(Some(meta)) = BranchMetadata::read(repo.inner(), branch) {
+            let old_parent_boundary = repo
+                .branch_commit(&parent)
+                .ok()
+                .filter(|tip| repo.is_ancestor(tip, branch).unwrap_or(false))
+                .unwrap_or_else(|| meta.parent_branch_revision.clone());
+            let updated = BranchMetadata {
+                parent_branch_name: new_base.clone(),
+                parent_branch_revision: old_parent_boundary,
+                ..meta
+            };
+            if updated.write(repo.inner(), branch).is_ok() && !quiet {
+                println!(
+                    "    {} reparented {} → {}",
+                    "↪".cyan(),
+                    branch.cyan(),
+                    new_base.cyan()
+                );
+            }
+        }
+    }
+
+    Some(started_at.elapsed())
+}
+
+/// Walk up from `parent` past every branch in `merged`, falling back to trunk.
+fn nearest_unmerged_base(stack: &Stack, parent: &str, merged: &HashSet<String>) -> String {
+    let mut current = parent.to_string();
+    let mut visited = HashSet::new();
+    while merged.contains(&current) && visited.insert(current.clone()) {
+        match stack.branches.get(&current).and_then(|b| b.parent.clone()) {
+            Some(next) => current = next,
+            None => return stack.trunk.clone(),
+        }
+    }
+    if merged.contains(&current) {
+        stack.trunk.clone()
+    } else {
+        current
+    }
+}
+
 fn apply_live_pr_state(
     repo: &GitRepo,
     stack: &Stack,
@@ -2255,11 +2711,11 @@ fn find_merged_branches(
     repo: &GitRepo,
     workdir: &std::path::Path,
     stack: &Stack,
-    remote_name: &str,
+    trunk_remote: &str,
     remote_branches: &HashSet<String>,
 ) -> Result<Vec<MergedBranchInfo>> {
     let mut merged = Vec::new();
-    let remote_trunk_ref = format!("{}/{}", remote_name, stack.trunk);
+    let remote_trunk_ref = format!("{}/{}", trunk_remote, stack.trunk);
 
     // Method 1: git branch --merged (finds local branches merged into trunk)
     let output = Command::new("git")
@@ -3551,6 +4007,53 @@ mod tests {
         assert!(!output.contains("Next: st restack --all"));
     }
 
+    fn restack_outcome(branch: &str, millis: u64) -> RestackOutcome {
+        RestackOutcome {
+            branch: branch.to_string(),
+            ok: true,
+            duration: Duration::from_millis(millis),
+        }
+    }
+
+    #[test]
+    fn flags_restacks_well_above_the_median_as_slow() {
+        let outcomes = vec![
+            restack_outcome("a", 300),
+            restack_outcome("b", 350),
+            restack_outcome("c", 2_000),
+            restack_outcome("d", 320),
+        ];
+
+        assert_eq!(slow_restacks(&outcomes), vec![false, false, true, false]);
+    }
+
+    #[test]
+    fn does_not_flag_fast_or_tiny_restack_runs() {
+        let fast = vec![
+            restack_outcome("a", 10),
+            restack_outcome("b", 12),
+            restack_outcome("c", 90),
+        ];
+        assert_eq!(slow_restacks(&fast), vec![false, false, false]);
+
+        let pair = vec![restack_outcome("a", 100), restack_outcome("b", 5_000)];
+        assert_eq!(slow_restacks(&pair), vec![false, false]);
+    }
+
+    #[test]
+    fn json_report_lists_each_restack_with_duration() {
+        let report = render_json_report(
+            &[restack_outcome("a", 42), restack_outcome("b", 7)],
+            Duration::from_millis(1_500),
+        );
+
+        assert_eq!(report["duration_ms"], 1_500);
+        assert_eq!(report["restacked"][0]["branch"], "a");
+        assert_eq!(report["restacked"][0]["status"], "ok");
+        assert_eq!(report["restacked"][0]["duration_ms"], 42);
+        assert_eq!(report["restacked"][1]["slow"], false);
+    }
+
     #[test]
     fn waits_for_slow_trunk_summary_instead_of_dropping_it() {
         let worker = std::thread::spawn(|| {
@@ -3690,4 +4193,43 @@ mod tests {
             ))
         );
     }
+
+    fn chain_stack(chain: &[&str]) -> Stack {
+        let mut branches = HashMap::new();
+        for (index, name) in chain.iter().enumerate() {
+            branches.insert(
+                name.to_string(),
+                crate::engine::stack::StackBranch {
+                    name: name.to_string(),
+                    parent: index.checked_sub(1).map(|parent| chain[parent].to_string()),
+                    parent_revision: None,
+                    children: chain
+                        .get(index + 1)
+                        .map(|c| c.to_string())
+                        .into_iter()
+                        .collect(),
+                    needs_restack: false,
+                    pr_number: None,
+                    pr_state: None,
+                    pr_is_draft: None,
+                },
+            );
+        }
+        Stack {
+            branches,
+            trunk: chain[0].to_string(),
+        }
+    }
+
+    #[test]
+    fn nearest_unmerged_base_skips_a_chain_of_merged_parents() {
+        let stack = chain_stack(&["main", "a", "b", "c", "d"]);
+        let merged: HashSet<String> = ["b", "c"].iter().map(|b| b.to_string()).collect();
+
+        assert_eq!(nearest_unmerged_base(&stack, "c", &merged), "a");
+        assert_eq!(nearest_unmerged_base(&stack, "a", &merged), "a");
+
+        let all_merged: HashSet<String> = ["a", "b", "c"].iter().map(|b| b.to_string()).collect();
+        assert_eq!(nearest_unmerged_base(&stack, "c", &all_merged), "main");
+    }
 }
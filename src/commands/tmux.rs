@@ -1,4 +1,5 @@
 use crate::cache::CiCache;
+use crate::config::{CacheConfig, Config};
 use crate::engine::Stack;
 use crate::git::GitRepo;
 use anyhow::Result;
@@ -138,13 +139,12 @@ fn run_status() -> Result<()> {
     );
     print!("{}", output);
 
-    // Spawn a background `stax ci` refresh when the cache is older than 90 seconds so the
+    // Spawn a background `stax ci` refresh once the cache outlives `cache.ttl_secs` so the
     // status bar stays current without the user having to run stax ci manually.
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_secs())
-        .unwrap_or(0);
-    if now.saturating_sub(cache.last_refresh) > 90
+    let ttl_secs = Config::load()
+        .map(|config| config.cache.ttl_secs)
+        .unwrap_or_else(|_| CacheConfig::default().ttl_secs);
+    if !cache.is_fresh(ttl_secs)
         && let Ok(exe) = std::env::current_exe()
     {
         let _ = std::process::Command::new(exe)
@@ -9,7 +9,13 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use dialoguer::{Confirm, theme::ColorfulTheme};
 
-pub fn run(op_id: Option<String>, yes: bool, no_push: bool, quiet: bool) -> Result<()> {
+pub fn run(
+    op_id: Option<String>,
+    yes: bool,
+    no_push: bool,
+    quiet: bool,
+    autostash: bool,
+) -> Result<()> {
     let repo = GitRepo::open()?;
     let git_dir = repo.git_dir()?;
 
@@ -55,7 +61,13 @@ pub fn run(op_id: Option<String>, yes: bool, no_push: bool, quiet: bool) -> Resu
     }
 
     // Check for dirty working tree
-    if repo.is_dirty()? {
+    let mut autostashed = false;
+    if autostash && repo.is_dirty()? {
+        autostashed = repo.stash_push()?;
+        if autostashed && !quiet {
+            println!("  {} Stashed working tree changes.", "✓".green());
+        }
+    } else if repo.is_dirty()? {
         if quiet {
             anyhow::bail!("Working tree is dirty. Please stash or commit changes first.");
         }
@@ -88,7 +100,11 @@ pub fn run(op_id: Option<String>, yes: bool, no_push: bool, quiet: bool) -> Resu
         Some(&receipt.op_id),
         false,
         &mut NoopOperationReporter,
-    )?;
+    );
+    if autostashed {
+        restore_autostash(&repo, quiet);
+    }
+    let operation = operation?;
     let restored_count = match operation.outcome {
         OperationOutcome::TransactionUndone { changed_refs, .. } => changed_refs.len(),
         _ => 0,
@@ -163,6 +179,25 @@ pub fn run(op_id: Option<String>, yes: bool, no_push: bool, quiet: bool) -> Resu
     Ok(())
 }
 
+/// Re-apply changes stashed by `--autostash`. A pop that conflicts keeps the
+/// stash entry and tells the user how to finish instead of failing the undo.
+pub(crate) fn restore_autostash(repo: &GitRepo, quiet: bool) {
+    match repo.stash_pop() {
+        Ok(()) => {
+            if !quiet {
+                println!("  {} Restored stashed changes.", "✓".green());
+            }
+        }
+        Err(_) => eprintln!(
+            "{}",
+            "Warning: stashed changes did not apply cleanly and were kept in the stash. \
+             Resolve any conflicts and run `git stash drop`, or run `git stash pop` once the \
+             working tree is clean."
+                .yellow()
+        ),
+    }
+}
+
 /// Restore remote refs by force-pushing
 fn restore_remote_refs(repo: &GitRepo, receipt: &OpReceipt, quiet: bool) -> Result<()> {
     let config = Config::load()?;
@@ -144,17 +144,33 @@ pub fn run(auto_stash_pop: bool) -> Result<()> {
                 let source_remote = existing_metadata
                     .as_ref()
                     .and_then(|meta| meta.source_remote.clone());
-                let frozen = existing_metadata.is_some_and(|meta| meta.frozen);
+                let remote = existing_metadata
+                    .as_ref()
+                    .and_then(|meta| meta.remote.clone());
+                let head_owner = existing_metadata
+                    .as_ref()
+                    .and_then(|meta| meta.pr_info.as_ref())
+                    .and_then(|pr_info| pr_info.head_owner.clone());
+                let frozen = existing_metadata.as_ref().is_some_and(|meta| meta.frozen);
+                let (reviewers, team_reviewers, description) = existing_metadata
+                    .map(|meta| (meta.reviewers, meta.team_reviewers, meta.description))
+                    .unwrap_or_default();
                 let updated_meta = BranchMetadata {
                     parent_branch_name: parent_branch_name.clone(),
                     parent_branch_revision: new_parent_rev.clone(),
                     source_remote,
+                    remote,
                     frozen,
+                    reviewers,
+                    team_reviewers,
+                    base_tag: None,
+                    description,
                     pr_info: live_stack.branches.get(branch).and_then(|br| {
                         br.pr_number.map(|n| crate::engine::PrInfo {
                             number: n,
                             state: br.pr_state.clone().unwrap_or_default(),
                             is_draft: br.pr_is_draft,
+                            head_owner: head_owner.clone(),
                         })
                     }),
                 };
@@ -203,6 +219,7 @@ pub fn run(auto_stash_pop: bool) -> Result<()> {
                             .unwrap_or(0),
                         continue_commands: &["stax resolve", "stax continue"],
                         stack_branches: &conflict_stack,
+                        show_hunks: false,
                     },
                 );
 
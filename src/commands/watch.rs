@@ -16,9 +16,9 @@ const DEFAULT_INTERVAL_ACTIVE: u64 = 15;
 const DEFAULT_INTERVAL_IDLE: u64 = 60;
 const DEFAULT_INTERVAL_QUIET: u64 = 120;
 
-pub fn run(current_only: bool, interval: Option<u64>) -> Result<()> {
+pub fn run(current_only: bool, interval: Option<u64>, request_timeout: Option<u64>) -> Result<()> {
     let repo = GitRepo::open()?;
-    let config = Config::load()?;
+    let config = Config::load()?.with_request_timeout(request_timeout);
     let remote_info = RemoteInfo::from_repo(&repo, &config)?;
     let rt = tokio::runtime::Runtime::new()?;
     let _enter = rt.enter();
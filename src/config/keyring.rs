@@ -0,0 +1,151 @@
+//! OS keyring storage for the saved API token (`auth.storage = "keyring"`).
+//!
+//! Uses the platform's own CLI so no native bindings are needed: `security`
+//! for the macOS Keychain, `secret-tool` for Secret Service on Linux, and
+//! Windows PowerShell's `PasswordVault` for the Windows Credential Manager.
+//! Other platforms have no keyring backend, so saving reports an error and
+//! `stax auth` falls back to the credentials file.
+
+use anyhow::{Context, Result, bail};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const SERVICE: &str = "stax";
+const ACCOUNT: &str = "api-token";
+
+/// Keyring account for the default token or the one saved for `host`.
+fn account(host: Option<&str>) -> String {
+    match host {
+        Some(host) => format!("{}@{}", ACCOUNT, host),
+        None => ACCOUNT.to_string(),
+    }
+}
+
+/// Read the saved token, or `None` when the keyring is unavailable or empty.
+pub(super) fn read(host: Option<&str>) -> Option<String> {
+    let output = lookup_command(&account(host))?
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!token.is_empty()).then_some(token)
+}
+
+/// Save `token`, replacing any previous entry. The token goes over stdin so it
+/// never shows up in the process list.
+pub(super) fn store(token: &str, host: Option<&str>) -> Result<()> {
+    let (mut command, input) = store_command(token, &account(host))?;
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run the OS keyring tool")?;
+    child
+        .stdin
+        .take()
+        .context("Failed to open keyring tool stdin")?
+        .write_all(input.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "OS keyring rejected the token: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn lookup_command(account: &str) -> Option<Command> {
+    let mut command = Command::new("security");
+    command.args(["find-generic-password", "-s", SERVICE, "-a", account, "-w"]);
+    Some(command)
+}
+
+#[cfg(target_os = "macos")]
+fn store_command(token: &str, account: &str) -> Result<(Command, String)> {
+    if token.contains(['"', '\\', '\n']) {
+        bail!("Token contains characters the macOS Keychain CLI cannot store");
+    }
+    let mut command = Command::new("security");
+    command.arg("-i");
+    Ok((
+        command,
+        format!(
+            "add-generic-password -U -s {} -a {} -w \"{}\"\n",
+            SERVICE, account, token
+        ),
+    ))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn lookup_command(account: &str) -> Option<Command> {
+    let mut command = Command::new("secret-tool");
+    command.args(["lookup", "service", SERVICE, "account", account]);
+    Some(command)
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn store_command(token: &str, account: &str) -> Result<(Command, String)> {
+    let mut command = Command::new("secret-tool");
+    command.args([
+        "store",
+        "--label=stax API token",
+        "service",
+        SERVICE,
+        "account",
+        account,
+    ]);
+    Ok((command, token.to_string()))
+}
+
+/// Loads the WinRT `PasswordVault` type into Windows PowerShell 5.1.
+#[cfg(windows)]
+const LOAD_VAULT: &str = "[void][Windows.Security.Credentials.PasswordVault,Windows.Security.Credentials,ContentType=WindowsRuntime]; $vault = New-Object Windows.Security.Credentials.PasswordVault";
+
+/// `value` as a single-quoted PowerShell string literal.
+#[cfg(windows)]
+fn powershell_string(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(windows)]
+fn powershell(script: String) -> Command {
+    let mut command = Command::new("powershell");
+    command.args(["-NoProfile", "-NonInteractive", "-Command", &script]);
+    command
+}
+
+#[cfg(windows)]
+fn lookup_command(account: &str) -> Option<Command> {
+    Some(powershell(format!(
+        "{}; $credential = $vault.Retrieve({}, {}); $credential.RetrievePassword(); [Console]::Out.Write($credential.Password)",
+        LOAD_VAULT,
+        powershell_string(SERVICE),
+        powershell_string(account)
+    )))
+}
+
+#[cfg(windows)]
+fn store_command(token: &str, account: &str) -> Result<(Command, String)> {
+    let (service, account) = (powershell_string(SERVICE), powershell_string(account));
+    let command = powershell(format!(
+        "{}; $token = [Console]::In.ReadToEnd(); try {{ $vault.Remove($vault.Retrieve({}, {})) }} catch {{}}; $vault.Add((New-Object Windows.Security.Credentials.PasswordCredential({}, {}, $token)))",
+        LOAD_VAULT, service, account, service, account
+    ));
+    Ok((command, token.to_string()))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn lookup_command(_account: &str) -> Option<Command> {
+    None
+}
+
+#[cfg(not(any(unix, windows)))]
+fn store_command(_token: &str, _account: &str) -> Result<(Command, String)> {
+    bail!("OS keyring storage is only available on macOS, Linux and Windows")
+}
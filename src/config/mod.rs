@@ -1,11 +1,15 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
 
 use crate::remote::ForgeType;
 
+mod keyring;
+
 /// Main config (safe to commit to dotfiles)
 #[derive(Debug, Serialize, Deserialize, Default)]
 pub struct Config {
@@ -29,6 +33,10 @@ pub struct Config {
     pub git: GitConfig,
     #[serde(default)]
     pub restack: RestackConfig,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default)]
+    pub notify: NotifyConfig,
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -41,6 +49,10 @@ struct TrustedNetworkRepoConfig {
 struct TrustedNetworkRepoRemoteConfig {
     #[serde(default)]
     name: Option<String>,
+    #[serde(default)]
+    push_remote: Option<String>,
+    #[serde(default)]
+    pr_remote: Option<String>,
 }
 
 /// User-configurable restack behaviour.
@@ -98,6 +110,22 @@ pub struct BranchConfig {
     /// Number of days without commits before a branch is considered stale by `stax sweep` (default: 30)
     #[serde(default = "default_stale_days")]
     pub stale_days: u64,
+    /// Command run after `stax branch create` makes a branch, with `STAX_BRANCH`,
+    /// `STAX_PARENT` and `STAX_BASE` set. Failures only warn.
+    #[serde(default)]
+    pub post_create_hook: Option<String>,
+    /// Generator run by `stax branch create --scaffold` in the repository root,
+    /// with `STAX_BRANCH`, `STAX_PARENT` and `STAX_BASE` set. Failures only warn.
+    #[serde(default)]
+    pub scaffold_command: Option<String>,
+    /// Issue label to branch prefix for `stax create --from-issue <N> --prefix-from-labels`
+    /// (e.g. `bug = "fix/"`). Issues without a mapped label keep the default prefix.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub label_prefixes: BTreeMap<String, String>,
+    /// Refuse `stax branch create` on trunk once this many trunk children have no
+    /// commits yet (`--allow-trunk-child` overrides). Unset means no limit.
+    #[serde(default)]
+    pub max_empty_trunk_children: Option<usize>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -105,6 +133,12 @@ pub struct RemoteConfig {
     /// Git remote name (default: "origin")
     #[serde(default = "default_remote_name")]
     pub name: String,
+    /// Remote that branches are pushed to in a fork workflow (default: `name`)
+    #[serde(default)]
+    pub push_remote: Option<String>,
+    /// Remote whose repository pull requests are opened against (default: `name`)
+    #[serde(default)]
+    pub pr_remote: Option<String>,
     /// Base web URL for GitHub (e.g., https://github.com or GitHub Enterprise URL)
     #[serde(default = "default_remote_base_url")]
     pub base_url: String,
@@ -115,6 +149,9 @@ pub struct RemoteConfig {
     /// When set, skips auto-detection from the remote hostname.
     #[serde(default)]
     pub forge: Option<ForgeType>,
+    /// Seconds before a forge API request is abandoned (`stax --timeout` overrides).
+    #[serde(default)]
+    pub request_timeout_secs: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -135,6 +172,24 @@ pub struct SubmitConfig {
     /// PR exists, all PRs in the stack get links synced normally.
     #[serde(default)]
     pub single_stack: SingleStackMode,
+    /// Always open `$EDITOR` on each PR's title and body, as with `--edit`.
+    #[serde(default)]
+    pub edit: bool,
+    /// Rewrite existing PR titles and bodies from the branch's commits on
+    /// every submit. Sections between `<!-- stax-manual:start -->` and
+    /// `<!-- stax-manual:end -->` are kept.
+    #[serde(default)]
+    pub sync_pr_text: bool,
+    /// Reviewers requested on every new PR, on top of `--reviewers`.
+    #[serde(default)]
+    pub reviewers: Vec<String>,
+    /// Labels added to every new PR, on top of `--labels`.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Assignees added to every new PR, on top of `--assignees`. `@me` is the
+    /// authenticated user.
+    #[serde(default)]
+    pub assignees: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default)]
@@ -150,6 +205,35 @@ pub struct CiConfig {
     pub error_alert_sound: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct NotifyConfig {
+    /// Desktop notifications while `stax merge` waits: a PR became ready or
+    /// can't merge, and the run finished (as with `stax merge --notify`).
+    #[serde(default)]
+    pub desktop: bool,
+}
+
+/// Persisted PR/CI state under `.git/stax/`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CacheConfig {
+    /// Seconds after a refresh that cached PR/CI state still counts as fresh
+    /// (default: 90).
+    #[serde(default = "default_cache_ttl_secs")]
+    pub ttl_secs: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: default_cache_ttl_secs(),
+        }
+    }
+}
+
+fn default_cache_ttl_secs() -> u64 {
+    90
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum StackLinksMode {
@@ -185,11 +269,22 @@ pub enum SingleStackMode {
     Off,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum TreeStyle {
+    #[default]
+    Unicode,
+    Ascii,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UiConfig {
     /// Whether to show contextual tips/suggestions (default: true)
     #[serde(default = "default_tips")]
     pub tips: bool,
+    /// Connector style for status/log stack trees (default: unicode)
+    #[serde(default)]
+    pub tree_style: TreeStyle,
 }
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
@@ -285,6 +380,22 @@ pub struct AuthConfig {
     /// Optional GitHub hostname for `gh auth token --hostname` (enterprise)
     #[serde(default)]
     pub gh_hostname: Option<String>,
+    /// OAuth app client id used by `stax auth login` (device flow)
+    #[serde(default)]
+    pub oauth_client_id: Option<String>,
+    /// Where `stax auth` saves the token: "file" (default) or "keyring"
+    #[serde(default)]
+    pub storage: CredentialStorage,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CredentialStorage {
+    /// `~/.config/stax/.credentials` (mode 600)
+    #[default]
+    File,
+    /// macOS Keychain, Secret Service or Windows Credential Manager, falling back to the file when unavailable
+    Keyring,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -364,32 +475,50 @@ fn default_max_idle_slots() -> usize {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GitHubAuthSource {
     StaxGithubTokenEnv,
+    Keyring,
     CredentialsFile,
     GhCli,
     GithubTokenEnv,
+    GitHubApp,
 }
 
 impl GitHubAuthSource {
     pub fn display_name(self) -> &'static str {
         match self {
             Self::StaxGithubTokenEnv => "STAX_GITHUB_TOKEN",
+            Self::Keyring => "OS keyring",
             Self::CredentialsFile => "credentials file (~/.config/stax/.credentials)",
             Self::GhCli => "gh auth token",
             Self::GithubTokenEnv => "GITHUB_TOKEN",
+            Self::GitHubApp => "GitHub App installation (STAX_GITHUB_APP_ID)",
         }
     }
 }
 
+/// Where `Config::set_github_token` saved the token.
+#[derive(Debug)]
+pub struct StoredToken {
+    pub source: GitHubAuthSource,
+    /// Why the OS keyring was skipped when `auth.storage = "keyring"`.
+    pub keyring_error: Option<anyhow::Error>,
+}
+
 #[derive(Debug, Clone)]
 pub struct GitHubAuthStatus {
     pub active_source: Option<GitHubAuthSource>,
     pub stax_env_available: bool,
+    pub storage: CredentialStorage,
+    pub keyring_available: bool,
     pub credentials_file_available: bool,
     pub gh_cli_available: bool,
     pub github_env_available: bool,
     pub use_gh_cli: bool,
     pub allow_github_token_env: bool,
     pub gh_hostname: Option<String>,
+    /// Host of the current repository's remote, when inside one.
+    pub host: Option<String>,
+    /// Hosts with their own saved token (`stax auth --host`).
+    pub saved_hosts: Vec<String>,
 }
 
 impl Default for BranchConfig {
@@ -402,6 +531,10 @@ impl Default for BranchConfig {
             format: None,
             user: None,
             stale_days: default_stale_days(),
+            post_create_hook: None,
+            scaffold_command: None,
+            label_prefixes: BTreeMap::new(),
+            max_empty_trunk_children: None,
         }
     }
 }
@@ -418,9 +551,12 @@ impl Default for RemoteConfig {
     fn default() -> Self {
         Self {
             name: default_remote_name(),
+            push_remote: None,
+            pr_remote: None,
             base_url: default_remote_base_url(),
             api_base_url: None,
             forge: None,
+            request_timeout_secs: None,
         }
     }
 }
@@ -429,6 +565,7 @@ impl Default for UiConfig {
     fn default() -> Self {
         Self {
             tips: default_tips(),
+            tree_style: TreeStyle::default(),
         }
     }
 }
@@ -439,8 +576,54 @@ impl Default for AuthConfig {
             use_gh_cli: default_use_gh_cli(),
             allow_github_token_env: default_allow_github_token_env(),
             gh_hostname: None,
+            oauth_client_id: None,
+            storage: CredentialStorage::default(),
+        }
+    }
+}
+
+/// Lowercased bare host name for keying saved tokens, or `None` when `raw`
+/// is not a plain host (it becomes a file name).
+fn normalize_credential_host(raw: &str) -> Option<String> {
+    let host = raw.trim();
+    let host = host
+        .strip_prefix("https://")
+        .or_else(|| host.strip_prefix("http://"))
+        .unwrap_or(host)
+        .trim_end_matches('/')
+        .to_ascii_lowercase();
+    let valid = !host.is_empty()
+        && !host.starts_with('.')
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'));
+    valid.then_some(host)
+}
+
+/// Pull `oauth_token` for `host` out of gh's `hosts.yml`.
+///
+/// gh only stores the token here when it could not use the system keyring,
+/// so this handles just the flat `host: { oauth_token: ... }` shape.
+fn parse_gh_hosts_token(contents: &str, host: &str) -> Option<String> {
+    let unquote = |value: &str| value.trim().trim_matches(['"', '\'']).to_string();
+    let mut in_host = false;
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if !line.starts_with([' ', '\t']) {
+            in_host = trimmed
+                .strip_suffix(':')
+                .is_some_and(|key| unquote(key).eq_ignore_ascii_case(host));
+            continue;
+        }
+        if in_host && let Some(value) = trimmed.strip_prefix("oauth_token:") {
+            let token = unquote(value);
+            return (!token.is_empty()).then_some(token);
         }
     }
+    None
 }
 
 fn default_replacement() -> String {
@@ -511,8 +694,16 @@ impl Config {
     }
 
     /// Get the credentials file path (separate from config, not for dotfiles)
-    fn credentials_path() -> Result<PathBuf> {
-        Ok(Self::dir()?.join(".credentials"))
+    /// `.credentials` for the default token, `.credentials.d/<host>` per host.
+    pub fn credentials_path(host: Option<&str>) -> Result<PathBuf> {
+        let dir = Self::dir()?;
+        Ok(match host {
+            Some(raw) => dir.join(".credentials.d").join(
+                normalize_credential_host(raw)
+                    .with_context(|| format!("Invalid host '{}'", raw))?,
+            ),
+            None => dir.join(".credentials"),
+        })
     }
 
     /// Ensure config exists, creating default if needed
@@ -577,14 +768,19 @@ impl Config {
             .with_context(|| format!("Failed to read repo config {}", repo_path.display()))?;
         let repo_config: TrustedNetworkRepoConfig = toml::from_str(&repo_content)
             .with_context(|| format!("Failed to parse repo config {}", repo_path.display()))?;
-        if let Some(remote_name) = repo_config
-            .remote
-            .name
-            .map(|name| name.trim().to_string())
-            .filter(|name| !name.is_empty())
-        {
+        let non_empty = |name: Option<String>| {
+            name.map(|name| name.trim().to_string())
+                .filter(|name| !name.is_empty())
+        };
+        if let Some(remote_name) = non_empty(repo_config.remote.name) {
             config.remote.name = remote_name;
         }
+        if let Some(push_remote) = non_empty(repo_config.remote.push_remote) {
+            config.remote.push_remote = Some(push_remote);
+        }
+        if let Some(pr_remote) = non_empty(repo_config.remote.pr_remote) {
+            config.remote.pr_remote = Some(pr_remote);
+        }
         Ok(config)
     }
 
@@ -671,14 +867,19 @@ impl Config {
     /// 3. gh auth token (if auth.use_gh_cli = true)
     /// 4. GITHUB_TOKEN (if auth.allow_github_token_env = true)
     pub fn github_token() -> Option<String> {
-        let auth_config = Self::load().map(|c| c.auth).unwrap_or_default();
-        Self::resolve_github_auth_with_config(&auth_config).map(|(_, token)| token)
+        Self::github_token_for_host(None)
     }
 
-    /// Like `github_token` but also returns the source for error messages.
-    pub fn github_token_with_source() -> Option<(GitHubAuthSource, String)> {
-        let auth_config = Self::load().map(|c| c.auth).unwrap_or_default();
-        Self::resolve_github_auth_with_config(&auth_config)
+    /// Like `github_token`, preferring a token saved for `host` with
+    /// `stax auth --host` over the default one.
+    pub fn github_token_for_host(host: Option<&str>) -> Option<String> {
+        Self::github_token_with_source(host).map(|(_, token)| token)
+    }
+
+    /// Like `github_token_for_host` but also returns the source for error messages.
+    pub fn github_token_with_source(host: Option<&str>) -> Option<(GitHubAuthSource, String)> {
+        let auth_config = Self::global_auth_config();
+        Self::resolve_github_auth_with_config(&auth_config, host)
     }
 
     /// Resolve GitHub auth for a validated trusted network destination.
@@ -706,8 +907,8 @@ impl Config {
             return Ok(Some((GitHubAuthSource::StaxGithubTokenEnv, token)));
         }
 
-        if let Some(token) = Self::token_from_credentials_file() {
-            return Ok(Some((GitHubAuthSource::CredentialsFile, token)));
+        if let Some(saved) = Self::saved_token(&self.auth, Some(validated_host)) {
+            return Ok(Some(saved));
         }
 
         if self.auth.use_gh_cli
@@ -725,21 +926,106 @@ impl Config {
         Ok(None)
     }
 
-    /// Get the saved credentials-file token written by `stax auth`.
+    /// Get the saved token written by `stax auth` (keyring or credentials file).
     ///
-    /// This stored token is forge-agnostic and can be reused across GitHub,
-    /// GitLab, and Gitea when forge-specific env vars are not set.
-    pub fn saved_forge_token() -> Option<String> {
-        Self::token_from_credentials_file()
+    /// This stored token is forge-agnostic and is reused for every forge
+    /// when forge-specific env vars are not set.
+    /// A token saved for `host` wins over the default one.
+    pub fn saved_forge_token(host: Option<&str>) -> Option<String> {
+        let auth_config = Self::global_auth_config();
+        Self::saved_token(&auth_config, host).map(|(_, token)| token)
     }
 
-    pub fn github_auth_status() -> GitHubAuthStatus {
-        let auth_config = Self::load().map(|c| c.auth).unwrap_or_default();
+    /// Token saved by `stax auth`: the slot for `host` first, then the default
+    /// slot. Each slot checks the OS keyring when `auth.storage = "keyring"`,
+    /// then the credentials file.
+    fn saved_token(
+        auth_config: &AuthConfig,
+        host: Option<&str>,
+    ) -> Option<(GitHubAuthSource, String)> {
+        let host = host.and_then(normalize_credential_host);
+        if let Some(host) = host.as_deref()
+            && let Some(saved) = Self::saved_token_in_slot(auth_config, Some(host))
+        {
+            return Some(saved);
+        }
+        Self::saved_token_in_slot(auth_config, None)
+    }
+
+    fn saved_token_in_slot(
+        auth_config: &AuthConfig,
+        host: Option<&str>,
+    ) -> Option<(GitHubAuthSource, String)> {
+        if auth_config.storage == CredentialStorage::Keyring
+            && let Some(token) = keyring::read(host)
+        {
+            return Some((GitHubAuthSource::Keyring, token));
+        }
+        Self::token_from_credentials_file(host)
+            .map(|token| (GitHubAuthSource::CredentialsFile, token))
+    }
+
+    /// Auth settings from the global config only, like the trusted network
+    /// path: a repository's `stax.toml` must not decide where tokens are read
+    /// from or saved to.
+    fn global_auth_config() -> AuthConfig {
+        Self::path()
+            .and_then(|path| Self::load_path_or_default(&path))
+            .map(|config| config.auth)
+            .unwrap_or_default()
+    }
+
+    /// Hosts with a saved token (credentials directory or OS keyring), sorted.
+    pub fn saved_token_hosts() -> Vec<String> {
+        let Ok(dir) = Self::dir() else {
+            return Vec::new();
+        };
+        let mut hosts: Vec<String> = fs::read_dir(dir.join(".credentials.d"))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .collect();
+        if Self::global_auth_config().storage == CredentialStorage::Keyring {
+            hosts.extend(Self::keyring_token_hosts());
+        }
+        hosts.sort();
+        hosts.dedup();
+        hosts
+    }
+
+    /// Hosts whose token went to the OS keyring. The keyring tools cannot list
+    /// entries, so `set_github_token` keeps this index next to the config.
+    fn keyring_token_hosts() -> Vec<String> {
+        Self::dir()
+            .and_then(|dir| Ok(fs::read_to_string(dir.join(".keyring-hosts"))?))
+            .map(|content| content.lines().map(str::to_string).collect())
+            .unwrap_or_default()
+    }
+
+    fn record_keyring_token_host(host: &str) -> Result<()> {
+        let mut hosts = Self::keyring_token_hosts();
+        if hosts.iter().any(|saved| saved == host) {
+            return Ok(());
+        }
+        hosts.push(host.to_string());
+        hosts.sort();
+        let dir = Self::dir()?;
+        fs::create_dir_all(&dir)?;
+        fs::write(dir.join(".keyring-hosts"), hosts.join("\n") + "\n")?;
+        Ok(())
+    }
+
+    /// Auth status as seen from a repository on `host`, when known.
+    pub fn github_auth_status(host: Option<&str>) -> GitHubAuthStatus {
+        let auth_config = Self::global_auth_config();
 
         let stax_env_available = Self::read_env_token("STAX_GITHUB_TOKEN").is_some();
-        let credentials_file_available = Self::token_from_credentials_file().is_some();
+        let saved = Self::saved_token(&auth_config, host).map(|(source, _)| source);
+        let keyring_available = saved == Some(GitHubAuthSource::Keyring);
+        let credentials_file_available = saved == Some(GitHubAuthSource::CredentialsFile);
         let gh_cli_available = if auth_config.use_gh_cli {
-            Self::token_from_gh_cli(auth_config.gh_hostname.as_deref())
+            Self::token_from_gh_cli(auth_config.gh_hostname.as_deref().or(host))
                 .ok()
                 .flatten()
                 .is_some()
@@ -750,6 +1036,8 @@ impl Config {
 
         let active_source = if stax_env_available {
             Some(GitHubAuthSource::StaxGithubTokenEnv)
+        } else if keyring_available {
+            Some(GitHubAuthSource::Keyring)
         } else if credentials_file_available {
             Some(GitHubAuthSource::CredentialsFile)
         } else if auth_config.use_gh_cli && gh_cli_available {
@@ -763,18 +1051,62 @@ impl Config {
         GitHubAuthStatus {
             active_source,
             stax_env_available,
+            storage: auth_config.storage,
+            keyring_available,
             credentials_file_available,
             gh_cli_available,
             github_env_available,
             use_gh_cli: auth_config.use_gh_cli,
             allow_github_token_env: auth_config.allow_github_token_env,
             gh_hostname: auth_config.gh_hostname,
+            host: host.map(str::to_string),
+            saved_hosts: Self::saved_token_hosts(),
         }
     }
 
-    /// Set the saved API token (to credentials file)
-    pub fn set_github_token(token: &str) -> Result<()> {
-        let path = Self::credentials_path()?;
+    /// Save the API token and report where it went.
+    ///
+    /// With `auth.storage = "keyring"` the token goes to the OS keyring and any
+    /// plaintext credentials file is removed; if the keyring is unavailable the
+    /// file is used instead and the keyring error is returned alongside.
+    ///
+    /// With `host` the token is only used for repositories on that host;
+    /// without it, it is the default for every host.
+    pub fn set_github_token(token: &str, host: Option<&str>) -> Result<StoredToken> {
+        let host = host
+            .map(|raw| {
+                normalize_credential_host(raw).with_context(|| format!("Invalid host '{}'", raw))
+            })
+            .transpose()?;
+        let host = host.as_deref();
+        let mut keyring_error = None;
+        if Self::global_auth_config().storage == CredentialStorage::Keyring {
+            match keyring::store(token, host) {
+                Ok(()) => {
+                    let path = Self::credentials_path(host)?;
+                    if path.exists() {
+                        fs::remove_file(&path)?;
+                    }
+                    if let Some(host) = host {
+                        Self::record_keyring_token_host(host)?;
+                    }
+                    return Ok(StoredToken {
+                        source: GitHubAuthSource::Keyring,
+                        keyring_error: None,
+                    });
+                }
+                Err(error) => keyring_error = Some(error),
+            }
+        }
+        Self::write_credentials_file(token, host)?;
+        Ok(StoredToken {
+            source: GitHubAuthSource::CredentialsFile,
+            keyring_error,
+        })
+    }
+
+    fn write_credentials_file(token: &str, host: Option<&str>) -> Result<()> {
+        let path = Self::credentials_path(host)?;
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
@@ -791,24 +1123,70 @@ impl Config {
         Ok(())
     }
 
+    /// OAuth client id for `stax auth login`: `STAX_GITHUB_OAUTH_CLIENT_ID`,
+    /// then `auth.oauth_client_id`.
+    pub fn github_oauth_client_id(&self) -> Option<String> {
+        Self::read_env_token("STAX_GITHUB_OAUTH_CLIENT_ID").or_else(|| {
+            self.auth
+                .oauth_client_id
+                .as_deref()
+                .and_then(Self::normalize_token)
+        })
+    }
+
     /// Read token from gh CLI for explicit import (`stax auth --from-gh`).
-    pub fn gh_cli_token_for_import() -> Result<String> {
+    ///
+    /// Falls back to gh's `hosts.yml` when the gh binary is unavailable or
+    /// cannot print the token.
+    pub fn gh_cli_token_for_import(host: Option<&str>) -> Result<String> {
         let auth_config = Self::load().map(|c| c.auth).unwrap_or_default();
+        let hostname = host.or(auth_config.gh_hostname.as_deref());
 
-        Self::token_from_gh_cli(auth_config.gh_hostname.as_deref())?.context(
-            "Could not read token from `gh auth token`.\n\
+        if let Ok(Some(token)) = Self::token_from_gh_cli(hostname) {
+            return Ok(token);
+        }
+        Self::token_from_gh_hosts_file(hostname).context(
+            "Could not read token from `gh auth token` or gh's hosts.yml.\n\
              Ensure GitHub CLI is installed and authenticated (`gh auth login`).",
         )
     }
 
+    /// Whether `stax auth --from-gh` would find a token to import.
+    pub fn gh_token_importable() -> bool {
+        Self::gh_cli_token_for_import(None).is_ok()
+    }
+
+    fn token_from_gh_hosts_file(hostname: Option<&str>) -> Option<String> {
+        let dir = std::env::var_os("GH_CONFIG_DIR")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("XDG_CONFIG_HOME")
+                    .filter(|dir| !dir.is_empty())
+                    .map(|dir| PathBuf::from(dir).join("gh"))
+            })
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .filter(|home| !home.is_empty())
+                    .map(PathBuf::from)
+                    .or_else(dirs::home_dir)
+                    .map(|home| home.join(".config").join("gh"))
+            })?;
+        let contents = fs::read_to_string(dir.join("hosts.yml")).ok()?;
+        let host = hostname
+            .and_then(Self::normalize_token)
+            .unwrap_or_else(|| "github.com".to_string());
+        parse_gh_hosts_token(&contents, &host)
+    }
+
     fn read_env_token(var_name: &str) -> Option<String> {
         std::env::var(var_name)
             .ok()
             .and_then(|value| Self::normalize_token(value.as_str()))
     }
 
-    fn token_from_credentials_file() -> Option<String> {
-        let path = Self::credentials_path().ok()?;
+    fn token_from_credentials_file(host: Option<&str>) -> Option<String> {
+        let path = Self::credentials_path(host).ok()?;
         let token = fs::read_to_string(path).ok()?;
         Self::normalize_token(token.as_str())
     }
@@ -845,17 +1223,19 @@ impl Config {
 
     fn resolve_github_auth_with_config(
         auth_config: &AuthConfig,
+        host: Option<&str>,
     ) -> Option<(GitHubAuthSource, String)> {
         if let Some(token) = Self::read_env_token("STAX_GITHUB_TOKEN") {
             return Some((GitHubAuthSource::StaxGithubTokenEnv, token));
         }
 
-        if let Some(token) = Self::token_from_credentials_file() {
-            return Some((GitHubAuthSource::CredentialsFile, token));
+        if let Some(saved) = Self::saved_token(auth_config, host) {
+            return Some(saved);
         }
 
         if auth_config.use_gh_cli
-            && let Ok(Some(token)) = Self::token_from_gh_cli(auth_config.gh_hostname.as_deref())
+            && let Ok(Some(token)) =
+                Self::token_from_gh_cli(auth_config.gh_hostname.as_deref().or(host))
         {
             return Some((GitHubAuthSource::GhCli, token));
         }
@@ -1037,19 +1417,173 @@ impl Config {
         }
     }
 
+    /// Remote that stack branches are fetched from and pushed to:
+    /// `remote.push_remote` when set, otherwise `remote.name`.
     pub fn remote_name(&self) -> &str {
-        self.remote.name.as_str()
+        self.remote
+            .push_remote
+            .as_deref()
+            .unwrap_or(self.remote.name.as_str())
+    }
+
+    /// Remote whose repository hosts pull requests and the canonical trunk:
+    /// `remote.pr_remote` when set, otherwise `remote.name`.
+    pub fn pr_remote_name(&self) -> &str {
+        self.remote
+            .pr_remote
+            .as_deref()
+            .unwrap_or(self.remote.name.as_str())
     }
 
     pub fn remote_base_url(&self) -> &str {
         self.remote.base_url.as_str()
     }
 
+    /// Forge API request timeout from `remote.request_timeout_secs`.
+    pub fn request_timeout(&self) -> Option<Duration> {
+        self.remote
+            .request_timeout_secs
+            .map(|secs| Duration::from_secs(secs.max(1)))
+    }
+
+    /// Let `stax --timeout <secs>` win over `remote.request_timeout_secs` for
+    /// the clients built from this config.
+    pub fn with_request_timeout(mut self, secs: Option<u64>) -> Self {
+        if secs.is_some() {
+            self.remote.request_timeout_secs = secs;
+        }
+        self
+    }
+
+    /// Point both push and PR sides at the `--remote` selection, if any.
+    pub fn with_remote(mut self, name: Option<String>) -> Self {
+        if let Some(name) = name {
+            self.remote.name = name;
+            self.remote.push_remote = None;
+            self.remote.pr_remote = None;
+        }
+        self
+    }
+
     pub fn remote_forge_override(&self) -> Option<ForgeType> {
         self.remote.forge
     }
 }
 
+/// Where an effective setting's value comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigSource {
+    Default,
+    Global,
+    Repo,
+}
+
+impl std::fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Default => "default",
+            Self::Global => "global",
+            Self::Repo => "repo",
+        })
+    }
+}
+
+/// One resolved setting, as reported by `stax config get --effective`.
+#[derive(Debug, Clone, Serialize)]
+pub struct EffectiveSetting {
+    pub key: String,
+    pub value: toml::Value,
+    pub source: ConfigSource,
+}
+
+/// Files that fed into the effective config.
+pub struct ConfigLayers {
+    pub global_path: PathBuf,
+    /// `None` when there is no `stax.toml` or `STAX_CONFIG_DIR` disables the overlay.
+    pub repo_path: Option<PathBuf>,
+}
+
+impl Config {
+    /// Config files consulted by [`Config::load`].
+    pub fn layers() -> Result<ConfigLayers> {
+        let repo_path = if config_dir_override().is_some() {
+            None
+        } else {
+            Self::repo_local_path()?
+        };
+        Ok(ConfigLayers {
+            global_path: Self::path()?,
+            repo_path,
+        })
+    }
+
+    /// Every leaf setting of the loaded config, attributed to the layer that set it.
+    pub fn effective_settings() -> Result<Vec<EffectiveSetting>> {
+        let layers = Self::layers()?;
+        let global = read_toml_file(&layers.global_path)?;
+        let repo = match &layers.repo_path {
+            Some(path) => read_toml_file(path)?,
+            None => None,
+        };
+        let effective = toml::Value::try_from(Self::load()?)?;
+
+        let mut leaves = Vec::new();
+        collect_toml_leaves(&mut Vec::new(), &effective, &mut leaves);
+        Ok(leaves
+            .into_iter()
+            .map(|(path, value)| {
+                let source = if repo.as_ref().is_some_and(|r| toml_has_path(r, &path)) {
+                    ConfigSource::Repo
+                } else if global.as_ref().is_some_and(|g| toml_has_path(g, &path)) {
+                    ConfigSource::Global
+                } else {
+                    ConfigSource::Default
+                };
+                EffectiveSetting {
+                    key: path.join("."),
+                    value,
+                    source,
+                }
+            })
+            .collect())
+    }
+}
+
+fn read_toml_file(path: &Path) -> Result<Option<toml::Value>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read config {}", path.display()))?;
+    let value = toml::from_str(&content)
+        .with_context(|| format!("Failed to parse config {}", path.display()))?;
+    Ok(Some(value))
+}
+
+fn collect_toml_leaves(
+    path: &mut Vec<String>,
+    value: &toml::Value,
+    leaves: &mut Vec<(Vec<String>, toml::Value)>,
+) {
+    match value {
+        toml::Value::Table(table) => {
+            for (key, child) in table {
+                path.push(key.clone());
+                collect_toml_leaves(path, child, leaves);
+                path.pop();
+            }
+        }
+        leaf => leaves.push((path.clone(), leaf.clone())),
+    }
+}
+
+fn toml_has_path(value: &toml::Value, path: &[String]) -> bool {
+    path.iter()
+        .try_fold(value, |node, key| node.get(key.as_str()))
+        .is_some()
+}
+
 fn git_root() -> Result<Option<PathBuf>> {
     Ok(git2::Repository::discover(".")
         .ok()
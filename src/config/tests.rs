@@ -792,6 +792,24 @@ stack_links = "both"
     assert!(encoded.contains("stack_links = \"both\""));
 }
 
+#[test]
+fn test_submit_default_pr_metadata_parses() {
+    let config: Config = toml::from_str(
+        r#"
+[submit]
+reviewers = ["alice", "org/platform"]
+labels = ["stacked"]
+assignees = ["@me"]
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(config.submit.reviewers, vec!["alice", "org/platform"]);
+    assert_eq!(config.submit.labels, vec!["stacked"]);
+    assert_eq!(config.submit.assignees, vec!["@me"]);
+    assert!(Config::default().submit.reviewers.is_empty());
+}
+
 #[test]
 fn test_submit_stack_links_defaults_to_comment_when_missing() {
     let config: Config = toml::from_str(
@@ -1267,7 +1285,7 @@ fn test_set_github_token_writes_to_file() {
 
     // Write token
     let test_token = "ghp_test_token_12345";
-    let result = Config::set_github_token(test_token);
+    let result = Config::set_github_token(test_token, None);
     assert!(result.is_ok(), "set_github_token should succeed");
 
     // Verify file was created with correct content
@@ -1364,7 +1382,7 @@ fn test_github_token_roundtrip() {
 
     // Write token
     let test_token = "ghp_roundtrip_token_abcdef";
-    Config::set_github_token(test_token).unwrap();
+    Config::set_github_token(test_token, None).unwrap();
 
     // Verify by reading file directly (avoids env var race conditions)
     let creds_path = temp_dir.join(".config").join("stax").join(".credentials");
@@ -1981,3 +1999,126 @@ replacement = "-"
     // Legacy behavior should still work
     assert_eq!(config.format_branch_name("feature"), "cesar/feature");
 }
+
+#[test]
+fn fork_workflow_remotes_fall_back_to_remote_name() {
+    let mut config = Config::default();
+    assert_eq!(config.remote_name(), "origin");
+    assert_eq!(config.pr_remote_name(), "origin");
+
+    config.remote.pr_remote = Some("upstream".to_string());
+    assert_eq!(config.remote_name(), "origin");
+    assert_eq!(config.pr_remote_name(), "upstream");
+
+    config.remote.push_remote = Some("fork".to_string());
+    assert_eq!(config.remote_name(), "fork");
+    assert_eq!(config.pr_remote_name(), "upstream");
+}
+
+#[test]
+fn parse_gh_hosts_token_reads_matching_host() {
+    let contents = "\
+github.com:
+    user: octocat
+    oauth_token: gho_public
+    git_protocol: https
+github.company.com:
+    oauth_token: \"gho_enterprise\"
+";
+
+    assert_eq!(
+        parse_gh_hosts_token(contents, "github.com").as_deref(),
+        Some("gho_public")
+    );
+    assert_eq!(
+        parse_gh_hosts_token(contents, "github.company.com").as_deref(),
+        Some("gho_enterprise")
+    );
+    assert_eq!(parse_gh_hosts_token(contents, "gitlab.com"), None);
+}
+
+#[test]
+fn parse_gh_hosts_token_ignores_keyring_only_hosts() {
+    let contents = "\
+github.com:
+    git_protocol: ssh
+    users:
+        octocat:
+    user: octocat
+";
+
+    assert_eq!(parse_gh_hosts_token(contents, "github.com"), None);
+}
+
+#[test]
+fn auth_storage_defaults_to_file_and_parses_keyring() {
+    assert_eq!(AuthConfig::default().storage, CredentialStorage::File);
+
+    let parsed: Config = toml::from_str("[auth]\nstorage = \"keyring\"\n").unwrap();
+    assert_eq!(parsed.auth.storage, CredentialStorage::Keyring);
+}
+
+#[test]
+fn normalize_credential_host_accepts_plain_hosts_only() {
+    assert_eq!(
+        normalize_credential_host("https://GHE.Corp.com/").as_deref(),
+        Some("ghe.corp.com")
+    );
+    assert_eq!(
+        normalize_credential_host("ghe.corp.com:8443").as_deref(),
+        Some("ghe.corp.com:8443")
+    );
+    assert_eq!(normalize_credential_host("../etc"), None);
+    assert_eq!(normalize_credential_host("ghe.corp.com/api"), None);
+    assert_eq!(normalize_credential_host("  "), None);
+}
+
+#[test]
+fn github_token_for_host_prefers_host_specific_token() {
+    let _guard = env_lock();
+
+    let orig_home = env::var("HOME").ok();
+    let orig_stax = env::var("STAX_GITHUB_TOKEN").ok();
+    let temp_dir = std::env::temp_dir().join(format!("stax-test-hosts-{}", std::process::id()));
+    let config_dir = temp_dir.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).unwrap();
+
+    unsafe { env::set_var("HOME", &temp_dir) };
+    unsafe { env::set_var("STAX_CONFIG_DIR", &config_dir) };
+    unsafe { env::remove_var("STAX_GITHUB_TOKEN") };
+
+    Config::set_github_token("ghp_default", None).unwrap();
+    Config::set_github_token("ghp_enterprise", Some("GHE.corp.com")).unwrap();
+
+    assert!(
+        config_dir
+            .join(".credentials.d")
+            .join("ghe.corp.com")
+            .exists()
+    );
+    assert_eq!(
+        Config::github_token_for_host(Some("ghe.corp.com")).as_deref(),
+        Some("ghp_enterprise")
+    );
+    assert_eq!(
+        Config::github_token_for_host(Some("github.com")).as_deref(),
+        Some("ghp_default")
+    );
+    assert_eq!(Config::github_token().as_deref(), Some("ghp_default"));
+    assert_eq!(
+        Config::saved_token_hosts(),
+        vec!["ghe.corp.com".to_string()]
+    );
+    assert!(Config::set_github_token("x", Some("../evil")).is_err());
+
+    let _ = fs::remove_dir_all(&temp_dir);
+    unsafe { env::remove_var("STAX_CONFIG_DIR") };
+    match orig_home {
+        Some(v) => unsafe { env::set_var("HOME", v) },
+        None => unsafe { env::remove_var("HOME") },
+    }
+    match orig_stax {
+        Some(v) => unsafe { env::set_var("STAX_GITHUB_TOKEN", v) },
+        None => unsafe { env::remove_var("STAX_GITHUB_TOKEN") },
+    }
+}
@@ -19,9 +19,26 @@ pub struct BranchMetadata {
     /// them, but submit should not push or update their PRs.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_remote: Option<String>,
+    /// Git remote this branch's stack was submitted to with `--remote`.
+    ///
+    /// Submit, sync, and merge use it when no `--remote` is given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
     /// Protect this branch from history-rewriting bulk operations.
     #[serde(default)]
     pub frozen: bool,
+    /// User logins to request review from when submit opens this branch's PR.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reviewers: Vec<String>,
+    /// Team reviewers (`org/team`) to request when submit opens this branch's PR.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub team_reviewers: Vec<String>,
+    /// Tag the branch was created from with `stax create --from-tag`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_tag: Option<String>,
+    /// Free-form description written with `stax create --open-editor`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /// PR information (if submitted)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pr_info: Option<PrInfo>,
@@ -36,6 +53,10 @@ pub struct PrInfo {
     pub state: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_draft: Option<bool>,
+    /// Owner of the fork the PR's head branch lives in, when it differs from
+    /// the base repo (a cross-repo PR). Submit pushes to that fork's remote.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_owner: Option<String>,
 }
 
 impl BranchMetadata {
@@ -45,7 +66,12 @@ impl BranchMetadata {
             parent_branch_name: parent_name.to_string(),
             parent_branch_revision: parent_revision.to_string(),
             source_remote: None,
+            remote: None,
             frozen: false,
+            reviewers: Vec::new(),
+            team_reviewers: Vec::new(),
+            base_tag: None,
+            description: None,
             pr_info: None,
         }
     }
@@ -63,7 +89,10 @@ impl BranchMetadata {
                         refs::read_trunk(repo)?.unwrap_or_else(|| "main".to_string());
                 }
 
+                // An explicitly empty revision (`branch create --no-parent-revision`)
+                // is kept so the branch reads as needing a restack.
                 if meta.parent_branch_revision.trim().is_empty()
+                    && !json.contains("\"parentBranchRevision\"")
                     && let Ok(parent_ref) =
                         repo.find_branch(&meta.parent_branch_name, git2::BranchType::Local)
                     && let Ok(commit) = parent_ref.get().peel_to_commit()
@@ -118,6 +147,23 @@ mod tests {
         assert!(meta.pr_info.is_none());
     }
 
+    #[test]
+    fn test_metadata_reviewers_round_trip() {
+        let mut meta = BranchMetadata::new("main", "abc123");
+        let json = serde_json::to_string(&meta).unwrap();
+        assert!(!json.contains("reviewers"));
+        assert!(!json.contains("teamReviewers"));
+
+        meta.reviewers = vec!["alice".to_string()];
+        meta.team_reviewers = vec!["acme/core".to_string()];
+        let json = serde_json::to_string(&meta).unwrap();
+        assert!(json.contains("\"teamReviewers\":[\"acme/core\"]"));
+
+        let parsed: BranchMetadata = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.reviewers, vec!["alice"]);
+        assert_eq!(parsed.team_reviewers, vec!["acme/core"]);
+    }
+
     #[test]
     fn test_metadata_serialization() {
         let meta = BranchMetadata::new("main", "abc123");
@@ -167,6 +167,21 @@ pub fn choose_rebase_upstream(
     stored_revision: &str,
     _quiet: bool,
 ) -> RebaseBoundaryDecision {
+    // No stored boundary (e.g. `branch create --no-parent-revision`): the
+    // merge-base is the only usable upstream.
+    if stored_revision.trim().is_empty()
+        && let Ok(merge_base) = repo.merge_base(parent, branch)
+    {
+        return RebaseBoundaryDecision {
+            upstream: merge_base,
+            adjusted: true,
+            reason: Some(format!(
+                "'{}' has no stored boundary from '{}'; using merge-base boundary",
+                branch, parent
+            )),
+        };
+    }
+
     if !config.restack.preflight_auto_repair {
         return RebaseBoundaryDecision {
             upstream: stored_revision.to_string(),
@@ -18,6 +18,16 @@ pub struct StackBranch {
     pub pr_is_draft: Option<bool>,
 }
 
+/// A tracked branch whose recorded parent chain never reaches trunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisconnectedBranch {
+    pub branch: String,
+    /// Branch whose recorded parent breaks the chain (may be `branch` itself).
+    pub broken_at: String,
+    /// The recorded parent that is untracked, deleted, or part of a cycle.
+    pub missing_parent: String,
+}
+
 /// The full stack structure
 #[derive(Debug, Clone)]
 pub struct Stack {
@@ -240,6 +250,52 @@ impl Stack {
             .map(|b| b.children.clone())
             .unwrap_or_default()
     }
+
+    /// Report tracked branches whose *recorded* parent chain does not end at
+    /// trunk, e.g. because an intermediate branch was untracked or deleted
+    /// without reparenting its children.
+    ///
+    /// `load` reparents a branch with a missing parent onto trunk in memory,
+    /// which hides the break from everything stacked above it; this re-reads
+    /// the stored metadata so the whole stray subtree is reported.
+    pub fn validate(&self, repo: &GitRepo) -> Result<Vec<DisconnectedBranch>> {
+        let mut recorded_parents = HashMap::new();
+        for name in self.branches.keys().filter(|name| **name != self.trunk) {
+            if let Some(meta) = BranchMetadata::read(repo.inner(), name)? {
+                recorded_parents.insert(name.clone(), meta.parent_branch_name);
+            }
+        }
+        Ok(find_disconnected(&recorded_parents, &self.trunk))
+    }
+}
+
+/// Walk each branch's recorded parent chain and return the ones that never
+/// reach `trunk`, sorted by branch name. Iterative and cycle-safe.
+fn find_disconnected(parents: &HashMap<String, String>, trunk: &str) -> Vec<DisconnectedBranch> {
+    let mut disconnected = Vec::new();
+
+    for branch in parents.keys() {
+        let mut current = branch.clone();
+        let mut visited = HashSet::from([current.clone()]);
+
+        while let Some(parent) = parents.get(&current) {
+            if parent == trunk {
+                break;
+            }
+            if !parents.contains_key(parent) || !visited.insert(parent.clone()) {
+                disconnected.push(DisconnectedBranch {
+                    branch: branch.clone(),
+                    broken_at: current.clone(),
+                    missing_parent: parent.clone(),
+                });
+                break;
+            }
+            current = parent.clone();
+        }
+    }
+
+    disconnected.sort_by(|a, b| a.branch.cmp(&b.branch));
+    disconnected
 }
 
 #[cfg(test)]
@@ -599,4 +655,49 @@ mod tests {
         let debug_str = format!("{:?}", branch);
         assert!(debug_str.contains("test"));
     }
+
+    #[test]
+    fn test_find_disconnected_reports_chain_through_untracked_parent() {
+        // main <- a <- (b untracked) <- c <- d
+        let parents = HashMap::from([
+            ("a".to_string(), "main".to_string()),
+            ("c".to_string(), "b".to_string()),
+            ("d".to_string(), "c".to_string()),
+        ]);
+
+        let disconnected = find_disconnected(&parents, "main");
+
+        assert_eq!(
+            disconnected,
+            vec![
+                DisconnectedBranch {
+                    branch: "c".to_string(),
+                    broken_at: "c".to_string(),
+                    missing_parent: "b".to_string(),
+                },
+                DisconnectedBranch {
+                    branch: "d".to_string(),
+                    broken_at: "c".to_string(),
+                    missing_parent: "b".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_disconnected_terminates_on_cycles() {
+        let parents = HashMap::from([
+            ("x".to_string(), "y".to_string()),
+            ("y".to_string(), "x".to_string()),
+        ]);
+
+        let disconnected = find_disconnected(&parents, "main");
+
+        assert_eq!(disconnected.len(), 2);
+        assert!(
+            disconnected
+                .iter()
+                .all(|d| d.branch == "x" || d.branch == "y")
+        );
+    }
 }
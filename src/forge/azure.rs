@@ -0,0 +1,1417 @@
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{
+    AuthStyle, PrActivity, RepoIssueListItem, RepoPrListItem, ReviewActivity, STACK_COMMENT_MARKER,
+    aggregate_ci_overall, build_http_client, ci_status_from_string, delete_empty,
+    encode_query_value, get_json, make_issue_comment, mergeable_bool, patch_json, post_json,
+    put_json, stack_comment_body,
+};
+use crate::ci::CheckRunInfo;
+use crate::github::client::OpenPrInfo;
+use crate::github::pr::{MergeMethod, PrComment, PrInfo, PrInfoWithHead, PrMergeStatus};
+use crate::remote::{ForgeType, RemoteInfo};
+
+const API_VERSION: &str = "api-version=7.1";
+
+/// Client for Azure Repos pull requests (Azure DevOps Services and Server).
+#[derive(Clone)]
+pub struct AzureDevOpsClient {
+    client: Client,
+    api_base_url: String,
+    /// Organization (or `tfs/Collection` on Azure DevOps Server).
+    org: String,
+    project: String,
+    repo: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureList<T> {
+    value: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzurePull {
+    pull_request_id: u64,
+    /// `active`, `abandoned`, or `completed`.
+    status: String,
+    title: String,
+    description: Option<String>,
+    #[serde(default)]
+    is_draft: bool,
+    source_ref_name: String,
+    target_ref_name: String,
+    created_by: Option<AzureIdentity>,
+    creation_date: Option<DateTime<Utc>>,
+    closed_date: Option<DateTime<Utc>>,
+    /// `succeeded`, `conflicts`, `queued`, `notSet`, `rejectedByPolicy`, or `failure`.
+    merge_status: Option<String>,
+    last_merge_source_commit: Option<AzureCommitRef>,
+    #[serde(default)]
+    reviewers: Vec<AzureReviewer>,
+    repository: Option<AzureRepository>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureIdentity {
+    display_name: Option<String>,
+    unique_name: Option<String>,
+}
+
+impl AzureIdentity {
+    fn handle(&self) -> String {
+        self.unique_name
+            .clone()
+            .or_else(|| self.display_name.clone())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureReviewer {
+    #[serde(flatten)]
+    identity: AzureIdentity,
+    /// 10 approved, 5 approved with suggestions, 0 no vote, -5 waiting for author, -10 rejected.
+    #[serde(default)]
+    vote: i32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureCommitRef {
+    commit_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureRepository {
+    project: Option<AzureProject>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureProject {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureThread {
+    id: u64,
+    #[serde(default)]
+    comments: Vec<AzureComment>,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureComment {
+    id: u64,
+    content: Option<String>,
+    author: Option<AzureIdentity>,
+    published_date: Option<DateTime<Utc>>,
+    /// `text` for people, `system` for vote and push notices.
+    comment_type: Option<String>,
+    #[serde(default)]
+    is_deleted: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureCommitStatus {
+    state: Option<String>,
+    context: Option<AzureStatusContext>,
+    target_url: Option<String>,
+    creation_date: Option<String>,
+    updated_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureStatusContext {
+    name: Option<String>,
+    genre: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AzurePolicyEvaluation {
+    /// `approved`, `rejected`, `running`, `queued`, `notApplicable`, or `broken`.
+    status: Option<String>,
+    configuration: Option<AzurePolicyConfiguration>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzurePolicyConfiguration {
+    #[serde(default)]
+    is_blocking: bool,
+    #[serde(default = "default_true")]
+    is_enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+#[derive(Debug, Deserialize)]
+struct AzureIdentityMatch {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureConnectionData {
+    authenticated_user: AzureConnectionUser,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AzureConnectionUser {
+    provider_display_name: Option<String>,
+    #[serde(default)]
+    properties: serde_json::Value,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreatePullRequest<'a> {
+    source_ref_name: String,
+    target_ref_name: String,
+    title: &'a str,
+    description: &'a str,
+    is_draft: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CompletePullRequest<'a> {
+    status: &'a str,
+    last_merge_source_commit: CommitId<'a>,
+    completion_options: CompletionOptions<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CommitId<'a> {
+    commit_id: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CompletionOptions<'a> {
+    merge_strategy: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_commit_message: Option<&'a str>,
+    delete_source_branch: bool,
+}
+
+/// Combined state of the blocking branch policies on a PR.
+#[derive(Debug, PartialEq, Eq)]
+enum PolicyState {
+    Satisfied,
+    Pending,
+    Rejected,
+}
+
+impl AzureDevOpsClient {
+    pub fn new(remote: &RemoteInfo) -> Result<Self> {
+        if remote.forge != ForgeType::AzureDevOps {
+            bail!("Internal error: expected Azure DevOps remote");
+        }
+
+        let token = super::forge_token(ForgeType::AzureDevOps, &remote.host).context(
+            "Azure DevOps auth not configured. Use `stax auth` or set `STAX_AZURE_DEVOPS_TOKEN`, `AZURE_DEVOPS_EXT_PAT`, or `STAX_FORGE_TOKEN`.",
+        )?;
+        // Personal access tokens go in basic auth with an empty user name.
+        let token = if token.contains(':') {
+            token
+        } else {
+            format!(":{}", token)
+        };
+        let (org, project) = remote.namespace.rsplit_once('/').with_context(|| {
+            format!(
+                "Azure DevOps remote '{}' must look like <org>/<project>/_git/<repo>",
+                remote.project_path()
+            )
+        })?;
+
+        Ok(Self {
+            client: build_http_client(&token, AuthStyle::Basic, remote.request_timeout)?,
+            api_base_url: remote
+                .api_base_url
+                .clone()
+                .context("Missing Azure DevOps API base URL")?
+                .trim_end_matches('/')
+                .to_string(),
+            org: org.to_string(),
+            project: project.to_string(),
+            repo: remote.repo.clone(),
+        })
+    }
+
+    fn project_url(&self, suffix: &str) -> String {
+        with_api_version(format!(
+            "{}/{}/{}/_apis{}",
+            self.api_base_url, self.org, self.project, suffix
+        ))
+    }
+
+    fn repo_url(&self, suffix: &str) -> String {
+        self.project_url(&format!("/git/repositories/{}{}", self.repo, suffix))
+    }
+
+    fn pull_url(&self, number: u64, suffix: &str) -> String {
+        self.repo_url(&format!("/pullrequests/{}{}", number, suffix))
+    }
+
+    /// Identities live on `vssps.dev.azure.com` for Azure DevOps Services and
+    /// on the main host for Azure DevOps Server.
+    fn identities_url(&self, name: &str) -> String {
+        let base = self
+            .api_base_url
+            .replace("://dev.azure.com", "://vssps.dev.azure.com");
+        with_api_version(format!(
+            "{}/{}/_apis/identities?searchFilter=General&filterValue={}&queryMembership=None",
+            base,
+            self.org,
+            encode_query_value(name)
+        ))
+    }
+
+    async fn get_pull(&self, number: u64) -> Result<AzurePull> {
+        get_json(&self.client, &self.pull_url(number, "")).await
+    }
+
+    async fn list_pulls(&self, status: &str, top: u8) -> Result<Vec<AzurePull>> {
+        let url = self.repo_url(&format!(
+            "/pullrequests?searchCriteria.status={}&$top={}",
+            status, top
+        ));
+        let list: AzureList<AzurePull> = get_json(&self.client, &url).await?;
+        Ok(list.value)
+    }
+
+    /// Every PR matching `criteria` (`searchCriteria.*` query pairs), paged
+    /// with `$skip`.
+    async fn list_all_pulls(&self, criteria: &str) -> Result<Vec<AzurePull>> {
+        const PAGE_SIZE: usize = 100;
+        let mut prs = Vec::new();
+        loop {
+            let url = self.repo_url(&format!(
+                "/pullrequests?{}&$top={}&$skip={}",
+                criteria,
+                PAGE_SIZE,
+                prs.len()
+            ));
+            let list: AzureList<AzurePull> = get_json(&self.client, &url).await?;
+            let count = list.value.len();
+            prs.extend(list.value);
+            if count < PAGE_SIZE {
+                return Ok(prs);
+            }
+        }
+    }
+
+    /// Identity id for a user or group name, as reviewers and
+    /// `searchCriteria.creatorId` expect.
+    async fn identity_id(&self, name: &str) -> Result<String> {
+        let matches: AzureList<AzureIdentityMatch> =
+            get_json(&self.client, &self.identities_url(name)).await?;
+        matches
+            .value
+            .into_iter()
+            .next()
+            .map(|identity| identity.id)
+            .with_context(|| format!("Azure DevOps identity '{}' not found", name))
+    }
+
+    /// PRs whose source is `branch`, filtered by Azure rather than client-side.
+    async fn list_pulls_for_branch(&self, status: &str, branch: &str) -> Result<Vec<AzurePull>> {
+        let url = self.repo_url(&format!(
+            "/pullrequests?searchCriteria.status={}&searchCriteria.sourceRefName={}",
+            status,
+            encode_query_value(&format!("refs/heads/{}", branch))
+        ));
+        let list: AzureList<AzurePull> = get_json(&self.client, &url).await?;
+        Ok(list.value)
+    }
+
+    async fn update_pull(&self, number: u64, request: &serde_json::Value) -> Result<()> {
+        let _: AzurePull = patch_json(&self.client, &self.pull_url(number, ""), request).await?;
+        Ok(())
+    }
+
+    pub async fn find_open_pr_by_head(&self, branch: &str) -> Result<Option<PrInfoWithHead>> {
+        let prs = self.list_pulls_for_branch("active", branch).await?;
+        Ok(prs
+            .into_iter()
+            .find(|pr| branch_name(&pr.source_ref_name) == branch)
+            .map(pr_to_info_with_head))
+    }
+
+    /// Find the PR for `branch` in any state, preferring an active one over
+    /// completed or abandoned PRs (Azure lists newest first).
+    pub async fn find_pr(&self, branch: &str) -> Result<Option<PrInfo>> {
+        let prs = self.list_pulls_for_branch("all", branch).await?;
+        let mut matching = prs
+            .iter()
+            .filter(|pr| branch_name(&pr.source_ref_name) == branch);
+        let active = matching.clone().find(|pr| pr.status == "active");
+        Ok(active.or_else(|| matching.next()).map(pr_to_info))
+    }
+
+    pub async fn list_open_prs_by_head(&self) -> Result<HashMap<String, PrInfoWithHead>> {
+        let prs = self.list_all_pulls("searchCriteria.status=active").await?;
+        Ok(prs
+            .into_iter()
+            .map(pr_to_info_with_head)
+            .map(|pr| (pr.head.clone(), pr))
+            .collect())
+    }
+
+    pub async fn create_pr(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+        is_draft: bool,
+    ) -> Result<PrInfo> {
+        let request = CreatePullRequest {
+            source_ref_name: format!("refs/heads/{}", head),
+            target_ref_name: format!("refs/heads/{}", base),
+            title,
+            description: body,
+            is_draft,
+        };
+        let pr: AzurePull =
+            post_json(&self.client, &self.repo_url("/pullrequests"), &request).await?;
+        Ok(pr_to_info(&pr))
+    }
+
+    pub async fn get_pr(&self, number: u64) -> Result<PrInfo> {
+        Ok(pr_to_info(&self.get_pull(number).await?))
+    }
+
+    pub async fn get_pr_with_head(&self, number: u64) -> Result<PrInfoWithHead> {
+        Ok(pr_to_info_with_head(self.get_pull(number).await?))
+    }
+
+    pub async fn update_pr_base(&self, number: u64, new_base: &str) -> Result<()> {
+        self.update_pull(
+            number,
+            &serde_json::json!({ "targetRefName": format!("refs/heads/{}", new_base) }),
+        )
+        .await
+    }
+
+    pub async fn update_pr_title(&self, number: u64, title: &str) -> Result<()> {
+        self.update_pull(number, &serde_json::json!({ "title": title }))
+            .await
+    }
+
+    pub async fn update_pr_body(&self, number: u64, body: &str) -> Result<()> {
+        self.update_pull(number, &serde_json::json!({ "description": body }))
+            .await
+    }
+
+    pub async fn set_pr_draft(&self, number: u64, is_draft: bool) -> Result<()> {
+        self.update_pull(number, &serde_json::json!({ "isDraft": is_draft }))
+            .await
+    }
+
+    pub async fn get_pr_body(&self, number: u64) -> Result<String> {
+        Ok(self.get_pull(number).await?.description.unwrap_or_default())
+    }
+
+    pub async fn close_pr(&self, number: u64) -> Result<()> {
+        self.update_pull(number, &serde_json::json!({ "status": "abandoned" }))
+            .await
+            .context("Failed to abandon PR")
+    }
+
+    pub async fn reopen_pr(&self, number: u64) -> Result<()> {
+        self.update_pull(number, &serde_json::json!({ "status": "active" }))
+            .await
+            .context("Failed to reactivate PR")
+    }
+
+    /// Start a new comment thread on the PR.
+    pub async fn create_issue_comment(&self, number: u64, body: &str) -> Result<()> {
+        let request = serde_json::json!({
+            "comments": [{ "parentCommentId": 0, "content": body, "commentType": 1 }],
+            "status": 1,
+        });
+        let _: AzureThread =
+            post_json(&self.client, &self.pull_url(number, "/threads"), &request).await?;
+        Ok(())
+    }
+
+    pub async fn update_stack_comment(&self, number: u64, stack_comment: &str) -> Result<()> {
+        if let Some((thread_id, comment_id)) = self.find_stack_comment(number).await? {
+            let _: AzureComment = patch_json(
+                &self.client,
+                &self.pull_url(
+                    number,
+                    &format!("/threads/{}/comments/{}", thread_id, comment_id),
+                ),
+                &serde_json::json!({ "content": stack_comment_body(stack_comment) }),
+            )
+            .await?;
+            Ok(())
+        } else {
+            self.create_stack_comment(number, stack_comment).await
+        }
+    }
+
+    pub async fn create_stack_comment(&self, number: u64, stack_comment: &str) -> Result<()> {
+        self.create_issue_comment(number, &stack_comment_body(stack_comment))
+            .await
+    }
+
+    pub async fn delete_stack_comment(&self, number: u64) -> Result<()> {
+        let Some((thread_id, comment_id)) = self.find_stack_comment(number).await? else {
+            return Ok(());
+        };
+        delete_empty(
+            &self.client,
+            &self.pull_url(
+                number,
+                &format!("/threads/{}/comments/{}", thread_id, comment_id),
+            ),
+        )
+        .await
+    }
+
+    async fn list_threads(&self, number: u64) -> Result<Vec<AzureThread>> {
+        let list: AzureList<AzureThread> =
+            get_json(&self.client, &self.pull_url(number, "/threads")).await?;
+        Ok(list
+            .value
+            .into_iter()
+            .filter(|thread| !thread.is_deleted)
+            .collect())
+    }
+
+    /// Thread and comment ids of the stax stack comment, if any.
+    async fn find_stack_comment(&self, number: u64) -> Result<Option<(u64, u64)>> {
+        Ok(self
+            .list_threads(number)
+            .await?
+            .into_iter()
+            .find_map(|thread| {
+                thread
+                    .comments
+                    .iter()
+                    .find(|comment| {
+                        !comment.is_deleted
+                            && comment
+                                .content
+                                .as_deref()
+                                .is_some_and(|content| content.contains(STACK_COMMENT_MARKER))
+                    })
+                    .map(|comment| (thread.id, comment.id))
+            }))
+    }
+
+    /// Human comments across all threads. Comment ids are only unique within
+    /// a thread, so each comment is reported under its thread id.
+    pub async fn list_all_comments(&self, number: u64) -> Result<Vec<PrComment>> {
+        let mut comments = Vec::new();
+        for thread in self.list_threads(number).await? {
+            for comment in thread.comments {
+                if comment.is_deleted || comment.comment_type.as_deref() == Some("system") {
+                    continue;
+                }
+                let (Some(content), Some(published)) = (comment.content, comment.published_date)
+                else {
+                    continue;
+                };
+                comments.push(make_issue_comment(
+                    thread.id,
+                    content,
+                    comment
+                        .author
+                        .as_ref()
+                        .map(AzureIdentity::handle)
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    published,
+                ));
+            }
+        }
+        comments.sort_by_key(|comment| comment.created_at());
+        Ok(comments)
+    }
+
+    /// Complete the PR. Azure needs the source commit the caller reviewed, so
+    /// it is looked up when the caller doesn't pin one.
+    pub async fn merge_pr(
+        &self,
+        number: u64,
+        method: MergeMethod,
+        commit_title: Option<&str>,
+        sha: Option<&str>,
+    ) -> Result<()> {
+        let head_sha = match sha {
+            Some(sha) => sha.to_string(),
+            None => self.get_pr_head_sha(number).await?,
+        };
+        let request = CompletePullRequest {
+            status: "completed",
+            last_merge_source_commit: CommitId {
+                commit_id: &head_sha,
+            },
+            completion_options: CompletionOptions {
+                merge_strategy: merge_strategy(method),
+                merge_commit_message: commit_title,
+                delete_source_branch: false,
+            },
+        };
+        let _: AzurePull = patch_json(&self.client, &self.pull_url(number, ""), &request).await?;
+        Ok(())
+    }
+
+    pub async fn get_pr_merge_status(&self, number: u64) -> Result<PrMergeStatus> {
+        let pr = self.get_pull(number).await?;
+        let head_sha = pr
+            .last_merge_source_commit
+            .as_ref()
+            .map(|commit| commit.commit_id.clone())
+            .unwrap_or_default();
+        let ci_status = self
+            .fetch_checks(&head_sha)
+            .await
+            .ok()
+            .and_then(|(status, _)| status);
+        let policies = match pr
+            .repository
+            .as_ref()
+            .and_then(|repo| repo.project.as_ref())
+        {
+            Some(project) => self
+                .list_policy_evaluations(&project.id, pr.pull_request_id)
+                .await
+                .map(|evaluations| summarize_policies(&evaluations))
+                .unwrap_or(PolicyState::Satisfied),
+            None => PolicyState::Satisfied,
+        };
+        let (review_decision, approvals, changes_requested) = summarize_votes(&pr.reviewers);
+
+        let mut mergeable_state =
+            normalize_merge_status(pr.merge_status.as_deref().unwrap_or("notSet"));
+        let mut mergeable = mergeable_bool(&mergeable_state);
+        match policies {
+            PolicyState::Rejected => {
+                mergeable = Some(false);
+                mergeable_state = "blocked".to_string();
+            }
+            PolicyState::Pending if mergeable == Some(true) => {
+                mergeable = None;
+                mergeable_state = "unknown".to_string();
+            }
+            _ => {}
+        }
+
+        Ok(PrMergeStatus {
+            number: pr.pull_request_id,
+            state: normalize_azure_state(&pr.status),
+            updated_at: pr
+                .closed_date
+                .or(pr.creation_date)
+                .map(|updated| updated.to_rfc3339()),
+            is_draft: pr.is_draft,
+            title: pr.title,
+            mergeable,
+            mergeable_state,
+            ci_status: ci_status_from_string(ci_status.as_deref()),
+            review_decision,
+            approvals,
+            changes_requested,
+            head_sha,
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
+        })
+    }
+
+    async fn list_policy_evaluations(
+        &self,
+        project_id: &str,
+        number: u64,
+    ) -> Result<Vec<AzurePolicyEvaluation>> {
+        let url = format!(
+            "{}/{}/{}/_apis/policy/evaluations?artifactId={}&api-version=7.1-preview.1",
+            self.api_base_url,
+            self.org,
+            self.project,
+            encode_query_value(&format!(
+                "vstfs:///CodeReview/CodeReviewId/{}/{}",
+                project_id, number
+            ))
+        );
+        let list: AzureList<AzurePolicyEvaluation> = get_json(&self.client, &url).await?;
+        Ok(list.value)
+    }
+
+    pub async fn get_pr_review_decision(&self, number: u64) -> Result<Option<String>> {
+        Ok(summarize_votes(&self.get_pull(number).await?.reviewers).0)
+    }
+
+    pub async fn is_pr_merged(&self, number: u64) -> Result<bool> {
+        Ok(self.get_pull(number).await?.status == "completed")
+    }
+
+    pub async fn get_pr_head_sha(&self, number: u64) -> Result<String> {
+        Ok(self
+            .get_pull(number)
+            .await?
+            .last_merge_source_commit
+            .map(|commit| commit.commit_id)
+            .unwrap_or_default())
+    }
+
+    pub async fn fetch_checks(&self, sha: &str) -> Result<(Option<String>, Vec<CheckRunInfo>)> {
+        let list: AzureList<AzureCommitStatus> = get_json(
+            &self.client,
+            &self.repo_url(&format!("/commits/{}/statuses?latestOnly=true", sha)),
+        )
+        .await?;
+        let statuses = list.value;
+        let checks = statuses
+            .iter()
+            .map(|status| CheckRunInfo {
+                name: status
+                    .context
+                    .as_ref()
+                    .map(|context| match (&context.genre, &context.name) {
+                        (Some(genre), Some(name)) => format!("{}/{}", genre, name),
+                        (None, Some(name)) => name.clone(),
+                        _ => "status".to_string(),
+                    })
+                    .unwrap_or_else(|| "status".to_string()),
+                status: normalize_azure_status(status.state.as_deref()),
+                conclusion: status.state.as_deref().map(normalize_azure_conclusion),
+                url: status.target_url.clone(),
+                started_at: status.creation_date.clone(),
+                completed_at: status.updated_date.clone(),
+                elapsed_secs: None,
+                average_secs: None,
+                completion_percent: None,
+            })
+            .collect::<Vec<_>>();
+
+        let overall = aggregate_ci_overall(
+            statuses.iter().filter_map(|status| status.state.as_deref()),
+            |s| matches!(s, "failed" | "error"),
+            |s| matches!(s, "pending"),
+        );
+
+        Ok((overall, checks))
+    }
+
+    /// Add users or groups as optional reviewers. `org/team` entries are
+    /// looked up by team name.
+    pub async fn request_reviewers(&self, number: u64, reviewers: &[String]) -> Result<()> {
+        for reviewer in reviewers {
+            let name = reviewer.trim().trim_start_matches('@');
+            if name.is_empty() {
+                continue;
+            }
+            let name = name.rsplit('/').next().unwrap_or(name);
+            let id = self.identity_id(name).await?;
+            let _: serde_json::Value = put_json(
+                &self.client,
+                &self.pull_url(number, &format!("/reviewers/{}", id)),
+                &serde_json::json!({ "vote": 0 }),
+            )
+            .await
+            .context("Failed to request reviewers")?;
+        }
+        Ok(())
+    }
+
+    pub async fn get_requested_reviewers(&self, number: u64) -> Result<Vec<String>> {
+        let pr = self.get_pull(number).await?;
+        Ok(pr
+            .reviewers
+            .iter()
+            .map(|reviewer| reviewer.identity.handle())
+            .collect())
+    }
+
+    pub async fn add_labels(&self, number: u64, labels: &[String]) -> Result<()> {
+        for label in labels {
+            let _: serde_json::Value = post_json(
+                &self.client,
+                &self.pull_url(number, "/labels"),
+                &serde_json::json!({ "name": label }),
+            )
+            .await
+            .context("Failed to add labels")?;
+        }
+        Ok(())
+    }
+
+    pub async fn list_open_pull_requests(&self, limit: u8) -> Result<Vec<RepoPrListItem>> {
+        let prs = self.list_pulls("active", limit.clamp(1, 100)).await?;
+        Ok(prs
+            .into_iter()
+            .map(|pr| RepoPrListItem {
+                number: pr.pull_request_id,
+                url: self.web_pr_url(pr.pull_request_id),
+                author: pr
+                    .created_by
+                    .as_ref()
+                    .map(AzureIdentity::handle)
+                    .unwrap_or_else(|| "unknown".to_string()),
+                head_branch: branch_name(&pr.source_ref_name).to_string(),
+                base_branch: branch_name(&pr.target_ref_name).to_string(),
+                state: normalize_azure_state(&pr.status),
+                is_draft: pr.is_draft,
+                created_at: pr.creation_date.unwrap_or_default(),
+                title: pr.title,
+            })
+            .collect())
+    }
+
+    fn web_pr_url(&self, number: u64) -> String {
+        format!(
+            "{}/{}/{}/_git/{}/pullrequest/{}",
+            self.api_base_url, self.org, self.project, self.repo, number
+        )
+    }
+
+    pub async fn get_current_user(&self) -> Result<String> {
+        let url = with_api_version(format!(
+            "{}/{}/_apis/connectionData",
+            self.api_base_url, self.org
+        ));
+        let data: AzureConnectionData = get_json(&self.client, &url).await?;
+        let user = data.authenticated_user;
+        user.properties
+            .pointer("/Account/$value")
+            .and_then(|account| account.as_str())
+            .map(str::to_string)
+            .or(user.provider_display_name)
+            .context("Azure DevOps did not report the authenticated user")
+    }
+
+    pub async fn get_user_open_prs(&self, username: &str) -> Result<Vec<OpenPrInfo>> {
+        let criteria = format!(
+            "searchCriteria.status=active&searchCriteria.creatorId={}",
+            self.identity_id(username).await?
+        );
+        let prs = self.list_all_pulls(&criteria).await?;
+        Ok(prs
+            .into_iter()
+            .map(|pr| OpenPrInfo {
+                number: pr.pull_request_id,
+                head_branch: branch_name(&pr.source_ref_name).to_string(),
+                base_branch: branch_name(&pr.target_ref_name).to_string(),
+                state: normalize_azure_state(&pr.status),
+                is_draft: pr.is_draft,
+            })
+            .collect())
+    }
+
+    /// `username`'s PRs in `status` created or closed (`time_range`) since
+    /// `since`. Servers that ignore `minTime` return older PRs too, so callers
+    /// still check the date.
+    async fn list_recent_pulls_by(
+        &self,
+        username: &str,
+        status: &str,
+        time_range: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<AzurePull>> {
+        let criteria = format!(
+            "searchCriteria.status={}&searchCriteria.creatorId={}&searchCriteria.queryTimeRangeType={}&searchCriteria.minTime={}",
+            status,
+            self.identity_id(username).await?,
+            time_range,
+            encode_query_value(&since.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        );
+        self.list_all_pulls(&criteria).await
+    }
+
+    pub async fn get_recent_merged_prs(
+        &self,
+        hours: i64,
+        username: &str,
+    ) -> Result<Vec<PrActivity>> {
+        let since = Utc::now() - chrono::Duration::hours(hours);
+        let prs = self
+            .list_recent_pulls_by(username, "completed", "closed", since)
+            .await?;
+        Ok(prs
+            .into_iter()
+            .filter(|pr| pr.closed_date.is_some_and(|t| t >= since))
+            .map(|pr| PrActivity {
+                number: pr.pull_request_id,
+                url: self.web_pr_url(pr.pull_request_id),
+                timestamp: pr.closed_date.unwrap_or_default(),
+                title: pr.title,
+            })
+            .collect())
+    }
+
+    pub async fn get_recent_opened_prs(
+        &self,
+        hours: i64,
+        username: &str,
+    ) -> Result<Vec<PrActivity>> {
+        let since = Utc::now() - chrono::Duration::hours(hours);
+        let prs = self
+            .list_recent_pulls_by(username, "active", "created", since)
+            .await?;
+        Ok(prs
+            .into_iter()
+            .filter(|pr| pr.creation_date.is_some_and(|t| t >= since))
+            .map(|pr| PrActivity {
+                number: pr.pull_request_id,
+                url: self.web_pr_url(pr.pull_request_id),
+                timestamp: pr.creation_date.unwrap_or_default(),
+                title: pr.title,
+            })
+            .collect())
+    }
+
+    pub async fn get_reviews_received(
+        &self,
+        _hours: i64,
+        _username: &str,
+    ) -> Result<Vec<ReviewActivity>> {
+        // Not implemented: Azure DevOps reports reviewer votes without a timestamp.
+        Ok(vec![])
+    }
+
+    pub async fn get_reviews_given(
+        &self,
+        _hours: i64,
+        _username: &str,
+    ) -> Result<Vec<ReviewActivity>> {
+        // Not implemented: no query for "PRs this identity voted on".
+        Ok(vec![])
+    }
+
+    pub async fn list_open_issues(&self, _limit: u8) -> Result<Vec<RepoIssueListItem>> {
+        bail!("Listing issues is not supported for Azure DevOps (it uses work items)")
+    }
+
+    pub async fn get_issue(&self, _number: u64) -> Result<RepoIssueListItem> {
+        bail!("Fetching issues is not supported for Azure DevOps (it uses work items)")
+    }
+}
+
+fn with_api_version(url: String) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{}{}{}", url, separator, API_VERSION)
+}
+
+fn branch_name(ref_name: &str) -> &str {
+    ref_name.strip_prefix("refs/heads/").unwrap_or(ref_name)
+}
+
+fn merge_strategy(method: MergeMethod) -> &'static str {
+    match method {
+        MergeMethod::Squash => "squash",
+        MergeMethod::Merge => "noFastForward",
+        MergeMethod::Rebase => "rebase",
+    }
+}
+
+/// Reduce reviewer votes to `(review_decision, approvals, changes_requested)`.
+fn summarize_votes(reviewers: &[AzureReviewer]) -> (Option<String>, usize, bool) {
+    let approvals = reviewers.iter().filter(|r| r.vote >= 5).count();
+    let changes_requested = reviewers.iter().any(|r| r.vote < 0);
+    let decision = if changes_requested {
+        Some("CHANGES_REQUESTED".to_string())
+    } else if approvals > 0 {
+        Some("APPROVED".to_string())
+    } else {
+        None
+    };
+    (decision, approvals, changes_requested)
+}
+
+fn summarize_policies(evaluations: &[AzurePolicyEvaluation]) -> PolicyState {
+    let mut state = PolicyState::Satisfied;
+    for evaluation in evaluations {
+        let blocking = evaluation
+            .configuration
+            .as_ref()
+            .is_some_and(|config| config.is_blocking && config.is_enabled);
+        if !blocking {
+            continue;
+        }
+        match evaluation.status.as_deref() {
+            Some("rejected" | "broken") => return PolicyState::Rejected,
+            Some("running" | "queued") => state = PolicyState::Pending,
+            _ => {}
+        }
+    }
+    state
+}
+
+fn normalize_merge_status(status: &str) -> String {
+    match status {
+        "succeeded" => "clean",
+        "conflicts" => "dirty",
+        "rejectedByPolicy" => "blocked",
+        "failure" => "failure",
+        _ => "unknown",
+    }
+    .to_string()
+}
+
+fn normalize_azure_state(status: &str) -> String {
+    match status {
+        "active" => "OPEN",
+        "completed" => "MERGED",
+        _ => "CLOSED",
+    }
+    .to_string()
+}
+
+fn normalize_azure_status(state: Option<&str>) -> String {
+    match state.unwrap_or("") {
+        "pending" => "in_progress".to_string(),
+        _ => "completed".to_string(),
+    }
+}
+
+fn normalize_azure_conclusion(state: &str) -> String {
+    match state {
+        "succeeded" => "success".to_string(),
+        "failed" | "error" => "failure".to_string(),
+        "notApplicable" | "notSet" => "neutral".to_string(),
+        other => other.to_lowercase(),
+    }
+}
+
+fn pr_to_info(pr: &AzurePull) -> PrInfo {
+    PrInfo {
+        number: pr.pull_request_id,
+        state: normalize_azure_state(&pr.status),
+        is_draft: pr.is_draft,
+        base: branch_name(&pr.target_ref_name).to_string(),
+    }
+}
+
+fn pr_to_info_with_head(pr: AzurePull) -> PrInfoWithHead {
+    PrInfoWithHead {
+        info: pr_to_info(&pr),
+        head: branch_name(&pr.source_ref_name).to_string(),
+        head_label: None,
+        title: pr.title.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn ensure_crypto_provider() {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+    }
+
+    fn remote_info(server: &MockServer) -> RemoteInfo {
+        RemoteInfo {
+            name: "origin".to_string(),
+            forge: ForgeType::AzureDevOps,
+            host: "dev.azure.com".to_string(),
+            namespace: "contoso/web".to_string(),
+            repo: "portal".to_string(),
+            base_url: "https://dev.azure.com".to_string(),
+            api_base_url: Some(server.uri()),
+            request_timeout: None,
+            head_owner: None,
+        }
+    }
+
+    fn client(server: &MockServer) -> AzureDevOpsClient {
+        ensure_crypto_provider();
+        unsafe { std::env::set_var("STAX_AZURE_DEVOPS_TOKEN", "pat") };
+        AzureDevOpsClient::new(&remote_info(server)).unwrap()
+    }
+
+    const PULLS: &str = "/contoso/web/_apis/git/repositories/portal/pullrequests";
+
+    fn pull_json(id: u64, reviewers: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "pullRequestId": id,
+            "status": "active",
+            "title": "Feature",
+            "description": "body",
+            "isDraft": false,
+            "sourceRefName": "refs/heads/feature-a",
+            "targetRefName": "refs/heads/main",
+            "createdBy": { "displayName": "Alice", "uniqueName": "alice@contoso.com" },
+            "creationDate": "2099-01-01T00:00:00Z",
+            "mergeStatus": "succeeded",
+            "lastMergeSourceCommit": { "commitId": "abc123" },
+            "reviewers": reviewers,
+            "repository": { "project": { "id": "proj-guid" } }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_open_prs_by_head_strips_ref_prefix_and_uses_pat_auth() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            // base64(":pat")
+            .and(header("Authorization", "Basic OnBhdA=="))
+            .and(path(PULLS))
+            .and(query_param("searchCriteria.status", "active"))
+            .and(query_param("api-version", "7.1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [pull_json(12, serde_json::json!([]))],
+                "count": 1
+            })))
+            .mount(&server)
+            .await;
+
+        let prs = client(&server).list_open_prs_by_head().await.unwrap();
+        let pr = prs.get("feature-a").unwrap();
+        assert_eq!(pr.info.number, 12);
+        assert_eq!(pr.info.base, "main");
+        assert_eq!(pr.info.state, "OPEN");
+    }
+
+    #[tokio::test]
+    async fn test_list_open_prs_by_head_pages_with_skip() {
+        let server = MockServer::start().await;
+        let page = |ids: std::ops::RangeInclusive<u64>| {
+            let value = ids
+                .map(|id| {
+                    let mut pull = pull_json(id, serde_json::json!([]));
+                    pull["sourceRefName"] = format!("refs/heads/feature-{}", id).into();
+                    pull
+                })
+                .collect::<Vec<_>>();
+            serde_json::json!({ "value": value })
+        };
+        Mock::given(method("GET"))
+            .and(path(PULLS))
+            .and(query_param("$skip", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(1..=100)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(PULLS))
+            .and(query_param("$skip", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(101..=101)))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let prs = client(&server).list_open_prs_by_head().await.unwrap();
+        assert_eq!(prs.len(), 101);
+        assert_eq!(prs.get("feature-101").unwrap().info.number, 101);
+    }
+
+    #[tokio::test]
+    async fn test_find_pr_filters_by_source_ref_and_includes_completed() {
+        let server = MockServer::start().await;
+        let mut completed = pull_json(9, serde_json::json!([]));
+        completed["status"] = "completed".into();
+        Mock::given(method("GET"))
+            .and(path(PULLS))
+            .and(query_param("searchCriteria.status", "all"))
+            .and(query_param(
+                "searchCriteria.sourceRefName",
+                "refs/heads/feature-a",
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "value": [completed] })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(PULLS))
+            .and(query_param("searchCriteria.status", "active"))
+            .and(query_param(
+                "searchCriteria.sourceRefName",
+                "refs/heads/feature-a",
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "value": [] })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        assert!(
+            client
+                .find_open_pr_by_head("feature-a")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let pr = client.find_pr("feature-a").await.unwrap().unwrap();
+        assert_eq!(pr.number, 9);
+        assert_eq!(pr.state, "MERGED");
+    }
+
+    #[tokio::test]
+    async fn test_create_pr_and_retarget_use_full_refs() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path(PULLS))
+            .and(body_json(serde_json::json!({
+                "sourceRefName": "refs/heads/feature-a",
+                "targetRefName": "refs/heads/main",
+                "title": "Feature",
+                "description": "body",
+                "isDraft": true
+            })))
+            .respond_with(
+                ResponseTemplate::new(201).set_body_json(pull_json(12, serde_json::json!([]))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path(format!("{}/12", PULLS)))
+            .and(body_json(
+                serde_json::json!({ "targetRefName": "refs/heads/develop" }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(pull_json(12, serde_json::json!([]))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let pr = client
+            .create_pr("feature-a", "main", "Feature", "body", true)
+            .await
+            .unwrap();
+        assert_eq!(pr.number, 12);
+        client.update_pr_base(12, "develop").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_pr_completes_with_source_commit_and_strategy() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{}/12", PULLS)))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(pull_json(12, serde_json::json!([]))),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path(format!("{}/12", PULLS)))
+            .and(body_json(serde_json::json!({
+                "status": "completed",
+                "lastMergeSourceCommit": { "commitId": "abc123" },
+                "completionOptions": {
+                    "mergeStrategy": "squash",
+                    "mergeCommitMessage": "Feature (!12)",
+                    "deleteSourceBranch": false
+                }
+            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(pull_json(12, serde_json::json!([]))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server)
+            .merge_pr(12, MergeMethod::Squash, Some("Feature (!12)"), None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_status_blocks_on_rejected_policy_and_reads_votes() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{}/12", PULLS)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_json(
+                12,
+                serde_json::json!([
+                    { "uniqueName": "bob@contoso.com", "vote": 10 },
+                    { "uniqueName": "carol@contoso.com", "vote": 0 }
+                ]),
+            )))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/contoso/web/_apis/git/repositories/portal/commits/abc123/statuses",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [{
+                    "state": "succeeded",
+                    "context": { "genre": "ci", "name": "build" }
+                }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/contoso/web/_apis/policy/evaluations"))
+            .and(query_param(
+                "artifactId",
+                "vstfs:///CodeReview/CodeReviewId/proj-guid/12",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [
+                    { "status": "approved", "configuration": { "isBlocking": true } },
+                    { "status": "rejected", "configuration": { "isBlocking": true } },
+                    { "status": "rejected", "configuration": { "isBlocking": false } }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let status = client(&server).get_pr_merge_status(12).await.unwrap();
+        assert!(status.ci_status.is_success());
+        assert_eq!(status.approvals, 1);
+        assert_eq!(status.review_decision.as_deref(), Some("APPROVED"));
+        assert_eq!(status.mergeable, Some(false));
+        assert_eq!(status.mergeable_state, "blocked");
+        assert_eq!(status.head_sha, "abc123");
+    }
+
+    #[tokio::test]
+    async fn test_request_reviewers_resolves_identities() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contoso/_apis/identities"))
+            .and(query_param("filterValue", "bob@contoso.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [{ "id": "bob-guid" }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/contoso/_apis/identities"))
+            .and(query_param("filterValue", "ghost"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "value": [] })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path(format!("{}/12/reviewers/bob-guid", PULLS)))
+            .and(body_json(serde_json::json!({ "vote": 0 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "bob-guid",
+                "vote": 0
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        client
+            .request_reviewers(12, &["bob@contoso.com".to_string()])
+            .await
+            .unwrap();
+        let err = client
+            .request_reviewers(12, &["ghost".to_string()])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("identity 'ghost' not found"));
+    }
+
+    #[tokio::test]
+    async fn test_user_pr_lookups_filter_by_creator_and_page_with_skip() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/contoso/_apis/identities"))
+            .and(query_param("filterValue", "alice@contoso.com"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [{ "id": "alice-guid" }]
+            })))
+            .mount(&server)
+            .await;
+        let page = |ids: std::ops::RangeInclusive<u64>| {
+            let value = ids
+                .map(|id| pull_json(id, serde_json::json!([])))
+                .collect::<Vec<_>>();
+            serde_json::json!({ "value": value })
+        };
+        Mock::given(method("GET"))
+            .and(path(PULLS))
+            .and(query_param("searchCriteria.status", "active"))
+            .and(query_param("searchCriteria.creatorId", "alice-guid"))
+            .and(query_param("$skip", "0"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(1..=100)))
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(PULLS))
+            .and(query_param("searchCriteria.creatorId", "alice-guid"))
+            .and(query_param("$skip", "100"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(101..=101)))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let open = client.get_user_open_prs("alice@contoso.com").await.unwrap();
+        assert_eq!(open.len(), 101);
+
+        let opened = client
+            .get_recent_opened_prs(24, "alice@contoso.com")
+            .await
+            .unwrap();
+        assert_eq!(opened.len(), 101);
+        let requests = server.received_requests().await.unwrap();
+        assert!(requests.iter().any(|request| {
+            request.url.query_pairs().any(|(key, value)| {
+                key == "searchCriteria.queryTimeRangeType" && value == "created"
+            })
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_list_all_comments_skips_system_threads() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!("{}/12/threads", PULLS)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [
+                    {
+                        "id": 3,
+                        "comments": [{
+                            "id": 1,
+                            "content": "Looks good",
+                            "commentType": "text",
+                            "author": { "uniqueName": "bob@contoso.com" },
+                            "publishedDate": "2099-01-01T00:00:00Z"
+                        }]
+                    },
+                    {
+                        "id": 4,
+                        "comments": [{
+                            "id": 1,
+                            "content": "Bob voted 10",
+                            "commentType": "system",
+                            "publishedDate": "2099-01-01T00:00:00Z"
+                        }]
+                    }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let comments = client(&server).list_all_comments(12).await.unwrap();
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].user(), "bob@contoso.com");
+        assert_eq!(comments[0].body(), "Looks good");
+    }
+}
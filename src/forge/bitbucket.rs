@@ -0,0 +1,1215 @@
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{
+    AuthStyle, PrActivity, RepoIssueListItem, RepoPrListItem, ReviewActivity, STACK_COMMENT_MARKER,
+    aggregate_ci_overall, build_http_client, ci_status_from_string, delete_empty,
+    encode_query_value, get_json, make_issue_comment, post_json, put_json, stack_comment_body,
+};
+use crate::ci::CheckRunInfo;
+use crate::github::client::OpenPrInfo;
+use crate::github::pr::{
+    MergeMethod, PrComment, PrInfo, PrInfoWithHead, PrMergeStatus, split_reviewers,
+};
+use crate::remote::{ForgeType, RemoteInfo};
+
+/// Client for the Bitbucket Cloud 2.0 REST API.
+#[derive(Clone)]
+pub struct BitbucketClient {
+    client: Client,
+    api_base_url: String,
+    workspace: String,
+    repo: String,
+}
+
+/// Bitbucket wraps every list response in a page object; `next` holds the
+/// absolute URL of the following page when there is one.
+#[derive(Debug, Deserialize)]
+struct BitbucketPage<T> {
+    values: Vec<T>,
+    #[serde(default)]
+    next: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketPull {
+    id: u64,
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    state: String,
+    #[serde(default)]
+    draft: bool,
+    source: BitbucketEndpoint,
+    destination: BitbucketEndpoint,
+    author: Option<BitbucketUser>,
+    links: Option<BitbucketLinks>,
+    created_on: Option<DateTime<Utc>>,
+    updated_on: Option<DateTime<Utc>>,
+    #[serde(default)]
+    reviewers: Vec<BitbucketUser>,
+    #[serde(default)]
+    participants: Vec<BitbucketParticipant>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketEndpoint {
+    branch: BitbucketBranch,
+    commit: Option<BitbucketCommit>,
+    repository: Option<BitbucketRepository>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketBranch {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketCommit {
+    hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketRepository {
+    full_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketLinks {
+    html: Option<BitbucketHref>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketHref {
+    href: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct BitbucketUser {
+    #[serde(default)]
+    display_name: Option<String>,
+    #[serde(default)]
+    nickname: Option<String>,
+    #[serde(default)]
+    account_id: Option<String>,
+    #[serde(default)]
+    uuid: Option<String>,
+}
+
+impl BitbucketUser {
+    /// Bitbucket Cloud has no usernames; the nickname is the closest stable handle.
+    fn handle(&self) -> String {
+        self.nickname
+            .clone()
+            .or_else(|| self.display_name.clone())
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        [
+            &self.nickname,
+            &self.account_id,
+            &self.uuid,
+            &self.display_name,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|value| value.eq_ignore_ascii_case(name))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketParticipant {
+    user: BitbucketUser,
+    #[serde(default)]
+    approved: bool,
+    /// `approved`, `changes_requested`, or null.
+    state: Option<String>,
+    participated_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketMember {
+    user: BitbucketUser,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketComment {
+    id: u64,
+    content: BitbucketContent,
+    user: BitbucketUser,
+    created_on: DateTime<Utc>,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    inline: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BitbucketContent {
+    raw: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct BitbucketCommitStatus {
+    key: Option<String>,
+    name: Option<String>,
+    state: Option<String>,
+    url: Option<String>,
+    created_on: Option<String>,
+    updated_on: Option<String>,
+}
+
+#[derive(Serialize)]
+struct BranchSpec<'a> {
+    branch: BranchName<'a>,
+}
+
+#[derive(Serialize)]
+struct BranchName<'a> {
+    name: &'a str,
+}
+
+#[derive(Serialize)]
+struct CreatePullRequest<'a> {
+    title: &'a str,
+    description: &'a str,
+    source: BranchSpec<'a>,
+    destination: BranchSpec<'a>,
+    draft: bool,
+    close_source_branch: bool,
+}
+
+#[derive(Serialize)]
+struct MergePullRequest<'a> {
+    merge_strategy: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct CreateCommentRequest<'a> {
+    content: CommentContent<'a>,
+}
+
+#[derive(Serialize)]
+struct CommentContent<'a> {
+    raw: &'a str,
+}
+
+impl BitbucketClient {
+    pub fn new(remote: &RemoteInfo) -> Result<Self> {
+        if remote.forge != ForgeType::Bitbucket {
+            bail!("Internal error: expected Bitbucket remote");
+        }
+
+        let token = super::forge_token(ForgeType::Bitbucket, &remote.host).context(
+            "Bitbucket auth not configured. Use `stax auth` or set `STAX_BITBUCKET_TOKEN`, `BITBUCKET_TOKEN`, or `STAX_FORGE_TOKEN`.",
+        )?;
+        // Access tokens are sent as bearer tokens; `user:app-password` pairs use basic auth.
+        let auth_style = if token.contains(':') {
+            AuthStyle::Basic
+        } else {
+            AuthStyle::Bearer
+        };
+
+        Ok(Self {
+            client: build_http_client(&token, auth_style, remote.request_timeout)?,
+            api_base_url: remote
+                .api_base_url
+                .clone()
+                .context("Missing Bitbucket API base URL")?,
+            workspace: remote.owner().to_string(),
+            repo: remote.repo.clone(),
+        })
+    }
+
+    fn repo_url(&self, suffix: &str) -> String {
+        format!(
+            "{}/repositories/{}/{}{}",
+            self.api_base_url, self.workspace, self.repo, suffix
+        )
+    }
+
+    fn pull_url(&self, number: u64, suffix: &str) -> String {
+        self.repo_url(&format!("/pullrequests/{}{}", number, suffix))
+    }
+
+    async fn get_pull(&self, number: u64) -> Result<BitbucketPull> {
+        get_json(&self.client, &self.pull_url(number, "")).await
+    }
+
+    /// Every PR matching the `q` filter, newest first by `sort`.
+    async fn list_pulls(&self, filter: &str, sort: &str) -> Result<Vec<BitbucketPull>> {
+        let url = format!(
+            "{}?pagelen=50&sort={}&q={}",
+            self.repo_url("/pullrequests"),
+            sort,
+            encode_query_value(filter)
+        );
+        self.get_all_pages(&url).await
+    }
+
+    /// `q` clause selecting PRs opened by `username`. Bitbucket filters on
+    /// account uuids, so the name is resolved like reviewers are: the current
+    /// user first, then workspace members.
+    async fn author_filter(&self, username: &str) -> Result<String> {
+        let me: BitbucketUser =
+            get_json(&self.client, &format!("{}/user", self.api_base_url)).await?;
+        let user = if me.matches(username) {
+            me
+        } else {
+            let members: Vec<BitbucketMember> = self
+                .get_all_pages(&format!(
+                    "{}/workspaces/{}/members?pagelen=100",
+                    self.api_base_url, self.workspace
+                ))
+                .await?;
+            members
+                .into_iter()
+                .map(|member| member.user)
+                .find(|user| user.matches(username))
+                .with_context(|| format!("Bitbucket user '{}' not found", username))?
+        };
+        let uuid = user
+            .uuid
+            .with_context(|| format!("Bitbucket user '{}' has no uuid", username))?;
+        Ok(format!("author.uuid=\"{}\"", uuid))
+    }
+
+    /// Fetch every page of a list endpoint by following `next` links.
+    async fn get_all_pages<T: DeserializeOwned>(&self, url: &str) -> Result<Vec<T>> {
+        let mut values = Vec::new();
+        let mut next = Some(url.to_string());
+        while let Some(url) = next {
+            let page: BitbucketPage<T> = get_json(&self.client, &url).await?;
+            values.extend(page.values);
+            next = page.next;
+        }
+        Ok(values)
+    }
+
+    async fn update_pull(&self, number: u64, request: &serde_json::Value) -> Result<()> {
+        let _: BitbucketPull = put_json(&self.client, &self.pull_url(number, ""), request).await?;
+        Ok(())
+    }
+
+    pub async fn find_open_pr_by_head(&self, branch: &str) -> Result<Option<PrInfoWithHead>> {
+        let filter = format!(
+            "source.branch.name=\"{}\"",
+            branch.replace('\\', "\\\\").replace('"', "\\\"")
+        );
+        let url = format!(
+            "{}?state=OPEN&pagelen=50&q={}",
+            self.repo_url("/pullrequests"),
+            encode_query_value(&filter)
+        );
+        let prs: Vec<BitbucketPull> = self.get_all_pages(&url).await?;
+        Ok(prs
+            .into_iter()
+            .find(|pr| pr.source.branch.name == branch)
+            .map(pr_to_info_with_head))
+    }
+
+    pub async fn find_pr(&self, branch: &str) -> Result<Option<PrInfo>> {
+        Ok(self.find_open_pr_by_head(branch).await?.map(|pr| pr.info))
+    }
+
+    pub async fn list_open_prs_by_head(&self) -> Result<HashMap<String, PrInfoWithHead>> {
+        let url = format!("{}?state=OPEN&pagelen=50", self.repo_url("/pullrequests"));
+        let prs: Vec<BitbucketPull> = self.get_all_pages(&url).await?;
+        Ok(prs
+            .into_iter()
+            .map(pr_to_info_with_head)
+            .map(|pr| (pr.head.clone(), pr))
+            .collect())
+    }
+
+    pub async fn create_pr(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+        is_draft: bool,
+    ) -> Result<PrInfo> {
+        let request = CreatePullRequest {
+            title,
+            description: body,
+            source: BranchSpec {
+                branch: BranchName { name: head },
+            },
+            destination: BranchSpec {
+                branch: BranchName { name: base },
+            },
+            draft: is_draft,
+            close_source_branch: false,
+        };
+        let pr: BitbucketPull =
+            post_json(&self.client, &self.repo_url("/pullrequests"), &request).await?;
+        Ok(pr_to_info(&pr))
+    }
+
+    pub async fn get_pr(&self, number: u64) -> Result<PrInfo> {
+        Ok(pr_to_info(&self.get_pull(number).await?))
+    }
+
+    pub async fn get_pr_with_head(&self, number: u64) -> Result<PrInfoWithHead> {
+        Ok(pr_to_info_with_head(self.get_pull(number).await?))
+    }
+
+    pub async fn update_pr_base(&self, number: u64, new_base: &str) -> Result<()> {
+        self.update_pull(
+            number,
+            &serde_json::json!({ "destination": { "branch": { "name": new_base } } }),
+        )
+        .await
+    }
+
+    pub async fn update_pr_title(&self, number: u64, title: &str) -> Result<()> {
+        self.update_pull(number, &serde_json::json!({ "title": title }))
+            .await
+    }
+
+    pub async fn update_pr_body(&self, number: u64, body: &str) -> Result<()> {
+        self.update_pull(number, &serde_json::json!({ "description": body }))
+            .await
+    }
+
+    pub async fn set_pr_draft(&self, number: u64, is_draft: bool) -> Result<()> {
+        self.update_pull(number, &serde_json::json!({ "draft": is_draft }))
+            .await
+    }
+
+    pub async fn get_pr_body(&self, number: u64) -> Result<String> {
+        Ok(self.get_pull(number).await?.description.unwrap_or_default())
+    }
+
+    pub async fn update_stack_comment(&self, number: u64, stack_comment: &str) -> Result<()> {
+        if let Some(comment_id) = self.find_stack_comment_id(number).await? {
+            let request = CreateCommentRequest {
+                content: CommentContent {
+                    raw: &stack_comment_body(stack_comment),
+                },
+            };
+            let _: BitbucketComment = put_json(
+                &self.client,
+                &self.pull_url(number, &format!("/comments/{}", comment_id)),
+                &request,
+            )
+            .await?;
+            Ok(())
+        } else {
+            self.create_stack_comment(number, stack_comment).await
+        }
+    }
+
+    pub async fn create_stack_comment(&self, number: u64, stack_comment: &str) -> Result<()> {
+        self.create_issue_comment(number, &stack_comment_body(stack_comment))
+            .await
+    }
+
+    pub async fn create_issue_comment(&self, number: u64, body: &str) -> Result<()> {
+        let request = CreateCommentRequest {
+            content: CommentContent { raw: body },
+        };
+        let _: BitbucketComment =
+            post_json(&self.client, &self.pull_url(number, "/comments"), &request).await?;
+        Ok(())
+    }
+
+    pub async fn delete_stack_comment(&self, number: u64) -> Result<()> {
+        let Some(comment_id) = self.find_stack_comment_id(number).await? else {
+            return Ok(());
+        };
+        delete_empty(
+            &self.client,
+            &self.pull_url(number, &format!("/comments/{}", comment_id)),
+        )
+        .await
+    }
+
+    async fn list_comments(&self, number: u64) -> Result<Vec<BitbucketComment>> {
+        let comments: Vec<BitbucketComment> = self
+            .get_all_pages(&self.pull_url(number, "/comments?pagelen=100"))
+            .await?;
+        Ok(comments
+            .into_iter()
+            .filter(|comment| !comment.deleted)
+            .collect())
+    }
+
+    async fn find_stack_comment_id(&self, number: u64) -> Result<Option<u64>> {
+        Ok(self
+            .list_comments(number)
+            .await?
+            .into_iter()
+            .find(|comment| comment.content.raw.contains(STACK_COMMENT_MARKER))
+            .map(|comment| comment.id))
+    }
+
+    pub async fn list_all_comments(&self, number: u64) -> Result<Vec<PrComment>> {
+        let mut comments = self
+            .list_comments(number)
+            .await?
+            .into_iter()
+            .filter(|comment| comment.inline.is_none())
+            .map(|comment| {
+                make_issue_comment(
+                    comment.id,
+                    comment.content.raw,
+                    comment.user.handle(),
+                    comment.created_on,
+                )
+            })
+            .collect::<Vec<_>>();
+        comments.sort_by_key(|comment| comment.created_at());
+        Ok(comments)
+    }
+
+    /// Decline the PR. Bitbucket cannot reopen a declined PR.
+    pub async fn close_pr(&self, number: u64) -> Result<()> {
+        let _: BitbucketPull = post_json(
+            &self.client,
+            &self.pull_url(number, "/decline"),
+            &serde_json::json!({}),
+        )
+        .await
+        .context("Failed to decline PR")?;
+        Ok(())
+    }
+
+    pub async fn reopen_pr(&self, _number: u64) -> Result<()> {
+        bail!("Bitbucket cannot reopen a declined pull request — create a new one instead")
+    }
+
+    pub async fn merge_pr(
+        &self,
+        number: u64,
+        method: MergeMethod,
+        commit_title: Option<&str>,
+        _sha: Option<&str>,
+    ) -> Result<()> {
+        let request = MergePullRequest {
+            merge_strategy: merge_strategy(method),
+            message: commit_title,
+        };
+        let _: BitbucketPull =
+            post_json(&self.client, &self.pull_url(number, "/merge"), &request).await?;
+        Ok(())
+    }
+
+    pub async fn get_pr_merge_status(&self, number: u64) -> Result<PrMergeStatus> {
+        let pr = self.get_pull(number).await?;
+        let head_sha = pr
+            .source
+            .commit
+            .as_ref()
+            .map(|commit| commit.hash.clone())
+            .unwrap_or_default();
+        let ci_status = self
+            .fetch_checks(&head_sha)
+            .await
+            .ok()
+            .and_then(|(status, _)| status);
+        let (review_decision, approvals, changes_requested) =
+            summarize_participants(&pr.participants);
+
+        // Bitbucket Cloud does not report conflicts through the PR API, so an
+        // open PR is treated as mergeable and the merge call surfaces conflicts.
+        let is_open = pr.state == "OPEN";
+        let mergeable_state = if is_open { "clean" } else { "unknown" }.to_string();
+        let state = normalize_bitbucket_state(&pr.state);
+        Ok(PrMergeStatus {
+            number: pr.id,
+            title: pr.title,
+            state,
+            updated_at: pr.updated_on.map(|updated| updated.to_rfc3339()),
+            is_draft: pr.draft,
+            mergeable: is_open.then_some(true),
+            mergeable_state,
+            ci_status: ci_status_from_string(ci_status.as_deref()),
+            review_decision,
+            approvals,
+            changes_requested,
+            head_sha,
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
+        })
+    }
+
+    pub async fn get_pr_review_decision(&self, number: u64) -> Result<Option<String>> {
+        let pr = self.get_pull(number).await?;
+        Ok(summarize_participants(&pr.participants).0)
+    }
+
+    pub async fn is_pr_merged(&self, number: u64) -> Result<bool> {
+        Ok(self.get_pull(number).await?.state == "MERGED")
+    }
+
+    pub async fn get_pr_head_sha(&self, number: u64) -> Result<String> {
+        Ok(self
+            .get_pull(number)
+            .await?
+            .source
+            .commit
+            .map(|commit| commit.hash)
+            .unwrap_or_default())
+    }
+
+    pub async fn fetch_checks(&self, sha: &str) -> Result<(Option<String>, Vec<CheckRunInfo>)> {
+        let statuses: Vec<BitbucketCommitStatus> = self
+            .get_all_pages(&self.repo_url(&format!("/commit/{}/statuses?pagelen=100", sha)))
+            .await?;
+        let checks = statuses
+            .iter()
+            .map(|status| CheckRunInfo {
+                name: status
+                    .name
+                    .clone()
+                    .or_else(|| status.key.clone())
+                    .unwrap_or_else(|| "status".to_string()),
+                status: normalize_bitbucket_status(status.state.as_deref()),
+                conclusion: status.state.as_deref().map(normalize_bitbucket_conclusion),
+                url: status.url.clone(),
+                started_at: status.created_on.clone(),
+                completed_at: status.updated_on.clone(),
+                elapsed_secs: None,
+                average_secs: None,
+                completion_percent: None,
+            })
+            .collect::<Vec<_>>();
+
+        let overall = aggregate_ci_overall(
+            statuses.iter().filter_map(|status| status.state.as_deref()),
+            |s| matches!(s, "FAILED" | "STOPPED"),
+            |s| matches!(s, "INPROGRESS"),
+        );
+
+        Ok((overall, checks))
+    }
+
+    /// Add reviewers, keeping the ones already on the PR. Bitbucket identifies
+    /// users by account, so names are matched against workspace members.
+    pub async fn request_reviewers(&self, number: u64, reviewers: &[String]) -> Result<()> {
+        let (users, teams) = split_reviewers(reviewers);
+        if !teams.is_empty() {
+            eprintln!(
+                "{} Bitbucket has no team reviewers — skipping {}.",
+                "warn:".yellow(),
+                teams.join(", ")
+            );
+        }
+        if users.is_empty() {
+            return Ok(());
+        }
+
+        let pr = self.get_pull(number).await?;
+        let members: Vec<BitbucketMember> = self
+            .get_all_pages(&format!(
+                "{}/workspaces/{}/members?pagelen=100",
+                self.api_base_url, self.workspace
+            ))
+            .await?;
+
+        let mut uuids = pr
+            .reviewers
+            .iter()
+            .filter_map(|user| user.uuid.clone())
+            .collect::<Vec<_>>();
+        for name in &users {
+            let member = members
+                .iter()
+                .find(|member| member.user.matches(name))
+                .with_context(|| format!("Bitbucket workspace member '{}' not found", name))?;
+            let uuid = member
+                .user
+                .uuid
+                .clone()
+                .with_context(|| format!("Bitbucket user '{}' has no uuid", name))?;
+            if !uuids.contains(&uuid) {
+                uuids.push(uuid);
+            }
+        }
+
+        let reviewers = uuids
+            .into_iter()
+            .map(|uuid| serde_json::json!({ "uuid": uuid }))
+            .collect::<Vec<_>>();
+        self.update_pull(number, &serde_json::json!({ "reviewers": reviewers }))
+            .await
+            .context("Failed to request reviewers")
+    }
+
+    pub async fn get_requested_reviewers(&self, number: u64) -> Result<Vec<String>> {
+        let pr = self.get_pull(number).await?;
+        Ok(pr.reviewers.iter().map(BitbucketUser::handle).collect())
+    }
+
+    pub async fn list_open_pull_requests(&self, limit: u8) -> Result<Vec<RepoPrListItem>> {
+        let limit = limit.clamp(1, 50);
+        let url = format!(
+            "{}?state=OPEN&sort=-created_on&pagelen={}",
+            self.repo_url("/pullrequests"),
+            limit
+        );
+        let page: BitbucketPage<BitbucketPull> = get_json(&self.client, &url).await?;
+        Ok(page
+            .values
+            .into_iter()
+            .map(|pr| RepoPrListItem {
+                number: pr.id,
+                url: pr_html_url(&pr),
+                author: pr
+                    .author
+                    .as_ref()
+                    .map(BitbucketUser::handle)
+                    .unwrap_or_else(|| "unknown".to_string()),
+                state: normalize_bitbucket_state(&pr.state),
+                is_draft: pr.draft,
+                created_at: pr.created_on.unwrap_or_default(),
+                title: pr.title,
+                head_branch: pr.source.branch.name,
+                base_branch: pr.destination.branch.name,
+            })
+            .collect())
+    }
+
+    pub async fn get_current_user(&self) -> Result<String> {
+        let url = format!("{}/user", self.api_base_url);
+        let user: BitbucketUser = get_json(&self.client, &url).await?;
+        Ok(user.handle())
+    }
+
+    pub async fn get_user_open_prs(&self, username: &str) -> Result<Vec<OpenPrInfo>> {
+        let filter = format!("state=\"OPEN\" AND {}", self.author_filter(username).await?);
+        let prs = self.list_pulls(&filter, "-created_on").await?;
+        Ok(prs
+            .into_iter()
+            .map(|pr| OpenPrInfo {
+                number: pr.id,
+                state: normalize_bitbucket_state(&pr.state),
+                is_draft: pr.draft,
+                head_branch: pr.source.branch.name,
+                base_branch: pr.destination.branch.name,
+            })
+            .collect())
+    }
+
+    pub async fn get_recent_merged_prs(
+        &self,
+        hours: i64,
+        username: &str,
+    ) -> Result<Vec<PrActivity>> {
+        let since = Utc::now() - chrono::Duration::hours(hours);
+        let filter = format!(
+            "state=\"MERGED\" AND updated_on>={} AND {}",
+            bbql_time(since),
+            self.author_filter(username).await?
+        );
+        let prs = self.list_pulls(&filter, "-updated_on").await?;
+        Ok(prs
+            .into_iter()
+            .map(|pr| PrActivity {
+                number: pr.id,
+                url: pr_html_url(&pr),
+                timestamp: pr.updated_on.unwrap_or_default(),
+                title: pr.title,
+            })
+            .collect())
+    }
+
+    pub async fn get_recent_opened_prs(
+        &self,
+        hours: i64,
+        username: &str,
+    ) -> Result<Vec<PrActivity>> {
+        let since = Utc::now() - chrono::Duration::hours(hours);
+        let filter = format!(
+            "state=\"OPEN\" AND created_on>={} AND {}",
+            bbql_time(since),
+            self.author_filter(username).await?
+        );
+        let prs = self.list_pulls(&filter, "-created_on").await?;
+        Ok(prs
+            .into_iter()
+            .map(|pr| PrActivity {
+                number: pr.id,
+                url: pr_html_url(&pr),
+                timestamp: pr.created_on.unwrap_or_default(),
+                title: pr.title,
+            })
+            .collect())
+    }
+
+    pub async fn get_reviews_received(
+        &self,
+        hours: i64,
+        username: &str,
+    ) -> Result<Vec<ReviewActivity>> {
+        let since = Utc::now() - chrono::Duration::hours(hours);
+        // The PR list omits participants, so fetch each of the user's PRs.
+        let filter = format!("state=\"OPEN\" AND {}", self.author_filter(username).await?);
+        let prs = self.list_pulls(&filter, "-updated_on").await?;
+
+        let mut reviews = Vec::new();
+        for pr in &prs {
+            let Ok(pr) = self.get_pull(pr.id).await else {
+                continue;
+            };
+            for participant in &pr.participants {
+                if participant.user.matches(username) {
+                    continue;
+                }
+                let state = match participant.state.as_deref() {
+                    Some("changes_requested") => "CHANGES_REQUESTED",
+                    _ if participant.approved => "APPROVED",
+                    _ => continue,
+                };
+                if let Some(ts) = participant.participated_on
+                    && ts >= since
+                {
+                    reviews.push(ReviewActivity {
+                        pr_number: pr.id,
+                        pr_title: pr.title.clone(),
+                        reviewer: participant.user.handle(),
+                        state: state.to_string(),
+                        timestamp: ts,
+                        is_received: true,
+                    });
+                }
+            }
+        }
+        Ok(reviews)
+    }
+
+    pub async fn get_reviews_given(
+        &self,
+        _hours: i64,
+        _username: &str,
+    ) -> Result<Vec<ReviewActivity>> {
+        // Not implemented: Bitbucket has no query for "PRs this user reviewed".
+        Ok(vec![])
+    }
+
+    pub async fn list_open_issues(&self, _limit: u8) -> Result<Vec<RepoIssueListItem>> {
+        bail!("Listing issues is not supported for Bitbucket")
+    }
+
+    pub async fn get_issue(&self, _number: u64) -> Result<RepoIssueListItem> {
+        bail!("Fetching issues is not supported for Bitbucket")
+    }
+}
+
+fn merge_strategy(method: MergeMethod) -> &'static str {
+    match method {
+        MergeMethod::Squash => "squash",
+        MergeMethod::Merge => "merge_commit",
+        MergeMethod::Rebase => "fast_forward",
+    }
+}
+
+/// Datetime literal for a BBQL comparison.
+fn bbql_time(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%dT%H:%M:%S%:z").to_string()
+}
+
+fn pr_html_url(pr: &BitbucketPull) -> String {
+    pr.links
+        .as_ref()
+        .and_then(|links| links.html.as_ref())
+        .map(|html| html.href.clone())
+        .unwrap_or_default()
+}
+
+/// Reduce participants to `(review_decision, approvals, changes_requested)`.
+fn summarize_participants(participants: &[BitbucketParticipant]) -> (Option<String>, usize, bool) {
+    let approvals = participants.iter().filter(|p| p.approved).count();
+    let changes_requested = participants
+        .iter()
+        .any(|p| p.state.as_deref() == Some("changes_requested"));
+    let decision = if changes_requested {
+        Some("CHANGES_REQUESTED".to_string())
+    } else if approvals > 0 {
+        Some("APPROVED".to_string())
+    } else {
+        None
+    };
+    (decision, approvals, changes_requested)
+}
+
+fn normalize_bitbucket_state(state: &str) -> String {
+    match state {
+        "OPEN" => "OPEN",
+        "MERGED" => "MERGED",
+        _ => "CLOSED",
+    }
+    .to_string()
+}
+
+fn normalize_bitbucket_status(state: Option<&str>) -> String {
+    match state.unwrap_or("") {
+        "INPROGRESS" => "in_progress".to_string(),
+        _ => "completed".to_string(),
+    }
+}
+
+fn normalize_bitbucket_conclusion(state: &str) -> String {
+    match state {
+        "SUCCESSFUL" => "success".to_string(),
+        "FAILED" => "failure".to_string(),
+        "STOPPED" => "cancelled".to_string(),
+        other => other.to_lowercase(),
+    }
+}
+
+fn pr_to_info(pr: &BitbucketPull) -> PrInfo {
+    PrInfo {
+        number: pr.id,
+        state: normalize_bitbucket_state(&pr.state),
+        is_draft: pr.draft,
+        base: pr.destination.branch.name.clone(),
+    }
+}
+
+fn pr_to_info_with_head(pr: BitbucketPull) -> PrInfoWithHead {
+    PrInfoWithHead {
+        info: pr_to_info(&pr),
+        head: pr.source.branch.name.clone(),
+        head_label: pr
+            .source
+            .repository
+            .as_ref()
+            .and_then(|repo| repo.full_name.as_deref())
+            .map(|full_name| {
+                let owner = full_name.split('/').next().unwrap_or(full_name);
+                format!("{}:{}", owner, pr.source.branch.name)
+            }),
+        title: pr.title.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn ensure_crypto_provider() {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+    }
+
+    fn remote_info(server: &MockServer) -> RemoteInfo {
+        RemoteInfo {
+            name: "origin".to_string(),
+            forge: ForgeType::Bitbucket,
+            host: "bitbucket.org".to_string(),
+            namespace: "team".to_string(),
+            repo: "repo".to_string(),
+            base_url: "https://bitbucket.org".to_string(),
+            api_base_url: Some(server.uri()),
+            request_timeout: None,
+            head_owner: None,
+        }
+    }
+
+    fn client(server: &MockServer) -> BitbucketClient {
+        ensure_crypto_provider();
+        unsafe { std::env::set_var("STAX_BITBUCKET_TOKEN", "test-token") };
+        BitbucketClient::new(&remote_info(server)).unwrap()
+    }
+
+    fn pull_json(id: u64, branch: &str, participants: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "title": "Feature",
+            "description": "body",
+            "state": "OPEN",
+            "draft": false,
+            "source": {
+                "branch": { "name": branch },
+                "commit": { "hash": "abc123" },
+                "repository": { "full_name": "team/repo" }
+            },
+            "destination": { "branch": { "name": "main" } },
+            "author": { "nickname": "alice", "display_name": "Alice" },
+            "links": { "html": { "href": format!("https://bitbucket.org/team/repo/pull-requests/{}", id) } },
+            "reviewers": [{ "nickname": "bob", "uuid": "{bob}" }],
+            "participants": participants
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_open_prs_by_head_uses_bearer_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("Authorization", "Bearer test-token"))
+            .and(path("/repositories/team/repo/pullrequests"))
+            .and(query_param("state", "OPEN"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "values": [pull_json(4, "feature-a", serde_json::json!([]))]
+            })))
+            .mount(&server)
+            .await;
+
+        let prs = client(&server).list_open_prs_by_head().await.unwrap();
+        let pr = prs.get("feature-a").unwrap();
+        assert_eq!(pr.info.number, 4);
+        assert_eq!(pr.info.state, "OPEN");
+        assert_eq!(pr.info.base, "main");
+        assert_eq!(pr.head_label.as_deref(), Some("team:feature-a"));
+    }
+
+    #[tokio::test]
+    async fn test_open_pr_lookups_follow_next_pages() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repositories/team/repo/pullrequests"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "values": [pull_json(7, "feature-b", serde_json::json!([]))]
+            })))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repositories/team/repo/pullrequests"))
+            .and(query_param("state", "OPEN"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "values": [pull_json(4, "feature-a", serde_json::json!([]))],
+                "next": format!(
+                    "{}/repositories/team/repo/pullrequests?state=OPEN&pagelen=50&page=2",
+                    server.uri()
+                )
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let prs = client.list_open_prs_by_head().await.unwrap();
+        assert_eq!(prs.len(), 2);
+        assert_eq!(prs.get("feature-b").unwrap().info.number, 7);
+
+        let pr = client.find_open_pr_by_head("feature-b").await.unwrap();
+        assert_eq!(pr.unwrap().info.number, 7);
+        let requests = server.received_requests().await.unwrap();
+        assert!(requests.iter().any(|request| {
+            request
+                .url
+                .query_pairs()
+                .any(|(key, value)| key == "q" && value == "source.branch.name=\"feature-b\"")
+        }));
+    }
+
+    #[tokio::test]
+    async fn test_user_pr_lookups_filter_by_author_and_follow_next_pages() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "nickname": "alice",
+                "uuid": "{alice}"
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/workspaces/team/members"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "values": [{ "user": { "nickname": "bob", "uuid": "{bob}" } }]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repositories/team/repo/pullrequests"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "values": [pull_json(7, "feature-b", serde_json::json!([]))]
+            })))
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repositories/team/repo/pullrequests"))
+            .and(query_param(
+                "q",
+                "state=\"OPEN\" AND author.uuid=\"{alice}\"",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "values": [pull_json(4, "feature-a", serde_json::json!([]))],
+                "next": format!(
+                    "{}/repositories/team/repo/pullrequests?page=2",
+                    server.uri()
+                )
+            })))
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        let prs = client.get_user_open_prs("alice").await.unwrap();
+        assert_eq!(
+            prs.iter().map(|pr| pr.number).collect::<Vec<_>>(),
+            vec![4, 7]
+        );
+
+        let err = client.get_user_open_prs("ghost").await.unwrap_err();
+        assert!(err.to_string().contains("'ghost' not found"));
+    }
+
+    #[tokio::test]
+    async fn test_create_pr_sends_source_and_destination() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repositories/team/repo/pullrequests"))
+            .and(body_json(serde_json::json!({
+                "title": "Feature",
+                "description": "body",
+                "source": { "branch": { "name": "feature-a" } },
+                "destination": { "branch": { "name": "main" } },
+                "draft": true,
+                "close_source_branch": false
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(pull_json(
+                5,
+                "feature-a",
+                serde_json::json!([]),
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let pr = client(&server)
+            .create_pr("feature-a", "main", "Feature", "body", true)
+            .await
+            .unwrap();
+        assert_eq!(pr.number, 5);
+    }
+
+    #[tokio::test]
+    async fn test_merge_pr_maps_strategy() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/repositories/team/repo/pullrequests/5/merge"))
+            .and(body_json(serde_json::json!({
+                "merge_strategy": "squash",
+                "message": "Feature (#5)"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_json(
+                5,
+                "feature-a",
+                serde_json::json!([]),
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server)
+            .merge_pr(5, MergeMethod::Squash, Some("Feature (#5)"), None)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_merge_status_reads_participants_and_build_statuses() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repositories/team/repo/pullrequests/5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_json(
+                5,
+                "feature-a",
+                serde_json::json!([
+                    { "user": { "nickname": "bob" }, "approved": true, "state": "approved" },
+                    { "user": { "nickname": "carol" }, "approved": false, "state": null }
+                ]),
+            )))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repositories/team/repo/commit/abc123/statuses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "values": [
+                    { "key": "build", "name": "Build", "state": "SUCCESSFUL" },
+                    { "key": "lint", "state": "INPROGRESS" }
+                ]
+            })))
+            .mount(&server)
+            .await;
+
+        let status = client(&server).get_pr_merge_status(5).await.unwrap();
+        assert_eq!(status.approvals, 1);
+        assert_eq!(status.review_decision.as_deref(), Some("APPROVED"));
+        assert!(!status.changes_requested);
+        assert!(status.ci_status.is_pending());
+        assert_eq!(status.mergeable, Some(true));
+        assert_eq!(status.head_sha, "abc123");
+    }
+
+    #[tokio::test]
+    async fn test_request_reviewers_resolves_workspace_members() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repositories/team/repo/pullrequests/5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_json(
+                5,
+                "feature-a",
+                serde_json::json!([]),
+            )))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/workspaces/team/members"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "values": [
+                    { "user": { "nickname": "bob", "uuid": "{bob}" } },
+                    { "user": { "nickname": "dave", "uuid": "{dave}" } }
+                ]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/repositories/team/repo/pullrequests/5"))
+            .and(body_json(serde_json::json!({
+                "reviewers": [{ "uuid": "{bob}" }, { "uuid": "{dave}" }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_json(
+                5,
+                "feature-a",
+                serde_json::json!([]),
+            )))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client(&server);
+        client
+            .request_reviewers(5, &["dave".to_string(), "team/core".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(
+            client.get_requested_reviewers(5).await.unwrap(),
+            vec!["bob".to_string()]
+        );
+        let err = client
+            .request_reviewers(5, &["ghost".to_string()])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("'ghost' not found"));
+    }
+
+    #[test]
+    fn test_summarize_participants_prefers_changes_requested() {
+        let participants: Vec<BitbucketParticipant> = serde_json::from_value(serde_json::json!([
+            { "user": { "nickname": "bob" }, "approved": true, "state": "approved" },
+            { "user": { "nickname": "carol" }, "approved": false, "state": "changes_requested" }
+        ]))
+        .unwrap();
+        assert_eq!(
+            summarize_participants(&participants),
+            (Some("CHANGES_REQUESTED".to_string()), 1, true)
+        );
+    }
+}
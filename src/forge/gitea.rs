@@ -1,5 +1,6 @@
 use anyhow::{Context, Result, bail};
 use chrono::{DateTime, Utc};
+use colored::Colorize;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -11,7 +12,9 @@ use super::{
 };
 use crate::ci::CheckRunInfo;
 use crate::github::client::OpenPrInfo;
-use crate::github::pr::{MergeMethod, PrComment, PrInfo, PrInfoWithHead, PrMergeStatus};
+use crate::github::pr::{
+    MergeMethod, PrComment, PrInfo, PrInfoWithHead, PrMergeStatus, split_reviewers,
+};
 use crate::remote::{ForgeType, RemoteInfo};
 
 #[derive(Clone)]
@@ -38,6 +41,10 @@ struct GiteaPull {
     html_url: Option<String>,
     created_at: Option<DateTime<Utc>>,
     updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    requested_reviewers: Option<Vec<GiteaUser>>,
+    #[serde(default)]
+    assignees: Option<Vec<GiteaUser>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -82,6 +89,8 @@ struct GiteaIssue {
 
 #[derive(Debug, Deserialize)]
 struct GiteaLabel {
+    #[serde(default)]
+    id: u64,
     name: Option<String>,
 }
 
@@ -90,6 +99,8 @@ struct GiteaReview {
     user: Option<GiteaUser>,
     state: Option<String>,
     submitted_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    dismissed: bool,
 }
 
 #[derive(Serialize)]
@@ -128,18 +139,28 @@ struct CreateCommentRequest<'a> {
     body: &'a str,
 }
 
+#[derive(Serialize)]
+struct ReviewRequest<'a> {
+    reviewers: &'a [String],
+    team_reviewers: &'a [String],
+}
+
 impl GiteaClient {
     pub fn new(remote: &RemoteInfo) -> Result<Self> {
         if remote.forge != ForgeType::Gitea {
             bail!("Internal error: expected Gitea remote");
         }
 
-        let token = super::forge_token(ForgeType::Gitea).context(
+        let token = super::forge_token(ForgeType::Gitea, &remote.host).context(
             "Gitea auth not configured. Use `stax auth` or set `STAX_GITEA_TOKEN`, `GITEA_TOKEN`, or `STAX_FORGE_TOKEN`.",
         )?;
 
         Ok(Self {
-            client: build_http_client(&token, AuthStyle::AuthorizationToken)?,
+            client: build_http_client(
+                &token,
+                AuthStyle::AuthorizationToken,
+                remote.request_timeout,
+            )?,
             api_base_url: remote
                 .api_base_url
                 .clone()
@@ -394,6 +415,9 @@ impl GiteaClient {
             .ok()
             .and_then(|(status, _)| status);
 
+        let reviews = self.list_reviews(pr.number).await.unwrap_or_default();
+        let (review_decision, approvals, changes_requested) = summarize_reviews(&reviews);
+
         let state = normalize_gitea_state(&pr);
         Ok(PrMergeStatus {
             number: pr.number,
@@ -404,15 +428,157 @@ impl GiteaClient {
             mergeable: pr.mergeable.or_else(|| mergeable_bool(&mergeable_state)),
             mergeable_state,
             ci_status: ci_status_from_string(ci_status.as_deref()),
-            review_decision: None,
-            approvals: 0,
-            changes_requested: false,
+            review_decision,
+            approvals,
+            changes_requested,
             head_sha: pr.head.sha.unwrap_or_default(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         })
     }
 
-    pub async fn get_pr_review_decision(&self, _number: u64) -> Result<Option<String>> {
-        Ok(None)
+    pub async fn get_pr_review_decision(&self, number: u64) -> Result<Option<String>> {
+        let reviews = self.list_reviews(number).await?;
+        Ok(summarize_reviews(&reviews).0)
+    }
+
+    async fn list_reviews(&self, number: u64) -> Result<Vec<GiteaReview>> {
+        get_json(
+            &self.client,
+            &self.repo_url(&format!("/pulls/{}/reviews?limit=50", number)),
+        )
+        .await
+    }
+
+    /// Request reviews from users and `org/team` teams. Gitea only knows
+    /// team names within the repo's organization, so the `org/` is dropped.
+    pub async fn request_reviewers(&self, number: u64, reviewers: &[String]) -> Result<()> {
+        let (users, teams) = split_reviewers(reviewers);
+        if users.is_empty() && teams.is_empty() {
+            return Ok(());
+        }
+        let teams = teams
+            .iter()
+            .map(|team| team.rsplit('/').next().unwrap_or(team).to_string())
+            .collect::<Vec<_>>();
+        let request = ReviewRequest {
+            reviewers: &users,
+            team_reviewers: &teams,
+        };
+        let _: serde_json::Value = post_json(
+            &self.client,
+            &self.repo_url(&format!("/pulls/{}/requested_reviewers", number)),
+            &request,
+        )
+        .await
+        .context("Failed to request reviewers")?;
+        Ok(())
+    }
+
+    pub async fn get_requested_reviewers(&self, number: u64) -> Result<Vec<String>> {
+        let pr: GiteaPull =
+            get_json(&self.client, &self.repo_url(&format!("/pulls/{}", number))).await?;
+        Ok(pr
+            .requested_reviewers
+            .unwrap_or_default()
+            .into_iter()
+            .map(|user| user.login)
+            .collect())
+    }
+
+    /// Add existing repository labels by name. Gitea needs label ids, so
+    /// names that don't exist on the repo are skipped with a warning.
+    pub async fn add_labels(&self, number: u64, labels: &[String]) -> Result<()> {
+        if labels.is_empty() {
+            return Ok(());
+        }
+        let repo_labels: Vec<GiteaLabel> =
+            get_json(&self.client, &self.repo_url("/labels?limit=50")).await?;
+        let mut ids = Vec::new();
+        let mut missing = Vec::new();
+        for label in labels {
+            match repo_labels
+                .iter()
+                .find(|candidate| candidate.name.as_deref() == Some(label.as_str()))
+            {
+                Some(found) => ids.push(found.id),
+                None => missing.push(label.as_str()),
+            }
+        }
+        if !missing.is_empty() {
+            eprintln!(
+                "{} Label(s) not found in repository — skipping {}.",
+                "warn:".yellow(),
+                missing.join(", ")
+            );
+        }
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let _: serde_json::Value = post_json(
+            &self.client,
+            &self.repo_url(&format!("/issues/{}/labels", number)),
+            &serde_json::json!({ "labels": ids }),
+        )
+        .await
+        .context("Failed to add labels")?;
+        Ok(())
+    }
+
+    /// Add assignees, keeping the ones already assigned.
+    pub async fn add_assignees(&self, number: u64, assignees: &[String]) -> Result<()> {
+        if assignees.is_empty() {
+            return Ok(());
+        }
+        let pr: GiteaPull =
+            get_json(&self.client, &self.repo_url(&format!("/pulls/{}", number))).await?;
+        let mut logins = pr
+            .assignees
+            .unwrap_or_default()
+            .into_iter()
+            .map(|user| user.login)
+            .collect::<Vec<_>>();
+        for assignee in assignees {
+            if !logins.contains(assignee) {
+                logins.push(assignee.clone());
+            }
+        }
+        self.patch_pull(number, &serde_json::json!({ "assignees": logins }))
+            .await
+            .context("Failed to add assignees")
+    }
+
+    pub async fn close_pr(&self, number: u64) -> Result<()> {
+        self.patch_pull(number, &serde_json::json!({ "state": "closed" }))
+            .await
+            .context("Failed to close PR")
+    }
+
+    pub async fn reopen_pr(&self, number: u64) -> Result<()> {
+        self.patch_pull(number, &serde_json::json!({ "state": "open" }))
+            .await
+            .context("Failed to reopen PR")
+    }
+
+    pub async fn create_issue_comment(&self, number: u64, body: &str) -> Result<()> {
+        let request = CreateCommentRequest { body };
+        let _: GiteaComment = post_json(
+            &self.client,
+            &self.repo_url(&format!("/issues/{}/comments", number)),
+            &request,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn patch_pull(&self, number: u64, request: &serde_json::Value) -> Result<()> {
+        let _: GiteaPull = patch_json(
+            &self.client,
+            &self.repo_url(&format!("/pulls/{}", number)),
+            request,
+        )
+        .await?;
+        Ok(())
     }
 
     pub async fn is_pr_merged(&self, number: u64) -> Result<bool> {
@@ -514,6 +680,22 @@ impl GiteaClient {
             .collect())
     }
 
+    pub async fn get_issue(&self, number: u64) -> Result<RepoIssueListItem> {
+        let url = self.repo_url(&format!("/issues/{}", number));
+        let issue: GiteaIssue = get_json(&self.client, &url).await?;
+        Ok(RepoIssueListItem {
+            number: issue.number,
+            title: issue.title,
+            url: issue.html_url.unwrap_or_default(),
+            author: issue
+                .user
+                .map(|u| u.login)
+                .unwrap_or_else(|| "unknown".to_string()),
+            labels: issue.labels.into_iter().filter_map(|l| l.name).collect(),
+            updated_at: issue.updated_at,
+        })
+    }
+
     pub async fn get_current_user(&self) -> Result<String> {
         let url = format!("{}/user", self.api_base_url);
         let user: GiteaUser = get_json(&self.client, &url).await?;
@@ -672,6 +854,34 @@ fn normalize_gitea_conclusion(status: &str) -> String {
     }
 }
 
+/// Reduce a PR's reviews to `(review_decision, approvals, changes_requested)`
+/// using each reviewer's latest approving or blocking review.
+fn summarize_reviews(reviews: &[GiteaReview]) -> (Option<String>, usize, bool) {
+    let mut latest: HashMap<&str, &str> = HashMap::new();
+    for review in reviews {
+        let (Some(user), Some(state)) = (&review.user, review.state.as_deref()) else {
+            continue;
+        };
+        if review.dismissed {
+            latest.remove(user.login.as_str());
+            continue;
+        }
+        if matches!(state, "APPROVED" | "REQUEST_CHANGES") {
+            latest.insert(user.login.as_str(), state);
+        }
+    }
+    let approvals = latest.values().filter(|s| **s == "APPROVED").count();
+    let changes_requested = latest.values().any(|s| *s == "REQUEST_CHANGES");
+    let decision = if changes_requested {
+        Some("CHANGES_REQUESTED".to_string())
+    } else if approvals > 0 {
+        Some("APPROVED".to_string())
+    } else {
+        None
+    };
+    (decision, approvals, changes_requested)
+}
+
 fn normalize_gitea_state(pr: &GiteaPull) -> String {
     normalize_gitea_state_str(&pr.state, pr.merged)
 }
@@ -697,7 +907,7 @@ fn pr_to_info_with_head(pr: GiteaPull) -> PrInfoWithHead {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::matchers::{body_json, header, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn ensure_crypto_provider() {
@@ -713,6 +923,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://gitea.example.com".to_string(),
             api_base_url: Some(server.uri()),
+            request_timeout: None,
+            head_owner: None,
         }
     }
 
@@ -1058,4 +1270,157 @@ mod tests {
         assert_eq!(reviews[0].state, "APPROVED");
         assert!(reviews[0].is_received);
     }
+
+    fn review(login: &str, state: &str, dismissed: bool) -> GiteaReview {
+        GiteaReview {
+            user: Some(GiteaUser {
+                login: login.to_string(),
+            }),
+            state: Some(state.to_string()),
+            submitted_at: None,
+            dismissed,
+        }
+    }
+
+    #[test]
+    fn test_summarize_reviews_uses_latest_review_per_user() {
+        let reviews = vec![
+            review("alice", "REQUEST_CHANGES", false),
+            review("bob", "APPROVED", false),
+            review("alice", "COMMENT", false),
+            review("alice", "APPROVED", false),
+        ];
+        assert_eq!(
+            summarize_reviews(&reviews),
+            (Some("APPROVED".to_string()), 2, false)
+        );
+
+        let reviews = vec![
+            review("alice", "APPROVED", false),
+            review("bob", "REQUEST_CHANGES", false),
+        ];
+        assert_eq!(
+            summarize_reviews(&reviews),
+            (Some("CHANGES_REQUESTED".to_string()), 1, true)
+        );
+
+        let reviews = vec![review("alice", "APPROVED", true)];
+        assert_eq!(summarize_reviews(&reviews), (None, 0, false));
+    }
+
+    fn pull_json() -> serde_json::Value {
+        serde_json::json!({
+            "number": 8,
+            "state": "open",
+            "title": "Feature",
+            "merged": false,
+            "head": { "ref": "feature", "sha": "aaa" },
+            "base": { "ref": "main", "sha": "bbb" },
+            "requested_reviewers": [{ "login": "bob" }],
+            "assignees": [{ "login": "carol" }]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_request_reviewers_posts_users_and_teams() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        unsafe { std::env::set_var("STAX_GITEA_TOKEN", "test-token") };
+
+        Mock::given(method("POST"))
+            .and(path("/repos/org/repo/pulls/8/requested_reviewers"))
+            .and(body_json(serde_json::json!({
+                "reviewers": ["bob"],
+                "team_reviewers": ["core"]
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/org/repo/pulls/8"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_json()))
+            .mount(&server)
+            .await;
+
+        let client = GiteaClient::new(&remote_info(&server)).unwrap();
+        client
+            .request_reviewers(8, &["@bob".to_string(), "org/core".to_string()])
+            .await
+            .unwrap();
+        let reviewers = client.get_requested_reviewers(8).await.unwrap();
+        assert_eq!(reviewers, vec!["bob".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_add_labels_resolves_ids_and_assignees_keep_existing() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        unsafe { std::env::set_var("STAX_GITEA_TOKEN", "test-token") };
+
+        Mock::given(method("GET"))
+            .and(path("/repos/org/repo/labels"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": 3, "name": "bug" },
+                { "id": 9, "name": "stacked" }
+            ])))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/repos/org/repo/issues/8/labels"))
+            .and(body_json(serde_json::json!({ "labels": [9] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/org/repo/pulls/8"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(pull_json()))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/repos/org/repo/pulls/8"))
+            .and(body_json(
+                serde_json::json!({ "assignees": ["carol", "dave"] }),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(pull_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GiteaClient::new(&remote_info(&server)).unwrap();
+        client
+            .add_labels(8, &["stacked".to_string(), "missing".to_string()])
+            .await
+            .unwrap();
+        client
+            .add_assignees(8, &["carol".to_string(), "dave".to_string()])
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_close_and_reopen_patch_state() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        unsafe { std::env::set_var("STAX_GITEA_TOKEN", "test-token") };
+
+        for state in ["closed", "open"] {
+            Mock::given(method("PATCH"))
+                .and(path("/repos/org/repo/pulls/8"))
+                .and(body_json(serde_json::json!({ "state": state })))
+                .respond_with(ResponseTemplate::new(201).set_body_json(pull_json()))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = GiteaClient::new(&remote_info(&server)).unwrap();
+        client.close_pr(8).await.unwrap();
+        client.reopen_pr(8).await.unwrap();
+    }
 }
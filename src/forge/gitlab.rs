@@ -4,14 +4,19 @@ use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use colored::Colorize;
+
 use super::{
     AuthStyle, PrActivity, RepoIssueListItem, RepoPrListItem, ReviewActivity, STACK_COMMENT_MARKER,
-    aggregate_ci_overall, build_http_client, ci_status_from_string, delete_empty, get_json,
-    make_issue_comment, mergeable_bool, post_json, put_json, stack_comment_body,
+    aggregate_ci_overall, build_http_client, ci_status_from_string, delete_empty,
+    encode_query_value, get_json, make_issue_comment, mergeable_bool, post_json, put_json,
+    stack_comment_body,
 };
 use crate::ci::CheckRunInfo;
 use crate::github::client::OpenPrInfo;
-use crate::github::pr::{MergeMethod, PrComment, PrInfo, PrInfoWithHead, PrMergeStatus};
+use crate::github::pr::{
+    MergeMethod, PrComment, PrInfo, PrInfoWithHead, PrMergeStatus, split_reviewers,
+};
 use crate::remote::{ForgeType, RemoteInfo};
 
 #[derive(Clone)]
@@ -39,6 +44,10 @@ struct GitLabMr {
     created_at: Option<DateTime<Utc>>,
     merged_at: Option<DateTime<Utc>>,
     updated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    reviewers: Vec<GitLabMember>,
+    #[serde(default)]
+    assignees: Vec<GitLabMember>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -51,6 +60,13 @@ struct GitLabUser {
     username: String,
 }
 
+/// A user as returned by `/users` and in MR `reviewers`/`assignees`.
+#[derive(Debug, Deserialize)]
+struct GitLabMember {
+    id: u64,
+    username: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct GitLabNote {
     id: u64,
@@ -137,12 +153,12 @@ impl GitLabClient {
             bail!("Internal error: expected GitLab remote");
         }
 
-        let token = super::forge_token(ForgeType::GitLab).context(
+        let token = super::forge_token(ForgeType::GitLab, &remote.host).context(
             "GitLab auth not configured. Use `stax auth` or set `STAX_GITLAB_TOKEN`, `GITLAB_TOKEN`, or `STAX_FORGE_TOKEN`.",
         )?;
 
         Ok(Self {
-            client: build_http_client(&token, AuthStyle::PrivateToken)?,
+            client: build_http_client(&token, AuthStyle::PrivateToken, remote.request_timeout)?,
             api_base_url: remote
                 .api_base_url
                 .clone()
@@ -300,6 +316,127 @@ impl GitLabClient {
         Ok(mr.description.unwrap_or_default())
     }
 
+    async fn get_mr(&self, number: u64) -> Result<GitLabMr> {
+        get_json(
+            &self.client,
+            &self.project_url(&format!("/merge_requests/{}", number)),
+        )
+        .await
+    }
+
+    async fn update_mr(&self, number: u64, request: &serde_json::Value) -> Result<()> {
+        let _: GitLabMr = put_json(
+            &self.client,
+            &self.project_url(&format!("/merge_requests/{}", number)),
+            request,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Look up the numeric ids GitLab needs for reviewers and assignees.
+    async fn resolve_user_ids(&self, usernames: &[String]) -> Result<Vec<u64>> {
+        let mut ids = Vec::with_capacity(usernames.len());
+        for username in usernames {
+            let url = format!(
+                "{}/users?username={}",
+                self.api_base_url,
+                encode_query_value(username)
+            );
+            let users: Vec<GitLabMember> = get_json(&self.client, &url).await?;
+            let user = users
+                .into_iter()
+                .find(|user| user.username.eq_ignore_ascii_case(username))
+                .with_context(|| format!("GitLab user '{}' not found", username))?;
+            ids.push(user.id);
+        }
+        Ok(ids)
+    }
+
+    /// Add reviewers to an MR, keeping the ones already assigned. GitLab has
+    /// no team reviewers, so `org/team` entries are skipped with a warning.
+    pub async fn request_reviewers(&self, number: u64, reviewers: &[String]) -> Result<()> {
+        let (users, teams) = split_reviewers(reviewers);
+        if !teams.is_empty() {
+            eprintln!(
+                "{} GitLab has no team reviewers — skipping {}.",
+                "warn:".yellow(),
+                teams.join(", ")
+            );
+        }
+        if users.is_empty() {
+            return Ok(());
+        }
+
+        let mr = self.get_mr(number).await?;
+        let mut ids: Vec<u64> = mr.reviewers.iter().map(|user| user.id).collect();
+        for id in self.resolve_user_ids(&users).await? {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        self.update_mr(number, &serde_json::json!({ "reviewer_ids": ids }))
+            .await
+            .context("Failed to request reviewers")
+    }
+
+    pub async fn get_requested_reviewers(&self, number: u64) -> Result<Vec<String>> {
+        let mr = self.get_mr(number).await?;
+        Ok(mr.reviewers.into_iter().map(|user| user.username).collect())
+    }
+
+    pub async fn add_labels(&self, number: u64, labels: &[String]) -> Result<()> {
+        if labels.is_empty() {
+            return Ok(());
+        }
+        self.update_mr(
+            number,
+            &serde_json::json!({ "add_labels": labels.join(",") }),
+        )
+        .await
+        .context("Failed to add labels")
+    }
+
+    /// Add assignees to an MR, keeping the ones already assigned.
+    pub async fn add_assignees(&self, number: u64, assignees: &[String]) -> Result<()> {
+        if assignees.is_empty() {
+            return Ok(());
+        }
+        let mr = self.get_mr(number).await?;
+        let mut ids: Vec<u64> = mr.assignees.iter().map(|user| user.id).collect();
+        for id in self.resolve_user_ids(assignees).await? {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        self.update_mr(number, &serde_json::json!({ "assignee_ids": ids }))
+            .await
+            .context("Failed to add assignees")
+    }
+
+    pub async fn close_pr(&self, number: u64) -> Result<()> {
+        self.update_mr(number, &serde_json::json!({ "state_event": "close" }))
+            .await
+            .context("Failed to close MR")
+    }
+
+    pub async fn reopen_pr(&self, number: u64) -> Result<()> {
+        self.update_mr(number, &serde_json::json!({ "state_event": "reopen" }))
+            .await
+            .context("Failed to reopen MR")
+    }
+
+    pub async fn create_issue_comment(&self, number: u64, body: &str) -> Result<()> {
+        let request = CreateNoteRequest { body };
+        let _: GitLabNote = post_json(
+            &self.client,
+            &self.project_url(&format!("/merge_requests/{}/notes", number)),
+            &request,
+        )
+        .await?;
+        Ok(())
+    }
+
     pub async fn update_stack_comment(&self, number: u64, stack_comment: &str) -> Result<()> {
         if let Some(note_id) = self.find_stack_comment_id(number).await? {
             let body = serde_json::json!({ "body": stack_comment_body(stack_comment) });
@@ -451,6 +588,8 @@ impl GitLabClient {
             approvals: 0,
             changes_requested: false,
             head_sha: mr.sha.unwrap_or_default(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         })
     }
 
@@ -567,6 +706,22 @@ impl GitLabClient {
             .collect())
     }
 
+    pub async fn get_issue(&self, number: u64) -> Result<RepoIssueListItem> {
+        let url = self.project_url(&format!("/issues/{}", number));
+        let issue: GitLabIssue = get_json(&self.client, &url).await?;
+        Ok(RepoIssueListItem {
+            number: issue.iid,
+            title: issue.title,
+            url: issue.web_url.unwrap_or_default(),
+            author: issue
+                .author
+                .map(|a| a.username)
+                .unwrap_or_else(|| "unknown".to_string()),
+            labels: issue.labels,
+            updated_at: issue.updated_at,
+        })
+    }
+
     pub async fn get_current_user(&self) -> Result<String> {
         let url = format!("{}/user", self.api_base_url);
         let user: GitLabUser = get_json(&self.client, &url).await?;
@@ -705,25 +860,6 @@ impl GitLabClient {
     }
 }
 
-/// Percent-encode a value for use in a URL query parameter.
-fn encode_query_value(value: &str) -> String {
-    use std::fmt::Write;
-    let mut encoded = String::with_capacity(value.len());
-    for byte in value.bytes() {
-        match byte {
-            // Unreserved characters (RFC 3986 section 2.3)
-            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
-                encoded.push(byte as char);
-            }
-            _ => {
-                // write! into a String is infallible
-                let _ = write!(encoded, "%{:02X}", byte);
-            }
-        }
-    }
-    encoded
-}
-
 fn normalize_gitlab_check_status(status: Option<&str>) -> String {
     match status.unwrap_or("") {
         "running" | "pending" | "created" => "in_progress".to_string(),
@@ -770,7 +906,7 @@ fn mr_to_pr_with_head(mr: GitLabMr) -> PrInfoWithHead {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::matchers::{body_json, header, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn ensure_crypto_provider() {
@@ -786,6 +922,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://gitlab.example.com".to_string(),
             api_base_url: Some(server.uri()),
+            request_timeout: None,
+            head_owner: None,
         }
     }
 
@@ -1108,4 +1246,144 @@ mod tests {
         assert_eq!(reviews[0].pr_number, 30);
         assert!(reviews[0].is_received);
     }
+
+    fn mr_json(reviewers: serde_json::Value) -> serde_json::Value {
+        serde_json::json!({
+            "iid": 40,
+            "title": "Feature",
+            "state": "opened",
+            "draft": false,
+            "source_branch": "feature",
+            "target_branch": "main",
+            "reviewers": reviewers
+        })
+    }
+
+    #[tokio::test]
+    async fn test_request_reviewers_keeps_existing_and_skips_teams() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        unsafe { std::env::set_var("STAX_GITLAB_TOKEN", "test-token") };
+
+        Mock::given(method("GET"))
+            .and(path("/projects/group%2Fsubgroup%2Frepo/merge_requests/40"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mr_json(
+                serde_json::json!([{ "id": 1, "username": "alice" }]),
+            )))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .and(query_param("username", "bob"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([{ "id": 2, "username": "bob" }])),
+            )
+            .mount(&server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/projects/group%2Fsubgroup%2Frepo/merge_requests/40"))
+            .and(body_json(serde_json::json!({ "reviewer_ids": [1, 2] })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(mr_json(serde_json::json!([
+                    { "id": 1, "username": "alice" },
+                    { "id": 2, "username": "bob" }
+                ]))),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GitLabClient::new(&remote_info(&server)).unwrap();
+        client
+            .request_reviewers(40, &["bob".to_string(), "org/team".to_string()])
+            .await
+            .unwrap();
+        let reviewers = client.get_requested_reviewers(40).await.unwrap();
+        assert_eq!(reviewers, vec!["alice".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_request_reviewers_fails_for_unknown_user() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        unsafe { std::env::set_var("STAX_GITLAB_TOKEN", "test-token") };
+
+        Mock::given(method("GET"))
+            .and(path("/projects/group%2Fsubgroup%2Frepo/merge_requests/40"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(mr_json(serde_json::json!([]))))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/users"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+
+        let client = GitLabClient::new(&remote_info(&server)).unwrap();
+        let err = client
+            .request_reviewers(40, &["ghost".to_string()])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("GitLab user 'ghost' not found"));
+    }
+
+    #[tokio::test]
+    async fn test_add_labels_and_close_reopen() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        unsafe { std::env::set_var("STAX_GITLAB_TOKEN", "test-token") };
+
+        for body in [
+            serde_json::json!({ "add_labels": "bug,stacked" }),
+            serde_json::json!({ "state_event": "close" }),
+            serde_json::json!({ "state_event": "reopen" }),
+        ] {
+            Mock::given(method("PUT"))
+                .and(path("/projects/group%2Fsubgroup%2Frepo/merge_requests/40"))
+                .and(body_json(body))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(mr_json(serde_json::json!([]))),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let client = GitLabClient::new(&remote_info(&server)).unwrap();
+        client
+            .add_labels(40, &["bug".to_string(), "stacked".to_string()])
+            .await
+            .unwrap();
+        client.close_pr(40).await.unwrap();
+        client.reopen_pr(40).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_comment_posts_note() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        unsafe { std::env::set_var("STAX_GITLAB_TOKEN", "test-token") };
+
+        Mock::given(method("POST"))
+            .and(path(
+                "/projects/group%2Fsubgroup%2Frepo/merge_requests/40/notes",
+            ))
+            .and(body_json(serde_json::json!({ "body": "Looks good" })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": 5,
+                "body": "Looks good",
+                "created_at": "2099-01-01T09:00:00Z",
+                "author": { "username": "alice" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = GitLabClient::new(&remote_info(&server)).unwrap();
+        client.create_issue_comment(40, "Looks good").await.unwrap();
+    }
 }
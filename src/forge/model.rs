@@ -75,8 +75,24 @@ pub struct PrInfoWithHead {
     pub title: String,
 }
 
+impl PrInfoWithHead {
+    /// Owner of the repo holding the PR's head branch, from an `owner:branch`
+    /// head label.
+    pub fn head_owner(&self) -> Option<&str> {
+        self.head_label
+            .as_deref()
+            .and_then(|label| label.split_once(':').map(|(owner, _)| owner))
+    }
+
+    /// The head owner when the PR comes from a fork of `base_owner`'s repo.
+    pub fn cross_repo_owner(&self, base_owner: &str) -> Option<&str> {
+        self.head_owner()
+            .filter(|owner| !owner.eq_ignore_ascii_case(base_owner))
+    }
+}
+
 /// Merge method for PRs
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum MergeMethod {
     #[default]
     Squash,
@@ -107,6 +123,32 @@ impl FromStr for MergeMethod {
     }
 }
 
+/// Merge methods a repository accepts, from its settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllowedMergeMethods {
+    pub squash: bool,
+    pub merge: bool,
+    pub rebase: bool,
+}
+
+impl AllowedMergeMethods {
+    pub fn allows(&self, method: MergeMethod) -> bool {
+        match method {
+            MergeMethod::Squash => self.squash,
+            MergeMethod::Merge => self.merge,
+            MergeMethod::Rebase => self.rebase,
+        }
+    }
+
+    /// Allowed methods, in the order stax prefers them as a default.
+    pub fn methods(&self) -> Vec<MergeMethod> {
+        [MergeMethod::Squash, MergeMethod::Rebase, MergeMethod::Merge]
+            .into_iter()
+            .filter(|method| self.allows(*method))
+            .collect()
+    }
+}
+
 /// CI check status
 #[derive(Debug, Clone, PartialEq)]
 pub enum CiStatus {
@@ -173,6 +215,10 @@ pub struct PrMergeStatus {
     pub approvals: usize,
     pub changes_requested: bool,
     pub head_sha: String,
+    /// Approving reviews the base branch protection requires (0 if unknown)
+    pub required_approvals: usize,
+    /// Status checks the base branch protection requires that have not reported yet
+    pub missing_required_checks: Vec<String>,
 }
 
 impl PrMergeStatus {
@@ -183,19 +229,30 @@ impl PrMergeStatus {
             && self.mergeable.unwrap_or(false)
             && !self.changes_requested
             && self.state.to_lowercase() == "open"
+            && self.meets_branch_protection()
     }
 
-    /// Check if PR is waiting (CI pending or mergeable computing)
+    /// Check if the base branch protection's required reviews and checks are in
+    pub fn meets_branch_protection(&self) -> bool {
+        self.approvals >= self.required_approvals && self.missing_required_checks.is_empty()
+    }
+
+    /// Check if PR is waiting (CI pending, mergeable computing, or required
+    /// checks that have not reported yet)
     pub fn is_waiting(&self) -> bool {
-        self.ci_status.is_pending() || self.mergeable.is_none()
+        self.ci_status.is_pending()
+            || self.mergeable.is_none()
+            || !self.missing_required_checks.is_empty()
     }
 
-    /// Check if PR has a blocking issue
+    /// Check if PR has a blocking issue. Missing required approvals block:
+    /// waiting will not produce a review.
     pub fn is_blocked(&self) -> bool {
         self.ci_status.is_failure()
             || self.changes_requested
             || self.is_draft
             || self.mergeable == Some(false)
+            || self.approvals < self.required_approvals
     }
 
     /// Get human-readable status
@@ -215,9 +272,15 @@ impl PrMergeStatus {
         if self.mergeable == Some(false) {
             return "Has conflicts";
         }
-        if self.is_waiting() {
+        if self.approvals < self.required_approvals {
+            return "Review approval";
+        }
+        if self.ci_status.is_pending() || self.mergeable.is_none() {
             return "Waiting";
         }
+        if !self.missing_required_checks.is_empty() {
+            return "Required checks";
+        }
         if self.is_ready() {
             return "Ready";
         }
@@ -236,6 +299,16 @@ pub struct MergeQueueEntry {
     pub position: Option<u32>,
 }
 
+/// Where a PR stands in the merge queue.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeQueueStatus {
+    pub merged: bool,
+    /// Whether the PR is still queued; `None` when the forge does not report
+    /// it (GitLab merge trains).
+    pub in_queue: Option<bool>,
+    pub position: Option<u32>,
+}
+
 /// Open PR info for tracking command
 #[derive(Debug, Clone)]
 pub struct OpenPrInfo {
@@ -280,6 +353,25 @@ pub struct RepoPrListItem {
     pub created_at: DateTime<Utc>,
 }
 
+/// A single pull request with the details `stax pr view` shows.
+#[derive(Debug, Clone, Serialize)]
+pub struct PrDetails {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    /// `OPEN`, `CLOSED` or `MERGED`.
+    pub state: String,
+    pub is_draft: bool,
+    pub author: String,
+    pub head_branch: String,
+    pub base_branch: String,
+    pub head_sha: String,
+    pub labels: Vec<String>,
+    /// Requested reviewers; teams are `@org/team`.
+    pub reviewers: Vec<String>,
+    pub body: String,
+}
+
 /// Open issue info for repo-level listing commands.
 #[derive(Debug, Clone, Serialize)]
 pub struct RepoIssueListItem {
@@ -4,10 +4,13 @@ use std::collections::HashMap;
 use crate::ci::CheckRunInfo;
 use crate::forge::model::*;
 
-/// Forge-neutral seam over the concrete forge clients (GitHub, GitLab, Gitea).
+/// Forge-neutral seam over the concrete forge clients (GitHub, GitLab, Gitea,
+/// Bitbucket, Azure DevOps).
 ///
 /// Every method mirrors the existing inherent method on each concrete client
-/// so the trait can be introduced without changing behavior.
+/// so the trait can be introduced without changing behavior. Command helpers
+/// that only talk to the forge should take `&impl Forge` rather than
+/// `&ForgeClient` so they can be exercised against `FakeForge` in tests.
 #[allow(async_fn_in_trait)]
 pub trait Forge {
     async fn find_open_pr_by_head(&self, branch: &str) -> Result<Option<PrInfoWithHead>>;
@@ -15,6 +18,7 @@ pub trait Forge {
     async fn list_open_prs_by_head(&self) -> Result<HashMap<String, PrInfoWithHead>>;
     async fn list_open_pull_requests(&self, limit: u8) -> Result<Vec<RepoPrListItem>>;
     async fn list_open_issues(&self, limit: u8) -> Result<Vec<RepoIssueListItem>>;
+    async fn get_issue(&self, number: u64) -> Result<RepoIssueListItem>;
     async fn create_pr(
         &self,
         head: &str,
@@ -36,6 +40,7 @@ pub trait Forge {
     async fn create_stack_comment(&self, number: u64, stack_comment: &str) -> Result<()>;
     async fn create_issue_comment(&self, number: u64, body: &str) -> Result<()>;
     async fn close_pr(&self, number: u64) -> Result<()>;
+    async fn reopen_pr(&self, number: u64) -> Result<()>;
     async fn delete_stack_comment(&self, number: u64) -> Result<()>;
     async fn list_all_comments(&self, number: u64) -> Result<Vec<PrComment>>;
     async fn merge_pr(
@@ -70,10 +75,17 @@ pub trait Forge {
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
+    use std::cell::RefCell;
 
     #[derive(Default)]
     pub(crate) struct FakeForge {
         pub(crate) open_pr_by_head: Option<PrInfoWithHead>,
+        /// Returned by `get_pr`/`get_pr_with_head`; the base tracks `update_pr_base`.
+        pub(crate) pr_with_head: RefCell<Option<PrInfoWithHead>>,
+        pub(crate) merge_status: Option<PrMergeStatus>,
+        /// Error message returned by `update_pr_base` instead of applying it.
+        pub(crate) update_pr_base_error: Option<String>,
+        pub(crate) base_updates: RefCell<Vec<(u64, String)>>,
     }
 
     impl Forge for FakeForge {
@@ -97,6 +109,9 @@ pub(crate) mod tests {
         async fn list_open_issues(&self, _limit: u8) -> Result<Vec<RepoIssueListItem>> {
             anyhow::bail!("unused in fake")
         }
+        async fn get_issue(&self, _number: u64) -> Result<RepoIssueListItem> {
+            anyhow::bail!("unused in fake")
+        }
         async fn create_pr(
             &self,
             _head: &str,
@@ -107,14 +122,26 @@ pub(crate) mod tests {
         ) -> Result<PrInfo> {
             anyhow::bail!("unused in fake")
         }
-        async fn get_pr(&self, _number: u64) -> Result<PrInfo> {
-            anyhow::bail!("unused in fake")
+        async fn get_pr(&self, number: u64) -> Result<PrInfo> {
+            Ok(self.get_pr_with_head(number).await?.info)
         }
         async fn get_pr_with_head(&self, _number: u64) -> Result<PrInfoWithHead> {
-            anyhow::bail!("unused in fake")
-        }
-        async fn update_pr_base(&self, _number: u64, _new_base: &str) -> Result<()> {
-            anyhow::bail!("unused in fake")
+            self.pr_with_head
+                .borrow()
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("no PR configured in fake"))
+        }
+        async fn update_pr_base(&self, number: u64, new_base: &str) -> Result<()> {
+            if let Some(message) = &self.update_pr_base_error {
+                anyhow::bail!("{}", message);
+            }
+            self.base_updates
+                .borrow_mut()
+                .push((number, new_base.to_string()));
+            if let Some(pr) = self.pr_with_head.borrow_mut().as_mut() {
+                pr.info.base = new_base.to_string();
+            }
+            Ok(())
         }
         async fn set_pr_draft(&self, _number: u64, _is_draft: bool) -> Result<()> {
             anyhow::bail!("unused in fake")
@@ -146,6 +173,9 @@ pub(crate) mod tests {
         async fn close_pr(&self, _number: u64) -> Result<()> {
             anyhow::bail!("unused in fake")
         }
+        async fn reopen_pr(&self, _number: u64) -> Result<()> {
+            anyhow::bail!("unused in fake")
+        }
         async fn delete_stack_comment(&self, _number: u64) -> Result<()> {
             anyhow::bail!("unused in fake")
         }
@@ -162,7 +192,9 @@ pub(crate) mod tests {
             anyhow::bail!("unused in fake")
         }
         async fn get_pr_merge_status(&self, _number: u64) -> Result<PrMergeStatus> {
-            anyhow::bail!("unused in fake")
+            self.merge_status
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("no merge status configured in fake"))
         }
         async fn get_pr_review_decision(&self, _number: u64) -> Result<Option<String>> {
             anyhow::bail!("unused in fake")
@@ -2,4 +2,7 @@ pub(crate) mod command;
 pub mod refs;
 pub mod repo;
 
-pub use repo::{GitRepo, RebaseResult, RebaseTimings, checkout_branch_in, local_branch_exists_in};
+pub use repo::{
+    ConflictPrediction, GitRepo, RebaseResult, RebaseTimings, checkout_branch_in,
+    local_branch_exists_in,
+};
@@ -154,6 +154,29 @@ pub fn write_trunk(repo: &Repository, trunk: &str) -> Result<()> {
     Ok(())
 }
 
+/// Git config key holding a trunk pinned with `stax config set-trunk`
+const STAX_EXPLICIT_TRUNK_KEY: &str = "stax.trunk";
+
+/// Read the explicitly pinned trunk branch, if any (repo-local git config)
+pub fn read_explicit_trunk(repo: &Repository) -> Result<Option<String>> {
+    let config = repo.config()?.open_level(git2::ConfigLevel::Local)?;
+    match config.get_string(STAX_EXPLICIT_TRUNK_KEY) {
+        Ok(trunk) if !trunk.trim().is_empty() => Ok(Some(trunk.trim().to_string())),
+        Ok(_) => Ok(None),
+        Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Pin the trunk branch so it takes precedence over detection
+pub fn write_explicit_trunk(repo: &Repository, trunk: &str) -> Result<()> {
+    let mut config = repo.config()?.open_level(git2::ConfigLevel::Local)?;
+    config
+        .set_str(STAX_EXPLICIT_TRUNK_KEY, trunk)
+        .context("Failed to write explicit trunk to git config")?;
+    Ok(())
+}
+
 /// Read the previous branch (for `stax prev` command)
 pub fn read_prev_branch(repo: &Repository) -> Result<Option<String>> {
     match repo.find_reference(STAX_PREV_BRANCH_REF) {
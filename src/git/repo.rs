@@ -1062,9 +1062,15 @@ impl GitRepo {
         Ok(obj.id())
     }
 
-    /// Get the trunk branch name (from stored setting or auto-detect main/master)
+    /// Get the trunk branch name (explicit override, stored setting, or auto-detect main/master)
     pub fn trunk_branch(&self) -> Result<String> {
-        // First check if trunk is stored
+        // An explicitly pinned trunk wins over everything else while it exists
+        if let Some(trunk) = self.explicit_trunk()?
+            && self.repo.find_branch(&trunk, BranchType::Local).is_ok()
+        {
+            return Ok(trunk);
+        }
+        // Then check if trunk is stored
         if let Some(trunk) = super::refs::read_trunk(&self.repo)? {
             // Validate the stored trunk branch actually exists locally
             if self.repo.find_branch(&trunk, BranchType::Local).is_ok() {
@@ -1078,6 +1084,11 @@ impl GitRepo {
         Ok(detected)
     }
 
+    /// Trunk pinned with `stax config set-trunk`, if any
+    pub fn explicit_trunk(&self) -> Result<Option<String>> {
+        super::refs::read_explicit_trunk(&self.repo)
+    }
+
     /// Auto-detect trunk branch (main or master)
     pub fn detect_trunk(&self) -> Result<String> {
         for name in ["main", "master"] {
@@ -1576,6 +1587,68 @@ Use --auto-stash-pop or stash/commit changes first.",
         self.rebase_branch_onto_with_provenance(branch, onto, "", auto_stash_pop)
     }
 
+    /// Bring `branch` up to date by merging `parent` into it instead of rebasing,
+    /// using the branch's owning worktree. Existing commits keep their IDs; a
+    /// conflict leaves the merge in progress for the user to resolve and commit.
+    pub fn merge_parent_into_branch(
+        &self,
+        branch: &str,
+        parent: &str,
+        auto_stash_pop: bool,
+    ) -> Result<RebaseResult> {
+        let (_current_workdir, target_workdir) = self.prepare_branch_rebase_context(branch)?;
+
+        let mut stashed = false;
+        if self.is_dirty_at(&target_workdir)? {
+            if !auto_stash_pop {
+                anyhow::bail!(
+                    "Cannot restack '{}': worktree '{}' has uncommitted changes. \
+Use --auto-stash-pop or stash/commit changes first.",
+                    branch,
+                    target_workdir.display()
+                );
+            }
+            stashed = self.stash_push_at(&target_workdir)?;
+        }
+
+        let message = format!("Merge {} into {}", parent, branch);
+        let output = self.run_git(
+            &target_workdir,
+            &["merge", "--no-edit", "-m", &message, parent],
+        )?;
+        if !output.status.success() {
+            if self.merge_in_progress_in(&target_workdir)? {
+                return Ok(RebaseResult::Conflict);
+            }
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            let err = anyhow::anyhow!(
+                "git merge {} failed in '{}': {}",
+                parent,
+                target_workdir.display(),
+                stderr
+            );
+            if stashed {
+                return Err(err.context(format!(
+                    "Auto-stash was kept in '{}' due to merge failure.",
+                    target_workdir.display()
+                )));
+            }
+            return Err(err);
+        }
+
+        if stashed {
+            self.stash_pop_at(&target_workdir).with_context(|| {
+                format!(
+                    "Merged '{}' into '{}' successfully, but failed to auto-pop stash in '{}'",
+                    parent,
+                    branch,
+                    target_workdir.display()
+                )
+            })?;
+        }
+        Ok(RebaseResult::Success)
+    }
+
     /// Continue a rebase after resolving conflicts
     pub fn rebase_continue(&self) -> Result<RebaseResult> {
         let status = Command::new("git")
@@ -1797,6 +1870,27 @@ Use --auto-stash-pop or stash/commit changes first.",
         Ok(commits)
     }
 
+    /// Get the `limit` most recent commits reachable from a branch, newest first
+    pub fn recent_commits(&self, branch: &str, limit: usize) -> Result<Vec<CommitInfo>> {
+        let branch_ref = self.repo.find_branch(branch, BranchType::Local)?;
+        let branch_commit = branch_ref.get().peel_to_commit()?;
+
+        let mut revwalk = self.repo.revwalk()?;
+        revwalk.push(branch_commit.id())?;
+
+        revwalk
+            .take(limit)
+            .map(|oid| {
+                let oid = oid?;
+                let commit = self.repo.find_commit(oid)?;
+                Ok(CommitInfo {
+                    short_hash: oid.to_string()[..10].to_string(),
+                    message: commit.summary().ok().flatten().unwrap_or("").to_string(),
+                })
+            })
+            .collect()
+    }
+
     /// Get time since last commit on a branch
     pub fn branch_age(&self, branch: &str) -> Result<String> {
         let branch_ref = self.repo.find_branch(branch, BranchType::Local)?;
@@ -2113,6 +2207,40 @@ Use --auto-stash-pop or stash/commit changes first.",
         Ok(results)
     }
 
+    /// Paths a branch changes relative to its merge base with `parent`; a
+    /// rename lists both the old and the new path.
+    pub fn diff_paths(&self, branch: &str, parent: &str) -> Result<Vec<String>> {
+        let target = self.resolve_diff_target(branch, parent)?;
+        let output = command::output(
+            self.workdir()?,
+            &[
+                "diff",
+                "--name-only",
+                "-z",
+                "--no-renames",
+                &target.merge_base_oid,
+                &target.branch_oid,
+            ],
+        )
+        .context("Failed to list changed paths")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+            anyhow::bail!(
+                "git diff --name-only for branch '{}' against parent '{}' failed: {}",
+                branch,
+                parent,
+                stderr
+            );
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .split('\0')
+            .filter(|path| !path.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
     /// Get all branches that are merged into trunk (excluding trunk itself)
     pub fn merged_branches(&self) -> Result<Vec<String>> {
         let trunk = self.trunk_branch()?;
@@ -2138,56 +2266,43 @@ Use --auto-stash-pop or stash/commit changes first.",
     /// Uses git merge-tree to detect potential conflicts without actually rebasing
     /// Returns a list of files that would have conflicts
     pub fn check_rebase_conflicts(&self, branch: &str, onto: &str) -> Result<Vec<String>> {
-        // Get the merge base between the branch and onto target
-        let merge_base = match self.merge_base(onto, branch) {
-            Ok(base) => base,
-            Err(_) => return Ok(Vec::new()),
-        };
+        if self.merge_base_refs(onto, branch).is_err() {
+            return Ok(Vec::new());
+        }
 
-        // Use git merge-tree to check for conflicts
-        // git merge-tree --write-tree <base> <onto> <branch>
+        // `git merge-tree --write-tree --name-only <onto> <branch>` prints the
+        // result tree, then one conflicted path per line up to a blank line.
+        // It exits 1 on conflicts and writes no refs or working tree files.
         let output = self.run_git(
             self.workdir()?,
             &[
                 "merge-tree",
                 "--write-tree",
+                "--name-only",
                 "--no-messages",
-                merge_base.as_str(),
                 onto,
                 branch,
             ],
         )?;
 
-        // If the command fails (non-zero exit), there are conflicts
-        // The output will contain the conflicting files
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            let stdout = String::from_utf8_lossy(&output.stdout);
-
-            // Parse conflict information from output
-            let mut conflict_files = Vec::new();
-
-            // The output format typically shows conflicting files
-            for line in stdout.lines().chain(stderr.lines()) {
-                // Look for lines indicating conflicts (file paths in merge conflicts)
-                if line.contains("CONFLICT") {
-                    // Extract filename from conflict message
-                    // Format: "CONFLICT (content): Merge conflict in <file>"
-                    if let Some(file) = line.split("Merge conflict in ").nth(1) {
-                        conflict_files.push(file.trim().to_string());
-                    } else if let Some(file) = line.split("CONFLICT (").nth(1) {
-                        // Other conflict formats
-                        if let Some(f) = file.split("):").nth(1) {
-                            conflict_files.push(f.trim().to_string());
-                        }
-                    }
-                }
+        match output.status.code() {
+            Some(0) => Ok(Vec::new()),
+            Some(1) => {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let mut conflict_files: Vec<String> = stdout
+                    .lines()
+                    .skip(1)
+                    .take_while(|line| !line.trim().is_empty())
+                    .map(|line| line.trim().to_string())
+                    .collect();
+                conflict_files.dedup();
+                Ok(conflict_files)
+            }
+            _ => {
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                anyhow::bail!("git merge-tree failed: {}", stderr);
             }
-
-            return Ok(conflict_files);
         }
-
-        Ok(Vec::new())
     }
 
     /// Predict conflicts for multiple branches before restacking.
@@ -2444,7 +2559,7 @@ pub struct ConflictPrediction {
     pub conflicting_files: Vec<String>,
 }
 
-fn format_duration(seconds: i64) -> String {
+pub(crate) fn format_duration(seconds: i64) -> String {
     if seconds < 60 {
         "just now".to_string()
     } else if seconds < 3600 {
@@ -0,0 +1,158 @@
+//! GitHub App authentication for bot-driven automation.
+//!
+//! When `STAX_GITHUB_APP_ID` is set, stax authenticates as an installation of
+//! that app instead of with a personal token. Octocrab mints the installation
+//! token on first use and mints a new one shortly before it expires, so long
+//! waits like `stax merge --when-ready` keep working past the one-hour token
+//! lifetime.
+
+use anyhow::{Context, Result, bail};
+use jsonwebtoken::EncodingKey;
+use std::fs;
+
+pub const APP_ID_ENV: &str = "STAX_GITHUB_APP_ID";
+pub const INSTALLATION_ID_ENV: &str = "STAX_GITHUB_APP_INSTALLATION_ID";
+pub const PRIVATE_KEY_ENV: &str = "STAX_GITHUB_APP_PRIVATE_KEY";
+pub const PRIVATE_KEY_PATH_ENV: &str = "STAX_GITHUB_APP_PRIVATE_KEY_PATH";
+
+#[derive(Clone)]
+pub struct GitHubAppCredentials {
+    pub app_id: u64,
+    pub installation_id: u64,
+    private_key_pem: String,
+}
+
+impl std::fmt::Debug for GitHubAppCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GitHubAppCredentials")
+            .field("app_id", &self.app_id)
+            .field("installation_id", &self.installation_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GitHubAppCredentials {
+    /// Read app credentials from the environment.
+    ///
+    /// Returns `None` when `STAX_GITHUB_APP_ID` is unset, and an error when it
+    /// is set but the installation id or private key is missing or invalid.
+    pub fn from_env() -> Result<Option<Self>> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    pub(crate) fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Option<Self>> {
+        let read = |name: &str| {
+            lookup(name)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let Some(app_id) = read(APP_ID_ENV) else {
+            return Ok(None);
+        };
+        let app_id = app_id
+            .parse()
+            .with_context(|| format!("{} must be a numeric app id", APP_ID_ENV))?;
+        let installation_id = read(INSTALLATION_ID_ENV)
+            .with_context(|| {
+                format!(
+                    "{} is set but {} is missing",
+                    APP_ID_ENV, INSTALLATION_ID_ENV
+                )
+            })?
+            .parse()
+            .with_context(|| {
+                format!("{} must be a numeric installation id", INSTALLATION_ID_ENV)
+            })?;
+
+        let private_key_pem = match (read(PRIVATE_KEY_ENV), read(PRIVATE_KEY_PATH_ENV)) {
+            // CI secrets often store the PEM with literal `\n` escapes.
+            (Some(pem), _) => pem.replace("\\n", "\n"),
+            (None, Some(path)) => fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read GitHub App private key at {}", path))?,
+            (None, None) => bail!(
+                "{} is set but neither {} nor {} is",
+                APP_ID_ENV,
+                PRIVATE_KEY_ENV,
+                PRIVATE_KEY_PATH_ENV
+            ),
+        };
+
+        let credentials = Self {
+            app_id,
+            installation_id,
+            private_key_pem,
+        };
+        credentials.encoding_key()?;
+        Ok(Some(credentials))
+    }
+
+    pub fn encoding_key(&self) -> Result<EncodingKey> {
+        EncodingKey::from_rsa_pem(self.private_key_pem.as_bytes())
+            .context("GitHub App private key is not a valid RSA PEM key")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    // Throwaway 2048-bit key used only to exercise PEM parsing.
+    const TEST_RSA_PEM: &str = include_str!("testdata/app_private_key.pem");
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn from_lookup_is_none_without_app_id() {
+        assert!(
+            GitHubAppCredentials::from_lookup(lookup(&[]))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn from_lookup_reads_inline_key_with_escaped_newlines() {
+        let escaped = TEST_RSA_PEM.replace('\n', "\\n");
+        let credentials = GitHubAppCredentials::from_lookup(lookup(&[
+            (APP_ID_ENV, "123"),
+            (INSTALLATION_ID_ENV, "456"),
+            (PRIVATE_KEY_ENV, &escaped),
+        ]))
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(credentials.app_id, 123);
+        assert_eq!(credentials.installation_id, 456);
+    }
+
+    #[test]
+    fn from_lookup_requires_installation_id_and_key() {
+        let err = GitHubAppCredentials::from_lookup(lookup(&[(APP_ID_ENV, "123")])).unwrap_err();
+        assert!(err.to_string().contains(INSTALLATION_ID_ENV));
+
+        let err = GitHubAppCredentials::from_lookup(lookup(&[
+            (APP_ID_ENV, "123"),
+            (INSTALLATION_ID_ENV, "456"),
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains(PRIVATE_KEY_PATH_ENV));
+    }
+
+    #[test]
+    fn from_lookup_rejects_invalid_key() {
+        let err = GitHubAppCredentials::from_lookup(lookup(&[
+            (APP_ID_ENV, "123"),
+            (INSTALLATION_ID_ENV, "456"),
+            (PRIVATE_KEY_ENV, "not a key"),
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("not a valid RSA PEM key"));
+    }
+}
@@ -1,4 +1,5 @@
 use super::client::GitHubClient;
+use super::retry::with_backoff;
 use crate::ci::{CheckRunInfo, history, normalize};
 use crate::git::GitRepo;
 use anyhow::Result;
@@ -65,7 +66,7 @@ impl GitHubClient {
         );
 
         let statuses: Vec<normalize::CommitStatus> =
-            match self.octocrab.get(&url, None::<&()>).await {
+            match with_backoff(self.quiet(), || self.get_json(&url)).await {
                 Ok(s) => s,
                 Err(_) => return Ok((None, Vec::new())),
             };
@@ -125,7 +126,8 @@ impl GitHubClient {
             self.owner, self.repo, commit_sha
         );
 
-        let response: CheckRunsResponse = self.octocrab.get(&url, None::<&()>).await?;
+        let response: CheckRunsResponse =
+            with_backoff(self.quiet(), || self.get_json(&url)).await?;
 
         if response.total_count == 0 {
             return Ok((None, Vec::new()));
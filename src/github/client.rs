@@ -1,28 +1,53 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
-use octocrab::Octocrab;
+use http::StatusCode;
+use http::header::{ETAG, HeaderMap, HeaderValue, IF_NONE_MATCH};
+use octocrab::models::{AppId, InstallationId};
 use octocrab::params::repos::Reference;
-use octocrab::service::middleware::retry::RetryConfig;
+use octocrab::{
+    DefaultOctocrabBuilderConfig, NoAuth, NoSvc, NotLayerReady, Octocrab, OctocrabBuilder,
+};
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+use crate::cache::{ApiResponseCache, CachedApiResponse};
 use crate::config::{Config, GitHubAuthSource};
-use crate::forge::{PrActivity, RepoIssueListItem, RepoPrListItem, ReviewActivity};
+use crate::forge::{
+    AllowedMergeMethods, PrActivity, PrDetails, RepoIssueListItem, RepoPrListItem, ReviewActivity,
+};
+use crate::github::app_auth::GitHubAppCredentials;
+use crate::github::retry;
 
 const GITHUB_API_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 const GITHUB_API_READ_TIMEOUT: Duration = Duration::from_secs(30);
 const GITHUB_API_WRITE_TIMEOUT: Duration = Duration::from_secs(30);
-const GITHUB_API_RETRY_COUNT: usize = 1;
 
 pub struct GitHubClient {
     pub octocrab: Octocrab,
     pub owner: String,
     pub repo: String,
+    /// Fork owner that PR heads are pushed to (`remote.push_remote`), if any.
+    head_owner: Option<String>,
     auth_source: Option<GitHubAuthSource>,
     api_call_tracker: Arc<ApiCallTracker>,
+    /// Git dir holding the ETag cache for conditional PR lookups, if enabled.
+    response_cache: Option<PathBuf>,
+    /// Base branch protection already looked up, so status polls read it once.
+    branch_protection: Arc<Mutex<HashMap<String, Option<BranchProtection>>>>,
+    /// Hide retry wait notices (`--quiet`).
+    quiet: bool,
+}
+
+/// Merge requirements from a base branch's protection rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BranchProtection {
+    pub required_checks: Vec<String>,
+    pub required_approvals: usize,
 }
 
 impl Clone for GitHubClient {
@@ -31,8 +56,12 @@ impl Clone for GitHubClient {
             octocrab: self.octocrab.clone(),
             owner: self.owner.clone(),
             repo: self.repo.clone(),
+            head_owner: self.head_owner.clone(),
             auth_source: self.auth_source,
             api_call_tracker: self.api_call_tracker.clone(),
+            response_cache: self.response_cache.clone(),
+            branch_protection: self.branch_protection.clone(),
+            quiet: self.quiet,
         }
     }
 }
@@ -76,6 +105,34 @@ impl ApiCallTracker {
     }
 }
 
+/// Merge settings from the repository API. GitHub leaves them out for tokens
+/// without push access, so a missing field means "unknown".
+#[derive(Debug, Deserialize)]
+struct RepoMergeSettings {
+    allow_squash_merge: Option<bool>,
+    allow_merge_commit: Option<bool>,
+    allow_rebase_merge: Option<bool>,
+}
+
+/// Branch protection from the REST API; only the parts readiness needs.
+#[derive(Debug, Deserialize)]
+struct BranchProtectionResponse {
+    required_status_checks: Option<RequiredStatusChecks>,
+    required_pull_request_reviews: Option<RequiredPullRequestReviews>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RequiredStatusChecks {
+    #[serde(default)]
+    contexts: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RequiredPullRequestReviews {
+    #[serde(default)]
+    required_approving_review_count: usize,
+}
+
 /// Response from the check-runs API
 #[derive(Debug, Deserialize)]
 struct CheckRunsResponse {
@@ -145,6 +202,38 @@ struct RepoListPullRequest {
     created_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Deserialize)]
+struct RepoPullRequestHead {
+    #[serde(rename = "ref")]
+    ref_field: String,
+    sha: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepoPullRequestTeam {
+    slug: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepoPullRequestDetails {
+    number: u64,
+    title: String,
+    html_url: String,
+    user: RepoListUser,
+    head: RepoPullRequestHead,
+    base: RepoListPullRef,
+    state: String,
+    draft: Option<bool>,
+    merged_at: Option<String>,
+    #[serde(default)]
+    labels: Vec<RepoListLabel>,
+    #[serde(default)]
+    requested_reviewers: Vec<RepoListUser>,
+    #[serde(default)]
+    requested_teams: Vec<RepoPullRequestTeam>,
+    body: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct RepoListLabel {
     name: Option<String>,
@@ -163,58 +252,130 @@ struct RepoListIssue {
 
 impl GitHubClient {
     /// Create a new GitHub client from config
-    pub fn new(owner: &str, repo: &str, api_base_url: Option<String>) -> Result<Self> {
-        let (auth_source, token) = Config::github_token_with_source().context(
+    pub fn new(
+        owner: &str,
+        repo: &str,
+        host: &str,
+        api_base_url: Option<String>,
+        request_timeout: Option<Duration>,
+    ) -> Result<Self> {
+        if let Some(app) = GitHubAppCredentials::from_env()? {
+            return Self::new_with_app(owner, repo, api_base_url, request_timeout, &app);
+        }
+        let (auth_source, token) = Config::github_token_with_source(Some(host)).context(
             "GitHub auth not configured. Use one of: `stax auth`, `stax auth --from-gh`, \
              `gh auth login`, or set `STAX_GITHUB_TOKEN`.",
         )?;
-        Self::new_with_auth(owner, repo, api_base_url, auth_source, token)
+        Self::new_with_auth(
+            owner,
+            repo,
+            api_base_url,
+            request_timeout,
+            auth_source,
+            token,
+        )
     }
 
     pub(crate) fn new_for_trusted_remote(
         owner: &str,
         repo: &str,
         api_base_url: Option<String>,
+        request_timeout: Option<Duration>,
         config: &Config,
         validated_remote_host: &str,
     ) -> Result<Self> {
+        if let Some(app) = GitHubAppCredentials::from_env()? {
+            return Self::new_with_app(owner, repo, api_base_url, request_timeout, &app);
+        }
         let (auth_source, token) = config
             .github_token_with_source_for_host(validated_remote_host)?
             .context(
                 "GitHub auth not configured. Use one of: `stax auth`, `stax auth --from-gh`, \
                  `gh auth login`, or set `STAX_GITHUB_TOKEN`.",
             )?;
-        Self::new_with_auth(owner, repo, api_base_url, auth_source, token)
+        Self::new_with_auth(
+            owner,
+            repo,
+            api_base_url,
+            request_timeout,
+            auth_source,
+            token,
+        )
     }
 
     fn new_with_auth(
         owner: &str,
         repo: &str,
         api_base_url: Option<String>,
+        request_timeout: Option<Duration>,
         auth_source: GitHubAuthSource,
         token: String,
     ) -> Result<Self> {
-        let mut builder = Octocrab::builder()
+        let octocrab = Self::octocrab_builder(api_base_url, request_timeout)?
             .personal_token(token)
-            .add_retry_config(RetryConfig::Simple(GITHUB_API_RETRY_COUNT))
-            .set_connect_timeout(Some(GITHUB_API_CONNECT_TIMEOUT))
-            .set_read_timeout(Some(GITHUB_API_READ_TIMEOUT))
-            .set_write_timeout(Some(GITHUB_API_WRITE_TIMEOUT));
+            .build()
+            .context("Failed to create GitHub client")?;
+        Ok(Self::from_parts(octocrab, owner, repo, auth_source))
+    }
+
+    /// Authenticate as a GitHub App installation. Octocrab mints the
+    /// installation token lazily and replaces it before it expires.
+    fn new_with_app(
+        owner: &str,
+        repo: &str,
+        api_base_url: Option<String>,
+        request_timeout: Option<Duration>,
+        app: &GitHubAppCredentials,
+    ) -> Result<Self> {
+        let octocrab = Self::octocrab_builder(api_base_url, request_timeout)?
+            .app(AppId(app.app_id), app.encoding_key()?)
+            .build()
+            .context("Failed to create GitHub client")?
+            .installation(InstallationId(app.installation_id))
+            .context("Failed to authenticate as GitHub App installation")?;
+        Ok(Self::from_parts(
+            octocrab,
+            owner,
+            repo,
+            GitHubAuthSource::GitHubApp,
+        ))
+    }
+
+    fn octocrab_builder(
+        api_base_url: Option<String>,
+        request_timeout: Option<Duration>,
+    ) -> Result<OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>> {
+        // An explicit timeout caps every phase so a stalled request fails fast.
+        let mut builder = Octocrab::builder()
+            .add_retry_config(retry::octocrab_retry_config())
+            .set_connect_timeout(Some(request_timeout.unwrap_or(GITHUB_API_CONNECT_TIMEOUT)))
+            .set_read_timeout(Some(request_timeout.unwrap_or(GITHUB_API_READ_TIMEOUT)))
+            .set_write_timeout(Some(request_timeout.unwrap_or(GITHUB_API_WRITE_TIMEOUT)));
         if let Some(api_base) = api_base_url {
             builder = builder
                 .base_uri(api_base)
                 .context("Failed to set GitHub API base URL")?;
         }
+        Ok(builder)
+    }
 
-        let octocrab = builder.build().context("Failed to create GitHub client")?;
-
-        Ok(Self {
+    fn from_parts(
+        octocrab: Octocrab,
+        owner: &str,
+        repo: &str,
+        auth_source: GitHubAuthSource,
+    ) -> Self {
+        Self {
             octocrab,
             owner: owner.to_string(),
             repo: repo.to_string(),
+            head_owner: None,
             auth_source: Some(auth_source),
             api_call_tracker: Arc::new(ApiCallTracker::default()),
-        })
+            response_cache: None,
+            branch_protection: Arc::default(),
+            quiet: false,
+        }
     }
 
     /// Create a new GitHub client with a custom Octocrab instance (for testing)
@@ -224,11 +385,43 @@ impl GitHubClient {
             octocrab,
             owner: owner.to_string(),
             repo: repo.to_string(),
+            head_owner: None,
             auth_source: None,
             api_call_tracker: Arc::new(ApiCallTracker::default()),
+            response_cache: None,
+            branch_protection: Arc::default(),
+            quiet: false,
         }
     }
 
+    /// Qualify PR heads with a fork owner (triangular fork workflow).
+    pub fn with_head_owner(mut self, head_owner: Option<String>) -> Self {
+        self.head_owner = head_owner;
+        self
+    }
+
+    /// Revalidate PR lookups against the ETag cache in `git_dir` instead of
+    /// re-downloading unchanged responses.
+    pub fn with_response_cache(mut self, git_dir: Option<PathBuf>) -> Self {
+        self.response_cache = git_dir;
+        self
+    }
+
+    /// Hide the notices printed while a retried call waits.
+    pub fn with_quiet(mut self, quiet: bool) -> Self {
+        self.quiet = quiet;
+        self
+    }
+
+    pub(crate) fn quiet(&self) -> bool {
+        self.quiet
+    }
+
+    /// Owner of the repository that PR head branches live in.
+    pub fn pr_head_owner(&self) -> &str {
+        self.head_owner.as_deref().unwrap_or(&self.owner)
+    }
+
     pub fn api_call_stats(&self) -> ApiCallStats {
         self.api_call_tracker.snapshot()
     }
@@ -237,6 +430,80 @@ impl GitHubClient {
         self.api_call_tracker.record(operation, 1);
     }
 
+    /// `GET` `route` (relative to the API base, query string included),
+    /// revalidating a cached copy with `If-None-Match` when the response cache
+    /// is enabled. A `304 Not Modified` reply is served from disk.
+    pub(crate) async fn get_cached<R: DeserializeOwned>(&self, route: &str) -> Result<R> {
+        let Some(git_dir) = &self.response_cache else {
+            return self.get_json(route).await;
+        };
+
+        let cached = ApiResponseCache::get(git_dir, route);
+        let mut headers = HeaderMap::new();
+        if let Some(entry) = &cached
+            && let Ok(etag) = HeaderValue::from_str(&entry.etag)
+        {
+            headers.insert(IF_NONE_MATCH, etag);
+        }
+
+        let Some((body, etag)) = self.get_body(route, headers).await? else {
+            let entry = cached.context("GitHub answered 304 without a cached response")?;
+            return serde_json::from_str(&entry.body)
+                .with_context(|| format!("Failed to parse cached response for {}", route));
+        };
+        let value = serde_json::from_str(&body)
+            .with_context(|| format!("Failed to parse response for {}", route))?;
+        if let Some(etag) = etag {
+            let entry = CachedApiResponse {
+                endpoint: route.to_string(),
+                etag,
+                body,
+            };
+            // The cache only saves quota; a failed write must not fail the lookup.
+            let _ = ApiResponseCache::store(git_dir, &entry);
+        }
+        Ok(value)
+    }
+
+    /// `GET` `route` without the response cache. Unlike `octocrab.get`, a
+    /// rate-limited reply keeps the wait its headers asked for, so
+    /// [`retry::with_backoff`] can honor it.
+    pub(crate) async fn get_json<R: DeserializeOwned>(&self, route: &str) -> Result<R> {
+        let (body, _) = self
+            .get_body(route, HeaderMap::new())
+            .await?
+            .with_context(|| format!("Unexpected 304 for {}", route))?;
+        serde_json::from_str(&body)
+            .with_context(|| format!("Failed to parse response for {}", route))
+    }
+
+    /// Body and `ETag` of a `GET`, or `None` for `304 Not Modified`.
+    async fn get_body(
+        &self,
+        route: &str,
+        headers: HeaderMap,
+    ) -> Result<Option<(String, Option<String>)>> {
+        let response = self
+            .octocrab
+            ._get_with_headers(route, Some(headers))
+            .await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+
+        let wait = retry::requested_wait(response.headers());
+        let response = octocrab::map_github_error(response)
+            .await
+            .map_err(|err| retry::response_error(err, wait))?;
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let body = self.octocrab.body_to_string(response).await?;
+        Ok(Some((body, etag)))
+    }
+
     /// Enrich an API error with auth troubleshooting context when it looks
     /// like a token permissions issue (GitHub returns 404 for private repos
     /// when the token lacks access, not 403).
@@ -351,6 +618,63 @@ impl GitHubClient {
         }
     }
 
+    /// Merge methods the repository allows. Settings GitHub does not report
+    /// count as allowed.
+    pub async fn allowed_merge_methods(&self) -> Result<AllowedMergeMethods> {
+        self.record_api_call("repos.get");
+        let url = format!("/repos/{}/{}", self.owner, self.repo);
+        let settings: RepoMergeSettings = self
+            .octocrab
+            .get(&url, None::<&()>)
+            .await
+            .context("Failed to read repository merge settings")?;
+        Ok(AllowedMergeMethods {
+            squash: settings.allow_squash_merge.unwrap_or(true),
+            merge: settings.allow_merge_commit.unwrap_or(true),
+            rebase: settings.allow_rebase_merge.unwrap_or(true),
+        })
+    }
+
+    /// Required checks and approving review count of `branch`, read once per
+    /// client. `None` when the branch is unprotected or the token may not read
+    /// its protection (GitHub only shows it to admins).
+    pub async fn branch_protection(&self, branch: &str) -> Option<BranchProtection> {
+        if let Some(cached) = self
+            .branch_protection
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(branch).cloned())
+        {
+            return cached;
+        }
+
+        self.record_api_call("repos.branch_protection");
+        let url = format!(
+            "/repos/{}/{}/branches/{}/protection",
+            self.owner,
+            self.repo,
+            crate::forge::encode_query_value(branch)
+        );
+        let protection = self
+            .octocrab
+            .get::<BranchProtectionResponse, _, _>(&url, None::<&()>)
+            .await
+            .ok()
+            .map(|response| BranchProtection {
+                required_checks: response
+                    .required_status_checks
+                    .map(|checks| checks.contexts)
+                    .unwrap_or_default(),
+                required_approvals: response
+                    .required_pull_request_reviews
+                    .map_or(0, |reviews| reviews.required_approving_review_count),
+            });
+        if let Ok(mut cache) = self.branch_protection.lock() {
+            cache.insert(branch.to_string(), protection.clone());
+        }
+        protection
+    }
+
     /// Get the authenticated user's login name
     pub async fn get_current_user(&self) -> Result<String> {
         let user = self.octocrab.current().user().await?;
@@ -572,6 +896,52 @@ impl GitHubClient {
             .collect())
     }
 
+    /// Fetch one pull request with its labels, requested reviewers and body.
+    pub async fn get_pr_details(&self, number: u64) -> Result<PrDetails> {
+        self.record_api_call("pulls.get.details");
+        let url = format!("/repos/{}/{}/pulls/{}", self.owner, self.repo, number);
+        let pr: RepoPullRequestDetails = self
+            .octocrab
+            .get(&url, None::<&()>)
+            .await
+            .with_context(|| format!("Failed to get PR #{}", number))?;
+
+        let state = if pr.merged_at.is_some() {
+            "MERGED".to_string()
+        } else {
+            pr.state.to_uppercase()
+        };
+        let reviewers = pr
+            .requested_reviewers
+            .into_iter()
+            .map(|user| user.login)
+            .chain(
+                pr.requested_teams
+                    .into_iter()
+                    .map(|team| format!("@{}/{}", self.owner, team.slug)),
+            )
+            .collect();
+
+        Ok(PrDetails {
+            number: pr.number,
+            title: pr.title,
+            url: pr.html_url,
+            state,
+            is_draft: pr.draft.unwrap_or(false),
+            author: pr.user.login,
+            head_branch: pr.head.ref_field,
+            base_branch: pr.base.ref_field,
+            head_sha: pr.head.sha,
+            labels: pr
+                .labels
+                .into_iter()
+                .filter_map(|label| label.name)
+                .collect(),
+            reviewers,
+            body: pr.body.unwrap_or_default(),
+        })
+    }
+
     /// List open issues for the current repository.
     ///
     /// GitHub's issues endpoint includes pull requests, so we filter them client-side and
@@ -625,6 +995,31 @@ impl GitHubClient {
 
         Ok(collected)
     }
+
+    /// Fetch a single issue by number.
+    pub async fn get_issue(&self, number: u64) -> Result<RepoIssueListItem> {
+        self.record_api_call("issues.get");
+        let url = format!("/repos/{}/{}/issues/{}", self.owner, self.repo, number);
+
+        let issue: RepoListIssue = self
+            .octocrab
+            .get(&url, None::<&()>)
+            .await
+            .with_context(|| format!("Failed to fetch issue #{}", number))?;
+
+        Ok(RepoIssueListItem {
+            number: issue.number,
+            title: issue.title,
+            url: issue.html_url,
+            author: issue.user.login,
+            labels: issue
+                .labels
+                .into_iter()
+                .filter_map(|label| label.name)
+                .collect(),
+            updated_at: issue.updated_at,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -649,6 +1044,117 @@ mod tests {
         GitHubClient::with_octocrab(octocrab, "test-owner", "test-repo")
     }
 
+    fn app_credentials() -> GitHubAppCredentials {
+        let pem = include_str!("testdata/app_private_key.pem");
+        let vars = [
+            ("STAX_GITHUB_APP_ID", "123"),
+            ("STAX_GITHUB_APP_INSTALLATION_ID", "456"),
+            ("STAX_GITHUB_APP_PRIVATE_KEY", pem),
+        ];
+        GitHubAppCredentials::from_lookup(|name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+        .unwrap()
+        .unwrap()
+    }
+
+    async fn mount_installation_token(server: &MockServer, expires_in: i64, calls: u64) {
+        let expires_at = (Utc::now() + chrono::Duration::seconds(expires_in)).to_rfc3339();
+        Mock::given(method("POST"))
+            .and(path("/app/installations/456/access_tokens"))
+            .and(wiremock::matchers::header_regex(
+                "authorization",
+                "^Bearer ey",
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "token": "ghs_installation",
+                "expires_at": expires_at,
+                "permissions": {},
+            })))
+            .expect(calls)
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test-owner/test-repo"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer ghs_installation",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+            .expect(2)
+            .mount(server)
+            .await;
+    }
+
+    async fn get_repo_twice(client: &GitHubClient) {
+        for _ in 0..2 {
+            let _: serde_json::Value = client
+                .octocrab
+                .get("/repos/test-owner/test-repo", None::<&()>)
+                .await
+                .unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn branch_protection_encodes_branch_name_in_path() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/repos/test-owner/test-repo/branches/release%2F1.x/protection",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "required_pull_request_reviews": { "required_approving_review_count": 1 }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = create_test_client(&server).await;
+        let protection = client.branch_protection("release/1.x").await.unwrap();
+
+        assert_eq!(protection.required_approvals, 1);
+    }
+
+    #[tokio::test]
+    async fn app_client_reuses_installation_token_until_near_expiry() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        mount_installation_token(&server, 3600, 1).await;
+
+        let client = GitHubClient::new_with_app(
+            "test-owner",
+            "test-repo",
+            Some(server.uri()),
+            None,
+            &app_credentials(),
+        )
+        .unwrap();
+        get_repo_twice(&client).await;
+
+        assert_eq!(client.auth_source, Some(GitHubAuthSource::GitHubApp));
+    }
+
+    #[tokio::test]
+    async fn app_client_mints_a_new_token_when_the_old_one_expires() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        // Inside octocrab's 30s refresh buffer, so every request re-mints.
+        mount_installation_token(&server, 10, 2).await;
+
+        let client = GitHubClient::new_with_app(
+            "test-owner",
+            "test-repo",
+            Some(server.uri()),
+            None,
+            &app_credentials(),
+        )
+        .unwrap();
+        get_repo_twice(&client).await;
+    }
+
     #[tokio::test]
     async fn test_check_runs_all_success() {
         let mock_server = MockServer::start().await;
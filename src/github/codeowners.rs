@@ -0,0 +1,175 @@
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::fs;
+use std::path::Path;
+
+/// CODEOWNERS locations GitHub reads, in the order it looks for them.
+const CODEOWNERS_CANDIDATES: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// One `pattern owner...` line from a CODEOWNERS file
+#[derive(Debug)]
+struct OwnerRule {
+    pattern: Regex,
+    owners: Vec<String>,
+}
+
+/// Parsed CODEOWNERS rules; later rules take precedence, as on GitHub.
+#[derive(Debug, Default)]
+pub struct CodeOwners {
+    rules: Vec<OwnerRule>,
+}
+
+impl CodeOwners {
+    /// Load the first CODEOWNERS file GitHub would use, if any.
+    pub fn discover(workdir: &Path) -> Result<Option<Self>> {
+        for candidate in CODEOWNERS_CANDIDATES {
+            let path = workdir.join(candidate);
+            if path.is_file() {
+                let content = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                return Ok(Some(Self::parse(&content)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Parse CODEOWNERS content. Lines with an invalid pattern are skipped.
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let line = line.split('#').next().unwrap_or("").trim();
+                let mut parts = line.split_whitespace();
+                let pattern = pattern_regex(parts.next()?)?;
+                let owners = parts.map(str::to_string).collect();
+                Some(OwnerRule { pattern, owners })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Owners of a repository-relative path, from the last matching rule.
+    pub fn owners_of(&self, path: &str) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.pattern.is_match(path))
+            .map(|rule| rule.owners.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Reviewers to request for a set of changed paths: `@user` becomes
+    /// `user` and `@org/team` becomes `org/team`. Email owners cannot be
+    /// requested and are dropped.
+    pub fn reviewers_for_paths(&self, paths: &[String]) -> Vec<String> {
+        let mut reviewers: Vec<String> = Vec::new();
+        for path in paths {
+            for owner in self.owners_of(path) {
+                let Some(handle) = owner.strip_prefix('@') else {
+                    continue;
+                };
+                if !reviewers.iter().any(|r| r.eq_ignore_ascii_case(handle)) {
+                    reviewers.push(handle.to_string());
+                }
+            }
+        }
+        reviewers
+    }
+}
+
+/// Translate a gitignore-style CODEOWNERS pattern into an anchored regex.
+fn pattern_regex(pattern: &str) -> Option<Regex> {
+    let dir_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.contains('/');
+    let trimmed = trimmed.trim_start_matches('/');
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = trimmed.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    // `dir/*` owns only the files directly inside `dir`; anything else also
+    // owns everything beneath a matching directory.
+    if trimmed.ends_with("/*") && !trimmed.ends_with("**/*") {
+        regex.push('$');
+    } else if dir_only {
+        regex.push_str("/.*$");
+    } else {
+        regex.push_str("(?:/.*)?$");
+    }
+    Regex::new(&regex).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(list: &[&str]) -> Vec<String> {
+        list.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn last_matching_rule_wins() {
+        let owners = CodeOwners::parse(
+            "# Default owners\n\
+             *       @org/core\n\
+             *.rs    @rustacean # Rust code\n\
+             /docs/  @writer docs@example.com\n",
+        );
+
+        assert_eq!(owners.owners_of("README.md"), ["@org/core"]);
+        assert_eq!(owners.owners_of("src/main.rs"), ["@rustacean"]);
+        assert_eq!(
+            owners.owners_of("docs/guide/intro.md"),
+            ["@writer", "docs@example.com"]
+        );
+        assert_eq!(owners.owners_of("src/docs/x.md"), ["@org/core"]);
+    }
+
+    #[test]
+    fn directory_patterns_follow_github_semantics() {
+        let owners = CodeOwners::parse(
+            "apps/ @apps\n\
+             /build/logs/ @logs\n\
+             docs/* @docs\n\
+             **/fixtures/** @fixtures\n",
+        );
+
+        assert_eq!(owners.owners_of("web/apps/index.ts"), ["@apps"]);
+        assert_eq!(owners.owners_of("build/logs/today.log"), ["@logs"]);
+        assert!(owners.owners_of("src/build/logs/today.log").is_empty());
+        assert_eq!(owners.owners_of("docs/readme.md"), ["@docs"]);
+        assert!(owners.owners_of("docs/nested/readme.md").is_empty());
+        assert_eq!(owners.owners_of("tests/fixtures/a/b.json"), ["@fixtures"]);
+    }
+
+    #[test]
+    fn reviewers_for_paths_dedupes_and_drops_emails() {
+        let owners = CodeOwners::parse(
+            "*.rs @Alice @org/core\n\
+             *.md @alice dev@example.com\n",
+        );
+
+        assert_eq!(
+            owners.reviewers_for_paths(&paths(&["a.rs", "b.md", "c.txt"])),
+            vec!["Alice", "org/core"]
+        );
+    }
+}
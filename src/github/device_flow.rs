@@ -0,0 +1,345 @@
+//! GitHub OAuth device flow used by `stax auth login`.
+//!
+//! See <https://docs.github.com/en/apps/oauth-apps/building-oauth-apps/authorizing-oauth-apps#device-flow>.
+
+use anyhow::{Context, Result, bail};
+use reqwest::Client;
+use reqwest::header::{ACCEPT, USER_AGENT};
+use serde::Deserialize;
+use std::time::Duration;
+
+const DEVICE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Scopes requested for the token: pushing and PR management need `repo`,
+/// team reviewers need `read:org`.
+pub const DEFAULT_SCOPES: &str = "repo read:org";
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DeviceCode {
+    pub device_code: String,
+    pub user_code: String,
+    pub verification_uri: String,
+    pub expires_in: u64,
+    pub interval: u64,
+}
+
+/// Result of a single poll of the access token endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PollOutcome {
+    /// The user approved the request.
+    Token(String),
+    /// The user has not entered the code yet.
+    Pending,
+    /// Polling too fast; wait this many seconds before the next poll.
+    SlowDown(u64),
+}
+
+#[derive(Debug, Deserialize)]
+struct AccessTokenResponse {
+    access_token: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+    interval: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuthenticatedUser {
+    login: String,
+}
+
+/// Web and API endpoints for a GitHub host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceFlowEndpoints {
+    pub web_base_url: String,
+    pub api_base_url: String,
+}
+
+impl DeviceFlowEndpoints {
+    pub fn for_host(host: &str) -> Self {
+        let host = host.trim().trim_end_matches('/');
+        if host.eq_ignore_ascii_case("github.com") {
+            Self {
+                web_base_url: "https://github.com".to_string(),
+                api_base_url: "https://api.github.com".to_string(),
+            }
+        } else {
+            Self {
+                web_base_url: format!("https://{}", host),
+                api_base_url: format!("https://{}/api/v3", host),
+            }
+        }
+    }
+}
+
+pub struct DeviceFlow {
+    client: Client,
+    endpoints: DeviceFlowEndpoints,
+    client_id: String,
+}
+
+impl DeviceFlow {
+    pub fn new(endpoints: DeviceFlowEndpoints, client_id: &str) -> Result<Self> {
+        let client = Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(Duration::from_secs(30))
+            .build()
+            .context("Failed to build GitHub HTTP client")?;
+        Ok(Self {
+            client,
+            endpoints,
+            client_id: client_id.to_string(),
+        })
+    }
+
+    /// Ask GitHub for a user code to show and a device code to poll with.
+    pub async fn request_code(&self, scopes: &str) -> Result<DeviceCode> {
+        let url = format!("{}/login/device/code", self.endpoints.web_base_url);
+        let response = self
+            .client
+            .post(&url)
+            .header(ACCEPT, "application/json")
+            .header(USER_AGENT, "stax")
+            .json(&serde_json::json!({
+                "client_id": self.client_id,
+                "scope": scopes,
+            }))
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            bail!(
+                "GitHub rejected the device code request ({}): {}",
+                status,
+                body
+            );
+        }
+        let body = response.text().await?;
+        if let Ok(error) = serde_json::from_str::<AccessTokenResponse>(&body)
+            && let Some(code) = error.error
+        {
+            bail!(
+                "GitHub rejected the device code request: {}",
+                describe_error(&code, error.error_description.as_deref())
+            );
+        }
+        serde_json::from_str(&body).context("Unexpected device code response from GitHub")
+    }
+
+    /// Poll the token endpoint once.
+    pub async fn poll_token(&self, device_code: &str) -> Result<PollOutcome> {
+        let url = format!("{}/login/oauth/access_token", self.endpoints.web_base_url);
+        let response: AccessTokenResponse = self
+            .client
+            .post(&url)
+            .header(ACCEPT, "application/json")
+            .header(USER_AGENT, "stax")
+            .json(&serde_json::json!({
+                "client_id": self.client_id,
+                "device_code": device_code,
+                "grant_type": DEVICE_GRANT_TYPE,
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+            .context("Unexpected access token response from GitHub")?;
+
+        if let Some(token) = response.access_token.filter(|token| !token.is_empty()) {
+            return Ok(PollOutcome::Token(token));
+        }
+        match response.error.as_deref() {
+            Some("authorization_pending") => Ok(PollOutcome::Pending),
+            // GitHub asks for +5s on every slow_down and reports the new interval.
+            Some("slow_down") => Ok(PollOutcome::SlowDown(response.interval.unwrap_or(5))),
+            Some(code) => bail!(
+                "{}",
+                describe_error(code, response.error_description.as_deref())
+            ),
+            None => bail!("GitHub returned neither a token nor an error"),
+        }
+    }
+
+    /// Login of the user the token belongs to.
+    pub async fn authenticated_login(&self, token: &str) -> Result<String> {
+        let url = format!("{}/user", self.endpoints.api_base_url);
+        let user: AuthenticatedUser = self
+            .client
+            .get(&url)
+            .bearer_auth(token)
+            .header(ACCEPT, "application/vnd.github+json")
+            .header(USER_AGENT, "stax")
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+            .context("Unexpected user response from GitHub")?;
+        Ok(user.login)
+    }
+}
+
+fn describe_error(code: &str, description: Option<&str>) -> String {
+    match code {
+        "expired_token" => "The device code expired. Run `stax auth login` again.".to_string(),
+        "access_denied" => "Authorization was denied in the browser.".to_string(),
+        "device_flow_disabled" => {
+            "Device flow is not enabled for this OAuth app. Enable it in the app settings."
+                .to_string()
+        }
+        "incorrect_client_credentials" => {
+            "GitHub does not recognize the OAuth client id. Check auth.oauth_client_id.".to_string()
+        }
+        _ => match description {
+            Some(description) => format!("{} ({})", description, code),
+            None => code.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn ensure_crypto_provider() {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+    }
+
+    fn flow(server: &MockServer) -> DeviceFlow {
+        ensure_crypto_provider();
+        DeviceFlow::new(
+            DeviceFlowEndpoints {
+                web_base_url: server.uri(),
+                api_base_url: server.uri(),
+            },
+            "client-123",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn endpoints_for_enterprise_host_use_api_v3() {
+        assert_eq!(
+            DeviceFlowEndpoints::for_host("github.com").api_base_url,
+            "https://api.github.com"
+        );
+        let enterprise = DeviceFlowEndpoints::for_host("github.company.com");
+        assert_eq!(enterprise.web_base_url, "https://github.company.com");
+        assert_eq!(enterprise.api_base_url, "https://github.company.com/api/v3");
+    }
+
+    #[tokio::test]
+    async fn request_code_returns_user_code() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/login/device/code"))
+            .and(header("accept", "application/json"))
+            .and(body_partial_json(serde_json::json!({
+                "client_id": "client-123",
+                "scope": DEFAULT_SCOPES,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "device_code": "dev-1",
+                "user_code": "ABCD-1234",
+                "verification_uri": "https://github.com/login/device",
+                "expires_in": 900,
+                "interval": 5,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let code = flow(&server).request_code(DEFAULT_SCOPES).await.unwrap();
+
+        assert_eq!(code.user_code, "ABCD-1234");
+        assert_eq!(code.device_code, "dev-1");
+        assert_eq!(code.interval, 5);
+    }
+
+    #[tokio::test]
+    async fn request_code_surfaces_oauth_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/login/device/code"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "error": "device_flow_disabled",
+            })))
+            .mount(&server)
+            .await;
+
+        let err = flow(&server)
+            .request_code(DEFAULT_SCOPES)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("Device flow is not enabled"));
+    }
+
+    #[tokio::test]
+    async fn poll_token_maps_pending_slow_down_and_success() {
+        for (body, expected) in [
+            (
+                serde_json::json!({ "error": "authorization_pending" }),
+                PollOutcome::Pending,
+            ),
+            (
+                serde_json::json!({ "error": "slow_down", "interval": 10 }),
+                PollOutcome::SlowDown(10),
+            ),
+            (
+                serde_json::json!({ "access_token": "gho_abc", "token_type": "bearer" }),
+                PollOutcome::Token("gho_abc".to_string()),
+            ),
+        ] {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path("/login/oauth/access_token"))
+                .and(body_partial_json(serde_json::json!({
+                    "device_code": "dev-1",
+                    "grant_type": DEVICE_GRANT_TYPE,
+                })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .mount(&server)
+                .await;
+
+            assert_eq!(flow(&server).poll_token("dev-1").await.unwrap(), expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn poll_token_fails_on_expired_code() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/login/oauth/access_token"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "error": "expired_token" })),
+            )
+            .mount(&server)
+            .await;
+
+        let err = flow(&server).poll_token("dev-1").await.unwrap_err();
+
+        assert!(err.to_string().contains("device code expired"));
+    }
+
+    #[tokio::test]
+    async fn authenticated_login_reads_user_endpoint() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(header("authorization", "Bearer gho_abc"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "login": "octocat" })),
+            )
+            .mount(&server)
+            .await;
+
+        let login = flow(&server).authenticated_login("gho_abc").await.unwrap();
+
+        assert_eq!(login, "octocat");
+    }
+}
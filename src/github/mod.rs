@@ -1,7 +1,12 @@
+pub mod app_auth;
 pub mod checks;
 pub mod client;
+pub mod codeowners;
+pub mod device_flow;
 pub mod gh_stack;
 pub mod pr;
 pub mod pr_template;
+pub mod retry;
+pub mod token_info;
 
 pub use client::GitHubClient;
@@ -1,17 +1,19 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use octocrab::models::pulls::{Base, Head, PullRequest};
-use octocrab::params::State;
-use octocrab::params::pulls::Sort;
 use serde::{Deserialize, de::DeserializeOwned};
 use std::collections::HashMap;
 
 use super::GitHubClient;
+use super::client::BranchProtection;
+use super::retry::with_backoff;
 use crate::remote::{ForgeType, RemoteInfo};
 
 const STACK_COMMENT_MARKER: &str = "<!-- stax-stack-comment -->";
 const STACK_LINKS_BODY_START_MARKER: &str = "<!-- stax-stack-links:start -->";
 const STACK_LINKS_BODY_END_MARKER: &str = "<!-- stax-stack-links:end -->";
+const MANUAL_BODY_START_MARKER: &str = "<!-- stax-manual:start -->";
+const MANUAL_BODY_END_MARKER: &str = "<!-- stax-manual:end -->";
 
 /// True when a PR base-update failure is GitHub rejecting the change because
 /// the PR is registered in a native GitHub Stack (private preview). GitHub
@@ -36,8 +38,8 @@ pub(crate) fn is_native_stack_base_locked_error(err: &anyhow::Error) -> bool {
 }
 
 pub use crate::forge::{
-    CiStatus, EnqueueResult, IssueComment, MergeMethod, MergeQueueEntry, PrComment, PrInfo,
-    PrInfoWithHead, PrMergeStatus, ReviewComment,
+    CiStatus, EnqueueResult, IssueComment, MergeMethod, MergeQueueEntry, MergeQueueStatus,
+    PrComment, PrInfo, PrInfoWithHead, PrMergeStatus, ReviewComment,
 };
 
 #[derive(Debug, Deserialize)]
@@ -123,12 +125,86 @@ struct PrNodeId {
     id: String,
 }
 
+// --- Admin merge (mergePullRequest) GraphQL types ---
+
+#[derive(Debug, Deserialize)]
+struct ViewerPermissionData {
+    repository: Option<ViewerPermissionRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ViewerPermissionRepo {
+    #[serde(rename = "viewerPermission")]
+    viewer_permission: Option<String>,
+}
+
+// --- Branch protection rule GraphQL types ---
+
+#[derive(Debug, Deserialize)]
+struct BranchProtectionData {
+    repository: Option<BranchProtectionRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BranchProtectionRepo {
+    #[serde(rename = "branchProtectionRules")]
+    branch_protection_rules: BranchProtectionRules,
+}
+
+#[derive(Debug, Deserialize)]
+struct BranchProtectionRules {
+    nodes: Vec<BranchProtectionRule>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BranchProtectionRule {
+    pattern: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MergePullRequestData {
+    #[serde(rename = "mergePullRequest")]
+    merge_pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EnableAutoMergeData {
+    #[serde(rename = "enablePullRequestAutoMerge")]
+    enable_pull_request_auto_merge: Option<serde_json::Value>,
+}
+
 #[derive(Debug, Deserialize)]
 struct EnqueueData {
     #[serde(rename = "enqueuePullRequest")]
     enqueue_pull_request: Option<EnqueueResult>,
 }
 
+#[derive(Debug, Deserialize)]
+struct DequeueData {
+    #[serde(rename = "dequeuePullRequest")]
+    dequeue_pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MergeQueueStatusData {
+    repository: Option<MergeQueueStatusRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MergeQueueStatusRepo {
+    #[serde(rename = "pullRequest")]
+    pull_request: Option<MergeQueueStatusPr>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MergeQueueStatusPr {
+    merged: bool,
+    #[serde(rename = "isInMergeQueue")]
+    is_in_merge_queue: bool,
+    #[serde(rename = "mergeQueueEntry")]
+    merge_queue_entry: Option<MergeQueueEntry>,
+}
+
 #[derive(Debug, Deserialize)]
 struct RepositoryData {
     #[serde(rename = "pullRequest")]
@@ -142,11 +218,62 @@ struct PullRequestData {
     reviews: ReviewConnection,
 }
 
+/// PRs per batched merge status query; keeps each query well under GitHub's
+/// GraphQL node limits (every PR selects up to 100 checks and 100 reviews).
+const MERGE_STATUS_BATCH_SIZE: usize = 50;
+
+/// Pull request fields shared by the single and batched merge status queries.
+const PR_MERGE_STATUS_FIELDS: &str = r#"
+    number
+    title
+    state
+    updatedAt
+    isDraft
+    mergeable
+    reviewDecision
+    headRefOid
+    baseRefName
+    statusCheckRollup {
+        state
+        contexts(first: 100) {
+            nodes {
+                __typename
+                ... on CheckRun {
+                    name
+                    status
+                    conclusion
+                    startedAt
+                    completedAt
+                }
+                ... on StatusContext {
+                    context
+                    state
+                    createdAt
+                }
+            }
+        }
+    }
+    reviews(last: 100) {
+        nodes {
+            state
+            author {
+                login
+            }
+        }
+    }
+"#;
+
 #[derive(Debug, Deserialize)]
 struct PrMergeStatusData {
     repository: Option<PrMergeStatusRepository>,
 }
 
+/// Aliased `prN: pullRequest(...)` selections from a batched merge status query.
+#[derive(Debug, Deserialize)]
+struct PrMergeStatusBatchData {
+    repository: Option<HashMap<String, Option<PullRequestMergeStatusData>>>,
+}
+
 #[derive(Debug, Deserialize)]
 struct PrMergeStatusRepository {
     #[serde(rename = "pullRequest")]
@@ -167,6 +294,8 @@ struct PullRequestMergeStatusData {
     review_decision: Option<String>,
     #[serde(rename = "headRefOid")]
     head_ref_oid: String,
+    #[serde(rename = "baseRefName")]
+    base_ref_name: Option<String>,
     #[serde(rename = "statusCheckRollup")]
     status_check_rollup: Option<StatusCheckRollupData>,
     reviews: ReviewConnection,
@@ -286,6 +415,25 @@ fn graphql_check_rollup_status(state: &str) -> CiStatus {
     }
 }
 
+/// Names of every check run and commit status context in the rollup.
+fn rollup_context_names(rollup: &StatusCheckRollupData) -> Vec<&str> {
+    rollup
+        .contexts
+        .as_ref()
+        .map(|connection| {
+            connection
+                .nodes
+                .iter()
+                .filter_map(|ctx| match ctx {
+                    RollupContext::CheckRun(c) => Some(c.name.as_str()),
+                    RollupContext::StatusContext(s) => Some(s.context.as_str()),
+                    RollupContext::Unknown => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 /// Compute the effective CI status from a status-check rollup.
 ///
 /// Prefers a deduplicated view of `contexts` (latest run per name wins) over
@@ -395,6 +543,32 @@ impl GitHubClient {
         }
     }
 
+    /// [`Self::graphql_data`] for read-only queries, retried on transient
+    /// failures. Mutations go through `graphql_data` so they never repeat.
+    async fn graphql_query<T: DeserializeOwned>(&self, payload: serde_json::Value) -> Result<T> {
+        with_backoff(self.quiet(), || self.graphql_data(payload.clone())).await
+    }
+
+    /// `pulls.get`, revalidated against the response cache and retried on
+    /// transient failures.
+    async fn fetch_pull(&self, pr_number: u64) -> Result<PullRequest> {
+        let route = format!("/repos/{}/{}/pulls/{}", self.owner, self.repo, pr_number);
+        with_backoff(self.quiet(), || self.get_cached(&route)).await
+    }
+
+    /// `pulls.list` of open PRs, newest first, through the response cache.
+    async fn list_open_pulls(&self, query: &[(&str, &str)]) -> Result<Vec<PullRequest>> {
+        let mut params = vec![("state", "open"), ("sort", "created")];
+        params.extend_from_slice(query);
+        let route = format!(
+            "/repos/{}/{}/pulls?{}",
+            self.owner,
+            self.repo,
+            serde_urlencoded::to_string(&params)?
+        );
+        with_backoff(self.quiet(), || self.get_cached(&route)).await
+    }
+
     /// Find existing open PR for a branch owned by `head_owner`.
     ///
     /// Uses GitHub's `head` filter first (single request) and validates the
@@ -405,15 +579,9 @@ impl GitHubClient {
         branch: &str,
     ) -> Result<Option<PrInfoWithHead>> {
         self.record_api_call("pulls.list.head");
+        let head = format!("{}:{}", head_owner, branch);
         let prs = match self
-            .octocrab
-            .pulls(&self.owner, &self.repo)
-            .list()
-            .state(State::Open)
-            .head(format!("{}:{}", head_owner, branch))
-            .per_page(100u8)
-            .sort(Sort::Created)
-            .send()
+            .list_open_pulls(&[("head", &head), ("per_page", "100")])
             .await
             .context("Failed to list PRs by head")
         {
@@ -421,7 +589,7 @@ impl GitHubClient {
             Err(e) => return Err(self.enrich_api_error(e)),
         };
 
-        for pr in &prs.items {
+        for pr in &prs {
             let head = octocrab_pr_head(pr)?;
             if head.ref_field != branch {
                 continue;
@@ -450,7 +618,10 @@ impl GitHubClient {
     /// Uses the `head` filter first (fast path), then falls back to scanning
     /// open PRs if needed.
     pub async fn find_pr(&self, branch: &str) -> Result<Option<PrInfo>> {
-        if let Some(pr) = self.find_open_pr_by_head(&self.owner, branch).await? {
+        if let Some(pr) = self
+            .find_open_pr_by_head(self.pr_head_owner(), branch)
+            .await?
+        {
             return Ok(Some(pr.info));
         }
 
@@ -466,15 +637,10 @@ impl GitHubClient {
 
         loop {
             self.record_api_call("pulls.list.open.page");
+            let per_page = PER_PAGE.to_string();
+            let page_number = page.to_string();
             let prs = match self
-                .octocrab
-                .pulls(&self.owner, &self.repo)
-                .list()
-                .state(State::Open)
-                .per_page(PER_PAGE)
-                .page(page)
-                .sort(Sort::Created)
-                .send()
+                .list_open_pulls(&[("per_page", &per_page), ("page", &page_number)])
                 .await
                 .context("Failed to list PRs")
             {
@@ -482,7 +648,7 @@ impl GitHubClient {
                 Err(e) => return Err(self.enrich_api_error(e)),
             };
 
-            for pr in &prs.items {
+            for pr in &prs {
                 let head = octocrab_pr_head(pr)?.ref_field.clone();
                 if prs_by_head.contains_key(&head) {
                     continue;
@@ -491,7 +657,7 @@ impl GitHubClient {
                 prs_by_head.insert(head, octocrab_pr_info_with_head(pr)?);
             }
 
-            if (prs.items.len() as u8) < PER_PAGE {
+            if prs.len() < usize::from(PER_PAGE) {
                 break;
             }
 
@@ -511,10 +677,16 @@ impl GitHubClient {
         draft: bool,
     ) -> Result<PrInfo> {
         self.record_api_call("pulls.create");
+        // Fork heads must be qualified as `owner:branch`.
+        let head = if self.pr_head_owner() == self.owner {
+            branch.to_string()
+        } else {
+            format!("{}:{}", self.pr_head_owner(), branch)
+        };
         let pr = self
             .octocrab
             .pulls(&self.owner, &self.repo)
-            .create(title, branch, base)
+            .create(title, head, base)
             .body(body)
             .draft(Some(draft))
             .send()
@@ -528,9 +700,7 @@ impl GitHubClient {
     pub async fn get_pr(&self, pr_number: u64) -> Result<PrInfo> {
         self.record_api_call("pulls.get");
         let pr = self
-            .octocrab
-            .pulls(&self.owner, &self.repo)
-            .get(pr_number)
+            .fetch_pull(pr_number)
             .await
             .context("Failed to get PR")?;
 
@@ -551,9 +721,7 @@ impl GitHubClient {
     pub async fn get_pr_with_head(&self, pr_number: u64) -> Result<PrInfoWithHead> {
         self.record_api_call("pulls.get");
         let pr = self
-            .octocrab
-            .pulls(&self.owner, &self.repo)
-            .get(pr_number)
+            .fetch_pull(pr_number)
             .await
             .context("Failed to get PR")?;
 
@@ -675,19 +843,21 @@ impl GitHubClient {
     pub async fn get_pr_body(&self, pr_number: u64) -> Result<String> {
         self.record_api_call("pulls.get.body");
         let pr = self
-            .octocrab
-            .pulls(&self.owner, &self.repo)
-            .get(pr_number)
+            .fetch_pull(pr_number)
             .await
             .context("Failed to get PR body")?;
 
         Ok(pr.body.unwrap_or_default())
     }
 
-    /// Add or update the stack comment on a PR
+    /// Add or update the stack comment on a PR. An existing comment that
+    /// already matches is left alone, so resubmitting does not notify anyone.
     pub async fn update_stack_comment(&self, pr_number: u64, stack_comment: &str) -> Result<()> {
-        if let Some(comment_id) = self.find_stack_comment_id(pr_number).await? {
+        if let Some((comment_id, current)) = self.find_stack_comment(pr_number).await? {
             let full_comment = format!("{}\n{}", STACK_COMMENT_MARKER, stack_comment);
+            if current == full_comment {
+                return Ok(());
+            }
             self.record_api_call("issues.comments.update");
             let route = format!(
                 "/repos/{}/{}/issues/comments/{}",
@@ -745,9 +915,23 @@ impl GitHubClient {
         Ok(())
     }
 
+    /// Reopen a closed PR.
+    pub async fn reopen_pr(&self, pr_number: u64) -> Result<()> {
+        self.record_api_call("pulls.update.state");
+        self.octocrab
+            .pulls(&self.owner, &self.repo)
+            .update(pr_number)
+            .state(octocrab::params::pulls::State::Open)
+            .send()
+            .await
+            .context("Failed to reopen PR")?;
+
+        Ok(())
+    }
+
     /// Delete the stax-managed stack comment on a PR, if present.
     pub async fn delete_stack_comment(&self, pr_number: u64) -> Result<()> {
-        let Some(comment_id) = self.find_stack_comment_id(pr_number).await? else {
+        let Some((comment_id, _)) = self.find_stack_comment(pr_number).await? else {
             return Ok(());
         };
 
@@ -761,28 +945,36 @@ impl GitHubClient {
         Ok(())
     }
 
-    async fn find_stack_comment_id(
+    /// The stax stack comment's id and body. Pages through every comment so
+    /// a busy PR does not get a second stack comment.
+    async fn find_stack_comment(
         &self,
         pr_number: u64,
-    ) -> Result<Option<octocrab::models::CommentId>> {
-        self.record_api_call("issues.comments.list");
-        let url = format!(
-            "/repos/{}/{}/issues/{}/comments",
-            self.owner, self.repo, pr_number
-        );
-        let comments: Vec<ApiIssueComment> = self
-            .octocrab
-            .get(&url, None::<&()>)
-            .await
-            .context("Failed to list comments")?;
-
-        Ok(comments.into_iter().find_map(|comment| {
-            comment
-                .body
-                .as_ref()
-                .filter(|body| body.contains(STACK_COMMENT_MARKER))
-                .map(|_| octocrab::models::CommentId::from(comment.id))
-        }))
+    ) -> Result<Option<(octocrab::models::CommentId, String)>> {
+        const PER_PAGE: usize = 100;
+        let mut page = 1u32;
+        loop {
+            self.record_api_call("issues.comments.list");
+            let url = format!(
+                "/repos/{}/{}/issues/{}/comments?per_page={}&page={}",
+                self.owner, self.repo, pr_number, PER_PAGE, page
+            );
+            let comments: Vec<ApiIssueComment> = with_backoff(self.quiet(), || self.get_json(&url))
+                .await
+                .context("Failed to list comments")?;
+            let count = comments.len();
+
+            let found = comments.into_iter().find_map(|comment| {
+                comment
+                    .body
+                    .filter(|body| body.contains(STACK_COMMENT_MARKER))
+                    .map(|body| (octocrab::models::CommentId::from(comment.id), body))
+            });
+            if found.is_some() || count < PER_PAGE {
+                return Ok(found);
+            }
+            page += 1;
+        }
     }
 
     pub async fn request_reviewers(&self, pr_number: u64, reviewers: &[String]) -> Result<()> {
@@ -791,9 +983,14 @@ impl GitHubClient {
         }
 
         self.record_api_call("pulls.request_reviewers");
+        let (users, teams) = split_reviewers(reviewers);
+        let team_slugs: Vec<String> = teams
+            .iter()
+            .map(|team| team.rsplit('/').next().unwrap_or(team).to_string())
+            .collect();
         self.octocrab
             .pulls(&self.owner, &self.repo)
-            .request_reviews(pr_number, reviewers.to_vec(), Vec::<String>::new())
+            .request_reviews(pr_number, users, team_slugs)
             .await
             .context("Failed to request reviewers")?;
 
@@ -804,9 +1001,7 @@ impl GitHubClient {
     pub async fn get_requested_reviewers(&self, pr_number: u64) -> Result<Vec<String>> {
         self.record_api_call("pulls.get");
         let pr = self
-            .octocrab
-            .pulls(&self.owner, &self.repo)
-            .get(pr_number)
+            .fetch_pull(pr_number)
             .await
             .context("Failed to get PR for reviewers")?;
 
@@ -894,51 +1089,16 @@ impl GitHubClient {
             query {{
                 repository(owner: "{}", name: "{}") {{
                     pullRequest(number: {}) {{
-                        number
-                        title
-                        state
-                        updatedAt
-                        isDraft
-                        mergeable
-                        reviewDecision
-                        headRefOid
-                        statusCheckRollup {{
-                            state
-                            contexts(first: 100) {{
-                                nodes {{
-                                    __typename
-                                    ... on CheckRun {{
-                                        name
-                                        status
-                                        conclusion
-                                        startedAt
-                                        completedAt
-                                    }}
-                                    ... on StatusContext {{
-                                        context
-                                        state
-                                        createdAt
-                                    }}
-                                }}
-                            }}
-                        }}
-                        reviews(last: 100) {{
-                            nodes {{
-                                state
-                                author {{
-                                    login
-                                }}
-                            }}
-                        }}
+                        {}
                     }}
                 }}
             }}
             "#,
-            self.owner, self.repo, pr_number
+            self.owner, self.repo, pr_number, PR_MERGE_STATUS_FIELDS
         );
 
         let data: PrMergeStatusData = self
-            .graphql_data(serde_json::json!({ "query": query }))
+            .graphql_query(serde_json::json!({ "query": query }))
             .await
             .context("Failed to query PR merge status")?;
 
@@ -949,33 +1109,62 @@ impl GitHubClient {
             .pull_request
             .context("GraphQL response did not include pull request merge status data")?;
 
-        let approvals = count_effective_approvals(&pr.reviews.nodes);
-        // The reviews list retains historical events, so scanning it would let
-        // a superseded CHANGES_REQUESTED review keep blocking the PR. Rely on
-        // reviewDecision, which already applies per-reviewer latest-wins logic.
-        let changes_requested = pr.review_decision.as_deref() == Some("CHANGES_REQUESTED");
-        let mergeable = graphql_mergeable_bool(&pr.mergeable);
-        let mergeable_state = graphql_mergeable_state(&pr.mergeable);
-        let ci_status = pr
-            .status_check_rollup
-            .as_ref()
-            .map(rollup_ci_status)
-            .unwrap_or(CiStatus::NoCi);
-
-        Ok(PrMergeStatus {
-            number: pr.number,
-            title: pr.title,
-            state: pr.state.to_ascii_lowercase(),
-            updated_at: Some(pr.updated_at),
-            is_draft: pr.is_draft,
-            mergeable,
-            mergeable_state,
-            ci_status,
-            review_decision: pr.review_decision,
-            approvals,
-            changes_requested,
-            head_sha: pr.head_ref_oid,
-        })
+        let protection = match pr.base_ref_name.as_deref() {
+            Some(base) => self.branch_protection(base).await,
+            None => None,
+        };
+        Ok(merge_status_from_graphql(pr, protection.as_ref()))
+    }
+
+    /// Get merge status for many PRs with one aliased GraphQL query per
+    /// [`MERGE_STATUS_BATCH_SIZE`] PRs. PRs GitHub does not return are left
+    /// out of the map, so callers can fall back to [`Self::get_pr_merge_status`].
+    pub async fn get_pr_merge_statuses(
+        &self,
+        pr_numbers: &[u64],
+    ) -> Result<HashMap<u64, PrMergeStatus>> {
+        let mut statuses = HashMap::new();
+        for chunk in pr_numbers.chunks(MERGE_STATUS_BATCH_SIZE) {
+            self.record_api_call("graphql.pr_merge_status_batch");
+            let selections = chunk
+                .iter()
+                .enumerate()
+                .map(|(idx, number)| {
+                    format!(
+                        "pr{}: pullRequest(number: {}) {{ {} }}",
+                        idx, number, PR_MERGE_STATUS_FIELDS
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let query = format!(
+                r#"
+                query {{
+                    repository(owner: "{}", name: "{}") {{
+                        {}
+                    }}
+                }}
+                "#,
+                self.owner, self.repo, selections
+            );
+
+            let data: PrMergeStatusBatchData = self
+                .graphql_query(serde_json::json!({ "query": query }))
+                .await
+                .context("Failed to query PR merge statuses")?;
+            let repository = data
+                .repository
+                .context("GraphQL response did not include repository data")?;
+            for pr in repository.into_values().flatten() {
+                let protection = match pr.base_ref_name.as_deref() {
+                    Some(base) => self.branch_protection(base).await,
+                    None => None,
+                };
+                let status = merge_status_from_graphql(pr, protection.as_ref());
+                statuses.insert(status.number, status);
+            }
+        }
+        Ok(statuses)
     }
 
     /// Return the overall review decision for a PR (`"APPROVED"`, `"CHANGES_REQUESTED"`,
@@ -1009,7 +1198,7 @@ impl GitHubClient {
         );
 
         let data: PrReviewData = self
-            .graphql_data(serde_json::json!({ "query": query }))
+            .graphql_query(serde_json::json!({ "query": query }))
             .await
             .context("Failed to query PR reviews")?;
 
@@ -1043,7 +1232,7 @@ impl GitHubClient {
         );
 
         let data: PrNodeIdData = self
-            .graphql_data(serde_json::json!({ "query": query }))
+            .graphql_query(serde_json::json!({ "query": query }))
             .await
             .context("Failed to query PR node ID")?;
 
@@ -1082,12 +1271,203 @@ impl GitHubClient {
             .context("No enqueue result returned — is merge queue enabled on this repository?")
     }
 
+    /// Take a PR out of the merge queue (`dequeuePullRequest`).
+    pub async fn dequeue_pr(&self, pr_number: u64) -> Result<()> {
+        let node_id = self.get_pr_node_id(pr_number).await?;
+
+        self.record_api_call("graphql.dequeue_pr");
+        let mutation = format!(
+            r#"
+            mutation {{
+                dequeuePullRequest(input: {{ id: "{}" }}) {{
+                    mergeQueueEntry {{
+                        position
+                    }}
+                }}
+            }}
+            "#,
+            node_id
+        );
+
+        let data: DequeueData = self
+            .graphql_data(serde_json::json!({ "query": mutation }))
+            .await
+            .context("Failed to remove PR from merge queue")?;
+
+        data.dequeue_pull_request
+            .map(|_| ())
+            .context("GitHub did not confirm the PR left the merge queue")
+    }
+
+    /// Whether a PR has merged, is still waiting in the merge queue, or was
+    /// dropped from it (for example after its queue checks failed).
+    pub async fn merge_queue_status(&self, pr_number: u64) -> Result<MergeQueueStatus> {
+        self.record_api_call("graphql.merge_queue_status");
+        let query = format!(
+            r#"
+            query {{
+                repository(owner: "{}", name: "{}") {{
+                    pullRequest(number: {}) {{
+                        merged
+                        isInMergeQueue
+                        mergeQueueEntry {{
+                            position
+                        }}
+                    }}
+                }}
+            }}
+            "#,
+            self.owner, self.repo, pr_number
+        );
+
+        let data: MergeQueueStatusData = self
+            .graphql_query(serde_json::json!({ "query": query }))
+            .await
+            .context("Failed to query merge queue status")?;
+        let pr = data
+            .repository
+            .and_then(|r| r.pull_request)
+            .context("PR not found")?;
+
+        Ok(MergeQueueStatus {
+            merged: pr.merged,
+            in_queue: Some(pr.is_in_merge_queue),
+            position: pr.merge_queue_entry.and_then(|entry| entry.position),
+        })
+    }
+
+    /// The viewer's permission on the repository (`ADMIN`, `MAINTAIN`,
+    /// `WRITE`, ...), or `None` when GitHub does not report one.
+    pub async fn viewer_permission(&self) -> Result<Option<String>> {
+        self.record_api_call("graphql.viewer_permission");
+        let query = format!(
+            r#"
+            query {{
+                repository(owner: "{}", name: "{}") {{
+                    viewerPermission
+                }}
+            }}
+            "#,
+            self.owner, self.repo
+        );
+
+        let data: ViewerPermissionData = self
+            .graphql_query(serde_json::json!({ "query": query }))
+            .await
+            .context("Failed to query repository permission")?;
+
+        Ok(data.repository.and_then(|r| r.viewer_permission))
+    }
+
+    /// Branch name patterns (fnmatch-style, e.g. `release/*`) covered by the
+    /// repository's branch protection rules.
+    pub async fn protected_branch_patterns(&self) -> Result<Vec<String>> {
+        self.record_api_call("graphql.branch_protection_rules");
+        let query = format!(
+            r#"
+            query {{
+                repository(owner: "{}", name: "{}") {{
+                    branchProtectionRules(first: 100) {{
+                        nodes {{
+                            pattern
+                        }}
+                    }}
+                }}
+            }}
+            "#,
+            self.owner, self.repo
+        );
+
+        let data: BranchProtectionData = self
+            .graphql_query(serde_json::json!({ "query": query }))
+            .await
+            .context("Failed to query branch protection rules")?;
+
+        Ok(data
+            .repository
+            .map(|repo| {
+                repo.branch_protection_rules
+                    .nodes
+                    .into_iter()
+                    .map(|rule| rule.pattern)
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// Merge a PR through the GraphQL `mergePullRequest` mutation, which lets a
+    /// repository admin override branch protection (required checks and
+    /// reviews) the way `gh pr merge --admin` does.
+    pub async fn admin_merge_pr(&self, pr_number: u64, method: MergeMethod) -> Result<()> {
+        let node_id = self.get_pr_node_id(pr_number).await?;
+        let merge_method = match method {
+            MergeMethod::Squash => "SQUASH",
+            MergeMethod::Merge => "MERGE",
+            MergeMethod::Rebase => "REBASE",
+        };
+
+        self.record_api_call("graphql.merge_pull_request");
+        let mutation = format!(
+            r#"
+            mutation {{
+                mergePullRequest(input: {{ pullRequestId: "{}", mergeMethod: {} }}) {{
+                    pullRequest {{
+                        number
+                    }}
+                }}
+            }}
+            "#,
+            node_id, merge_method
+        );
+
+        let data: MergePullRequestData = self
+            .graphql_data(serde_json::json!({ "query": mutation }))
+            .await
+            .context("Failed to merge PR with admin override")?;
+
+        data.merge_pull_request
+            .map(|_| ())
+            .context("GitHub did not return a merge result")
+    }
+
+    /// Turn on auto-merge so GitHub merges the PR with `method` once its
+    /// required checks and reviews pass (`enablePullRequestAutoMerge`).
+    pub async fn enable_auto_merge(&self, pr_number: u64, method: MergeMethod) -> Result<()> {
+        let node_id = self.get_pr_node_id(pr_number).await?;
+        let merge_method = match method {
+            MergeMethod::Squash => "SQUASH",
+            MergeMethod::Merge => "MERGE",
+            MergeMethod::Rebase => "REBASE",
+        };
+
+        self.record_api_call("graphql.enable_auto_merge");
+        let mutation = format!(
+            r#"
+            mutation {{
+                enablePullRequestAutoMerge(input: {{ pullRequestId: "{}", mergeMethod: {} }}) {{
+                    pullRequest {{
+                        number
+                    }}
+                }}
+            }}
+            "#,
+            node_id, merge_method
+        );
+
+        let data: EnableAutoMergeData = self
+            .graphql_data(serde_json::json!({ "query": mutation }))
+            .await
+            .context("Failed to enable auto-merge")?;
+
+        data.enable_pull_request_auto_merge
+            .map(|_| ())
+            .context("GitHub did not confirm auto-merge")
+    }
+
     /// Check if a PR is already merged
     pub async fn is_pr_merged(&self, pr_number: u64) -> Result<bool> {
         let pr = self
-            .octocrab
-            .pulls(&self.owner, &self.repo)
-            .get(pr_number)
+            .fetch_pull(pr_number)
             .await
             .context("Failed to get PR")?;
 
@@ -1100,9 +1480,7 @@ impl GitHubClient {
     pub async fn get_pr_head_sha(&self, pr_number: u64) -> Result<String> {
         self.record_api_call("pulls.get");
         let pr = self
-            .octocrab
-            .pulls(&self.owner, &self.repo)
-            .get(pr_number)
+            .fetch_pull(pr_number)
             .await
             .context("Failed to get PR")?;
         Ok(octocrab_pr_head(&pr)?.sha.clone())
@@ -1114,9 +1492,7 @@ impl GitHubClient {
             "/repos/{}/{}/issues/{}/comments",
             self.owner, self.repo, pr_number
         );
-        let comments: Vec<ApiIssueComment> = self
-            .octocrab
-            .get(&url, None::<&()>)
+        let comments: Vec<ApiIssueComment> = with_backoff(self.quiet(), || self.get_json(&url))
             .await
             .context("Failed to list issue comments")?;
 
@@ -1150,9 +1526,7 @@ impl GitHubClient {
             diff_hunk: Option<String>,
         }
 
-        let comments: Vec<ApiReviewComment> = self
-            .octocrab
-            .get(&url, None::<&()>)
+        let comments: Vec<ApiReviewComment> = with_backoff(self.quiet(), || self.get_json(&url))
             .await
             .context("Failed to list review comments")?;
 
@@ -1195,6 +1569,78 @@ impl GitHubClient {
     }
 }
 
+/// Split reviewer names into user logins and `org/team` team reviewers.
+///
+/// A leading `@` is dropped; anything containing `/` is a team, since logins cannot.
+pub fn split_reviewers(reviewers: &[String]) -> (Vec<String>, Vec<String>) {
+    let mut users = Vec::new();
+    let mut teams = Vec::new();
+    for reviewer in reviewers {
+        let name = reviewer.trim().trim_start_matches('@');
+        if name.is_empty() {
+            continue;
+        }
+        if name.contains('/') {
+            teams.push(name.to_string());
+        } else {
+            users.push(name.to_string());
+        }
+    }
+    (users, teams)
+}
+
+fn merge_status_from_graphql(
+    pr: PullRequestMergeStatusData,
+    protection: Option<&BranchProtection>,
+) -> PrMergeStatus {
+    let approvals = count_effective_approvals(&pr.reviews.nodes);
+    // The reviews list retains historical events, so scanning it would let
+    // a superseded CHANGES_REQUESTED review keep blocking the PR. Rely on
+    // reviewDecision, which already applies per-reviewer latest-wins logic.
+    let changes_requested = pr.review_decision.as_deref() == Some("CHANGES_REQUESTED");
+    let mergeable = graphql_mergeable_bool(&pr.mergeable);
+    let mergeable_state = graphql_mergeable_state(&pr.mergeable);
+    let ci_status = pr
+        .status_check_rollup
+        .as_ref()
+        .map(rollup_ci_status)
+        .unwrap_or(CiStatus::NoCi);
+    // Checks that reported are already reflected in `ci_status`; a required
+    // check that never reported would still make GitHub refuse the merge.
+    let missing_required_checks: Vec<String> = protection
+        .map(|protection| {
+            let reported = pr
+                .status_check_rollup
+                .as_ref()
+                .map(rollup_context_names)
+                .unwrap_or_default();
+            protection
+                .required_checks
+                .iter()
+                .filter(|check| !reported.contains(&check.as_str()))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    PrMergeStatus {
+        number: pr.number,
+        title: pr.title,
+        state: pr.state.to_ascii_lowercase(),
+        updated_at: Some(pr.updated_at),
+        is_draft: pr.is_draft,
+        mergeable,
+        mergeable_state,
+        ci_status,
+        review_decision: pr.review_decision,
+        approvals,
+        changes_requested,
+        head_sha: pr.head_ref_oid,
+        required_approvals: protection.map_or(0, |protection| protection.required_approvals),
+        missing_required_checks,
+    }
+}
+
 /// Build a detailed error when octocrab returns a GitHub API failure on merge.
 ///
 /// octocrab's default Display for `Error::GitHub` doesn't surface the HTTP
@@ -1276,7 +1722,7 @@ pub fn generate_stack_links_markdown(
         _ => "PR",
     };
     let mr_prefix = match remote.forge {
-        ForgeType::GitLab => "!",
+        ForgeType::GitLab | ForgeType::AzureDevOps => "!",
         _ => "#",
     };
 
@@ -1345,6 +1791,16 @@ pub fn upsert_stack_links_in_body(existing_body: &str, stack_links: &str) -> Str
         end = STACK_LINKS_BODY_END_MARKER
     );
 
+    // Keep the block where it already sits (e.g. a `{{STACK}}` slot from a PR
+    // template) instead of moving it to the bottom of the body.
+    if let Some((start_idx, end_idx)) = find_stack_links_block(existing_body) {
+        let mut result = String::with_capacity(existing_body.len() + managed_block.len());
+        result.push_str(&existing_body[..start_idx]);
+        result.push_str(&managed_block);
+        result.push_str(&existing_body[end_idx..]);
+        return result;
+    }
+
     let body_without_existing = remove_stack_links_from_body(existing_body);
     if body_without_existing.is_empty() {
         return managed_block;
@@ -1359,15 +1815,55 @@ pub fn upsert_stack_links_in_body(existing_body: &str, stack_links: &str) -> Str
     }
 }
 
+/// Empty managed block that marks where stack links go in a templated body.
+pub fn stack_links_placeholder() -> String {
+    format!(
+        "{}\n{}",
+        STACK_LINKS_BODY_START_MARKER, STACK_LINKS_BODY_END_MARKER
+    )
+}
+
+fn find_stack_links_block(body: &str) -> Option<(usize, usize)> {
+    let start_idx = body.find(STACK_LINKS_BODY_START_MARKER)?;
+    let end_marker_idx = body[start_idx..].find(STACK_LINKS_BODY_END_MARKER)?;
+    Some((
+        start_idx,
+        start_idx + end_marker_idx + STACK_LINKS_BODY_END_MARKER.len(),
+    ))
+}
+
+/// Replace a PR body with `generated` text while keeping what stax does not
+/// own: every `<!-- stax-manual:start -->` ... `<!-- stax-manual:end -->`
+/// section and the managed stack links block, in that order.
+pub fn sync_body_with_manual_sections(existing_body: &str, generated: &str) -> String {
+    let mut kept = Vec::new();
+    let mut rest = existing_body;
+    while let Some(start_idx) = rest.find(MANUAL_BODY_START_MARKER) {
+        let Some(end_offset) = rest[start_idx..].find(MANUAL_BODY_END_MARKER) else {
+            break;
+        };
+        let end_idx = start_idx + end_offset + MANUAL_BODY_END_MARKER.len();
+        kept.push(&rest[start_idx..end_idx]);
+        rest = &rest[end_idx..];
+    }
+    if let Some((start_idx, end_idx)) = find_stack_links_block(existing_body) {
+        kept.push(&existing_body[start_idx..end_idx]);
+    }
+
+    let mut body = generated.trim_end().to_string();
+    for section in kept {
+        if !body.is_empty() {
+            body.push_str("\n\n");
+        }
+        body.push_str(section);
+    }
+    body
+}
+
 pub fn remove_stack_links_from_body(existing_body: &str) -> String {
-    let Some(start_idx) = existing_body.find(STACK_LINKS_BODY_START_MARKER) else {
+    let Some((start_idx, end_idx)) = find_stack_links_block(existing_body) else {
         return existing_body.to_string();
     };
-    let Some(end_marker_idx) = existing_body[start_idx..].find(STACK_LINKS_BODY_END_MARKER) else {
-        return existing_body.to_string();
-    };
-
-    let end_idx = start_idx + end_marker_idx + STACK_LINKS_BODY_END_MARKER.len();
     let mut remove_start = start_idx;
     let mut remove_end = end_idx;
 
@@ -1391,9 +1887,44 @@ pub fn remove_stack_links_from_body(existing_body: &str) -> String {
 mod tests {
     use super::*;
     use octocrab::Octocrab;
-    use wiremock::matchers::{method, path, query_param};
+    use wiremock::matchers::{body_partial_json, body_string_contains, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    #[test]
+    fn sync_body_keeps_manual_sections_and_stack_links() {
+        let existing = "## Summary\n\n- Old commit\n\n\
+            <!-- stax-manual:start -->\nRollout notes\n<!-- stax-manual:end -->\n\n\
+            <!-- stax-stack-links:start -->\n* #1\n<!-- stax-stack-links:end -->\n";
+
+        assert_eq!(
+            sync_body_with_manual_sections(existing, "## Summary\n\n- New commit\n"),
+            "## Summary\n\n- New commit\n\n\
+            <!-- stax-manual:start -->\nRollout notes\n<!-- stax-manual:end -->\n\n\
+            <!-- stax-stack-links:start -->\n* #1\n<!-- stax-stack-links:end -->"
+        );
+    }
+
+    #[test]
+    fn sync_body_replaces_everything_without_markers() {
+        assert_eq!(
+            sync_body_with_manual_sections("Hand-written body", "## Summary\n\n- Commit"),
+            "## Summary\n\n- Commit"
+        );
+    }
+
+    #[test]
+    fn split_reviewers_separates_users_and_teams() {
+        let input = vec![
+            "alice".to_string(),
+            " @bob ".to_string(),
+            "@acme/core".to_string(),
+            "".to_string(),
+        ];
+        let (users, teams) = split_reviewers(&input);
+        assert_eq!(users, vec!["alice", "bob"]);
+        assert_eq!(teams, vec!["acme/core"]);
+    }
+
     #[test]
     fn is_native_stack_base_locked_error_matches_githubs_exact_wording() {
         let err = anyhow::anyhow!(
@@ -1545,6 +2076,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
 
         assert!(status.is_ready());
@@ -1552,6 +2085,78 @@ mod tests {
         assert!(!status.is_blocked());
     }
 
+    #[test]
+    fn test_pr_merge_status_waits_for_branch_protection() {
+        let ready = PrMergeStatus {
+            number: 1,
+            title: "Test".to_string(),
+            state: "Open".to_string(),
+            updated_at: None,
+            is_draft: false,
+            mergeable: Some(true),
+            mergeable_state: "clean".to_string(),
+            ci_status: CiStatus::Success,
+            review_decision: Some("APPROVED".to_string()),
+            approvals: 1,
+            changes_requested: false,
+            head_sha: "abc123".to_string(),
+            required_approvals: 1,
+            missing_required_checks: Vec::new(),
+        };
+        assert!(ready.is_ready());
+
+        let needs_review = PrMergeStatus {
+            required_approvals: 2,
+            ..ready.clone()
+        };
+        assert!(!needs_review.is_ready());
+        assert!(!needs_review.is_waiting());
+        assert!(needs_review.is_blocked());
+        assert_eq!(needs_review.status_text(), "Review approval");
+
+        let missing_check = PrMergeStatus {
+            missing_required_checks: vec!["lint".to_string()],
+            ..ready
+        };
+        assert!(!missing_check.is_ready());
+        assert!(missing_check.is_waiting());
+        assert!(!missing_check.is_blocked());
+        assert_eq!(missing_check.status_text(), "Required checks");
+    }
+
+    #[test]
+    fn test_merge_status_from_graphql_reports_unreported_required_checks() {
+        let pr: PullRequestMergeStatusData = serde_json::from_value(serde_json::json!({
+            "number": 7,
+            "title": "Protected",
+            "state": "OPEN",
+            "updatedAt": "2026-06-02T10:00:00Z",
+            "isDraft": false,
+            "mergeable": "MERGEABLE",
+            "reviewDecision": "APPROVED",
+            "headRefOid": "abc123",
+            "baseRefName": "main",
+            "statusCheckRollup": {
+                "state": "SUCCESS",
+                "contexts": { "nodes": [
+                    { "__typename": "CheckRun", "name": "test", "status": "COMPLETED", "conclusion": "SUCCESS" }
+                ] }
+            },
+            "reviews": { "nodes": [] }
+        }))
+        .unwrap();
+        let protection = BranchProtection {
+            required_checks: vec!["test".to_string(), "lint".to_string()],
+            required_approvals: 1,
+        };
+
+        let status = merge_status_from_graphql(pr, Some(&protection));
+
+        assert_eq!(status.missing_required_checks, vec!["lint".to_string()]);
+        assert_eq!(status.required_approvals, 1);
+        assert!(!status.is_ready());
+    }
+
     #[test]
     fn test_pr_merge_status_is_waiting_ci_pending() {
         let status = PrMergeStatus {
@@ -1567,6 +2172,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
 
         assert!(!status.is_ready());
@@ -1589,6 +2196,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
 
         assert!(!status.is_ready());
@@ -1610,6 +2219,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
 
         assert!(!status.is_ready());
@@ -1631,6 +2242,8 @@ mod tests {
             approvals: 0,
             changes_requested: true,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
 
         assert!(!status.is_ready());
@@ -1652,6 +2265,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
 
         assert!(!status.is_ready());
@@ -1673,6 +2288,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
 
         assert!(!status.is_ready());
@@ -1695,6 +2312,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
         assert_eq!(status.status_text(), "Ready");
 
@@ -1748,6 +2367,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let prs = vec![StackPrInfo {
@@ -1778,6 +2399,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let prs = vec![
@@ -1822,6 +2445,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let prs = vec![
@@ -1886,6 +2511,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let prs = vec![
@@ -1927,6 +2554,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let prs = vec![
@@ -1961,6 +2590,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://gitlab.com".to_string(),
             api_base_url: Some("https://gitlab.com/api/v4".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let prs = vec![
@@ -1999,6 +2630,8 @@ mod tests {
             repo: "project".to_string(),
             base_url: "https://gitea.example.com".to_string(),
             api_base_url: Some("https://gitea.example.com/api/v1".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let prs = vec![StackPrInfo {
@@ -2025,6 +2658,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://gitlab.com".to_string(),
             api_base_url: Some("https://gitlab.com/api/v4".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let prs = vec![StackPrInfo {
@@ -2069,6 +2704,22 @@ mod tests {
         assert_eq!(body.matches(STACK_LINKS_BODY_START_MARKER).count(), 1);
     }
 
+    #[test]
+    fn test_upsert_stack_links_fills_template_placeholder_in_place() {
+        let existing = format!(
+            "## Summary\n\nhello\n\n{}\n\n## Testing\n\nok",
+            stack_links_placeholder()
+        );
+        let body = upsert_stack_links_in_body(&existing, "## Stack Links\n\nnew");
+        assert_eq!(
+            body,
+            format!(
+                "## Summary\n\nhello\n\n{}\n## Stack Links\n\nnew\n{}\n\n## Testing\n\nok",
+                STACK_LINKS_BODY_START_MARKER, STACK_LINKS_BODY_END_MARKER
+            )
+        );
+    }
+
     #[test]
     fn test_remove_stack_links_from_body_preserves_surrounding_content() {
         let existing = format!(
@@ -2141,6 +2792,8 @@ mod tests {
             approvals: 1,
             changes_requested: false,
             head_sha: "abc123".to_string(),
+            required_approvals: 0,
+            missing_required_checks: Vec::new(),
         };
         let cloned = status.clone();
         assert_eq!(cloned.number, 1);
@@ -2220,6 +2873,37 @@ mod tests {
         assert_eq!(body, "## Summary\n\nhello");
     }
 
+    #[tokio::test]
+    async fn test_enable_auto_merge_sends_merge_method() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("pullRequest(number: 7)"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "repository": { "pullRequest": { "id": "PR_node_7" } } }
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("enablePullRequestAutoMerge"))
+            .and(body_string_contains("PR_node_7"))
+            .and(body_string_contains("mergeMethod: REBASE"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "enablePullRequestAutoMerge": { "pullRequest": { "number": 7 } } }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(&mock_server).await;
+        client
+            .enable_auto_merge(7, MergeMethod::Rebase)
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn test_get_pr_merge_status_maps_graphql_fields() {
         let mock_server = MockServer::start().await;
@@ -2268,6 +2952,54 @@ mod tests {
         assert_eq!(status.head_sha, "aaaa");
     }
 
+    #[tokio::test]
+    async fn test_get_pr_merge_statuses_batches_prs_into_one_query() {
+        let mock_server = MockServer::start().await;
+        let pr = |number: u64, decision: &str| {
+            serde_json::json!({
+                "number": number,
+                "title": format!("PR {}", number),
+                "state": "OPEN",
+                "updatedAt": "2026-06-02T10:00:00Z",
+                "isDraft": false,
+                "mergeable": "MERGEABLE",
+                "reviewDecision": decision,
+                "headRefOid": format!("sha{}", number),
+                "statusCheckRollup": { "state": "SUCCESS" },
+                "reviews": { "nodes": [] }
+            })
+        };
+
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "repository": {
+                        "pr0": pr(11, "APPROVED"),
+                        "pr1": pr(12, "CHANGES_REQUESTED"),
+                        "pr2": null
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(&mock_server).await;
+        let statuses = client.get_pr_merge_statuses(&[11, 12, 13]).await.unwrap();
+
+        assert_eq!(statuses.len(), 2);
+        assert_eq!(statuses[&11].head_sha, "sha11");
+        assert!(statuses[&12].changes_requested);
+        assert!(!statuses.contains_key(&13));
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        let query = body["query"].as_str().unwrap();
+        assert!(query.contains("pr0: pullRequest(number: 11)"));
+        assert!(query.contains("pr2: pullRequest(number: 13)"));
+    }
+
     // A superseded CHANGES_REQUESTED review must not block a PR whose
     // reviewDecision is APPROVED — the reviews list retains historical events.
     #[tokio::test]
@@ -2803,6 +3535,75 @@ mod tests {
         assert_eq!(body["body"], format!("{}\nnew body", STACK_COMMENT_MARKER));
     }
 
+    #[tokio::test]
+    async fn test_update_stack_comment_skips_unchanged_comment() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test-owner/test-repo/issues/11/comments"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                issue_comment_fixture(101, "<!-- stax-stack-comment -->\nsame body")
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(&mock_server).await;
+        client.update_stack_comment(11, "same body").await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(
+            requests
+                .iter()
+                .all(|request| request.method.as_str() == "GET"),
+            "unchanged stack comment should not be rewritten"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_stack_comment_finds_comment_past_first_page() {
+        let mock_server = MockServer::start().await;
+
+        let first_page: Vec<serde_json::Value> = (0..100)
+            .map(|id| issue_comment_fixture(id, "review chatter"))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/repos/test-owner/test-repo/issues/11/comments"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(first_page))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test-owner/test-repo/issues/11/comments"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                issue_comment_fixture(201, "<!-- stax-stack-comment -->\nold")
+            ])))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/test-owner/test-repo/issues/comments/201"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(issue_comment_fixture(
+                    201,
+                    "<!-- stax-stack-comment -->\nnew body",
+                )),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(&mock_server).await;
+        client.update_stack_comment(11, "new body").await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(
+            !requests
+                .iter()
+                .any(|request| request.method.as_str() == "POST"),
+            "must not post a duplicate stack comment"
+        );
+    }
+
     #[tokio::test]
     async fn test_delete_stack_comment_deletes_existing_comment() {
         let mock_server = MockServer::start().await;
@@ -2883,6 +3684,70 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_create_pr_qualifies_head_with_fork_owner() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/repos/test-owner/test-repo/pulls"))
+            .and(body_partial_json(serde_json::json!({
+                "head": "fork-owner:feature-a",
+                "base": "main"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "url": "https://api.github.com/repos/test-owner/test-repo/pulls/21",
+                "id": 21,
+                "number": 21,
+                "state": "open",
+                "draft": false,
+                "head": { "ref": "feature-a", "sha": "aaaa", "label": "fork-owner:feature-a" },
+                "base": { "ref": "main", "sha": "bbbb" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(&mock_server)
+            .await
+            .with_head_owner(Some("fork-owner".to_string()));
+        let pr = client
+            .create_pr("feature-a", "main", "Title", "Body", false)
+            .await
+            .unwrap();
+
+        assert_eq!(pr.number, 21);
+    }
+
+    #[tokio::test]
+    async fn test_find_pr_filters_by_fork_head_owner() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test-owner/test-repo/pulls"))
+            .and(query_param("state", "open"))
+            .and(query_param("head", "fork-owner:feature-a"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "url": "https://api.github.com/repos/test-owner/test-repo/pulls/21",
+                    "id": 21,
+                    "number": 21,
+                    "head": { "ref": "feature-a", "sha": "aaaa", "label": "fork-owner:feature-a" },
+                    "base": { "ref": "main", "sha": "bbbb" },
+                    "draft": false
+                }
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = create_test_client(&mock_server)
+            .await
+            .with_head_owner(Some("fork-owner".to_string()));
+        let pr = client.find_pr("feature-a").await.unwrap();
+
+        assert_eq!(pr.map(|pr| pr.number), Some(21));
+    }
+
     #[tokio::test]
     async fn test_find_open_pr_by_head_uses_head_filter() {
         let mock_server = MockServer::start().await;
@@ -18,7 +18,8 @@ pub struct PrTemplate {
 /// Discover all PR templates in standard GitHub locations
 ///
 /// Priority order:
-/// 1. .github/PULL_REQUEST_TEMPLATE/ directory - scan for all .md files
+/// 1. A template directory - scan for all .md files. Checked in
+///    .github/, the repository root, and docs/, upper- and lowercase
 /// 2. .github/PULL_REQUEST_TEMPLATE.md - single template (named "Default")
 /// 3. .github/pull_request_template.md - lowercase variant
 /// 4. PULL_REQUEST_TEMPLATE.md at repository root (GitHub-supported)
@@ -29,9 +30,22 @@ pub struct PrTemplate {
 pub fn discover_pr_templates(workdir: &Path) -> Result<Vec<PrTemplate>> {
     let mut templates = Vec::new();
 
-    // Check directory first (multiple templates)
-    let template_dir = workdir.join(".github/PULL_REQUEST_TEMPLATE");
-    if template_dir.is_dir() {
+    // Check directories first (multiple templates)
+    let template_dir_candidates = [
+        ".github/PULL_REQUEST_TEMPLATE",
+        ".github/pull_request_template",
+        "PULL_REQUEST_TEMPLATE",
+        "pull_request_template",
+        "docs/PULL_REQUEST_TEMPLATE",
+        "docs/pull_request_template",
+    ];
+
+    for candidate in &template_dir_candidates {
+        let template_dir = workdir.join(candidate);
+        if !template_dir.is_dir() {
+            continue;
+        }
+
         let mut entries: Vec<_> = fs::read_dir(&template_dir)
             .context("Failed to read PR template directory")?
             .filter_map(|entry| entry.ok())
@@ -200,6 +214,26 @@ mod tests {
         assert!(names.contains(&"feature"));
     }
 
+    #[test]
+    fn test_discover_lowercase_and_docs_template_directories() {
+        let dir = TempDir::new().unwrap();
+        let template_dir = dir.path().join("docs/pull_request_template");
+        fs::create_dir_all(&template_dir).unwrap();
+        fs::write(template_dir.join("release.md"), "# Release").unwrap();
+
+        let templates = discover_pr_templates(dir.path()).unwrap();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].name, "release");
+
+        let github_dir = dir.path().join(".github/pull_request_template");
+        fs::create_dir_all(&github_dir).unwrap();
+        fs::write(github_dir.join("feature.md"), "# Feature").unwrap();
+
+        let templates = discover_pr_templates(dir.path()).unwrap();
+        assert_eq!(templates.len(), 1);
+        assert_eq!(templates[0].name, "feature");
+    }
+
     #[test]
     fn test_discover_no_templates() {
         let dir = TempDir::new().unwrap();
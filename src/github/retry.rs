@@ -0,0 +1,567 @@
+//! Retries for GitHub API calls.
+//!
+//! Octocrab clients keep [`octocrab_retry_config`], which repeats any failed
+//! request once straight away, so writes and reads stax does not wrap still
+//! ride out a single dropped connection or 5xx. Read calls that may fail for
+//! longer go through [`with_backoff`], which retries 5xx responses, dropped
+//! connections and rate limits with exponential backoff and jitter. A rate
+//! limit waits as long as GitHub's `Retry-After` or `x-ratelimit-reset` header
+//! asks, or [`Backoff::rate_limit_wait`] when it names no time, but never
+//! longer than [`Backoff::max_rate_limit_wait`]. Each wait prints a
+//! "retrying in Ns" notice on stderr unless the command runs with `--quiet`.
+
+use anyhow::Result;
+use colored::Colorize;
+use http::{HeaderMap, StatusCode};
+use octocrab::service::middleware::retry::RetryConfig;
+use std::fmt;
+use std::future::Future;
+use std::time::Duration;
+
+fn notify(reason: &str, delay: Duration) {
+    let secs = delay.as_secs_f64().ceil() as u64;
+    eprintln!(
+        "  {}",
+        format!("{}, retrying in {}s", reason, secs).yellow()
+    );
+}
+
+/// Octocrab retry policy for every GitHub client stax builds: one immediate
+/// retry of a failed request.
+pub fn octocrab_retry_config() -> RetryConfig {
+    RetryConfig::Simple(1)
+}
+
+/// Wait a rate-limited reply asks for: `Retry-After`, or the time left until
+/// `x-ratelimit-reset` once `x-ratelimit-remaining` reaches zero.
+pub(crate) fn requested_wait(headers: &HeaderMap) -> Option<Duration> {
+    let header = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<i64>().ok();
+    if let Some(secs) = header("retry-after") {
+        return Some(Duration::from_secs(secs.max(0) as u64));
+    }
+    if header("x-ratelimit-remaining") == Some(0) {
+        let reset = header("x-ratelimit-reset")?;
+        let secs = reset - chrono::Utc::now().timestamp();
+        return Some(Duration::from_secs(secs.max(1) as u64));
+    }
+    None
+}
+
+/// A GitHub error reply together with the wait its headers asked for.
+#[derive(Debug)]
+struct RetryAfter {
+    wait: Duration,
+    error: octocrab::Error,
+}
+
+impl fmt::Display for RetryAfter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl std::error::Error for RetryAfter {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Error for a failed reply, keeping the wait from [`requested_wait`] so
+/// [`with_backoff`] can honor it.
+pub(crate) fn response_error(error: octocrab::Error, wait: Option<Duration>) -> anyhow::Error {
+    match wait {
+        Some(wait) => RetryAfter { wait, error }.into(),
+        None => error.into(),
+    }
+}
+
+/// Retry schedule for [`with_backoff`].
+#[derive(Debug, Clone, Copy)]
+pub struct Backoff {
+    pub retries: u32,
+    pub base: Duration,
+    pub max: Duration,
+    /// Minimum wait after a rate limit. GitHub asks for at least a minute
+    /// after a secondary rate limit.
+    pub rate_limit_wait: Duration,
+    /// Longest single wait after a rate limit.
+    pub max_rate_limit_wait: Duration,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            retries: 3,
+            base: Duration::from_secs(1),
+            max: Duration::from_secs(30),
+            rate_limit_wait: Duration::from_secs(60),
+            max_rate_limit_wait: Duration::from_secs(120),
+        }
+    }
+}
+
+impl Backoff {
+    /// Delay before retry `attempt` (0-based): the doubled step capped at
+    /// `max`, with its upper half scaled by `jitter` in `[0, 1)`.
+    fn delay(&self, attempt: u32, jitter: f64) -> Duration {
+        let step = self
+            .base
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max);
+        step / 2 + step.mul_f64(jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Transient {
+    /// Rate limited, with the wait GitHub asked for if it named one.
+    RateLimited(Option<Duration>),
+    ServerError(u16),
+    Connection,
+}
+
+impl Transient {
+    fn reason(self) -> String {
+        match self {
+            Self::RateLimited(_) => "rate limited".to_string(),
+            Self::ServerError(status) => format!("GitHub returned {}", status),
+            Self::Connection => "connection to GitHub failed".to_string(),
+        }
+    }
+}
+
+/// Whether `err` is worth retrying, based on the octocrab error it wraps.
+fn classify(err: &anyhow::Error) -> Option<Transient> {
+    let (err, requested) = err.chain().find_map(|cause| {
+        if let Some(reply) = cause.downcast_ref::<RetryAfter>() {
+            Some((&reply.error, Some(reply.wait)))
+        } else {
+            cause
+                .downcast_ref::<octocrab::Error>()
+                .map(|err| (err, None))
+        }
+    })?;
+    match err {
+        octocrab::Error::GitHub { source, .. } => {
+            let status = source.status_code;
+            let rate_limit = status == StatusCode::FORBIDDEN
+                && (requested.is_some()
+                    || source.message.to_ascii_lowercase().contains("rate limit"));
+            if status == StatusCode::TOO_MANY_REQUESTS || rate_limit {
+                Some(Transient::RateLimited(requested))
+            } else if status.is_server_error() {
+                Some(Transient::ServerError(status.as_u16()))
+            } else {
+                None
+            }
+        }
+        // Timeouts are left alone so `--timeout` still fails fast.
+        octocrab::Error::Hyper { .. } | octocrab::Error::Service { .. } if !is_timeout(err) => {
+            Some(Transient::Connection)
+        }
+        _ => None,
+    }
+}
+
+fn is_timeout(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut cause = Some(err);
+    while let Some(err) = cause {
+        let io_timeout = err
+            .downcast_ref::<std::io::Error>()
+            .is_some_and(|err| err.kind() == std::io::ErrorKind::TimedOut);
+        if io_timeout || err.to_string().contains("timed out") {
+            return true;
+        }
+        cause = err.source();
+    }
+    false
+}
+
+/// Run an API call, retrying transient failures with [`Backoff::default`].
+/// Only wrap calls that are safe to repeat. `quiet` hides the wait notices.
+pub async fn with_backoff<T, E, F, Fut>(quiet: bool, call: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, E>>,
+    E: Into<anyhow::Error>,
+{
+    retry_with(Backoff::default(), quiet, call).await
+}
+
+/// [`with_backoff`] with an explicit schedule.
+pub async fn retry_with<T, E, F, Fut>(backoff: Backoff, quiet: bool, mut call: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, E>>,
+    E: Into<anyhow::Error>,
+{
+    let mut attempt = 0;
+    loop {
+        let err = match call().await {
+            Ok(value) => return Ok(value),
+            Err(err) => err.into(),
+        };
+        let transient = match classify(&err) {
+            Some(transient) if attempt < backoff.retries => transient,
+            _ => return Err(err),
+        };
+        let mut delay = backoff.delay(attempt, fastrand::f64());
+        if let Transient::RateLimited(requested) = transient {
+            delay = requested
+                .unwrap_or(delay.max(backoff.rate_limit_wait))
+                .min(backoff.max_rate_limit_wait);
+        }
+        if !quiet {
+            notify(&transient.reason(), delay);
+        }
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use octocrab::Octocrab;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn octocrab(server: &MockServer, retry_config: RetryConfig) -> Octocrab {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        Octocrab::builder()
+            .base_uri(server.uri())
+            .unwrap()
+            .add_retry_config(retry_config)
+            .build()
+            .unwrap()
+    }
+
+    fn error_response(status: u16, message: &str) -> ResponseTemplate {
+        ResponseTemplate::new(status).set_body_json(serde_json::json!({ "message": message }))
+    }
+
+    async fn mount_once(server: &MockServer, response: ResponseTemplate) {
+        Mock::given(method("GET"))
+            .and(path("/resource"))
+            .respond_with(response)
+            .up_to_n_times(1)
+            .mount(server)
+            .await;
+    }
+
+    async fn github_error(status: u16, message: &str) -> anyhow::Error {
+        let server = MockServer::start().await;
+        mount_once(&server, error_response(status, message)).await;
+        octocrab(&server, RetryConfig::None)
+            .get::<serde_json::Value, _, _>("/resource", None::<&()>)
+            .await
+            .unwrap_err()
+            .into()
+    }
+
+    fn fast_backoff() -> Backoff {
+        Backoff {
+            retries: 3,
+            base: Duration::from_millis(1),
+            max: Duration::from_millis(4),
+            rate_limit_wait: Duration::from_millis(2),
+            max_rate_limit_wait: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    fn delay_doubles_up_to_the_cap_with_jitter_in_the_upper_half() {
+        let backoff = Backoff {
+            retries: 5,
+            base: Duration::from_secs(1),
+            max: Duration::from_secs(8),
+            rate_limit_wait: Duration::from_secs(60),
+            max_rate_limit_wait: Duration::from_secs(120),
+        };
+
+        assert_eq!(backoff.delay(0, 0.0), Duration::from_millis(500));
+        assert_eq!(backoff.delay(1, 0.0), Duration::from_secs(1));
+        assert_eq!(backoff.delay(2, 0.5), Duration::from_secs(3));
+        assert_eq!(backoff.delay(3, 0.0), Duration::from_secs(4));
+        assert_eq!(backoff.delay(10, 0.0), Duration::from_secs(4));
+        assert!(backoff.delay(10, 0.999) < Duration::from_secs(8));
+    }
+
+    #[tokio::test]
+    async fn classify_retries_server_errors_and_rate_limits_only() {
+        assert_eq!(
+            classify(&github_error(502, "Bad Gateway").await),
+            Some(Transient::ServerError(502))
+        );
+        assert_eq!(
+            classify(&github_error(429, "Too Many Requests").await),
+            Some(Transient::RateLimited(None))
+        );
+        assert_eq!(
+            classify(
+                &github_error(
+                    403,
+                    "You have exceeded a secondary rate limit. Please wait a few minutes."
+                )
+                .await
+            ),
+            Some(Transient::RateLimited(None))
+        );
+        assert_eq!(
+            classify(&github_error(403, "Resource not accessible").await),
+            None
+        );
+        assert_eq!(classify(&github_error(404, "Not Found").await), None);
+        assert_eq!(classify(&anyhow::anyhow!("not an API error")), None);
+    }
+
+    #[tokio::test]
+    async fn classify_retries_refused_connections_but_not_timeouts() {
+        // Nothing listens on a port freed right after binding it.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let uri = format!("http://127.0.0.1:{}", port);
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let refused: anyhow::Error = Octocrab::builder()
+            .base_uri(uri)
+            .unwrap()
+            .add_retry_config(RetryConfig::None)
+            .build()
+            .unwrap()
+            .get::<serde_json::Value, _, _>("/resource", None::<&()>)
+            .await
+            .unwrap_err()
+            .into();
+        assert_eq!(classify(&refused), Some(Transient::Connection));
+
+        let server = MockServer::start().await;
+        mount_once(
+            &server,
+            ResponseTemplate::new(200).set_delay(Duration::from_secs(5)),
+        )
+        .await;
+        let timed_out: anyhow::Error = Octocrab::builder()
+            .base_uri(server.uri())
+            .unwrap()
+            .add_retry_config(RetryConfig::None)
+            .set_read_timeout(Some(Duration::from_millis(100)))
+            .build()
+            .unwrap()
+            .get::<serde_json::Value, _, _>("/resource", None::<&()>)
+            .await
+            .unwrap_err()
+            .into();
+        assert_eq!(classify(&timed_out), None);
+    }
+
+    #[tokio::test]
+    async fn classify_sees_through_context() {
+        let err = github_error(503, "Service Unavailable")
+            .await
+            .context("Failed to get PR");
+        assert_eq!(classify(&err), Some(Transient::ServerError(503)));
+    }
+
+    #[tokio::test]
+    async fn retry_with_recovers_from_transient_errors() {
+        let server = MockServer::start().await;
+        mount_once(&server, error_response(502, "Bad Gateway")).await;
+        mount_once(&server, error_response(429, "Too Many Requests")).await;
+        mount_once(
+            &server,
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "ok": true })),
+        )
+        .await;
+        let client = octocrab(&server, RetryConfig::None);
+
+        let value: serde_json::Value = retry_with(fast_backoff(), true, || {
+            client.get("/resource", None::<&()>)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(value["ok"], true);
+        assert_eq!(server.received_requests().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn retry_with_gives_up_after_the_configured_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/resource"))
+            .respond_with(error_response(500, "Internal Server Error"))
+            .mount(&server)
+            .await;
+        let client = octocrab(&server, RetryConfig::None);
+
+        let err = retry_with(fast_backoff(), true, || {
+            client.get::<serde_json::Value, _, _>("/resource", None::<&()>)
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(server.received_requests().await.unwrap().len(), 4);
+        assert_eq!(classify(&err), Some(Transient::ServerError(500)));
+    }
+
+    #[tokio::test]
+    async fn retry_with_does_not_retry_client_errors() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/resource"))
+            .respond_with(error_response(404, "Not Found"))
+            .mount(&server)
+            .await;
+        let client = octocrab(&server, RetryConfig::None);
+
+        retry_with(fast_backoff(), true, || {
+            client.get::<serde_json::Value, _, _>("/resource", None::<&()>)
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn retry_with_caps_rate_limit_waits() {
+        let server = MockServer::start().await;
+        mount_once(&server, error_response(403, "API rate limit exceeded")).await;
+        mount_once(
+            &server,
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({})),
+        )
+        .await;
+        let client = octocrab(&server, RetryConfig::None);
+        let backoff = Backoff {
+            rate_limit_wait: Duration::from_secs(3600),
+            max_rate_limit_wait: Duration::from_millis(5),
+            ..fast_backoff()
+        };
+
+        let _: serde_json::Value = tokio::time::timeout(
+            Duration::from_secs(5),
+            retry_with(backoff, true, || client.get("/resource", None::<&()>)),
+        )
+        .await
+        .expect("rate-limit wait should be capped")
+        .unwrap();
+
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    /// `GET /resource` the way `GitHubClient::get_json` does, keeping the
+    /// wait a rate-limited reply asks for.
+    async fn get_with_requested_wait(client: &Octocrab) -> Result<serde_json::Value> {
+        let response = client._get("/resource").await?;
+        let wait = requested_wait(response.headers());
+        let response = octocrab::map_github_error(response)
+            .await
+            .map_err(|err| response_error(err, wait))?;
+        Ok(serde_json::from_str(
+            &client.body_to_string(response).await?,
+        )?)
+    }
+
+    #[test]
+    fn requested_wait_reads_retry_after_then_the_rate_limit_reset() {
+        let headers = |pairs: &[(&'static str, String)]| {
+            let mut map = HeaderMap::new();
+            for (name, value) in pairs {
+                map.insert(*name, value.parse().unwrap());
+            }
+            map
+        };
+        let reset = (chrono::Utc::now().timestamp() + 90).to_string();
+
+        assert_eq!(
+            requested_wait(&headers(&[("retry-after", "30".into())])),
+            Some(Duration::from_secs(30))
+        );
+        let waited = requested_wait(&headers(&[
+            ("x-ratelimit-remaining", "0".into()),
+            ("x-ratelimit-reset", reset.clone()),
+        ]))
+        .unwrap();
+        assert!(waited > Duration::from_secs(80) && waited <= Duration::from_secs(90));
+        assert_eq!(
+            requested_wait(&headers(&[
+                ("x-ratelimit-remaining", "12".into()),
+                ("x-ratelimit-reset", reset),
+            ])),
+            None
+        );
+        assert_eq!(requested_wait(&HeaderMap::new()), None);
+    }
+
+    #[tokio::test]
+    async fn retry_with_waits_as_long_as_retry_after_asks() {
+        let server = MockServer::start().await;
+        mount_once(
+            &server,
+            error_response(403, "You have exceeded a secondary rate limit")
+                .insert_header("retry-after", "0"),
+        )
+        .await;
+        mount_once(
+            &server,
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "ok": true })),
+        )
+        .await;
+        let client = octocrab(&server, RetryConfig::None);
+        let backoff = Backoff {
+            rate_limit_wait: Duration::from_secs(3600),
+            max_rate_limit_wait: Duration::from_secs(3600),
+            ..fast_backoff()
+        };
+
+        let value = tokio::time::timeout(
+            Duration::from_secs(5),
+            retry_with(backoff, true, || get_with_requested_wait(&client)),
+        )
+        .await
+        .expect("Retry-After: 0 should not wait for the fallback")
+        .unwrap();
+
+        assert_eq!(value["ok"], true);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn retry_with_caps_waits_named_by_retry_after() {
+        let server = MockServer::start().await;
+        mount_once(
+            &server,
+            error_response(429, "Too Many Requests").insert_header("retry-after", "3600"),
+        )
+        .await;
+        mount_once(
+            &server,
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({})),
+        )
+        .await;
+        let client = octocrab(&server, RetryConfig::None);
+        let err = get_with_requested_wait(&client).await.unwrap_err();
+        assert_eq!(
+            classify(&err),
+            Some(Transient::RateLimited(Some(Duration::from_secs(3600))))
+        );
+        assert_eq!(
+            err.to_string(),
+            github_error(429, "Too Many Requests").await.to_string()
+        );
+
+        let _ = tokio::time::timeout(
+            Duration::from_secs(5),
+            retry_with(fast_backoff(), true, || get_with_requested_wait(&client)),
+        )
+        .await
+        .expect("Retry-After wait should be capped")
+        .unwrap();
+
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,182 @@
+//! Token validation for `stax auth status`: who the token belongs to and what
+//! GitHub reports about its scopes, rate limit, and expiry.
+
+use anyhow::{Context, Result, bail};
+use reqwest::header::{ACCEPT, HeaderMap, USER_AGENT};
+use serde::Deserialize;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenInfo {
+    pub login: String,
+    /// `None` for fine-grained tokens and GitHub App tokens, which report no
+    /// OAuth scopes.
+    pub scopes: Option<Vec<String>>,
+    pub rate_limit_remaining: Option<u64>,
+    pub rate_limit_limit: Option<u64>,
+    /// Unix timestamp when the rate limit window resets.
+    pub rate_limit_reset: Option<i64>,
+    /// Expiry as reported by GitHub, e.g. `2026-11-01 00:00:00 UTC`.
+    pub expires_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuthenticatedUser {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    message: Option<String>,
+}
+
+/// Call `GET /user` with `token` and collect what the response says about it.
+pub async fn fetch_token_info(api_base_url: &str, token: &str) -> Result<TokenInfo> {
+    let client = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(10))
+        .build()
+        .context("Failed to build GitHub HTTP client")?;
+    let url = format!("{}/user", api_base_url.trim_end_matches('/'));
+    let response = client
+        .get(&url)
+        .bearer_auth(token)
+        .header(ACCEPT, "application/vnd.github+json")
+        .header(USER_AGENT, "stax")
+        .send()
+        .await
+        .with_context(|| format!("Could not reach {}", url))?;
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    if !status.is_success() {
+        let message = response
+            .json::<ErrorBody>()
+            .await
+            .ok()
+            .and_then(|body| body.message)
+            .unwrap_or_else(|| status.canonical_reason().unwrap_or("").to_string());
+        bail!(
+            "GitHub rejected the token ({}): {}",
+            status.as_u16(),
+            message
+        );
+    }
+
+    let user: AuthenticatedUser = response
+        .json()
+        .await
+        .context("Unexpected user response from GitHub")?;
+    Ok(TokenInfo {
+        login: user.login,
+        scopes: header_str(&headers, "x-oauth-scopes").map(|scopes| {
+            scopes
+                .split(',')
+                .map(str::trim)
+                .filter(|scope| !scope.is_empty())
+                .map(str::to_string)
+                .collect()
+        }),
+        rate_limit_remaining: header_str(&headers, "x-ratelimit-remaining")
+            .and_then(|value| value.parse().ok()),
+        rate_limit_limit: header_str(&headers, "x-ratelimit-limit")
+            .and_then(|value| value.parse().ok()),
+        rate_limit_reset: header_str(&headers, "x-ratelimit-reset")
+            .and_then(|value| value.parse().ok()),
+        expires_at: header_str(&headers, "github-authentication-token-expiration")
+            .map(str::to_string),
+    })
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn ensure_crypto_provider() {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+    }
+
+    #[tokio::test]
+    async fn fetch_token_info_reads_scopes_rate_limit_and_expiry() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .and(header("authorization", "Bearer ghp_abc"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-oauth-scopes", "repo, read:org")
+                    .insert_header("x-ratelimit-remaining", "4990")
+                    .insert_header("x-ratelimit-limit", "5000")
+                    .insert_header("x-ratelimit-reset", "1790000000")
+                    .insert_header(
+                        "github-authentication-token-expiration",
+                        "2026-11-01 00:00:00 UTC",
+                    )
+                    .set_body_json(serde_json::json!({ "login": "octocat" })),
+            )
+            .mount(&server)
+            .await;
+
+        let info = fetch_token_info(&server.uri(), "ghp_abc").await.unwrap();
+
+        assert_eq!(
+            info,
+            TokenInfo {
+                login: "octocat".to_string(),
+                scopes: Some(vec!["repo".to_string(), "read:org".to_string()]),
+                rate_limit_remaining: Some(4990),
+                rate_limit_limit: Some(5000),
+                rate_limit_reset: Some(1_790_000_000),
+                expires_at: Some("2026-11-01 00:00:00 UTC".to_string()),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_token_info_treats_missing_scopes_header_as_fine_grained() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "login": "octocat" })),
+            )
+            .mount(&server)
+            .await;
+
+        let info = fetch_token_info(&server.uri(), "github_pat_abc")
+            .await
+            .unwrap();
+
+        assert_eq!(info.scopes, None);
+        assert_eq!(info.expires_at, None);
+    }
+
+    #[tokio::test]
+    async fn fetch_token_info_reports_rejected_tokens() {
+        ensure_crypto_provider();
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/user"))
+            .respond_with(
+                ResponseTemplate::new(401)
+                    .set_body_json(serde_json::json!({ "message": "Bad credentials" })),
+            )
+            .mount(&server)
+            .await;
+
+        let err = fetch_token_info(&server.uri(), "bad").await.unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "GitHub rejected the token (401): Bad credentials"
+        );
+    }
+}
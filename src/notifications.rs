@@ -17,11 +17,34 @@ pub enum Sound {
     Path(PathBuf),
 }
 
-/// Show a desktop notification (macOS only; a no-op on other platforms).
-pub fn send_desktop_notification(title: &str, message: &str) {
+/// Announce that a long-running command finished. Always shown on macOS;
+/// elsewhere only with `desktop` (`--notify` / `notify.desktop`).
+pub fn send_desktop_notification(title: &str, message: &str, desktop: bool) {
+    if cfg!(target_os = "macos") || desktop {
+        show_notification(title, message);
+    }
+}
+
+/// Announce progress during a long wait (a PR became ready or failed, CI
+/// finished). Only shown with `desktop`.
+pub fn send_wait_notification(title: &str, message: &str, desktop: bool) {
+    if desktop {
+        show_notification(title, message);
+    }
+}
+
+/// Best-effort: `osascript` on macOS, `notify-send` on Linux, nothing elsewhere.
+fn show_notification(title: &str, message: &str) {
     if cfg!(target_os = "macos") {
         let script = notification_script(title, message);
         let _ = Command::new("osascript").args(["-e", &script]).output();
+    } else if cfg!(target_os = "linux") {
+        let _ = Command::new("notify-send")
+            .args(["--app-name", "stax", title, message])
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status();
     }
 }
 
@@ -33,6 +33,7 @@ pub enum OpKind {
     Fix,
     Edit,
     Fold,
+    SquashIntoParent,
 }
 
 impl OpKind {
@@ -52,6 +53,7 @@ impl OpKind {
             OpKind::Fix => "stack fix",
             OpKind::Edit => "edit",
             OpKind::Fold => "fold",
+            OpKind::SquashIntoParent => "squash into parent",
         }
     }
 }
@@ -108,6 +110,17 @@ pub struct PlanSummary {
     pub description: Vec<String>,
 }
 
+/// Where an interrupted submit left off, for `stax submit --continue`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitResume {
+    /// Submit scope (`branch`, `downstack`, `upstack` or `stack`)
+    pub scope: String,
+    /// Branch the scope was resolved from
+    pub anchor: String,
+    /// Branches selected for the submit, in submission order
+    pub branches: Vec<String>,
+}
+
 /// Operation receipt - persisted to `.git/stax/ops/<op-id>.json`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpReceipt {
@@ -143,6 +156,9 @@ pub struct OpReceipt {
     /// Branches that completed successfully before a conflict stopped the operation
     #[serde(default)]
     pub completed_branches: Vec<String>,
+    /// Scope of a resumable submit (only set on submit progress records)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submit_resume: Option<SubmitResume>,
 }
 
 impl OpReceipt {
@@ -172,6 +188,7 @@ impl OpReceipt {
             plan_summary: PlanSummary::default(),
             error: None,
             completed_branches: Vec::new(),
+            submit_resume: None,
         }
     }
 
@@ -198,3 +198,101 @@ impl LiveTimer {
         }
     }
 }
+
+/// Numbers the per-branch steps of a multi-branch operation (`[2/3] Restack feature`)
+/// and starts a [`LiveTimer`] for each step when enabled.
+pub struct StepProgress {
+    enabled: bool,
+    step: usize,
+    total: usize,
+}
+
+impl StepProgress {
+    pub fn new(enabled: bool, total: usize) -> Self {
+        Self {
+            enabled,
+            step: 0,
+            total,
+        }
+    }
+
+    /// Advance to the next step and start its timer (None when disabled).
+    pub fn start(&mut self, message: &str) -> Option<LiveTimer> {
+        self.start_at(self.step + 1, message)
+    }
+
+    /// Start the timer for a specific 1-based step, for loops that skip items
+    /// which turn out to need no work.
+    pub fn start_at(&mut self, step: usize, message: &str) -> Option<LiveTimer> {
+        self.step = step;
+        LiveTimer::maybe_new(self.enabled, &step_label(step, self.total, message))
+    }
+}
+
+/// `[k/n] message`; the counter is padded so labels line up for up to n steps.
+pub fn step_label(step: usize, total: usize, message: &str) -> String {
+    let width = total.to_string().len();
+    format!("[{:>width$}/{}] {}", step, total, message)
+}
+
+/// Renders restack progress events from the application layer as `[k/n]` steps.
+pub struct StepProgressReporter {
+    enabled: bool,
+    timer: Option<LiveTimer>,
+}
+
+impl StepProgressReporter {
+    pub fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            timer: None,
+        }
+    }
+}
+
+impl crate::application::OperationReporter for StepProgressReporter {
+    fn report(&mut self, event: crate::application::OperationEvent) {
+        use crate::application::{OperationErrorKind, OperationEvent};
+
+        match event {
+            OperationEvent::Progress(progress) => {
+                let (Some(total), Some(_)) = (progress.total, progress.branch.as_ref()) else {
+                    return;
+                };
+                LiveTimer::maybe_finish_timed(self.timer.take());
+                self.timer = LiveTimer::maybe_new(
+                    self.enabled,
+                    &step_label(progress.completed + 1, total, &progress.message),
+                );
+            }
+            OperationEvent::Completed(_) => LiveTimer::maybe_finish_timed(self.timer.take()),
+            OperationEvent::Failed(error) if error.kind == OperationErrorKind::RebaseConflict => {
+                LiveTimer::maybe_finish_warn(self.timer.take(), "conflict")
+            }
+            OperationEvent::Failed(_) => LiveTimer::maybe_finish_err(self.timer.take(), "failed"),
+            OperationEvent::Started(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_label_pads_counter_to_total_width() {
+        assert_eq!(step_label(2, 3, "Restack b"), "[2/3] Restack b");
+        assert_eq!(step_label(3, 12, "Restack c"), "[ 3/12] Restack c");
+    }
+
+    #[test]
+    fn step_progress_counts_each_started_step() {
+        let mut progress = StepProgress::new(false, 3);
+        assert!(progress.start("one").is_none());
+        assert!(progress.start("two").is_none());
+        assert_eq!(progress.step, 2);
+        assert!(progress.start_at(3, "three").is_none());
+        assert!(progress.start("four").is_none());
+        assert_eq!(progress.step, 4);
+    }
+}
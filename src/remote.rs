@@ -5,6 +5,7 @@ use git2::{ConfigLevel, Repository};
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::process::Command;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -13,6 +14,9 @@ pub enum ForgeType {
     GitLab,
     #[serde(alias = "forgejo")]
     Gitea,
+    Bitbucket,
+    #[serde(rename = "azuredevops", alias = "azure")]
+    AzureDevOps,
 }
 
 impl std::fmt::Display for ForgeType {
@@ -21,6 +25,8 @@ impl std::fmt::Display for ForgeType {
             Self::GitHub => write!(f, "GitHub"),
             Self::GitLab => write!(f, "GitLab"),
             Self::Gitea => write!(f, "Gitea"),
+            Self::Bitbucket => write!(f, "Bitbucket"),
+            Self::AzureDevOps => write!(f, "Azure DevOps"),
         }
     }
 }
@@ -34,6 +40,11 @@ pub struct RemoteInfo {
     pub repo: String,
     pub base_url: String,
     pub api_base_url: Option<String>,
+    /// Per-request timeout for forge API clients (`stax --timeout`).
+    pub request_timeout: Option<Duration>,
+    /// Owner of the fork that branches are pushed to, when `remote.push_remote`
+    /// points at a different repository than the pull request remote.
+    pub head_owner: Option<String>,
 }
 
 /// A remote whose Git host, provider, and API destination were validated before
@@ -58,20 +69,37 @@ impl TrustedRemoteInfo {
 impl RemoteInfo {
     pub fn from_repo(repo: &GitRepo, config: &Config) -> Result<Self> {
         let name = config.remote_name().to_string();
-        let url = get_remote_url(repo.workdir()?, &name)?;
+        let pr_remote = config.pr_remote_name();
+        let url = get_remote_url(repo.workdir()?, pr_remote)?;
         let (host, path) = parse_remote_url(&url)?;
         let forge = detect_forge(
             &host,
             config.remote_base_url(),
             config.remote_forge_override(),
         );
+        let (host, path) = match forge {
+            ForgeType::AzureDevOps => normalize_azure_remote(&host, &path),
+            // SSH over the HTTPS port (`ssh://git@ssh.github.com:443/...`).
+            ForgeType::GitHub if host.eq_ignore_ascii_case("ssh.github.com") => {
+                ("github.com".to_string(), path)
+            }
+            _ => (host, path),
+        };
         let (namespace, repo_name) = split_namespace_repo(&path)?;
+        let head_owner = if name != pr_remote {
+            let push_url = get_remote_url(repo.workdir()?, &name)?;
+            fork_head_owner(&push_url, &namespace, &repo_name)?
+        } else {
+            None
+        };
 
         let configured_base = config.remote_base_url().trim_end_matches('/');
         let base_url = if configured_base.is_empty()
             || (configured_base == "https://github.com" && host != "github.com")
             || (configured_base == "https://gitlab.com" && host != "gitlab.com")
             || (configured_base == "https://gitea.com" && host != "gitea.com")
+            || (configured_base == "https://bitbucket.org" && host != "bitbucket.org")
+            || (configured_base == "https://dev.azure.com" && host != "dev.azure.com")
         {
             format!("https://{}", url_authority_host(&host))
         } else {
@@ -92,6 +120,8 @@ impl RemoteInfo {
             repo: repo_name,
             base_url,
             api_base_url,
+            request_timeout: config.request_timeout(),
+            head_owner,
         })
     }
 
@@ -111,7 +141,12 @@ impl RemoteInfo {
     }
 
     pub fn repo_url(&self) -> String {
-        format!("{}/{}/{}", self.base_url, self.namespace, self.repo)
+        match self.forge {
+            ForgeType::AzureDevOps => {
+                format!("{}/{}/_git/{}", self.base_url, self.namespace, self.repo)
+            }
+            _ => format!("{}/{}/{}", self.base_url, self.namespace, self.repo),
+        }
     }
 
     pub fn pr_url(&self, number: u64) -> String {
@@ -119,10 +154,23 @@ impl RemoteInfo {
             ForgeType::GitHub => format!("{}/pull/{}", self.repo_url(), number),
             ForgeType::GitLab => format!("{}/-/merge_requests/{}", self.repo_url(), number),
             ForgeType::Gitea => format!("{}/pulls/{}", self.repo_url(), number),
+            ForgeType::Bitbucket => format!("{}/pull-requests/{}", self.repo_url(), number),
+            ForgeType::AzureDevOps => format!("{}/pullrequest/{}", self.repo_url(), number),
         }
     }
 }
 
+/// Owner of the push remote's repository when it is a fork of the pull request
+/// repository, so PR heads can be qualified as `owner:branch`.
+fn fork_head_owner(push_url: &str, pr_namespace: &str, pr_repo: &str) -> Result<Option<String>> {
+    let (_, path) = parse_remote_url(push_url)?;
+    let (namespace, repo) = split_namespace_repo(&path)?;
+    if namespace == pr_namespace && repo == pr_repo {
+        return Ok(None);
+    }
+    Ok(namespace.split('/').next().map(str::to_string))
+}
+
 fn detect_forge(
     host: &str,
     configured_base_url: &str,
@@ -143,25 +191,91 @@ fn detect_forge(
         || configured_base_url.contains("forgejo")
     {
         ForgeType::Gitea
+    } else if host.contains("bitbucket") || configured_base_url.contains("bitbucket") {
+        ForgeType::Bitbucket
+    } else if is_azure_devops_host(&host) || is_azure_devops_host(&configured_base_url) {
+        ForgeType::AzureDevOps
     } else {
         ForgeType::GitHub
     }
 }
 
+fn is_azure_devops_host(host: &str) -> bool {
+    host.contains("dev.azure.com") || host.contains("visualstudio.com")
+}
+
+/// Map the many Azure Repos remote shapes onto `dev.azure.com` and an
+/// `org/project/repo` path:
+///
+/// - `https://dev.azure.com/org/project/_git/repo`
+/// - `git@ssh.dev.azure.com:v3/org/project/repo`
+/// - `https://org.visualstudio.com/[DefaultCollection/]project/_git/repo`
+/// - `org@vs-ssh.visualstudio.com:v3/org/project/repo`
+///
+/// Azure DevOps Server hosts keep their host and only drop `_git`.
+fn normalize_azure_remote(host: &str, path: &str) -> (String, String) {
+    let mut parts: Vec<&str> = path
+        .split('/')
+        .filter(|part| !part.is_empty() && *part != "_git")
+        .collect();
+    if parts.first() == Some(&"v3") {
+        parts.remove(0);
+    }
+
+    if matches!(
+        host,
+        "dev.azure.com" | "ssh.dev.azure.com" | "vs-ssh.visualstudio.com"
+    ) {
+        return ("dev.azure.com".to_string(), parts.join("/"));
+    }
+    if let Some(org) = host.strip_suffix(".visualstudio.com") {
+        if parts.first() == Some(&"DefaultCollection") {
+            parts.remove(0);
+        }
+        let path = std::iter::once(org)
+            .chain(parts)
+            .collect::<Vec<_>>()
+            .join("/");
+        return ("dev.azure.com".to_string(), path);
+    }
+    (host.to_string(), parts.join("/"))
+}
+
 fn default_api_base_url(forge: ForgeType, base_url: &str) -> String {
     match forge {
         ForgeType::GitHub => {
             if base_url == "https://github.com" {
                 "https://api.github.com".to_string()
+            } else if let Some(host) = base_url
+                .strip_prefix("https://")
+                .filter(|host| is_ghe_cloud_host(host))
+            {
+                // GitHub Enterprise Cloud with data residency serves its API
+                // from `api.<subdomain>.ghe.com`.
+                format!("https://api.{}", host)
             } else {
+                // GitHub Enterprise Server.
                 format!("{}/api/v3", base_url)
             }
         }
         ForgeType::GitLab => format!("{}/api/v4", base_url),
         ForgeType::Gitea => format!("{}/api/v1", base_url),
+        ForgeType::Bitbucket => {
+            if base_url == "https://bitbucket.org" {
+                "https://api.bitbucket.org/2.0".to_string()
+            } else {
+                format!("{}/api/2.0", base_url)
+            }
+        }
+        ForgeType::AzureDevOps => base_url.to_string(),
     }
 }
 
+fn is_ghe_cloud_host(host: &str) -> bool {
+    host.strip_suffix(".ghe.com")
+        .is_some_and(|subdomain| !subdomain.is_empty() && !subdomain.contains('.'))
+}
+
 fn validate_trusted_network_remote(remote: &RemoteInfo, global_config: &Config) -> Result<()> {
     let remote_host = remote.host.to_ascii_lowercase();
     let base_host = network_url_host(&remote.base_url, "provider base URL")?;
@@ -200,7 +314,11 @@ fn validate_trusted_network_remote(remote: &RemoteInfo, global_config: &Config)
         (ForgeType::GitHub, "github.com", "api.github.com")
             | (ForgeType::GitLab, "gitlab.com", "gitlab.com")
             | (ForgeType::Gitea, "gitea.com", "gitea.com")
-    );
+            | (ForgeType::Bitbucket, "bitbucket.org", "api.bitbucket.org")
+            | (ForgeType::AzureDevOps, "dev.azure.com", "dev.azure.com")
+    ) || (remote.forge == ForgeType::GitHub
+        && is_ghe_cloud_host(&remote_host)
+        && api_host == format!("api.{remote_host}"));
     if api_host != remote_host
         && !built_in_relationship
         && global_config.remote.api_base_url.is_none()
@@ -219,6 +337,9 @@ fn official_forge_for_host(host: &str) -> Option<ForgeType> {
         "github.com" => Some(ForgeType::GitHub),
         "gitlab.com" => Some(ForgeType::GitLab),
         "gitea.com" => Some(ForgeType::Gitea),
+        "bitbucket.org" => Some(ForgeType::Bitbucket),
+        "dev.azure.com" => Some(ForgeType::AzureDevOps),
+        host if is_ghe_cloud_host(host) => Some(ForgeType::GitHub),
         _ => None,
     }
 }
@@ -305,6 +426,36 @@ pub fn get_remote_url(workdir: &Path, remote: &str) -> Result<String> {
     Ok(url)
 }
 
+/// Name of the git remote pointing at `owner`'s copy of a repo on `host`,
+/// such as the fork a cross-repo PR's head branch lives in.
+pub fn find_remote_for_owner(workdir: &Path, host: &str, owner: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["remote"])
+        .current_dir(workdir)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .find(|name| {
+            get_remote_url(workdir, name)
+                .and_then(|url| parse_remote_url(&url))
+                .and_then(|(remote_host, path)| {
+                    let (namespace, _) = split_namespace_repo(&path)?;
+                    Ok(remote_host.eq_ignore_ascii_case(host)
+                        && namespace
+                            .split('/')
+                            .next()
+                            .is_some_and(|remote_owner| remote_owner.eq_ignore_ascii_case(owner)))
+                })
+                .unwrap_or(false)
+        })
+        .map(str::to_string)
+}
+
 pub fn get_remote_branches(workdir: &Path, remote: &str) -> Result<Vec<String>> {
     let output = Command::new("git")
         .args(["branch", "-r", "--format=%(refname)"])
@@ -659,6 +810,35 @@ mod tests {
         assert_eq!(url, "https://github.com/test/repo.git");
     }
 
+    #[test]
+    fn test_find_remote_for_owner_matches_host_and_owner() {
+        let dir = TempDir::new().expect("Failed to create temp dir");
+        let path = dir.path();
+
+        Command::new("git")
+            .args(["init", "-b", "main"])
+            .current_dir(path)
+            .output()
+            .expect("Failed to init git repo");
+        for (name, url) in [
+            ("origin", "https://github.com/upstream/repo.git"),
+            ("mine", "git@github.com:Forker/repo.git"),
+            ("mirror", "https://gitlab.com/forker/repo.git"),
+        ] {
+            Command::new("git")
+                .args(["remote", "add", name, url])
+                .current_dir(path)
+                .output()
+                .expect("Failed to add remote");
+        }
+
+        assert_eq!(
+            find_remote_for_owner(path, "github.com", "forker").as_deref(),
+            Some("mine")
+        );
+        assert_eq!(find_remote_for_owner(path, "github.com", "someone"), None);
+    }
+
     #[test]
     fn test_get_existing_remote_branches_from_repo_checks_only_requested_branches() {
         let dir = TempDir::new().expect("Failed to create temp dir");
@@ -778,6 +958,8 @@ mod tests {
             repo: "myrepo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
         assert_eq!(info.owner(), "myorg");
     }
@@ -792,6 +974,8 @@ mod tests {
             repo: "myrepo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
         assert_eq!(info.repo_url(), "https://github.com/myorg/myrepo");
     }
@@ -806,6 +990,8 @@ mod tests {
             repo: "myrepo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
         assert_eq!(info.pr_url(42), "https://github.com/myorg/myrepo/pull/42");
     }
@@ -820,6 +1006,8 @@ mod tests {
             repo: "project".to_string(),
             base_url: "https://gitlab.com".to_string(),
             api_base_url: None,
+            request_timeout: None,
+            head_owner: None,
         };
         assert_eq!(info.repo_url(), "https://gitlab.com/org/team/project");
     }
@@ -834,6 +1022,8 @@ mod tests {
             repo: "project".to_string(),
             base_url: "https://gitlab.com".to_string(),
             api_base_url: Some("https://gitlab.com/api/v4".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
         assert_eq!(
             info.pr_url(42),
@@ -851,6 +1041,8 @@ mod tests {
             repo: "project".to_string(),
             base_url: "https://gitea.example.com".to_string(),
             api_base_url: Some("https://gitea.example.com/api/v1".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
         assert_eq!(
             info.pr_url(42),
@@ -867,6 +1059,46 @@ mod tests {
         (dir, repo)
     }
 
+    #[test]
+    fn remote_info_resolves_pr_repo_separately_from_push_fork() {
+        let (dir, _) = repo_with_remote("git@github.com:me/service.git");
+        Repository::open(dir.path())
+            .unwrap()
+            .remote("upstream", "https://github.com/platform/service.git")
+            .unwrap();
+        let repo = GitRepo::open_from_path(dir.path()).unwrap();
+        let mut config = Config::default();
+        config.remote.pr_remote = Some("upstream".to_string());
+
+        let remote = RemoteInfo::from_repo(&repo, &config).unwrap();
+
+        assert_eq!(remote.name, "origin");
+        assert_eq!(remote.owner(), "platform");
+        assert_eq!(remote.repo, "service");
+        assert_eq!(remote.head_owner.as_deref(), Some("me"));
+        assert_eq!(
+            remote.pr_url(3),
+            "https://github.com/platform/service/pull/3"
+        );
+    }
+
+    #[test]
+    fn remote_info_has_no_head_owner_when_push_and_pr_remotes_share_a_repo() {
+        let (dir, _) = repo_with_remote("git@github.com:platform/service.git");
+        Repository::open(dir.path())
+            .unwrap()
+            .remote("mirror", "https://github.com/platform/service.git")
+            .unwrap();
+        let repo = GitRepo::open_from_path(dir.path()).unwrap();
+        let mut config = Config::default();
+        config.remote.push_remote = Some("mirror".to_string());
+
+        let remote = RemoteInfo::from_repo(&repo, &config).unwrap();
+
+        assert_eq!(remote.name, "mirror");
+        assert_eq!(remote.head_owner, None);
+    }
+
     #[test]
     fn trusted_network_remote_trusts_official_host_resolutions() {
         let cases = [
@@ -891,6 +1123,13 @@ mod tests {
                 "https://gitea.com",
                 "https://gitea.com/api/v1",
             ),
+            (
+                "git@bitbucket.org:workspace/repo.git",
+                "bitbucket.org",
+                ForgeType::Bitbucket,
+                "https://bitbucket.org",
+                "https://api.bitbucket.org/2.0",
+            ),
         ];
 
         for (url, host, forge, base_url, api_base_url) in cases {
@@ -905,6 +1144,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn remote_info_derives_github_enterprise_api_urls() {
+        let cases = [
+            (
+                "git@github.company.com:platform/service.git",
+                "https://github.company.com",
+                "https://github.company.com/api/v3",
+            ),
+            (
+                "https://octo.ghe.com/platform/service.git",
+                "https://octo.ghe.com",
+                "https://api.octo.ghe.com",
+            ),
+            (
+                "ssh://git@ssh.github.com:443/platform/service.git",
+                "https://github.com",
+                "https://api.github.com",
+            ),
+        ];
+
+        for (url, base_url, api_base_url) in cases {
+            let (_dir, repo) = repo_with_remote(url);
+            let remote = RemoteInfo::from_repo(&repo, &Config::default()).unwrap();
+
+            assert_eq!(remote.forge, ForgeType::GitHub, "{url}");
+            assert_eq!(remote.base_url, base_url, "{url}");
+            assert_eq!(remote.api_base_url.as_deref(), Some(api_base_url), "{url}");
+        }
+    }
+
+    #[test]
+    fn remote_info_prefers_configured_enterprise_api_url() {
+        let (_dir, repo) = repo_with_remote("git@github.company.com:platform/service.git");
+        let mut config = Config::default();
+        config.remote.api_base_url = Some("https://api.company.com".to_string());
+
+        let remote = RemoteInfo::from_repo(&repo, &config).unwrap();
+
+        assert_eq!(
+            remote.api_base_url.as_deref(),
+            Some("https://api.company.com")
+        );
+    }
+
+    #[test]
+    fn trusted_network_remote_trusts_ghe_cloud_hosts() {
+        let (_dir, repo) = repo_with_remote("git@octo.ghe.com:platform/service.git");
+
+        let trusted = TrustedRemoteInfo::from_repo(&repo, &Config::default()).unwrap();
+
+        assert_eq!(
+            trusted.remote().api_base_url.as_deref(),
+            Some("https://api.octo.ghe.com")
+        );
+    }
+
     #[test]
     fn trusted_network_remote_accepts_globally_configured_custom_relationship() {
         let (_dir, repo) = repo_with_remote("git@git.corp.example:platform/service.git");
@@ -980,6 +1275,8 @@ mod tests {
             repo: "service".to_string(),
             base_url: "https://git.corp.example".to_string(),
             api_base_url: Some("https://api.other.example/v3".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let error = validate_trusted_network_remote(&remote, &config).unwrap_err();
@@ -1004,6 +1301,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bitbucket_remote_urls_resolve_workspace_and_pr_url() {
+        for url in [
+            "git@bitbucket.org:workspace/repo.git",
+            "https://alice@bitbucket.org/workspace/repo.git",
+        ] {
+            let (_dir, repo) = repo_with_remote(url);
+            let remote = RemoteInfo::from_repo(&repo, &Config::default()).unwrap();
+            assert_eq!(remote.forge, ForgeType::Bitbucket);
+            assert_eq!(remote.host, "bitbucket.org");
+            assert_eq!(remote.namespace, "workspace");
+            assert_eq!(remote.repo, "repo");
+            assert_eq!(
+                remote.pr_url(7),
+                "https://bitbucket.org/workspace/repo/pull-requests/7"
+            );
+        }
+    }
+
+    #[test]
+    fn test_azure_devops_remote_urls_resolve_org_project_and_repo() {
+        for url in [
+            "https://dev.azure.com/contoso/Web%20App/_git/portal",
+            "https://contoso@dev.azure.com/contoso/Web%20App/_git/portal",
+            "git@ssh.dev.azure.com:v3/contoso/Web%20App/portal",
+            "https://contoso.visualstudio.com/DefaultCollection/Web%20App/_git/portal",
+            "contoso@vs-ssh.visualstudio.com:v3/contoso/Web%20App/portal",
+        ] {
+            let (_dir, repo) = repo_with_remote(url);
+            let remote = RemoteInfo::from_repo(&repo, &Config::default()).unwrap();
+            assert_eq!(remote.forge, ForgeType::AzureDevOps, "{url}");
+            assert_eq!(remote.host, "dev.azure.com", "{url}");
+            assert_eq!(remote.namespace, "contoso/Web%20App", "{url}");
+            assert_eq!(remote.repo, "portal", "{url}");
+            assert_eq!(
+                remote.api_base_url.as_deref(),
+                Some("https://dev.azure.com")
+            );
+            assert_eq!(
+                remote.pr_url(12),
+                "https://dev.azure.com/contoso/Web%20App/_git/portal/pullrequest/12"
+            );
+        }
+    }
+
+    #[test]
+    fn test_azure_devops_server_remote_keeps_host_and_drops_git_segment() {
+        assert_eq!(
+            normalize_azure_remote("tfs.corp.example", "tfs/Main/Project/_git/repo"),
+            (
+                "tfs.corp.example".to_string(),
+                "tfs/Main/Project/repo".to_string()
+            )
+        );
+    }
+
     #[test]
     fn test_detect_forge_recognizes_forgejo() {
         assert_eq!(
@@ -1065,5 +1418,17 @@ mod tests {
             serde_json::from_str::<ForgeType>(r#""forgejo""#).unwrap(),
             ForgeType::Gitea
         );
+        assert_eq!(
+            serde_json::from_str::<ForgeType>(r#""bitbucket""#).unwrap(),
+            ForgeType::Bitbucket
+        );
+        assert_eq!(
+            serde_json::from_str::<ForgeType>(r#""azuredevops""#).unwrap(),
+            ForgeType::AzureDevOps
+        );
+        assert_eq!(
+            serde_json::from_str::<ForgeType>(r#""azure""#).unwrap(),
+            ForgeType::AzureDevOps
+        );
     }
 }
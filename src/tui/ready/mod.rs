@@ -266,6 +266,8 @@ mod tests {
             repo: "repo".to_string(),
             base_url: "https://github.com".to_string(),
             api_base_url: Some("https://api.github.com".to_string()),
+            request_timeout: None,
+            head_owner: None,
         };
 
         let result = create_loader_forge_client(&runtime, &remote);
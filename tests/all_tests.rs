@@ -24,6 +24,8 @@ mod application_operation_tests;
 mod application_session_tests;
 #[path = "auth_tests.rs"]
 mod auth_tests;
+#[path = "cache_tests.rs"]
+mod cache_tests;
 #[path = "changelog_tests.rs"]
 mod changelog_tests;
 #[path = "ci_tests.rs"]
@@ -36,6 +38,10 @@ mod command_coverage_tests;
 mod comments_tests;
 #[path = "comprehensive_coverage_tests.rs"]
 mod comprehensive_coverage_tests;
+#[path = "config_get_tests.rs"]
+mod config_get_tests;
+#[path = "config_set_trunk_tests.rs"]
+mod config_set_trunk_tests;
 #[path = "conflict_handling_tests.rs"]
 mod conflict_handling_tests;
 #[path = "continue_tests.rs"]
@@ -46,16 +52,48 @@ mod copy_tests;
 mod create_ai_tests;
 #[path = "create_below_tests.rs"]
 mod create_below_tests;
+#[path = "create_detached_tests.rs"]
+mod create_detached_tests;
+#[path = "create_dry_commit_tests.rs"]
+mod create_dry_commit_tests;
+#[path = "create_from_revision_tests.rs"]
+mod create_from_revision_tests;
+#[path = "create_from_tag_tests.rs"]
+mod create_from_tag_tests;
+#[path = "create_hook_tests.rs"]
+mod create_hook_tests;
 #[path = "create_insert_tests.rs"]
 mod create_insert_tests;
+#[path = "create_interactive_tests.rs"]
+mod create_interactive_tests;
+#[path = "create_message_file_tests.rs"]
+mod create_message_file_tests;
+#[path = "create_open_editor_tests.rs"]
+mod create_open_editor_tests;
+#[path = "create_patch_tests.rs"]
+mod create_patch_tests;
+#[path = "create_pending_tests.rs"]
+mod create_pending_tests;
+#[path = "create_protected_tests.rs"]
+mod create_protected_tests;
+#[path = "create_reviewers_tests.rs"]
+mod create_reviewers_tests;
 #[path = "create_rollback_tests.rs"]
 mod create_rollback_tests;
+#[path = "create_summary_tests.rs"]
+mod create_summary_tests;
+#[path = "create_trunk_child_tests.rs"]
+mod create_trunk_child_tests;
+#[path = "create_upstream_tests.rs"]
+mod create_upstream_tests;
 #[path = "demo_tests.rs"]
 mod demo_tests;
 #[path = "detach_tests.rs"]
 mod detach_tests;
 #[path = "doctor_fix_tests.rs"]
 mod doctor_fix_tests;
+#[path = "doctor_json_tests.rs"]
+mod doctor_json_tests;
 #[path = "downstack_tests.rs"]
 mod downstack_tests;
 #[path = "edge_cases_tests.rs"]
@@ -78,22 +116,44 @@ mod github_list_tests;
 mod gui_command_tests;
 #[path = "integration_tests.rs"]
 mod integration_tests;
+#[path = "log_tests.rs"]
+mod log_tests;
+#[path = "merge_admin_tests.rs"]
+mod merge_admin_tests;
+#[path = "merge_dry_run_tests.rs"]
+mod merge_dry_run_tests;
+#[path = "merge_only_tests.rs"]
+mod merge_only_tests;
 #[path = "navigation_tests.rs"]
 mod navigation_tests;
 #[path = "performance_tests.rs"]
 mod performance_tests;
 #[path = "pr_body_tests.rs"]
 mod pr_body_tests;
+#[path = "pr_close_tests.rs"]
+mod pr_close_tests;
+#[path = "pr_merge_tests.rs"]
+mod pr_merge_tests;
 #[path = "pr_open_tests.rs"]
 mod pr_open_tests;
 #[path = "pr_template_tests.rs"]
 mod pr_template_tests;
+#[path = "pr_view_tests.rs"]
+mod pr_view_tests;
+#[path = "remote_selection_tests.rs"]
+mod remote_selection_tests;
 #[path = "reorder_tests.rs"]
 mod reorder_tests;
+#[path = "request_timeout_tests.rs"]
+mod request_timeout_tests;
 #[path = "rerequest_review_tests.rs"]
 mod rerequest_review_tests;
 #[path = "resolve_tests.rs"]
 mod resolve_tests;
+#[path = "restack_merge_strategy_tests.rs"]
+mod restack_merge_strategy_tests;
+#[path = "restack_progress_tests.rs"]
+mod restack_progress_tests;
 #[path = "restack_provenance_tests.rs"]
 mod restack_provenance_tests;
 #[path = "runtime_safety_tests.rs"]
@@ -104,6 +164,8 @@ mod scoped_submit_tests;
 mod split_hunk_tests;
 #[path = "split_tests.rs"]
 mod split_tests;
+#[path = "stack_fixture_tests.rs"]
+mod stack_fixture_tests;
 #[path = "stack_test_tests.rs"]
 mod stack_test_tests;
 #[path = "staging_menu_tests.rs"]
@@ -112,14 +174,26 @@ mod staging_menu_tests;
 mod status_tests;
 #[path = "submit_fetch_failure_tests.rs"]
 mod submit_fetch_failure_tests;
+#[path = "submit_fork_pr_tests.rs"]
+mod submit_fork_pr_tests;
+#[path = "submit_lease_recovery_tests.rs"]
+mod submit_lease_recovery_tests;
 #[path = "submit_no_verify_tests.rs"]
 mod submit_no_verify_tests;
 #[path = "submit_plan_completions_tests.rs"]
 mod submit_plan_completions_tests;
 #[path = "submit_pr_base_tests.rs"]
 mod submit_pr_base_tests;
+#[path = "submit_resume_tests.rs"]
+mod submit_resume_tests;
 #[path = "sweep_tests.rs"]
 mod sweep_tests;
+#[path = "sync_fork_workflow_tests.rs"]
+mod sync_fork_workflow_tests;
+#[path = "sync_restack_real_only_tests.rs"]
+mod sync_restack_real_only_tests;
+#[path = "sync_retarget_tests.rs"]
+mod sync_retarget_tests;
 #[path = "track_all_prs_tests.rs"]
 mod track_all_prs_tests;
 #[path = "track_merge_base_tests.rs"]
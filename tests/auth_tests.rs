@@ -1,7 +1,7 @@
 //! Tests for `stax auth` command flags and behavior.
 
 use crate::common;
-use common::{OutputAssertions, TestRepo};
+use common::{IsolatedProcessEnv, OutputAssertions, TestRepo};
 
 #[test]
 fn test_auth_help_includes_from_gh_flag() {
@@ -49,3 +49,327 @@ fn test_auth_status_command() {
         stdout
     );
 }
+
+#[test]
+fn test_auth_login_requires_oauth_client_id() {
+    let repo = TestRepo::new();
+    let output = repo.run_stax(&["auth", "login"]);
+    output.assert_failure();
+    output.assert_stderr_contains("auth.oauth_client_id");
+}
+
+fn write_gh_hosts(env: &IsolatedProcessEnv, token: &str) {
+    std::fs::write(
+        env.gh_config_dir().join("hosts.yml"),
+        format!("github.com:\n    user: octocat\n    oauth_token: {token}\n"),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_auth_from_gh_imports_token_from_hosts_file() {
+    let repo = TestRepo::new();
+    let env = IsolatedProcessEnv::with_config("[auth]\nuse_gh_cli = false\n");
+    write_gh_hosts(&env, "gho_from_hosts");
+
+    let output = env
+        .command(&repo.path())
+        .args(["auth", "--from-gh"])
+        .output()
+        .unwrap();
+
+    output.assert_success();
+    let saved = std::fs::read_to_string(env.config_dir().join(".credentials")).unwrap();
+    assert_eq!(saved, "gho_from_hosts");
+}
+
+#[test]
+fn test_doctor_suggests_from_gh_when_gh_has_a_token() {
+    let repo = TestRepo::new();
+    let env = IsolatedProcessEnv::with_config("[auth]\nuse_gh_cli = false\n");
+    write_gh_hosts(&env, "gho_from_hosts");
+
+    let output = env.command(&repo.path()).arg("doctor").output().unwrap();
+
+    output.assert_stdout_contains("run `stax auth --from-gh`");
+}
+
+/// Put a fake `secret-tool` first on PATH that keeps the secret in `store`.
+/// With `working = false` it fails like a machine without a Secret Service.
+#[cfg(target_os = "linux")]
+fn fake_secret_tool(env: &IsolatedProcessEnv, working: bool) -> (String, std::path::PathBuf) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let bin = env.gh_config_dir().join("bin");
+    let store = env.gh_config_dir().join("keyring-secret");
+    std::fs::create_dir_all(&bin).unwrap();
+    let script = if working {
+        format!(
+            "#!/bin/sh\ncase \"$1\" in\n  store) cat > '{0}' ;;\n  lookup) cat '{0}' 2>/dev/null || exit 1 ;;\nesac\n",
+            store.display()
+        )
+    } else {
+        "#!/bin/sh\necho 'no secret service' >&2\nexit 1\n".to_string()
+    };
+    let tool = bin.join("secret-tool");
+    std::fs::write(&tool, script).unwrap();
+    std::fs::set_permissions(&tool, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let path = format!(
+        "{}:{}",
+        bin.display(),
+        std::env::var("PATH").unwrap_or_default()
+    );
+    (path, store)
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_auth_token_uses_keyring_storage() {
+    let repo = TestRepo::new();
+    let env =
+        IsolatedProcessEnv::with_config("[auth]\nstorage = \"keyring\"\nuse_gh_cli = false\n");
+    let (path, store) = fake_secret_tool(&env, true);
+    std::fs::write(env.config_dir().join(".credentials"), "old-plaintext").unwrap();
+
+    let output = env
+        .command(&repo.path())
+        .env("PATH", &path)
+        .args(["auth", "--token", "ghp_keyring"])
+        .output()
+        .unwrap();
+    output.assert_success();
+    output.assert_stdout_contains("OS keyring");
+
+    assert_eq!(std::fs::read_to_string(&store).unwrap(), "ghp_keyring");
+    assert!(!env.config_dir().join(".credentials").exists());
+
+    let status = env
+        .command(&repo.path())
+        .env("PATH", &path)
+        .args(["auth", "status"])
+        .output()
+        .unwrap();
+    status.assert_success();
+    status.assert_stdout_contains("Active source: OS keyring");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_auth_token_falls_back_to_file_without_keyring() {
+    let repo = TestRepo::new();
+    let env =
+        IsolatedProcessEnv::with_config("[auth]\nstorage = \"keyring\"\nuse_gh_cli = false\n");
+    let (path, _) = fake_secret_tool(&env, false);
+
+    let output = env
+        .command(&repo.path())
+        .env("PATH", &path)
+        .args(["auth", "--token", "ghp_file"])
+        .output()
+        .unwrap();
+    output.assert_success();
+    output.assert_stdout_contains("fell back to the credentials file");
+    output.assert_stdout_contains("no secret service");
+
+    let saved = std::fs::read_to_string(env.config_dir().join(".credentials")).unwrap();
+    assert_eq!(saved, "ghp_file");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_auth_status_lists_hosts_with_keyring_tokens() {
+    let repo = TestRepo::new();
+    let env =
+        IsolatedProcessEnv::with_config("[auth]\nstorage = \"keyring\"\nuse_gh_cli = false\n");
+    let (path, _) = fake_secret_tool(&env, true);
+
+    env.command(&repo.path())
+        .env("PATH", &path)
+        .args([
+            "auth",
+            "--host",
+            "ghe.corp.com",
+            "--token",
+            "ghp_enterprise",
+        ])
+        .output()
+        .unwrap()
+        .assert_success();
+    assert!(!env.config_dir().join(".credentials.d").exists());
+
+    let status = env
+        .command(&repo.path())
+        .env("PATH", &path)
+        .args(["auth", "status"])
+        .output()
+        .unwrap();
+    status.assert_success();
+    status.assert_stdout_contains("Host-specific tokens: ghe.corp.com");
+}
+
+#[test]
+fn test_auth_host_token_is_selected_for_matching_repo() {
+    let repo = TestRepo::new();
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "git@ghe.corp.com:platform/service.git",
+    ]);
+    let env = IsolatedProcessEnv::with_config("[auth]\nuse_gh_cli = false\n");
+
+    let output = env
+        .command(&repo.path())
+        .args([
+            "auth",
+            "--host",
+            "ghe.corp.com",
+            "--token",
+            "ghp_enterprise",
+        ])
+        .output()
+        .unwrap();
+    output.assert_success();
+    output.assert_stdout_contains("API token saved for ghe.corp.com");
+    let saved =
+        std::fs::read_to_string(env.config_dir().join(".credentials.d").join("ghe.corp.com"))
+            .unwrap();
+    assert_eq!(saved, "ghp_enterprise");
+
+    let status = env
+        .command(&repo.path())
+        .args(["auth", "status"])
+        .output()
+        .unwrap();
+    status.assert_success();
+    status.assert_stdout_contains("Repository host: ghe.corp.com");
+    status.assert_stdout_contains("Host-specific tokens: ghe.corp.com");
+    status.assert_stdout_contains("Active source: credentials file");
+}
+
+#[tokio::test]
+async fn test_auth_status_validates_token_against_api() {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .and(header("authorization", "Bearer ghp_env"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-oauth-scopes", "repo, workflow")
+                .insert_header("x-ratelimit-remaining", "4321")
+                .insert_header("x-ratelimit-limit", "5000")
+                .set_body_json(serde_json::json!({ "login": "octocat" })),
+        )
+        .mount(&server)
+        .await;
+    let repo = TestRepo::new();
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+    let env = IsolatedProcessEnv::with_config(&format!(
+        "[remote]\napi_base_url = \"{}\"\n[auth]\nuse_gh_cli = false\n",
+        server.uri()
+    ));
+
+    let output = env
+        .command(&repo.path())
+        .env("STAX_GITHUB_TOKEN", "ghp_env")
+        .args(["auth", "status"])
+        .output()
+        .unwrap();
+
+    output.assert_success();
+    output.assert_stdout_contains("Authenticated as octocat");
+    output.assert_stdout_contains("scopes:     repo, workflow");
+    output.assert_stdout_contains("rate limit: 4321/5000 remaining");
+}
+
+#[tokio::test]
+async fn test_auth_status_reports_rejected_token() {
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(
+            ResponseTemplate::new(401)
+                .set_body_json(serde_json::json!({ "message": "Bad credentials" })),
+        )
+        .mount(&server)
+        .await;
+    let repo = TestRepo::new();
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+    let env = IsolatedProcessEnv::with_config(&format!(
+        "[remote]\napi_base_url = \"{}\"\n[auth]\nuse_gh_cli = false\n",
+        server.uri()
+    ));
+
+    let output = env
+        .command(&repo.path())
+        .env("STAX_GITHUB_TOKEN", "ghp_revoked")
+        .args(["auth", "status"])
+        .output()
+        .unwrap();
+
+    output.assert_success();
+    output.assert_stdout_contains("GitHub rejected the token (401): Bad credentials");
+}
+
+#[test]
+fn test_auth_status_reports_github_app_source() {
+    let repo = TestRepo::new();
+    let env = IsolatedProcessEnv::with_config("[auth]\nuse_gh_cli = false\n");
+    let key_path = env.gh_config_dir().join("app.pem");
+    std::fs::write(
+        &key_path,
+        include_str!("../src/github/testdata/app_private_key.pem"),
+    )
+    .unwrap();
+
+    let output = env
+        .command(&repo.path())
+        .env("STAX_GITHUB_APP_ID", "123")
+        .env("STAX_GITHUB_APP_INSTALLATION_ID", "456")
+        .env("STAX_GITHUB_APP_PRIVATE_KEY_PATH", &key_path)
+        .args(["auth", "status"])
+        .output()
+        .unwrap();
+
+    output.assert_success();
+    output.assert_stdout_contains("Active source: GitHub App installation");
+    output.assert_stdout_contains("(app 123, installation 456)");
+}
+
+#[test]
+fn test_auth_status_reports_incomplete_github_app_config() {
+    let repo = TestRepo::new();
+    let env = IsolatedProcessEnv::with_config("[auth]\nuse_gh_cli = false\n");
+
+    let output = env
+        .command(&repo.path())
+        .env("STAX_GITHUB_APP_ID", "123")
+        .args(["auth", "status"])
+        .output()
+        .unwrap();
+
+    output.assert_success();
+    output.assert_stdout_contains("GitHub App misconfigured");
+    output.assert_stdout_contains("STAX_GITHUB_APP_INSTALLATION_ID is missing");
+}
@@ -0,0 +1,84 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+
+fn seed_ci_cache(repo: &TestRepo, branches: &[&str], last_refresh: u64) {
+    let entries: serde_json::Map<String, Value> = branches
+        .iter()
+        .map(|branch| {
+            (
+                branch.to_string(),
+                serde_json::json!({
+                    "ci_revision": null,
+                    "ci_state": "success",
+                    "pr_state": "OPEN",
+                    "updated_at": last_refresh
+                }),
+            )
+        })
+        .collect();
+    let cache = serde_json::json!({ "branches": entries, "last_refresh": last_refresh });
+    std::fs::create_dir_all(repo.path().join(".git/stax")).expect("cache dir");
+    std::fs::write(
+        repo.path().join(".git/stax/ci-cache.json"),
+        cache.to_string(),
+    )
+    .expect("seed CI cache");
+}
+
+fn cache_show_json(repo: &TestRepo) -> Value {
+    let output = repo.run_stax(&["cache", "show", "--json"]);
+    output.assert_success();
+    serde_json::from_str(&TestRepo::stdout(&output)).expect("cache show JSON")
+}
+
+#[test]
+fn cache_show_reports_entries_and_freshness_against_ttl() {
+    let repo = TestRepo::new();
+    let branch = repo.create_stack(&["cached"])[0].clone();
+    let now = chrono::Utc::now().timestamp() as u64;
+    seed_ci_cache(&repo, &[&branch], now - 60);
+
+    let show = cache_show_json(&repo);
+    assert_eq!(show["ttl_secs"], 90);
+    assert_eq!(show["fresh"], true);
+    assert_eq!(show["branches"][0]["name"], branch.as_str());
+    assert_eq!(show["branches"][0]["ci_state"], "success");
+
+    std::fs::write(repo.path().join("stax.toml"), "[cache]\nttl_secs = 30\n").unwrap();
+    let show = cache_show_json(&repo);
+    assert_eq!(show["ttl_secs"], 30);
+    assert_eq!(show["fresh"], false);
+
+    repo.run_stax(&["cache", "show"])
+        .assert_success()
+        .assert_stdout_contains("stale; ttl 30s")
+        .assert_stdout_contains(&branch);
+}
+
+#[test]
+fn cache_prune_drops_untracked_branches_and_clear_empties_the_cache() {
+    let repo = TestRepo::new();
+    let branch = repo.create_stack(&["kept"])[0].clone();
+    seed_ci_cache(&repo, &[&branch, "deleted-branch"], 1_790_000_000);
+
+    repo.run_stax(&["cache", "prune"])
+        .assert_success()
+        .assert_stdout_contains("Pruned cached state for 1 untracked branch");
+    let show = cache_show_json(&repo);
+    let names: Vec<&str> = show["branches"]
+        .as_array()
+        .expect("branches")
+        .iter()
+        .filter_map(|entry| entry["name"].as_str())
+        .collect();
+    assert_eq!(names, vec![branch.as_str()]);
+
+    repo.run_stax(&["cache", "clear"])
+        .assert_success()
+        .assert_stdout_contains("Cleared cached state for 1 branch and 0 API responses");
+    let show = cache_show_json(&repo);
+    assert_eq!(show["branches"].as_array().map(Vec::len), Some(0));
+    assert!(show["last_refresh"].is_null(), "show: {show}");
+}
@@ -170,6 +170,7 @@ fn test_ci_alert_flags_are_recognized() {
         vec!["ci", "--watch", "--alert", "/tmp/ci-done.wav"],
         vec!["ci", "--watch", "--no-alert"],
         vec!["ci", "--watch", "--strict"],
+        vec!["ci", "--watch", "--notify"],
     ] {
         let output = repo.run_stax(&args);
         let stderr = TestRepo::stderr(&output);
@@ -183,3 +184,116 @@ fn test_ci_alert_flags_are_recognized() {
         );
     }
 }
+
+/// `ci --watch --notify`, observed through a fake `notify-send` on `PATH`.
+#[cfg(target_os = "linux")]
+mod watch_notifications {
+    use super::common::{OutputAssertions, TestRepo};
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn check_runs(status: &str, conclusion: Option<&str>) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total_count": 1,
+            "check_runs": [{
+                "id": 1,
+                "name": "build",
+                "status": status,
+                "conclusion": conclusion,
+                "html_url": null,
+                "started_at": "2026-01-01T00:00:00Z",
+                "completed_at": conclusion.map(|_| "2026-01-01T00:01:00Z")
+            }]
+        }))
+    }
+
+    /// Watch one branch whose checks respond with `responses` in order (the last
+    /// one repeats), with a fake `notify-send` recording every notification.
+    /// Returns the recorded notifications.
+    async fn watch_ci_with_notify(responses: Vec<ResponseTemplate>) -> String {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let mock_server = MockServer::start().await;
+        let repo = TestRepo::new();
+        let home = repo.clean_home();
+        let config_dir = Path::new(&home).join(".config").join("stax");
+        fs::create_dir_all(&config_dir).unwrap();
+        fs::write(
+            config_dir.join("config.toml"),
+            format!("[remote]\napi_base_url = \"{}\"\n", mock_server.uri()),
+        )
+        .unwrap();
+        repo.git(&[
+            "remote",
+            "add",
+            "origin",
+            "https://github.com/test/repo.git",
+        ])
+        .assert_success();
+        repo.create_stack(&["watched"]);
+        let check_runs_path = format!("/repos/test/repo/commits/{}/check-runs", repo.head_sha());
+
+        let last = responses.len() - 1;
+        for (i, response) in responses.into_iter().enumerate() {
+            let mock = Mock::given(method("GET"))
+                .and(path(check_runs_path.as_str()))
+                .respond_with(response);
+            let mock = if i < last {
+                mock.up_to_n_times(1)
+            } else {
+                mock
+            };
+            mock.mount(&mock_server).await;
+        }
+
+        let bin = tempfile::tempdir().unwrap();
+        let log = bin.path().join("notifications.log");
+        let notify_send = bin.path().join("notify-send");
+        fs::write(
+            &notify_send,
+            format!("#!/bin/sh\necho \"$@\" >> '{}'\n", log.display()),
+        )
+        .unwrap();
+        fs::set_permissions(&notify_send, fs::Permissions::from_mode(0o755)).unwrap();
+        let path_env = format!(
+            "{}:{}",
+            bin.path().display(),
+            std::env::var("PATH").unwrap_or_default()
+        );
+
+        repo.run_stax_with_env(
+            &["ci", "--watch", "--notify", "--interval", "0"],
+            &[("STAX_GITHUB_TOKEN", "mock-token"), ("PATH", &path_env)],
+        )
+        .assert_success();
+
+        fs::read_to_string(&log).unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_ci_watch_notify_sends_notification_when_ci_finishes() {
+        let notifications = watch_ci_with_notify(vec![
+            check_runs("in_progress", None),
+            check_runs("completed", Some("success")),
+        ])
+        .await;
+
+        assert!(
+            notifications.contains("stax ci All CI checks passed"),
+            "expected a CI notification, got: {notifications:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ci_watch_notify_skips_ci_that_already_finished() {
+        let notifications =
+            watch_ci_with_notify(vec![check_runs("completed", Some("success"))]).await;
+
+        assert!(
+            notifications.is_empty(),
+            "nothing was waited on, got: {notifications:?}"
+        );
+    }
+}
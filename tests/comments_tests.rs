@@ -279,5 +279,6 @@ async fn reviews_inbox_reports_comment_api_failures() {
     );
 
     output.assert_failure();
+    output.assert_stderr_contains("GitHub returned 500, retrying in");
     output.assert_stderr_contains("Failed to list issue comments");
 }
@@ -9,6 +9,7 @@ mod git_fixture;
 pub(crate) use git_fixture::{commit_all, init_test_repo};
 
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
@@ -66,6 +67,14 @@ impl IsolatedProcessEnv {
         }
     }
 
+    pub fn config_dir(&self) -> &Path {
+        &self.config_dir
+    }
+
+    pub fn gh_config_dir(&self) -> &Path {
+        &self.gh_config_dir
+    }
+
     pub fn command(&self, repository: &Path) -> Command {
         let mut command = Command::new(stax_bin());
         let null = if cfg!(windows) { "NUL" } else { "/dev/null" };
@@ -82,6 +91,7 @@ impl IsolatedProcessEnv {
             .env_remove("STAX_FORGE_TOKEN")
             .env_remove("GITHUB_TOKEN")
             .env_remove("GH_TOKEN")
+            .env_remove("STAX_GITHUB_APP_ID")
             .env_remove("GITLAB_TOKEN")
             .env_remove("GITEA_TOKEN")
             .env("STAX_DISABLE_UPDATE_CHECK", "1");
@@ -116,6 +126,7 @@ fn apply_sanitized_test_env(cmd: &mut Command) {
         .env_remove("STAX_SHELL_INTEGRATION")
         .env_remove("STAX_CONFIG_DIR")
         .env_remove("GH_TOKEN")
+        .env_remove("STAX_GITHUB_APP_ID")
         .env("GIT_CONFIG_GLOBAL", null_path)
         .env("GIT_CONFIG_SYSTEM", null_path)
         .env("STAX_DISABLE_UPDATE_CHECK", "1")
@@ -647,6 +658,42 @@ impl TestRepo {
         created_branches
     }
 
+    /// Create a stack where each branch gets the given commits, in order.
+    /// Returns each created branch name mapped to its commit SHAs, oldest first.
+    pub fn create_stack_with_commits(
+        &self,
+        branches: &[(&str, &[&str])],
+    ) -> BTreeMap<String, Vec<String>> {
+        let mut created = BTreeMap::new();
+
+        for (name, messages) in branches {
+            let output = self.run_stax(&["bc", name]);
+            assert!(
+                output.status.success(),
+                "Failed to create branch {}: {}",
+                name,
+                Self::stderr(&output)
+            );
+            let branch_name = self.current_branch();
+
+            let shas = messages
+                .iter()
+                .enumerate()
+                .map(|(index, message)| {
+                    self.create_file(
+                        &format!("{}-{}.txt", name, index + 1),
+                        &format!("{}\n", message),
+                    );
+                    self.commit(message);
+                    self.head_sha()
+                })
+                .collect();
+            created.insert(branch_name, shas);
+        }
+
+        created
+    }
+
     /// Navigate to the top of the stack
     pub fn navigate_to_top(&self) -> Output {
         self.run_stax(&["top"])
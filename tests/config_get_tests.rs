@@ -0,0 +1,55 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+
+#[test]
+fn config_get_effective_attributes_repo_override_to_repo_file() {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    std::fs::write(
+        std::path::Path::new(&home).join(".config/stax/config.toml"),
+        "[branch]\nprefix = \"global/\"\n[ui]\ntips = false\n",
+    )
+    .expect("write global config");
+    std::fs::write(
+        repo.path().join("stax.toml"),
+        "[branch]\nprefix = \"repo/\"\n",
+    )
+    .expect("write repo config");
+
+    let output = repo.run_stax(&["config", "get", "--effective"]);
+    output.assert_success();
+    output.assert_stdout_contains("branch.prefix = \"repo/\"  (repo)");
+    output.assert_stdout_contains("ui.tips = false  (global)");
+    output.assert_stdout_contains("stax.toml");
+
+    let json = repo.run_stax(&["config", "get", "branch", "--effective", "--json"]);
+    json.assert_success();
+    let settings: Value =
+        serde_json::from_str(&TestRepo::stdout(&json)).expect("config JSON should parse");
+    let setting = |key: &str| {
+        settings
+            .as_array()
+            .expect("settings array")
+            .iter()
+            .find(|setting| setting["key"].as_str() == Some(key))
+            .cloned()
+            .unwrap_or_else(|| panic!("missing {key} in config JSON"))
+    };
+    assert_eq!(setting("branch.prefix")["value"], "repo/");
+    assert_eq!(setting("branch.prefix")["source"], "repo");
+    assert_eq!(setting("branch.date")["source"], "default");
+    assert!(
+        settings
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|setting| setting["key"].as_str().unwrap().starts_with("branch.")),
+        "key filter should limit output to the branch section"
+    );
+
+    let missing = repo.run_stax(&["config", "get", "branch.nope"]);
+    missing.assert_failure();
+    missing.assert_stderr_contains("Unknown config key");
+}
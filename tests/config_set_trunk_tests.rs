@@ -0,0 +1,36 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+#[test]
+fn config_set_trunk_overrides_detection_for_status_and_create() {
+    let repo = TestRepo::new();
+    repo.git(&["branch", "develop"]).assert_success();
+
+    let output = repo.run_stax(&["config", "set-trunk", "develop"]);
+    output.assert_success();
+    output.assert_stdout_contains("Trunk pinned to 'develop'");
+
+    let json = repo.get_status_json();
+    assert_eq!(json["trunk"], "develop");
+
+    repo.git(&["checkout", "develop"]).assert_success();
+    repo.run_stax(&["bc", "feature-on-develop"])
+        .assert_success();
+    assert_eq!(repo.get_current_parent().as_deref(), Some("develop"));
+
+    let doctor = repo.run_stax(&["doctor"]);
+    doctor.assert_stdout_contains("develop (explicit)");
+}
+
+#[test]
+fn config_set_trunk_rejects_missing_branch() {
+    let repo = TestRepo::new();
+
+    let output = repo.run_stax(&["config", "set-trunk", "no-such-branch"]);
+    output.assert_failure();
+    output.assert_stderr_contains("does not exist locally");
+
+    let doctor = repo.run_stax(&["doctor"]);
+    doctor.assert_stdout_contains("main (detected)");
+}
@@ -75,6 +75,33 @@ fn test_restack_conflict_still_prints_conflict_info() {
     repo.abort_rebase();
 }
 
+#[test]
+fn restack_show_conflicts_previews_conflicting_hunks() {
+    let repo = TestRepo::new();
+    repo.create_conflict_scenario();
+
+    let output = repo.run_stax(&["restack", "--yes", "--show-conflicts"]);
+    output.assert_failure();
+    let stdout = TestRepo::stdout(&output);
+    assert!(stdout.contains("Conflict preview:"), "stdout:\n{}", stdout);
+    assert!(stdout.contains("  conflict.txt"), "stdout:\n{}", stdout);
+    assert!(stdout.contains("<<<<<<<"), "stdout:\n{}", stdout);
+    assert!(stdout.contains("feature content"), "stdout:\n{}", stdout);
+    assert!(
+        stdout.contains("stax restack --continue"),
+        "stdout:\n{}",
+        stdout
+    );
+    repo.abort_rebase();
+
+    let quiet = repo.run_stax(&["restack", "--yes"]);
+    assert!(
+        !TestRepo::stdout(&quiet).contains("Conflict preview:"),
+        "preview should be opt-in"
+    );
+    repo.abort_rebase();
+}
+
 #[test]
 fn test_restack_no_conflict_exits_zero() {
     let repo = TestRepo::new();
@@ -0,0 +1,48 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+#[test]
+fn create_from_detached_head_requires_explicit_parent() {
+    let repo = TestRepo::new();
+    repo.create_file("base.txt", "base\n");
+    repo.commit("Base commit");
+    let detached_sha = repo.head_sha();
+    repo.git(&["checkout", "--detach", &detached_sha])
+        .assert_success();
+
+    let output = repo.run_stax(&["bc", "x"]);
+    output.assert_failure();
+    output.assert_stderr_contains("HEAD is detached");
+    output.assert_stderr_contains("--parent");
+    assert!(
+        !repo
+            .list_branches()
+            .iter()
+            .any(|branch| branch.ends_with('x')),
+        "no branch should be created from a detached HEAD without --parent"
+    );
+
+    repo.run_stax(&["bc", "x", "--parent", "main"])
+        .assert_success();
+
+    let current = repo.current_branch();
+    assert!(
+        current.ends_with('x'),
+        "expected to be on x, got {}",
+        current
+    );
+    assert_eq!(repo.head_sha(), detached_sha);
+    assert_eq!(repo.get_current_parent().as_deref(), Some("main"));
+}
+
+#[test]
+fn create_from_detached_head_rejects_missing_parent() {
+    let repo = TestRepo::new();
+    let sha = repo.head_sha();
+    repo.git(&["checkout", "--detach", &sha]).assert_success();
+
+    let output = repo.run_stax(&["bc", "x", "--parent", "does-not-exist"]);
+    output.assert_failure();
+    output.assert_stderr_contains("Parent branch 'does-not-exist' does not exist");
+}
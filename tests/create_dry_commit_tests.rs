@@ -0,0 +1,83 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo, run_stax_in_script_with_env};
+
+#[test]
+fn bc_dry_commit_declined_in_terminal_creates_nothing() {
+    let repo = TestRepo::new();
+    repo.run_stax(&["init", "--trunk", "main"]).assert_success();
+    repo.create_file("notes.txt", "notes\n");
+    repo.git(&["add", "notes.txt"]);
+    let home = repo.clean_home();
+
+    let output = run_stax_in_script_with_env(
+        &repo.path(),
+        &["bc", "-m", "Add notes", "--dry-commit"],
+        "printf 'n\\n'",
+        &[("HOME", &home)],
+    );
+
+    assert!(
+        output.status.success(),
+        "bc --dry-commit failed\nstdout:\n{}\nstderr:\n{}",
+        TestRepo::stdout(&output),
+        TestRepo::stderr(&output)
+    );
+    let stdout = TestRepo::stdout(&output);
+    assert!(
+        stdout.contains("Files to commit (1):"),
+        "stdout was:\n{stdout}"
+    );
+    assert!(stdout.contains("A  notes.txt"), "stdout was:\n{stdout}");
+    assert!(stdout.contains("Add-notes"), "stdout was:\n{stdout}");
+    assert!(
+        stdout.contains("Aborted. No branch was created."),
+        "stdout was:\n{stdout}"
+    );
+
+    assert_eq!(repo.current_branch(), "main");
+    assert!(repo.find_branch_containing("Add-notes").is_none());
+    let staged = TestRepo::stdout(&repo.git(&["diff", "--cached", "--name-only"]));
+    assert_eq!(staged.trim(), "notes.txt");
+}
+
+#[test]
+fn bc_dry_commit_without_terminal_declines() {
+    let repo = TestRepo::new();
+    repo.create_file("notes.txt", "notes\n");
+    repo.git(&["add", "notes.txt"]);
+
+    repo.run_stax(&["bc", "-m", "Add notes", "--dry-commit"])
+        .assert_success()
+        .assert_stdout_contains("A  notes.txt")
+        .assert_stdout_contains("pass --yes");
+    assert_eq!(repo.current_branch(), "main");
+    assert!(repo.find_branch_containing("Add-notes").is_none());
+}
+
+#[test]
+fn bc_dry_commit_yes_lists_all_files_and_commits() {
+    let repo = TestRepo::new();
+    repo.create_file("tracked.txt", "tracked\n");
+    repo.git(&["add", "tracked.txt"]);
+    repo.create_file("untracked.txt", "untracked\n");
+
+    repo.run_stax(&["bc", "-a", "-m", "Add both", "--dry-commit", "--yes"])
+        .assert_success()
+        .assert_stdout_contains("Files to commit (2):")
+        .assert_stdout_contains("tracked.txt")
+        .assert_stdout_contains("?? untracked.txt");
+
+    assert!(repo.current_branch_contains("Add-both"));
+    let files = TestRepo::stdout(&repo.git(&["show", "--name-only", "--format=", "HEAD"]));
+    assert!(files.contains("tracked.txt") && files.contains("untracked.txt"));
+}
+
+#[test]
+fn bc_dry_commit_requires_message() {
+    let repo = TestRepo::new();
+
+    repo.run_stax(&["bc", "feature", "--dry-commit"])
+        .assert_failure();
+    assert_eq!(repo.current_branch(), "main");
+}
@@ -0,0 +1,61 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+#[test]
+fn bc_from_head_relative_revision_starts_at_that_commit() {
+    let repo = TestRepo::new();
+    let stack = repo.create_stack_with_commits(&[("rev-base", &["Base 1", "Base 2"])]);
+    let base_commits = &stack[&repo.current_branch()];
+
+    repo.run_stax(&["bc", "rev-child", "--from", "HEAD~1"])
+        .assert_success()
+        .assert_stdout_contains("Branching from HEAD~1");
+
+    assert!(repo.current_branch_contains("rev-child"));
+    assert_eq!(repo.head_sha(), base_commits[0]);
+    // rev-base's tip is not an ancestor of HEAD~1, so trunk is the nearest tracked parent.
+    assert_eq!(repo.get_current_parent().as_deref(), Some("main"));
+}
+
+#[test]
+fn bc_from_raw_sha_stacks_on_nearest_tracked_ancestor() {
+    let repo = TestRepo::new();
+    let stack =
+        repo.create_stack_with_commits(&[("sha-lower", &["Lower 1"]), ("sha-upper", &["Upper 1"])]);
+    let lower = repo
+        .find_branch_containing("sha-lower")
+        .expect("lower branch");
+    let lower_tip = stack[&lower][0].clone();
+    repo.run_stax(&["trunk"]).assert_success();
+
+    repo.run_stax(&["bc", "sha-side", "--from", &lower_tip])
+        .assert_success();
+
+    assert!(repo.current_branch_contains("sha-side"));
+    assert_eq!(repo.head_sha(), lower_tip);
+    assert_eq!(repo.get_current_parent(), Some(lower));
+}
+
+#[test]
+fn bc_from_revision_honours_explicit_parent() {
+    let repo = TestRepo::new();
+    let stack = repo.create_stack_with_commits(&[("explicit-base", &["One", "Two"])]);
+    let base = repo.current_branch();
+    let first = stack[&base][0].clone();
+
+    repo.run_stax(&["bc", "explicit-child", "--from", &first, "--parent", &base])
+        .assert_success();
+
+    assert_eq!(repo.head_sha(), first);
+    assert_eq!(repo.get_current_parent(), Some(base));
+}
+
+#[test]
+fn bc_from_unknown_revision_reports_missing_branch() {
+    let repo = TestRepo::new();
+
+    repo.run_stax(&["bc", "nowhere", "--from", "no-such-thing"])
+        .assert_failure()
+        .assert_stderr_contains("Branch 'no-such-thing' does not exist");
+}
@@ -0,0 +1,51 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+
+#[test]
+fn bc_from_tag_starts_branch_at_tagged_commit() {
+    let repo = TestRepo::new();
+    repo.create_file("release.txt", "1.2.3\n");
+    repo.commit("Release 1.2.3");
+    let tagged = repo.get_commit_sha("HEAD");
+    repo.git(&["tag", "v1.2.3"]).assert_success();
+    repo.create_file("next.txt", "next\n");
+    repo.commit("Post-release work");
+
+    let output = repo.run_stax(&["bc", "hotfix", "--from-tag", "v1.2.3"]);
+    output.assert_success().assert_stdout_contains("from tag");
+
+    assert_eq!(repo.current_branch(), "hotfix");
+    assert_eq!(repo.get_commit_sha("hotfix"), tagged);
+
+    let status = repo.run_stax(&["status", "--json"]);
+    status.assert_success();
+    let json: Value = serde_json::from_str(&TestRepo::stdout(&status)).unwrap();
+    let hotfix = json["branches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|branch| branch["name"] == "hotfix")
+        .expect("hotfix in status");
+    assert_eq!(hotfix["parent"], "main");
+    assert_eq!(hotfix["base_tag"], "v1.2.3");
+
+    repo.run_stax(&["status"])
+        .assert_success()
+        .assert_stdout_contains("(from tag v1.2.3)");
+}
+
+#[test]
+fn bc_from_tag_rejects_unknown_tag() {
+    let repo = TestRepo::new();
+
+    let output = repo.run_stax(&["bc", "hotfix", "--from-tag", "v9.9.9"]);
+    output
+        .assert_failure()
+        .assert_stderr_contains("Tag 'v9.9.9' does not exist");
+    assert!(
+        !TestRepo::stdout(&repo.git(&["branch", "--list", "hotfix"])).contains("hotfix"),
+        "branch should not be created"
+    );
+}
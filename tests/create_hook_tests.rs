@@ -0,0 +1,167 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+
+fn write_hook_config(home: &Path, hook: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("failed to create test config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        // Debug formatting yields a valid TOML basic string for these ASCII hooks.
+        format!("[branch]\npost_create_hook = {:?}\n", hook),
+    )
+    .expect("failed to write test config");
+}
+
+fn env_dump_hook(out: &Path) -> String {
+    format!(
+        "printf '%s\\n%s\\n%s\\n' \"$STAX_BRANCH\" \"$STAX_PARENT\" \"$STAX_BASE\" > \"{}\"",
+        out.display()
+    )
+}
+
+#[test]
+fn bc_runs_configured_post_create_hook_with_branch_env() {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    let out_dir = tempfile::tempdir().expect("hook output dir");
+    let out = out_dir.path().join("hook-env.txt");
+    write_hook_config(Path::new(&home), &env_dump_hook(&out));
+    let main_sha = repo.get_commit_sha("main");
+
+    repo.run_stax(&["bc", "hooked-branch"]).assert_success();
+    let branch = repo.current_branch();
+
+    let dumped = fs::read_to_string(&out).expect("hook should have written its env");
+    let lines: Vec<&str> = dumped.lines().collect();
+    assert_eq!(lines, vec![branch.as_str(), "main", main_sha.as_str()]);
+}
+
+#[test]
+fn bc_hook_flag_overrides_config_and_failure_only_warns() {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    let out_dir = tempfile::tempdir().expect("hook output dir");
+    let configured = out_dir.path().join("configured.txt");
+    write_hook_config(Path::new(&home), &env_dump_hook(&configured));
+
+    let output = repo.run_stax(&["bc", "failing-hook", "--hook", "exit 3"]);
+    output
+        .assert_success()
+        .assert_stderr_contains("post-create hook exited");
+    assert!(
+        repo.current_branch().ends_with("failing-hook"),
+        "branch should stay created after a failing hook"
+    );
+    assert!(
+        !configured.exists(),
+        "--hook should replace the configured hook"
+    );
+
+    repo.run_stax(&["bc", "skipped-hook", "--no-verify"])
+        .assert_success();
+    assert!(
+        !configured.exists(),
+        "--no-verify should skip the configured hook"
+    );
+}
+
+#[test]
+fn bc_no_verify_skips_only_the_configured_hook() {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    let out_dir = tempfile::tempdir().expect("hook output dir");
+    let configured = out_dir.path().join("configured.txt");
+    let explicit = out_dir.path().join("explicit.txt");
+    write_hook_config(Path::new(&home), &env_dump_hook(&configured));
+
+    let hook = env_dump_hook(&explicit);
+    repo.run_stax(&["bc", "explicit-hook", "--no-verify", "--hook", &hook])
+        .assert_success();
+    assert!(
+        explicit.exists(),
+        "an explicit --hook should run despite --no-verify"
+    );
+    assert!(
+        !configured.exists(),
+        "--no-verify should skip the configured hook"
+    );
+
+    repo.run_stax(&["bc", "--help"])
+        .assert_success()
+        .assert_stdout_contains("branch.post_create_hook");
+}
+
+fn write_scaffold_config(home: &Path, command: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("failed to create test config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[branch]\nscaffold_command = {:?}\n", command),
+    )
+    .expect("failed to write test config");
+}
+
+#[test]
+fn bc_scaffold_runs_generator_and_optionally_commits_its_output() {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_scaffold_config(
+        Path::new(&home),
+        "printf 'component for %s\\n' \"$STAX_BRANCH\" > \"$STAX_BRANCH.component\"",
+    );
+
+    repo.run_stax(&["bc", "scaffolded", "--scaffold"])
+        .assert_success();
+    let branch = repo.current_branch();
+    let generated = repo.path().join(format!("{branch}.component"));
+    assert_eq!(
+        fs::read_to_string(&generated).expect("scaffold should have written its file"),
+        format!("component for {branch}\n")
+    );
+    assert_eq!(
+        repo.head_sha(),
+        repo.get_commit_sha("main"),
+        "--scaffold alone leaves the output uncommitted"
+    );
+    fs::remove_file(&generated).expect("remove uncommitted scaffold output");
+
+    repo.run_stax(&[
+        "bc",
+        "committed-scaffold",
+        "--scaffold",
+        "--scaffold-commit",
+    ])
+    .assert_success();
+    let branch = repo.current_branch();
+    let file = format!("{branch}.component");
+    let committed = repo.git(&["show", &format!("HEAD:{file}")]);
+    assert!(
+        committed.status.success(),
+        "scaffold output should be committed"
+    );
+    assert_eq!(
+        String::from_utf8_lossy(&committed.stdout),
+        format!("component for {branch}\n")
+    );
+    let subject = repo.git(&["log", "-1", "--format=%s"]);
+    assert_eq!(
+        String::from_utf8_lossy(&subject.stdout).trim(),
+        format!("Scaffold {branch}")
+    );
+}
+
+#[test]
+fn bc_scaffold_failure_only_warns() {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_scaffold_config(Path::new(&home), "exit 4");
+
+    repo.run_stax(&["bc", "broken-scaffold", "--scaffold", "--scaffold-commit"])
+        .assert_success()
+        .assert_stderr_contains("scaffold command exited");
+    assert!(repo.current_branch().ends_with("broken-scaffold"));
+    assert_eq!(repo.head_sha(), repo.get_commit_sha("main"));
+}
@@ -0,0 +1,25 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+#[test]
+fn bc_interactive_requires_a_terminal() {
+    let repo = TestRepo::new();
+
+    let output = repo.run_stax(&["bc", "--interactive"]);
+    output
+        .assert_failure()
+        .assert_stderr_contains("Interactive mode needs a terminal");
+    assert_eq!(repo.current_branch(), "main");
+}
+
+#[test]
+fn bc_interactive_conflicts_with_branch_name() {
+    let repo = TestRepo::new();
+
+    repo.run_stax(&["bc", "-i", "named"]).assert_failure();
+    assert!(
+        !TestRepo::stdout(&repo.git(&["branch", "--list", "named"])).contains("named"),
+        "branch should not be created"
+    );
+}
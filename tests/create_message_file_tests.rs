@@ -0,0 +1,54 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+
+#[test]
+fn bc_message_file_names_branch_from_subject_and_commits_full_message() {
+    let repo = TestRepo::new();
+    let message_file = tempfile::NamedTempFile::new().expect("message temp file");
+    fs::write(
+        message_file.path(),
+        "Add retry logic\n\nRetries failed uploads three times.\nRefs #42\n\n",
+    )
+    .expect("write message file");
+    repo.create_file("retry.txt", "retry\n");
+    repo.git(&["add", "retry.txt"]).assert_success();
+
+    repo.run_stax(&[
+        "bc",
+        "-F",
+        message_file.path().to_str().expect("message path"),
+    ])
+    .assert_success();
+
+    let branch = repo.current_branch();
+    assert_eq!(
+        branch, "Add-retry-logic",
+        "branch should be named after the subject line only"
+    );
+    let body = repo.git(&["log", "-1", "--format=%B"]);
+    body.assert_success();
+    assert_eq!(
+        TestRepo::stdout(&body).trim_end(),
+        "Add retry logic\n\nRetries failed uploads three times.\nRefs #42"
+    );
+}
+
+#[test]
+fn bc_message_file_rejects_missing_and_empty_files() {
+    let repo = TestRepo::new();
+    let empty = tempfile::NamedTempFile::new().expect("empty temp file");
+    fs::write(empty.path(), " \n\n").expect("write empty message file");
+
+    repo.run_stax(&[
+        "bc",
+        "--message-file",
+        empty.path().to_str().expect("empty path"),
+    ])
+    .assert_failure()
+    .assert_stderr_contains("is empty");
+    repo.run_stax(&["bc", "--message-file", "does-not-exist.txt"])
+        .assert_failure()
+        .assert_stderr_contains("Failed to read message file");
+}
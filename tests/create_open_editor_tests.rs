@@ -0,0 +1,172 @@
+//! `stax branch create --open-editor`, `stax branch describe` and `stax branch info`.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An `$EDITOR` that appends a description below the template.
+fn write_editor_script(dir: &Path) -> PathBuf {
+    let script = dir.join("editor.sh");
+    fs::write(
+        &script,
+        "#!/bin/sh\nprintf 'Split the lexer out of the parser\\n\\nKeeps tokens reusable.\\n' >> \"$1\"\n",
+    )
+    .expect("write editor script");
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755))
+            .expect("make editor script executable");
+    }
+    script
+}
+
+fn branch_info_json(repo: &TestRepo, branch: &str) -> serde_json::Value {
+    let output = repo.run_stax(&["branch", "info", branch, "--json"]);
+    output.assert_success();
+    serde_json::from_str(&TestRepo::stdout(&output)).expect("branch info JSON")
+}
+
+#[test]
+fn open_editor_stores_description_shown_by_branch_info() {
+    let repo = TestRepo::new();
+    let dir = tempfile::tempdir().expect("editor dir");
+    let editor = write_editor_script(dir.path());
+    let editor = editor.to_str().expect("editor path");
+
+    repo.run_stax_with_env(
+        &["branch", "create", "lexer-split", "--open-editor"],
+        &[
+            ("EDITOR", editor),
+            ("STAX_TEST_FORCE_INTERACTIVE_TERMINAL", "1"),
+        ],
+    )
+    .assert_success()
+    .assert_stdout_contains("Saved description");
+    let branch = repo.current_branch();
+
+    let info = branch_info_json(&repo, &branch);
+    assert_eq!(info["branch"], branch.as_str());
+    assert_eq!(info["parent"], "main");
+    assert_eq!(
+        info["description"],
+        "Split the lexer out of the parser\n\nKeeps tokens reusable."
+    );
+
+    repo.run_stax(&["branch", "info"])
+        .assert_success()
+        .assert_stdout_contains("Keeps tokens reusable.");
+}
+
+#[test]
+fn open_editor_is_skipped_when_quiet_or_without_a_terminal() {
+    let repo = TestRepo::new();
+    let dir = tempfile::tempdir().expect("editor dir");
+    let editor = write_editor_script(dir.path());
+    let editor = editor.to_str().expect("editor path");
+
+    repo.run_stax_with_env(&["bc", "no-tty", "--open-editor"], &[("EDITOR", editor)])
+        .assert_success();
+    let no_tty = repo.current_branch();
+    repo.run_stax_with_env(
+        &["bc", "quiet-create", "--open-editor", "--quiet"],
+        &[
+            ("EDITOR", editor),
+            ("STAX_TEST_FORCE_INTERACTIVE_TERMINAL", "1"),
+        ],
+    )
+    .assert_success();
+    let quiet = repo.current_branch();
+
+    for branch in [no_tty, quiet] {
+        assert!(branch_info_json(&repo, &branch)["description"].is_null());
+    }
+}
+
+#[test]
+fn branch_description_survives_restack() {
+    let repo = TestRepo::new();
+    let dir = tempfile::tempdir().expect("editor dir");
+    let editor = write_editor_script(dir.path());
+    let editor = editor.to_str().expect("editor path");
+
+    repo.run_stax_with_env(
+        &["bc", "described", "--open-editor"],
+        &[
+            ("EDITOR", editor),
+            ("STAX_TEST_FORCE_INTERACTIVE_TERMINAL", "1"),
+        ],
+    )
+    .assert_success();
+    let branch = repo.current_branch();
+    repo.create_file("described.txt", "work");
+    repo.commit("Described work");
+
+    repo.run_stax(&["t"]).assert_success();
+    repo.create_file("trunk.txt", "trunk moved");
+    repo.commit("Advance trunk");
+    repo.run_stax(&["checkout", &branch]).assert_success();
+    repo.run_stax(&["restack", "--yes"]).assert_success();
+
+    assert_eq!(
+        branch_info_json(&repo, &branch)["description"],
+        "Split the lexer out of the parser\n\nKeeps tokens reusable."
+    );
+}
+
+#[test]
+fn branch_describe_sets_edits_and_clears_the_description() {
+    let repo = TestRepo::new();
+    let branch = repo.create_stack(&["described"]).remove(0);
+
+    repo.run_stax(&[
+        "branch",
+        "describe",
+        "-m",
+        "Move login to OAuth\n\nThe old form is removed.",
+    ])
+    .assert_success()
+    .assert_stdout_contains("Saved description");
+    assert_eq!(
+        branch_info_json(&repo, &branch)["description"],
+        "Move login to OAuth\n\nThe old form is removed."
+    );
+
+    let log = repo.run_stax(&["log"]);
+    log.assert_success()
+        .assert_stdout_contains("Move login to OAuth");
+    assert!(!TestRepo::stdout(&log).contains("The old form is removed."));
+    let log_json: serde_json::Value =
+        serde_json::from_str(&TestRepo::stdout(&repo.run_stax(&["log", "--json"])))
+            .expect("log JSON");
+    let entry = log_json["branches"]
+        .as_array()
+        .expect("branches")
+        .iter()
+        .find(|entry| entry["name"] == branch.as_str())
+        .expect("described branch in log");
+    assert_eq!(
+        entry["description"],
+        "Move login to OAuth\n\nThe old form is removed."
+    );
+
+    // The editor starts from the current text.
+    let dir = tempfile::tempdir().expect("editor dir");
+    let editor = write_editor_script(dir.path());
+    repo.run_stax_with_env(
+        &["branch", "describe", &branch],
+        &[("EDITOR", editor.to_str().expect("editor path"))],
+    )
+    .assert_success();
+    assert_eq!(
+        branch_info_json(&repo, &branch)["description"],
+        "Move login to OAuth\n\nThe old form is removed.\n\n\
+         Split the lexer out of the parser\n\nKeeps tokens reusable."
+    );
+
+    repo.run_stax(&["branch", "describe", "--clear"])
+        .assert_success();
+    assert!(branch_info_json(&repo, &branch)["description"].is_null());
+}
@@ -0,0 +1,97 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+fn write_patch(repo: &TestRepo, file: &str, content: &str) -> std::path::PathBuf {
+    repo.create_file(file, content);
+    let diff = repo.git(&["diff"]);
+    diff.assert_success();
+    repo.git(&["checkout", "--", file]).assert_success();
+
+    let patch_path = repo.path().join(".git").join("test.patch");
+    std::fs::write(&patch_path, TestRepo::stdout(&diff) + "\n").expect("write patch");
+    patch_path
+}
+
+#[test]
+fn create_with_patch_commits_patch_on_new_branch() {
+    let repo = TestRepo::new();
+    repo.create_file("app.txt", "one\n");
+    repo.commit("Add app");
+    let patch = write_patch(&repo, "app.txt", "one\ntwo\n");
+
+    repo.run_stax(&[
+        "bc",
+        "fix",
+        "--patch",
+        patch.to_str().unwrap(),
+        "-m",
+        "Apply fix",
+    ])
+    .assert_success();
+
+    let branch = repo.current_branch();
+    assert!(branch.ends_with("fix"), "expected fix branch, got {branch}");
+    assert_eq!(
+        std::fs::read_to_string(repo.path().join("app.txt")).unwrap(),
+        "one\ntwo\n"
+    );
+    let log = repo.git(&["log", "--format=%s", "main..HEAD"]);
+    assert_eq!(TestRepo::stdout(&log).trim(), "Apply fix");
+    let status = repo.git(&["status", "--porcelain"]);
+    assert!(TestRepo::stdout(&status).trim().is_empty());
+    assert_eq!(repo.get_current_parent().as_deref(), Some("main"));
+}
+
+#[test]
+fn create_with_patch_uses_format_patch_subject() {
+    let repo = TestRepo::new();
+    repo.create_file("lib.txt", "a\n");
+    repo.commit("Add lib");
+    repo.create_file("lib.txt", "a\nb\n");
+    repo.commit("fix: add b");
+    let format_patch = repo.git(&["format-patch", "-1", "--stdout"]);
+    format_patch.assert_success();
+    repo.git(&["reset", "--hard", "HEAD~1"]).assert_success();
+    let patch = repo.path().join(".git").join("fp.patch");
+    std::fs::write(&patch, TestRepo::stdout(&format_patch)).unwrap();
+
+    repo.run_stax(&["bc", "from-patch", "--patch", patch.to_str().unwrap()])
+        .assert_success();
+
+    let log = repo.git(&["log", "-1", "--format=%s"]);
+    assert_eq!(TestRepo::stdout(&log).trim(), "fix: add b");
+    assert_eq!(
+        std::fs::read_to_string(repo.path().join("lib.txt")).unwrap(),
+        "a\nb\n"
+    );
+}
+
+#[test]
+fn create_with_non_applying_patch_leaves_no_partial_state() {
+    let repo = TestRepo::new();
+    repo.create_file("app.txt", "one\n");
+    repo.commit("Add app");
+    let patch = write_patch(&repo, "app.txt", "one\ntwo\n");
+    repo.create_file("app.txt", "completely different\n");
+    repo.commit("Rewrite app");
+    let head_before = repo.head_sha();
+    let branches_before = repo.list_branches();
+
+    let output = repo.run_stax(&[
+        "bc",
+        "broken",
+        "--patch",
+        patch.to_str().unwrap(),
+        "-m",
+        "Apply",
+    ]);
+    output.assert_failure();
+    output.assert_stderr_contains("does not apply cleanly");
+
+    assert_eq!(repo.current_branch(), "main");
+    assert_eq!(repo.head_sha(), head_before);
+    assert_eq!(repo.list_branches(), branches_before);
+    let status = repo.git(&["status", "--porcelain"]);
+    assert!(TestRepo::stdout(&status).trim().is_empty());
+}
@@ -0,0 +1,50 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+
+fn needs_restack(repo: &TestRepo, branch: &str) -> bool {
+    let output = repo.run_stax(&["status", "--json"]);
+    output.assert_success();
+    let status: Value =
+        serde_json::from_str(&TestRepo::stdout(&output)).expect("status JSON should parse");
+    status["branches"]
+        .as_array()
+        .expect("status JSON should include branches")
+        .iter()
+        .find(|entry| entry["name"] == branch)
+        .expect("branch should be listed")["needs_restack"]
+        .as_bool()
+        .expect("needs_restack should be a bool")
+}
+
+#[test]
+fn bc_no_parent_revision_flags_branch_for_restack_immediately() {
+    let repo = TestRepo::new();
+
+    repo.run_stax(&["bc", "pending-branch", "--no-parent-revision"])
+        .assert_success()
+        .assert_stdout_contains("marked for restack");
+    let branch = repo.current_branch();
+    repo.create_file("pending.txt", "pending\n");
+    repo.commit("Pending change");
+
+    assert!(needs_restack(&repo, &branch));
+
+    // Restack falls back to the merge-base boundary and records a real revision.
+    repo.run_stax(&["restack", "--yes", "--quiet"])
+        .assert_success();
+    assert!(!needs_restack(&repo, &branch));
+    assert!(repo.path().join("pending.txt").exists());
+}
+
+#[test]
+fn bc_pending_alias_matches_no_parent_revision() {
+    let repo = TestRepo::new();
+
+    repo.run_stax(&["bc", "pending-alias", "--pending"])
+        .assert_success();
+    let branch = repo.current_branch();
+
+    assert!(needs_restack(&repo, &branch));
+}
@@ -0,0 +1,82 @@
+//! `stax branch create` guard against protected remote branch names.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn ensure_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{}\"\n", api_base_url),
+    )
+    .expect("Failed to write config");
+}
+
+async fn setup_protected_repo(mock_server: &MockServer) -> TestRepo {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("branchProtectionRules"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "repository": { "branchProtectionRules": { "nodes": [
+                { "pattern": "main" },
+                { "pattern": "release/*" }
+            ] } } }
+        })))
+        .mount(mock_server)
+        .await;
+    repo
+}
+
+#[tokio::test]
+async fn create_refuses_protected_branch_name_without_force() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let repo = setup_protected_repo(&mock_server).await;
+    let env = [("STAX_GITHUB_TOKEN", "mock-token")];
+
+    repo.run_stax_with_env(&["bc", "main"], &env)
+        .assert_failure()
+        .assert_stderr_contains("protected branch pattern 'main'")
+        .assert_stderr_contains("--force");
+
+    repo.run_stax_with_env(&["bc", "release/v2"], &env)
+        .assert_failure()
+        .assert_stderr_contains("protected branch pattern 'release/*'");
+    assert_eq!(repo.current_branch(), "main");
+
+    repo.run_stax_with_env(&["bc", "release/v2", "--force"], &env)
+        .assert_success()
+        .assert_stderr_contains("Warning: 'release/v2' matches the protected branch pattern");
+    assert_eq!(repo.current_branch(), "release/v2");
+}
+
+#[test]
+fn create_only_warns_on_common_names_without_protection_info() {
+    let repo = TestRepo::new();
+
+    repo.run_stax(&["bc", "develop"])
+        .assert_success()
+        .assert_stderr_contains("'develop' looks like a long-lived branch");
+    assert_eq!(repo.current_branch(), "develop");
+}
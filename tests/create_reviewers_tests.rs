@@ -0,0 +1,221 @@
+use crate::common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("failed to create test config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!(
+            "[remote]\napi_base_url = \"{api_base_url}\"\n\n\
+             [submit]\nstack_links = \"off\"\nnative_stack = \"off\"\n"
+        ),
+    )
+    .expect("failed to write test config");
+}
+
+fn created_pr() -> Value {
+    serde_json::json!({
+        "url": "https://api.github.com/repos/test-owner/test-repo/pulls/42",
+        "id": 42,
+        "number": 42,
+        "state": "open",
+        "title": "created",
+        "body": "",
+        "draft": false,
+        "head": { "ref": "created", "sha": "aaaa", "label": "test-owner:created" },
+        "base": { "ref": "main", "sha": "bbbb" },
+        "html_url": "https://github.com/test-owner/test-repo/pull/42"
+    })
+}
+
+/// GitHub answers a review request with the PR, which octocrab parses as a review.
+fn requested_reviewers_response() -> Value {
+    let mut pr = created_pr();
+    pr["node_id"] = serde_json::json!("PR_test_42");
+    pr["user"] = Value::Null;
+    pr
+}
+
+async fn mock_github_pr_create(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/pulls"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(created_pr()))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(created_pr()))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/issues/42/comments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/repos/test-owner/test-repo/pulls/42/requested_reviewers",
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(requested_reviewers_response()))
+        .mount(mock_server)
+        .await;
+}
+
+fn metadata_for(repo: &TestRepo, branch: &str) -> Value {
+    let output = repo.git(&["show", &format!("refs/branch-metadata/{}", branch)]);
+    output.assert_success();
+    serde_json::from_str(&TestRepo::stdout(&output)).expect("valid metadata JSON")
+}
+
+#[test]
+fn bc_reviewers_are_stored_in_metadata_and_survive_restack() {
+    let repo = TestRepo::new();
+    let parent = repo.create_stack(&["reviewed-parent"]).remove(0);
+    repo.create_file("reviewed.txt", "change\n");
+
+    repo.run_stax(&[
+        "bc",
+        "-a",
+        "-m",
+        "Reviewed change",
+        "--reviewers",
+        "alice,@acme/core",
+    ])
+    .assert_success()
+    .assert_stdout_contains("Reviewers for submit");
+    let branch = repo.current_branch();
+
+    let metadata = metadata_for(&repo, &branch);
+    assert_eq!(metadata["reviewers"], serde_json::json!(["alice"]));
+    assert_eq!(metadata["teamReviewers"], serde_json::json!(["acme/core"]));
+
+    // Restack rewrites metadata; the saved reviewers must be carried over.
+    repo.run_stax(&["checkout", &parent]).assert_success();
+    repo.create_file("parent-extra.txt", "parent\n");
+    repo.commit("Advance parent");
+    repo.run_stax(&["restack", "--all", "--quiet"])
+        .assert_success();
+    assert_eq!(
+        metadata_for(&repo, &branch)["reviewers"],
+        serde_json::json!(["alice"])
+    );
+}
+
+#[tokio::test]
+async fn submit_requests_reviewers_saved_by_bc() {
+    let mock_server = MockServer::start().await;
+    mock_github_pr_create(&mock_server).await;
+
+    let repo = TestRepo::new_with_remote();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.configure_github_like_submit_remote();
+
+    repo.create_file("reviewed.txt", "change\n");
+    repo.run_stax(&[
+        "bc",
+        "-a",
+        "-m",
+        "Reviewed change",
+        "--reviewers",
+        "alice,@acme/core",
+    ])
+    .assert_success();
+
+    let output = repo.run_stax_with_env(
+        &["submit", "--yes", "--no-prompt", "--no-template"],
+        &[("STAX_GITHUB_TOKEN", "test-token")],
+    );
+    assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let review_request = requests
+        .iter()
+        .find(|request| {
+            request.method.as_str() == "POST"
+                && request.url.path() == "/repos/test-owner/test-repo/pulls/42/requested_reviewers"
+        })
+        .expect("submit should request the saved reviewers");
+    let payload: Value = serde_json::from_slice(&review_request.body).expect("JSON body");
+    assert_eq!(payload["reviewers"], serde_json::json!(["alice"]));
+    assert_eq!(payload["team_reviewers"], serde_json::json!(["core"]));
+}
+
+#[tokio::test]
+async fn submit_codeowners_requests_owners_of_changed_files() {
+    let mock_server = MockServer::start().await;
+    mock_github_pr_create(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path("/user"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "login": "me",
+            "id": 1,
+            "node_id": "MDQ6VXNlcjE=",
+            "avatar_url": "https://avatars.githubusercontent.com/u/1?v=4",
+            "gravatar_id": "",
+            "url": "https://api.github.com/users/me",
+            "html_url": "https://github.com/me",
+            "followers_url": "https://api.github.com/users/me/followers",
+            "following_url": "https://api.github.com/users/me/following{/other_user}",
+            "gists_url": "https://api.github.com/users/me/gists{/gist_id}",
+            "starred_url": "https://api.github.com/users/me/starred{/owner}{/repo}",
+            "subscriptions_url": "https://api.github.com/users/me/subscriptions",
+            "organizations_url": "https://api.github.com/users/me/orgs",
+            "repos_url": "https://api.github.com/users/me/repos",
+            "events_url": "https://api.github.com/users/me/events{/privacy}",
+            "received_events_url": "https://api.github.com/users/me/received_events",
+            "type": "User",
+            "site_admin": false
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let repo = TestRepo::new_with_remote();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.configure_github_like_submit_remote();
+    repo.create_file(
+        ".github/CODEOWNERS",
+        "* @fallback\n/api/ @me @bob @acme/api\n",
+    );
+    repo.commit("Add CODEOWNERS");
+    repo.git(&["push", "origin", "main"]).assert_success();
+
+    repo.create_file("api/handler.rs", "change\n");
+    repo.run_stax(&["bc", "-a", "-m", "Touch api"])
+        .assert_success();
+
+    let output = repo.run_stax_with_env(
+        &[
+            "submit",
+            "--yes",
+            "--no-prompt",
+            "--no-template",
+            "--codeowners",
+        ],
+        &[("STAX_GITHUB_TOKEN", "test-token")],
+    );
+    assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let review_request = requests
+        .iter()
+        .find(|request| {
+            request.method.as_str() == "POST"
+                && request.url.path() == "/repos/test-owner/test-repo/pulls/42/requested_reviewers"
+        })
+        .expect("submit should request the code owners");
+    let payload: Value = serde_json::from_slice(&review_request.body).expect("JSON body");
+    assert_eq!(payload["reviewers"], serde_json::json!(["bob"]));
+    assert_eq!(payload["team_reviewers"], serde_json::json!(["api"]));
+}
@@ -0,0 +1,24 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+#[test]
+fn bc_summary_names_the_inferred_parent_and_base() {
+    let repo = TestRepo::new();
+    let feature = repo.create_stack(&["summary-feature"]).remove(0);
+    let base = repo.head_sha();
+
+    let output = repo.run_stax(&["bc", "summary-child"]);
+    output.assert_success();
+    let child = repo.current_branch();
+    output.assert_stdout_contains(&format!(
+        "Created {child} on {feature} (base {}) — tracked",
+        &base[..7]
+    ));
+    output.assert_stdout_not_contains("Created and switched");
+
+    repo.run_stax(&["checkout", &feature]).assert_success();
+    repo.run_stax(&["bc", "summary-quiet", "--quiet"])
+        .assert_success()
+        .assert_stdout_not_contains("— tracked");
+}
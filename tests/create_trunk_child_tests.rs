@@ -0,0 +1,86 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+
+fn repo_with_trunk_child_limit(limit: usize) -> TestRepo {
+    let repo = TestRepo::new();
+    let config_dir = Path::new(&repo.clean_home()).join(".config").join("stax");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[branch]\nmax_empty_trunk_children = {}\n", limit),
+    )
+    .expect("failed to write test config");
+    repo
+}
+
+#[test]
+fn bc_on_trunk_stops_once_empty_trunk_children_hit_the_limit() {
+    let repo = repo_with_trunk_child_limit(2);
+    for name in ["scratch-a", "scratch-b"] {
+        repo.run_stax(&["bc", name]).assert_success();
+        repo.run_stax(&["trunk"]).assert_success();
+    }
+
+    repo.run_stax(&["bc", "scratch-c"])
+        .assert_failure()
+        .assert_stderr_contains("already has 2 branches with no commits")
+        .assert_stderr_contains("--allow-trunk-child");
+    assert_eq!(repo.current_branch(), "main");
+    assert!(repo.find_branch_containing("scratch-c").is_none());
+
+    repo.run_stax(&["bc", "scratch-c", "--allow-trunk-child"])
+        .assert_success();
+    assert!(repo.current_branch_contains("scratch-c"));
+}
+
+#[test]
+fn bc_trunk_child_limit_ignores_children_with_commits() {
+    let repo = repo_with_trunk_child_limit(1);
+    repo.run_stax(&["bc", "real-work"]).assert_success();
+    repo.create_file("work.txt", "work\n");
+    repo.commit("Real work");
+    repo.run_stax(&["trunk"]).assert_success();
+
+    repo.run_stax(&["bc", "next-work"]).assert_success();
+    assert!(repo.current_branch_contains("next-work"));
+}
+
+#[test]
+fn bc_without_limit_allows_any_number_of_trunk_children() {
+    let repo = TestRepo::new();
+    for name in ["free-a", "free-b", "free-c"] {
+        repo.run_stax(&["bc", name]).assert_success();
+        repo.run_stax(&["trunk"]).assert_success();
+    }
+}
+
+#[test]
+fn bc_from_tag_or_detached_head_respects_the_trunk_child_limit() {
+    let repo = repo_with_trunk_child_limit(1);
+    repo.run_stax(&["bc", "scratch-a"]).assert_success();
+    repo.run_stax(&["trunk"]).assert_success();
+    repo.git(&["tag", "v1.0.0"]).assert_success();
+
+    repo.run_stax(&["bc", "from-tag", "--from-tag", "v1.0.0"])
+        .assert_failure()
+        .assert_stderr_contains("already has 1 branches with no commits");
+    assert!(repo.find_branch_containing("from-tag").is_none());
+
+    let head = repo.head_sha();
+    repo.git(&["checkout", "--detach", &head]).assert_success();
+    repo.run_stax(&["bc", "loose-work", "--parent", "main"])
+        .assert_failure()
+        .assert_stderr_contains("already has 1 branches with no commits");
+    assert!(repo.find_branch_containing("loose-work").is_none());
+
+    repo.run_stax(&[
+        "bc",
+        "loose-work",
+        "--parent",
+        "main",
+        "--allow-trunk-child",
+    ])
+    .assert_success();
+}
@@ -0,0 +1,61 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+fn upstream_of(repo: &TestRepo, branch: &str) -> String {
+    let output = repo.git(&[
+        "rev-parse",
+        "--abbrev-ref",
+        &format!("{}@{{upstream}}", branch),
+    ]);
+    output.assert_success();
+    TestRepo::stdout(&output).trim().to_string()
+}
+
+#[test]
+fn bc_set_upstream_configures_existing_remote_branch_without_pushing() {
+    let repo = TestRepo::new_with_remote();
+    repo.git(&["push", "origin", "main:shared-base"])
+        .assert_success();
+    repo.git(&["fetch", "origin"]).assert_success();
+
+    let output = repo.run_stax(&["bc", "tracked", "--set-upstream", "origin/shared-base"]);
+    output.assert_success();
+    output.assert_stdout_contains("not pushed");
+
+    let branch = repo.current_branch();
+    assert!(branch.contains("tracked"), "unexpected branch {branch}");
+    assert_eq!(upstream_of(&repo, &branch), "origin/shared-base");
+    assert!(
+        !repo
+            .list_remote_branches()
+            .iter()
+            .any(|remote| remote.contains("tracked")),
+        "--set-upstream must not push"
+    );
+}
+
+#[test]
+fn bc_set_upstream_accepts_a_future_remote_branch() {
+    let repo = TestRepo::new_with_remote();
+
+    let output = repo.run_stax(&["bc", "later", "--set-upstream", "origin/later-remote"]);
+    output.assert_success();
+
+    let branch = repo.current_branch();
+    let remote = repo.git(&["config", &format!("branch.{}.remote", branch)]);
+    assert_eq!(TestRepo::stdout(&remote).trim(), "origin");
+    let merge = repo.git(&["config", &format!("branch.{}.merge", branch)]);
+    assert_eq!(TestRepo::stdout(&merge).trim(), "refs/heads/later-remote");
+}
+
+#[test]
+fn bc_set_upstream_rejects_unknown_remote_before_creating_branch() {
+    let repo = TestRepo::new_with_remote();
+    let before = repo.list_branches();
+
+    let output = repo.run_stax(&["bc", "nope", "--set-upstream", "upstream/nope"]);
+    output.assert_failure();
+    output.assert_stderr_contains("expected <remote>/<branch>");
+    assert_eq!(repo.list_branches(), before);
+}
@@ -0,0 +1,48 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+
+#[test]
+fn doctor_json_counts_drifted_branches_and_missing_refs() {
+    let repo = TestRepo::new();
+    let branches = repo.create_stack(&["drift-base", "drift-middle", "drift-tip"]);
+    let (base, middle, tip) = (&branches[0], &branches[1], &branches[2]);
+
+    let clean = repo.run_stax(&["doctor", "--json"]);
+    clean.assert_success();
+    let report: Value = serde_json::from_str(&TestRepo::stdout(&clean)).expect("doctor JSON");
+    assert_eq!(report["tracked_branches"], 3);
+    assert_eq!(report["drifted_branches"], 0);
+    assert_eq!(report["health_score"], 100);
+
+    // Advancing the base leaves its child's recorded parent revision behind.
+    repo.run_stax(&["checkout", base]).assert_success();
+    repo.create_file("drift.txt", "advance base");
+    repo.commit("Advance base");
+    repo.git(&["branch", "-D", tip]).assert_success();
+
+    let output = repo.run_stax(&["doctor", "--json"]);
+    output.assert_success();
+    let report: Value = serde_json::from_str(&TestRepo::stdout(&output)).expect("doctor JSON");
+    assert_eq!(report["drifted_branches"], 1);
+    assert_eq!(report["missing_refs"], 1);
+    assert_eq!(report["health_score"], 33);
+
+    let entry = |name: &str| {
+        report["branches"]
+            .as_array()
+            .expect("branches array")
+            .iter()
+            .find(|entry| entry["name"].as_str() == Some(name))
+            .cloned()
+            .unwrap_or_else(|| panic!("missing {name} in doctor JSON"))
+    };
+    assert_eq!(entry(middle)["drifted"], true);
+    assert_eq!(
+        entry(middle)["parent_revision"],
+        repo.get_commit_sha(base).as_str()
+    );
+    assert_eq!(entry(base)["drifted"], false);
+    assert_eq!(entry(tip)["missing_ref"], true);
+}
@@ -454,3 +454,82 @@ fn test_fold_orphaned_pr_hint_when_pr_info_present() {
         out
     );
 }
+
+// =============================================================================
+// squash --into-parent
+// =============================================================================
+
+#[test]
+fn test_squash_into_parent_folds_one_commit_and_restacks_child() {
+    let repo = TestRepo::new();
+    let stack = repo.create_stack_with_commits(&[
+        ("sq-parent", &["Parent work"]),
+        ("sq-middle", &["Middle one", "Middle two", "Middle three"]),
+        ("sq-child", &["Child work"]),
+    ]);
+    let name = |needle: &str| {
+        stack
+            .keys()
+            .find(|branch| branch.contains(needle))
+            .cloned()
+            .expect("branch created")
+    };
+    let (parent, middle, child) = (name("sq-parent"), name("sq-middle"), name("sq-child"));
+    let parent_before = repo.get_commit_sha(&parent);
+
+    repo.run_stax(&["checkout", &middle]).assert_success();
+    repo.run_stax(&[
+        "branch",
+        "squash",
+        "--into-parent",
+        "-m",
+        "Squashed middle",
+        "--yes",
+    ])
+    .assert_success();
+
+    assert_eq!(repo.current_branch(), parent);
+    assert!(
+        !repo.list_branches().contains(&middle),
+        "squashed branch should be deleted"
+    );
+    let log = repo.git(&["log", "--format=%s", &format!("{parent_before}..{parent}")]);
+    assert_eq!(TestRepo::stdout(&log).trim(), "Squashed middle");
+    let files = repo.git(&["ls-tree", "--name-only", &parent]);
+    let files = TestRepo::stdout(&files);
+    for index in 1..=3 {
+        assert!(files.contains(&format!("sq-middle-{index}.txt")), "{files}");
+    }
+
+    repo.run_stax(&["checkout", &child]).assert_success();
+    assert_eq!(repo.get_current_parent().as_deref(), Some(parent.as_str()));
+    let child_log = repo.git(&["log", "--format=%s", &format!("{parent}..{child}")]);
+    assert_eq!(TestRepo::stdout(&child_log).trim(), "Child work");
+    let merge_base = repo.git(&["merge-base", &parent, &child]);
+    assert_eq!(
+        TestRepo::stdout(&merge_base).trim(),
+        repo.get_commit_sha(&parent),
+        "child should be restacked onto the squashed parent"
+    );
+}
+
+#[test]
+fn test_squash_into_parent_undo_restores_both_branches() {
+    let repo = TestRepo::new();
+    let stack = repo.create_stack_with_commits(&[
+        ("undo-parent", &["Parent work"]),
+        ("undo-middle", &["Middle one", "Middle two"]),
+    ]);
+    let shas: Vec<(String, String)> = stack
+        .keys()
+        .map(|branch| (branch.clone(), repo.get_commit_sha(branch)))
+        .collect();
+
+    repo.run_stax(&["branch", "squash", "--into-parent", "--yes"])
+        .assert_success();
+    repo.run_stax(&["undo", "--yes"]).assert_success();
+
+    for (branch, sha) in shas {
+        assert_eq!(repo.get_commit_sha(&branch), sha, "{branch} restored");
+    }
+}
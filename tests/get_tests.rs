@@ -8,7 +8,7 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 use tempfile::TempDir;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn push_remote_only_branch_from(
@@ -254,6 +254,57 @@ fn get_remote_upstack_includes_remote_only_child_prs() {
     assert_eq!(metadata_for(&repo, "remote-child")["prInfo"]["number"], 124);
 }
 
+#[test]
+fn pr_checkout_stack_rebuilds_pr_base_chain() {
+    let repo = TestRepo::new_with_remote();
+    repo.configure_github_like_submit_remote();
+    let a_sha = push_remote_only_branch(&repo, "chain-a", "a.txt", "a\n");
+    let b_sha = push_remote_only_branch_from(&repo, "chain-b", "origin/chain-a", "b.txt", "b\n");
+    let c_sha = push_remote_only_branch_from(&repo, "chain-c", "origin/chain-b", "c.txt", "c\n");
+    let home = repo.clean_home();
+
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    let mock_server = runtime.block_on(async {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test-owner/test-repo/pulls/303"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(mock_pr_json(303, "chain-c", "chain-b")),
+            )
+            .mount(&mock_server)
+            .await;
+        for (number, head, base) in [(302, "chain-b", "chain-a"), (301, "chain-a", "main")] {
+            Mock::given(method("GET"))
+                .and(path("/repos/test-owner/test-repo/pulls"))
+                .and(query_param("head", format!("test-owner:{}", head)))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(serde_json::json!([mock_pr_json(number, head, base)])),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+        mock_server
+    });
+    write_test_config(&home, &mock_server.uri());
+
+    let out = repo.run_stax_with_env(
+        &["pr", "checkout", "303", "--stack"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    out.assert_success();
+
+    assert_eq!(repo.current_branch(), "chain-c");
+    assert_eq!(repo.get_commit_sha("chain-a"), a_sha);
+    assert_eq!(repo.get_commit_sha("chain-b"), b_sha);
+    assert_eq!(repo.get_commit_sha("chain-c"), c_sha);
+    assert_eq!(parent_for(&repo, "chain-a").as_deref(), Some("main"));
+    assert_eq!(parent_for(&repo, "chain-b").as_deref(), Some("chain-a"));
+    assert_eq!(parent_for(&repo, "chain-c").as_deref(), Some("chain-b"));
+    assert_eq!(metadata_for(&repo, "chain-a")["prInfo"]["number"], 301);
+    assert_eq!(metadata_for(&repo, "chain-b")["prInfo"]["number"], 302);
+}
+
 #[test]
 fn get_can_track_without_checkout_under_explicit_parent() {
     let repo = TestRepo::new_with_remote();
@@ -667,3 +718,101 @@ fn sync_delete_upstream_gone_deletes_imported_branch_locally() {
     repo.git(&["show-ref", "--verify", "refs/branch-metadata/borrowed-gone"])
         .assert_failure();
 }
+
+fn mount_pr(number: u64, head: &str, base: &str) -> MockServer {
+    let runtime = tokio::runtime::Runtime::new().expect("runtime");
+    runtime.block_on(async {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/repos/test-owner/test-repo/pulls/{}",
+                number
+            )))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(mock_pr_json(number, head, base)),
+            )
+            .mount(&mock_server)
+            .await;
+        mock_server
+    })
+}
+
+#[test]
+fn pr_checkout_refuses_existing_local_branch_with_extra_commits_unless_forced() {
+    let repo = TestRepo::new_with_remote();
+    repo.configure_github_like_submit_remote();
+    let remote_sha = push_remote_only_branch(&repo, "pr-local-work", "pr.txt", "pr head\n");
+    repo.git(&["checkout", "-b", "pr-local-work", &remote_sha])
+        .assert_success();
+    repo.create_file("local.txt", "unpushed work\n");
+    repo.commit("Unpushed local work");
+    let local_sha = repo.head_sha();
+    repo.git(&["checkout", "main"]).assert_success();
+    let home = repo.clean_home();
+    let server = mount_pr(404, "pr-local-work", "main");
+    write_test_config(&home, &server.uri());
+    let env = [("STAX_GITHUB_TOKEN", "mock-token")];
+
+    let out = repo.run_stax_with_env(&["pr", "checkout", "404"], &env);
+    out.assert_failure()
+        .assert_stderr_contains("1 commit(s) that are not on origin/pr-local-work")
+        .assert_stderr_contains("stax pr checkout 404 --force");
+    assert_eq!(repo.current_branch(), "main");
+    assert_eq!(repo.get_commit_sha("pr-local-work"), local_sha);
+
+    repo.run_stax_with_env(&["pr", "checkout", "404", "--force"], &env)
+        .assert_success();
+    assert_eq!(repo.current_branch(), "pr-local-work");
+    assert_eq!(repo.get_commit_sha("pr-local-work"), remote_sha);
+}
+
+#[test]
+fn pr_checkout_fast_forwards_behind_local_branch_unless_no_ff() {
+    let repo = TestRepo::new_with_remote();
+    repo.configure_github_like_submit_remote();
+    let old_sha = push_remote_only_branch(&repo, "pr-behind", "pr.txt", "pr head\n");
+    repo.git(&["branch", "pr-behind", &old_sha])
+        .assert_success();
+    let new_sha = update_remote_branch_in_clone(&repo, "pr-behind", "pr-2.txt", "more\n");
+    let home = repo.clean_home();
+    let server = mount_pr(405, "pr-behind", "main");
+    write_test_config(&home, &server.uri());
+    let env = [("STAX_GITHUB_TOKEN", "mock-token")];
+
+    repo.run_stax_with_env(&["pr", "checkout", "405", "--no-ff"], &env)
+        .assert_failure()
+        .assert_stderr_contains("refuses to fast-forward");
+    assert_eq!(repo.get_commit_sha("pr-behind"), old_sha);
+
+    repo.run_stax_with_env(&["pr", "checkout", "405"], &env)
+        .assert_success();
+    assert_eq!(repo.current_branch(), "pr-behind");
+    assert_eq!(repo.get_commit_sha("pr-behind"), new_sha);
+}
+
+#[test]
+fn bc_base_pr_stacks_new_branch_on_fetched_pr_head() {
+    let repo = TestRepo::new_with_remote();
+    repo.configure_github_like_submit_remote();
+    let pr_sha = push_remote_only_branch(&repo, "teammate-work", "mate.txt", "teammate\n");
+    let home = repo.clean_home();
+    let server = mount_pr(501, "teammate-work", "main");
+    write_test_config(&home, &server.uri());
+
+    repo.run_stax_with_env(
+        &["bc", "my-follow-up", "--base-pr", "501"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    )
+    .assert_success()
+    .assert_stdout_contains("Stacking on PR #501");
+
+    assert!(repo.current_branch_contains("my-follow-up"));
+    assert_eq!(repo.head_sha(), pr_sha);
+    assert_eq!(repo.get_current_parent().as_deref(), Some("teammate-work"));
+    assert_eq!(repo.get_commit_sha("teammate-work"), pr_sha);
+    assert_eq!(parent_for(&repo, "teammate-work").as_deref(), Some("main"));
+    assert_eq!(
+        metadata_for(&repo, "teammate-work")["prInfo"]["number"],
+        501
+    );
+}
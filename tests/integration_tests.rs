@@ -3318,6 +3318,50 @@ fn test_sync_pulls_trunk_updates() {
     );
 }
 
+#[test]
+fn test_sync_since_skips_recent_sync_unless_forced() {
+    let repo = TestRepo::new_with_remote();
+
+    let first = repo.run_stax(&["sync", "--since", "1h"]);
+    assert!(
+        first.status.success(),
+        "Failed: {}",
+        TestRepo::stderr(&first)
+    );
+    assert!(TestRepo::stdout(&first).contains("Sync complete!"));
+
+    repo.simulate_remote_commit("remote-file.txt", "from remote", "Remote commit");
+
+    let second = repo.run_stax(&["sync", "--since", "1h"]);
+    assert!(
+        second.status.success(),
+        "Failed: {}",
+        TestRepo::stderr(&second)
+    );
+    let stdout = TestRepo::stdout(&second);
+    assert!(
+        stdout.contains("Sync skipped"),
+        "Expected recent sync to be skipped, got: {}",
+        stdout
+    );
+    assert!(
+        !repo.path().join("remote-file.txt").exists(),
+        "Skipped sync must not fetch remote changes"
+    );
+
+    let third = repo.run_stax(&["sync", "--since", "1h", "--force"]);
+    assert!(
+        third.status.success(),
+        "Failed: {}",
+        TestRepo::stderr(&third)
+    );
+    assert!(TestRepo::stdout(&third).contains("Sync complete!"));
+    assert!(
+        repo.path().join("remote-file.txt").exists(),
+        "Expected --force to fetch and pull remote changes"
+    );
+}
+
 #[test]
 fn test_sync_with_feature_branch() {
     let repo = TestRepo::new_with_remote();
@@ -5251,6 +5295,56 @@ fn test_undo_with_dirty_working_tree() {
     assert!(!output.status.success() || TestRepo::stderr(&output).contains("dirty"));
 }
 
+#[test]
+fn test_undo_autostash_restores_dirty_working_tree() {
+    let repo = TestRepo::new();
+
+    repo.run_stax(&["bc", "feature-autostash"]);
+    let feature_branch = repo.current_branch();
+    repo.create_file("feature.txt", "feature");
+    repo.commit("Feature commit");
+    let sha_before = repo.head_sha();
+
+    repo.run_stax(&["t"]);
+    repo.create_file("main.txt", "main");
+    repo.commit("Main update");
+
+    repo.run_stax(&["checkout", &feature_branch]);
+    let output = repo.run_stax(&["restack", "--quiet"]);
+    assert!(
+        output.status.success(),
+        "Restack failed: {}",
+        TestRepo::stderr(&output)
+    );
+    assert_ne!(repo.head_sha(), sha_before);
+
+    repo.create_file("feature.txt", "edited but not committed");
+    repo.create_file("scratch.txt", "untracked notes");
+
+    let output = repo.run_stax(&["undo", "--autostash", "--yes"]);
+    assert!(
+        output.status.success(),
+        "Undo failed: {}",
+        TestRepo::stderr(&output)
+    );
+    assert!(TestRepo::stdout(&output).contains("Restored stashed changes"));
+
+    assert_eq!(repo.head_sha(), sha_before);
+    assert_eq!(
+        std::fs::read_to_string(repo.path().join("feature.txt")).unwrap(),
+        "edited but not committed"
+    );
+    assert_eq!(
+        std::fs::read_to_string(repo.path().join("scratch.txt")).unwrap(),
+        "untracked notes"
+    );
+    let stashes = repo.git(&["stash", "list"]);
+    assert!(
+        TestRepo::stdout(&stashes).trim().is_empty(),
+        "autostash should be popped"
+    );
+}
+
 // =============================================================================
 // Sync Merged Branch Detection Tests
 // =============================================================================
@@ -7814,6 +7908,86 @@ mod forge_mock_tests {
         mount_github_merge_status_with_head(mock_server, number, state, decision, &head_sha).await;
     }
 
+    async fn mount_github_merge_queue_status(
+        mock_server: &MockServer,
+        number: u64,
+        merged: bool,
+        in_queue: bool,
+        priority: u8,
+        times: Option<u64>,
+    ) {
+        let mock = Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains(format!(
+                "pullRequest(number: {})",
+                number
+            )))
+            .and(body_string_contains("isInMergeQueue"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "repository": {
+                        "pullRequest": {
+                            "merged": merged,
+                            "isInMergeQueue": in_queue,
+                            "mergeQueueEntry": if in_queue {
+                                serde_json::json!({ "position": 1 })
+                            } else {
+                                serde_json::Value::Null
+                            }
+                        }
+                    }
+                }
+            })))
+            .with_priority(priority);
+        match times {
+            Some(times) => mock.up_to_n_times(times).mount(mock_server).await,
+            None => mock.mount(mock_server).await,
+        }
+    }
+
+    /// Node IDs and the enqueue/dequeue mutations used by `merge --queue`.
+    async fn mount_github_merge_queue_mutations(mock_server: &MockServer, numbers: &[u64]) {
+        for number in numbers {
+            Mock::given(method("POST"))
+                .and(path("/graphql"))
+                .and(body_string_contains(format!(
+                    "pullRequest(number: {})",
+                    number
+                )))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "data": { "repository": { "pullRequest": { "id": format!("PR_{}", number) } } }
+                })))
+                .with_priority(10)
+                .mount(mock_server)
+                .await;
+        }
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("enqueuePullRequest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "enqueuePullRequest": { "mergeQueueEntry": { "position": 1 } } }
+            })))
+            .mount(mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("dequeuePullRequest"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "dequeuePullRequest": { "mergeQueueEntry": { "position": 2 } } }
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    fn graphql_requests_containing(requests: &[wiremock::Request], needle: &str) -> Vec<String> {
+        requests
+            .iter()
+            .filter(|request| request.url.path() == "/graphql")
+            .map(|request| String::from_utf8_lossy(&request.body).to_string())
+            .filter(|body| body.contains(needle))
+            .collect()
+    }
+
     async fn mount_github_merge_status_with_head(
         mock_server: &MockServer,
         number: u64,
@@ -8020,6 +8194,23 @@ mod forge_mock_tests {
         command.output().expect("Failed to execute stax")
     }
 
+    fn run_stax_with_editor(repo: &TestRepo, home: &Path, editor: &Path, args: &[&str]) -> Output {
+        let gitconfig = ensure_empty_gitconfig(home);
+        let mut command = Command::new(stax_bin());
+        command
+            .args(args)
+            .current_dir(repo.path())
+            .env("HOME", home)
+            .env("GIT_CONFIG_GLOBAL", &gitconfig)
+            .env("GIT_CONFIG_SYSTEM", &gitconfig)
+            .env("STAX_GITHUB_TOKEN", "mock-token")
+            .env("EDITOR", editor)
+            .env_remove("VISUAL")
+            .env("STAX_DISABLE_UPDATE_CHECK", "1")
+            .env("STAX_TEST_DISABLE_HEAD_SYNC", "1");
+        command.output().expect("Failed to execute stax")
+    }
+
     fn run_stax_with_token_env_and_path(
         repo: &TestRepo,
         home: &Path,
@@ -8982,6 +9173,145 @@ mod forge_mock_tests {
         assert_eq!(payload["body"], default_body);
     }
 
+    #[tokio::test]
+    async fn test_submit_pr_template_places_stack_links_in_template_slot() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+        let home = super::test_tempdir();
+        write_test_config_with_submit(home.path(), &mock_server.uri(), Some("body"));
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+        repo.create_file(
+            ".github/PULL_REQUEST_TEMPLATE/feature.md",
+            "## What\n\n{{COMMITS}}\n\n{{STACK}}\n\n## Checklist\n- [ ] Tests\n",
+        );
+        repo.commit("Add feature PR template");
+
+        let output = run_stax_with_env(&repo, home.path(), &["bc", "feature-templated"]);
+        assert!(
+            output.status.success(),
+            "Failed to create branch: {}",
+            TestRepo::stderr(&output)
+        );
+        let branch = repo.current_branch();
+        repo.create_file("templated.txt", "templated\n");
+        repo.commit("Add templated change");
+        let created_body = "## What\n\n- Add templated change\n\n\
+            <!-- stax-stack-links:start -->\n<!-- stax-stack-links:end -->\n\n\
+            ## Checklist\n- [ ] Tests\n";
+
+        mount_github_new_pr_flow(
+            &mock_server,
+            45,
+            &branch,
+            "Add templated change",
+            created_body,
+        )
+        .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/test/repo/pulls/45"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                github_pull_fixture_with_details(45, &branch, "main", "Add templated change", ""),
+            ))
+            .mount(&mock_server)
+            .await;
+
+        let output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &["submit", "--yes", "--no-prompt", "--pr-template", "feature"],
+        );
+        assert!(
+            output.status.success(),
+            "submit --pr-template failed\nstdout: {}\nstderr: {}",
+            TestRepo::stdout(&output),
+            TestRepo::stderr(&output)
+        );
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let pr_create = requests
+            .iter()
+            .find(|request| {
+                request.method.as_str() == "POST" && request.url.path() == "/repos/test/repo/pulls"
+            })
+            .expect("missing PR create request");
+        let payload: serde_json::Value = serde_json::from_slice(&pr_create.body).unwrap();
+        assert_eq!(payload["body"], created_body);
+
+        let patch = find_body_patch(&requests, "/repos/test/repo/pulls/45");
+        let payload: serde_json::Value = serde_json::from_slice(&patch.body).unwrap();
+        let body = payload["body"].as_str().unwrap();
+        assert!(
+            body.starts_with(
+                "## What\n\n- Add templated change\n\n<!-- stax-stack-links:start -->\n"
+            ),
+            "unexpected body: {}",
+            body
+        );
+        assert!(body.contains("**PR #45** 👈"), "unexpected body: {}", body);
+        assert!(
+            body.ends_with("<!-- stax-stack-links:end -->\n\n## Checklist\n- [ ] Tests\n"),
+            "unexpected body: {}",
+            body
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submit_edit_config_opens_editor_for_new_pr_details() {
+        use std::os::unix::fs::PermissionsExt;
+
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+        let home = super::test_tempdir();
+        write_test_config_with_submit(home.path(), &mock_server.uri(), Some("off"));
+        let config_path = home.path().join(".config/stax/config.toml");
+        let config = fs::read_to_string(&config_path).unwrap();
+        fs::write(&config_path, format!("{}edit = true\n", config)).unwrap();
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+
+        let output = run_stax_with_env(&repo, home.path(), &["bc", "feature-edited"]);
+        assert!(
+            output.status.success(),
+            "Failed to create branch: {}",
+            TestRepo::stderr(&output)
+        );
+        let branch = repo.current_branch();
+        repo.create_file("edited.txt", "edited\n");
+        repo.commit("Add edited change");
+
+        mount_github_new_pr_flow(&mock_server, 46, &branch, "Edited title", "Edited body").await;
+
+        let editor = home.path().join("editor.sh");
+        fs::write(
+            &editor,
+            "#!/bin/sh\nprintf 'Edited title\\n\\nEdited body\\n' > \"$1\"\n",
+        )
+        .unwrap();
+        let mut permissions = fs::metadata(&editor).unwrap().permissions();
+        permissions.set_mode(0o755);
+        fs::set_permissions(&editor, permissions).unwrap();
+
+        let output = run_stax_with_editor(&repo, home.path(), &editor, &["submit", "--yes"]);
+        assert!(
+            output.status.success(),
+            "submit with submit.edit failed\nstdout: {}\nstderr: {}",
+            TestRepo::stdout(&output),
+            TestRepo::stderr(&output)
+        );
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let pr_create = requests
+            .iter()
+            .find(|request| {
+                request.method.as_str() == "POST" && request.url.path() == "/repos/test/repo/pulls"
+            })
+            .expect("missing PR create request");
+        let payload: serde_json::Value = serde_json::from_slice(&pr_create.body).unwrap();
+        assert_eq!(payload["title"], "Edited title");
+        assert_eq!(payload["body"], "Edited body");
+    }
+
     #[tokio::test]
     async fn test_submit_plain_ai_yes_skips_existing_pr_content_updates() {
         ensure_crypto_provider();
@@ -10524,37 +10854,307 @@ mod forge_mock_tests {
     }
 
     #[tokio::test]
-    async fn test_merge_resets_diverged_trunk_after_squash_merge() {
+    async fn test_create_from_issue_maps_labels_to_branch_prefix() {
         ensure_crypto_provider();
         let mock_server = MockServer::start().await;
+        let home = super::test_tempdir();
+        let config_dir = home.path().join(".config").join("stax");
+        std::fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+        fs::write(
+            config_dir.join("config.toml"),
+            format!(
+                "[remote]\napi_base_url = \"{}\"\n\n[branch]\nprefix = \"cesar/\"\n\n\
+                 [branch.label_prefixes]\nbug = \"fix/\"\nenhancement = \"feat/\"\n",
+                mock_server.uri()
+            ),
+        )
+        .expect("Failed to write config");
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
 
-        // Both stack PRs resolve during merge scope validation.
-        Mock::given(method("GET"))
-            .and(path("/repos/test/repo/pulls"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
-                {
-                    "url": "https://api.github.com/repos/test/repo/pulls/201",
-                    "id": 201,
-                    "number": 201,
-                    "state": "open",
-                    "draft": false,
-                    "head": { "ref": "trunk-diverged-a", "sha": "sha-a", "label": "test:trunk-diverged-a" },
-                    "base": { "ref": "main", "sha": "main-sha" }
-                },
-                {
-                    "url": "https://api.github.com/repos/test/repo/pulls/202",
-                    "id": 202,
-                    "number": 202,
-                    "state": "open",
-                    "draft": false,
-                    "head": { "ref": "trunk-diverged-b", "sha": "sha-b", "label": "test:trunk-diverged-b" },
-                    "base": { "ref": "trunk-diverged-a", "sha": "sha-a" }
-                }
-            ])))
-            .mount(&mock_server)
-            .await;
+        for (number, title, label) in [
+            (7, "Login crashes on submit", "bug"),
+            (8, "Dark mode toggle", "question"),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(format!("/repos/test/repo/issues/{}", number)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "number": number,
+                    "title": title,
+                    "html_url": format!("https://github.com/test/repo/issues/{}", number),
+                    "user": { "login": "octocat" },
+                    "labels": [{ "name": "triage" }, { "name": label }],
+                    "updated_at": "2026-01-01T00:00:00Z"
+                })))
+                .mount(&mock_server)
+                .await;
+        }
 
-        // Both PRs are already merged on the remote.
+        let output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &["bc", "--from-issue", "7", "--prefix-from-labels"],
+        );
+        assert!(
+            output.status.success(),
+            "bc --from-issue failed\nstdout: {}\nstderr: {}",
+            TestRepo::stdout(&output),
+            TestRepo::stderr(&output)
+        );
+        assert_eq!(repo.current_branch(), "fix/7-Login-crashes-on-submit");
+
+        run_stax_with_env(&repo, home.path(), &["trunk"]);
+        let output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &["bc", "--from-issue", "8", "--prefix-from-labels"],
+        );
+        assert!(
+            output.status.success(),
+            "bc --from-issue failed\nstdout: {}\nstderr: {}",
+            TestRepo::stdout(&output),
+            TestRepo::stderr(&output)
+        );
+        assert_eq!(repo.current_branch(), "cesar/8-Dark-mode-toggle");
+    }
+
+    #[tokio::test]
+    async fn test_status_approvals_rolls_up_review_decisions() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+        let home = super::test_tempdir();
+        write_test_config(home.path(), &mock_server.uri());
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+        let mut parent = "main".to_string();
+        for (name, number) in [
+            ("approvals-a", 21),
+            ("approvals-b", 22),
+            ("approvals-c", 23),
+        ] {
+            let output = run_stax_with_env(&repo, home.path(), &["bc", name]);
+            assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+            let branch = repo.current_branch();
+            repo.create_file(&format!("{}.txt", name), name);
+            repo.commit(name);
+            write_branch_pr_metadata(&repo, &branch, &parent, number, Some(false));
+            parent = branch;
+        }
+        mount_github_review_status(&mock_server, 21, "APPROVED").await;
+        mount_github_review_status(&mock_server, 22, "REVIEW_REQUIRED").await;
+        // PR 23 has no mock, so its lookup fails and must count as unknown.
+
+        let output = run_stax_with_env(&repo, home.path(), &["status", "--approvals", "--json"]);
+        assert!(
+            output.status.success(),
+            "status --approvals failed: {}",
+            TestRepo::stderr(&output)
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&TestRepo::stdout(&output)).expect("status JSON");
+        assert_eq!(json["approved_prs"], 1);
+        assert_eq!(json["total_prs"], 3);
+
+        let output = run_stax_with_env(&repo, home.path(), &["status", "--approvals"]);
+        assert!(
+            output.status.success(),
+            "status --approvals failed: {}",
+            TestRepo::stderr(&output)
+        );
+        assert!(
+            TestRepo::stdout(&output).contains("Approvals: 1/3 PRs approved (1 unknown)"),
+            "{}",
+            TestRepo::stdout(&output)
+        );
+
+        let output = run_stax_with_env(&repo, home.path(), &["status", "--json"]);
+        let json: serde_json::Value =
+            serde_json::from_str(&TestRepo::stdout(&output)).expect("status JSON");
+        assert!(json.get("approved_prs").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_status_approvals_batches_review_decisions_in_one_query() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+        let home = super::test_tempdir();
+        write_test_config(home.path(), &mock_server.uri());
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+        let mut parent = "main".to_string();
+        for (name, number) in [("batched-a", 31), ("batched-b", 32)] {
+            let output = run_stax_with_env(&repo, home.path(), &["bc", name]);
+            assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+            let branch = repo.current_branch();
+            repo.create_file(&format!("{}.txt", name), name);
+            repo.commit(name);
+            write_branch_pr_metadata(&repo, &branch, &parent, number, Some(false));
+            parent = branch;
+        }
+        let status = |number: u64, decision: &str| {
+            serde_json::json!({
+                "number": number,
+                "title": format!("PR #{}", number),
+                "state": "OPEN",
+                "updatedAt": "2026-06-02T10:00:00Z",
+                "isDraft": false,
+                "mergeable": "MERGEABLE",
+                "reviewDecision": decision,
+                "headRefOid": format!("sha-{}", number),
+                "statusCheckRollup": { "state": "SUCCESS" },
+                "reviews": { "nodes": [] }
+            })
+        };
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("pullRequest(number: 31)"))
+            .and(body_string_contains("pr1: pullRequest(number:"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "repository": {
+                        "pr0": status(31, "APPROVED"),
+                        "pr1": status(32, "APPROVED")
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let output = run_stax_with_env(&repo, home.path(), &["status", "--approvals", "--json"]);
+        assert!(
+            output.status.success(),
+            "status --approvals failed: {}",
+            TestRepo::stderr(&output)
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&TestRepo::stdout(&output)).expect("status JSON");
+        assert_eq!(json["approved_prs"], 2);
+        assert_eq!(json["total_prs"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_merge_update_trunk_fast_forwards_local_trunk_without_sync() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "url": "https://api.github.com/repos/test/repo/pulls/301",
+                    "id": 301,
+                    "number": 301,
+                    "state": "open",
+                    "draft": false,
+                    "head": { "ref": "ff-trunk-a", "sha": "sha-a", "label": "test:ff-trunk-a" },
+                    "base": { "ref": "main", "sha": "main-sha" }
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        // GitHub already squash-merged the PR; merge only has to catch local state up.
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls/301"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "url": "https://api.github.com/repos/test/repo/pulls/301",
+                "id": 301,
+                "number": 301,
+                "state": "closed",
+                "draft": false,
+                "merged_at": "2024-01-01T00:00:00Z",
+                "mergeable": true,
+                "mergeable_state": "clean",
+                "head": { "ref": "ff-trunk-a", "sha": "sha-a", "label": "test:ff-trunk-a" },
+                "base": { "ref": "main", "sha": "main-sha" }
+            })))
+            .mount(&mock_server)
+            .await;
+        mount_github_merge_status(&mock_server, 301, "CLOSED", "APPROVED").await;
+
+        let home = super::test_tempdir();
+        let repo = TestRepo::new();
+        let remote_root = setup_fake_github_remote(&repo, home.path());
+        write_test_config(home.path(), &mock_server.uri());
+
+        let output = run_stax_with_env(&repo, home.path(), &["bc", "ff-trunk-a"]);
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let branch = repo.current_branch();
+        repo.create_file("feature.txt", "feature\n");
+        repo.commit("Feature");
+        let push = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch]);
+        assert!(push.status.success(), "{}", TestRepo::stderr(&push));
+
+        let trunk_before = repo.get_commit_sha("main");
+        squash_merge_branch_on_fake_remote(&remote_root, &branch);
+
+        let merge_output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &[
+                "merge",
+                "--yes",
+                "--no-wait",
+                "--no-delete",
+                "--no-sync",
+                "--update-trunk",
+            ],
+        );
+        assert!(
+            merge_output.status.success(),
+            "Merge failed: {}\n{}",
+            TestRepo::stderr(&merge_output),
+            TestRepo::stdout(&merge_output)
+        );
+        assert!(
+            TestRepo::stdout(&merge_output).contains("fast-forwarded"),
+            "{}",
+            TestRepo::stdout(&merge_output)
+        );
+
+        let trunk_after = repo.get_commit_sha("main");
+        assert_ne!(trunk_after, trunk_before, "local trunk should advance");
+        assert_eq!(trunk_after, repo.get_commit_sha("origin/main"));
+        let log = git_with_env(&repo, home.path(), &["log", "--format=%s", "-1", "main"]);
+        assert_eq!(
+            TestRepo::stdout(&log).trim(),
+            format!("Squash merge {}", branch)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merge_resets_diverged_trunk_after_squash_merge() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+
+        // Both stack PRs resolve during merge scope validation.
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "url": "https://api.github.com/repos/test/repo/pulls/201",
+                    "id": 201,
+                    "number": 201,
+                    "state": "open",
+                    "draft": false,
+                    "head": { "ref": "trunk-diverged-a", "sha": "sha-a", "label": "test:trunk-diverged-a" },
+                    "base": { "ref": "main", "sha": "main-sha" }
+                },
+                {
+                    "url": "https://api.github.com/repos/test/repo/pulls/202",
+                    "id": 202,
+                    "number": 202,
+                    "state": "open",
+                    "draft": false,
+                    "head": { "ref": "trunk-diverged-b", "sha": "sha-b", "label": "test:trunk-diverged-b" },
+                    "base": { "ref": "trunk-diverged-a", "sha": "sha-a" }
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        // Both PRs are already merged on the remote.
         Mock::given(method("GET"))
             .and(path("/repos/test/repo/pulls/201"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
@@ -10811,154 +11411,615 @@ mod forge_mock_tests {
         );
     }
 
-    #[tokio::test]
-    async fn test_merge_retargets_next_pr_after_merging_parent_pr() {
-        ensure_crypto_provider();
-        let mock_server = MockServer::start().await;
+    #[tokio::test]
+    async fn test_merge_retargets_next_pr_after_merging_parent_pr() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "url": "https://api.github.com/repos/test/repo/pulls/101",
+                    "id": 101,
+                    "number": 101,
+                    "state": "open",
+                    "draft": false,
+                    "head": { "ref": "merge-a", "sha": "sha-a", "label": "test:merge-a" },
+                    "base": { "ref": "main", "sha": "main-sha" }
+                },
+                {
+                    "url": "https://api.github.com/repos/test/repo/pulls/102",
+                    "id": 102,
+                    "number": 102,
+                    "state": "open",
+                    "draft": false,
+                    "head": { "ref": "merge-b", "sha": "sha-b", "label": "test:merge-b" },
+                    "base": { "ref": "merge-a", "sha": "sha-a" }
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls/101"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "url": "https://api.github.com/repos/test/repo/pulls/101",
+                "id": 101,
+                "number": 101,
+                "state": "open",
+                "draft": false,
+                "merged_at": null,
+                "mergeable": true,
+                "mergeable_state": "clean",
+                "head": { "ref": "merge-a", "sha": "sha-a", "label": "test:merge-a" },
+                "base": { "ref": "main", "sha": "main-sha" }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls/102"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "url": "https://api.github.com/repos/test/repo/pulls/102",
+                "id": 102,
+                "number": 102,
+                "state": "open",
+                "draft": false,
+                "merged_at": null,
+                "mergeable": true,
+                "mergeable_state": "clean",
+                "head": { "ref": "merge-b", "sha": "sha-b", "label": "test:merge-b" },
+                "base": { "ref": "merge-a", "sha": "sha-a" }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/repos/test/repo/pulls/102"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "url": "https://api.github.com/repos/test/repo/pulls/102",
+                "id": 102,
+                "number": 102,
+                "state": "open",
+                "draft": false,
+                "head": { "ref": "merge-b", "sha": "sha-b", "label": "test:merge-b" },
+                "base": { "ref": "main", "sha": "main-sha" }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/repos/test/repo/pulls/101/merge"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sha": "merge-a-commit",
+                "merged": true,
+                "message": "Pull Request successfully merged"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("PUT"))
+            .and(path("/repos/test/repo/pulls/102/merge"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sha": "merge-b-commit",
+                "merged": true,
+                "message": "Pull Request successfully merged"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let home = super::test_tempdir();
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+        write_test_config(home.path(), &mock_server.uri());
+
+        let output = run_stax_with_env(&repo, home.path(), &["bc", "merge-a"]);
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let branch_a = repo.current_branch();
+        repo.create_file("parent.txt", "parent\n");
+        repo.commit("Parent commit");
+        let push_a = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch_a]);
+        assert!(push_a.status.success(), "{}", TestRepo::stderr(&push_a));
+
+        let output = run_stax_with_env(&repo, home.path(), &["bc", "merge-b"]);
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let branch_b = repo.current_branch();
+        repo.create_file("child.txt", "child\n");
+        repo.commit("Child commit");
+        let push_b = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch_b]);
+        assert!(push_b.status.success(), "{}", TestRepo::stderr(&push_b));
+
+        mount_github_review_status(&mock_server, 101, "APPROVED").await;
+        mount_github_review_status(&mock_server, 102, "APPROVED").await;
+
+        let merge_output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &["merge", "--yes", "--no-wait", "--no-delete", "--no-sync"],
+        );
+        assert!(
+            merge_output.status.success(),
+            "Merge failed: {}\n{}",
+            TestRepo::stderr(&merge_output),
+            TestRepo::stdout(&merge_output)
+        );
+
+        let requests = mock_server
+            .received_requests()
+            .await
+            .expect("request recording enabled");
+        let patch_idx = find_request_index(&requests, "PATCH", "/repos/test/repo/pulls/102");
+        let merge_idx = find_request_index(&requests, "PUT", "/repos/test/repo/pulls/101/merge");
+        assert!(
+            patch_idx > merge_idx,
+            "Expected dependent PR retarget after parent merge, requests were: {:?}",
+            requests
+                .iter()
+                .map(|request| format!("{} {}", request.method, request.url.path()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_merge_rebases_again_when_next_pr_conflicts_after_trunk_moves() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                github_pull_fixture(101, "merge-a", "main", "sha-a"),
+                github_pull_fixture(102, "merge-b", "merge-a", "sha-b"),
+            ])))
+            .mount(&mock_server)
+            .await;
+        for (number, head, base) in [(101, "merge-a", "main"), (102, "merge-b", "merge-a")] {
+            Mock::given(method("GET"))
+                .and(path(format!("/repos/test/repo/pulls/{}", number)))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(github_pull_fixture(
+                        number,
+                        head,
+                        base,
+                        &format!("sha-{}", number),
+                    )),
+                )
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("PATCH"))
+            .and(path("/repos/test/repo/pulls/102"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(github_pull_fixture(102, "merge-b", "main", "sha-b")),
+            )
+            .mount(&mock_server)
+            .await;
+        for number in [101, 102] {
+            Mock::given(method("PUT"))
+                .and(path(format!("/repos/test/repo/pulls/{}/merge", number)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "sha": format!("merge-{}-commit", number),
+                    "merged": true,
+                    "message": "Pull Request successfully merged"
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+
+        // Trunk moved after #101 merged: #102 conflicts until it is rebased again
+        // (the plan's batched status query, the readiness check and its recheck).
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("pullRequest(number: 102)"))
+            .and(body_string_contains("reviewDecision"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "repository": {
+                        "pullRequest": {
+                            "number": 102,
+                            "title": "PR #102",
+                            "state": "OPEN",
+                            "updatedAt": "2026-06-02T10:00:00Z",
+                            "isDraft": false,
+                            "mergeable": "CONFLICTING",
+                            "reviewDecision": "APPROVED",
+                            "headRefOid": "sha-102",
+                            "statusCheckRollup": { "state": "SUCCESS" },
+                            "reviews": { "nodes": [{ "state": "APPROVED" }] }
+                        }
+                    }
+                }
+            })))
+            .up_to_n_times(3)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+        mount_github_review_status(&mock_server, 101, "APPROVED").await;
+        mount_github_review_status(&mock_server, 102, "APPROVED").await;
+
+        let home = super::test_tempdir();
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+        write_test_config(home.path(), &mock_server.uri());
+
+        for (name, file) in [("merge-a", "parent.txt"), ("merge-b", "child.txt")] {
+            let output = run_stax_with_env(&repo, home.path(), &["bc", name]);
+            assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+            let branch = repo.current_branch();
+            repo.create_file(file, "content\n");
+            repo.commit(&format!("Add {}", file));
+            let push = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch]);
+            assert!(push.status.success(), "{}", TestRepo::stderr(&push));
+        }
+
+        let merge_output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &["merge", "--yes", "--no-wait", "--no-delete", "--no-sync"],
+        );
+        let stdout = TestRepo::stdout(&merge_output);
+        assert!(
+            merge_output.status.success(),
+            "Merge failed: {}\n{}",
+            TestRepo::stderr(&merge_output),
+            stdout
+        );
+        assert!(
+            stdout.contains("#102 conflicts with the updated main, rebasing again (1/3)"),
+            "{}",
+            stdout
+        );
+        assert!(stdout.contains("2 PRs merged into main"), "{}", stdout);
+
+        let requests = mock_server
+            .received_requests()
+            .await
+            .expect("request recording enabled");
+        find_request_index(&requests, "PUT", "/repos/test/repo/pulls/102/merge");
+    }
+
+    #[tokio::test]
+    async fn test_merge_follows_repository_merge_method_settings() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "name": "repo",
+                "full_name": "test/repo",
+                "allow_squash_merge": false,
+                "allow_merge_commit": false,
+                "allow_rebase_merge": true
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                github_pull_fixture(101, "merge-a", "main", "sha-101"),
+            ])))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls/101"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(github_pull_fixture(101, "merge-a", "main", "sha-101")),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/repos/test/repo/pulls/101/merge"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sha": "merge-a-commit",
+                "merged": true,
+                "message": "Pull Request successfully merged"
+            })))
+            .mount(&mock_server)
+            .await;
+        mount_github_review_status(&mock_server, 101, "APPROVED").await;
+
+        let home = super::test_tempdir();
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+        write_test_config(home.path(), &mock_server.uri());
+
+        let output = run_stax_with_env(&repo, home.path(), &["bc", "merge-a"]);
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let branch = repo.current_branch();
+        repo.create_file("feature.txt", "feature\n");
+        repo.commit("Feature commit");
+        let push = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch]);
+        assert!(push.status.success(), "{}", TestRepo::stderr(&push));
+
+        let rejected = run_stax_with_env(
+            &repo,
+            home.path(),
+            &[
+                "merge",
+                "--yes",
+                "--no-wait",
+                "--no-sync",
+                "--method",
+                "squash",
+            ],
+        );
+        assert!(!rejected.status.success());
+        let stderr = TestRepo::stderr(&rejected);
+        assert!(
+            stderr.contains("This repository does not allow squash merges (allowed: rebase)"),
+            "{}",
+            stderr
+        );
+        let requests = mock_server
+            .received_requests()
+            .await
+            .expect("request recording enabled");
+        assert!(
+            !requests
+                .iter()
+                .any(|request| request.method.as_str() == "PUT"),
+            "a disallowed method must not reach the merge API"
+        );
+
+        let merged = run_stax_with_env(
+            &repo,
+            home.path(),
+            &["merge", "--yes", "--no-wait", "--no-delete", "--no-sync"],
+        );
+        assert!(
+            merged.status.success(),
+            "Merge failed: {}\n{}",
+            TestRepo::stderr(&merged),
+            TestRepo::stdout(&merged)
+        );
+        let requests = mock_server
+            .received_requests()
+            .await
+            .expect("request recording enabled");
+        let merge_request =
+            &requests[find_request_index(&requests, "PUT", "/repos/test/repo/pulls/101/merge")];
+        let body: serde_json::Value =
+            serde_json::from_slice(&merge_request.body).expect("merge request body");
+        assert_eq!(body["merge_method"], "rebase");
+    }
+
+    #[tokio::test]
+    async fn test_merge_blocks_on_review_count_required_by_branch_protection() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/branches/main/protection"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "required_status_checks": { "strict": false, "contexts": ["test"] },
+                "required_pull_request_reviews": { "required_approving_review_count": 2 }
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                github_pull_fixture(101, "merge-a", "main", "sha-101"),
+            ])))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls/101"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(github_pull_fixture(101, "merge-a", "main", "sha-101")),
+            )
+            .mount(&mock_server)
+            .await;
+        // One approval and a passing `test` check, but protection wants two approvals.
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_string_contains("pullRequest(number: 101)"))
+            .and(body_string_contains("reviewDecision"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": {
+                    "repository": {
+                        "pullRequest": {
+                            "number": 101,
+                            "title": "PR #101",
+                            "state": "OPEN",
+                            "updatedAt": "2026-06-02T10:00:00Z",
+                            "isDraft": false,
+                            "mergeable": "MERGEABLE",
+                            "reviewDecision": "REVIEW_REQUIRED",
+                            "headRefOid": "sha-101",
+                            "baseRefName": "main",
+                            "statusCheckRollup": {
+                                "state": "SUCCESS",
+                                "contexts": { "nodes": [{
+                                    "__typename": "CheckRun",
+                                    "name": "test",
+                                    "status": "COMPLETED",
+                                    "conclusion": "SUCCESS"
+                                }] }
+                            },
+                            "reviews": { "nodes": [
+                                { "state": "APPROVED", "author": { "login": "reviewer" } }
+                            ] }
+                        }
+                    }
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let home = super::test_tempdir();
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+        write_test_config(home.path(), &mock_server.uri());
+
+        let output = run_stax_with_env(&repo, home.path(), &["bc", "merge-a"]);
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let branch = repo.current_branch();
+        repo.create_file("feature.txt", "feature\n");
+        repo.commit("Feature commit");
+        let push = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch]);
+        assert!(push.status.success(), "{}", TestRepo::stderr(&push));
+
+        let output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &["merge", "--yes", "--no-wait", "--no-sync"],
+        );
+        let stdout = TestRepo::stdout(&output);
+        assert!(
+            stdout.contains("Review approval"),
+            "{}\n{}",
+            stdout,
+            TestRepo::stderr(&output)
+        );
+
+        let requests = mock_server
+            .received_requests()
+            .await
+            .expect("request recording enabled");
+        assert!(
+            !requests
+                .iter()
+                .any(|request| request.method.as_str() == "PUT"),
+            "merge must not be attempted before protection is satisfied"
+        );
+        let protection_reads = requests
+            .iter()
+            .filter(|request| request.url.path() == "/repos/test/repo/branches/main/protection")
+            .count();
+        assert_eq!(protection_reads, 1, "protection is read once per run");
+    }
+
+    #[tokio::test]
+    async fn test_merge_refuses_when_local_branch_differs_from_pr_head() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+
+        let home = super::test_tempdir();
+        let repo = TestRepo::new();
+        let _remote_root = setup_fake_github_remote(&repo, home.path());
+        write_test_config(home.path(), &mock_server.uri());
+
+        let output = run_stax_with_env(&repo, home.path(), &["bc", "merge-amended"]);
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let branch = repo.current_branch();
+        repo.create_file("feature.txt", "feature\n");
+        repo.commit("Feature commit");
+        let push = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch]);
+        assert!(push.status.success(), "{}", TestRepo::stderr(&push));
+        let pushed_sha = repo.get_commit_sha(&branch);
+
+        // Amend locally without pushing: the PR head still points at the old commit.
+        repo.create_file("feature.txt", "feature, amended\n");
+        repo.git(&["add", "-A"]);
+        let amend = repo.git(&["commit", "--amend", "-m", "Feature commit (amended)"]);
+        assert!(amend.status.success(), "{}", TestRepo::stderr(&amend));
 
         Mock::given(method("GET"))
             .and(path("/repos/test/repo/pulls"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
-                {
-                    "url": "https://api.github.com/repos/test/repo/pulls/101",
-                    "id": 101,
-                    "number": 101,
-                    "state": "open",
-                    "draft": false,
-                    "head": { "ref": "merge-a", "sha": "sha-a", "label": "test:merge-a" },
-                    "base": { "ref": "main", "sha": "main-sha" }
-                },
-                {
-                    "url": "https://api.github.com/repos/test/repo/pulls/102",
-                    "id": 102,
-                    "number": 102,
-                    "state": "open",
-                    "draft": false,
-                    "head": { "ref": "merge-b", "sha": "sha-b", "label": "test:merge-b" },
-                    "base": { "ref": "merge-a", "sha": "sha-a" }
-                }
+                github_pull_fixture(101, &branch, "main", &pushed_sha)
             ])))
             .mount(&mock_server)
             .await;
 
         Mock::given(method("GET"))
             .and(path("/repos/test/repo/pulls/101"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "url": "https://api.github.com/repos/test/repo/pulls/101",
-                "id": 101,
-                "number": 101,
-                "state": "open",
-                "draft": false,
-                "merged_at": null,
-                "mergeable": true,
-                "mergeable_state": "clean",
-                "head": { "ref": "merge-a", "sha": "sha-a", "label": "test:merge-a" },
-                "base": { "ref": "main", "sha": "main-sha" }
-            })))
-            .mount(&mock_server)
-            .await;
-
-        Mock::given(method("GET"))
-            .and(path("/repos/test/repo/pulls/102"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "url": "https://api.github.com/repos/test/repo/pulls/102",
-                "id": 102,
-                "number": 102,
-                "state": "open",
-                "draft": false,
-                "merged_at": null,
-                "mergeable": true,
-                "mergeable_state": "clean",
-                "head": { "ref": "merge-b", "sha": "sha-b", "label": "test:merge-b" },
-                "base": { "ref": "merge-a", "sha": "sha-a" }
-            })))
-            .mount(&mock_server)
-            .await;
-
-        Mock::given(method("PATCH"))
-            .and(path("/repos/test/repo/pulls/102"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "url": "https://api.github.com/repos/test/repo/pulls/102",
-                "id": 102,
-                "number": 102,
-                "state": "open",
-                "draft": false,
-                "head": { "ref": "merge-b", "sha": "sha-b", "label": "test:merge-b" },
-                "base": { "ref": "main", "sha": "main-sha" }
-            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(github_pull_fixture(
+                    101,
+                    &branch,
+                    "main",
+                    &pushed_sha,
+                )),
+            )
             .mount(&mock_server)
             .await;
 
         Mock::given(method("PUT"))
             .and(path("/repos/test/repo/pulls/101/merge"))
             .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "sha": "merge-a-commit",
+                "sha": "merge-commit",
                 "merged": true,
                 "message": "Pull Request successfully merged"
             })))
             .mount(&mock_server)
             .await;
 
-        Mock::given(method("PUT"))
-            .and(path("/repos/test/repo/pulls/102/merge"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-                "sha": "merge-b-commit",
-                "merged": true,
-                "message": "Pull Request successfully merged"
-            })))
-            .mount(&mock_server)
+        mount_github_merge_status_with_head(&mock_server, 101, "OPEN", "APPROVED", &pushed_sha)
             .await;
 
-        let home = super::test_tempdir();
-        let repo = TestRepo::new();
-        let _remote_root = setup_fake_github_remote(&repo, home.path());
-        write_test_config(home.path(), &mock_server.uri());
-
-        let output = run_stax_with_env(&repo, home.path(), &["bc", "merge-a"]);
-        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
-        let branch_a = repo.current_branch();
-        repo.create_file("parent.txt", "parent\n");
-        repo.commit("Parent commit");
-        let push_a = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch_a]);
-        assert!(push_a.status.success(), "{}", TestRepo::stderr(&push_a));
-
-        let output = run_stax_with_env(&repo, home.path(), &["bc", "merge-b"]);
-        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
-        let branch_b = repo.current_branch();
-        repo.create_file("child.txt", "child\n");
-        repo.commit("Child commit");
-        let push_b = git_with_env(&repo, home.path(), &["push", "-u", "origin", &branch_b]);
-        assert!(push_b.status.success(), "{}", TestRepo::stderr(&push_b));
-
-        mount_github_review_status(&mock_server, 101, "APPROVED").await;
-        mount_github_review_status(&mock_server, 102, "APPROVED").await;
-
         let merge_output = run_stax_with_env(
             &repo,
             home.path(),
             &["merge", "--yes", "--no-wait", "--no-delete", "--no-sync"],
         );
+        let stdout = TestRepo::stdout(&merge_output);
+        let stderr = TestRepo::stderr(&merge_output);
         assert!(
-            merge_output.status.success(),
-            "Merge failed: {}\n{}",
-            TestRepo::stderr(&merge_output),
-            TestRepo::stdout(&merge_output)
+            !merge_output.status.success(),
+            "Expected merge to refuse, stdout:\n{}\nstderr:\n{}",
+            stdout,
+            stderr
+        );
+        assert!(
+            stdout.contains("local changes not pushed"),
+            "Expected mismatch warning, got:\n{}",
+            stdout
+        );
+        assert!(
+            stderr.contains("Local changes are not on the PR") && stderr.contains(&branch),
+            "Expected refusal naming the branch, got:\n{}",
+            stderr
         );
 
+        let merge_calls = |requests: &[wiremock::Request]| {
+            requests
+                .iter()
+                .filter(|request| {
+                    request.method.as_str() == "PUT"
+                        && request.url.path() == "/repos/test/repo/pulls/101/merge"
+                })
+                .count()
+        };
         let requests = mock_server
             .received_requests()
             .await
             .expect("request recording enabled");
-        let patch_idx = find_request_index(&requests, "PATCH", "/repos/test/repo/pulls/102");
-        let merge_idx = find_request_index(&requests, "PUT", "/repos/test/repo/pulls/101/merge");
+        assert_eq!(
+            merge_calls(&requests),
+            0,
+            "Refused merge must not hit the API"
+        );
+
+        let forced = run_stax_with_env(
+            &repo,
+            home.path(),
+            &[
+                "merge",
+                "--yes",
+                "--no-wait",
+                "--no-delete",
+                "--no-sync",
+                "--force",
+            ],
+        );
         assert!(
-            patch_idx > merge_idx,
-            "Expected dependent PR retarget after parent merge, requests were: {:?}",
-            requests
-                .iter()
-                .map(|request| format!("{} {}", request.method, request.url.path()))
-                .collect::<Vec<_>>()
+            forced.status.success(),
+            "Forced merge failed: {}\n{}",
+            TestRepo::stderr(&forced),
+            TestRepo::stdout(&forced)
         );
+        let requests = mock_server
+            .received_requests()
+            .await
+            .expect("request recording enabled");
+        assert_eq!(merge_calls(&requests), 1);
     }
 
     #[tokio::test]
@@ -12720,6 +13781,8 @@ mod forge_mock_tests {
 
         mount_github_merge_status(&mock_server, 401, "CLOSED", "APPROVED").await;
         mount_github_review_status(&mock_server, 402, "APPROVED").await;
+        mount_github_merge_queue_status(&mock_server, 401, true, false, 1, None).await;
+        mount_github_merge_queue_status(&mock_server, 402, false, false, 1, None).await;
 
         let queue_output = run_stax_with_env(
             &repo,
@@ -12766,6 +13829,163 @@ mod forge_mock_tests {
         assert_eq!(patch_count, 2, "Expected retarget and rollback PATCHes");
     }
 
+    /// Two-branch stack `queue-a (#501) -> queue-b (#502)` with both PRs open.
+    async fn setup_merge_queue_stack(
+        mock_server: &MockServer,
+        repo: &TestRepo,
+        home: &Path,
+    ) -> (TempDir, String, String) {
+        let remote_root = setup_fake_github_remote(repo, home);
+        write_test_config(home, &mock_server.uri());
+
+        let output = run_stax_with_env(repo, home, &["bc", "queue-a"]);
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let branch_a = repo.current_branch();
+        repo.create_file("a.txt", "a\n");
+        repo.commit("A commit");
+        let output = run_stax_with_env(repo, home, &["bc", "queue-b"]);
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let branch_b = repo.current_branch();
+        repo.create_file("b.txt", "b\n");
+        repo.commit("B commit");
+
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                github_pull_fixture(501, &branch_a, "main", "sha-a"),
+                github_pull_fixture(502, &branch_b, &branch_a, "sha-b")
+            ])))
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls/501"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(github_pull_fixture(501, &branch_a, "main", "sha-a")),
+            )
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls/502"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(github_pull_fixture(502, &branch_b, &branch_a, "sha-b")),
+            )
+            .with_priority(1)
+            .up_to_n_times(1)
+            .mount(mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/repos/test/repo/pulls/502"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(github_pull_fixture(502, &branch_b, "main", "sha-b")),
+            )
+            .with_priority(2)
+            .mount(mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/repos/test/repo/pulls/502"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(github_pull_fixture(502, &branch_b, "main", "sha-b")),
+            )
+            .mount(mock_server)
+            .await;
+        mount_github_merge_queue_mutations(mock_server, &[501, 502]).await;
+
+        (remote_root, branch_a, branch_b)
+    }
+
+    #[tokio::test]
+    async fn test_merge_queue_dequeues_stacked_prs_when_a_pr_leaves_the_queue() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+        let home = super::test_tempdir();
+        let repo = TestRepo::new();
+        let _remote = setup_merge_queue_stack(&mock_server, &repo, home.path()).await;
+
+        // #501 never shows up as queued: its queue checks failed.
+        mount_github_merge_queue_status(&mock_server, 501, false, false, 1, None).await;
+        mount_github_merge_queue_status(&mock_server, 502, false, false, 1, Some(1)).await;
+        mount_github_merge_queue_status(&mock_server, 502, false, true, 2, None).await;
+
+        let output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &["merge", "--queue", "--yes", "--interval", "1", "--no-sync"],
+        );
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let stdout = TestRepo::stdout(&output);
+        assert!(
+            stdout.contains("#501") && stdout.contains("left the merge queue without merging"),
+            "{}",
+            stdout
+        );
+        assert!(
+            stdout.contains("removed from the merge queue (stacked on #501)"),
+            "{}",
+            stdout
+        );
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let dequeues = graphql_requests_containing(&requests, "dequeuePullRequest");
+        assert_eq!(
+            dequeues.len(),
+            1,
+            "only the PR above the failed one is dequeued"
+        );
+        assert!(dequeues[0].contains("PR_502"));
+    }
+
+    #[tokio::test]
+    async fn test_merge_queue_requeue_puts_dropped_prs_back_in_order() {
+        ensure_crypto_provider();
+        let mock_server = MockServer::start().await;
+        let home = super::test_tempdir();
+        let repo = TestRepo::new();
+        let _remote = setup_merge_queue_stack(&mock_server, &repo, home.path()).await;
+
+        // #501 drops out once (flaky queue CI), then both merge after requeue.
+        mount_github_merge_queue_status(&mock_server, 501, false, false, 1, Some(3)).await;
+        mount_github_merge_queue_status(&mock_server, 501, true, false, 2, None).await;
+        mount_github_merge_queue_status(&mock_server, 502, false, false, 1, Some(1)).await;
+        mount_github_merge_queue_status(&mock_server, 502, false, true, 2, Some(2)).await;
+        mount_github_merge_queue_status(&mock_server, 502, false, false, 3, Some(1)).await;
+        mount_github_merge_queue_status(&mock_server, 502, true, false, 4, None).await;
+
+        let output = run_stax_with_env(
+            &repo,
+            home.path(),
+            &[
+                "merge",
+                "--queue",
+                "--requeue",
+                "1",
+                "--yes",
+                "--interval",
+                "1",
+                "--no-sync",
+            ],
+        );
+        assert!(output.status.success(), "{}", TestRepo::stderr(&output));
+        let stdout = TestRepo::stdout(&output);
+        assert!(stdout.contains("requeuing (1 of 1)"), "{}", stdout);
+        assert!(stdout.contains("Stack Merged"), "{}", stdout);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let enqueues = graphql_requests_containing(&requests, "enqueuePullRequest");
+        let order: Vec<bool> = enqueues
+            .iter()
+            .map(|body| body.contains("PR_501"))
+            .collect();
+        assert_eq!(order, vec![true, false, true, false], "bottom-up, twice");
+        assert_eq!(
+            graphql_requests_containing(&requests, "dequeuePullRequest").len(),
+            1
+        );
+    }
+
     #[tokio::test]
     async fn test_github_api_mock_responses() {
         ensure_crypto_provider();
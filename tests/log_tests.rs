@@ -0,0 +1,157 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+#[test]
+fn log_only_changed_hides_empty_branches_and_counts_them() {
+    let repo = TestRepo::new();
+    let base = repo.create_stack(&["changed-base"]).remove(0);
+    repo.run_stax(&["bc", "empty-middle"]).assert_success();
+    let empty = repo.current_branch();
+    repo.run_stax(&["bc", "changed-tip"]).assert_success();
+    let tip = repo.current_branch();
+    repo.create_file("tip.txt", "tip\n");
+    repo.commit("Tip change");
+    repo.run_stax(&["checkout", &base]).assert_success();
+
+    let full = repo.run_stax(&["log"]);
+    full.assert_success().assert_stdout_contains(&empty);
+
+    let output = repo.run_stax(&["log", "--only-changed"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    assert!(!stdout.contains(&empty), "unexpected {empty}: {stdout}");
+    assert!(stdout.contains(&tip), "expected {tip}: {stdout}");
+    assert!(stdout.contains(&base), "expected {base}: {stdout}");
+    assert!(
+        stdout.contains("(1 empty branch hidden)"),
+        "expected hidden summary: {stdout}"
+    );
+}
+
+#[test]
+fn log_include_trunk_commits_lists_recent_trunk_commits_above_the_stack() {
+    let repo = TestRepo::new();
+    for n in 1..=3 {
+        repo.create_file(&format!("trunk-{n}.txt"), "trunk\n");
+        repo.commit(&format!("Trunk change {n}"));
+    }
+    let branch = repo.create_stack(&["trunk-context"]).remove(0);
+
+    let output = repo.run_stax(&["log", "--include-trunk-commits", "2"]);
+    output
+        .assert_success()
+        .assert_stdout_contains("main · last 2 commits")
+        .assert_stdout_not_contains("Trunk change 1");
+    let stdout = TestRepo::stdout(&output);
+    let newest = stdout.find("Trunk change 3").expect("newest trunk commit");
+    let older = stdout.find("Trunk change 2").expect("second trunk commit");
+    let branch_line = stdout.find(branch.as_str()).expect("branch line");
+    assert!(
+        newest < older && older < branch_line,
+        "trunk commits should come first, newest first: {stdout}"
+    );
+
+    let json = repo.run_stax(&["log", "--json", "--include-trunk-commits", "2"]);
+    json.assert_success();
+    let log: serde_json::Value =
+        serde_json::from_str(&TestRepo::stdout(&json)).expect("log JSON should parse");
+    let messages: Vec<&str> = log["trunk_commits"]
+        .as_array()
+        .expect("trunk_commits array")
+        .iter()
+        .filter_map(|commit| commit["message"].as_str())
+        .collect();
+    assert_eq!(messages, vec!["Trunk change 3", "Trunk change 2"]);
+
+    let plain = repo.run_stax(&["log", "--json"]);
+    plain.assert_success();
+    let log: serde_json::Value =
+        serde_json::from_str(&TestRepo::stdout(&plain)).expect("log JSON should parse");
+    assert!(log.get("trunk_commits").is_none());
+}
+
+fn set_pr_number(repo: &TestRepo, branch: &str, parent: &str, number: u64) {
+    let metadata = serde_json::json!({
+        "parentBranchName": parent,
+        "parentBranchRevision": repo.get_commit_sha(parent),
+        "prInfo": { "number": number, "state": "OPEN" }
+    });
+    let file = tempfile::NamedTempFile::new().expect("metadata file");
+    std::fs::write(file.path(), metadata.to_string()).expect("metadata contents");
+    let hash = repo.git(&["hash-object", "-w", file.path().to_str().unwrap()]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{branch}"),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+#[test]
+fn log_checks_lists_cached_check_names_beneath_the_branch() {
+    let repo = TestRepo::new();
+    let branches = repo.create_stack(&["checks-base", "checks-tip"]);
+    let (base, tip) = (&branches[0], &branches[1]);
+    set_pr_number(&repo, base, "main", 21);
+    let cache = serde_json::json!({
+        "branches": {
+            base.as_str(): {
+                "ci_revision": repo.get_commit_sha(base),
+                "ci_state": "failure",
+                "pr_state": "OPEN",
+                "updated_at": 0,
+                "checks": [
+                    { "name": "unit-tests", "status": "completed", "conclusion": "success" },
+                    { "name": "lint-rules", "status": "completed", "conclusion": "failure" }
+                ]
+            }
+        },
+        "last_refresh": 0
+    });
+    std::fs::create_dir_all(repo.path().join(".git/stax")).expect("cache dir");
+    std::fs::write(
+        repo.path().join(".git/stax/ci-cache.json"),
+        cache.to_string(),
+    )
+    .expect("seed CI cache");
+
+    let plain = repo.run_stax(&["log"]);
+    plain
+        .assert_success()
+        .assert_stdout_not_contains("unit-tests");
+
+    let output = repo.run_stax(&["log", "--checks"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    let base_line = stdout.find(&format!("{base} ")).expect("base branch line");
+    let unit = stdout.find("unit-tests").expect("unit-tests check");
+    let lint = stdout.find("lint-rules").expect("lint-rules check");
+    let tip_line = stdout.find(tip.as_str()).expect("tip branch line");
+    assert!(
+        tip_line < base_line && base_line < unit && base_line < lint,
+        "checks should render beneath their branch: {stdout}"
+    );
+    assert!(stdout.contains("passed"), "expected passed label: {stdout}");
+    assert!(stdout.contains("failed"), "expected failed label: {stdout}");
+}
+
+#[test]
+fn log_offline_marks_cached_state_as_stale() {
+    let repo = TestRepo::new();
+    repo.create_stack(&["offline-log"]);
+
+    repo.run_stax(&["log"])
+        .assert_success()
+        .assert_stdout_not_contains("Offline:");
+    repo.run_stax(&["log", "--offline"])
+        .assert_success()
+        .assert_stdout_contains("Offline: no cached PR/CI state yet");
+
+    let output = repo.run_stax(&["log", "--offline", "--json"]);
+    output.assert_success();
+    let log: serde_json::Value =
+        serde_json::from_str(&TestRepo::stdout(&output)).expect("log JSON");
+    assert!(log.get("stale_as_of").is_none(), "log: {log}");
+}
@@ -0,0 +1,211 @@
+//! `stax merge --admin` integration tests.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn ensure_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{}\"\n", api_base_url),
+    )
+    .expect("Failed to write config");
+}
+
+fn write_branch_pr_metadata(repo: &TestRepo, branch: &str, parent: &str, pr_number: u64) {
+    let metadata = serde_json::json!({
+        "parentBranchName": parent,
+        "parentBranchRevision": repo.get_commit_sha(parent),
+        "prInfo": {
+            "number": pr_number,
+            "state": "OPEN"
+        }
+    });
+
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{}", branch),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+/// A PR blocked by branch protection: checks pending, review required.
+fn blocked_status(number: u64) -> serde_json::Value {
+    serde_json::json!({
+        "number": number,
+        "title": "Test PR",
+        "state": "OPEN",
+        "updatedAt": "2026-06-02T10:00:00Z",
+        "isDraft": false,
+        "mergeable": "MERGEABLE",
+        "reviewDecision": "REVIEW_REQUIRED",
+        "headRefOid": "aaaa",
+        "statusCheckRollup": { "state": "PENDING" },
+        "reviews": { "nodes": [] }
+    })
+}
+
+fn pr_fixture(number: u64, branch: &str) -> serde_json::Value {
+    serde_json::json!({
+        "url": format!("https://api.github.com/repos/test/repo/pulls/{}", number),
+        "id": number,
+        "number": number,
+        "state": "open",
+        "draft": false,
+        "title": "Test PR",
+        "body": "",
+        "html_url": format!("https://github.com/test/repo/pull/{}", number),
+        "head": { "ref": branch, "sha": "aaaa", "label": format!("test:{}", branch) },
+        "base": { "ref": "main", "sha": "bbbb" }
+    })
+}
+
+/// Single branch `admin-merge (#42)` with its PR reads mocked; the caller
+/// mounts the permission answer.
+async fn setup_admin_merge(mock_server: &MockServer, permission: &str) -> TestRepo {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+    let branch = repo.create_stack(&["admin-merge"])[0].clone();
+    write_branch_pr_metadata(&repo, &branch, "main", 42);
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("viewerPermission"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "repository": { "viewerPermission": permission } }
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("mergePullRequest"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "mergePullRequest": { "pullRequest": { "number": 42 } } }
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("statusCheckRollup"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "repository": { "pr0": blocked_status(42) } }
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "repository": { "pullRequest": { "id": "PR_node42" } } }
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/pulls/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pr_fixture(42, &branch)))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/repos/test/repo/pulls/42/merge"))
+        .respond_with(ResponseTemplate::new(405))
+        .expect(0)
+        .mount(mock_server)
+        .await;
+
+    repo
+}
+
+async fn graphql_bodies(mock_server: &MockServer) -> Vec<String> {
+    mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|request| request.url.path() == "/graphql")
+        .map(|request| String::from_utf8_lossy(&request.body).into_owned())
+        .collect()
+}
+
+#[tokio::test]
+async fn merge_admin_uses_merge_pull_request_override() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let repo = setup_admin_merge(&mock_server, "ADMIN").await;
+
+    let output = repo.run_stax_with_env(
+        &["merge", "--admin", "--yes", "--no-sync", "--no-delete"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output.assert_success();
+
+    let bodies = graphql_bodies(&mock_server).await;
+    let merge = bodies
+        .iter()
+        .find(|body| body.contains("mergePullRequest"))
+        .expect("--admin should merge through mergePullRequest");
+    assert!(merge.contains("PR_node42"), "{merge}");
+    assert!(merge.contains("mergeMethod: SQUASH"), "{merge}");
+}
+
+#[tokio::test]
+async fn merge_admin_fails_without_admin_rights() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let repo = setup_admin_merge(&mock_server, "WRITE").await;
+
+    let output = repo.run_stax_with_env(
+        &["merge", "--admin", "--yes", "--no-sync", "--no-delete"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output
+        .assert_failure()
+        .assert_stderr_contains("needs admin rights on test/repo")
+        .assert_stderr_contains("WRITE");
+
+    let bodies = graphql_bodies(&mock_server).await;
+    assert!(
+        !bodies.iter().any(|body| body.contains("mergePullRequest")),
+        "nothing should be merged without admin rights"
+    );
+}
+
+#[tokio::test]
+async fn merge_admin_requires_confirmation_without_yes() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let repo = setup_admin_merge(&mock_server, "ADMIN").await;
+
+    let output = repo.run_stax_with_env(
+        &["merge", "--admin", "--no-sync", "--no-delete"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output
+        .assert_failure()
+        .assert_stderr_contains("must be confirmed");
+}
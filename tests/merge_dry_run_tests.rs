@@ -0,0 +1,295 @@
+//! `stax merge --dry-run` integration tests.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn ensure_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{}\"\n", api_base_url),
+    )
+    .expect("Failed to write config");
+}
+
+fn write_branch_pr_metadata(repo: &TestRepo, branch: &str, parent: &str, pr_number: u64) {
+    let metadata = serde_json::json!({
+        "parentBranchName": parent,
+        "parentBranchRevision": repo.get_commit_sha(parent),
+        "prInfo": {
+            "number": pr_number,
+            "state": "OPEN"
+        }
+    });
+
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{}", branch),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+fn merge_status_fixture(number: u64) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "repository": {
+                "pullRequest": {
+                    "number": number,
+                    "title": "Test PR",
+                    "state": "OPEN",
+                    "updatedAt": "2026-06-02T10:00:00Z",
+                    "isDraft": false,
+                    "mergeable": "MERGEABLE",
+                    "reviewDecision": "APPROVED",
+                    "headRefOid": "aaaa",
+                    "statusCheckRollup": { "state": "SUCCESS" },
+                    "reviews": { "nodes": [] }
+                }
+            }
+        }
+    })
+}
+
+/// Stack `first (#42) -> second (#43)` where only `second` edits `shared.txt`,
+/// which `origin/main` has since changed in an incompatible way.
+fn setup_conflicting_stack(api_base_url: &str) -> (TestRepo, String, String) {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), api_base_url);
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+    repo.create_file("shared.txt", "base\n");
+    repo.commit("Add shared file");
+
+    repo.run_stax(&["bc", "dry-first"]).assert_success();
+    let first = repo.current_branch();
+    repo.create_file("first.txt", "first\n");
+    repo.commit("First change");
+
+    repo.run_stax(&["bc", "dry-second"]).assert_success();
+    let second = repo.current_branch();
+    repo.create_file("shared.txt", "second\n");
+    repo.commit("Second edits shared file");
+
+    write_branch_pr_metadata(&repo, &first, "main", 42);
+    write_branch_pr_metadata(&repo, &second, &first, 43);
+
+    // Move the remote trunk past the stack with a conflicting edit.
+    repo.run_stax(&["checkout", "main"]).assert_success();
+    repo.create_file("shared.txt", "trunk\n");
+    repo.commit("Trunk edits shared file");
+    let trunk_tip = repo.get_commit_sha("main");
+    repo.git(&["update-ref", "refs/remotes/origin/main", &trunk_tip])
+        .assert_success();
+    repo.git(&["reset", "--hard", "HEAD~1"]).assert_success();
+    repo.run_stax(&["checkout", &second]).assert_success();
+
+    (repo, first, second)
+}
+
+#[tokio::test]
+async fn merge_dry_run_flags_steps_that_would_conflict() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, first, second) = setup_conflicting_stack(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(merge_status_fixture(42)))
+        .mount(&mock_server)
+        .await;
+
+    let second_before = repo.get_commit_sha(&second);
+    let output = repo.run_stax_with_env(
+        &["merge", "--dry-run"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+
+    assert!(stdout.contains("Likely conflicts"), "stdout: {stdout}");
+    let conflict_line = stdout
+        .lines()
+        .find(|line| line.contains('✗'))
+        .expect("a conflicting step should be listed");
+    assert!(conflict_line.contains(&second), "line: {conflict_line}");
+    assert!(
+        conflict_line.contains("origin/main"),
+        "line: {conflict_line}"
+    );
+    assert!(
+        conflict_line.contains("shared.txt"),
+        "line: {conflict_line}"
+    );
+    assert!(
+        !stdout
+            .lines()
+            .any(|line| line.contains('✗') && line.contains(&format!(" {first} "))),
+        "{first} does not touch shared.txt: {stdout}"
+    );
+
+    // Read-only: nothing was rebased or checked out.
+    assert_eq!(repo.current_branch(), second);
+    assert_eq!(repo.get_commit_sha(&second), second_before);
+    assert!(
+        !repo.path().join(".git").join("rebase-merge").exists(),
+        "dry-run must not start a rebase"
+    );
+}
+
+#[tokio::test]
+async fn merge_dry_run_reports_clean_stack() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, _first, second) = setup_conflicting_stack(&mock_server.uri());
+    repo.git(&["update-ref", "refs/remotes/origin/main", "main"])
+        .assert_success();
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(merge_status_fixture(42)))
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &["merge", "--dry-run"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output
+        .assert_success()
+        .assert_stdout_contains("No rebase conflicts predicted");
+    assert_eq!(repo.current_branch(), second);
+}
+
+#[tokio::test]
+async fn merge_fetches_stack_statuses_in_one_graphql_request() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, _first, second) = setup_conflicting_stack(&mock_server.uri());
+    repo.run_stax(&["bc", "dry-third"]).assert_success();
+    let third = repo.current_branch();
+    repo.create_file("third.txt", "third\n");
+    repo.commit("Third change");
+    write_branch_pr_metadata(&repo, &third, &second, 44);
+
+    let status =
+        |number: u64| merge_status_fixture(number)["data"]["repository"]["pullRequest"].clone();
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {
+                "repository": {
+                    "pr0": status(42),
+                    "pr1": status(43),
+                    "pr2": status(44)
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &["merge", "--dry-run"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output
+        .assert_success()
+        .assert_stdout_contains("3 PRs to merge");
+
+    let graphql_requests: Vec<_> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|request| request.url.path() == "/graphql")
+        .collect();
+    assert_eq!(graphql_requests.len(), 1, "statuses should be batched");
+    let body: serde_json::Value = serde_json::from_slice(&graphql_requests[0].body).unwrap();
+    let query = body["query"].as_str().unwrap();
+    for number in [42, 43, 44] {
+        assert!(
+            query.contains(&format!("pullRequest(number: {number})")),
+            "query should include #{number}: {query}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn merge_dry_run_json_describes_plan_with_readiness() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, first, second) = setup_conflicting_stack(&mock_server.uri());
+    repo.run_stax(&["checkout", &first]).assert_success();
+
+    let status =
+        |number: u64| merge_status_fixture(number)["data"]["repository"]["pullRequest"].clone();
+    let mut pending = status(43);
+    pending["reviewDecision"] = serde_json::json!("REVIEW_REQUIRED");
+    pending["statusCheckRollup"] = serde_json::json!({ "state": "PENDING" });
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {
+                "repository": {
+                    "pr0": status(42),
+                    "pr1": pending
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &["merge", "--dry-run", "--json"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output.assert_success();
+    let plan: serde_json::Value = serde_json::from_str(&TestRepo::stdout(&output))
+        .unwrap_or_else(|e| panic!("stdout should be JSON ({e}): {}", TestRepo::stdout(&output)));
+
+    assert_eq!(plan["trunk"], "main");
+    assert_eq!(plan["method"], "squash");
+    let to_merge = plan["to_merge"].as_array().expect("to_merge array");
+    assert_eq!(to_merge.len(), 1);
+    assert_eq!(to_merge[0]["branch"], first.as_str());
+    assert_eq!(to_merge[0]["pr_number"], 42);
+    assert_eq!(to_merge[0]["is_current"], true);
+    let ready = &to_merge[0]["readiness"];
+    assert_eq!(ready["ready"], true);
+    assert_eq!(ready["ci"], "success");
+    assert_eq!(ready["review_decision"], "APPROVED");
+    assert_eq!(ready["mergeable"], true);
+
+    let remaining = plan["remaining"].as_array().expect("remaining array");
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0]["branch"], second.as_str());
+    assert_eq!(remaining[0]["pr_number"], 43);
+    let waiting = &remaining[0]["readiness"];
+    assert_eq!(waiting["ready"], false);
+    assert_eq!(waiting["ci"], "pending");
+    assert_eq!(waiting["review_decision"], "REVIEW_REQUIRED");
+}
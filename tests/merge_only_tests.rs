@@ -0,0 +1,280 @@
+//! `stax merge --only <branch>` and `stax merge --until <branch>` integration tests.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const PULLS: &str = "/repos/test-owner/test-repo/pulls";
+
+fn ensure_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{}\"\n", api_base_url),
+    )
+    .expect("Failed to write config");
+}
+
+fn write_branch_pr_metadata(
+    repo: &TestRepo,
+    branch: &str,
+    parent: &str,
+    pr_number: u64,
+    state: &str,
+) {
+    let metadata = serde_json::json!({
+        "parentBranchName": parent,
+        "parentBranchRevision": repo.get_commit_sha(parent),
+        "prInfo": { "number": pr_number, "state": state }
+    });
+
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{}", branch),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+fn pr_fixture(number: u64, head: &str, head_sha: &str, base: &str) -> serde_json::Value {
+    serde_json::json!({
+        "url": format!("https://api.github.com{PULLS}/{number}"),
+        "id": number,
+        "number": number,
+        "state": "open",
+        "draft": false,
+        "merged_at": null,
+        "title": format!("PR #{number}"),
+        "body": "",
+        "html_url": format!("https://github.com/test-owner/test-repo/pull/{number}"),
+        "head": { "ref": head, "sha": head_sha, "label": format!("test-owner:{head}") },
+        "base": { "ref": base, "sha": "base-sha" }
+    })
+}
+
+async fn mount_ready_status(mock_server: &MockServer, number: u64, head_sha: &str) {
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains(format!(
+            "pullRequest(number: {number})"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "repository": { "pullRequest": {
+                "number": number,
+                "title": format!("PR #{number}"),
+                "state": "OPEN",
+                "updatedAt": "2026-06-02T10:00:00Z",
+                "isDraft": false,
+                "mergeable": "MERGEABLE",
+                "reviewDecision": "APPROVED",
+                "headRefOid": head_sha,
+                "statusCheckRollup": { "state": "SUCCESS" },
+                "reviews": { "nodes": [{ "state": "APPROVED" }] }
+            } } }
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_pr(mock_server: &MockServer, number: u64, head: &str, head_sha: &str, base: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("{PULLS}/{number}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pr_fixture(number, head, head_sha, base)),
+        )
+        .mount(mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("{PULLS}/{number}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pr_fixture(number, head, head_sha, "main")),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+/// `main → a (#51) → b (#52) → c (#53)`, all pushed; the caller decides
+/// whether `a` is recorded as merged.
+fn setup_stack(mock_server: &MockServer, a_state: &str) -> (TestRepo, Vec<String>) {
+    let repo = TestRepo::new_with_remote();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.configure_github_like_submit_remote();
+
+    let branches = repo.create_stack(&["only-a", "only-b", "only-c"]);
+    for branch in &branches {
+        repo.git(&["push", "-q", "origin", branch]).assert_success();
+    }
+    write_branch_pr_metadata(&repo, &branches[0], "main", 51, a_state);
+    write_branch_pr_metadata(&repo, &branches[1], &branches[0], 52, "OPEN");
+    write_branch_pr_metadata(&repo, &branches[2], &branches[1], 53, "OPEN");
+    (repo, branches)
+}
+
+async fn merge_requests(mock_server: &MockServer) -> Vec<String> {
+    mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|request| request.method.as_str() == "PUT")
+        .map(|request| request.url.path().to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn merge_only_merges_one_branch_and_rebases_its_child_onto_trunk() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, branches) = setup_stack(&mock_server, "MERGED");
+    // `a` already landed on trunk.
+    repo.git(&["push", "-q", "origin", &format!("{}:main", branches[0])])
+        .assert_success();
+
+    let b_sha = repo.get_commit_sha(&branches[1]);
+    let c_sha = repo.get_commit_sha(&branches[2]);
+    mount_ready_status(&mock_server, 52, &b_sha).await;
+    mount_ready_status(&mock_server, 53, &c_sha).await;
+    mount_pr(&mock_server, 52, &branches[1], &b_sha, &branches[0]).await;
+    mount_pr(&mock_server, 53, &branches[2], &c_sha, &branches[1]).await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{PULLS}/52/merge")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sha": "merge-b-commit",
+            "merged": true,
+            "message": "Pull Request successfully merged"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &[
+            "merge",
+            "--only",
+            &branches[1],
+            "--yes",
+            "--no-wait",
+            "--no-delete",
+            "--no-sync",
+        ],
+        &[
+            ("STAX_GITHUB_TOKEN", "mock-token"),
+            ("STAX_TEST_DISABLE_HEAD_SYNC", "1"),
+        ],
+    );
+    output.assert_success();
+
+    assert_eq!(
+        merge_requests(&mock_server).await,
+        vec![format!("{PULLS}/52/merge")],
+        "only the selected PR should be merged"
+    );
+
+    let child = &branches[2];
+    let json = repo.get_status_json();
+    let entry = json["branches"]
+        .as_array()
+        .expect("branches array")
+        .iter()
+        .find(|entry| entry["name"] == child.as_str())
+        .expect("child in status");
+    assert_eq!(entry["parent"], "main");
+    repo.git(&["merge-base", "--is-ancestor", "origin/main", child])
+        .assert_success();
+    repo.git(&["merge-base", "--is-ancestor", &b_sha, child])
+        .assert_failure();
+}
+
+#[tokio::test]
+async fn merge_only_refuses_when_an_ancestor_is_unmerged() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, branches) = setup_stack(&mock_server, "OPEN");
+    let a_sha = repo.get_commit_sha(&branches[0]);
+    mount_pr(&mock_server, 51, &branches[0], &a_sha, "main").await;
+
+    let output = repo.run_stax_with_env(
+        &["merge", "--only", &branches[1], "--yes", "--no-sync"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output
+        .assert_failure()
+        .assert_stderr_contains("not merged yet")
+        .assert_stderr_contains(&branches[0]);
+    assert!(merge_requests(&mock_server).await.is_empty());
+}
+
+#[tokio::test]
+async fn merge_until_merges_the_bottom_prs_without_checking_out_the_branch() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, branches) = setup_stack(&mock_server, "OPEN");
+    repo.git(&["checkout", "-q", "main"]).assert_success();
+
+    for (idx, number) in [51, 52, 53].into_iter().enumerate() {
+        let sha = repo.get_commit_sha(&branches[idx]);
+        let base = if idx == 0 { "main" } else { &branches[idx - 1] };
+        mount_ready_status(&mock_server, number, &sha).await;
+        mount_pr(&mock_server, number, &branches[idx], &sha, base).await;
+    }
+    for number in [51, 52] {
+        Mock::given(method("PUT"))
+            .and(path(format!("{PULLS}/{number}/merge")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "sha": format!("merge-{number}-commit"),
+                "merged": true,
+                "message": "Pull Request successfully merged"
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+
+    let output = repo.run_stax_with_env(
+        &[
+            "merge",
+            "--until",
+            &branches[1],
+            "--yes",
+            "--no-wait",
+            "--no-sync",
+        ],
+        &[
+            ("STAX_GITHUB_TOKEN", "mock-token"),
+            ("STAX_TEST_DISABLE_HEAD_SYNC", "1"),
+        ],
+    );
+    output.assert_success();
+
+    assert_eq!(
+        merge_requests(&mock_server).await,
+        vec![format!("{PULLS}/51/merge"), format!("{PULLS}/52/merge")],
+        "PRs up to the named branch merge bottom first"
+    );
+    assert_eq!(repo.current_branch(), "main");
+
+    let json = repo.get_status_json();
+    let top = json["branches"]
+        .as_array()
+        .expect("branches array")
+        .iter()
+        .find(|entry| entry["name"] == branches[2].as_str())
+        .expect("top branch in status");
+    assert_eq!(top["parent"], "main");
+}
@@ -5,7 +5,7 @@ use crate::common;
 use common::{OutputAssertions, TestRepo};
 use std::fs;
 use std::path::Path;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 fn ensure_crypto_provider() {
@@ -120,6 +120,42 @@ async fn pr_body_prints_current_pr_description() {
     assert!(stdout.contains("Details line"), "stdout was: {stdout}");
 }
 
+#[tokio::test]
+async fn pr_body_revalidates_cached_pr_with_etag() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, _home, branch) = setup_pr_body_repo(&mock_server.uri(), 44);
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/pulls/44"))
+        .and(header("if-none-match", "\"pr-44-v1\""))
+        .respond_with(ResponseTemplate::new(304))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/pulls/44"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"pr-44-v1\"")
+                .set_body_json(pr_fixture(44, &branch, "Cached body")),
+        )
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    for _ in 0..2 {
+        let output =
+            repo.run_stax_with_env(&["pr", "body"], &[("STAX_GITHUB_TOKEN", "mock-token")]);
+        output.assert_success();
+        output.assert_stdout_contains("Cached body");
+    }
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(!requests[0].headers.contains_key("if-none-match"));
+    assert_eq!(requests[1].headers["if-none-match"], "\"pr-44-v1\"");
+}
+
 #[cfg(unix)]
 #[tokio::test]
 async fn pr_body_edit_updates_current_pr_description() {
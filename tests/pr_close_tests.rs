@@ -0,0 +1,234 @@
+//! `stax pr close` / `stax pr reopen` integration tests.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+const TOKEN_ENV: &[(&str, &str)] = &[("STAX_GITHUB_TOKEN", "mock-token")];
+
+fn ensure_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{}\"\n", api_base_url),
+    )
+    .expect("Failed to write config");
+}
+
+fn write_branch_pr_metadata(repo: &TestRepo, branch: &str, pr_number: u64, state: &str) {
+    let metadata = serde_json::json!({
+        "parentBranchName": "main",
+        "parentBranchRevision": repo.get_commit_sha("main"),
+        "prInfo": {
+            "number": pr_number,
+            "state": state
+        }
+    });
+
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{}", branch),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+fn pr_fixture(number: u64, branch: &str, state: &str) -> Value {
+    serde_json::json!({
+        "url": format!("https://api.github.com/repos/test/repo/pulls/{}", number),
+        "id": number,
+        "number": number,
+        "state": state,
+        "draft": false,
+        "title": "Test PR",
+        "html_url": format!("https://github.com/test/repo/pull/{}", number),
+        "head": { "ref": branch, "sha": "aaaa", "label": format!("test:{}", branch) },
+        "base": { "ref": "main", "sha": "bbbb" }
+    })
+}
+
+fn comment_fixture(number: u64, body: &str) -> Value {
+    serde_json::json!({
+        "id": 9001,
+        "node_id": "IC_test_9001",
+        "url": "https://api.github.com/repos/test/repo/issues/comments/9001",
+        "html_url": format!("https://github.com/test/repo/pull/{}#issuecomment-9001", number),
+        "body": body,
+        "user": {
+            "login": "stax",
+            "id": 1,
+            "node_id": "MDQ6VXNlcjE=",
+            "avatar_url": "https://avatars.githubusercontent.com/u/1?v=4",
+            "gravatar_id": "",
+            "url": "https://api.github.com/users/stax",
+            "html_url": "https://github.com/stax",
+            "followers_url": "https://api.github.com/users/stax/followers",
+            "following_url": "https://api.github.com/users/stax/following{/other_user}",
+            "gists_url": "https://api.github.com/users/stax/gists{/gist_id}",
+            "starred_url": "https://api.github.com/users/stax/starred{/owner}{/repo}",
+            "subscriptions_url": "https://api.github.com/users/stax/subscriptions",
+            "organizations_url": "https://api.github.com/users/stax/orgs",
+            "repos_url": "https://api.github.com/users/stax/repos",
+            "events_url": "https://api.github.com/users/stax/events{/privacy}",
+            "received_events_url": "https://api.github.com/users/stax/received_events",
+            "type": "User",
+            "site_admin": false
+        },
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-01T00:00:00Z"
+    })
+}
+
+fn setup_repo(api_base_url: &str) -> (TestRepo, String) {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), api_base_url);
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+
+    repo.create_stack(&["feature-lifecycle"]);
+    let branch = repo.current_branch();
+    (repo, branch)
+}
+
+fn cached_pr_state(repo: &TestRepo, branch: &str) -> Option<String> {
+    let cache = fs::read_to_string(repo.path().join(".git/stax/ci-cache.json")).ok()?;
+    let cache: Value = serde_json::from_str(&cache).ok()?;
+    cache["branches"][branch]["pr_state"]
+        .as_str()
+        .map(str::to_string)
+}
+
+fn metadata_pr_state(repo: &TestRepo, branch: &str) -> String {
+    let output = repo.git(&["show", &format!("refs/branch-metadata/{}", branch)]);
+    output.assert_success();
+    let metadata: Value = serde_json::from_str(&TestRepo::stdout(&output)).expect("metadata JSON");
+    metadata["prInfo"]["state"]
+        .as_str()
+        .expect("prInfo.state")
+        .to_string()
+}
+
+fn request_json(requests: &[Request], verb: &str, url_path: &str) -> Vec<Value> {
+    requests
+        .iter()
+        .filter(|request| request.method.as_str() == verb && request.url.path() == url_path)
+        .map(|request| serde_json::from_slice(&request.body).expect("JSON request body"))
+        .collect()
+}
+
+#[tokio::test]
+async fn pr_close_comments_then_closes_and_caches_state() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, branch) = setup_repo(&mock_server.uri());
+    write_branch_pr_metadata(&repo, &branch, 42, "OPEN");
+
+    Mock::given(method("POST"))
+        .and(path("/repos/test/repo/issues/42/comments"))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(comment_fixture(42, "Superseded by #50")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test/repo/pulls/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pr_fixture(42, &branch, "closed")))
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &["pr", "close", "--comment", "Superseded by #50"],
+        TOKEN_ENV,
+    );
+    output.assert_success().assert_stdout_contains("closed");
+
+    let requests = mock_server.received_requests().await.expect("requests");
+    let comments = request_json(&requests, "POST", "/repos/test/repo/issues/42/comments");
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0]["body"], "Superseded by #50");
+    let patches = request_json(&requests, "PATCH", "/repos/test/repo/pulls/42");
+    assert_eq!(patches.len(), 1);
+    assert_eq!(patches[0]["state"], "closed");
+
+    assert_eq!(metadata_pr_state(&repo, &branch), "CLOSED");
+    assert_eq!(cached_pr_state(&repo, &branch).as_deref(), Some("CLOSED"));
+}
+
+#[tokio::test]
+async fn pr_reopen_sets_state_open_and_caches_state() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, branch) = setup_repo(&mock_server.uri());
+    write_branch_pr_metadata(&repo, &branch, 43, "CLOSED");
+
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test/repo/pulls/43"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pr_fixture(43, &branch, "open")))
+        .mount(&mock_server)
+        .await;
+
+    repo.run_stax_with_env(&["pr", "reopen"], TOKEN_ENV)
+        .assert_success()
+        .assert_stdout_contains("reopened");
+
+    let requests = mock_server.received_requests().await.expect("requests");
+    let patches = request_json(&requests, "PATCH", "/repos/test/repo/pulls/43");
+    assert_eq!(patches.len(), 1);
+    assert_eq!(patches[0]["state"], "open");
+    assert!(
+        request_json(&requests, "POST", "/repos/test/repo/issues/43/comments").is_empty(),
+        "reopen should not comment"
+    );
+
+    assert_eq!(metadata_pr_state(&repo, &branch), "OPEN");
+    assert_eq!(cached_pr_state(&repo, &branch).as_deref(), Some("OPEN"));
+}
+
+#[tokio::test]
+async fn pr_close_without_pr_fails() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, _branch) = setup_repo(&mock_server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/pulls"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&mock_server)
+        .await;
+
+    repo.run_stax_with_env(&["pr", "close"], TOKEN_ENV)
+        .assert_failure()
+        .assert_stderr_contains("No PR found for branch");
+
+    let requests = mock_server.received_requests().await.expect("requests");
+    assert!(
+        requests
+            .iter()
+            .all(|request| request.method.as_str() != "PATCH"),
+        "no PR should be updated"
+    );
+}
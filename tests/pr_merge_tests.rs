@@ -0,0 +1,190 @@
+//! `stax pr merge` integration tests.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn ensure_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{}\"\n", api_base_url),
+    )
+    .expect("Failed to write config");
+}
+
+fn write_branch_pr_metadata(repo: &TestRepo, branch: &str, parent: &str, pr_number: u64) {
+    let metadata = serde_json::json!({
+        "parentBranchName": parent,
+        "parentBranchRevision": repo.get_commit_sha(parent),
+        "prInfo": {
+            "number": pr_number,
+            "state": "OPEN"
+        }
+    });
+
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    let blob = TestRepo::stdout(&hash);
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{}", branch),
+        blob.trim(),
+    ])
+    .assert_success();
+}
+
+fn pr_fixture(number: u64, branch: &str, base: &str) -> serde_json::Value {
+    serde_json::json!({
+        "url": format!("https://api.github.com/repos/test/repo/pulls/{}", number),
+        "id": number,
+        "number": number,
+        "state": "open",
+        "draft": false,
+        "title": "Test PR",
+        "body": "",
+        "html_url": format!("https://github.com/test/repo/pull/{}", number),
+        "head": { "ref": branch, "sha": "aaaa", "label": format!("test:{}", branch) },
+        "base": { "ref": base, "sha": "bbbb" }
+    })
+}
+
+fn merge_status_fixture(number: u64) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "repository": {
+                "pullRequest": {
+                    "number": number,
+                    "title": "Test PR",
+                    "state": "OPEN",
+                    "updatedAt": "2026-06-02T10:00:00Z",
+                    "isDraft": false,
+                    "mergeable": "MERGEABLE",
+                    "reviewDecision": "APPROVED",
+                    "headRefOid": "aaaa",
+                    "statusCheckRollup": { "state": "SUCCESS" },
+                    "reviews": { "nodes": [] }
+                }
+            }
+        }
+    })
+}
+
+/// Two-branch stack `parent (#42) -> child (#43)`, checked out on the parent.
+fn setup_pr_merge_repo(api_base_url: &str) -> (TestRepo, String, String) {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), api_base_url);
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+
+    let branches = repo.create_stack(&["merge-parent", "merge-child"]);
+    let (parent, child) = (branches[0].clone(), branches[1].clone());
+    write_branch_pr_metadata(&repo, &parent, "main", 42);
+    write_branch_pr_metadata(&repo, &child, &parent, 43);
+    repo.run_stax(&["checkout", &parent]).assert_success();
+
+    (repo, parent, child)
+}
+
+#[tokio::test]
+async fn pr_merge_merges_current_pr_and_retargets_child() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, parent, child) = setup_pr_merge_repo(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(merge_status_fixture(42)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/repos/test/repo/pulls/42/merge"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "sha": "cccc",
+            "merged": true,
+            "message": "Pull Request successfully merged"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/pulls/43"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pr_fixture(43, &child, &parent)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test/repo/pulls/43"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pr_fixture(43, &child, "main")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &["pr", "merge", "--yes"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output.assert_success();
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let patch = requests
+        .iter()
+        .find(|request| {
+            request.method.as_str() == "PATCH" && request.url.path() == "/repos/test/repo/pulls/43"
+        })
+        .expect("child PR should be retargeted");
+    let body: serde_json::Value = serde_json::from_slice(&patch.body).unwrap();
+    assert_eq!(body["base"], "main");
+    assert!(
+        !requests
+            .iter()
+            .any(|request| request.url.path() == "/repos/test/repo/pulls/43/merge"),
+        "pr merge must only merge the current branch PR"
+    );
+}
+
+#[tokio::test]
+async fn pr_merge_fails_closed_when_status_lookup_fails() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let (repo, _parent, _child) = setup_pr_merge_repo(&mock_server.uri());
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(500))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/repos/test/repo/pulls/42/merge"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &["pr", "merge", "--yes"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output.assert_failure();
+    output.assert_stderr_contains("refusing to merge");
+}
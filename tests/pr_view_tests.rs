@@ -0,0 +1,137 @@
+//! `stax pr view` integration tests.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn ensure_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{}\"\n", api_base_url),
+    )
+    .expect("Failed to write config");
+}
+
+fn write_branch_pr_metadata(repo: &TestRepo, branch: &str, pr_number: u64) {
+    let metadata = serde_json::json!({
+        "parentBranchName": "main",
+        "parentBranchRevision": repo.get_commit_sha("main"),
+        "prInfo": { "number": pr_number, "state": "OPEN" }
+    });
+
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{}", branch),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+fn setup_pr_view_repo(api_base_url: &str) -> TestRepo {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), api_base_url);
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+    repo
+}
+
+async fn mount_pr_42(mock_server: &MockServer, head: &str) {
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/pulls/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "number": 42,
+            "title": "Split the lexer out of the parser",
+            "state": "open",
+            "draft": false,
+            "merged_at": null,
+            "body": "The lexer now lives in its own module.",
+            "html_url": "https://github.com/test/repo/pull/42",
+            "user": { "login": "octocat" },
+            "head": { "ref": head, "sha": "aaaa" },
+            "base": { "ref": "main", "sha": "bbbb" },
+            "labels": [{ "name": "refactor" }],
+            "requested_reviewers": [{ "login": "hubot" }],
+            "requested_teams": [{ "slug": "core" }]
+        })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test/repo/commits/aaaa/check-runs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total_count": 2,
+            "check_runs": [
+                { "name": "build", "status": "completed", "conclusion": "success" },
+                { "name": "lint", "status": "in_progress", "conclusion": null }
+            ]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn pr_view_renders_title_branches_and_body() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let repo = setup_pr_view_repo(&mock_server.uri());
+    let branch = repo.create_stack(&["lexer-split"])[0].clone();
+    write_branch_pr_metadata(&repo, &branch, 42);
+    mount_pr_42(&mock_server, &branch).await;
+
+    let output = repo.run_stax_with_env(&["pr", "view"], &[("STAX_GITHUB_TOKEN", "mock-token")]);
+    output
+        .assert_success()
+        .assert_stdout_contains("#42")
+        .assert_stdout_contains("Split the lexer out of the parser")
+        .assert_stdout_contains(&format!("main ← {branch}"))
+        .assert_stdout_contains("octocat")
+        .assert_stdout_contains("refactor")
+        .assert_stdout_contains("hubot, @test/core")
+        .assert_stdout_contains("1 passed, 0 failed, 1 pending")
+        .assert_stdout_contains("The lexer now lives in its own module.");
+
+    let json = repo.run_stax_with_env(
+        &["pr", "view", "42", "--json"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    json.assert_success();
+    let value: serde_json::Value =
+        serde_json::from_str(&TestRepo::stdout(&json)).expect("pr view JSON");
+    assert_eq!(value["title"], "Split the lexer out of the parser");
+    assert_eq!(value["base_branch"], "main");
+    assert_eq!(value["head_branch"], branch.as_str());
+    assert_eq!(value["ci"]["passed"], 1);
+}
+
+#[test]
+fn pr_view_reports_missing_pr_without_failing() {
+    let repo = TestRepo::new();
+    repo.create_stack(&["no-pr-yet"]);
+
+    repo.run_stax(&["pr", "view"])
+        .assert_success()
+        .assert_stdout_contains("No PR found for branch");
+}
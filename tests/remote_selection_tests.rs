@@ -0,0 +1,90 @@
+//! `--remote <name>` on submit/sync/merge and the remote remembered per stack.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+/// Adds a bare `work` remote that looks like `github.com/work-org/test-repo`.
+fn add_work_remote(repo: &TestRepo) -> tempfile::TempDir {
+    repo.configure_github_like_submit_remote();
+    let work = tempfile::tempdir().expect("work dir");
+    let work_path = work.path().to_str().unwrap();
+    repo.git(&["init", "--bare", work_path]).assert_success();
+    repo.git(&[
+        "remote",
+        "add",
+        "work",
+        "https://github.com/work-org/test-repo.git",
+    ])
+    .assert_success();
+    repo.git(&[
+        "config",
+        "--local",
+        &format!("url.file://{work_path}.insteadOf"),
+        "https://github.com/work-org/test-repo.git",
+    ])
+    .assert_success();
+    repo.git(&["push", "work", "main"]).assert_success();
+    work
+}
+
+fn remote_has_branch(repo: &TestRepo, remote: &str, branch: &str) -> bool {
+    let heads = repo.git(&["ls-remote", "--heads", remote, branch]);
+    heads.assert_success();
+    !TestRepo::stdout(&heads).trim().is_empty()
+}
+
+fn branch_info(repo: &TestRepo, branch: &str) -> serde_json::Value {
+    let out = repo.run_stax(&["branch", "info", branch, "--json"]);
+    out.assert_success();
+    serde_json::from_str(&TestRepo::stdout(&out)).expect("branch info JSON")
+}
+
+#[test]
+fn submit_remote_pushes_there_and_remembers_it_for_the_stack() {
+    let repo = TestRepo::new_with_remote();
+    let _work = add_work_remote(&repo);
+    let branches = repo.create_stack(&["work-a", "work-b"]);
+
+    repo.run_stax(&["submit", "--no-pr", "--yes", "--remote", "work"])
+        .assert_success();
+
+    for branch in &branches {
+        assert!(remote_has_branch(&repo, "work", branch));
+        assert!(!remote_has_branch(&repo, "origin", branch));
+        assert_eq!(branch_info(&repo, branch)["remote"], "work");
+    }
+
+    // Later submits reuse the recorded remote without the flag.
+    repo.create_file("more.txt", "more\n");
+    repo.commit("More work");
+    repo.run_stax(&["submit", "--no-pr", "--yes"])
+        .assert_success();
+    let top = branches.last().unwrap();
+    let pushed = repo.git(&["ls-remote", "--heads", "work", top]);
+    assert!(TestRepo::stdout(&pushed).starts_with(&repo.get_commit_sha(top)));
+    assert!(!remote_has_branch(&repo, "origin", top));
+}
+
+#[test]
+fn submit_remote_rejects_unknown_remote() {
+    let repo = TestRepo::new_with_remote();
+    repo.create_stack(&["lonely"]);
+
+    repo.run_stax(&["submit", "--no-pr", "--yes", "--remote", "nope"])
+        .assert_failure()
+        .assert_stderr_contains("Remote 'nope' not found");
+}
+
+#[test]
+fn sync_remote_updates_trunk_from_selected_remote() {
+    let repo = TestRepo::new_with_remote();
+    let _work = add_work_remote(&repo);
+    let work_main = repo.get_commit_sha("main");
+    repo.simulate_remote_commit("origin.txt", "origin only\n", "Origin change");
+
+    repo.run_stax(&["sync", "--force", "--remote", "work"])
+        .assert_success();
+
+    assert_eq!(repo.get_commit_sha("main"), work_main);
+}
@@ -0,0 +1,122 @@
+//! `stax --timeout <secs>` / `remote.request_timeout_secs` for forge API calls.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn ensure_crypto_provider() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+}
+
+fn write_test_config(home: &Path, extra: &str, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("Failed to create config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{api_base_url}\"\n{extra}"),
+    )
+    .expect("Failed to write config");
+}
+
+fn write_branch_pr_metadata(repo: &TestRepo, branch: &str, pr_number: u64) {
+    let metadata = serde_json::json!({
+        "parentBranchName": "main",
+        "parentBranchRevision": repo.get_commit_sha("main"),
+        "prInfo": { "number": pr_number, "state": "OPEN" }
+    });
+
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{}", branch),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+/// One branch with PR #42 against a forge whose every API answer stalls.
+async fn setup_stalled_forge(mock_server: &MockServer, extra_config: &str) -> TestRepo {
+    let repo = TestRepo::new();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), extra_config, &mock_server.uri());
+    repo.git(&[
+        "remote",
+        "add",
+        "origin",
+        "https://github.com/test/repo.git",
+    ])
+    .assert_success();
+    let branch = repo.create_stack(&["slow-forge"])[0].clone();
+    write_branch_pr_metadata(&repo, &branch, 42);
+
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "data": null }))
+                .set_delay(Duration::from_secs(10)),
+        )
+        .mount(mock_server)
+        .await;
+    repo
+}
+
+#[tokio::test]
+async fn timeout_flag_fails_stalled_forge_request_fast() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let repo = setup_stalled_forge(&mock_server, "").await;
+
+    let started = Instant::now();
+    let output = repo.run_stax_with_env(
+        &["--timeout", "1", "merge", "--dry-run"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output.assert_failure().assert_stderr_contains("timed out");
+    assert!(
+        started.elapsed() < Duration::from_secs(9),
+        "merge should give up well before the stalled response, took {:?}",
+        started.elapsed()
+    );
+}
+
+#[tokio::test]
+async fn request_timeout_config_applies_without_flag() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let repo = setup_stalled_forge(&mock_server, "request_timeout_secs = 1\n").await;
+
+    let output = repo.run_stax_with_env(
+        &["merge", "--dry-run"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output.assert_failure().assert_stderr_contains("timed out");
+}
+
+#[tokio::test]
+async fn status_falls_back_to_cached_state_when_forge_is_unreachable() {
+    ensure_crypto_provider();
+    let mock_server = MockServer::start().await;
+    let repo = setup_stalled_forge(&mock_server, "").await;
+
+    let output = repo.run_stax_with_env(
+        &["--timeout", "1", "status", "--approvals"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output
+        .assert_success()
+        .assert_stdout_contains("Approvals: 0/1 PR approved (1 unknown)")
+        .assert_stdout_contains("Offline: no cached PR/CI state yet");
+}
@@ -0,0 +1,76 @@
+//! Tests for `--restack-strategy merge`, which brings branches up to date by
+//! merging their parent in instead of rebasing them.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+fn parent_count(repo: &TestRepo, rev: &str) -> usize {
+    let output = repo.git(&["rev-list", "--parents", "-n", "1", rev]);
+    output.assert_success();
+    TestRepo::stdout(&output).split_whitespace().count() - 1
+}
+
+fn is_ancestor(repo: &TestRepo, ancestor: &str, descendant: &str) -> bool {
+    repo.git(&["merge-base", "--is-ancestor", ancestor, descendant])
+        .status
+        .success()
+}
+
+#[test]
+fn restack_merge_strategy_merges_parent_instead_of_rewriting_history() {
+    let repo = TestRepo::new();
+    let branches = repo.create_stack(&["merge-parent", "merge-child"]);
+    let (parent, child) = (&branches[0], &branches[1]);
+    let child_before = repo.get_commit_sha(child);
+
+    repo.run_stax(&["checkout", parent]).assert_success();
+    repo.create_file("parent-update.txt", "parent update\n");
+    repo.commit("Parent update");
+    let parent_tip = repo.get_commit_sha(parent);
+    repo.run_stax(&["checkout", child]).assert_success();
+
+    repo.run_stax(&["restack", "--restack-strategy", "merge", "--quiet"])
+        .assert_success();
+
+    let child_after = repo.get_commit_sha(child);
+    assert_ne!(child_after, child_before);
+    assert_eq!(parent_count(&repo, child), 2, "expected a merge commit");
+    assert!(
+        is_ancestor(&repo, &child_before, child),
+        "original child commit must be kept, not rewritten"
+    );
+    assert!(is_ancestor(&repo, &parent_tip, child));
+    assert!(repo.path().join("parent-update.txt").exists());
+
+    let status = repo.get_status_json();
+    let entry = status["branches"]
+        .as_array()
+        .expect("branches array")
+        .iter()
+        .find(|entry| entry["name"] == child.as_str())
+        .expect("child listed");
+    assert_eq!(entry["needs_restack"], false);
+}
+
+#[test]
+fn sync_restack_merge_strategy_merges_updated_trunk() {
+    let repo = TestRepo::new_with_remote();
+    let branch = repo.create_stack(&["merge-sync"]).remove(0);
+    let branch_before = repo.get_commit_sha(&branch);
+    repo.simulate_remote_commit("trunk-update.txt", "trunk\n", "Trunk update");
+
+    repo.run_stax(&[
+        "sync",
+        "--restack",
+        "--restack-strategy",
+        "merge",
+        "--force",
+        "--no-delete",
+    ])
+    .assert_success();
+
+    assert_eq!(parent_count(&repo, &branch), 2, "expected a merge commit");
+    assert!(is_ancestor(&repo, &branch_before, &branch));
+    assert!(is_ancestor(&repo, "main", &branch));
+}
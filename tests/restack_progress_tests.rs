@@ -0,0 +1,104 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+fn three_branch_stack_behind_trunk(repo: &TestRepo) -> Vec<String> {
+    let branches = repo.create_stack(&["progress-a", "progress-b", "progress-c"]);
+    repo.run_stax(&["trunk"]).assert_success();
+    repo.create_file("trunk.txt", "trunk moved\n");
+    repo.commit("Advance trunk");
+    repo.run_stax(&["checkout", &branches[2]]).assert_success();
+    branches
+}
+
+#[test]
+fn restack_all_numbers_each_branch_step() {
+    let repo = TestRepo::new();
+    let branches = three_branch_stack_behind_trunk(&repo);
+
+    let output = repo.run_stax(&["restack", "--all", "--yes"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    for (step, branch) in branches.iter().enumerate() {
+        let label = format!("[{}/3] Restacking {}", step + 1, branch);
+        assert!(stdout.contains(&label), "expected {label:?}: {stdout}");
+    }
+}
+
+#[test]
+fn restack_quiet_hides_step_counters() {
+    let repo = TestRepo::new();
+    three_branch_stack_behind_trunk(&repo);
+
+    let output = repo.run_stax(&["restack", "--all", "--yes", "--quiet"]);
+    output.assert_success();
+    assert!(
+        !TestRepo::stdout(&output).contains("/3]"),
+        "{}",
+        TestRepo::stdout(&output)
+    );
+}
+
+#[test]
+fn sync_restack_numbers_each_branch_step() {
+    let repo = TestRepo::new_with_remote();
+    let branches = three_branch_stack_behind_trunk(&repo);
+    repo.git(&["push", "origin", "main"]).assert_success();
+
+    let output = repo.run_stax(&["sync", "--restack", "--force"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    assert!(stdout.contains("[2/3] Restack"), "{stdout}");
+    assert!(
+        stdout.contains(&format!("[3/3] Restack {}", branches[2])),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn sync_restack_summary_shows_each_branch_duration() {
+    let repo = TestRepo::new_with_remote();
+    let branches = three_branch_stack_behind_trunk(&repo);
+    repo.git(&["push", "origin", "main"]).assert_success();
+
+    let output = repo.run_stax(&["sync", "--restack", "--force"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    let summary = stdout
+        .split("Restack summary:")
+        .nth(1)
+        .unwrap_or_else(|| panic!("missing restack summary: {stdout}"));
+    for branch in &branches {
+        let line = summary
+            .lines()
+            .find(|line| line.contains(branch.as_str()))
+            .unwrap_or_else(|| panic!("{branch} missing from summary: {summary}"));
+        assert!(
+            line.contains(" ok ") && line.trim_end().ends_with('s'),
+            "expected a duration on {line:?}"
+        );
+    }
+}
+
+#[test]
+fn sync_report_json_includes_restack_durations() {
+    let repo = TestRepo::new_with_remote();
+    let branches = three_branch_stack_behind_trunk(&repo);
+    repo.git(&["push", "origin", "main"]).assert_success();
+
+    let output = repo.run_stax(&["sync", "--restack", "--force", "--report", "json"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    let report: serde_json::Value = serde_json::from_str(&stdout)
+        .unwrap_or_else(|err| panic!("report is not JSON ({err}): {stdout}"));
+
+    let restacked = report["restacked"].as_array().expect("restacked array");
+    assert_eq!(restacked.len(), branches.len(), "{report}");
+    for (entry, branch) in restacked.iter().zip(&branches) {
+        assert_eq!(entry["branch"], branch.as_str());
+        assert_eq!(entry["status"], "ok");
+        assert!(entry["duration_ms"].is_u64(), "{entry}");
+        assert!(entry["slow"].is_boolean(), "{entry}");
+    }
+    assert!(report["duration_ms"].is_u64(), "{report}");
+}
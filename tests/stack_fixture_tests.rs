@@ -0,0 +1,32 @@
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+
+#[test]
+fn create_stack_with_commits_returns_the_shas_git_sees() {
+    let repo = TestRepo::new();
+    let stack = repo.create_stack_with_commits(&[
+        ("fixture-a", &["A1", "A2"]),
+        ("fixture-b", &["B1"]),
+        ("fixture-c", &["C1", "C2", "C3"]),
+    ]);
+
+    assert_eq!(stack.len(), 3);
+    let mut parent = "main".to_string();
+    for name in ["fixture-a", "fixture-b", "fixture-c"] {
+        let branch = repo
+            .find_branch_containing(name)
+            .unwrap_or_else(|| panic!("branch {} not created", name));
+        let output = repo.git(&["rev-list", "--reverse", &format!("{}..{}", parent, branch)]);
+        output.assert_success();
+        let rev_list: Vec<String> = TestRepo::stdout(&output)
+            .lines()
+            .map(str::to_string)
+            .collect();
+        assert_eq!(stack[&branch], rev_list, "commits on {}", branch);
+        parent = branch;
+    }
+
+    let subject = repo.git(&["log", "-1", "--format=%s", &stack["fixture-c"][1]]);
+    assert_eq!(TestRepo::stdout(&subject).trim(), "C2");
+}
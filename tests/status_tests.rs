@@ -86,3 +86,384 @@ fn status_labels_missing_parent_instead_of_needs_restack() {
         "missing-parent-only status should not suggest restack, got: {stdout}"
     );
 }
+
+#[test]
+fn status_reports_branches_disconnected_by_untracked_middle_branch() {
+    let repo = TestRepo::new();
+    let branches = repo.create_stack(&["stray-base", "stray-middle", "stray-tip"]);
+    let (base, middle, tip) = (&branches[0], &branches[1], &branches[2]);
+
+    repo.git(&[
+        "update-ref",
+        "-d",
+        &format!("refs/branch-metadata/{}", middle),
+    ])
+    .assert_success();
+
+    let output = repo.run_stax(&["status", "--json"]);
+    output.assert_success();
+    let status: Value =
+        serde_json::from_str(&TestRepo::stdout(&output)).expect("status JSON should parse");
+    let entry = |name: &str| {
+        status["branches"]
+            .as_array()
+            .expect("status JSON should include branches")
+            .iter()
+            .find(|entry| entry["name"].as_str() == Some(name))
+            .cloned()
+            .unwrap_or_else(|| panic!("missing {name} in status JSON"))
+    };
+
+    let tip_entry = entry(tip);
+    assert_eq!(tip_entry["disconnected"], true);
+    assert_eq!(tip_entry["disconnected_at"], middle.as_str());
+    assert_eq!(entry(base)["disconnected"], false);
+
+    let doctor = repo.run_stax(&["doctor"]);
+    doctor.assert_stdout_contains("Branches not connected to trunk");
+    doctor.assert_stdout_contains(tip);
+}
+
+fn set_pr_number(repo: &TestRepo, branch: &str, parent: &str, number: u64) {
+    set_pr_state(repo, branch, parent, number, "OPEN");
+}
+
+fn set_pr_state(repo: &TestRepo, branch: &str, parent: &str, number: u64, state: &str) {
+    let metadata = serde_json::json!({
+        "parentBranchName": parent,
+        "parentBranchRevision": repo.get_commit_sha(parent),
+        "prInfo": { "number": number, "state": state }
+    });
+    let file = tempfile::NamedTempFile::new().expect("metadata file");
+    std::fs::write(file.path(), metadata.to_string()).expect("metadata contents");
+    let hash = repo.git(&["hash-object", "-w", file.path().to_str().unwrap()]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{branch}"),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+#[test]
+fn status_only_prs_hides_branches_without_prs() {
+    let repo = TestRepo::new();
+    let branches = repo.create_stack(&["prs-base", "prs-middle", "prs-tip"]);
+    let (base, middle, tip) = (&branches[0], &branches[1], &branches[2]);
+    set_pr_number(&repo, base, "main", 11);
+    set_pr_number(&repo, tip, middle, 13);
+
+    let output = repo.run_stax(&["status", "--only-prs"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    assert!(stdout.contains(base.as_str()), "expected {base}: {stdout}");
+    assert!(stdout.contains(tip.as_str()), "expected {tip}: {stdout}");
+    assert!(
+        !stdout.contains(middle.as_str()),
+        "unexpected {middle}: {stdout}"
+    );
+    assert!(
+        stdout.contains("main"),
+        "trunk should stay visible: {stdout}"
+    );
+
+    let json = repo.run_stax(&["status", "--only-prs", "--json"]);
+    json.assert_success();
+    let status: Value =
+        serde_json::from_str(&TestRepo::stdout(&json)).expect("status JSON should parse");
+    let names: Vec<&str> = status["branches"]
+        .as_array()
+        .expect("status JSON should include branches")
+        .iter()
+        .filter_map(|entry| entry["name"].as_str())
+        .collect();
+    assert_eq!(names, vec![tip.as_str(), base.as_str(), "main"]);
+}
+
+#[test]
+fn status_flags_branches_whose_parent_is_not_submitted() {
+    let repo = TestRepo::new_with_remote();
+    let branches = repo.create_stack(&["unpushed-base", "unpushed-child", "unpushed-tip"]);
+    let (base, child, tip) = (&branches[0], &branches[1], &branches[2]);
+    repo.git(&["push", "origin", base]).assert_success();
+
+    let status_entry = |name: &str| -> Value {
+        let output = repo.run_stax(&["status", "--json"]);
+        output.assert_success();
+        let status: Value =
+            serde_json::from_str(&TestRepo::stdout(&output)).expect("status JSON should parse");
+        status["branches"]
+            .as_array()
+            .expect("status JSON should include branches")
+            .iter()
+            .find(|entry| entry["name"] == name)
+            .cloned()
+            .expect("branch should be listed")
+    };
+
+    assert_eq!(status_entry(base)["parent_unpushed"], false);
+    assert_eq!(status_entry(child)["parent_unpushed"], false);
+    assert_eq!(status_entry(tip)["parent_unpushed"], true);
+
+    // A pushed parent with new local commits is not submitted either.
+    repo.run_stax(&["checkout", base]).assert_success();
+    repo.create_file("unpushed-extra.txt", "local only\n");
+    repo.commit("Local-only base change");
+    assert_eq!(status_entry(child)["parent_unpushed"], true);
+
+    let output = repo.run_stax(&["status"]);
+    output
+        .assert_success()
+        .assert_stdout_contains("parent not submitted");
+}
+
+#[test]
+fn status_flags_merged_prs_that_still_have_local_commits() {
+    let repo = TestRepo::new();
+    let branches = repo.create_stack(&["leftover-merged", "leftover-open"]);
+    let (merged, open) = (&branches[0], &branches[1]);
+    set_pr_state(&repo, merged, "main", 21, "MERGED");
+    set_pr_state(&repo, open, merged, 22, "OPEN");
+
+    let json = repo.run_stax(&["status", "--json"]);
+    json.assert_success();
+    let status: Value =
+        serde_json::from_str(&TestRepo::stdout(&json)).expect("status JSON should parse");
+    let entry = |name: &str| -> Value {
+        status["branches"]
+            .as_array()
+            .expect("status JSON should include branches")
+            .iter()
+            .find(|entry| entry["name"] == name)
+            .cloned()
+            .expect("branch should be listed")
+    };
+    assert_eq!(entry(merged)["merged_with_leftovers"], true);
+    assert_eq!(entry(open)["merged_with_leftovers"], false);
+    assert_eq!(entry("main")["merged_with_leftovers"], false);
+
+    let output = repo.run_stax(&["status"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    let merged_line = stdout
+        .lines()
+        .find(|line| line.contains(merged.as_str()) && !line.contains(open.as_str()))
+        .expect("merged branch line");
+    assert!(
+        merged_line.contains("merged, local leftovers"),
+        "expected leftovers label: {stdout}"
+    );
+    assert!(
+        stdout.contains("1 branch has a merged PR but still has local commits"),
+        "expected cleanup hint: {stdout}"
+    );
+}
+
+#[test]
+fn status_health_is_yellow_when_a_branch_needs_restack() {
+    let repo = TestRepo::new();
+    let branches = repo.create_stack(&["health-parent", "health-child"]);
+    repo.run_stax(&["checkout", &branches[0]]).assert_success();
+    repo.create_file("parent-moved.txt", "new parent work");
+    repo.commit("Move parent ahead of child");
+
+    let json = repo.run_stax(&["status", "--json"]);
+    json.assert_success();
+    let status: Value =
+        serde_json::from_str(&TestRepo::stdout(&json)).expect("status JSON should parse");
+    let health = &status["health"];
+    assert_eq!(health["overall"], "yellow", "health: {health}");
+    assert_eq!(health["needs_restack"], 1);
+    assert_eq!(health["failing_ci"], 0);
+    assert_eq!(health["disconnected"], 0);
+    assert!(
+        health["changes_requested"].is_null(),
+        "review decisions are only looked up with --approvals: {health}"
+    );
+
+    let output = repo.run_stax(&["status"]);
+    output.assert_success();
+    assert!(
+        TestRepo::stdout(&output).contains("Health: yellow · 1 needs restack"),
+        "expected health summary: {}",
+        TestRepo::stdout(&output)
+    );
+}
+
+#[test]
+fn status_json_describes_the_forge_remote() {
+    let repo = TestRepo::new_with_remote();
+    repo.configure_github_like_submit_remote();
+    repo.create_stack(&["remote-json"]);
+
+    let status = repo.get_status_json();
+    assert_eq!(status["schema_version"], 1);
+    let remote = &status["remote"];
+    assert_eq!(remote["name"], "origin", "remote: {remote}");
+    assert_eq!(remote["provider"], "github");
+    assert_eq!(remote["owner"], "test-owner");
+    assert_eq!(remote["repo"], "test-repo");
+    assert_eq!(remote["base_url"], "https://github.com");
+}
+
+#[test]
+fn status_json_omits_remote_without_one() {
+    let repo = TestRepo::new();
+    repo.create_stack(&["no-remote-json"]);
+
+    let status = repo.get_status_json();
+    assert_eq!(status["trunk"], "main");
+    assert!(status.get("remote").is_none(), "status: {status}");
+}
+
+#[test]
+fn tree_style_ascii_renders_stack_without_box_drawing() {
+    let repo = TestRepo::new();
+    repo.create_stack(&["ascii-a", "ascii-b"]);
+    repo.run_stax(&["trunk"]).assert_success();
+    repo.run_stax(&["bc", "ascii-side"]).assert_success();
+
+    for command in [
+        vec!["status", "--tree-style", "ascii"],
+        vec!["log", "--tree-style", "ascii"],
+    ] {
+        let output = repo.run_stax(&command);
+        output.assert_success();
+        let stdout = TestRepo::stdout(&output);
+        for glyph in ['│', '─', '┘', '┴', '◉', '○'] {
+            assert!(
+                !stdout.contains(glyph),
+                "{command:?} printed {glyph:?}: {stdout}"
+            );
+        }
+        assert!(stdout.contains("ascii-b"), "missing branch: {stdout}");
+        assert!(stdout.contains("| "), "missing vertical lane: {stdout}");
+        assert!(stdout.contains("o-`"), "missing corner: {stdout}");
+    }
+}
+
+#[test]
+fn tree_style_config_default_applies_to_status() {
+    let repo = TestRepo::new();
+    repo.create_stack(&["cfg-ascii"]);
+    let home = repo.clean_home();
+    std::fs::write(
+        std::path::Path::new(&home).join(".config/stax/config.toml"),
+        "[ui]\ntree_style = \"ascii\"\n",
+    )
+    .unwrap();
+
+    let output = repo.run_stax(&["status"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    assert!(!stdout.contains('○'), "unexpected unicode node: {stdout}");
+    assert!(
+        stdout.contains("@ "),
+        "expected ascii current marker: {stdout}"
+    );
+}
+
+#[test]
+fn status_next_action_hint_recommends_restack_after_trunk_advances() {
+    let repo = TestRepo::new();
+    repo.create_stack(&["hint-a", "hint-b"]);
+    repo.run_stax(&["t"]).assert_success();
+    repo.create_file("trunk-moved.txt", "new trunk work");
+    repo.commit("Advance trunk");
+
+    let output = repo.run_stax(&["status"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    let last_line = stdout.lines().last().unwrap_or_default();
+    assert!(
+        last_line.contains("Next:")
+            && last_line.contains("1 branch needs restack")
+            && last_line.contains("stax restack --all"),
+        "expected restack next-action hint last, got: {stdout}"
+    );
+
+    let quiet = repo.run_stax(&["status", "--quiet"]);
+    quiet.assert_success();
+    assert!(
+        !TestRepo::stdout(&quiet).contains("Next:"),
+        "--quiet should suppress the next-action hint"
+    );
+}
+
+#[test]
+fn status_flat_lists_branches_bottom_to_top_without_tree_connectors() {
+    let repo = TestRepo::new();
+    let branches = repo.create_stack(&["flat-a", "flat-b"]);
+    let (base, tip) = (&branches[0], &branches[1]);
+    set_pr_number(&repo, tip, base, 21);
+    repo.run_stax(&["t"]).assert_success();
+    let side = repo.create_stack(&["flat-side"])[0].clone();
+
+    let output = repo.run_stax(&["status", "--flat"]);
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    assert!(
+        !stdout.contains(['│', '├', '└', '┘', '┴', '─', '◉', '○']),
+        "flat output should have no tree glyphs: {stdout}"
+    );
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "main [0/0] - -".to_string(),
+            format!("  {side} [1/0] - -"),
+            format!("  {base} [1/0] - -"),
+            format!("    {tip} [1/0] #21 -"),
+        ],
+        "unexpected flat output: {stdout}"
+    );
+}
+
+#[test]
+fn status_offline_marks_cached_state_as_stale() {
+    let repo = TestRepo::new();
+    let branch = repo.create_stack(&["offline-status"])[0].clone();
+    set_pr_number(&repo, &branch, "main", 31);
+    let cache = serde_json::json!({
+        "branches": {
+            branch.as_str(): {
+                "ci_revision": repo.get_commit_sha(&branch),
+                "ci_state": "success",
+                "pr_state": "OPEN",
+                "updated_at": 1_790_000_000u64
+            }
+        },
+        "last_refresh": 1_790_000_000u64
+    });
+    std::fs::create_dir_all(repo.path().join(".git/stax")).expect("cache dir");
+    std::fs::write(
+        repo.path().join(".git/stax/ci-cache.json"),
+        cache.to_string(),
+    )
+    .expect("seed CI cache");
+
+    repo.run_stax(&["status"])
+        .assert_success()
+        .assert_stdout_not_contains("stale as of");
+    repo.run_stax(&["status", "--offline", "--approvals"])
+        .assert_success()
+        .assert_stdout_contains("Offline: PR/CI state is stale as of ")
+        .assert_stdout_contains("Approvals: 0/1 PR approved (1 unknown)");
+
+    let output = repo.run_stax(&["status", "--offline", "--json"]);
+    output.assert_success();
+    let status: Value = serde_json::from_str(&TestRepo::stdout(&output)).expect("status JSON");
+    let stale_as_of = status["stale_as_of"].as_str().expect("stale_as_of");
+    assert_eq!(
+        chrono::DateTime::parse_from_rfc3339(stale_as_of)
+            .expect("RFC 3339 timestamp")
+            .timestamp(),
+        1_790_000_000
+    );
+    let entry = status["branches"]
+        .as_array()
+        .and_then(|branches| branches.iter().find(|b| b["name"] == branch.as_str()))
+        .expect("branch entry");
+    assert_eq!(entry["ci_state"], "success");
+}
@@ -0,0 +1,167 @@
+//! `stax submit`/`stax status` handling of cross-repo PRs, whose head branch
+//! lives in a fork rather than the base repo.
+
+use crate::common::{OutputAssertions, TestRepo};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("failed to create test config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!(
+            "[remote]\napi_base_url = \"{api_base_url}\"\n\n\
+             [submit]\nstack_links = \"off\"\nnative_stack = \"off\"\n"
+        ),
+    )
+    .expect("failed to write test config");
+}
+
+fn fork_pr_fixture(number: u64, branch: &str) -> serde_json::Value {
+    serde_json::json!({
+        "url": format!("https://api.github.com/repos/test-owner/test-repo/pulls/{number}"),
+        "id": number,
+        "number": number,
+        "state": "open",
+        "draft": false,
+        "title": format!("PR {number}"),
+        "body": "",
+        "head": { "ref": branch, "sha": "aaaa", "label": format!("forker:{branch}") },
+        "base": { "ref": "main", "sha": "bbbb" },
+        "html_url": format!("https://github.com/test-owner/test-repo/pull/{number}")
+    })
+}
+
+/// Adds a `fork` remote that looks like `forker`'s GitHub fork but pushes to
+/// and fetches from a local bare repo.
+fn add_fork_remote(repo: &TestRepo, fork_dir: &Path) {
+    let init = Command::new("git")
+        .args(["init", "--bare", "-q"])
+        .arg(fork_dir)
+        .output()
+        .expect("failed to init fork repo");
+    assert!(init.status.success(), "{}", TestRepo::stderr(&init));
+
+    let fork_path = fork_dir.to_string_lossy().to_string();
+    repo.git(&[
+        "remote",
+        "add",
+        "fork",
+        "https://github.com/forker/test-repo.git",
+    ])
+    .assert_success();
+    repo.git(&["remote", "set-url", "--push", "fork", &fork_path])
+        .assert_success();
+    repo.git(&[
+        "config",
+        "--local",
+        &format!("url.file://{}.insteadOf", fork_path),
+        "https://github.com/forker/test-repo.git",
+    ])
+    .assert_success();
+}
+
+fn write_branch_pr_metadata(repo: &TestRepo, branch: &str, pr_number: u64) {
+    let parent_revision = TestRepo::stdout(&repo.git(&["rev-parse", "main"]))
+        .trim()
+        .to_string();
+    let metadata = serde_json::json!({
+        "parentBranchName": "main",
+        "parentBranchRevision": parent_revision,
+        "prInfo": { "number": pr_number, "state": "OPEN", "isDraft": false }
+    });
+
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{branch}"),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+fn remote_head(remote_dir: &Path, branch: &str) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--verify", &format!("refs/heads/{branch}")])
+        .current_dir(remote_dir)
+        .output()
+        .expect("failed to run git rev-parse");
+    output
+        .status
+        .success()
+        .then(|| TestRepo::stdout(&output).trim().to_string())
+}
+
+#[tokio::test]
+async fn submit_pushes_cross_repo_pr_to_fork_remote_and_status_annotates_it() {
+    let mock_server = MockServer::start().await;
+    let repo = TestRepo::new_with_remote();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.configure_github_like_submit_remote();
+    let fork_dir = tempfile::tempdir().expect("fork temp dir");
+    add_fork_remote(&repo, fork_dir.path());
+
+    repo.create_stack(&["fork-feature"]);
+    let branch = repo.current_branch();
+    write_branch_pr_metadata(&repo, &branch, 601);
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls/601"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fork_pr_fixture(601, &branch)))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/issues/601/comments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &["submit", "--yes", "--no-prompt", "--no-template"],
+        &[("STAX_GITHUB_TOKEN", "test-token")],
+    );
+    output.assert_success();
+    let stdout = TestRepo::stdout(&output);
+    assert!(
+        stdout.contains("cross-repo") && stdout.contains("forker"),
+        "expected a cross-repo annotation, got: {stdout}"
+    );
+
+    let head = repo.head_sha();
+    assert_eq!(
+        remote_head(fork_dir.path(), &branch).as_deref(),
+        Some(head.as_str()),
+        "submit should push the branch to the fork remote"
+    );
+    let origin = repo.remote_path().expect("origin path");
+    assert_eq!(
+        remote_head(&origin, &branch),
+        None,
+        "submit should not push a fork PR's branch to the base repo"
+    );
+
+    let json = repo.get_status_json();
+    let entry = json["branches"]
+        .as_array()
+        .expect("branches array")
+        .iter()
+        .find(|entry| entry["name"] == branch.as_str())
+        .expect("branch in status");
+    assert_eq!(entry["pr_head_owner"], "forker");
+
+    let status = repo.run_stax(&["status"]);
+    status.assert_success();
+    status.assert_stdout_contains("cross-repo PR from forker");
+}
@@ -0,0 +1,123 @@
+//! Tests for `stax submit` when `--force-with-lease` is rejected because
+//! someone else force-pushed the branch since stax last fetched it.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn run_git_in(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// Rewrite `branch` on the remote from a separate clone, as a collaborator
+/// amending and force-pushing would. Returns the new remote head.
+fn force_push_rewrite_from_clone(repo: &TestRepo, branch: &str) -> String {
+    let remote_path = repo.remote_path().expect("No remote configured");
+    let clone_dir = TempDir::new().expect("temp clone");
+
+    run_git_in(
+        clone_dir.path(),
+        &["clone", remote_path.to_str().unwrap(), "."],
+    );
+    run_git_in(
+        clone_dir.path(),
+        &["checkout", "-B", branch, &format!("origin/{}", branch)],
+    );
+    run_git_in(
+        clone_dir.path(),
+        &["config", "user.email", "other@test.com"],
+    );
+    run_git_in(clone_dir.path(), &["config", "user.name", "Other User"]);
+    std::fs::write(clone_dir.path().join("theirs.txt"), "their rewrite").expect("write rewrite");
+    run_git_in(clone_dir.path(), &["add", "-A"]);
+    run_git_in(
+        clone_dir.path(),
+        &["commit", "--amend", "-m", "Rewritten by someone else"],
+    );
+    run_git_in(clone_dir.path(), &["push", "--force", "origin", branch]);
+    run_git_in(clone_dir.path(), &["rev-parse", "HEAD"])
+}
+
+fn remote_head(repo: &TestRepo, branch: &str) -> String {
+    let remote_path = repo.remote_path().expect("No remote configured");
+    run_git_in(
+        &remote_path,
+        &["rev-parse", &format!("refs/heads/{branch}")],
+    )
+}
+
+/// Push a branch, let someone else force-push over it, then add a local
+/// commit so the next submit pushes with a stale lease.
+fn repo_with_force_pushed_remote() -> (TestRepo, String, String) {
+    let repo = TestRepo::new_with_remote();
+    repo.configure_github_like_submit_remote();
+
+    repo.run_stax(&["bc", "lease-feature"]).assert_success();
+    repo.create_file("mine.txt", "my first change");
+    repo.commit("My first change");
+    let branch = repo.current_branch();
+    repo.run_stax(&["ss", "--no-pr", "--no-prompt", "--yes"])
+        .assert_success();
+
+    let their_head = force_push_rewrite_from_clone(&repo, &branch);
+
+    repo.create_file("mine-2.txt", "my second change");
+    repo.commit("My second change");
+    (repo, branch, their_head)
+}
+
+#[test]
+fn submit_detects_force_pushed_remote_and_offers_range_diff() {
+    let (repo, branch, their_head) = repo_with_force_pushed_remote();
+
+    repo.run_stax(&["ss", "--no-fetch", "--no-pr", "--no-prompt", "--yes"])
+        .assert_failure()
+        .assert_stderr_contains("The remote changed underneath this submit")
+        .assert_stderr_contains(&format!("{branch}: expected"))
+        .assert_stderr_contains(&format!("Range diff for {branch}"))
+        .assert_stderr_contains("Rewritten by someone else")
+        .assert_stderr_contains(&format!("git rebase --onto origin/{branch}"));
+
+    assert_eq!(
+        remote_head(&repo, &branch),
+        their_head,
+        "the collaborator's force-push must not be overwritten"
+    );
+}
+
+#[test]
+fn submit_quiet_fails_with_diagnosis_when_remote_was_force_pushed() {
+    let (repo, branch, their_head) = repo_with_force_pushed_remote();
+
+    let output = repo.run_stax(&[
+        "ss",
+        "--no-fetch",
+        "--no-pr",
+        "--no-prompt",
+        "--yes",
+        "--quiet",
+    ]);
+    output
+        .assert_failure()
+        .assert_stderr_contains("The remote changed underneath this submit")
+        .assert_stderr_contains(&format!("{branch}: expected"));
+    assert!(
+        !TestRepo::stderr(&output).contains("Range diff"),
+        "quiet mode should not offer the interactive range-diff"
+    );
+    assert_eq!(remote_head(&repo, &branch), their_head);
+}
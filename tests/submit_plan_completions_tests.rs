@@ -112,6 +112,91 @@ fn submit_plan_json_is_read_only_and_describes_stack_actions() {
     );
 }
 
+#[test]
+fn submit_plan_reports_leases_and_pr_metadata() {
+    let repo = TestRepo::new_with_remote();
+    let branches = repo.create_stack(&["lease-parent", "lease-child"]);
+    repo.configure_github_like_submit_remote();
+    repo.git(&["push", "origin", &branches[0]]).assert_success();
+    let pushed_oid = repo.get_commit_sha(&branches[0]);
+    repo.run_stax(&["checkout", &branches[0]]).assert_success();
+    repo.create_file("lease-update.txt", "update\n");
+    repo.commit("advance lease parent");
+    repo.run_stax(&["checkout", &branches[1]]).assert_success();
+
+    let output = repo.run_stax(&[
+        "submit",
+        "--plan",
+        "--json",
+        "--publish",
+        "--reviewers",
+        "alice,bob",
+        "--labels",
+        "stacked",
+    ]);
+    output.assert_success();
+    let plan: serde_json::Value = serde_json::from_slice(&output.stdout).expect("plan JSON");
+
+    assert_eq!(plan["new_pull_requests"], "ready");
+    assert_eq!(plan["reviewers"], serde_json::json!(["alice", "bob"]));
+    assert_eq!(plan["labels"], serde_json::json!(["stacked"]));
+    assert_eq!(plan["assignees"], serde_json::json!([]));
+    let parent = branch_plan(&plan, &branches[0]);
+    assert_eq!(parent["push"], "update");
+    assert_eq!(parent["lease_expected_oid"], pushed_oid);
+    assert_eq!(
+        branch_plan(&plan, &branches[1])["lease_expected_oid"],
+        serde_json::Value::Null
+    );
+
+    let human = repo.run_stax(&["submit", "--plan", "--reviewers", "alice"]);
+    human.assert_success();
+    human.assert_stdout_contains("force-with-lease expects");
+    human.assert_stdout_contains("reviewers: alice");
+    human.assert_stdout_contains("new PRs: prompt");
+}
+
+#[test]
+fn submit_plan_narrows_stack_with_only_and_up_to() {
+    let repo = TestRepo::new_with_remote();
+    let branches = repo.create_stack(&["range-a", "range-b", "range-c"]);
+    repo.configure_github_like_submit_remote();
+
+    let planned_branches = |args: &[&str]| -> Vec<String> {
+        let output = repo.run_stax(args);
+        output.assert_success();
+        let plan: serde_json::Value = serde_json::from_slice(&output.stdout).expect("plan JSON");
+        plan["branches"]
+            .as_array()
+            .expect("branches array")
+            .iter()
+            .map(|entry| entry["branch"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    assert_eq!(
+        planned_branches(&[
+            "submit",
+            "--plan",
+            "--json",
+            "--no-fetch",
+            "--up-to",
+            &branches[1]
+        ]),
+        vec![branches[0].clone(), branches[1].clone()]
+    );
+    assert_eq!(
+        planned_branches(&["submit", "--plan", "--json", "--no-fetch", "--only"]),
+        vec![branches[2].clone()]
+    );
+
+    repo.run_stax(&["checkout", &branches[1]]).assert_success();
+    assert_eq!(
+        planned_branches(&["submit", "--plan", "--json", "--no-fetch", "--downstack"]),
+        vec![branches[0].clone(), branches[1].clone()]
+    );
+}
+
 #[test]
 fn submit_plan_reports_invalid_repository_without_mutating_it() {
     let repo = TestRepo::new();
@@ -0,0 +1,129 @@
+use crate::common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("failed to create test config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!(
+            "[remote]\napi_base_url = \"{api_base_url}\"\n\n\
+             [submit]\nstack_links = \"off\"\nnative_stack = \"off\"\n"
+        ),
+    )
+    .expect("failed to write test config");
+}
+
+fn created_pr() -> Value {
+    serde_json::json!({
+        "url": "https://api.github.com/repos/test-owner/test-repo/pulls/42",
+        "id": 42,
+        "number": 42,
+        "state": "open",
+        "title": "created",
+        "body": "",
+        "draft": false,
+        "head": { "ref": "created", "sha": "aaaa", "label": "test-owner:created" },
+        "base": { "ref": "main", "sha": "bbbb" },
+        "html_url": "https://github.com/test-owner/test-repo/pull/42"
+    })
+}
+
+fn pr_creates_for(requests: &[wiremock::Request], branch: &str) -> usize {
+    requests
+        .iter()
+        .filter(|request| {
+            request.method.as_str() == "POST"
+                && request.url.path() == "/repos/test-owner/test-repo/pulls"
+                && serde_json::from_slice::<Value>(&request.body)
+                    .is_ok_and(|body| body["head"] == branch)
+        })
+        .count()
+}
+
+#[tokio::test]
+async fn submit_continue_resumes_from_the_branch_that_failed() {
+    let mock_server = MockServer::start().await;
+    let repo = TestRepo::new_with_remote();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.configure_github_like_submit_remote();
+    let branches = repo.create_stack(&["resume-first", "resume-second"]);
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&mock_server)
+        .await;
+    // The second PR fails to open once, as if the API had a hiccup.
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/pulls"))
+        .and(body_string_contains(branches[1].as_str()))
+        .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "message": "Validation Failed",
+            "errors": [{ "message": "temporary failure" }]
+        })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/repos/test-owner/test-repo/pulls"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(created_pr()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls/42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(created_pr()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/issues/42/comments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&mock_server)
+        .await;
+
+    let submit = ["submit", "--yes", "--no-prompt", "--no-template"];
+    let token = [("STAX_GITHUB_TOKEN", "test-token")];
+    let failed = repo.run_stax_with_env(&submit, &token);
+    assert!(!failed.status.success(), "first submit should fail");
+    let stderr = TestRepo::stderr(&failed);
+    assert!(
+        stderr.contains("1 of 2 branches were submitted"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("stax submit --continue"), "{stderr}");
+
+    let progress_path = repo.path().join(".git/stax/ops/submit-progress");
+    let progress: Value =
+        serde_json::from_str(&fs::read_to_string(&progress_path).expect("progress file"))
+            .expect("progress JSON");
+    assert_eq!(
+        progress["completed_branches"],
+        serde_json::json!([branches[0]])
+    );
+
+    // Resuming from another branch keeps the recorded scope.
+    repo.run_stax(&["checkout", "main"]).assert_success();
+    let resumed =
+        repo.run_stax_with_env(&["submit", "--continue", "--yes", "--no-template"], &token);
+    resumed
+        .assert_success()
+        .assert_stdout_contains("1 of 2 branches already submitted");
+
+    let requests = mock_server.received_requests().await.unwrap();
+    assert_eq!(pr_creates_for(&requests, &branches[0]), 1);
+    assert_eq!(pr_creates_for(&requests, &branches[1]), 2);
+    assert!(
+        !progress_path.exists(),
+        "finished submit clears its progress"
+    );
+
+    repo.run_stax_with_env(&["submit", "--continue"], &token)
+        .assert_failure()
+        .assert_stderr_contains("No interrupted submit to continue");
+}
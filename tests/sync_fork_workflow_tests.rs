@@ -0,0 +1,72 @@
+//! Fork workflow: `remote.push_remote` holds the user's fork while trunk is
+//! synced from `remote.pr_remote`.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+
+/// Adds a bare `fork` remote that looks like `github.com/me/test-repo` and
+/// points the repo's fork workflow at it.
+fn add_fork_remote(repo: &TestRepo) -> tempfile::TempDir {
+    repo.configure_github_like_submit_remote();
+    let fork = tempfile::tempdir().expect("fork dir");
+    let fork_path = fork.path().to_str().unwrap();
+    repo.git(&["init", "--bare", fork_path]).assert_success();
+    repo.git(&[
+        "remote",
+        "add",
+        "fork",
+        "https://github.com/me/test-repo.git",
+    ])
+    .assert_success();
+    repo.git(&[
+        "config",
+        "--local",
+        &format!("url.file://{fork_path}.insteadOf"),
+        "https://github.com/me/test-repo.git",
+    ])
+    .assert_success();
+    repo.git(&["push", "fork", "main"]).assert_success();
+    fs::write(
+        repo.path().join("stax.toml"),
+        "[remote]\npush_remote = \"fork\"\npr_remote = \"origin\"\n",
+    )
+    .expect("write stax.toml");
+    fork
+}
+
+#[test]
+fn sync_fast_forwards_trunk_from_pr_remote() {
+    let repo = TestRepo::new_with_remote();
+    let _fork = add_fork_remote(&repo);
+    repo.simulate_remote_commit("upstream.txt", "from upstream\n", "Upstream change");
+
+    repo.run_stax(&["sync", "--force"]).assert_success();
+
+    assert_eq!(
+        repo.get_commit_sha("main"),
+        repo.get_commit_sha("origin/main"),
+        "trunk should follow the pull request remote"
+    );
+    assert_ne!(
+        repo.get_commit_sha("main"),
+        repo.get_commit_sha("fork/main"),
+        "the fork's stale trunk must not be used"
+    );
+}
+
+#[test]
+fn submit_no_pr_pushes_branches_to_push_remote() {
+    let repo = TestRepo::new_with_remote();
+    let _fork = add_fork_remote(&repo);
+    let branch = repo.create_stack(&["forked"])[0].clone();
+
+    repo.run_stax(&["submit", "--no-pr", "--yes"])
+        .assert_success();
+
+    let fork_heads = repo.git(&["ls-remote", "--heads", "fork", &branch]);
+    assert!(TestRepo::stdout(&fork_heads).contains(&branch));
+    let origin_heads = repo.git(&["ls-remote", "--heads", "origin", &branch]);
+    assert!(TestRepo::stdout(&origin_heads).trim().is_empty());
+}
@@ -0,0 +1,72 @@
+//! `stax sync --restack --restack-real-only` skips rebasing branches whose
+//! only restack reason is a stale parent revision in metadata.
+
+use crate::common;
+
+use common::{OutputAssertions, TestRepo};
+use std::fs;
+
+fn write_parent_revision(repo: &TestRepo, branch: &str, parent_revision: &str) {
+    let metadata = serde_json::json!({
+        "parentBranchName": "main",
+        "parentBranchRevision": parent_revision,
+    });
+    let file = tempfile::NamedTempFile::new().expect("metadata file");
+    fs::write(file.path(), metadata.to_string()).expect("metadata contents");
+    let hash = repo.git(&["hash-object", "-w", file.path().to_str().unwrap()]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{branch}"),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+#[test]
+fn restack_real_only_refreshes_stale_metadata_without_rebasing() {
+    let repo = TestRepo::new_with_remote();
+    let old_main = repo.get_commit_sha("main");
+    repo.create_file("trunk.txt", "trunk moved\n");
+    repo.commit("Advance trunk");
+    repo.git(&["push", "origin", "main"]).assert_success();
+    let main = repo.get_commit_sha("main");
+
+    // Built on the current trunk, but metadata still names the old trunk commit.
+    let branch = repo.create_stack(&["drifted"])[0].clone();
+    write_parent_revision(&repo, &branch, &old_main);
+    let before = repo.get_commit_sha(&branch);
+    assert_eq!(
+        repo.get_status_json()["branches"][0]["needs_restack"],
+        true,
+        "stale metadata should flag the branch for restack"
+    );
+
+    repo.run_stax(&["sync", "--restack", "--restack-real-only", "--force"])
+        .assert_success()
+        .assert_stdout_contains("refreshed metadata without rebasing");
+
+    assert_eq!(repo.get_commit_sha(&branch), before, "branch was rebased");
+    let info = repo.run_stax(&["branch", "info", &branch, "--json"]);
+    info.assert_success();
+    let info: serde_json::Value =
+        serde_json::from_str(&TestRepo::stdout(&info)).expect("branch info JSON");
+    assert_eq!(info["parent_revision"], main.as_str());
+}
+
+#[test]
+fn restack_real_only_still_rebases_branches_behind_their_parent() {
+    let repo = TestRepo::new_with_remote();
+    let branch = repo.create_stack(&["behind"])[0].clone();
+    repo.run_stax(&["trunk"]).assert_success();
+    repo.create_file("trunk.txt", "trunk moved\n");
+    repo.commit("Advance trunk");
+    repo.git(&["push", "origin", "main"]).assert_success();
+    repo.run_stax(&["checkout", &branch]).assert_success();
+
+    repo.run_stax(&["sync", "--restack", "--restack-real-only", "--force"])
+        .assert_success();
+
+    repo.git(&["merge-base", "--is-ancestor", "main", &branch])
+        .assert_success();
+}
@@ -0,0 +1,177 @@
+//! `stax sync` retargets PRs whose parent PR merged on the forge.
+
+use crate::common::{OutputAssertions, TestRepo};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn write_test_config(home: &Path, api_base_url: &str) {
+    let config_dir = home.join(".config").join("stax");
+    fs::create_dir_all(&config_dir).expect("failed to create test config dir");
+    fs::write(
+        config_dir.join("config.toml"),
+        format!("[remote]\napi_base_url = \"{api_base_url}\"\n"),
+    )
+    .expect("failed to write test config");
+}
+
+fn write_branch_pr_metadata(repo: &TestRepo, branch: &str, parent: &str, pr_number: u64) {
+    let metadata = serde_json::json!({
+        "parentBranchName": parent,
+        "parentBranchRevision": repo.get_commit_sha(parent),
+        "prInfo": { "number": pr_number, "state": "OPEN" }
+    });
+    let metadata_file = tempfile::NamedTempFile::new().expect("metadata temp file");
+    fs::write(metadata_file.path(), metadata.to_string()).expect("write metadata temp file");
+    let hash = repo.git(&[
+        "hash-object",
+        "-w",
+        metadata_file.path().to_str().expect("metadata path"),
+    ]);
+    hash.assert_success();
+    repo.git(&[
+        "update-ref",
+        &format!("refs/branch-metadata/{branch}"),
+        TestRepo::stdout(&hash).trim(),
+    ])
+    .assert_success();
+}
+
+fn pr_fixture(number: u64, branch: &str, base: &str, merged: bool) -> Value {
+    serde_json::json!({
+        "url": format!("https://api.github.com/repos/test-owner/test-repo/pulls/{number}"),
+        "id": number,
+        "number": number,
+        "state": if merged { "closed" } else { "open" },
+        "merged_at": if merged { Some("2026-06-02T10:00:00Z") } else { None },
+        "draft": false,
+        "title": "Test PR",
+        "body": "",
+        "html_url": format!("https://github.com/test-owner/test-repo/pull/{number}"),
+        "head": { "ref": branch, "sha": "aaaa", "label": format!("test-owner:{branch}") },
+        "base": { "ref": base, "sha": "bbbb" }
+    })
+}
+
+#[tokio::test]
+async fn sync_retargets_child_pr_when_kept_parent_pr_merged() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let mock_server = MockServer::start().await;
+    let repo = TestRepo::new_with_remote();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.configure_github_like_submit_remote();
+
+    let branches = repo.create_stack(&["retarget-parent", "retarget-child"]);
+    let (parent, child) = (branches[0].clone(), branches[1].clone());
+    repo.git(&["push", "origin", &parent, &child])
+        .assert_success();
+    write_branch_pr_metadata(&repo, &parent, "main", 42);
+    write_branch_pr_metadata(&repo, &child, &parent, 43);
+
+    // The parent was merged on GitHub, but its head branch is still around and
+    // its commits are not on main, so git alone can't tell.
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls/42"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pr_fixture(42, &parent, "main", true)),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls/43"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pr_fixture(43, &child, &parent, false)),
+        )
+        .up_to_n_times(2)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls/43"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pr_fixture(43, &child, "main", false)),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-owner/test-repo/pulls/43"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pr_fixture(43, &child, "main", false)),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let output = repo.run_stax_with_env(
+        &["sync", "--force", "--no-delete"],
+        &[("STAX_GITHUB_TOKEN", "mock-token")],
+    );
+    output
+        .assert_success()
+        .assert_stdout_contains("retargeted PR #43");
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let patch = requests
+        .iter()
+        .find(|request| request.method.as_str() == "PATCH")
+        .expect("child PR should be retargeted");
+    let body: Value = serde_json::from_slice(&patch.body).unwrap();
+    assert_eq!(body["base"], "main");
+
+    let info = repo.run_stax(&["branch", "info", &child, "--json"]);
+    info.assert_success();
+    let info: Value = serde_json::from_str(&TestRepo::stdout(&info)).expect("branch info JSON");
+    assert_eq!(info["parent"], "main");
+    assert!(
+        repo.list_branches().contains(&parent),
+        "--no-delete keeps the merged parent"
+    );
+}
+
+#[tokio::test]
+async fn sync_leaves_child_pr_alone_while_parent_pr_is_open() {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    let mock_server = MockServer::start().await;
+    let repo = TestRepo::new_with_remote();
+    let home = repo.clean_home();
+    write_test_config(Path::new(&home), &mock_server.uri());
+    repo.configure_github_like_submit_remote();
+
+    let branches = repo.create_stack(&["open-parent", "open-child"]);
+    let (parent, child) = (branches[0].clone(), branches[1].clone());
+    repo.git(&["push", "origin", &parent, &child])
+        .assert_success();
+    write_branch_pr_metadata(&repo, &parent, "main", 42);
+    write_branch_pr_metadata(&repo, &child, &parent, 43);
+
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls/42"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pr_fixture(42, &parent, "main", false)),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/test-owner/test-repo/pulls/43"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(pr_fixture(43, &child, &parent, false)),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/test-owner/test-repo/pulls/43"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    repo.run_stax_with_env(&["sync", "--force"], &[("STAX_GITHUB_TOKEN", "mock-token")])
+        .assert_success();
+
+    let info = repo.run_stax(&["branch", "info", &child, "--json"]);
+    let info: Value = serde_json::from_str(&TestRepo::stdout(&info)).expect("branch info JSON");
+    assert_eq!(info["parent"], parent.as_str());
+}